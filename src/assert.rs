@@ -41,10 +41,10 @@ macro_rules! assert_as_result {
                 if a {
                     Ok(())
                 } else {
-                    Err(format!(
+                    Err($crate::__private::format!(
                         concat!(
                             "assertion failed: `assert!(condition)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert.html\n",
+                            $crate::assertables_url_line!("assert"),
                             " condition label: `{}`,\n",
                             " condition debug: `{:?}`,\n",
                         ),
@@ -73,7 +73,7 @@ mod test_assert_as_result {
         let actual = assert_as_result!(a);
         let message = concat!(
             "assertion failed: `assert!(condition)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert.html\n",
+            assertables_url_line!("assert"),
             " condition label: `a`,\n",
             " condition debug: `false`,\n",
         );
@@ -44,16 +44,16 @@ macro_rules! assert_abs_diff_gt_x_as_result {
     ($a:expr, $b:expr, $x:expr $(,)?) => {{
         match (&$a, &$b, &$x) {
             (a, b, x) => {
-                match ::std::panic::catch_unwind(|| if (a >= b) { a - b } else { b - a }) {
+                match $crate::__private::catch_unwind(|| if (a >= b) { a - b } else { b - a }) {
                     Ok(abs_diff) => {
                         if abs_diff > *x {
                             Ok((abs_diff, *x))
                         } else {
                             Err(
-                                format!(
+                                $crate::__private::format!(
                                     concat!(
                                         "assertion failed: `assert_abs_diff_gt_x!(a, b, x)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_gt_x.html\n",
+                                        $crate::assertables_url_line!("assert_abs_diff_gt_x"),
                                         " a label: `{}`,\n",
                                         " a debug: `{:?}`,\n",
                                         " b label: `{}`,\n",
@@ -77,10 +77,10 @@ macro_rules! assert_abs_diff_gt_x_as_result {
                     },
                     Err(_err) => {
                         Err(
-                            format!(
+                            $crate::__private::format!(
                                 concat!(
                                     "assertion failed: `assert_abs_diff_gt_x!(a, b, x)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_gt_x.html\n",
+                                    $crate::assertables_url_line!("assert_abs_diff_gt_x"),
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`,\n",
                                     " b label: `{}`,\n",
@@ -124,7 +124,7 @@ mod test_assert_abs_diff_gt_x_as_result {
         let actual = assert_abs_diff_gt_x_as_result!(a, b, x);
         let message = concat!(
             "assertion failed: `assert_abs_diff_gt_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_gt_x.html\n",
+            assertables_url_line!("assert_abs_diff_gt_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -145,7 +145,7 @@ mod test_assert_abs_diff_gt_x_as_result {
         let actual = assert_abs_diff_gt_x_as_result!(a, b, x);
         let message = concat!(
             "assertion failed: `assert_abs_diff_gt_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_gt_x.html\n",
+            assertables_url_line!("assert_abs_diff_gt_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -167,7 +167,7 @@ mod test_assert_abs_diff_gt_x_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_abs_diff_gt_x!(a, b, x)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_gt_x.html\n",
+                assertables_url_line!("assert_abs_diff_gt_x"),
                 " a label: `a`,\n",
                 " a debug: `{}`,\n",
                 " b label: `b`,\n",
@@ -180,6 +180,15 @@ mod test_assert_abs_diff_gt_x_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn unsigned_with_b_greater_than_a() {
+        let a: u8 = 10;
+        let b: u8 = 13;
+        let x: u8 = 2;
+        let actual = assert_abs_diff_gt_x_as_result!(a, b, x);
+        assert_eq!(actual.unwrap(), (3, 2));
+    }
 }
 
 /// Assert an absolute difference is greater than an expression.
@@ -224,7 +233,7 @@ mod test_assert_abs_diff_gt_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_abs_diff_gt_x!(a, b, x)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_gt_x.html\n",
+/// #     assertables_url_line!("assert_abs_diff_gt_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `10`,\n",
 /// #     " b label: `b`,\n",
@@ -249,13 +258,13 @@ macro_rules! assert_abs_diff_gt_x {
     ($a:expr, $b:expr, $x:expr $(,)?) => {{
         match $crate::assert_abs_diff_gt_x_as_result!($a, $b, $x) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $x:expr, $($message:tt)+) => {{
         match $crate::assert_abs_diff_gt_x_as_result!($a, $b, $x) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -283,7 +292,7 @@ mod assert_abs_diff_gt_x {
         });
         let message = concat!(
             "assertion failed: `assert_abs_diff_gt_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_gt_x.html\n",
+            assertables_url_line!("assert_abs_diff_gt_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -313,7 +322,7 @@ mod assert_abs_diff_gt_x {
         });
         let message = concat!(
             "assertion failed: `assert_abs_diff_gt_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_gt_x.html\n",
+            assertables_url_line!("assert_abs_diff_gt_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -344,7 +353,7 @@ mod assert_abs_diff_gt_x {
         let message = format!(
             concat!(
                 "assertion failed: `assert_abs_diff_gt_x!(a, b, x)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_gt_x.html\n",
+                assertables_url_line!("assert_abs_diff_gt_x"),
                 " a label: `a`,\n",
                 " a debug: `{}`,\n",
                 " b label: `b`,\n",
@@ -364,6 +373,17 @@ mod assert_abs_diff_gt_x {
             message
         );
     }
+
+    #[test]
+    fn debug_form_panics_only_with_debug_assertions() {
+        let result = panic::catch_unwind(|| {
+            let a = 10;
+            let b = 13;
+            let x = 3;
+            crate::debug_assert_abs_diff_gt_x!(a, b, x);
+        });
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
 }
 
 /// Assert an absolute difference is greater than an expression.
@@ -400,7 +420,7 @@ mod assert_abs_diff_gt_x {
 #[macro_export]
 macro_rules! debug_assert_abs_diff_gt_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_abs_diff_gt_x!($($arg)*);
         }
     };
@@ -0,0 +1,466 @@
+//! Assert an absolute difference is less than or equal to a percentage of the larger magnitude.
+//!
+//! Pseudocode:<br>
+//! | a - b | ≤ pct / 100 × max(| a |, | b |)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = 100;
+//! let b = 104;
+//! let pct = 5;
+//! assert_abs_diff_le_pct!(a, b, pct);
+//! ```
+//!
+//! This macro is useful for a relative tolerance, such as "within 5%",
+//! when the values can be of any magnitude.
+//!
+//! * The values can be any primitive numbers, such as integers or floats.
+//!   The macro computes the absolute difference in the type of the values,
+//!   as [`assert_abs_diff_le_x`](macro@crate::assert_abs_diff_le_x) does, so
+//!   large integers keep their precision. The macro converts the values to
+//!   `f64` only to compute the tolerance.
+//!
+//! * If `a` and `b` are both zero, then the assertion succeeds, at any
+//!   percentage, including zero.
+//!
+//! * The failure message shows the allowed tolerance that is derived from
+//!   the percentage, and the actual absolute difference.
+//!
+//! # Module macros
+//!
+//! * [`assert_abs_diff_le_pct`](macro@crate::assert_abs_diff_le_pct)
+//! * [`assert_abs_diff_le_pct_as_result`](macro@crate::assert_abs_diff_le_pct_as_result)
+//! * [`debug_assert_abs_diff_le_pct`](macro@crate::debug_assert_abs_diff_le_pct)
+
+/// Assert an absolute difference is less than or equal to a percentage of the larger magnitude.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ pct / 100 × max(| a |, | b |)
+///
+/// * If true, return Result `Ok((abs_diff, tolerance))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_abs_diff_le_pct`](macro@crate::assert_abs_diff_le_pct)
+/// * [`assert_abs_diff_le_pct_as_result`](macro@crate::assert_abs_diff_le_pct_as_result)
+/// * [`debug_assert_abs_diff_le_pct`](macro@crate::debug_assert_abs_diff_le_pct)
+///
+#[macro_export]
+macro_rules! assert_abs_diff_le_pct_as_result {
+    ($a:expr, $b:expr, $pct:expr $(,)?) => {{
+        match (&$a, &$b, &$pct) {
+            (a, b, pct) => {
+                match $crate::__private::catch_unwind(|| if (a >= b) { a - b } else { b - a }) {
+                    Ok(abs_diff) => {
+                        let a_f64 = *a as f64;
+                        let b_f64 = *b as f64;
+                        let a_abs = if a_f64 < 0.0 { -a_f64 } else { a_f64 };
+                        let b_abs = if b_f64 < 0.0 { -b_f64 } else { b_f64 };
+                        let max_abs = if a_abs >= b_abs { a_abs } else { b_abs };
+                        // If a and b are both zero, then they are equal at any percentage.
+                        let tolerance = if max_abs == 0.0 {
+                            0.0
+                        } else {
+                            (*pct as f64) * max_abs / 100.0
+                        };
+                        if (a_f64 == 0.0 && b_f64 == 0.0) || (abs_diff as f64) <= tolerance {
+                            Ok((abs_diff, tolerance))
+                        } else {
+                            Err(
+                                $crate::__private::format!(
+                                    concat!(
+                                        "assertion failed: `assert_abs_diff_le_pct!(a, b, pct)`\n",
+                                        $crate::assertables_url_line!("assert_abs_diff_le_pct"),
+                                        "         a label: `{}`,\n",
+                                        "         a debug: `{:?}`,\n",
+                                        "         b label: `{}`,\n",
+                                        "         b debug: `{:?}`,\n",
+                                        "       pct label: `{}`,\n",
+                                        "       pct debug: `{:?}`,\n",
+                                        "             |Δ|: `{:?}`,\n",
+                                        "       tolerance: `{:?}`,\n",
+                                        " |Δ| ≤ tolerance: {}"
+                                    ),
+                                    stringify!($a),
+                                    a,
+                                    stringify!($b),
+                                    b,
+                                    stringify!($pct),
+                                    pct,
+                                    abs_diff,
+                                    tolerance,
+                                    false
+                                )
+                            )
+                        }
+                    },
+                    Err(_err) => {
+                        Err(
+                            $crate::__private::format!(
+                                concat!(
+                                    "assertion failed: `assert_abs_diff_le_pct!(a, b, pct)`\n",
+                                    $crate::assertables_url_line!("assert_abs_diff_le_pct"),
+                                    "         a label: `{}`,\n",
+                                    "         a debug: `{:?}`,\n",
+                                    "         b label: `{}`,\n",
+                                    "         b debug: `{:?}`,\n",
+                                    "       pct label: `{}`,\n",
+                                    "       pct debug: `{:?}`,\n",
+                                    "             |Δ|: panic"
+                                ),
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b,
+                                stringify!($pct),
+                                pct
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_abs_diff_le_pct_as_result {
+    #[test]
+    fn success() {
+        let a = 100;
+        let b = 104;
+        let pct = 5;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert_eq!(actual.unwrap(), (4, 5.2));
+    }
+
+    #[test]
+    fn failure() {
+        let a = 100;
+        let b = 110;
+        let pct = 5;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        let message = concat!(
+            "assertion failed: `assert_abs_diff_le_pct!(a, b, pct)`\n",
+            assertables_url_line!("assert_abs_diff_le_pct"),
+            "         a label: `a`,\n",
+            "         a debug: `100`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `110`,\n",
+            "       pct label: `pct`,\n",
+            "       pct debug: `5`,\n",
+            "             |Δ|: `10`,\n",
+            "       tolerance: `5.5`,\n",
+            " |Δ| ≤ tolerance: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn eq() {
+        let a = 100;
+        let b = 105;
+        let pct = 5;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert_eq!(actual.unwrap(), (5, 5.25));
+    }
+
+    #[test]
+    fn zero_pct_with_equal_values() {
+        let a = 7;
+        let b = 7;
+        let pct = 0;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert_eq!(actual.unwrap(), (0, 0.0));
+    }
+
+    #[test]
+    fn zero_pct_with_different_values() {
+        let a = 7;
+        let b = 8;
+        let pct = 0;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        let message = concat!(
+            "assertion failed: `assert_abs_diff_le_pct!(a, b, pct)`\n",
+            assertables_url_line!("assert_abs_diff_le_pct"),
+            "         a label: `a`,\n",
+            "         a debug: `7`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `8`,\n",
+            "       pct label: `pct`,\n",
+            "       pct debug: `0`,\n",
+            "             |Δ|: `1`,\n",
+            "       tolerance: `0.0`,\n",
+            " |Δ| ≤ tolerance: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn both_zero() {
+        let a = 0.0;
+        let b = -0.0;
+        let pct = 0.0;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert_eq!(actual.unwrap(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn one_zero() {
+        let a = 0.0;
+        let b = 1e-9;
+        let pct = 50.0;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn small_pct() {
+        let a: f64 = 1000.0;
+        let b: f64 = 1001.0;
+        let pct: f64 = 0.1;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert!(actual.is_ok());
+        let b: f64 = 1002.0;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn large_magnitude() {
+        let a: f64 = 1.0e12;
+        let b: f64 = 1.000001e12;
+        let pct: f64 = 0.0001;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert!(actual.is_ok());
+        let pct: f64 = 0.00001;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn large_magnitude_integers() {
+        let a: i64 = 9_000_000_000;
+        let b: i64 = 9_090_000_000;
+        let pct: i64 = 1;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert_eq!(actual.unwrap(), (90_000_000, 90_900_000.0));
+    }
+
+    #[test]
+    fn large_magnitude_integers_keep_precision() {
+        let a: u64 = 9007199254740993;
+        let b: u64 = 9007199254740992;
+        let pct: u64 = 0;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert!(actual
+            .unwrap_err()
+            .ends_with("|Δ|: `1`,\n       tolerance: `0.0`,\n |Δ| ≤ tolerance: false"));
+    }
+
+    #[test]
+    fn negative_values() {
+        let a = -100;
+        let b = -104;
+        let pct = 5;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert_eq!(actual.unwrap(), (4, 5.2));
+    }
+
+    #[test]
+    fn unsigned_with_b_greater_than_a() {
+        let a: u8 = 200;
+        let b: u8 = 210;
+        let pct: u8 = 5;
+        let actual = assert_abs_diff_le_pct_as_result!(a, b, pct);
+        assert_eq!(actual.unwrap(), (10, 10.5));
+    }
+}
+
+/// Assert an absolute difference is less than or equal to a percentage of the larger magnitude.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ pct / 100 × max(| a |, | b |)
+///
+/// * If true, return `(abs_diff, tolerance)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = 100;
+/// let b = 104;
+/// let pct = 5;
+/// assert_abs_diff_le_pct!(a, b, pct);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = 100;
+/// let b = 110;
+/// let pct = 5;
+/// assert_abs_diff_le_pct!(a, b, pct);
+/// # });
+/// // assertion failed: `assert_abs_diff_le_pct!(a, b, pct)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_abs_diff_le_pct.html
+/// //          a label: `a`,
+/// //          a debug: `100`,
+/// //          b label: `b`,
+/// //          b debug: `110`,
+/// //        pct label: `pct`,
+/// //        pct debug: `5`,
+/// //              |Δ|: `10`,
+/// //        tolerance: `5.5`,
+/// //  |Δ| ≤ tolerance: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_abs_diff_le_pct!(a, b, pct)`\n",
+/// #     assertables_url_line!("assert_abs_diff_le_pct"),
+/// #     "         a label: `a`,\n",
+/// #     "         a debug: `100`,\n",
+/// #     "         b label: `b`,\n",
+/// #     "         b debug: `110`,\n",
+/// #     "       pct label: `pct`,\n",
+/// #     "       pct debug: `5`,\n",
+/// #     "             |Δ|: `10`,\n",
+/// #     "       tolerance: `5.5`,\n",
+/// #     " |Δ| ≤ tolerance: false"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for a relative tolerance, such as "within 5%",
+/// when the values can be of any magnitude.
+///
+/// * The values can be any primitive numbers, such as integers or floats.
+///   The macro computes the absolute difference in the type of the values,
+///   as [`assert_abs_diff_le_x`](macro@crate::assert_abs_diff_le_x) does, so
+///   large integers keep their precision. The macro converts the values to
+///   `f64` only to compute the tolerance.
+///
+/// * If `a` and `b` are both zero, then the assertion succeeds, at any
+///   percentage, including zero.
+///
+/// * The failure message shows the allowed tolerance that is derived from
+///   the percentage, and the actual absolute difference.
+///
+/// # Module macros
+///
+/// * [`assert_abs_diff_le_pct`](macro@crate::assert_abs_diff_le_pct)
+/// * [`assert_abs_diff_le_pct_as_result`](macro@crate::assert_abs_diff_le_pct_as_result)
+/// * [`debug_assert_abs_diff_le_pct`](macro@crate::debug_assert_abs_diff_le_pct)
+///
+#[macro_export]
+macro_rules! assert_abs_diff_le_pct {
+    ($a:expr, $b:expr, $pct:expr $(,)?) => {{
+        match $crate::assert_abs_diff_le_pct_as_result!($a, $b, $pct) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $pct:expr, $($message:tt)+) => {{
+        match $crate::assert_abs_diff_le_pct_as_result!($a, $b, $pct) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_abs_diff_le_pct {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = 100;
+        let b = 104;
+        let pct = 5;
+        let actual = assert_abs_diff_le_pct!(a, b, pct);
+        assert_eq!(actual, (4, 5.2));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = 100;
+            let b = 110;
+            let pct = 5;
+            let _actual = assert_abs_diff_le_pct!(a, b, pct);
+        });
+        let message = concat!(
+            "assertion failed: `assert_abs_diff_le_pct!(a, b, pct)`\n",
+            assertables_url_line!("assert_abs_diff_le_pct"),
+            "         a label: `a`,\n",
+            "         a debug: `100`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `110`,\n",
+            "       pct label: `pct`,\n",
+            "       pct debug: `5`,\n",
+            "             |Δ|: `10`,\n",
+            "       tolerance: `5.5`,\n",
+            " |Δ| ≤ tolerance: false"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an absolute difference is less than or equal to a percentage of the larger magnitude.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ pct / 100 × max(| a |, | b |)
+///
+/// This macro provides the same statements as [`assert_abs_diff_le_pct`](macro.assert_abs_diff_le_pct.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_abs_diff_le_pct`](macro@crate::assert_abs_diff_le_pct)
+/// * [`assert_abs_diff_le_pct_as_result`](macro@crate::assert_abs_diff_le_pct_as_result)
+/// * [`debug_assert_abs_diff_le_pct`](macro@crate::debug_assert_abs_diff_le_pct)
+///
+#[macro_export]
+macro_rules! debug_assert_abs_diff_le_pct {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_abs_diff_le_pct!($($arg)*);
+        }
+    };
+}
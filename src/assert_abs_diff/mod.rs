@@ -17,6 +17,10 @@
 //!
 //! * [`assert_abs_diff_ge_x!(a, b, x)`](macro@crate::assert_abs_diff_ge) ≈ | a - b | ≥ x
 //!
+//! Compare an absolute difference with a percentage of the larger magnitude:
+//!
+//! * [`assert_abs_diff_le_pct!(a, b, pct)`](macro@crate::assert_abs_diff_le_pct) ≈ | a - b | ≤ pct / 100 × max(| a |, | b |)
+//!
 //! # Example
 //!
 //! ```rust
@@ -35,6 +39,8 @@ pub mod assert_abs_diff_le_x;
 pub mod assert_abs_diff_lt_x;
 pub mod assert_abs_diff_ne_x;
 
+pub mod assert_abs_diff_le_pct;
+
 pub mod assert_abs_diff_eq; // Deprecated
 pub mod assert_abs_diff_ge; // Deprecated
 pub mod assert_abs_diff_gt; // Deprecated
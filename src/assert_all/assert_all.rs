@@ -9,10 +9,24 @@
 //! use assertables::*;
 //!
 //! let a = [1, 2, 3];
-//! assert_all!(a.into_iter(), |x: i8| x > 0);
+//! assert_all!(a, |x: i8| x > 0);
 //! ```
 //!
-//! This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+//! This implementation uses [`::std::iter::IntoIterator`](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html),
+//! so the collection can be an array, an iterator such as `a.iter()`, or a
+//! reference to a collection such as `&vec` or `&set`. For a reference, the
+//! predicate receives references to the elements.
+//!
+//! The failure message shows the index and the debug representation of the
+//! first element that does not match the predicate. The macro evaluates the
+//! collection expression once, and walks it once, so the collection can be a
+//! one-shot iterator. Pass a collection that is not `Copy` by reference, such
+//! as `&v`, so the macro does not move it.
+//!
+//! To show the element after the predicate takes it, the macro keeps a clone
+//! of each element until the predicate returns, and formats only the failing
+//! element. When the element type is not `Clone` and `Debug`, the message
+//! shows the index and a note instead.
 //!
 //! # Module macros
 //!
@@ -32,7 +46,7 @@
 /// This macro is useful for runtime checks, such as checking parameters,
 /// or sanitizing inputs, or handling different results in different ways.
 ///
-/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+/// This implementation uses [`::std::iter::IntoIterator`](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
 ///
 /// # Module macros
 ///
@@ -43,23 +57,30 @@
 #[macro_export]
 macro_rules! assert_all_as_result {
     ($collection:expr, $predicate:expr $(,)?) => {{
-        match (&$collection, &$predicate) {
-            (collection, _predicate) => {
-                if $collection.all($predicate) {
-                    Ok(())
-                } else {
-                    Err(format!(
+        #[allow(unused_imports)]
+        use $crate::__private::{ProbeKeepClone as _, ProbeKeepNone as _};
+        match $collection {
+            collection => {
+                match $crate::assert_all::first_failure(
+                    collection,
+                    $predicate,
+                    |element| (&$crate::__private::KeepProbe(element)).keep(),
+                ) {
+                    None => Ok(()),
+                    Some((index, element_kept)) => Err(format!(
                         concat!(
                             "assertion failed: `assert_all!(collection, predicate)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_all.html\n",
+                            $crate::assertables_url_line!("assert_all"),
                             " collection label: `{}`,\n",
-                            " collection debug: `{:?}`,\n",
-                            "        predicate: `{}`"
+                            "        predicate: `{}`,\n",
+                            "    element index: `{}`,\n",
+                            "    element debug: `{:?}`"
                         ),
                         stringify!($collection),
-                        collection,
-                        stringify!($predicate)
-                    ))
+                        stringify!($predicate),
+                        index,
+                        element_kept
+                    )),
                 }
             }
         }
@@ -82,13 +103,87 @@ mod test_assert_all_as_result {
         let actual = assert_all_as_result!(a.into_iter(), |x: i8| x > 0);
         let message = concat!(
             "assertion failed: `assert_all!(collection, predicate)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_all.html\n",
+            assertables_url_line!("assert_all"),
             " collection label: `a.into_iter()`,\n",
-            " collection debug: `IntoIter([1, -2, 3])`,\n",
-            "        predicate: `|x: i8| x > 0`"
+            "        predicate: `|x: i8| x > 0`,\n",
+            "    element index: `1`,\n",
+            "    element debug: `-2`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn success_with_array() {
+        let a = [1, 2, 3];
+        let actual = assert_all_as_result!(a, |x: i8| x > 0);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_slice() {
+        let a: &[i32] = &[1, 2, 3];
+        let actual = assert_all_as_result!(a, |x: &i32| *x > 0);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn vec_of_strings_by_reference() {
+        let a: Vec<String> = vec![String::from("alfa"), String::from("bravo")];
+        let actual = assert_all_as_result!(&a, |x: &String| x.len() > 3);
+        assert_eq!(actual.unwrap(), ());
+        let actual = assert_all_as_result!(&a, |x: &String| x.starts_with('a'));
+        let message = concat!(
+            "assertion failed: `assert_all!(collection, predicate)`\n",
+            assertables_url_line!("assert_all"),
+            " collection label: `&a`,\n",
+            "        predicate: `|x: &String| x.starts_with('a')`,\n",
+            "    element index: `1`,\n",
+            "    element debug: `\"bravo\"`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+        // The vector is borrowed, not moved, so it is usable here.
+        assert_eq!(a.len(), 2);
+    }
+
+    #[test]
+    fn hash_set_by_reference() {
+        use std::collections::HashSet;
+        let a: HashSet<i32> = HashSet::from([1, 2, 3]);
+        let actual = assert_all_as_result!(&a, |x: &i32| *x > 0);
+        assert_eq!(actual.unwrap(), ());
+        let actual = assert_all_as_result!(&a, |x: &i32| *x > 2);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn one_shot_iterator_is_evaluated_once() {
+        let a = [1, -2, 3];
+        let mut evaluations = 0;
+        let mut visits = 0;
+        let actual = assert_all_as_result!(
+            {
+                evaluations += 1;
+                a.into_iter().inspect(|_| visits += 1)
+            },
+            |x: i8| x > 0
+        );
+        assert!(actual
+            .unwrap_err()
+            .ends_with("    element index: `1`,\n    element debug: `-2`"));
+        assert_eq!(evaluations, 1);
+        assert_eq!(visits, 2);
+    }
+
+    #[test]
+    fn element_type_without_clone_and_debug() {
+        struct N(i8);
+        let a = [1, -2, 3];
+        let actual = assert_all_as_result!(a.iter().map(|x| N(*x)), |n: N| n.0 > 0);
+        assert!(actual.unwrap_err().ends_with(concat!(
+            "    element index: `1`,\n",
+            "    element debug: `(type does not implement Clone and Debug)`"
+        )));
+    }
 }
 
 /// Assert every element of the iterator matches a predicate.
@@ -119,21 +214,28 @@ mod test_assert_all_as_result {
 /// // assertion failed: `assert_all!(collection, predicate)`
 /// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_all.html
 /// //  collection label: `a.into_iter()`,
-/// //  collection debug: `IntoIter([1, -2, 3])`,
-/// //         predicate: `|x: i8| x > 0`
+/// //         predicate: `|x: i8| x > 0`,
+/// //     element index: `1`,
+/// //     element debug: `-2`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_all!(collection, predicate)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_all.html\n",
+/// #     assertables_url_line!("assert_all"),
 /// #     " collection label: `a.into_iter()`,\n",
-/// #     " collection debug: `IntoIter([1, -2, 3])`,\n",
-/// #     "        predicate: `|x: i8| x > 0`",
+/// #     "        predicate: `|x: i8| x > 0`,\n",
+/// #     "    element index: `1`,\n",
+/// #     "    element debug: `-2`",
 /// # );
 /// # assert_eq!(actual, message);
 /// # }
 /// ```
 ///
-/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+/// This implementation uses [`::std::iter::IntoIterator`](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
+///
+/// The failure message shows the index and the debug representation of the
+/// first element that does not match the predicate. The macro evaluates the
+/// collection expression once, and walks it once, so the collection can be a
+/// one-shot iterator.
 ///
 /// # Module macros
 ///
@@ -146,13 +248,13 @@ macro_rules! assert_all {
     ($collection:expr, $predicate:expr $(,)?) => {{
         match $crate::assert_all_as_result!($collection, $predicate) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($collection:expr, $predicate:expr, $($message:tt)+) => {{
         match $crate::assert_all_as_result!($collection, $predicate) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -176,10 +278,11 @@ mod test_assert_all {
         });
         let message = concat!(
             "assertion failed: `assert_all!(collection, predicate)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_all.html\n",
+            assertables_url_line!("assert_all"),
             " collection label: `a.into_iter()`,\n",
-            " collection debug: `IntoIter([1, -2, 3])`,\n",
-            "        predicate: `|x: i8| x > 0`"
+            "        predicate: `|x: i8| x > 0`,\n",
+            "    element index: `1`,\n",
+            "    element debug: `-2`"
         );
         assert_eq!(
             result
@@ -226,7 +329,7 @@ mod test_assert_all {
 #[macro_export]
 macro_rules! debug_assert_all {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_all!($($arg)*);
         }
     };
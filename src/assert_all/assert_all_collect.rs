@@ -0,0 +1,328 @@
+//! Assert every element of the iterator matches a predicate, and report every element that does not.
+//!
+//! Pseudocode:<br>
+//! collection into iter ∀ predicate
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3];
+//! assert_all_collect!(a, |x: i8| x > 0);
+//! ```
+//!
+//! This macro is useful for data validation, because the failure message shows
+//! every element that does not match the predicate, rather than only the first.
+//! The macro calls the predicate for every element, even after a failure.
+//!
+//! The failure message shows the failure count, and each failure as a pair of
+//! (index, value). The macro evaluates the collection expression once, and
+//! walks it once, so the collection can be a one-shot iterator. Pass a
+//! collection that is not `Copy` by reference, such as `&v`, so the macro
+//! does not move it.
+//!
+//! This implementation uses [`::std::iter::IntoIterator`](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
+//!
+//! # Module macros
+//!
+//! * [`assert_all_collect`](macro@crate::assert_all_collect)
+//! * [`assert_all_collect_as_result`](macro@crate::assert_all_collect_as_result)
+//! * [`debug_assert_all_collect`](macro@crate::debug_assert_all_collect)
+
+/// Assert every element of the iterator matches a predicate, and report every element that does not.
+///
+/// Pseudocode:<br>
+/// collection into iter ∀ predicate
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(failures)`, where `failures` is an
+///   [`AllCollectFailures`](crate::assert_all::AllCollectFailures) with the
+///   list of (index, value) failures, and displays as the message.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_all_collect`](macro@crate::assert_all_collect)
+/// * [`assert_all_collect_as_result`](macro@crate::assert_all_collect_as_result)
+/// * [`debug_assert_all_collect`](macro@crate::debug_assert_all_collect)
+///
+#[macro_export]
+macro_rules! assert_all_collect_as_result {
+    ($collection:expr, $predicate:expr $(,)?) => {{
+        match $collection {
+            collection => {
+                let collection_debug = format!("{:?}", collection);
+                let failures = $crate::assert_all::all_failures(collection, $predicate);
+                if failures.is_empty() {
+                    Ok(())
+                } else {
+                    let message = format!(
+                        concat!(
+                            "assertion failed: `assert_all_collect!(collection, predicate)`\n",
+                            $crate::assertables_url_line!("assert_all_collect"),
+                            " collection label: `{}`,\n",
+                            " collection debug: `{}`,\n",
+                            "        predicate: `{}`,\n",
+                            "    failure count: `{}`,\n",
+                            "         failures: `[{}]`"
+                        ),
+                        stringify!($collection),
+                        collection_debug,
+                        stringify!($predicate),
+                        failures.len(),
+                        failures
+                            .iter()
+                            .map(|(index, debug)| format!("({}, {})", index, debug))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    );
+                    Err($crate::assert_all::AllCollectFailures { failures, message })
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_all_collect_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let actual = assert_all_collect_as_result!(a.into_iter(), |x: i8| x > 0);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, -2, 3, -4];
+        let actual = assert_all_collect_as_result!(a.into_iter(), |x: i8| x > 0);
+        let message = concat!(
+            "assertion failed: `assert_all_collect!(collection, predicate)`\n",
+            assertables_url_line!("assert_all_collect"),
+            " collection label: `a.into_iter()`,\n",
+            " collection debug: `IntoIter([1, -2, 3, -4])`,\n",
+            "        predicate: `|x: i8| x > 0`,\n",
+            "    failure count: `2`,\n",
+            "         failures: `[(1, -2), (3, -4)]`"
+        );
+        assert_eq!(actual.unwrap_err().to_string(), message);
+    }
+
+    #[test]
+    fn calls_the_predicate_for_every_element() {
+        let a = [-1, -2, -3];
+        let mut calls = 0;
+        let actual = assert_all_collect_as_result!(a.into_iter(), |x: i8| {
+            calls += 1;
+            x > 0
+        });
+        assert!(actual.is_err());
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn failures_as_err_payload() {
+        let a = [1, -2, 3, -4];
+        let actual = assert_all_collect_as_result!(a.into_iter(), |x: i8| x > 0);
+        let failures = actual.unwrap_err().failures;
+        assert_eq!(
+            failures,
+            vec![(1, String::from("-2")), (3, String::from("-4"))]
+        );
+    }
+
+    #[test]
+    fn vec_of_strings_by_reference() {
+        let a: Vec<String> = vec![
+            String::from("alfa"),
+            String::from("bravo"),
+            String::from("ab"),
+        ];
+        let actual = assert_all_collect_as_result!(&a, |x: &String| x.len() > 3);
+        assert!(actual
+            .unwrap_err()
+            .to_string()
+            .ends_with(" failures: `[(2, \"ab\")]`"));
+        // The vector is borrowed, not moved, so it is usable here.
+        assert_eq!(a.len(), 3);
+    }
+
+    #[test]
+    fn one_shot_iterator_is_evaluated_once() {
+        let a = [1, -2, 3, -4];
+        let mut evaluations = 0;
+        let mut visits = 0;
+        let actual = assert_all_collect_as_result!(
+            {
+                evaluations += 1;
+                a.into_iter().inspect(|_| visits += 1)
+            },
+            |x: i8| x > 0
+        );
+        assert_eq!(
+            actual.unwrap_err().failures,
+            vec![(1, String::from("-2")), (3, String::from("-4"))]
+        );
+        assert_eq!(evaluations, 1);
+        assert_eq!(visits, 4);
+    }
+}
+
+/// Assert every element of the iterator matches a predicate, and report every element that does not.
+///
+/// Pseudocode:<br>
+/// collection into iter ∀ predicate
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// assert_all_collect!(a.into_iter(), |x: i8| x > 0);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, -2, 3, -4];
+/// assert_all_collect!(a.into_iter(), |x: i8| x > 0);
+/// # });
+/// // assertion failed: `assert_all_collect!(collection, predicate)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_all_collect.html
+/// //  collection label: `a.into_iter()`,
+/// //  collection debug: `IntoIter([1, -2, 3, -4])`,
+/// //         predicate: `|x: i8| x > 0`,
+/// //     failure count: `2`,
+/// //          failures: `[(1, -2), (3, -4)]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_all_collect!(collection, predicate)`\n",
+/// #     assertables_url_line!("assert_all_collect"),
+/// #     " collection label: `a.into_iter()`,\n",
+/// #     " collection debug: `IntoIter([1, -2, 3, -4])`,\n",
+/// #     "        predicate: `|x: i8| x > 0`,\n",
+/// #     "    failure count: `2`,\n",
+/// #     "         failures: `[(1, -2), (3, -4)]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for data validation, because the failure message shows
+/// every element that does not match the predicate, rather than only the first.
+/// The macro calls the predicate for every element, even after a failure.
+///
+/// The failure message shows the failure count, and each failure as a pair of
+/// (index, value). The macro evaluates the collection expression once, and
+/// walks it once, so the collection can be a one-shot iterator.
+///
+/// This implementation uses [`::std::iter::IntoIterator`](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_all_collect`](macro@crate::assert_all_collect)
+/// * [`assert_all_collect_as_result`](macro@crate::assert_all_collect_as_result)
+/// * [`debug_assert_all_collect`](macro@crate::debug_assert_all_collect)
+///
+#[macro_export]
+macro_rules! assert_all_collect {
+    ($collection:expr, $predicate:expr $(,)?) => {{
+        match $crate::assert_all_collect_as_result!($collection, $predicate) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($collection:expr, $predicate:expr, $($message:tt)+) => {{
+        match $crate::assert_all_collect_as_result!($collection, $predicate) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_all_collect {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let actual = assert_all_collect!(a.into_iter(), |x: i8| x > 0);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, -2, 3, -4];
+            let _actual = assert_all_collect!(a.into_iter(), |x: i8| x > 0);
+        });
+        let message = concat!(
+            "assertion failed: `assert_all_collect!(collection, predicate)`\n",
+            assertables_url_line!("assert_all_collect"),
+            " collection label: `a.into_iter()`,\n",
+            " collection debug: `IntoIter([1, -2, 3, -4])`,\n",
+            "        predicate: `|x: i8| x > 0`,\n",
+            "    failure count: `2`,\n",
+            "         failures: `[(1, -2), (3, -4)]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert every element of the iterator matches a predicate, and report every element that does not.
+///
+/// Pseudocode:<br>
+/// collection into iter ∀ predicate
+///
+/// This macro provides the same statements as [`assert_all_collect`](macro.assert_all_collect.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_all_collect`](macro@crate::assert_all_collect)
+/// * [`assert_all_collect_as_result`](macro@crate::assert_all_collect_as_result)
+/// * [`debug_assert_all_collect`](macro@crate::debug_assert_all_collect)
+///
+#[macro_export]
+macro_rules! debug_assert_all_collect {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_all_collect!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,294 @@
+//! Assert every entry of the map matches a predicate.
+//!
+//! Pseudocode:<br>
+//! map into iter ∀ (key, value) predicate
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::collections::BTreeMap;
+//!
+//! let map = BTreeMap::from([("alfa", 1), ("bravo", 2)]);
+//! assert_all_entries!(map, |_key: &&str, value: &i8| *value > 0);
+//! ```
+//!
+//! This implementation iterates the map by reference, so the map is not moved,
+//! and calls the predicate with a reference to each key and each value.
+//!
+//! * The failure message shows the first entry that does not match the predicate.
+//!
+//! * The map can be any type that implements `IntoIterator` by reference with
+//!   items `(&key, &value)`, such as `HashMap` and `BTreeMap`.
+//!
+//! # Module macros
+//!
+//! * [`assert_all_entries`](macro@crate::assert_all_entries)
+//! * [`assert_all_entries_as_result`](macro@crate::assert_all_entries_as_result)
+//! * [`debug_assert_all_entries`](macro@crate::debug_assert_all_entries)
+
+/// Assert every entry of the map matches a predicate.
+///
+/// Pseudocode:<br>
+/// map into iter ∀ (key, value) predicate
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_all_entries`](macro@crate::assert_all_entries)
+/// * [`assert_all_entries_as_result`](macro@crate::assert_all_entries_as_result)
+/// * [`debug_assert_all_entries`](macro@crate::debug_assert_all_entries)
+///
+#[macro_export]
+macro_rules! assert_all_entries_as_result {
+    ($map:expr, $predicate:expr $(,)?) => {{
+        match (&$map, &$predicate) {
+            (map, predicate) => {
+                match map.into_iter().find(|&(key, value)| !predicate(key, value)) {
+                    None => Ok(()),
+                    Some((key, value)) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_all_entries!(map, predicate)`\n",
+                                    $crate::assertables_url_line!("assert_all_entries"),
+                                    "   map label: `{}`,\n",
+                                    "   map debug: `{:?}`,\n",
+                                    "   predicate: `{}`,\n",
+                                    "   entry key: `{:?}`,\n",
+                                    " entry value: `{:?}`"
+                                ),
+                                stringify!($map),
+                                map,
+                                stringify!($predicate),
+                                key,
+                                value
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_all_entries_as_result {
+    use std::collections::BTreeMap;
+    use std::collections::HashMap;
+
+    #[test]
+    fn success() {
+        let map = BTreeMap::from([("alfa", 1), ("bravo", 2)]);
+        let actual = assert_all_entries_as_result!(map, |_key: &&str, value: &i8| *value > 0);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let map = BTreeMap::from([("alfa", 1), ("bravo", -2)]);
+        let actual = assert_all_entries_as_result!(map, |_key: &&str, value: &i8| *value > 0);
+        let message = concat!(
+            "assertion failed: `assert_all_entries!(map, predicate)`\n",
+            assertables_url_line!("assert_all_entries"),
+            "   map label: `map`,\n",
+            "   map debug: `{\"alfa\": 1, \"bravo\": -2}`,\n",
+            "   predicate: `|_key: &&str, value: &i8| *value > 0`,\n",
+            "   entry key: `\"bravo\"`,\n",
+            " entry value: `-2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_hash_map() {
+        let map = HashMap::from([("alfa", 1), ("bravo", 2)]);
+        let actual = assert_all_entries_as_result!(map, |key: &&str, value: &i8| key.len() > 3
+            && *value > 0);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_empty_map() {
+        let map: BTreeMap<&str, i8> = BTreeMap::new();
+        let actual = assert_all_entries_as_result!(map, |_key: &&str, value: &i8| *value > 0);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_reports_first_failing_entry() {
+        let map = BTreeMap::from([("alfa", -1), ("bravo", -2)]);
+        let actual = assert_all_entries_as_result!(map, |_key: &&str, value: &i8| *value > 0);
+        let message = actual.unwrap_err();
+        assert!(message.contains("   entry key: `\"alfa\"`,\n entry value: `-1`"));
+    }
+
+    #[test]
+    fn map_is_not_moved() {
+        let map = BTreeMap::from([("alfa", String::from("x"))]);
+        let _ = assert_all_entries_as_result!(map, |_key: &&str, value: &String| !value.is_empty());
+        let _ = assert_all_entries_as_result!(map, |_key: &&str, value: &String| !value.is_empty());
+        assert_eq!(map.len(), 1);
+    }
+}
+
+/// Assert every entry of the map matches a predicate.
+///
+/// Pseudocode:<br>
+/// map into iter ∀ (key, value) predicate
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::collections::BTreeMap;
+///
+/// # fn main() {
+/// let map = BTreeMap::from([("alfa", 1), ("bravo", 2)]);
+/// assert_all_entries!(map, |_key: &&str, value: &i8| *value > 0);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let map = BTreeMap::from([("alfa", 1), ("bravo", -2)]);
+/// assert_all_entries!(map, |_key: &&str, value: &i8| *value > 0);
+/// # });
+/// // assertion failed: `assert_all_entries!(map, predicate)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_all_entries.html
+/// //    map label: `map`,
+/// //    map debug: `{\"alfa\": 1, \"bravo\": -2}`,
+/// //    predicate: `|_key: &&str, value: &i8| *value > 0`,
+/// //    entry key: `\"bravo\"`,
+/// //  entry value: `-2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_all_entries!(map, predicate)`\n",
+/// #     assertables_url_line!("assert_all_entries"),
+/// #     "   map label: `map`,\n",
+/// #     "   map debug: `{\"alfa\": 1, \"bravo\": -2}`,\n",
+/// #     "   predicate: `|_key: &&str, value: &i8| *value > 0`,\n",
+/// #     "   entry key: `\"bravo\"`,\n",
+/// #     " entry value: `-2`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This implementation iterates the map by reference, so the map is not moved,
+/// and calls the predicate with a reference to each key and each value.
+///
+/// * The failure message shows the first entry that does not match the predicate.
+///
+/// * The map can be any type that implements `IntoIterator` by reference with
+///   items `(&key, &value)`, such as `HashMap` and `BTreeMap`.
+///
+/// # Module macros
+///
+/// * [`assert_all_entries`](macro@crate::assert_all_entries)
+/// * [`assert_all_entries_as_result`](macro@crate::assert_all_entries_as_result)
+/// * [`debug_assert_all_entries`](macro@crate::debug_assert_all_entries)
+///
+#[macro_export]
+macro_rules! assert_all_entries {
+    ($map:expr, $predicate:expr $(,)?) => {{
+        match $crate::assert_all_entries_as_result!($map, $predicate) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($map:expr, $predicate:expr, $($message:tt)+) => {{
+        match $crate::assert_all_entries_as_result!($map, $predicate) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_all_entries {
+    use std::collections::BTreeMap;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let map = BTreeMap::from([("alfa", 1), ("bravo", 2)]);
+        let actual = assert_all_entries!(map, |_key: &&str, value: &i8| *value > 0);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let map = BTreeMap::from([("alfa", 1), ("bravo", -2)]);
+            let _actual = assert_all_entries!(map, |_key: &&str, value: &i8| *value > 0);
+        });
+        let message = concat!(
+            "assertion failed: `assert_all_entries!(map, predicate)`\n",
+            assertables_url_line!("assert_all_entries"),
+            "   map label: `map`,\n",
+            "   map debug: `{\"alfa\": 1, \"bravo\": -2}`,\n",
+            "   predicate: `|_key: &&str, value: &i8| *value > 0`,\n",
+            "   entry key: `\"bravo\"`,\n",
+            " entry value: `-2`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert every entry of the map matches a predicate.
+///
+/// Pseudocode:<br>
+/// map into iter ∀ (key, value) predicate
+///
+/// This macro provides the same statements as [`assert_all_entries`](macro.assert_all_entries.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_all_entries`](macro@crate::assert_all_entries)
+/// * [`assert_all_entries_as_result`](macro@crate::assert_all_entries_as_result)
+/// * [`debug_assert_all_entries`](macro@crate::debug_assert_all_entries)
+///
+#[macro_export]
+macro_rules! debug_assert_all_entries {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_all_entries!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,95 @@
+//! Assert for every element of an iterator.
+//!
+//! These macros help check that every element of an iterator matches a predicate.
+//!
+//! * [`assert_all!(collection, predicate)`](macro@crate::assert_all) ≈ collection into iter ∀ predicate
+//!
+//! * [`assert_all_collect!(collection, predicate)`](macro@crate::assert_all_collect) ≈ collection into iter ∀ predicate, reporting every failure
+//!
+//! * [`assert_all_entries!(map, predicate)`](macro@crate::assert_all_entries) ≈ map into iter ∀ (key, value) predicate
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3];
+//! assert_all!(a, |x: i8| x > 0);
+//! ```
+//!
+//! The collection can be anything that implements `IntoIterator`, such as an
+//! array, an iterator, or a reference to a collection such as `&vec`. Pass a
+//! collection that is not `Copy` by reference, so the macro does not move it.
+
+#[allow(clippy::module_inception)]
+pub mod assert_all;
+pub mod assert_all_collect;
+pub mod assert_all_entries;
+
+/// Find the first element that does not match the predicate.
+///
+/// Return the index of the element, and what `keep` returns for the element,
+/// which the macros use to keep a copy to show in the failure message. The
+/// macros call this function, so they walk the collection once, and the
+/// closure's parameter type is inferred from the element type.
+#[doc(hidden)]
+pub fn first_failure<I, F, G, K>(collection: I, mut predicate: F, mut keep: G) -> Option<(usize, K)>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> bool,
+    G: FnMut(&I::Item) -> K,
+{
+    collection
+        .into_iter()
+        .enumerate()
+        .find_map(|(index, element)| {
+            let kept = keep(&element);
+            if predicate(element) {
+                None
+            } else {
+                Some((index, kept))
+            }
+        })
+}
+
+/// Find every element that does not match the predicate.
+///
+/// Return the index and the debug representation of each such element, in
+/// order. The macros call this function, so they walk the collection once.
+#[doc(hidden)]
+pub fn all_failures<I, F>(collection: I, mut predicate: F) -> Vec<(usize, String)>
+where
+    I: IntoIterator,
+    I::Item: ::std::fmt::Debug,
+    F: FnMut(I::Item) -> bool,
+{
+    collection
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, element)| {
+            let debug = format!("{:?}", element);
+            if predicate(element) {
+                None
+            } else {
+                Some((index, debug))
+            }
+        })
+        .collect()
+}
+
+/// The error from [`assert_all_collect_as_result`](macro@crate::assert_all_collect_as_result).
+///
+/// The `failures` field has each element that does not match the predicate,
+/// as a pair of (index, debug representation), so a caller can process them.
+/// The `Display` form is the assertion message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllCollectFailures {
+    pub failures: Vec<(usize, String)>,
+    pub message: String,
+}
+
+impl ::std::fmt::Display for AllCollectFailures {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
@@ -1,7 +1,7 @@
-//! Assert every element of the iterator matches a predicate.
+//! Assert any element of the iterator matches a predicate.
 //!
 //! Pseudocode:<br>
-//! collection into iter ∀ predicate
+//! collection into iter ∃ predicate
 //!
 //! # Example
 //!
@@ -9,10 +9,30 @@
 //! use assertables::*;
 //!
 //! let a = [1, 2, 3];
-//! assert_any!(a.into_iter(), |x: i8| x > 0);
+//! assert_any!(a, |x: i8| x > 0);
 //! ```
 //!
-//! This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+//! This implementation uses [`::std::iter::IntoIterator`](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html),
+//! so the collection can be an array, an iterator such as `a.iter()`, or a
+//! reference to a collection such as `&vec` or `&set`. For a reference, the
+//! predicate receives references to the elements. Pass a collection that is
+//! not `Copy` by reference, such as `&v`, so the macro does not move it.
+//!
+//! On success, the macro returns the zero-based index of the first element
+//! that matches, so you can use it for more testing:
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = vec![String::from("alfa"), String::from("bravo")];
+//! let index = assert_any!(&a, |x: &String| x.starts_with('b'));
+//! assert_eq!(a[index], "bravo");
+//! ```
+//!
+//! The macro evaluates the collection expression once, and walks it once,
+//! so the collection can be a one-shot iterator. The macro returns the index
+//! rather than the element, because the predicate takes the element, so the
+//! elements need not implement `Clone`.
 //!
 //! # Module macros
 //!
@@ -20,19 +40,32 @@
 //! * [`assert_any_as_result`](macro@crate::assert_any_as_result)
 //! * [`debug_assert_any`](macro@crate::debug_assert_any)
 
-/// Assert every element of the iterator matches a predicate.
+/// Find the index of the first element that matches the predicate.
+///
+/// The macros call this function, so they walk the collection once, and the
+/// closure's parameter type is inferred from the element type.
+#[doc(hidden)]
+pub fn first_match_index<I, F>(collection: I, predicate: F) -> Option<usize>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> bool,
+{
+    collection.into_iter().position(predicate)
+}
+
+/// Assert any element of the iterator matches a predicate.
 ///
 /// Pseudocode:<br>
-/// collection into iter ∀ predicate
+/// collection into iter ∃ predicate
 ///
-/// * If true, return Result `Ok(())`.
+/// * If true, return Result `Ok(index)` with the index of the first element that matches.
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro is useful for runtime checks, such as checking parameters,
 /// or sanitizing inputs, or handling different results in different ways.
 ///
-/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+/// This implementation uses [`::std::iter::IntoIterator`](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
 ///
 /// # Module macros
 ///
@@ -43,23 +76,23 @@
 #[macro_export]
 macro_rules! assert_any_as_result {
     ($collection:expr, $predicate:expr $(,)?) => {{
-        match (&$collection, &$predicate) {
-            (collection, _predicate) => {
-                if $collection.any($predicate) {
-                    Ok(())
-                } else {
-                    Err(format!(
+        match $collection {
+            collection => {
+                let collection_debug = format!("{:?}", collection);
+                match $crate::assert_any::first_match_index(collection, $predicate) {
+                    Some(index) => Ok(index),
+                    None => Err(format!(
                         concat!(
                             "assertion failed: `assert_any!(collection, predicate)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_any.html\n",
+                            $crate::assertables_url_line!("assert_any"),
                             " collection label: `{}`,\n",
-                            " collection debug: `{:?}`,\n",
+                            " collection debug: `{}`,\n",
                             "        predicate: `{}`",
                         ),
                         stringify!($collection),
-                        collection,
+                        collection_debug,
                         stringify!($predicate)
-                    ))
+                    )),
                 }
             }
         }
@@ -73,7 +106,7 @@ mod test_assert_any_as_result {
     fn success() {
         let a = [1, 2, 3];
         let actual = assert_any_as_result!(a.into_iter(), |x: i8| x > 0);
-        assert_eq!(actual.unwrap(), ());
+        assert_eq!(actual.unwrap(), 0);
     }
 
     #[test]
@@ -82,21 +115,80 @@ mod test_assert_any_as_result {
         let actual = assert_any_as_result!(a.into_iter(), |x: i8| x > 3);
         let message = concat!(
             "assertion failed: `assert_any!(collection, predicate)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_any.html\n",
+            assertables_url_line!("assert_any"),
             " collection label: `a.into_iter()`,\n",
             " collection debug: `IntoIter([1, 2, 3])`,\n",
             "        predicate: `|x: i8| x > 3`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn success_with_array() {
+        let a = [1, 2, 3];
+        let actual = assert_any_as_result!(a, |x: i8| x > 2);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn success_with_slice() {
+        let a: &[i32] = &[1, 2, 3];
+        let actual = assert_any_as_result!(a, |x: &i32| *x > 2);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn vec_of_strings_by_reference() {
+        let a: Vec<String> = vec![String::from("alfa"), String::from("bravo")];
+        let actual = assert_any_as_result!(&a, |x: &String| x.starts_with('b'));
+        assert_eq!(actual.unwrap(), 1);
+        let actual = assert_any_as_result!(&a, |x: &String| x.starts_with('c'));
+        let message = concat!(
+            "assertion failed: `assert_any!(collection, predicate)`\n",
+            assertables_url_line!("assert_any"),
+            " collection label: `&a`,\n",
+            " collection debug: `[\"alfa\", \"bravo\"]`,\n",
+            "        predicate: `|x: &String| x.starts_with('c')`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+        // The vector is borrowed, not moved, so it is usable here.
+        assert_eq!(a.len(), 2);
+    }
+
+    #[test]
+    fn hash_set_by_reference() {
+        use std::collections::HashSet;
+        let a: HashSet<i32> = HashSet::from([1, 2, 3]);
+        let actual = assert_any_as_result!(&a, |x: &i32| *x > 2);
+        assert_eq!(a.iter().nth(actual.unwrap()), Some(&3));
+        let actual = assert_any_as_result!(&a, |x: &i32| *x > 3);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn one_shot_iterator_is_evaluated_once() {
+        let a = [1, 2, 3];
+        let mut evaluations = 0;
+        let mut visits = 0;
+        let actual = assert_any_as_result!(
+            {
+                evaluations += 1;
+                a.into_iter().inspect(|_| visits += 1)
+            },
+            |x: i8| x > 1
+        );
+        assert_eq!(actual.unwrap(), 1);
+        assert_eq!(evaluations, 1);
+        assert_eq!(visits, 2);
+    }
 }
 
-/// Assert every element of the iterator matches a predicate.
+/// Assert any element of the iterator matches a predicate.
 ///
 /// Pseudocode:<br>
-/// collection into iter ∀ predicate
+/// collection into iter ∃ predicate
 ///
-/// * If true, return `()`.
+/// * If true, return the index of the first element that matches.
 ///
 /// * Otherwise, call [`panic!`] with a message and the values of the
 ///   expressions with their debug representations.
@@ -124,7 +216,7 @@ mod test_assert_any_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_any!(collection, predicate)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_any.html\n",
+/// #     assertables_url_line!("assert_any"),
 /// #     " collection label: `a.into_iter()`,\n",
 /// #     " collection debug: `IntoIter([1, 2, 3])`,\n",
 /// #     "        predicate: `|x: i8| x > 3`",
@@ -133,7 +225,7 @@ mod test_assert_any_as_result {
 /// # }
 /// ```
 ///
-/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+/// This implementation uses [`::std::iter::IntoIterator`](https://doc.rust-lang.org/std/iter/trait.IntoIterator.html).
 ///
 /// # Module macros
 ///
@@ -145,14 +237,14 @@ mod test_assert_any_as_result {
 macro_rules! assert_any {
     ($collection:expr, $predicate:expr $(,)?) => {{
         match $crate::assert_any_as_result!($collection, $predicate) {
-            Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($collection:expr, $predicate:expr, $($message:tt)+) => {{
         match $crate::assert_any_as_result!($collection, $predicate) {
-            Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -165,7 +257,26 @@ mod test_assert_any {
     fn success() {
         let a = [1, 2, 3];
         let actual = assert_any!(a.into_iter(), |x: i8| x > 0);
-        assert_eq!(actual, ());
+        assert_eq!(actual, 0);
+    }
+
+    #[test]
+    fn success_returns_index_of_first_matching_element() {
+        let a: Vec<String> = vec![
+            String::from("alfa"),
+            String::from("bravo"),
+            String::from("bingo"),
+        ];
+        let index: usize = assert_any!(&a, |x: &String| x.starts_with('b'));
+        assert_eq!(a[index], "bravo");
+    }
+
+    #[test]
+    fn element_type_without_clone() {
+        struct S(i8);
+        let a = [1, 2, 3];
+        let index = assert_any!(a.iter().map(|x| S(*x)), |s: S| s.0 > 1);
+        assert_eq!(index, 1);
     }
 
     #[test]
@@ -176,7 +287,7 @@ mod test_assert_any {
         });
         let message = concat!(
             "assertion failed: `assert_any!(collection, predicate)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_any.html\n",
+            assertables_url_line!("assert_any"),
             " collection label: `a.into_iter()`,\n",
             " collection debug: `IntoIter([1, 2, 3])`,\n",
             "        predicate: `|x: i8| x > 3`"
@@ -192,10 +303,10 @@ mod test_assert_any {
     }
 }
 
-/// Assert every element of the iterator matches a predicate.
+/// Assert any element of the iterator matches a predicate.
 ///
 /// Pseudocode:<br>
-/// collection into iter ∀ predicate
+/// collection into iter ∃ predicate
 ///
 /// This macro provides the same statements as [`assert_any`](macro.assert_any.html),
 /// except this macro's statements are only enabled in non-optimized
@@ -226,7 +337,7 @@ mod test_assert_any {
 #[macro_export]
 macro_rules! debug_assert_any {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_any!($($arg)*);
         }
     };
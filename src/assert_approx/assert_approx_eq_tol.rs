@@ -0,0 +1,371 @@
+//! Assert a number is approximately equal to another, within a tolerance.
+//!
+//! Pseudocode:<br>
+//! | a - b | ≤ tol
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a: f32 = 1.0;
+//! let b: f32 = 1.25;
+//! let tol: f32 = 0.5;
+//! assert_approx_eq_tol!(a, b, tol);
+//! ```
+//!
+//! This macro is like [`assert_approx_eq`](macro@crate::assert_approx_eq),
+//! except the caller specifies the tolerance, rather than using the default 1e-6.
+//! The numbers and the tolerance can be `f32` or `f64`, and must be the same type.
+//!
+//! If either number is NaN, then the assertion fails, and the message
+//! says the numbers are not comparable.
+//!
+//! Infinity is approximately equal to the same infinity, and is not
+//! approximately equal to any finite number nor to the opposite infinity.
+//!
+//! # Module macros
+//!
+//! * [`assert_approx_eq_tol`](macro@crate::assert_approx_eq_tol)
+//! * [`assert_approx_eq_tol_as_result`](macro@crate::assert_approx_eq_tol_as_result)
+//! * [`debug_assert_approx_eq_tol`](macro@crate::debug_assert_approx_eq_tol)
+
+/// Assert a number is approximately equal to another, within a tolerance.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ tol
+///
+/// * If true, return Result `Ok((abs_diff, tol))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_approx_eq_tol`](macro@crate::assert_approx_eq_tol)
+/// * [`assert_approx_eq_tol_as_result`](macro@crate::assert_approx_eq_tol_as_result)
+/// * [`debug_assert_approx_eq_tol`](macro@crate::debug_assert_approx_eq_tol)
+///
+#[macro_export]
+macro_rules! assert_approx_eq_tol_as_result {
+    // Internal rule: return `Some(abs_diff)` of references to two numbers,
+    // or `None` if the numbers are not comparable, i.e. either number is NaN.
+    // Equal infinities have `abs_diff` zero, rather than infinity minus infinity.
+    // The approximation macros delegate to this rule, then format their own messages.
+    (@impl $a:expr, $b:expr) => {{
+        match ($a, $b) {
+            (a, b) => match ::core::cmp::PartialOrd::partial_cmp(a, b) {
+                None => None,
+                Some(::core::cmp::Ordering::Equal) => Some(0.0),
+                Some(::core::cmp::Ordering::Greater) => Some(a - b),
+                Some(::core::cmp::Ordering::Less) => Some(b - a),
+            },
+        }
+    }};
+    ($a:expr, $b:expr, $tol:expr $(,)?) => {{
+        match (&$a, &$b, &$tol) {
+            (a, b, tol) => {
+                match $crate::assert_approx_eq_tol_as_result!(@impl a, b) {
+                    Some(abs_diff) if abs_diff <= *tol => Ok((abs_diff, *tol)),
+                    Some(abs_diff) => Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_approx_eq_tol!(a, b, tol)`\n",
+                                $crate::assertables_url_line!("assert_approx_eq_tol"),
+                                "         a label: `{}`,\n",
+                                "         a debug: `{:?}`,\n",
+                                "         b label: `{}`,\n",
+                                "         b debug: `{:?}`,\n",
+                                "       tol label: `{}`,\n",
+                                "       tol debug: `{:?}`,\n",
+                                "       | a - b |: `{:?}`,\n",
+                                " | a - b | ≤ tol: false"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($tol),
+                            tol,
+                            abs_diff
+                        )
+                    ),
+                    None => Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_approx_eq_tol!(a, b, tol)`\n",
+                                $crate::assertables_url_line!("assert_approx_eq_tol"),
+                                "         a label: `{}`,\n",
+                                "         a debug: `{:?}`,\n",
+                                "         b label: `{}`,\n",
+                                "         b debug: `{:?}`,\n",
+                                "       tol label: `{}`,\n",
+                                "       tol debug: `{:?}`,\n",
+                                "          reason: a or b is NaN, so the numbers are not comparable"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($tol),
+                            tol
+                        )
+                    ),
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_approx_eq_tol_as_result {
+    #[test]
+    fn success() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.25;
+        let tol: f32 = 0.5;
+        let actual = assert_approx_eq_tol_as_result!(a, b, tol);
+        assert_eq!(actual.unwrap(), (0.25, 0.5));
+    }
+
+    #[test]
+    fn failure() {
+        let a: f32 = 1.0;
+        let b: f32 = 2.0;
+        let tol: f32 = 0.5;
+        let actual = assert_approx_eq_tol_as_result!(a, b, tol);
+        let message = concat!(
+            "assertion failed: `assert_approx_eq_tol!(a, b, tol)`\n",
+            assertables_url_line!("assert_approx_eq_tol"),
+            "         a label: `a`,\n",
+            "         a debug: `1.0`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `2.0`,\n",
+            "       tol label: `tol`,\n",
+            "       tol debug: `0.5`,\n",
+            "       | a - b |: `1.0`,\n",
+            " | a - b | ≤ tol: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_f64() {
+        let a: f64 = 1.0;
+        let b: f64 = 1.0000000001;
+        let actual = assert_approx_eq_tol_as_result!(a, b, 1e-9);
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn failure_f64() {
+        let a: f64 = 1.0;
+        let b: f64 = 1.00000001;
+        let actual = assert_approx_eq_tol_as_result!(a, b, 1e-9);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let a: f32 = f32::NAN;
+        let b: f32 = 1.0;
+        let tol: f32 = 0.5;
+        let actual = assert_approx_eq_tol_as_result!(a, b, tol);
+        let message = concat!(
+            "assertion failed: `assert_approx_eq_tol!(a, b, tol)`\n",
+            assertables_url_line!("assert_approx_eq_tol"),
+            "         a label: `a`,\n",
+            "         a debug: `NaN`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `1.0`,\n",
+            "       tol label: `tol`,\n",
+            "       tol debug: `0.5`,\n",
+            "          reason: a or b is NaN, so the numbers are not comparable"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn infinity() {
+        let actual = assert_approx_eq_tol_as_result!(f64::INFINITY, f64::INFINITY, 0.5);
+        assert_eq!(actual.unwrap(), (0.0, 0.5));
+        let actual = assert_approx_eq_tol_as_result!(f64::INFINITY, 1.0, 0.5);
+        assert!(actual.is_err());
+        let actual = assert_approx_eq_tol_as_result!(f64::INFINITY, f64::NEG_INFINITY, 0.5);
+        assert!(actual.is_err());
+    }
+}
+
+/// Assert a number is approximately equal to another, within a tolerance.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ tol
+///
+/// * If true, return `(abs_diff, tol)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: f32 = 1.0;
+/// let b: f32 = 1.25;
+/// let tol: f32 = 0.5;
+/// assert_approx_eq_tol!(a, b, tol);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: f32 = 1.0;
+/// let b: f32 = 2.0;
+/// let tol: f32 = 0.5;
+/// assert_approx_eq_tol!(a, b, tol);
+/// # });
+/// // assertion failed: `assert_approx_eq_tol!(a, b, tol)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_approx_eq_tol.html
+/// //          a label: `a`,
+/// //          a debug: `1.0`,
+/// //          b label: `b`,
+/// //          b debug: `2.0`,
+/// //        tol label: `tol`,
+/// //        tol debug: `0.5`,
+/// //        | a - b |: `1.0`,
+/// //  | a - b | ≤ tol: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_approx_eq_tol!(a, b, tol)`\n",
+/// #     assertables_url_line!("assert_approx_eq_tol"),
+/// #     "         a label: `a`,\n",
+/// #     "         a debug: `1.0`,\n",
+/// #     "         b label: `b`,\n",
+/// #     "         b debug: `2.0`,\n",
+/// #     "       tol label: `tol`,\n",
+/// #     "       tol debug: `0.5`,\n",
+/// #     "       | a - b |: `1.0`,\n",
+/// #     " | a - b | ≤ tol: false"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is like [`assert_approx_eq`](macro@crate::assert_approx_eq),
+/// except the caller specifies the tolerance, rather than using the default 1e-6.
+/// The numbers and the tolerance can be `f32` or `f64`, and must be the same type.
+///
+/// If either number is NaN, then the assertion fails, and the message
+/// says the numbers are not comparable.
+///
+/// Infinity is approximately equal to the same infinity, and is not
+/// approximately equal to any finite number nor to the opposite infinity.
+///
+/// # Module macros
+///
+/// * [`assert_approx_eq_tol`](macro@crate::assert_approx_eq_tol)
+/// * [`assert_approx_eq_tol_as_result`](macro@crate::assert_approx_eq_tol_as_result)
+/// * [`debug_assert_approx_eq_tol`](macro@crate::debug_assert_approx_eq_tol)
+///
+#[macro_export]
+macro_rules! assert_approx_eq_tol {
+    ($a:expr, $b:expr, $tol:expr $(,)?) => {{
+        match $crate::assert_approx_eq_tol_as_result!($a, $b, $tol) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $tol:expr, $($message:tt)+) => {{
+        match $crate::assert_approx_eq_tol_as_result!($a, $b, $tol) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_approx_eq_tol {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.25;
+        let tol: f32 = 0.5;
+        let actual = assert_approx_eq_tol!(a, b, tol);
+        assert_eq!(actual, (0.25, 0.5));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a: f32 = 1.0;
+            let b: f32 = 2.0;
+            let tol: f32 = 0.5;
+            let _actual = assert_approx_eq_tol!(a, b, tol);
+        });
+        let message = concat!(
+            "assertion failed: `assert_approx_eq_tol!(a, b, tol)`\n",
+            assertables_url_line!("assert_approx_eq_tol"),
+            "         a label: `a`,\n",
+            "         a debug: `1.0`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `2.0`,\n",
+            "       tol label: `tol`,\n",
+            "       tol debug: `0.5`,\n",
+            "       | a - b |: `1.0`,\n",
+            " | a - b | ≤ tol: false"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a number is approximately equal to another, within a tolerance.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ tol
+///
+/// This macro provides the same statements as [`assert_approx_eq_tol`](macro.assert_approx_eq_tol.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_approx_eq_tol`](macro@crate::assert_approx_eq_tol)
+/// * [`assert_approx_eq_tol_as_result`](macro@crate::assert_approx_eq_tol_as_result)
+/// * [`debug_assert_approx_eq_tol`](macro@crate::debug_assert_approx_eq_tol)
+///
+#[macro_export]
+macro_rules! debug_assert_approx_eq_tol {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_approx_eq_tol!($($arg)*);
+        }
+    };
+}
@@ -13,6 +13,12 @@
 //! assert_approx_ne!(a, b);
 //! ```
 //!
+//! If either number is NaN, then the assertion fails, and the message
+//! says the numbers are not comparable.
+//!
+//! Infinity is approximately equal to the same infinity, and is not
+//! approximately equal to any finite number nor to the opposite infinity.
+//!
 //!
 //! ## Comparisons
 //!
@@ -64,6 +70,10 @@
 /// Pseudocode:<br>
 /// | a - b | > 1e-6
 ///
+/// This macro delegates to [`assert_approx_ne_tol`](macro@crate::assert_approx_ne_tol)
+/// with the default tolerance 1e-6, which is the same as for
+/// [`assert_approx_eq`](macro@crate::assert_approx_eq).
+///
 /// * If true, return Result `Ok(abs_diff, approx)`.
 ///
 /// * When false, return [`Err`] with a message and the values of the
@@ -86,16 +96,14 @@ macro_rules! assert_approx_ne_as_result {
     ($a:expr, $b:expr $(,)?) => {{
         match (&$a, &$b) {
             (a, b) => {
-                let approx = 1e-6;
-                let abs_diff = if (a >= b) { a - b } else { b - a };
-                if abs_diff > approx {
-                    Ok((abs_diff, approx))
-                } else {
-                    Err(
-                        format!(
+                let approx = 1.0e-6;
+                match $crate::assert_approx_eq_tol_as_result!(@impl a, b) {
+                    Some(abs_diff) if abs_diff > approx => Ok((abs_diff, approx)),
+                    Some(abs_diff) => Err(
+                        $crate::__private::format!(
                             concat!(
                                 "assertion failed: `assert_approx_ne!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_approx_ne.html\n",
+                                $crate::assertables_url_line!("assert_approx_ne"),
                                 "            a label: `{}`,\n",
                                 "            a debug: `{:?}`,\n",
                                 "            b label: `{}`,\n",
@@ -111,7 +119,24 @@ macro_rules! assert_approx_ne_as_result {
                             abs_diff,
                             approx
                         )
-                    )
+                    ),
+                    None => Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_approx_ne!(a, b)`\n",
+                                $crate::assertables_url_line!("assert_approx_ne"),
+                                "            a label: `{}`,\n",
+                                "            a debug: `{:?}`,\n",
+                                "            b label: `{}`,\n",
+                                "            b debug: `{:?}`,\n",
+                                "             reason: a or b is NaN, so the numbers are not comparable"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b
+                        )
+                    ),
                 }
             }
         }
@@ -136,7 +161,7 @@ mod test_assert_approx_ne_as_result {
         let actual = assert_approx_ne_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_approx_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_approx_ne.html\n",
+            assertables_url_line!("assert_approx_ne"),
             "            a label: `a`,\n",
             "            a debug: `1.0000001`,\n",
             "            b label: `b`,\n",
@@ -147,6 +172,51 @@ mod test_assert_approx_ne_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn nan() {
+        let a: f32 = f32::NAN;
+        let b: f32 = 1.0;
+        let actual = assert_approx_ne_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_approx_ne!(a, b)`\n",
+            assertables_url_line!("assert_approx_ne"),
+            "            a label: `a`,\n",
+            "            a debug: `NaN`,\n",
+            "            b label: `b`,\n",
+            "            b debug: `1.0`,\n",
+            "             reason: a or b is NaN, so the numbers are not comparable"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn nan_and_nan() {
+        let actual = assert_approx_ne_as_result!(f64::NAN, f64::NAN);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn infinity_and_infinity() {
+        let actual = assert_approx_ne_as_result!(f32::INFINITY, f32::INFINITY);
+        assert!(actual.is_err());
+        let actual = assert_approx_ne_as_result!(f32::NEG_INFINITY, f32::NEG_INFINITY);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn infinity_and_finite() {
+        let actual = assert_approx_ne_as_result!(f32::INFINITY, 1.0);
+        assert!(actual.is_ok());
+        let actual = assert_approx_ne_as_result!(f32::NEG_INFINITY, 1.0);
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn infinity_and_negative_infinity() {
+        let actual = assert_approx_ne_as_result!(f32::INFINITY, f32::NEG_INFINITY);
+        assert!(actual.is_ok());
+    }
 }
 
 /// Assert a number is approximately not equal to another.
@@ -188,7 +258,7 @@ mod test_assert_approx_ne_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_approx_ne!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_approx_ne.html\n",
+/// #     assertables_url_line!("assert_approx_ne"),
 /// #     "            a label: `a`,\n",
 /// #     "            a debug: `1.0000001`,\n",
 /// #     "            b label: `b`,\n",
@@ -230,13 +300,13 @@ macro_rules! assert_approx_ne {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_approx_ne_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_approx_ne_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -262,7 +332,7 @@ mod test_assert_approx_ne {
         });
         let message = concat!(
             "assertion failed: `assert_approx_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_approx_ne.html\n",
+            assertables_url_line!("assert_approx_ne"),
             "            a label: `a`,\n",
             "            a debug: `1.0000001`,\n",
             "            b label: `b`,\n",
@@ -316,7 +386,7 @@ mod test_assert_approx_ne {
 #[macro_export]
 macro_rules! debug_assert_approx_ne {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_approx_ne!($($arg)*);
         }
     };
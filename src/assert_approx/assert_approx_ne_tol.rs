@@ -0,0 +1,388 @@
+//! Assert a number is approximately not equal to another, beyond a tolerance.
+//!
+//! Pseudocode:<br>
+//! | a - b | > tol
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a: f32 = 1.0;
+//! let b: f32 = 2.0;
+//! let tol: f32 = 0.5;
+//! assert_approx_ne_tol!(a, b, tol);
+//! ```
+//!
+//! This macro is like [`assert_approx_ne`](macro@crate::assert_approx_ne),
+//! except the caller specifies the tolerance, rather than using the default 1e-6.
+//! The numbers and the tolerance can be `f32` or `f64`, and must be the same type.
+//!
+//! This macro is the opposite of [`assert_approx_eq_tol`](macro@crate::assert_approx_eq_tol),
+//! and uses the same comparison, so for any numbers that are comparable,
+//! exactly one of the two macros succeeds.
+//!
+//! If either number is NaN, then the assertion fails, and the message
+//! says the numbers are not comparable.
+//!
+//! Infinity is approximately equal to the same infinity, so the assertion
+//! fails for two equal infinities, and succeeds for infinity and any finite
+//! number or the opposite infinity.
+//!
+//! # Module macros
+//!
+//! * [`assert_approx_ne_tol`](macro@crate::assert_approx_ne_tol)
+//! * [`assert_approx_ne_tol_as_result`](macro@crate::assert_approx_ne_tol_as_result)
+//! * [`debug_assert_approx_ne_tol`](macro@crate::debug_assert_approx_ne_tol)
+
+/// Assert a number is approximately not equal to another, beyond a tolerance.
+///
+/// Pseudocode:<br>
+/// | a - b | > tol
+///
+/// * If true, return Result `Ok((abs_diff, tol))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_approx_ne_tol`](macro@crate::assert_approx_ne_tol)
+/// * [`assert_approx_ne_tol_as_result`](macro@crate::assert_approx_ne_tol_as_result)
+/// * [`debug_assert_approx_ne_tol`](macro@crate::debug_assert_approx_ne_tol)
+///
+#[macro_export]
+macro_rules! assert_approx_ne_tol_as_result {
+    ($a:expr, $b:expr, $tol:expr $(,)?) => {{
+        match (&$a, &$b, &$tol) {
+            (a, b, tol) => {
+                match $crate::assert_approx_eq_tol_as_result!(@impl a, b) {
+                    Some(abs_diff) if abs_diff > *tol => Ok((abs_diff, *tol)),
+                    Some(abs_diff) => Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_approx_ne_tol!(a, b, tol)`\n",
+                                $crate::assertables_url_line!("assert_approx_ne_tol"),
+                                "         a label: `{}`,\n",
+                                "         a debug: `{:?}`,\n",
+                                "         b label: `{}`,\n",
+                                "         b debug: `{:?}`,\n",
+                                "       tol label: `{}`,\n",
+                                "       tol debug: `{:?}`,\n",
+                                "       | a - b |: `{:?}`,\n",
+                                " | a - b | > tol: false"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($tol),
+                            tol,
+                            abs_diff
+                        )
+                    ),
+                    None => Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_approx_ne_tol!(a, b, tol)`\n",
+                                $crate::assertables_url_line!("assert_approx_ne_tol"),
+                                "         a label: `{}`,\n",
+                                "         a debug: `{:?}`,\n",
+                                "         b label: `{}`,\n",
+                                "         b debug: `{:?}`,\n",
+                                "       tol label: `{}`,\n",
+                                "       tol debug: `{:?}`,\n",
+                                "          reason: a or b is NaN, so the numbers are not comparable"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($tol),
+                            tol
+                        )
+                    ),
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_approx_ne_tol_as_result {
+    #[test]
+    fn success() {
+        let a: f32 = 1.0;
+        let b: f32 = 2.0;
+        let tol: f32 = 0.5;
+        let actual = assert_approx_ne_tol_as_result!(a, b, tol);
+        assert_eq!(actual.unwrap(), (1.0, 0.5));
+    }
+
+    #[test]
+    fn failure() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.25;
+        let tol: f32 = 0.5;
+        let actual = assert_approx_ne_tol_as_result!(a, b, tol);
+        let message = concat!(
+            "assertion failed: `assert_approx_ne_tol!(a, b, tol)`\n",
+            assertables_url_line!("assert_approx_ne_tol"),
+            "         a label: `a`,\n",
+            "         a debug: `1.0`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `1.25`,\n",
+            "       tol label: `tol`,\n",
+            "       tol debug: `0.5`,\n",
+            "       | a - b |: `0.25`,\n",
+            " | a - b | > tol: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_f64() {
+        let a: f64 = 1.0;
+        let b: f64 = 1.00000001;
+        let actual = assert_approx_ne_tol_as_result!(a, b, 1e-9);
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn failure_f64() {
+        let a: f64 = 1.0;
+        let b: f64 = 1.0000000001;
+        let actual = assert_approx_ne_tol_as_result!(a, b, 1e-9);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let a: f32 = f32::NAN;
+        let b: f32 = 1.0;
+        let tol: f32 = 0.5;
+        let actual = assert_approx_ne_tol_as_result!(a, b, tol);
+        let message = concat!(
+            "assertion failed: `assert_approx_ne_tol!(a, b, tol)`\n",
+            assertables_url_line!("assert_approx_ne_tol"),
+            "         a label: `a`,\n",
+            "         a debug: `NaN`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `1.0`,\n",
+            "       tol label: `tol`,\n",
+            "       tol debug: `0.5`,\n",
+            "          reason: a or b is NaN, so the numbers are not comparable"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn infinity() {
+        let actual = assert_approx_ne_tol_as_result!(f64::INFINITY, f64::INFINITY, 0.5);
+        assert!(actual.is_err());
+        let actual = assert_approx_ne_tol_as_result!(f64::INFINITY, 1.0, 0.5);
+        assert!(actual.is_ok());
+        let actual = assert_approx_ne_tol_as_result!(f64::INFINITY, f64::NEG_INFINITY, 0.5);
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn tiny_difference_passes_only_above_tolerance() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.001;
+        assert!(assert_approx_ne_tol_as_result!(a, b, 0.0001).is_ok());
+        assert!(assert_approx_ne_tol_as_result!(a, b, 0.01).is_err());
+        let a: f64 = 1.0;
+        let b: f64 = 1.000001;
+        assert!(assert_approx_ne_tol_as_result!(a, b, 1e-7).is_ok());
+        assert!(assert_approx_ne_tol_as_result!(a, b, 1e-5).is_err());
+    }
+
+    #[test]
+    fn opposite_of_eq_tol() {
+        for (a, b, tol) in [(1.0, 1.25, 0.5), (1.0, 2.0, 0.5), (1.0, 1.5, 0.5)] {
+            let eq = crate::assert_approx_eq_tol_as_result!(a, b, tol).is_ok();
+            let ne = assert_approx_ne_tol_as_result!(a, b, tol).is_ok();
+            assert_ne!(eq, ne);
+        }
+    }
+}
+
+/// Assert a number is approximately not equal to another, beyond a tolerance.
+///
+/// Pseudocode:<br>
+/// | a - b | > tol
+///
+/// * If true, return `(abs_diff, tol)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: f32 = 1.0;
+/// let b: f32 = 2.0;
+/// let tol: f32 = 0.5;
+/// assert_approx_ne_tol!(a, b, tol);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: f32 = 1.0;
+/// let b: f32 = 1.25;
+/// let tol: f32 = 0.5;
+/// assert_approx_ne_tol!(a, b, tol);
+/// # });
+/// // assertion failed: `assert_approx_ne_tol!(a, b, tol)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_approx_ne_tol.html
+/// //          a label: `a`,
+/// //          a debug: `1.0`,
+/// //          b label: `b`,
+/// //          b debug: `1.25`,
+/// //        tol label: `tol`,
+/// //        tol debug: `0.5`,
+/// //        | a - b |: `0.25`,
+/// //  | a - b | > tol: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_approx_ne_tol!(a, b, tol)`\n",
+/// #     assertables_url_line!("assert_approx_ne_tol"),
+/// #     "         a label: `a`,\n",
+/// #     "         a debug: `1.0`,\n",
+/// #     "         b label: `b`,\n",
+/// #     "         b debug: `1.25`,\n",
+/// #     "       tol label: `tol`,\n",
+/// #     "       tol debug: `0.5`,\n",
+/// #     "       | a - b |: `0.25`,\n",
+/// #     " | a - b | > tol: false"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is like [`assert_approx_ne`](macro@crate::assert_approx_ne),
+/// except the caller specifies the tolerance, rather than using the default 1e-6.
+/// The numbers and the tolerance can be `f32` or `f64`, and must be the same type.
+///
+/// This macro is the opposite of [`assert_approx_eq_tol`](macro@crate::assert_approx_eq_tol),
+/// and uses the same comparison, so for any numbers that are comparable,
+/// exactly one of the two macros succeeds.
+///
+/// If either number is NaN, then the assertion fails, and the message
+/// says the numbers are not comparable.
+///
+/// Infinity is approximately equal to the same infinity, so the assertion
+/// fails for two equal infinities, and succeeds for infinity and any finite
+/// number or the opposite infinity.
+///
+/// # Module macros
+///
+/// * [`assert_approx_ne_tol`](macro@crate::assert_approx_ne_tol)
+/// * [`assert_approx_ne_tol_as_result`](macro@crate::assert_approx_ne_tol_as_result)
+/// * [`debug_assert_approx_ne_tol`](macro@crate::debug_assert_approx_ne_tol)
+///
+#[macro_export]
+macro_rules! assert_approx_ne_tol {
+    ($a:expr, $b:expr, $tol:expr $(,)?) => {{
+        match $crate::assert_approx_ne_tol_as_result!($a, $b, $tol) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $tol:expr, $($message:tt)+) => {{
+        match $crate::assert_approx_ne_tol_as_result!($a, $b, $tol) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_approx_ne_tol {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a: f32 = 1.0;
+        let b: f32 = 2.0;
+        let tol: f32 = 0.5;
+        let actual = assert_approx_ne_tol!(a, b, tol);
+        assert_eq!(actual, (1.0, 0.5));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a: f32 = 1.0;
+            let b: f32 = 1.25;
+            let tol: f32 = 0.5;
+            let _actual = assert_approx_ne_tol!(a, b, tol);
+        });
+        let message = concat!(
+            "assertion failed: `assert_approx_ne_tol!(a, b, tol)`\n",
+            assertables_url_line!("assert_approx_ne_tol"),
+            "         a label: `a`,\n",
+            "         a debug: `1.0`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `1.25`,\n",
+            "       tol label: `tol`,\n",
+            "       tol debug: `0.5`,\n",
+            "       | a - b |: `0.25`,\n",
+            " | a - b | > tol: false"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a number is approximately not equal to another, beyond a tolerance.
+///
+/// Pseudocode:<br>
+/// | a - b | > tol
+///
+/// This macro provides the same statements as [`assert_approx_ne_tol`](macro.assert_approx_ne_tol.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_approx_ne_tol`](macro@crate::assert_approx_ne_tol)
+/// * [`assert_approx_ne_tol_as_result`](macro@crate::assert_approx_ne_tol_as_result)
+/// * [`debug_assert_approx_ne_tol`](macro@crate::debug_assert_approx_ne_tol)
+///
+#[macro_export]
+macro_rules! debug_assert_approx_ne_tol {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_approx_ne_tol!($($arg)*);
+        }
+    };
+}
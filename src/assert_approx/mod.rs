@@ -5,8 +5,12 @@
 //!
 //! * [`assert_approx_eq!(a, b)`](macro@crate::assert_approx_eq) ≈ a is approximately equal to b
 //!
+//! * [`assert_approx_eq_tol!(a, b, tol)`](macro@crate::assert_approx_eq_tol) ≈ | a - b | ≤ tol
+//!
 //! * [`assert_approx_ne!(a, b)`](macro@crate::assert_approx_ne) ≈ a is approximately not equal to b
 //!
+//! * [`assert_approx_ne_tol!(a, b, tol)`](macro@crate::assert_approx_ne_tol) ≈ | a - b | > tol
+//!
 //! # Example
 //!
 //! ```rust
@@ -18,4 +22,6 @@
 //! ```
 
 pub mod assert_approx_eq;
+pub mod assert_approx_eq_tol;
 pub mod assert_approx_ne;
+pub mod assert_approx_ne_tol;
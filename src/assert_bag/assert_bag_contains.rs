@@ -0,0 +1,263 @@
+//! Assert a bag contains an element at least once.
+//!
+//! Pseudocode:<br>
+//! (collection ⇒ bag).count(element) ≥ 1
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 1, 2];
+//! assert_bag_contains!(&a, 1);
+//! ```
+//!
+//! An element that is not in the bag has count 0.
+//!
+//! This implementation uses [`::std::collections::BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html) to count items and sort them.
+//!
+//! # Module macros
+//!
+//! * [`assert_bag_contains`](macro@crate::assert_bag_contains)
+//! * [`assert_bag_contains_as_result`](macro@crate::assert_bag_contains_as_result)
+//! * [`debug_assert_bag_contains`](macro@crate::debug_assert_bag_contains)
+
+/// Assert a bag contains an element at least once.
+///
+/// Pseudocode:<br>
+/// (collection ⇒ bag).count(element) ≥ 1
+///
+/// * If true, return Result `Ok(count)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_bag_contains`](macro@crate::assert_bag_contains)
+/// * [`assert_bag_contains_as_result`](macro@crate::assert_bag_contains_as_result)
+/// * [`debug_assert_bag_contains`](macro@crate::debug_assert_bag_contains)
+///
+#[macro_export]
+macro_rules! assert_bag_contains_as_result {
+    ($collection:expr, $element:expr $(,)?) => {{
+        match (&$collection, &$element) {
+            (collection, element) => {
+                let bag = assert_bag_impl_prep!(collection);
+                let count: usize = bag.get(element).copied().unwrap_or(0);
+                if count >= 1 {
+                    Ok(count)
+                } else {
+                    Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_bag_contains!(collection, element)`\n",
+                                $crate::assertables_url_line!("assert_bag_contains"),
+                                " collection label: `{}`,\n",
+                                " collection debug: `{:?}`,\n",
+                                "    element label: `{}`,\n",
+                                "    element debug: `{:?}`,\n",
+                                "              bag: `{:?}`,\n",
+                                "            count: `{:?}`"
+                            ),
+                            stringify!($collection),
+                            collection,
+                            stringify!($element),
+                            element,
+                            bag,
+                            count
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_bag_contains_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 1, 2];
+        let actual = assert_bag_contains_as_result!(&a, 1);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 1, 2];
+        let actual = assert_bag_contains_as_result!(&a, 3);
+        let message = concat!(
+            "assertion failed: `assert_bag_contains!(collection, element)`\n",
+            assertables_url_line!("assert_bag_contains"),
+            " collection label: `&a`,\n",
+            " collection debug: `[1, 1, 2]`,\n",
+            "    element label: `3`,\n",
+            "    element debug: `3`,\n",
+            "              bag: `{1: 2, 2: 1}`,\n",
+            "            count: `0`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_strings() {
+        let a = ["alfa", "bravo", "alfa"];
+        let actual = assert_bag_contains_as_result!(&a, "alfa");
+        assert_eq!(actual.unwrap(), 2);
+    }
+}
+
+/// Assert a bag contains an element at least once.
+///
+/// Pseudocode:<br>
+/// (collection ⇒ bag).count(element) ≥ 1
+///
+/// * If true, return `count`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 1, 2];
+/// assert_bag_contains!(&a, 1);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 1, 2];
+/// assert_bag_contains!(&a, 3);
+/// # });
+/// // assertion failed: `assert_bag_contains!(collection, element)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_contains.html
+/// //  collection label: `&a`,
+/// //  collection debug: `[1, 1, 2]`,
+/// //     element label: `3`,
+/// //     element debug: `3`,
+/// //               bag: `{1: 2, 2: 1}`,
+/// //             count: `0`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_bag_contains!(collection, element)`\n",
+/// #     assertables_url_line!("assert_bag_contains"),
+/// #     " collection label: `&a`,\n",
+/// #     " collection debug: `[1, 1, 2]`,\n",
+/// #     "    element label: `3`,\n",
+/// #     "    element debug: `3`,\n",
+/// #     "              bag: `{1: 2, 2: 1}`,\n",
+/// #     "            count: `0`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// An element that is not in the bag has count 0.
+///
+/// This implementation uses [`::std::collections::BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html) to count items and sort them.
+///
+/// # Module macros
+///
+/// * [`assert_bag_contains`](macro@crate::assert_bag_contains)
+/// * [`assert_bag_contains_as_result`](macro@crate::assert_bag_contains_as_result)
+/// * [`debug_assert_bag_contains`](macro@crate::debug_assert_bag_contains)
+///
+#[macro_export]
+macro_rules! assert_bag_contains {
+    ($collection:expr, $element:expr $(,)?) => {{
+        match $crate::assert_bag_contains_as_result!($collection, $element) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($collection:expr, $element:expr, $($message:tt)+) => {{
+        match $crate::assert_bag_contains_as_result!($collection, $element) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_bag_contains {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 1, 2];
+        let actual = assert_bag_contains!(&a, 1);
+        assert_eq!(actual, 2);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 1, 2];
+            let _actual = assert_bag_contains!(&a, 3);
+        });
+        let message = concat!(
+            "assertion failed: `assert_bag_contains!(collection, element)`\n",
+            assertables_url_line!("assert_bag_contains"),
+            " collection label: `&a`,\n",
+            " collection debug: `[1, 1, 2]`,\n",
+            "    element label: `3`,\n",
+            "    element debug: `3`,\n",
+            "              bag: `{1: 2, 2: 1}`,\n",
+            "            count: `0`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a bag contains an element at least once.
+///
+/// Pseudocode:<br>
+/// (collection ⇒ bag).count(element) ≥ 1
+///
+/// This macro provides the same statements as [`assert_bag_contains`](macro.assert_bag_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_bag_contains`](macro@crate::assert_bag_contains)
+/// * [`assert_bag_contains_as_result`](macro@crate::assert_bag_contains_as_result)
+/// * [`debug_assert_bag_contains`](macro@crate::debug_assert_bag_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_bag_contains {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_bag_contains!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,275 @@
+//! Assert a bag contains an element a number of times.
+//!
+//! Pseudocode:<br>
+//! (collection ⇒ bag).count(element) = n
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 1, 2];
+//! assert_bag_count_eq_x!(&a, 1, 2);
+//! ```
+//!
+//! An element that is not in the bag has count 0.
+//!
+//! This implementation uses [`::std::collections::BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html) to count items and sort them.
+//!
+//! # Module macros
+//!
+//! * [`assert_bag_count_eq_x`](macro@crate::assert_bag_count_eq_x)
+//! * [`assert_bag_count_eq_x_as_result`](macro@crate::assert_bag_count_eq_x_as_result)
+//! * [`debug_assert_bag_count_eq_x`](macro@crate::debug_assert_bag_count_eq_x)
+
+/// Assert a bag contains an element a number of times.
+///
+/// Pseudocode:<br>
+/// (collection ⇒ bag).count(element) = n
+///
+/// * If true, return Result `Ok((count, n))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_bag_count_eq_x`](macro@crate::assert_bag_count_eq_x)
+/// * [`assert_bag_count_eq_x_as_result`](macro@crate::assert_bag_count_eq_x_as_result)
+/// * [`debug_assert_bag_count_eq_x`](macro@crate::debug_assert_bag_count_eq_x)
+///
+#[macro_export]
+macro_rules! assert_bag_count_eq_x_as_result {
+    ($collection:expr, $element:expr, $n:expr $(,)?) => {{
+        match (&$collection, &$element, &$n) {
+            (collection, element, n) => {
+                let bag = assert_bag_impl_prep!(collection);
+                let count: usize = bag.get(element).copied().unwrap_or(0);
+                if count == *n {
+                    Ok((count, *n))
+                } else {
+                    Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_bag_count_eq_x!(collection, element, n)`\n",
+                                $crate::assertables_url_line!("assert_bag_count_eq_x"),
+                                " collection label: `{}`,\n",
+                                " collection debug: `{:?}`,\n",
+                                "    element label: `{}`,\n",
+                                "    element debug: `{:?}`,\n",
+                                "          n label: `{}`,\n",
+                                "          n debug: `{:?}`,\n",
+                                "              bag: `{:?}`,\n",
+                                "            count: `{:?}`"
+                            ),
+                            stringify!($collection),
+                            collection,
+                            stringify!($element),
+                            element,
+                            stringify!($n),
+                            n,
+                            bag,
+                            count
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_bag_count_eq_x_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 1, 2];
+        let actual = assert_bag_count_eq_x_as_result!(&a, 1, 2);
+        assert_eq!(actual.unwrap(), (2, 2));
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 1, 2];
+        let actual = assert_bag_count_eq_x_as_result!(&a, 1, 3);
+        let message = concat!(
+            "assertion failed: `assert_bag_count_eq_x!(collection, element, n)`\n",
+            assertables_url_line!("assert_bag_count_eq_x"),
+            " collection label: `&a`,\n",
+            " collection debug: `[1, 1, 2]`,\n",
+            "    element label: `1`,\n",
+            "    element debug: `1`,\n",
+            "          n label: `3`,\n",
+            "          n debug: `3`,\n",
+            "              bag: `{1: 2, 2: 1}`,\n",
+            "            count: `2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_because_missing_element_has_count_zero() {
+        let a = [1, 1, 2];
+        let actual = assert_bag_count_eq_x_as_result!(&a, 3, 0);
+        assert_eq!(actual.unwrap(), (0, 0));
+    }
+}
+
+/// Assert a bag contains an element a number of times.
+///
+/// Pseudocode:<br>
+/// (collection ⇒ bag).count(element) = n
+///
+/// * If true, return `(count, n)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 1, 2];
+/// assert_bag_count_eq_x!(&a, 1, 2);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 1, 2];
+/// assert_bag_count_eq_x!(&a, 1, 3);
+/// # });
+/// // assertion failed: `assert_bag_count_eq_x!(collection, element, n)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_count_eq_x.html
+/// //  collection label: `&a`,
+/// //  collection debug: `[1, 1, 2]`,
+/// //     element label: `1`,
+/// //     element debug: `1`,
+/// //           n label: `3`,
+/// //           n debug: `3`,
+/// //               bag: `{1: 2, 2: 1}`,
+/// //             count: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_bag_count_eq_x!(collection, element, n)`\n",
+/// #     assertables_url_line!("assert_bag_count_eq_x"),
+/// #     " collection label: `&a`,\n",
+/// #     " collection debug: `[1, 1, 2]`,\n",
+/// #     "    element label: `1`,\n",
+/// #     "    element debug: `1`,\n",
+/// #     "          n label: `3`,\n",
+/// #     "          n debug: `3`,\n",
+/// #     "              bag: `{1: 2, 2: 1}`,\n",
+/// #     "            count: `2`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// An element that is not in the bag has count 0.
+///
+/// This implementation uses [`::std::collections::BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html) to count items and sort them.
+///
+/// # Module macros
+///
+/// * [`assert_bag_count_eq_x`](macro@crate::assert_bag_count_eq_x)
+/// * [`assert_bag_count_eq_x_as_result`](macro@crate::assert_bag_count_eq_x_as_result)
+/// * [`debug_assert_bag_count_eq_x`](macro@crate::debug_assert_bag_count_eq_x)
+///
+#[macro_export]
+macro_rules! assert_bag_count_eq_x {
+    ($collection:expr, $element:expr, $n:expr $(,)?) => {{
+        match $crate::assert_bag_count_eq_x_as_result!($collection, $element, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($collection:expr, $element:expr, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_bag_count_eq_x_as_result!($collection, $element, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_bag_count_eq_x {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 1, 2];
+        let actual = assert_bag_count_eq_x!(&a, 1, 2);
+        assert_eq!(actual, (2, 2));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 1, 2];
+            let _actual = assert_bag_count_eq_x!(&a, 1, 3);
+        });
+        let message = concat!(
+            "assertion failed: `assert_bag_count_eq_x!(collection, element, n)`\n",
+            assertables_url_line!("assert_bag_count_eq_x"),
+            " collection label: `&a`,\n",
+            " collection debug: `[1, 1, 2]`,\n",
+            "    element label: `1`,\n",
+            "    element debug: `1`,\n",
+            "          n label: `3`,\n",
+            "          n debug: `3`,\n",
+            "              bag: `{1: 2, 2: 1}`,\n",
+            "            count: `2`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a bag contains an element a number of times.
+///
+/// Pseudocode:<br>
+/// (collection ⇒ bag).count(element) = n
+///
+/// This macro provides the same statements as [`assert_bag_count_eq_x`](macro.assert_bag_count_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_bag_count_eq_x`](macro@crate::assert_bag_count_eq_x)
+/// * [`assert_bag_count_eq_x_as_result`](macro@crate::assert_bag_count_eq_x_as_result)
+/// * [`debug_assert_bag_count_eq_x`](macro@crate::debug_assert_bag_count_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_bag_count_eq_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_bag_count_eq_x!($($arg)*);
+        }
+    };
+}
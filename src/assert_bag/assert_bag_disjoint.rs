@@ -0,0 +1,281 @@
+//! Assert a bag is disjoint with another, meaning the bags share no keys.
+//!
+//! Pseudocode:<br>
+//! (a_collection ⇒ a_bag) ∩ (b_collection ⇒ b_bag) = ∅
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 1];
+//! let b = [2, 2];
+//! assert_bag_disjoint!(&a, &b);
+//! ```
+//!
+//! This macro checks key presence, and ignores the key counts.
+//! The failure message shows the shared keys.
+//!
+//! This implementation uses [`::std::collections::BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html) to count items and sort them.
+//!
+//! # Module macros
+//!
+//! * [`assert_bag_disjoint`](macro@crate::assert_bag_disjoint)
+//! * [`assert_bag_disjoint_as_result`](macro@crate::assert_bag_disjoint_as_result)
+//! * [`debug_assert_bag_disjoint`](macro@crate::debug_assert_bag_disjoint)
+
+/// Assert a bag is disjoint with another, meaning the bags share no keys.
+///
+/// Pseudocode:<br>
+/// (a_collection ⇒ a_bag) ∩ (b_collection ⇒ b_bag) = ∅
+///
+/// * If true, return Result `Ok((a_bag, b_bag))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_bag_disjoint`](macro@crate::assert_bag_disjoint)
+/// * [`assert_bag_disjoint_as_result`](macro@crate::assert_bag_disjoint_as_result)
+/// * [`debug_assert_bag_disjoint`](macro@crate::debug_assert_bag_disjoint)
+///
+#[macro_export]
+macro_rules! assert_bag_disjoint_as_result {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        match (&$a_collection, &$b_collection) {
+            (a_collection, b_collection) => {
+                let a_bag = assert_bag_impl_prep!(a_collection);
+                let b_bag = assert_bag_impl_prep!(b_collection);
+                let shared_keys: $crate::__private::Vec<_> = a_bag.keys().filter(|key| b_bag.contains_key(*key)).collect();
+                if shared_keys.is_empty() {
+                    Ok((a_bag, b_bag))
+                } else {
+                    Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_bag_disjoint!(a_collection, b_collection)`\n",
+                                $crate::assertables_url_line!("assert_bag_disjoint"),
+                                "     a label: `{}`,\n",
+                                "     a debug: `{:?}`,\n",
+                                "     b label: `{}`,\n",
+                                "     b debug: `{:?}`,\n",
+                                "       a bag: `{:?}`,\n",
+                                "       b bag: `{:?}`,\n",
+                                " shared keys: `{:?}`"
+                            ),
+                            stringify!($a_collection),
+                            a_collection,
+                            stringify!($b_collection),
+                            b_collection,
+                            a_bag,
+                            b_bag,
+                            shared_keys
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_bag_disjoint_as_result {
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn success() {
+        let a = [1, 1];
+        let b = [2, 2];
+        let actual = assert_bag_disjoint_as_result!(&a, &b);
+        assert_eq!(
+            actual.unwrap(),
+            (BTreeMap::from([(&1, 2)]), BTreeMap::from([(&2, 2)]))
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 1, 2, 3];
+        let b = [2, 3, 3];
+        let actual = assert_bag_disjoint_as_result!(&a, &b);
+        let message = concat!(
+            "assertion failed: `assert_bag_disjoint!(a_collection, b_collection)`\n",
+            assertables_url_line!("assert_bag_disjoint"),
+            "     a label: `&a`,\n",
+            "     a debug: `[1, 1, 2, 3]`,\n",
+            "     b label: `&b`,\n",
+            "     b debug: `[2, 3, 3]`,\n",
+            "       a bag: `{1: 2, 2: 1, 3: 1}`,\n",
+            "       b bag: `{2: 1, 3: 2}`,\n",
+            " shared keys: `[2, 3]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a bag is disjoint with another, meaning the bags share no keys.
+///
+/// Pseudocode:<br>
+/// (a_collection ⇒ a_bag) ∩ (b_collection ⇒ b_bag) = ∅
+///
+/// * If true, return `(a_bag, b_bag)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 1];
+/// let b = [2, 2];
+/// assert_bag_disjoint!(&a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 1, 2, 3];
+/// let b = [2, 3, 3];
+/// assert_bag_disjoint!(&a, &b);
+/// # });
+/// // assertion failed: `assert_bag_disjoint!(a_collection, b_collection)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_disjoint.html
+/// //      a label: `&a`,
+/// //      a debug: `[1, 1, 2, 3]`,
+/// //      b label: `&b`,
+/// //      b debug: `[2, 3, 3]`,
+/// //        a bag: `{1: 2, 2: 1, 3: 1}`,
+/// //        b bag: `{2: 1, 3: 2}`,
+/// //  shared keys: `[2, 3]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_bag_disjoint!(a_collection, b_collection)`\n",
+/// #     assertables_url_line!("assert_bag_disjoint"),
+/// #     "     a label: `&a`,\n",
+/// #     "     a debug: `[1, 1, 2, 3]`,\n",
+/// #     "     b label: `&b`,\n",
+/// #     "     b debug: `[2, 3, 3]`,\n",
+/// #     "       a bag: `{1: 2, 2: 1, 3: 1}`,\n",
+/// #     "       b bag: `{2: 1, 3: 2}`,\n",
+/// #     " shared keys: `[2, 3]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro checks key presence, and ignores the key counts.
+/// The failure message shows the shared keys.
+///
+/// This implementation uses [`::std::collections::BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html) to count items and sort them.
+///
+/// # Module macros
+///
+/// * [`assert_bag_disjoint`](macro@crate::assert_bag_disjoint)
+/// * [`assert_bag_disjoint_as_result`](macro@crate::assert_bag_disjoint_as_result)
+/// * [`debug_assert_bag_disjoint`](macro@crate::debug_assert_bag_disjoint)
+///
+#[macro_export]
+macro_rules! assert_bag_disjoint {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        match $crate::assert_bag_disjoint_as_result!($a_collection, $b_collection) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
+        match $crate::assert_bag_disjoint_as_result!($a_collection, $b_collection) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_bag_disjoint {
+    use std::collections::BTreeMap;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 1];
+        let b = [2, 2];
+        let actual = assert_bag_disjoint!(&a, &b);
+        assert_eq!(
+            actual,
+            (BTreeMap::from([(&1, 2)]), BTreeMap::from([(&2, 2)]))
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 1, 2, 3];
+            let b = [2, 3, 3];
+            let _actual = assert_bag_disjoint!(&a, &b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_bag_disjoint!(a_collection, b_collection)`\n",
+            assertables_url_line!("assert_bag_disjoint"),
+            "     a label: `&a`,\n",
+            "     a debug: `[1, 1, 2, 3]`,\n",
+            "     b label: `&b`,\n",
+            "     b debug: `[2, 3, 3]`,\n",
+            "       a bag: `{1: 2, 2: 1, 3: 1}`,\n",
+            "       b bag: `{2: 1, 3: 2}`,\n",
+            " shared keys: `[2, 3]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a bag is disjoint with another, meaning the bags share no keys.
+///
+/// Pseudocode:<br>
+/// (a_collection ⇒ a_bag) ∩ (b_collection ⇒ b_bag) = ∅
+///
+/// This macro provides the same statements as [`assert_bag_disjoint`](macro.assert_bag_disjoint.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_bag_disjoint`](macro@crate::assert_bag_disjoint)
+/// * [`assert_bag_disjoint_as_result`](macro@crate::assert_bag_disjoint_as_result)
+/// * [`debug_assert_bag_disjoint`](macro@crate::debug_assert_bag_disjoint)
+///
+#[macro_export]
+macro_rules! debug_assert_bag_disjoint {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_bag_disjoint!($($arg)*);
+        }
+    };
+}
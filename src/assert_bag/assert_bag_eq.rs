@@ -13,6 +13,12 @@
 //! assert_bag_eq!(&a, &b);
 //! ```
 //!
+//! The failure message shows only the items whose counts differ, such as
+//! `{1: 2 vs 3}`, which means the item `1` is in `a` two times, and in `b`
+//! three times. An item that is only in one bag has a count of 0 in the other.
+//! The debug of each whole collection is truncated to 64 characters, so the
+//! message stays short for large bags.
+//!
 //! # Module macros
 //!
 //! * [`assert_bag_eq`](macro@crate::assert_bag_eq)
@@ -48,23 +54,21 @@ macro_rules! assert_bag_eq_as_result {
                     Ok((a_bag, b_bag))
                 } else {
                     Err(
-                        format!(
+                        $crate::__private::format!(
                             concat!(
                                 "assertion failed: `assert_bag_eq!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_eq.html\n",
+                                $crate::assertables_url_line!("assert_bag_eq"),
                                 " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
+                                " a debug: `{}`,\n",
                                 " b label: `{}`,\n",
-                                " b debug: `{:?}`,\n",
-                                "   a bag: `{:?}`,\n",
-                                "   b bag: `{:?}`"
+                                " b debug: `{}`,\n",
+                                "  counts: `{}`"
                             ),
                             stringify!($a_collection),
-                            a_collection,
+                            $crate::__private::debug_truncated(a_collection, 64),
                             stringify!($b_collection),
-                            b_collection,
-                            a_bag,
-                            b_bag
+                            $crate::__private::debug_truncated(b_collection, 64),
+                            $crate::assert_bag::bag_count_differences(&a_bag, &b_bag)
                         )
                     )
                 }
@@ -95,16 +99,41 @@ mod test_assert_bag_eq_as_result {
         let actual = assert_bag_eq_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_bag_eq!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_eq.html\n",
+            assertables_url_line!("assert_bag_eq"),
             " a label: `&a`,\n",
             " a debug: `[1, 1]`,\n",
             " b label: `&b`,\n",
             " b debug: `[1, 1, 1]`,\n",
-            "   a bag: `{1: 2}`,\n",
-            "   b bag: `{1: 3}`"
+            "  counts: `{1: 2 vs 3}`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn ne_with_items_in_one_bag_only() {
+        let a = [1, 2, 2];
+        let b = [2, 2, 3];
+        let actual = assert_bag_eq_as_result!(&a, &b);
+        assert!(actual
+            .unwrap_err()
+            .ends_with("  counts: `{1: 1 vs 0, 3: 0 vs 1}`"));
+    }
+
+    #[test]
+    fn ne_with_large_bags_shows_only_differences() {
+        let a: Vec<i32> = (0..100).collect();
+        let mut b = a.clone();
+        b.push(42);
+        let actual = assert_bag_eq_as_result!(&a, &b);
+        let message = actual.unwrap_err();
+        assert!(message.ends_with("  counts: `{42: 1 vs 2}`"));
+        assert!(!message.contains("0: 1"));
+        assert!(message.contains(
+            " a debug: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 1…`,\n"
+        ));
+        assert!(!message.contains("99"));
+        assert!(message.len() < 500);
+    }
 }
 
 /// Assert a bag is equal to another.
@@ -140,18 +169,16 @@ mod test_assert_bag_eq_as_result {
 /// //  a debug: `[1, 1]`,
 /// //  b label: `&b`,
 /// //  b debug: `[1, 1, 1]`,
-/// //    a bag: `{1: 2}`,
-/// //    b bag: `{1: 3}`
+/// //   counts: `{1: 2 vs 3}`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_bag_eq!(a_collection, b_collection)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_eq.html\n",
+/// #     assertables_url_line!("assert_bag_eq"),
 /// #     " a label: `&a`,\n",
 /// #     " a debug: `[1, 1]`,\n",
 /// #     " b label: `&b`,\n",
 /// #     " b debug: `[1, 1, 1]`,\n",
-/// #     "   a bag: `{1: 2}`,\n",
-/// #     "   b bag: `{1: 3}`"
+/// #     "  counts: `{1: 2 vs 3}`"
 /// # );
 /// # assert_eq!(actual, message);
 /// # }
@@ -170,13 +197,13 @@ macro_rules! assert_bag_eq {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match $crate::assert_bag_eq_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
         match $crate::assert_bag_eq_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -206,13 +233,12 @@ mod test_assert_bag_eq {
         });
         let message = concat!(
             "assertion failed: `assert_bag_eq!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_eq.html\n",
+            assertables_url_line!("assert_bag_eq"),
             " a label: `&a`,\n",
             " a debug: `[1, 1]`,\n",
             " b label: `&b`,\n",
             " b debug: `[1, 1, 1]`,\n",
-            "   a bag: `{1: 2}`,\n",
-            "   b bag: `{1: 3}`"
+            "  counts: `{1: 2 vs 3}`"
         );
         assert_eq!(
             result
@@ -259,7 +285,7 @@ mod test_assert_bag_eq {
 #[macro_export]
 macro_rules! debug_assert_bag_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_bag_eq!($($arg)*);
         }
     };
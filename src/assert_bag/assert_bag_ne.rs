@@ -50,23 +50,22 @@ macro_rules! assert_bag_ne_as_result {
                     Ok((a_bag, b_bag))
                 } else {
                     Err(
-                        format!(
+                        $crate::__private::format!(
                             concat!(
                                 "assertion failed: `assert_bag_ne!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_ne.html\n",
-                                " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
-                                " b label: `{}`,\n",
-                                " b debug: `{:?}`,\n",
-                                "   a bag: `{:?}`,\n",
-                                "   b bag: `{:?}`"
+                                $crate::assertables_url_line!("assert_bag_ne"),
+                                "    a label: `{}`,\n",
+                                "    a debug: `{:?}`,\n",
+                                "    b label: `{}`,\n",
+                                "    b debug: `{:?}`,\n",
+                                "     reason: `bags are equal`,\n",
+                                " shared bag: `{:?}`"
                             ),
                             stringify!($a_collection),
                             a_collection,
                             stringify!($b_collection),
                             b_collection,
-                            a_bag,
-                            b_bag
+                            a_bag
                         )
                     )
                 }
@@ -97,16 +96,25 @@ mod test_assert_bag_ne_as_result {
         let actual = assert_bag_ne_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_bag_ne!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_ne.html\n",
-            " a label: `&a`,\n",
-            " a debug: `[1, 1]`,\n",
-            " b label: `&b`,\n",
-            " b debug: `[1, 1]`,\n",
-            "   a bag: `{1: 2}`,\n",
-            "   b bag: `{1: 2}`"
+            assertables_url_line!("assert_bag_ne"),
+            "    a label: `&a`,\n",
+            "    a debug: `[1, 1]`,\n",
+            "    b label: `&b`,\n",
+            "    b debug: `[1, 1]`,\n",
+            "     reason: `bags are equal`,\n",
+            " shared bag: `{1: 2}`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn failure_with_different_order_shows_shared_bag() {
+        let a = [1, 2, 1];
+        let b = [2, 1, 1];
+        let actual = assert_bag_ne_as_result!(&a, &b);
+        let message = actual.unwrap_err();
+        assert!(message.contains("     reason: `bags are equal`,\n shared bag: `{1: 2, 2: 1}`"));
+    }
 }
 
 /// Assert a bag is not equal to another.
@@ -138,22 +146,22 @@ mod test_assert_bag_ne_as_result {
 /// # });
 /// // assertion failed: `assert_bag_ne!(a_collection, b_collection)`
 /// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_ne.html
-/// //  a label: `&a`,
-/// //  a debug: `[1, 1]`,
-/// //  b label: `&b`,
-/// //  b debug: `[1, 1]`,
-/// //    a bag: `{1: 2}`,
-/// //    b bag: `{1: 2}`
+/// //     a label: `&a`,
+/// //     a debug: `[1, 1]`,
+/// //     b label: `&b`,
+/// //     b debug: `[1, 1]`,
+/// //      reason: `bags are equal`,
+/// //  shared bag: `{1: 2}`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_bag_ne!(a_collection, b_collection)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_ne.html\n",
-/// #     " a label: `&a`,\n",
-/// #     " a debug: `[1, 1]`,\n",
-/// #     " b label: `&b`,\n",
-/// #     " b debug: `[1, 1]`,\n",
-/// #     "   a bag: `{1: 2}`,\n",
-/// #     "   b bag: `{1: 2}`"
+/// #     assertables_url_line!("assert_bag_ne"),
+/// #     "    a label: `&a`,\n",
+/// #     "    a debug: `[1, 1]`,\n",
+/// #     "    b label: `&b`,\n",
+/// #     "    b debug: `[1, 1]`,\n",
+/// #     "     reason: `bags are equal`,\n",
+/// #     " shared bag: `{1: 2}`"
 /// # );
 /// # assert_eq!(actual, message);
 /// # }
@@ -172,13 +180,13 @@ macro_rules! assert_bag_ne {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match $crate::assert_bag_ne_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
         match $crate::assert_bag_ne_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -208,13 +216,13 @@ mod test_assert_bag_ne {
         });
         let message = concat!(
             "assertion failed: `assert_bag_ne!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_ne.html\n",
-            " a label: `&a`,\n",
-            " a debug: `[1, 1]`,\n",
-            " b label: `&b`,\n",
-            " b debug: `[1, 1]`,\n",
-            "   a bag: `{1: 2}`,\n",
-            "   b bag: `{1: 2}`"
+            assertables_url_line!("assert_bag_ne"),
+            "    a label: `&a`,\n",
+            "    a debug: `[1, 1]`,\n",
+            "    b label: `&b`,\n",
+            "    b debug: `[1, 1]`,\n",
+            "     reason: `bags are equal`,\n",
+            " shared bag: `{1: 2}`"
         );
         assert_eq!(
             result
@@ -261,7 +269,7 @@ mod test_assert_bag_ne {
 #[macro_export]
 macro_rules! debug_assert_bag_ne {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_bag_ne!($($arg)*);
         }
     };
@@ -54,10 +54,10 @@ macro_rules! assert_bag_superbag_as_result {
                     Ok((a_bag, b_bag))
                 } else {
                     Err(
-                        format!(
+                        $crate::__private::format!(
                             concat!(
                                 "assertion failed: `assert_bag_superbag!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_superbag.html\n",
+                                $crate::assertables_url_line!("assert_bag_superbag"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -101,7 +101,7 @@ mod test_assert_bag_superbag_as_result {
         let actual = assert_bag_superbag_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_bag_superbag!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_superbag.html\n",
+            assertables_url_line!("assert_bag_superbag"),
             " a label: `&a`,\n",
             " a debug: `[1, 1]`,\n",
             " b label: `&b`,\n",
@@ -119,7 +119,7 @@ mod test_assert_bag_superbag_as_result {
         let actual = assert_bag_superbag_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_bag_superbag!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_superbag.html\n",
+            assertables_url_line!("assert_bag_superbag"),
             " a label: `&a`,\n",
             " a debug: `[1, 1]`,\n",
             " b label: `&b`,\n",
@@ -169,7 +169,7 @@ mod test_assert_bag_superbag_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_bag_superbag!(a_collection, b_collection)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_superbag.html\n",
+/// #     assertables_url_line!("assert_bag_superbag"),
 /// #     " a label: `&a`,\n",
 /// #     " a debug: `[1, 1]`,\n",
 /// #     " b label: `&b`,\n",
@@ -194,13 +194,13 @@ macro_rules! assert_bag_superbag {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match $crate::assert_bag_superbag_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
         match $crate::assert_bag_superbag_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -230,7 +230,7 @@ mod test_assert_bag_superbag {
         });
         let message = concat!(
             "assertion failed: `assert_bag_superbag!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_superbag.html\n",
+            assertables_url_line!("assert_bag_superbag"),
             " a label: `&a`,\n",
             " a debug: `[1, 1]`,\n",
             " b label: `&b`,\n",
@@ -257,7 +257,7 @@ mod test_assert_bag_superbag {
         });
         let message = concat!(
             "assertion failed: `assert_bag_superbag!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_bag_superbag.html\n",
+            assertables_url_line!("assert_bag_superbag"),
             " a label: `&a`,\n",
             " a debug: `[1, 1]`,\n",
             " b label: `&b`,\n",
@@ -310,7 +310,7 @@ mod test_assert_bag_superbag {
 #[macro_export]
 macro_rules! debug_assert_bag_superbag {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_bag_superbag!($($arg)*);
         }
     };
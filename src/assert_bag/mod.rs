@@ -17,6 +17,18 @@
 //!
 //! * [`assert_bag_superbag!(collection1, collection2)`](macro@crate::assert_bag_superbag) ≈ bag a ⊇ bag b
 //!
+//! For joint & disjoint:
+//!
+//! * [`assert_bag_joint!(collection1, collection2)`](macro@crate::assert_bag_joint) ≈ bag a ∩ bag b ≠ ∅
+//!
+//! * [`assert_bag_disjoint!(collection1, collection2)`](macro@crate::assert_bag_disjoint) ≈ bag a ∩ bag b = ∅
+//!
+//! For element counts:
+//!
+//! * [`assert_bag_contains!(collection, element)`](macro@crate::assert_bag_contains) ≈ bag count of element ≥ 1
+//!
+//! * [`assert_bag_count_eq_x!(collection, element, n)`](macro@crate::assert_bag_count_eq_x) ≈ bag count of element = n
+//!
 //!
 //! # Example
 //!
@@ -99,8 +111,8 @@ macro_rules! assert_bag_impl_prep {
     ($impl_into_iter:expr $(,)?) => {{
         match (&$impl_into_iter) {
             impl_into_iter => {
-                let mut bag: std::collections::BTreeMap<_, usize> =
-                    std::collections::BTreeMap::new();
+                let mut bag: $crate::__private::BTreeMap<_, usize> =
+                    $crate::__private::BTreeMap::new();
                 for x in impl_into_iter.into_iter() {
                     let n = bag.entry(x).or_insert(0);
                     *n += 1;
@@ -115,3 +127,40 @@ pub mod assert_bag_eq;
 pub mod assert_bag_ne;
 pub mod assert_bag_subbag;
 pub mod assert_bag_superbag;
+
+// Overlaps
+pub mod assert_bag_disjoint;
+pub mod assert_bag_joint;
+
+// Element counts
+pub mod assert_bag_contains;
+pub mod assert_bag_count_eq_x;
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Return the counts that differ between two bags, such as `{1: 2 vs 3}`.
+///
+/// Each item shows its count in `a` then its count in `b`. An item that
+/// is only in one bag has a count of 0 in the other bag.
+#[doc(hidden)]
+pub fn bag_count_differences<K: Ord + ::core::fmt::Debug>(
+    a: &BTreeMap<K, usize>,
+    b: &BTreeMap<K, usize>,
+) -> String {
+    let mut counts: BTreeMap<&K, (usize, usize)> = BTreeMap::new();
+    for (key, &count) in a {
+        counts.entry(key).or_insert((0, 0)).0 = count;
+    }
+    for (key, &count) in b {
+        counts.entry(key).or_insert((0, 0)).1 = count;
+    }
+    let differences: Vec<String> = counts
+        .into_iter()
+        .filter(|(_, (a_count, b_count))| a_count != b_count)
+        .map(|(key, (a_count, b_count))| format!("{:?}: {} vs {}", key, a_count, b_count))
+        .collect();
+    format!("{{{}}}", differences.join(", "))
+}
@@ -0,0 +1,379 @@
+//! Assert a value is between a lower bound and an upper bound, inclusive.
+//!
+//! Pseudocode:<br>
+//! lo ≤ x ≤ hi
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let x = 2;
+//! let lo = 1;
+//! let hi = 3;
+//! assert_between!(x, lo, hi);
+//! ```
+//!
+//! The values need `PartialOrd` and `Debug`. This macro is more ergonomic
+//! than a range for simple bounds, and the failure message says which bound
+//! is violated, by showing `lo ≤ x` and `x ≤ hi` as true or false.
+//!
+//! # Module macros
+//!
+//! * [`assert_between`](macro@crate::assert_between)
+//! * [`assert_between_as_result`](macro@crate::assert_between_as_result)
+//! * [`debug_assert_between`](macro@crate::debug_assert_between)
+
+/// Assert a value is between a lower bound and an upper bound, inclusive.
+///
+/// Pseudocode:<br>
+/// lo ≤ x ≤ hi
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_between`](macro@crate::assert_between)
+/// * [`assert_between_as_result`](macro@crate::assert_between_as_result)
+/// * [`debug_assert_between`](macro@crate::debug_assert_between)
+///
+#[macro_export]
+macro_rules! assert_between_as_result {
+    ($x:expr, $lo:expr, $hi:expr $(,)?) => {{
+        match (&$x, &$lo, &$hi) {
+            (x, lo, hi) => {
+                let lo_ok = lo <= x;
+                let hi_ok = x <= hi;
+                if lo_ok && hi_ok {
+                    Ok(())
+                } else {
+                    Err($crate::__private::format!(
+                        concat!(
+                            "assertion failed: `assert_between!(x, lo, hi)`\n",
+                            $crate::assertables_url_line!("assert_between"),
+                            "  x label: `{}`,\n",
+                            "  x debug: `{:?}`,\n",
+                            " lo label: `{}`,\n",
+                            " lo debug: `{:?}`,\n",
+                            " hi label: `{}`,\n",
+                            " hi debug: `{:?}`,\n",
+                            "   lo ≤ x: {},\n",
+                            "   x ≤ hi: {}"
+                        ),
+                        stringify!($x),
+                        x,
+                        stringify!($lo),
+                        lo,
+                        stringify!($hi),
+                        hi,
+                        lo_ok,
+                        hi_ok
+                    ))
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_between_as_result {
+    #[test]
+    fn success() {
+        let x = 2;
+        let lo = 1;
+        let hi = 3;
+        let actual = assert_between_as_result!(x, lo, hi);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let x = 0;
+        let lo = 1;
+        let hi = 3;
+        let actual = assert_between_as_result!(x, lo, hi);
+        let message = concat!(
+            "assertion failed: `assert_between!(x, lo, hi)`\n",
+            assertables_url_line!("assert_between"),
+            "  x label: `x`,\n",
+            "  x debug: `0`,\n",
+            " lo label: `lo`,\n",
+            " lo debug: `1`,\n",
+            " hi label: `hi`,\n",
+            " hi debug: `3`,\n",
+            "   lo ≤ x: false,\n",
+            "   x ≤ hi: true"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_at_lower_bound() {
+        let x = 1;
+        let lo = 1;
+        let hi = 3;
+        let actual = assert_between_as_result!(x, lo, hi);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_at_upper_bound() {
+        let x = 3;
+        let lo = 1;
+        let hi = 3;
+        let actual = assert_between_as_result!(x, lo, hi);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_because_upper_bound() {
+        let x = 4;
+        let lo = 1;
+        let hi = 3;
+        let actual = assert_between_as_result!(x, lo, hi);
+        let message = concat!(
+            "assertion failed: `assert_between!(x, lo, hi)`\n",
+            assertables_url_line!("assert_between"),
+            "  x label: `x`,\n",
+            "  x debug: `4`,\n",
+            " lo label: `lo`,\n",
+            " lo debug: `1`,\n",
+            " hi label: `hi`,\n",
+            " hi debug: `3`,\n",
+            "   lo ≤ x: true,\n",
+            "   x ≤ hi: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_both_bounds() {
+        let x = 2;
+        let lo = 3;
+        let hi = 1;
+        let actual = assert_between_as_result!(x, lo, hi);
+        let message = concat!(
+            "assertion failed: `assert_between!(x, lo, hi)`\n",
+            assertables_url_line!("assert_between"),
+            "  x label: `x`,\n",
+            "  x debug: `2`,\n",
+            " lo label: `lo`,\n",
+            " lo debug: `3`,\n",
+            " hi label: `hi`,\n",
+            " hi debug: `1`,\n",
+            "   lo ≤ x: false,\n",
+            "   x ≤ hi: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_floats() {
+        let x: f64 = 0.5;
+        let lo: f64 = 0.0;
+        let hi: f64 = 1.0;
+        let actual = assert_between_as_result!(x, lo, hi);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_nan() {
+        let x: f64 = f64::NAN;
+        let lo: f64 = 0.0;
+        let hi: f64 = 1.0;
+        let actual = assert_between_as_result!(x, lo, hi);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn success_with_strings() {
+        let x = "bravo";
+        let lo = "alfa";
+        let hi = "charlie";
+        let actual = assert_between_as_result!(x, lo, hi);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        let mut count = 0;
+        let mut x = || {
+            count += 1;
+            2
+        };
+        let actual = assert_between_as_result!(x(), 1, 3);
+        assert_eq!(actual.unwrap(), ());
+        assert_eq!(count, 1);
+    }
+}
+
+/// Assert a value is between a lower bound and an upper bound, inclusive.
+///
+/// Pseudocode:<br>
+/// lo ≤ x ≤ hi
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let x = 2;
+/// let lo = 1;
+/// let hi = 3;
+/// assert_between!(x, lo, hi);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let x = 0;
+/// let lo = 1;
+/// let hi = 3;
+/// assert_between!(x, lo, hi);
+/// # });
+/// // assertion failed: `assert_between!(x, lo, hi)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_between.html
+/// //   x label: `x`,
+/// //   x debug: `0`,
+/// //  lo label: `lo`,
+/// //  lo debug: `1`,
+/// //  hi label: `hi`,
+/// //  hi debug: `3`,
+/// //    lo ≤ x: false,
+/// //    x ≤ hi: true
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_between!(x, lo, hi)`\n",
+/// #     assertables_url_line!("assert_between"),
+/// #     "  x label: `x`,\n",
+/// #     "  x debug: `0`,\n",
+/// #     " lo label: `lo`,\n",
+/// #     " lo debug: `1`,\n",
+/// #     " hi label: `hi`,\n",
+/// #     " hi debug: `3`,\n",
+/// #     "   lo ≤ x: false,\n",
+/// #     "   x ≤ hi: true"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The values need `PartialOrd` and `Debug`. This macro is more ergonomic
+/// than a range for simple bounds, and the failure message says which bound
+/// is violated, by showing `lo ≤ x` and `x ≤ hi` as true or false.
+///
+/// # Module macros
+///
+/// * [`assert_between`](macro@crate::assert_between)
+/// * [`assert_between_as_result`](macro@crate::assert_between_as_result)
+/// * [`debug_assert_between`](macro@crate::debug_assert_between)
+///
+#[macro_export]
+macro_rules! assert_between {
+    ($x:expr, $lo:expr, $hi:expr $(,)?) => {{
+        match $crate::assert_between_as_result!($x, $lo, $hi) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($x:expr, $lo:expr, $hi:expr, $($message:tt)+) => {{
+        match $crate::assert_between_as_result!($x, $lo, $hi) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_between {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let x = 2;
+        let lo = 1;
+        let hi = 3;
+        let actual = assert_between!(x, lo, hi);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let x = 0;
+            let lo = 1;
+            let hi = 3;
+            let _actual = assert_between!(x, lo, hi);
+        });
+        let message = concat!(
+            "assertion failed: `assert_between!(x, lo, hi)`\n",
+            assertables_url_line!("assert_between"),
+            "  x label: `x`,\n",
+            "  x debug: `0`,\n",
+            " lo label: `lo`,\n",
+            " lo debug: `1`,\n",
+            " hi label: `hi`,\n",
+            " hi debug: `3`,\n",
+            "   lo ≤ x: false,\n",
+            "   x ≤ hi: true"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a value is between a lower bound and an upper bound, inclusive.
+///
+/// Pseudocode:<br>
+/// lo ≤ x ≤ hi
+///
+/// This macro provides the same statements as [`assert_between`](macro.assert_between.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_between`](macro@crate::assert_between)
+/// * [`assert_between_as_result`](macro@crate::assert_between_as_result)
+/// * [`debug_assert_between`](macro@crate::debug_assert_between)
+///
+#[macro_export]
+macro_rules! debug_assert_between {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_between!($($arg)*);
+        }
+    };
+}
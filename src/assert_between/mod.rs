@@ -0,0 +1,22 @@
+//! Assert for checking a value is between a lower bound and an upper bound.
+//!
+//! These macros help with the common pattern `lo <= x && x <= hi`, without
+//! two asserts, and without constructing a range. The failure message shows
+//! all three values, and says which bound is violated.
+//!
+//! * [`assert_between!(x, lo, hi)`](macro@crate::assert_between) ≈ lo ≤ x ≤ hi
+//! * [`assert_between_exclusive!(x, lo, hi)`](macro@crate::assert_between_exclusive) ≈ lo < x < hi
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let x = 2;
+//! assert_between!(x, 1, 3);
+//! assert_between_exclusive!(x, 1, 3);
+//! ```
+
+#[allow(clippy::module_inception)]
+pub mod assert_between;
+pub mod assert_between_exclusive;
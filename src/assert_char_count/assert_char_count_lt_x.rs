@@ -0,0 +1,266 @@
+//! Assert a char count is less than an expression.
+//!
+//! Pseudocode:<br>
+//! a.chars().count() < b
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "café";
+//! let b = 5;
+//! assert_char_count_lt_x!(a, b);
+//! ```
+//!
+//! This macro counts chars, i.e. Unicode scalar values, rather than bytes.
+//! For example, `"café".len()` is 5 bytes, and `"café".chars().count()` is 4.
+//!
+//! The failure message shows both the byte length and the char count,
+//! so you can see any discrepancy between them.
+//!
+//! # Module macros
+//!
+//! * [`assert_char_count_lt_x`](macro@crate::assert_char_count_lt_x)
+//! * [`assert_char_count_lt_x_as_result`](macro@crate::assert_char_count_lt_x_as_result)
+//! * [`debug_assert_char_count_lt_x`](macro@crate::debug_assert_char_count_lt_x)
+
+/// Assert a char count is less than an expression.
+///
+/// Pseudocode:<br>
+/// a.chars().count() < b
+///
+/// * If true, return Result `Ok((a.chars().count(), b))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_char_count_lt_x`](macro@crate::assert_char_count_lt_x)
+/// * [`assert_char_count_lt_x_as_result`](macro@crate::assert_char_count_lt_x_as_result)
+/// * [`debug_assert_char_count_lt_x`](macro@crate::debug_assert_char_count_lt_x)
+///
+#[macro_export]
+macro_rules! assert_char_count_lt_x_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let a_char_count = a.chars().count();
+                if a_char_count < *b {
+                    Ok((a_char_count, *b))
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_char_count_lt_x!(a, b)`\n",
+                                $crate::assertables_url_line!("assert_char_count_lt_x"),
+                                "           a label: `{}`,\n",
+                                "           a debug: `{:?}`,\n",
+                                "           a.len(): `{:?}`,\n",
+                                " a.chars().count(): `{:?}`,\n",
+                                "           b label: `{}`,\n",
+                                "           b debug: `{:?}`"
+                            ),
+                            stringify!($a),
+                            a,
+                            a.len(),
+                            a_char_count,
+                            stringify!($b),
+                            b
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_char_count_lt_x_as_result {
+    #[test]
+    fn success() {
+        let a = "café";
+        let b = 5;
+        let actual = assert_char_count_lt_x_as_result!(a, b);
+        assert_eq!(actual.unwrap(), (4, 5));
+    }
+
+    #[test]
+    fn failure() {
+        let a = "café";
+        let b = 4;
+        let actual = assert_char_count_lt_x_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_char_count_lt_x!(a, b)`\n",
+            assertables_url_line!("assert_char_count_lt_x"),
+            "           a label: `a`,\n",
+            "           a debug: `\"café\"`,\n",
+            "           a.len(): `5`,\n",
+            " a.chars().count(): `4`,\n",
+            "           b label: `b`,\n",
+            "           b debug: `4`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a char count is less than an expression.
+///
+/// Pseudocode:<br>
+/// a.chars().count() < b
+///
+/// * If true, return `(a.chars().count(), b)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "café";
+/// let b = 5;
+/// assert_char_count_lt_x!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "café";
+/// let b = 4;
+/// assert_char_count_lt_x!(a, b);
+/// # });
+/// // assertion failed: `assert_char_count_lt_x!(a, b)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_char_count_lt_x.html
+/// //            a label: `a`,
+/// //            a debug: `\"café\"`,
+/// //            a.len(): `5`,
+/// //  a.chars().count(): `4`,
+/// //            b label: `b`,
+/// //            b debug: `4`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_char_count_lt_x!(a, b)`\n",
+/// #     assertables_url_line!("assert_char_count_lt_x"),
+/// #     "           a label: `a`,\n",
+/// #     "           a debug: `\"café\"`,\n",
+/// #     "           a.len(): `5`,\n",
+/// #     " a.chars().count(): `4`,\n",
+/// #     "           b label: `b`,\n",
+/// #     "           b debug: `4`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro counts chars, i.e. Unicode scalar values, rather than bytes.
+/// For example, `"café".len()` is 5 bytes, and `"café".chars().count()` is 4.
+///
+/// The failure message shows both the byte length and the char count,
+/// so you can see any discrepancy between them.
+///
+/// # Module macros
+///
+/// * [`assert_char_count_lt_x`](macro@crate::assert_char_count_lt_x)
+/// * [`assert_char_count_lt_x_as_result`](macro@crate::assert_char_count_lt_x_as_result)
+/// * [`debug_assert_char_count_lt_x`](macro@crate::debug_assert_char_count_lt_x)
+///
+#[macro_export]
+macro_rules! assert_char_count_lt_x {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_char_count_lt_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_char_count_lt_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_char_count_lt_x {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = "café";
+        let b = 5;
+        let actual = assert_char_count_lt_x!(a, b);
+        assert_eq!(actual, (4, 5));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = "café";
+            let b = 4;
+            let _actual = assert_char_count_lt_x!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_char_count_lt_x!(a, b)`\n",
+            assertables_url_line!("assert_char_count_lt_x"),
+            "           a label: `a`,\n",
+            "           a debug: `\"café\"`,\n",
+            "           a.len(): `5`,\n",
+            " a.chars().count(): `4`,\n",
+            "           b label: `b`,\n",
+            "           b debug: `4`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a char count is less than an expression.
+///
+/// Pseudocode:<br>
+/// a.chars().count() < b
+///
+/// This macro provides the same statements as [`assert_char_count_lt_x`](macro.assert_char_count_lt_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_char_count_lt_x`](macro@crate::assert_char_count_lt_x)
+/// * [`assert_char_count_lt_x_as_result`](macro@crate::assert_char_count_lt_x_as_result)
+/// * [`debug_assert_char_count_lt_x`](macro@crate::debug_assert_char_count_lt_x)
+///
+#[macro_export]
+macro_rules! debug_assert_char_count_lt_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_char_count_lt_x!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,39 @@
+//! Assert for comparing char counts.
+//!
+//! These macros help with strings, where you want to count the chars, i.e.
+//! the Unicode scalar values, rather than the bytes. This is because
+//! `str::len()` returns the number of bytes, so a multibyte string such as
+//! `"café"` has a length of 5, yet has a char count of 4.
+//!
+//! Compare a char count with an expression:
+//!
+//! * [`assert_char_count_eq_x!(a, expr)`](macro@crate::assert_char_count_eq_x) ≈ a.chars().count() = expr
+//! * [`assert_char_count_ne_x!(a, expr)`](macro@crate::assert_char_count_ne_x) ≈ a.chars().count() ≠ expr
+//! * [`assert_char_count_lt_x!(a, expr)`](macro@crate::assert_char_count_lt_x) ≈ a.chars().count() < expr
+//! * [`assert_char_count_le_x!(a, expr)`](macro@crate::assert_char_count_le_x) ≈ a.chars().count() ≤ expr
+//! * [`assert_char_count_gt_x!(a, expr)`](macro@crate::assert_char_count_gt_x) ≈ a.chars().count() > expr
+//! * [`assert_char_count_ge_x!(a, expr)`](macro@crate::assert_char_count_ge_x) ≈ a.chars().count() ≥ expr
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "café";
+//! assert_len_eq_x!(a, 5);
+//! assert_char_count_eq_x!(a, 4);
+//! ```
+//!
+//! ## Chars versus graphemes
+//!
+//! A char is a Unicode scalar value, which is not always what a person sees
+//! as one character. For example, the letter "e" followed by the combining
+//! acute accent U+0301 looks like "é", yet is two chars.
+
+// Compare expression
+pub mod assert_char_count_eq_x;
+pub mod assert_char_count_ge_x;
+pub mod assert_char_count_gt_x;
+pub mod assert_char_count_le_x;
+pub mod assert_char_count_lt_x;
+pub mod assert_char_count_ne_x;
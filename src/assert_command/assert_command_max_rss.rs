@@ -0,0 +1,373 @@
+//! Assert a command runs within a peak memory limit, measured as maximum resident set size.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ spawn ⇒ wait ⇒ max rss in kilobytes) ≤ kilobytes
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[cfg(any(target_os = "linux", target_os = "macos"))] {
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let kilobytes = 1_000_000;
+//! assert_command_max_rss!(command, kilobytes);
+//! # }
+//! ```
+//!
+//! This macro is for catching memory regressions in command line tools.
+//!
+//! Platform limitation: this macro is available only on Linux and macOS,
+//! because it uses the system call `wait4`, via the crate `libc`, to collect
+//! the resource usage of the child process, which includes `ru_maxrss`, the
+//! peak resident set size.
+//!
+//! Accuracy caveats:
+//!
+//! * The measurement is the peak resident set size, which is the peak amount
+//!   of physical memory, not the peak amount of virtual memory.
+//!
+//! * The measurement is of the command process itself, not of any child
+//!   process that the command spawns in turn.
+//!
+//! * The measurement is reported in kilobytes, rounded down. On macOS, the
+//!   operating system reports bytes, which this macro converts to kilobytes.
+//!
+//! * The measurement includes the memory used by the program loader and
+//!   shared libraries, so even a tiny command uses some hundreds of kilobytes.
+//!
+//! * The command stdout and stderr are discarded.
+//!
+//! # Module macros
+//!
+//! * [`assert_command_max_rss`](macro@crate::assert_command_max_rss)
+//! * [`assert_command_max_rss_as_result`](macro@crate::assert_command_max_rss_as_result)
+//! * [`debug_assert_command_max_rss`](macro@crate::debug_assert_command_max_rss)
+
+/// Run a command, wait for it, and return its maximum resident set size in kilobytes.
+///
+/// This function is an implementation detail of the macro
+/// [`assert_command_max_rss`](macro@crate::assert_command_max_rss).
+///
+/// This function uses the system call `wait4` rather than `Child::wait`,
+/// because `wait4` provides the resource usage of this specific child.
+#[doc(hidden)]
+pub fn command_max_rss_kilobytes(command: &mut ::std::process::Command) -> ::std::io::Result<u64> {
+    let mut child = command
+        .stdin(::std::process::Stdio::null())
+        .stdout(::std::process::Stdio::null())
+        .stderr(::std::process::Stdio::null())
+        .spawn()?;
+    let pid = child.id() as ::libc::pid_t;
+    let mut status: ::libc::c_int = 0;
+    // SAFETY: `rusage` is a plain C struct, for which all zeros is a valid value.
+    let mut rusage: ::libc::rusage = unsafe { ::std::mem::zeroed() };
+    loop {
+        // SAFETY: the pid is our own unwaited child, and the pointers are valid for the call.
+        let result = unsafe { ::libc::wait4(pid, &mut status, 0, &mut rusage) };
+        if result == pid {
+            break;
+        }
+        let err = ::std::io::Error::last_os_error();
+        if err.kind() != ::std::io::ErrorKind::Interrupted {
+            // Reap the child, so a failed measurement does not leave a zombie.
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(err);
+        }
+    }
+    let max_rss = rusage.ru_maxrss.max(0) as u64;
+    if cfg!(target_os = "macos") {
+        Ok(max_rss / 1024)
+    } else {
+        Ok(max_rss)
+    }
+}
+
+/// Assert a command runs within a peak memory limit, measured as maximum resident set size.
+///
+/// Pseudocode:<br>
+/// (command ⇒ spawn ⇒ wait ⇒ max rss in kilobytes) ≤ kilobytes
+///
+/// * If true, return Result `Ok(max rss in kilobytes)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_max_rss`](macro@crate::assert_command_max_rss)
+/// * [`assert_command_max_rss_as_result`](macro@crate::assert_command_max_rss_as_result)
+/// * [`debug_assert_command_max_rss`](macro@crate::debug_assert_command_max_rss)
+///
+#[macro_export]
+macro_rules! assert_command_max_rss_as_result {
+    ($command:expr, $kilobytes:expr $(,)?) => {{
+        match (&$kilobytes) {
+            kilobytes => {
+                match $crate::assert_command::assert_command_max_rss::command_max_rss_kilobytes(&mut $command) {
+                    Ok(max_rss) => {
+                        if max_rss <= *kilobytes {
+                            Ok(max_rss)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_max_rss!(command, kilobytes)`\n",
+                                        $crate::assertables_url_line!("assert_command_max_rss"),
+                                        "   command label: `{}`,\n",
+                                        "   command debug: `{:?}`,\n",
+                                        " kilobytes label: `{}`,\n",
+                                        " kilobytes debug: `{:?}`,\n",
+                                        "   max rss in kb: `{:?}`"
+                                    ),
+                                    stringify!($command),
+                                    $command,
+                                    stringify!($kilobytes),
+                                    kilobytes,
+                                    max_rss
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_max_rss!(command, kilobytes)`\n",
+                                    $crate::assertables_url_line!("assert_command_max_rss"),
+                                    "   command label: `{}`,\n",
+                                    "   command debug: `{:?}`,\n",
+                                    " kilobytes label: `{}`,\n",
+                                    " kilobytes debug: `{:?}`,\n",
+                                    "    spawn is err: `{:?}`"
+                                ),
+                                stringify!($command),
+                                $command,
+                                stringify!($kilobytes),
+                                kilobytes,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_max_rss_as_result {
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let kilobytes = 1_000_000;
+        let actual = assert_command_max_rss_as_result!(command, kilobytes);
+        let max_rss = actual.unwrap();
+        assert!(max_rss > 0);
+        assert!(max_rss <= 1_000_000);
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let kilobytes = 1;
+        let actual = assert_command_max_rss_as_result!(command, kilobytes);
+        let message = concat!(
+            "assertion failed: `assert_command_max_rss!(command, kilobytes)`\n",
+            assertables_url_line!("assert_command_max_rss"),
+            "   command label: `command`,\n",
+            "   command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+            " kilobytes label: `kilobytes`,\n",
+            " kilobytes debug: `1`,\n",
+            "   max rss in kb: `"
+        );
+        assert!(actual.unwrap_err().starts_with(message));
+    }
+}
+
+/// Assert a command runs within a peak memory limit, measured as maximum resident set size.
+///
+/// Pseudocode:<br>
+/// (command ⇒ spawn ⇒ wait ⇒ max rss in kilobytes) ≤ kilobytes
+///
+/// * If true, return `max rss in kilobytes`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(any(target_os = "linux", target_os = "macos"))] {
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let kilobytes = 1_000_000;
+/// assert_command_max_rss!(command, kilobytes);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let kilobytes = 1;
+/// assert_command_max_rss!(command, kilobytes);
+/// # });
+/// // assertion failed: `assert_command_max_rss!(command, kilobytes)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_max_rss.html
+/// //    command label: `command`,
+/// //    command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,
+/// //  kilobytes label: `kilobytes`,
+/// //  kilobytes debug: `1`,
+/// //    max rss in kb: `1234`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_command_max_rss!(command, kilobytes)`\n",
+/// #     assertables_url_line!("assert_command_max_rss"),
+/// #     "   command label: `command`,\n",
+/// #     "   command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+/// #     " kilobytes label: `kilobytes`,\n",
+/// #     " kilobytes debug: `1`,\n",
+/// #     "   max rss in kb: `"
+/// # );
+/// # assert!(actual.starts_with(message));
+/// # }
+/// # }
+/// ```
+///
+/// This macro is for catching memory regressions in command line tools.
+///
+/// Platform limitation: this macro is available only on Unix, because it
+/// uses the system call `wait4` to collect the resource usage of the child
+/// process, which includes `ru_maxrss`, the peak resident set size.
+///
+/// Accuracy caveats:
+///
+/// * The measurement is the peak resident set size, which is the peak amount
+///   of physical memory, not the peak amount of virtual memory.
+///
+/// * The measurement is of the command process itself, not of any child
+///   process that the command spawns in turn.
+///
+/// * The measurement is reported in kilobytes, rounded down. On macOS, the
+///   operating system reports bytes, which this macro converts to kilobytes.
+///
+/// * The measurement includes the memory used by the program loader and
+///   shared libraries, so even a tiny command uses some hundreds of kilobytes.
+///
+/// * The command stdout and stderr are discarded.
+///
+/// # Module macros
+///
+/// * [`assert_command_max_rss`](macro@crate::assert_command_max_rss)
+/// * [`assert_command_max_rss_as_result`](macro@crate::assert_command_max_rss_as_result)
+/// * [`debug_assert_command_max_rss`](macro@crate::debug_assert_command_max_rss)
+///
+#[macro_export]
+macro_rules! assert_command_max_rss {
+    ($command:expr, $kilobytes:expr $(,)?) => {{
+        match $crate::assert_command_max_rss_as_result!($command, $kilobytes) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($command:expr, $kilobytes:expr, $($message:tt)+) => {{
+        match $crate::assert_command_max_rss_as_result!($command, $kilobytes) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_max_rss {
+    use std::panic;
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let kilobytes = 1_000_000;
+        let actual = assert_command_max_rss!(command, kilobytes);
+        assert!(actual > 0);
+        assert!(actual <= 1_000_000);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut command = Command::new("bin/printf-stdout");
+            command.args(["%s", "alfa"]);
+            let kilobytes = 1;
+            let _actual = assert_command_max_rss!(command, kilobytes);
+        });
+        let message = concat!(
+            "assertion failed: `assert_command_max_rss!(command, kilobytes)`\n",
+            assertables_url_line!("assert_command_max_rss"),
+            "   command label: `command`,\n",
+            "   command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+            " kilobytes label: `kilobytes`,\n",
+            " kilobytes debug: `1`,\n",
+            "   max rss in kb: `"
+        );
+        assert!(result
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap()
+            .to_string()
+            .starts_with(message));
+    }
+}
+
+/// Assert a command runs within a peak memory limit, measured as maximum resident set size.
+///
+/// Pseudocode:<br>
+/// (command ⇒ spawn ⇒ wait ⇒ max rss in kilobytes) ≤ kilobytes
+///
+/// This macro provides the same statements as [`assert_command_max_rss`](macro.assert_command_max_rss.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_max_rss`](macro@crate::assert_command_max_rss)
+/// * [`assert_command_max_rss_as_result`](macro@crate::assert_command_max_rss_as_result)
+/// * [`debug_assert_command_max_rss`](macro@crate::debug_assert_command_max_rss)
+///
+#[macro_export]
+macro_rules! debug_assert_command_max_rss {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_command_max_rss!($($arg)*);
+        }
+    };
+}
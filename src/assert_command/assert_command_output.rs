@@ -0,0 +1,304 @@
+//! Assert a command runs and returns its output.
+//!
+//! Pseudocode:<br>
+//! command ⇒ output
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! assert_command_output!(command);
+//! ```
+//!
+//! This macro runs the command once, and returns its output, which is a
+//! [`std::process::Output`](https://doc.rust-lang.org/std/process/struct.Output.html).
+//! The assertion fails only when the command cannot run, such as when the
+//! program is not found; the assertion does not check the exit status.
+//!
+//! You can pass the output to the command macros, such as
+//! [`assert_command_stdout_eq_x`](macro@crate::assert_command_stdout_eq_x),
+//! and to the status macros, such as
+//! [`assert_status_success`](macro@crate::assert_status_success).
+//! These macros check the captured output, without running the command again,
+//! which helps for a command that is slow, costly, or flaky.
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let output = assert_command_output!(command);
+//! assert_status_success!(output);
+//! assert_command_stdout_eq_x!(output, b"alfa");
+//! assert_command_stderr_eq_x!(output, b"");
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_command_output`](macro@crate::assert_command_output)
+//! * [`assert_command_output_as_result`](macro@crate::assert_command_output_as_result)
+//! * [`debug_assert_command_output`](macro@crate::debug_assert_command_output)
+
+/// Assert a command runs and returns its output.
+///
+/// Pseudocode:<br>
+/// command ⇒ output
+///
+/// * If true, return Result `Ok(output)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_output`](macro@crate::assert_command_output)
+/// * [`assert_command_output_as_result`](macro@crate::assert_command_output_as_result)
+/// * [`debug_assert_command_output`](macro@crate::debug_assert_command_output)
+///
+#[macro_export]
+macro_rules! assert_command_output_as_result {
+    ($command:expr $(,)?) => {{
+        match $command.output() {
+            Ok(output) => Ok(output),
+            Err(err) => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_command_output!(command)`\n",
+                            $crate::assertables_url_line!("assert_command_output"),
+                            " command label: `{}`,\n",
+                            " command debug: `{:?}`,\n",
+                            "    output err: `{:?}`"
+                        ),
+                        stringify!($command),
+                        $command,
+                        err
+                    )
+                )
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_output_as_result {
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let actual = assert_command_output_as_result!(command);
+        assert_eq!(actual.unwrap(), command.output().unwrap());
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/nonexistent");
+        let actual = assert_command_output_as_result!(command);
+        let message = concat!(
+            "assertion failed: `assert_command_output!(command)`\n",
+            assertables_url_line!("assert_command_output"),
+            " command label: `command`,\n",
+            " command debug: `\"bin/nonexistent\"`,\n",
+            "    output err: `Os { code: 2, kind: NotFound, message: \"No such file or directory\" }`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_then_check_output_without_running_again() {
+        let mut command = Command::new("bin/printf-stdout-stderr");
+        command.args(["alfa", "bravo"]);
+        let output = assert_command_output_as_result!(command).unwrap();
+        assert!(crate::assert_status_success_as_result!(output).is_ok());
+        assert!(crate::assert_status_code_value_eq_x_as_result!(output, 0).is_ok());
+        assert!(crate::assert_command_stdout_eq_x_as_result!(output, b"alfa").is_ok());
+        assert!(crate::assert_command_stdout_string_eq_x_as_result!(output, "alfa").is_ok());
+        assert!(crate::assert_command_stderr_eq_x_as_result!(output, b"bravo").is_ok());
+        assert!(crate::assert_command_output_eq_x_as_result!(output, b"alfabravo").is_ok());
+    }
+
+    #[test]
+    fn success_with_failing_status() {
+        let mut command = Command::new("bin/exit-with-arg");
+        command.arg("1");
+        let output = assert_command_output_as_result!(command).unwrap();
+        assert!(crate::assert_status_success_false_as_result!(output).is_ok());
+        assert!(crate::assert_status_code_value_eq_x_as_result!(output, 1).is_ok());
+    }
+}
+
+/// Assert a command runs and returns its output.
+///
+/// Pseudocode:<br>
+/// command ⇒ output
+///
+/// * If true, return `output`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// assert_command_output!(command);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/nonexistent");
+/// assert_command_output!(command);
+/// # });
+/// // assertion failed: `assert_command_output!(command)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_output.html
+/// //  command label: `command`,
+/// //  command debug: `\"bin/nonexistent\"`,
+/// //     output err: `Os { code: 2, kind: NotFound, message: \"No such file or directory\" }`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_command_output!(command)`\n",
+/// #     assertables_url_line!("assert_command_output"),
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"bin/nonexistent\"`,\n",
+/// #     "    output err: `Os { code: 2, kind: NotFound, message: \"No such file or directory\" }`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro runs the command once, and returns its output, which is a
+/// [`std::process::Output`](https://doc.rust-lang.org/std/process/struct.Output.html).
+/// The assertion fails only when the command cannot run, such as when the
+/// program is not found; the assertion does not check the exit status.
+///
+/// You can pass the output to the command macros, such as
+/// [`assert_command_stdout_eq_x`](macro@crate::assert_command_stdout_eq_x),
+/// and to the status macros, such as
+/// [`assert_status_success`](macro@crate::assert_status_success).
+/// These macros check the captured output, without running the command again,
+/// which helps for a command that is slow, costly, or flaky.
+///
+/// ```rust
+/// use assertables::*;
+/// use std::process::Command;
+///
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let output = assert_command_output!(command);
+/// assert_status_success!(output);
+/// assert_command_stdout_eq_x!(output, b"alfa");
+/// assert_command_stderr_eq_x!(output, b"");
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_command_output`](macro@crate::assert_command_output)
+/// * [`assert_command_output_as_result`](macro@crate::assert_command_output_as_result)
+/// * [`debug_assert_command_output`](macro@crate::debug_assert_command_output)
+///
+#[macro_export]
+macro_rules! assert_command_output {
+    ($command:expr $(,)?) => {{
+        match $crate::assert_command_output_as_result!($command) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($command:expr, $($message:tt)+) => {{
+        match $crate::assert_command_output_as_result!($command) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_output {
+    use std::panic;
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let actual = assert_command_output!(command);
+        assert_eq!(actual, command.output().unwrap());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut command = Command::new("bin/nonexistent");
+            let _actual = assert_command_output!(command);
+        });
+        let message = concat!(
+            "assertion failed: `assert_command_output!(command)`\n",
+            assertables_url_line!("assert_command_output"),
+            " command label: `command`,\n",
+            " command debug: `\"bin/nonexistent\"`,\n",
+            "    output err: `Os { code: 2, kind: NotFound, message: \"No such file or directory\" }`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a command runs and returns its output.
+///
+/// Pseudocode:<br>
+/// command ⇒ output
+///
+/// This macro provides the same statements as [`assert_command_output`](macro.assert_command_output.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_output`](macro@crate::assert_command_output)
+/// * [`assert_command_output_as_result`](macro@crate::assert_command_output_as_result)
+/// * [`debug_assert_command_output`](macro@crate::debug_assert_command_output)
+///
+#[macro_export]
+macro_rules! debug_assert_command_output {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_command_output!($($arg)*);
+        }
+    };
+}
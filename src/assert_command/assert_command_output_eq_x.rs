@@ -0,0 +1,340 @@
+//! Assert a command combined stdout and stderr is equal to an expression, as bytes.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ output ⇒ stdout + stderr) = (expr as bytes)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! let mut command = Command::new("bin/printf-stdout-stderr");
+//! command.args(["ab", "cd"]);
+//! let bytes = b"abcd";
+//! assert_command_output_eq_x!(command, bytes);
+//! ```
+//!
+//! This macro runs the command once, by calling `output()` one time,
+//! then concatenates the stdout bytes and then the stderr bytes.
+//!
+//! The streams are captured separately, so the combined bytes are always
+//! all of stdout followed by all of stderr, rather than the order in which
+//! the command happened to interleave its writes.
+//!
+//! The failure message shows the bytes of each stream, then the combined bytes.
+//!
+//! # Module macros
+//!
+//! * [`assert_command_output_eq_x`](macro@crate::assert_command_output_eq_x)
+//! * [`assert_command_output_eq_x_as_result`](macro@crate::assert_command_output_eq_x_as_result)
+//! * [`debug_assert_command_output_eq_x`](macro@crate::debug_assert_command_output_eq_x)
+
+/// Assert a command combined stdout and stderr is equal to an expression, as bytes.
+///
+/// Pseudocode:<br>
+/// (command ⇒ output ⇒ stdout + stderr) = (expr as bytes)
+///
+/// * If true, return Result `Ok(stdout + stderr)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_output_eq_x`](macro@crate::assert_command_output_eq_x)
+/// * [`assert_command_output_eq_x_as_result`](macro@crate::assert_command_output_eq_x_as_result)
+/// * [`debug_assert_command_output_eq_x`](macro@crate::debug_assert_command_output_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_output_eq_x_as_result {
+    ($command:expr, $expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
+        match (/*&$command,*/ &$expr) {
+            expr => {
+                match $command.output() {
+                    Ok(output) => {
+                        let expr_bytes: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(expr);
+                        let mut combined = output.stdout.clone();
+                        combined.extend_from_slice(&output.stderr);
+                        if combined.as_slice() == expr_bytes {
+                            Ok(combined)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_output_eq_x!(command, expr)`\n",
+                                        $crate::assertables_url_line!("assert_command_output_eq_x"),
+                                        "  command label: `{}`,\n",
+                                        "  command debug: `{:?}`,\n",
+                                        "     expr label: `{}`,\n",
+                                        "     expr debug: `{:?}`,\n",
+                                        "   stdout value: `{:?}`,\n",
+                                        "   stderr value: `{:?}`,\n",
+                                        " combined value: `{:?}`,\n",
+                                        "     expr value: `{:?}`"
+                                    ),
+                                    stringify!($command),
+                                    $command,
+                                    stringify!($expr),
+                                    expr,
+                                    output.stdout,
+                                    output.stderr,
+                                    combined,
+                                    expr_bytes
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_output_eq_x!(command, expr)`\n",
+                                    $crate::assertables_url_line!("assert_command_output_eq_x"),
+                                    "  command label: `{}`,\n",
+                                    "  command debug: `{:?}`,\n",
+                                    "     expr label: `{}`,\n",
+                                    "     expr debug: `{:?}`,\n",
+                                    "     output err: `{:?}`"
+                                ),
+                                stringify!($command),
+                                $command,
+                                stringify!($expr),
+                                expr,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_output_eq_x_as_result {
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout-stderr");
+        command.args(["ab", "cd"]);
+        let bytes = b"abcd";
+        let actual = assert_command_output_eq_x_as_result!(command, bytes);
+        assert_eq!(actual.unwrap(), b"abcd".to_vec());
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout-stderr");
+        command.args(["ab", "cd"]);
+        let bytes = b"cdab";
+        let actual = assert_command_output_eq_x_as_result!(command, bytes);
+        let message = concat!(
+            "assertion failed: `assert_command_output_eq_x!(command, expr)`\n",
+            assertables_url_line!("assert_command_output_eq_x"),
+            "  command label: `command`,\n",
+            "  command debug: `\"bin/printf-stdout-stderr\" \"ab\" \"cd\"`,\n",
+            "     expr label: `bytes`,\n",
+            "     expr debug: `[99, 100, 97, 98]`,\n",
+            "   stdout value: `[97, 98]`,\n",
+            "   stderr value: `[99, 100]`,\n",
+            " combined value: `[97, 98, 99, 100]`,\n",
+            "     expr value: `[99, 100, 97, 98]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_runs_command_once() {
+        let path =
+            std::env::temp_dir().join(format!("assertables-output-eq-x-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut command = Command::new("sh");
+        command.args([
+            "-c",
+            &format!("printf ab; printf cd >&2; printf x >> {}", path.display()),
+        ]);
+        let actual = assert_command_output_eq_x_as_result!(command, b"abcd");
+        assert!(actual.is_ok());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "x");
+        let _ = std::fs::remove_file(&path);
+    }
+}
+
+/// Assert a command combined stdout and stderr is equal to an expression, as bytes.
+///
+/// Pseudocode:<br>
+/// (command ⇒ output ⇒ stdout + stderr) = (expr as bytes)
+///
+/// * If true, return `stdout + stderr`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout-stderr");
+/// command.args(["ab", "cd"]);
+/// let bytes = b"abcd";
+/// assert_command_output_eq_x!(command, bytes);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout-stderr");
+/// command.args(["ab", "cd"]);
+/// let bytes = b"cdab";
+/// assert_command_output_eq_x!(command, bytes);
+/// # });
+/// // assertion failed: `assert_command_output_eq_x!(command, expr)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_output_eq_x.html
+/// //   command label: `command`,
+/// //   command debug: `\"bin/printf-stdout-stderr\" \"ab\" \"cd\"`,
+/// //      expr label: `bytes`,
+/// //      expr debug: `[99, 100, 97, 98]`,
+/// //    stdout value: `[97, 98]`,
+/// //    stderr value: `[99, 100]`,
+/// //  combined value: `[97, 98, 99, 100]`,
+/// //      expr value: `[99, 100, 97, 98]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_command_output_eq_x!(command, expr)`\n",
+/// #     assertables_url_line!("assert_command_output_eq_x"),
+/// #     "  command label: `command`,\n",
+/// #     "  command debug: `\"bin/printf-stdout-stderr\" \"ab\" \"cd\"`,\n",
+/// #     "     expr label: `bytes`,\n",
+/// #     "     expr debug: `[99, 100, 97, 98]`,\n",
+/// #     "   stdout value: `[97, 98]`,\n",
+/// #     "   stderr value: `[99, 100]`,\n",
+/// #     " combined value: `[97, 98, 99, 100]`,\n",
+/// #     "     expr value: `[99, 100, 97, 98]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro runs the command once, by calling `output()` one time,
+/// then concatenates the stdout bytes and then the stderr bytes.
+///
+/// The streams are captured separately, so the combined bytes are always
+/// all of stdout followed by all of stderr, rather than the order in which
+/// the command happened to interleave its writes.
+///
+/// The failure message shows the bytes of each stream, then the combined bytes.
+///
+/// # Module macros
+///
+/// * [`assert_command_output_eq_x`](macro@crate::assert_command_output_eq_x)
+/// * [`assert_command_output_eq_x_as_result`](macro@crate::assert_command_output_eq_x_as_result)
+/// * [`debug_assert_command_output_eq_x`](macro@crate::debug_assert_command_output_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_output_eq_x {
+    ($command:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_command_output_eq_x_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($command:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_output_eq_x_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_output_eq_x {
+    use std::panic;
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout-stderr");
+        command.args(["ab", "cd"]);
+        let bytes = b"abcd";
+        let actual = assert_command_output_eq_x!(command, bytes);
+        assert_eq!(actual, b"abcd".to_vec());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut command = Command::new("bin/printf-stdout-stderr");
+            command.args(["ab", "cd"]);
+            let bytes = b"cdab";
+            let _actual = assert_command_output_eq_x!(command, bytes);
+        });
+        let message = concat!(
+            "assertion failed: `assert_command_output_eq_x!(command, expr)`\n",
+            assertables_url_line!("assert_command_output_eq_x"),
+            "  command label: `command`,\n",
+            "  command debug: `\"bin/printf-stdout-stderr\" \"ab\" \"cd\"`,\n",
+            "     expr label: `bytes`,\n",
+            "     expr debug: `[99, 100, 97, 98]`,\n",
+            "   stdout value: `[97, 98]`,\n",
+            "   stderr value: `[99, 100]`,\n",
+            " combined value: `[97, 98, 99, 100]`,\n",
+            "     expr value: `[99, 100, 97, 98]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a command combined stdout and stderr is equal to an expression, as bytes.
+///
+/// Pseudocode:<br>
+/// (command ⇒ output ⇒ stdout + stderr) = (expr as bytes)
+///
+/// This macro provides the same statements as [`assert_command_output_eq_x`](macro.assert_command_output_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_output_eq_x`](macro@crate::assert_command_output_eq_x)
+/// * [`assert_command_output_eq_x_as_result`](macro@crate::assert_command_output_eq_x_as_result)
+/// * [`debug_assert_command_output_eq_x`](macro@crate::debug_assert_command_output_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_command_output_eq_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_command_output_eq_x!($($arg)*);
+        }
+    };
+}
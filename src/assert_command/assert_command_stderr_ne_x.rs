@@ -42,6 +42,8 @@
 #[macro_export]
 macro_rules! assert_command_stderr_ne_x_as_result {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
         match (/*&$command,*/ &$b_expr) {
             b => {
                 match $a_command.output() {
@@ -54,7 +56,7 @@ macro_rules! assert_command_stderr_ne_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_command_stderr_ne_x!(command, expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_ne_x.html\n",
+                                        $crate::assertables_url_line!("assert_command_stderr_ne_x"),
                                         " command label: `{}`,\n",
                                         " command debug: `{:?}`,\n",
                                         "    expr label: `{}`,\n",
@@ -77,7 +79,7 @@ macro_rules! assert_command_stderr_ne_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_command_stderr_ne_x!(command, expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_ne_x.html\n",
+                                    $crate::assertables_url_line!("assert_command_stderr_ne_x"),
                                     "  command label: `{}`,\n",
                                     "  command debug: `{:?}`,\n",
                                     "     expr label: `{}`,\n",
@@ -128,7 +130,7 @@ mod test_assert_command_stderr_ne_x_as_result {
         let actual = assert_command_stderr_ne_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stderr_ne_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_ne_x.html\n",
+            assertables_url_line!("assert_command_stderr_ne_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stderr\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
@@ -181,7 +183,7 @@ mod test_assert_command_stderr_ne_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_command_stderr_ne_x!(command, expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_ne_x.html\n",
+/// #     assertables_url_line!("assert_command_stderr_ne_x"),
 /// #     " command label: `command`,\n",
 /// #     " command debug: `\"bin/printf-stderr\" \"%s\" \"alfa\"`,\n",
 /// #     "    expr label: `bytes`,\n",
@@ -204,13 +206,13 @@ macro_rules! assert_command_stderr_ne_x {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_command_stderr_ne_x_as_result!($a_command, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_command:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_command_stderr_ne_x_as_result!($a_command, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -248,7 +250,7 @@ mod test_assert_command_stderr_ne_x {
         });
         let message = concat!(
             "assertion failed: `assert_command_stderr_ne_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_ne_x.html\n",
+            assertables_url_line!("assert_command_stderr_ne_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stderr\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
@@ -301,7 +303,7 @@ mod test_assert_command_stderr_ne_x {
 #[macro_export]
 macro_rules! debug_assert_command_stderr_ne_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_command_stderr_ne_x!($($arg)*);
         }
     };
@@ -43,6 +43,8 @@
 #[macro_export]
 macro_rules! assert_command_stderr_string_is_match_as_result {
     ($command:expr, $matcher:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
         match (/*&$command,*/ &$matcher) {
             matcher => {
                 match $command.output() {
@@ -55,7 +57,7 @@ macro_rules! assert_command_stderr_string_is_match_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_command_stderr_string_is_match!(command, matcher)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_string_is_match.html\n",
+                                        $crate::assertables_url_line!("assert_command_stderr_string_is_match"),
                                         " command label: `{}`,\n",
                                         " command debug: `{:?}`,\n",
                                         " matcher label: `{}`,\n",
@@ -78,7 +80,7 @@ macro_rules! assert_command_stderr_string_is_match_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_command_stderr_string_is_match!(command, matcher)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_string_is_match.html\n",
+                                    $crate::assertables_url_line!("assert_command_stderr_string_is_match"),
                                     "  command label: `{}`,\n",
                                     "  command debug: `{:?}`,\n",
                                     "  matcher label: `{}`,\n",
@@ -121,7 +123,7 @@ mod test_assert_command_stderr_string_is_match_as_result {
         let actual = assert_command_stderr_string_is_match_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stderr_string_is_match!(command, matcher)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_string_is_match.html\n",
+            assertables_url_line!("assert_command_stderr_string_is_match"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stderr\" \"%s\" \"alfa\"`,\n",
             " matcher label: `b`,\n",
@@ -175,7 +177,7 @@ mod test_assert_command_stderr_string_is_match_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_command_stderr_string_is_match!(command, matcher)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_string_is_match.html\n",
+/// #     assertables_url_line!("assert_command_stderr_string_is_match"),
 /// #     " command label: `command`,\n",
 /// #     " command debug: `\"bin/printf-stderr\" \"%s\" \"alfa\"`,\n",
 /// #     " matcher label: `&matcher`,\n",
@@ -198,13 +200,13 @@ macro_rules! assert_command_stderr_string_is_match {
     ($command:expr, $matcher:expr $(,)?) => {{
         match $crate::assert_command_stderr_string_is_match_as_result!($command, $matcher) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($command:expr, $matcher:expr, $($message:tt)+) => {{
         match $crate::assert_command_stderr_string_is_match_as_result!($command, $matcher) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -234,7 +236,7 @@ mod test_assert_command_stderr_string_is_match {
         });
         let message = concat!(
             "assertion failed: `assert_command_stderr_string_is_match!(command, matcher)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stderr_string_is_match.html\n",
+            assertables_url_line!("assert_command_stderr_string_is_match"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stderr\" \"%s\" \"alfa\"`,\n",
             " matcher label: `b`,\n",
@@ -287,7 +289,7 @@ mod test_assert_command_stderr_string_is_match {
 #[macro_export]
 macro_rules! debug_assert_command_stderr_string_is_match {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_command_stderr_string_is_match!($($arg)*);
         }
     };
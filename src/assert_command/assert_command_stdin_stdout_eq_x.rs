@@ -0,0 +1,401 @@
+//! Assert a command, given stdin bytes, has stdout equal to an expression, as bytes.
+//!
+//! Pseudocode:<br>
+//! (command with stdin ⇒ stdout) = (expr as bytes)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! let mut command = Command::new("cat");
+//! let stdin = "alfa";
+//! let bytes = b"alfa";
+//! assert_command_stdin_stdout_eq_x!(command, stdin, bytes);
+//! ```
+//!
+//! This macro sets the command stdin to a pipe, writes the stdin bytes
+//! to the pipe, closes the pipe, then compares the command stdout with
+//! the expression, the same way as
+//! [`assert_command_stdout_eq_x`](macro@crate::assert_command_stdout_eq_x).
+//!
+//! * The stdin can be anything that implements `AsRef<[u8]>`, such as
+//!   a `Vec<u8>`, a `&[u8]`, a `&str`, or a byte string literal.
+//!
+//! * The stdin bytes are written on a separate thread, while this thread
+//!   reads the stdout and stderr, so large input and output do not deadlock.
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdin_stdout_eq_x`](macro@crate::assert_command_stdin_stdout_eq_x)
+//! * [`assert_command_stdin_stdout_eq_x_as_result`](macro@crate::assert_command_stdin_stdout_eq_x_as_result)
+//! * [`debug_assert_command_stdin_stdout_eq_x`](macro@crate::debug_assert_command_stdin_stdout_eq_x)
+
+/// Run a command, write bytes to its stdin, and return its output.
+///
+/// This function is an implementation detail of the macro
+/// [`assert_command_stdin_stdout_eq_x`](macro@crate::assert_command_stdin_stdout_eq_x).
+///
+/// The stdin bytes are written on a separate thread, then the pipe is closed,
+/// while this thread reads the stdout and stderr, so neither side blocks.
+/// A command may exit without reading all its stdin, so a write error such
+/// as a broken pipe is ignored.
+#[doc(hidden)]
+pub fn command_output_with_stdin(
+    command: &mut ::std::process::Command,
+    stdin: &[u8],
+) -> ::std::io::Result<::std::process::Output> {
+    use ::std::io::Write;
+    use ::std::process::Stdio;
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let writer = child.stdin.take().map(|mut pipe| {
+        let bytes = stdin.to_vec();
+        ::std::thread::spawn(move || {
+            let _ = pipe.write_all(&bytes);
+        })
+    });
+    let output = child.wait_with_output();
+    if let Some(writer) = writer {
+        let _ = writer.join();
+    }
+    output
+}
+
+/// Assert a command, given stdin bytes, has stdout equal to an expression, as bytes.
+///
+/// Pseudocode:<br>
+/// (command with stdin ⇒ stdout) = (expr as bytes)
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdin_stdout_eq_x`](macro@crate::assert_command_stdin_stdout_eq_x)
+/// * [`assert_command_stdin_stdout_eq_x_as_result`](macro@crate::assert_command_stdin_stdout_eq_x_as_result)
+/// * [`debug_assert_command_stdin_stdout_eq_x`](macro@crate::debug_assert_command_stdin_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdin_stdout_eq_x_as_result {
+    ($command:expr, $stdin:expr, $expr:expr $(,)?) => {{
+        match (/*&$command,*/ &$stdin, &$expr) {
+            (stdin, expr) => {
+                let stdin_bytes: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(stdin);
+                match $crate::assert_command::assert_command_stdin_stdout_eq_x::command_output_with_stdin(&mut $command, stdin_bytes) {
+                    Ok(output) => {
+                        let a = output.stdout;
+                        let b_bytes: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(expr);
+                        if a.as_slice() == b_bytes {
+                            Ok(a)
+                        } else {
+                            let (index, a_region, b_region) = $crate::bytes_diff::bytes_diff_region(&a, b_bytes, $crate::bytes_diff::escape_ascii);
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_stdin_stdout_eq_x!(command, stdin, expr)`\n",
+                                        $crate::assertables_url_line!("assert_command_stdin_stdout_eq_x"),
+                                        " command label: `{}`,\n",
+                                        " command debug: `{:?}`,\n",
+                                        "   stdin label: `{}`,\n",
+                                        "   stdin debug: `{:?}`,\n",
+                                        "    expr label: `{}`,\n",
+                                        "    expr debug: `{:?}`,\n",
+                                        " command value: `{:?}`,\n",
+                                        "    expr value: `{:?}`,\n",
+                                        "    diff index: `{}`,\n",
+                                        "  command diff: `{}`,\n",
+                                        "     expr diff: `{}`"
+                                    ),
+                                    stringify!($command),
+                                    $command,
+                                    stringify!($stdin),
+                                    stdin,
+                                    stringify!($expr),
+                                    expr,
+                                    a,
+                                    b_bytes,
+                                    index,
+                                    a_region,
+                                    b_region
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdin_stdout_eq_x!(command, stdin, expr)`\n",
+                                    $crate::assertables_url_line!("assert_command_stdin_stdout_eq_x"),
+                                    " command label: `{}`,\n",
+                                    " command debug: `{:?}`,\n",
+                                    "   stdin label: `{}`,\n",
+                                    "   stdin debug: `{:?}`,\n",
+                                    "    expr label: `{}`,\n",
+                                    "    expr debug: `{:?}`,\n",
+                                    "    output err: `{:?}`"
+                                ),
+                                stringify!($command),
+                                $command,
+                                stringify!($stdin),
+                                stdin,
+                                stringify!($expr),
+                                expr,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdin_stdout_eq_x_as_result {
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("cat");
+        let stdin = "alfa";
+        let bytes = b"alfa";
+        let actual = assert_command_stdin_stdout_eq_x_as_result!(command, stdin, bytes);
+        assert_eq!(actual.unwrap(), b"alfa".to_vec());
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("cat");
+        let stdin = "alfa";
+        let bytes = b"zz";
+        let actual = assert_command_stdin_stdout_eq_x_as_result!(command, stdin, bytes);
+        let message = concat!(
+            "assertion failed: `assert_command_stdin_stdout_eq_x!(command, stdin, expr)`\n",
+            assertables_url_line!("assert_command_stdin_stdout_eq_x"),
+            " command label: `command`,\n",
+            " command debug: `\"cat\"`,\n",
+            "   stdin label: `stdin`,\n",
+            "   stdin debug: `\"alfa\"`,\n",
+            "    expr label: `bytes`,\n",
+            "    expr debug: `[122, 122]`,\n",
+            " command value: `[97, 108, 102, 97]`,\n",
+            "    expr value: `[122, 122]`,\n",
+            "    diff index: `0`,\n",
+            "  command diff: `alfa`,\n",
+            "     expr diff: `zz`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_large_input() {
+        let mut command = Command::new("cat");
+        let stdin: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+        let actual = assert_command_stdin_stdout_eq_x_as_result!(command, stdin, stdin);
+        assert_eq!(actual.unwrap().len(), 200_000);
+    }
+
+    #[test]
+    fn success_when_command_ignores_stdin() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let stdin = vec![b'x'; 200_000];
+        let actual = assert_command_stdin_stdout_eq_x_as_result!(command, stdin, b"alfa");
+        assert!(actual.is_ok());
+    }
+}
+
+/// Assert a command, given stdin bytes, has stdout equal to an expression, as bytes.
+///
+/// Pseudocode:<br>
+/// (command with stdin ⇒ stdout) = (expr as bytes)
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("cat");
+/// let stdin = "alfa";
+/// let bytes = b"alfa";
+/// assert_command_stdin_stdout_eq_x!(command, stdin, bytes);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("cat");
+/// let stdin = "alfa";
+/// let bytes = b"zz";
+/// assert_command_stdin_stdout_eq_x!(command, stdin, bytes);
+/// # });
+/// // assertion failed: `assert_command_stdin_stdout_eq_x!(command, stdin, expr)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdin_stdout_eq_x.html
+/// //  command label: `command`,
+/// //  command debug: `\"cat\"`,
+/// //    stdin label: `stdin`,
+/// //    stdin debug: `\"alfa\"`,
+/// //     expr label: `bytes`,
+/// //     expr debug: `[122, 122]`,
+/// //  command value: `[97, 108, 102, 97]`,
+/// //     expr value: `[122, 122]`,
+/// //     diff index: `0`,
+/// //   command diff: `alfa`,
+/// //      expr diff: `zz`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_command_stdin_stdout_eq_x!(command, stdin, expr)`\n",
+/// #     assertables_url_line!("assert_command_stdin_stdout_eq_x"),
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"cat\"`,\n",
+/// #     "   stdin label: `stdin`,\n",
+/// #     "   stdin debug: `\"alfa\"`,\n",
+/// #     "    expr label: `bytes`,\n",
+/// #     "    expr debug: `[122, 122]`,\n",
+/// #     " command value: `[97, 108, 102, 97]`,\n",
+/// #     "    expr value: `[122, 122]`,\n",
+/// #     "    diff index: `0`,\n",
+/// #     "  command diff: `alfa`,\n",
+/// #     "     expr diff: `zz`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro sets the command stdin to a pipe, writes the stdin bytes
+/// to the pipe, closes the pipe, then compares the command stdout with
+/// the expression, the same way as
+/// [`assert_command_stdout_eq_x`](macro@crate::assert_command_stdout_eq_x).
+///
+/// * The stdin can be anything that implements `AsRef<[u8]>`, such as
+///   a `Vec<u8>`, a `&[u8]`, a `&str`, or a byte string literal.
+///
+/// * The stdin bytes are written on a separate thread, while this thread
+///   reads the stdout and stderr, so large input and output do not deadlock.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdin_stdout_eq_x`](macro@crate::assert_command_stdin_stdout_eq_x)
+/// * [`assert_command_stdin_stdout_eq_x_as_result`](macro@crate::assert_command_stdin_stdout_eq_x_as_result)
+/// * [`debug_assert_command_stdin_stdout_eq_x`](macro@crate::debug_assert_command_stdin_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdin_stdout_eq_x {
+    ($command:expr, $stdin:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_command_stdin_stdout_eq_x_as_result!($command, $stdin, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($command:expr, $stdin:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdin_stdout_eq_x_as_result!($command, $stdin, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdin_stdout_eq_x {
+    use std::panic;
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("cat");
+        let stdin = "alfa";
+        let bytes = b"alfa";
+        let actual = assert_command_stdin_stdout_eq_x!(command, stdin, bytes);
+        assert_eq!(actual, b"alfa".to_vec());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut command = Command::new("cat");
+            let stdin = "alfa";
+            let bytes = b"zz";
+            let _actual = assert_command_stdin_stdout_eq_x!(command, stdin, bytes);
+        });
+        let message = concat!(
+            "assertion failed: `assert_command_stdin_stdout_eq_x!(command, stdin, expr)`\n",
+            assertables_url_line!("assert_command_stdin_stdout_eq_x"),
+            " command label: `command`,\n",
+            " command debug: `\"cat\"`,\n",
+            "   stdin label: `stdin`,\n",
+            "   stdin debug: `\"alfa\"`,\n",
+            "    expr label: `bytes`,\n",
+            "    expr debug: `[122, 122]`,\n",
+            " command value: `[97, 108, 102, 97]`,\n",
+            "    expr value: `[122, 122]`,\n",
+            "    diff index: `0`,\n",
+            "  command diff: `alfa`,\n",
+            "     expr diff: `zz`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a command, given stdin bytes, has stdout equal to an expression, as bytes.
+///
+/// Pseudocode:<br>
+/// (command with stdin ⇒ stdout) = (expr as bytes)
+///
+/// This macro provides the same statements as [`assert_command_stdin_stdout_eq_x`](macro.assert_command_stdin_stdout_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdin_stdout_eq_x`](macro@crate::assert_command_stdin_stdout_eq_x)
+/// * [`assert_command_stdin_stdout_eq_x_as_result`](macro@crate::assert_command_stdin_stdout_eq_x_as_result)
+/// * [`debug_assert_command_stdin_stdout_eq_x`](macro@crate::debug_assert_command_stdin_stdout_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdin_stdout_eq_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_command_stdin_stdout_eq_x!($($arg)*);
+        }
+    };
+}
@@ -1,7 +1,7 @@
-//! Assert a command stdout string is equal to an expression.
+//! Assert a command stdout is equal to an expression, as bytes.
 //!
 //! Pseudocode:<br>
-//! (command ⇒ stdout) = (expr into string)
+//! (command ⇒ stdout) = (expr as bytes)
 //!
 //! # Example
 //!
@@ -15,16 +15,25 @@
 //! assert_command_stdout_eq_x!(command, bytes);
 //! ```
 //!
+//! This macro compares the raw stdout bytes with the expression bytes.
+//! The expression can be anything that implements `AsRef<[u8]>`, such as
+//! a `Vec<u8>`, a `&[u8]`, or a byte string literal such as `b"alfa"`.
+//! To compare the stdout as a string, use
+//! [`assert_command_stdout_string_eq_x`](macro@crate::assert_command_stdout_string_eq_x).
+//!
+//! The failure message shows the index of the first differing byte,
+//! and the bytes from that index onward, escaped as ASCII.
+//!
 //! # Module macros
 //!
 //! * [`assert_command_stdout_eq_x`](macro@crate::assert_command_stdout_eq_x)
 //! * [`assert_command_stdout_eq_x_as_result`](macro@crate::assert_command_stdout_eq_x_as_result)
 //! * [`debug_assert_command_stdout_eq_x`](macro@crate::debug_assert_command_stdout_eq_x)
 
-/// Assert a command stdout string is equal to an expression.
+/// Assert a command stdout is equal to an expression, as bytes.
 ///
 /// Pseudocode:<br>
-/// (command ⇒ stdout) = (expr into string)
+/// (command ⇒ stdout) = (expr as bytes)
 ///
 /// * If true, return Result `Ok(stdout)`.
 ///
@@ -42,32 +51,42 @@
 #[macro_export]
 macro_rules! assert_command_stdout_eq_x_as_result {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
         match (/*&$command,*/ &$b_expr) {
             b => {
                 match $a_command.output() {
                     Ok(a) => {
                         let a = a.stdout;
-                        if a.eq(&$b_expr) {
+                        let b_bytes: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(b);
+                        if a.as_slice() == b_bytes {
                             Ok(a)
                         } else {
+                            let (index, a_region, b_region) = $crate::bytes_diff::bytes_diff_region(&a, b_bytes, $crate::bytes_diff::escape_ascii);
                             Err(
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_command_stdout_eq_x!(command, expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_eq_x.html\n",
+                                        $crate::assertables_url_line!("assert_command_stdout_eq_x"),
                                         " command label: `{}`,\n",
                                         " command debug: `{:?}`,\n",
                                         "    expr label: `{}`,\n",
                                         "    expr debug: `{:?}`,\n",
                                         " command value: `{:?}`,\n",
-                                        "    expr value: `{:?}`"
+                                        "    expr value: `{:?}`,\n",
+                                        "    diff index: `{}`,\n",
+                                        "  command diff: `{}`,\n",
+                                        "     expr diff: `{}`"
                                     ),
                                     stringify!($a_command),
                                     $a_command,
                                     stringify!($b_expr),
-                                    $b_expr,
+                                    b,
                                     a,
-                                    b
+                                    b_bytes,
+                                    index,
+                                    a_region,
+                                    b_region
                                 )
                             )
                         }
@@ -77,7 +96,7 @@ macro_rules! assert_command_stdout_eq_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_command_stdout_eq_x!(command, expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_eq_x.html\n",
+                                    $crate::assertables_url_line!("assert_command_stdout_eq_x"),
                                     "  command label: `{}`,\n",
                                     "  command debug: `{:?}`,\n",
                                     "     expr label: `{}`,\n",
@@ -119,13 +138,16 @@ mod test_assert_command_stdout_eq_x_as_result {
         let actual = assert_command_stdout_eq_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stdout_eq_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_eq_x.html\n",
+            assertables_url_line!("assert_command_stdout_eq_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
             "    expr debug: `[122, 122]`,\n",
             " command value: `[97, 108, 102, 97]`,\n",
-            "    expr value: `[122, 122]`"
+            "    expr value: `[122, 122]`,\n",
+            "    diff index: `0`,\n",
+            "  command diff: `alfa`,\n",
+            "     expr diff: `zz`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
@@ -138,22 +160,49 @@ mod test_assert_command_stdout_eq_x_as_result {
         let actual = assert_command_stdout_eq_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stdout_eq_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_eq_x.html\n",
+            assertables_url_line!("assert_command_stdout_eq_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
             "    expr debug: `[97, 97]`,\n",
             " command value: `[97, 108, 102, 97]`,\n",
-            "    expr value: `[97, 97]`"
+            "    expr value: `[97, 97]`,\n",
+            "    diff index: `1`,\n",
+            "  command diff: `lfa`,\n",
+            "     expr diff: `a`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn eq_with_byte_string_and_slice() {
+        let mut a = Command::new("bin/printf-stdout");
+        a.args(["%s", "alfa"]);
+        let actual = assert_command_stdout_eq_x_as_result!(a, b"alfa");
+        assert_eq!(actual.unwrap(), b"alfa".to_vec());
+        let b: &[u8] = &b"alfa"[..];
+        let actual = assert_command_stdout_eq_x_as_result!(a, b);
+        assert_eq!(actual.unwrap(), b.to_vec());
+    }
+
+    #[test]
+    fn ne_with_escaped_diff() {
+        let mut a = Command::new("bin/printf-stdout");
+        a.args(["%s", "alfa\n"]);
+        let actual = assert_command_stdout_eq_x_as_result!(a, b"alfa\t");
+        let message = actual.unwrap_err();
+        assert!(message.ends_with(concat!(
+            "    diff index: `4`,\n",
+            "  command diff: `\\n`,\n",
+            "     expr diff: `\\t`"
+        )));
+    }
 }
 
-/// Assert a command stdout string is equal to an expression.
+/// Assert a command stdout is equal to an expression, as bytes.
 ///
 /// Pseudocode:<br>
-/// (command ⇒ stdout) = (expr into string)
+/// (command ⇒ stdout) = (expr as bytes)
 ///
 /// * If true, return `(stdout)`.
 ///
@@ -187,17 +236,23 @@ mod test_assert_command_stdout_eq_x_as_result {
 /// //     expr label: `bytes`,
 /// //     expr debug: `[122, 122]`,
 /// //  command value: `[97, 108, 102, 97]`,
-/// //     expr value: `[122, 122]`
+/// //     expr value: `[122, 122]`,
+/// //     diff index: `0`,
+/// //   command diff: `alfa`,
+/// //      expr diff: `zz`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_command_stdout_eq_x!(command, expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_eq_x.html\n",
+/// #     assertables_url_line!("assert_command_stdout_eq_x"),
 /// #     " command label: `command`,\n",
 /// #     " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
 /// #     "    expr label: `bytes`,\n",
 /// #     "    expr debug: `[122, 122]`,\n",
 /// #     " command value: `[97, 108, 102, 97]`,\n",
-/// #     "    expr value: `[122, 122]`"
+/// #     "    expr value: `[122, 122]`,\n",
+/// #     "    diff index: `0`,\n",
+/// #     "  command diff: `alfa`,\n",
+/// #     "     expr diff: `zz`"
 /// # );
 /// # assert_eq!(actual, message);
 /// # }
@@ -214,13 +269,13 @@ macro_rules! assert_command_stdout_eq_x {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_command_stdout_eq_x_as_result!($a_command, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_command:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_command_stdout_eq_x_as_result!($a_command, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -249,13 +304,16 @@ mod test_assert_command_stdout_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_command_stdout_eq_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_eq_x.html\n",
+            assertables_url_line!("assert_command_stdout_eq_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
             "    expr debug: `[122, 122]`,\n",
             " command value: `[97, 108, 102, 97]`,\n",
-            "    expr value: `[122, 122]`"
+            "    expr value: `[122, 122]`,\n",
+            "    diff index: `0`,\n",
+            "  command diff: `alfa`,\n",
+            "     expr diff: `zz`"
         );
         assert_eq!(
             result
@@ -277,13 +335,16 @@ mod test_assert_command_stdout_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_command_stdout_eq_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_eq_x.html\n",
+            assertables_url_line!("assert_command_stdout_eq_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
             "    expr debug: `[97, 97]`,\n",
             " command value: `[97, 108, 102, 97]`,\n",
-            "    expr value: `[97, 97]`"
+            "    expr value: `[97, 97]`,\n",
+            "    diff index: `1`,\n",
+            "  command diff: `lfa`,\n",
+            "     expr diff: `a`"
         );
         assert_eq!(
             result
@@ -296,10 +357,10 @@ mod test_assert_command_stdout_eq_x {
     }
 }
 
-/// Assert a command stdout string is equal to an expression.
+/// Assert a command stdout is equal to an expression, as bytes.
 ///
 /// Pseudocode:<br>
-/// (command ⇒ stdout) = (expr into string)
+/// (command ⇒ stdout) = (expr as bytes)
 ///
 /// This macro provides the same statements as [`assert_command_stdout_eq_x`](macro.assert_command_stdout_eq_x.html),
 /// except this macro's statements are only enabled in non-optimized
@@ -330,7 +391,7 @@ mod test_assert_command_stdout_eq_x {
 #[macro_export]
 macro_rules! debug_assert_command_stdout_eq_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_command_stdout_eq_x!($($arg)*);
         }
     };
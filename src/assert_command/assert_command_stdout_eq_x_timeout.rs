@@ -0,0 +1,517 @@
+//! Assert a command stdout is equal to an expression, as bytes, within a timeout.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout within duration) = (expr as bytes)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//! use std::time::Duration;
+//!
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let bytes = b"alfa";
+//! let duration = Duration::from_secs(10);
+//! assert_command_stdout_eq_x_timeout!(command, bytes, duration);
+//! ```
+//!
+//! This macro spawns the command, then waits for it up to the duration.
+//! If the command does not finish in time, then this macro kills the
+//! command, and the assertion fails, with a message that says how long
+//! it waited before giving up. This helps a test suite fail rather than hang.
+//!
+//! * The command stdin is null, and the command stdout and stderr are
+//!   read on separate threads, so a command with large output does not block.
+//!
+//! * The comparison is the same as for
+//!   [`assert_command_stdout_eq_x`](macro@crate::assert_command_stdout_eq_x).
+//!
+//! * A kill stops the command itself, not any child process that the command
+//!   spawns in turn. If such a process keeps the command stdout or stderr
+//!   open after the command exits, then this macro stops reading at the
+//!   timeout, and the assertion fails the same way.
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_eq_x_timeout`](macro@crate::assert_command_stdout_eq_x_timeout)
+//! * [`assert_command_stdout_eq_x_timeout_as_result`](macro@crate::assert_command_stdout_eq_x_timeout_as_result)
+//! * [`debug_assert_command_stdout_eq_x_timeout`](macro@crate::debug_assert_command_stdout_eq_x_timeout)
+
+/// Run a command, wait for it up to a timeout, and return its output.
+///
+/// This function is an implementation detail of the macro
+/// [`assert_command_stdout_eq_x_timeout`](macro@crate::assert_command_stdout_eq_x_timeout).
+///
+/// * If the command finishes in time, return `Ok(Ok(output))`.
+///
+/// * If the command does not finish in time, kill it, and return `Ok(Err(waited))`.
+///
+/// The stdout and stderr are read on separate threads, so that a command
+/// that writes more than a pipe buffer does not block while we wait. The
+/// reads also stop at the deadline, because a process that the command
+/// spawns can keep the pipes open after the command exits.
+#[doc(hidden)]
+pub fn command_output_with_timeout(
+    command: &mut ::std::process::Command,
+    timeout: ::std::time::Duration,
+) -> ::std::io::Result<Result<::std::process::Output, ::std::time::Duration>> {
+    use ::std::io::Read;
+    use ::std::process::Stdio;
+    use ::std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+    use ::std::time::{Duration, Instant};
+
+    fn read_on_thread<R: Read + Send + 'static>(reader: Option<R>) -> Receiver<Vec<u8>> {
+        let (sender, receiver) = mpsc::channel();
+        ::std::thread::spawn(move || {
+            let mut bytes = Vec::new();
+            if let Some(mut reader) = reader {
+                let _ = reader.read_to_end(&mut bytes);
+            }
+            let _ = sender.send(bytes);
+        });
+        receiver
+    }
+
+    let start = Instant::now();
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = read_on_thread(child.stdout.take());
+    let stderr = read_on_thread(child.stderr.take());
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => {}
+            Err(err) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(err);
+            }
+        }
+        let waited = start.elapsed();
+        if waited >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(Err(waited));
+        }
+        ::std::thread::sleep((timeout - waited).min(Duration::from_millis(10)));
+    };
+    let mut outputs = [Vec::new(), Vec::new()];
+    for (output, receiver) in outputs.iter_mut().zip([stdout, stderr]) {
+        match receiver.recv_timeout(timeout.saturating_sub(start.elapsed())) {
+            Ok(bytes) => *output = bytes,
+            Err(RecvTimeoutError::Timeout) => return Ok(Err(start.elapsed())),
+            Err(RecvTimeoutError::Disconnected) => {}
+        }
+    }
+    let [stdout, stderr] = outputs;
+    Ok(Ok(::std::process::Output {
+        status,
+        stdout,
+        stderr,
+    }))
+}
+
+/// Assert a command stdout is equal to an expression, as bytes, within a timeout.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout within duration) = (expr as bytes)
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_timeout`](macro@crate::assert_command_stdout_eq_x_timeout)
+/// * [`assert_command_stdout_eq_x_timeout_as_result`](macro@crate::assert_command_stdout_eq_x_timeout_as_result)
+/// * [`debug_assert_command_stdout_eq_x_timeout`](macro@crate::debug_assert_command_stdout_eq_x_timeout)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_x_timeout_as_result {
+    ($command:expr, $expr:expr, $duration:expr $(,)?) => {{
+        match (/*&$command,*/ &$expr, &$duration) {
+            (expr, duration) => {
+                match $crate::assert_command::assert_command_stdout_eq_x_timeout::command_output_with_timeout(&mut $command, *duration) {
+                    Ok(Ok(output)) => {
+                        let a = output.stdout;
+                        let b_bytes: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(expr);
+                        if a.as_slice() == b_bytes {
+                            Ok(a)
+                        } else {
+                            let (index, a_region, b_region) = $crate::bytes_diff::bytes_diff_region(&a, b_bytes, $crate::bytes_diff::escape_ascii);
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_command_stdout_eq_x_timeout!(command, expr, duration)`\n",
+                                        $crate::assertables_url_line!("assert_command_stdout_eq_x_timeout"),
+                                        "  command label: `{}`,\n",
+                                        "  command debug: `{:?}`,\n",
+                                        "     expr label: `{}`,\n",
+                                        "     expr debug: `{:?}`,\n",
+                                        " duration label: `{}`,\n",
+                                        " duration debug: `{:?}`,\n",
+                                        "  command value: `{:?}`,\n",
+                                        "     expr value: `{:?}`,\n",
+                                        "     diff index: `{}`,\n",
+                                        "   command diff: `{}`,\n",
+                                        "      expr diff: `{}`"
+                                    ),
+                                    stringify!($command),
+                                    $command,
+                                    stringify!($expr),
+                                    expr,
+                                    stringify!($duration),
+                                    duration,
+                                    a,
+                                    b_bytes,
+                                    index,
+                                    a_region,
+                                    b_region
+                                )
+                            )
+                        }
+                    },
+                    Ok(Err(waited)) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdout_eq_x_timeout!(command, expr, duration)`\n",
+                                    $crate::assertables_url_line!("assert_command_stdout_eq_x_timeout"),
+                                    "  command label: `{}`,\n",
+                                    "  command debug: `{:?}`,\n",
+                                    "     expr label: `{}`,\n",
+                                    "     expr debug: `{:?}`,\n",
+                                    " duration label: `{}`,\n",
+                                    " duration debug: `{:?}`,\n",
+                                    "         waited: `{:?}`,\n",
+                                    "        timeout: command did not finish, so it was killed"
+                                ),
+                                stringify!($command),
+                                $command,
+                                stringify!($expr),
+                                expr,
+                                stringify!($duration),
+                                duration,
+                                waited
+                            )
+                        )
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdout_eq_x_timeout!(command, expr, duration)`\n",
+                                    $crate::assertables_url_line!("assert_command_stdout_eq_x_timeout"),
+                                    "  command label: `{}`,\n",
+                                    "  command debug: `{:?}`,\n",
+                                    "     expr label: `{}`,\n",
+                                    "     expr debug: `{:?}`,\n",
+                                    " duration label: `{}`,\n",
+                                    " duration debug: `{:?}`,\n",
+                                    "     output err: `{:?}`"
+                                ),
+                                stringify!($command),
+                                $command,
+                                stringify!($expr),
+                                expr,
+                                stringify!($duration),
+                                duration,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdout_eq_x_timeout_as_result {
+    use std::process::Command;
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let bytes = b"alfa";
+        let duration = Duration::from_secs(10);
+        let actual = assert_command_stdout_eq_x_timeout_as_result!(command, bytes, duration);
+        assert_eq!(actual.unwrap(), b"alfa".to_vec());
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let bytes = b"zz";
+        let duration = Duration::from_secs(10);
+        let actual = assert_command_stdout_eq_x_timeout_as_result!(command, bytes, duration);
+        let message = concat!(
+            "assertion failed: `assert_command_stdout_eq_x_timeout!(command, expr, duration)`\n",
+            assertables_url_line!("assert_command_stdout_eq_x_timeout"),
+            "  command label: `command`,\n",
+            "  command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+            "     expr label: `bytes`,\n",
+            "     expr debug: `[122, 122]`,\n",
+            " duration label: `duration`,\n",
+            " duration debug: `10s`,\n",
+            "  command value: `[97, 108, 102, 97]`,\n",
+            "     expr value: `[122, 122]`,\n",
+            "     diff index: `0`,\n",
+            "   command diff: `alfa`,\n",
+            "      expr diff: `zz`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_timeout() {
+        let mut command = Command::new("sleep");
+        command.arg("10");
+        let duration = Duration::from_millis(100);
+        let start = std::time::Instant::now();
+        let actual = assert_command_stdout_eq_x_timeout_as_result!(command, b"", duration);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let message = actual.unwrap_err();
+        assert!(message.starts_with(concat!(
+            "assertion failed: `assert_command_stdout_eq_x_timeout!(command, expr, duration)`\n",
+            assertables_url_line!("assert_command_stdout_eq_x_timeout"),
+            "  command label: `command`,\n",
+            "  command debug: `\"sleep\" \"10\"`,\n",
+            "     expr label: `b\"\"`,\n",
+            "     expr debug: `[]`,\n",
+            " duration label: `duration`,\n",
+            " duration debug: `100ms`,\n",
+            "         waited: `"
+        )));
+        assert!(message.ends_with("`,\n        timeout: command did not finish, so it was killed"));
+    }
+
+    #[test]
+    fn failure_because_timeout_with_descendant_holding_stdout() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "sleep 5 & echo hi"]);
+        let duration = Duration::from_millis(500);
+        let start = std::time::Instant::now();
+        let actual = assert_command_stdout_eq_x_timeout_as_result!(command, b"hi\n", duration);
+        assert!(start.elapsed() < Duration::from_secs(3));
+        assert!(actual
+            .unwrap_err()
+            .ends_with("`,\n        timeout: command did not finish, so it was killed"));
+    }
+
+    #[test]
+    fn success_with_large_output() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "head -c 1000000 /dev/zero"]);
+        let actual = assert_command_stdout_eq_x_timeout_as_result!(
+            command,
+            vec![0u8; 1000000],
+            Duration::from_secs(10)
+        );
+        assert!(actual.is_ok());
+    }
+}
+
+/// Assert a command stdout is equal to an expression, as bytes, within a timeout.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout within duration) = (expr as bytes)
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+/// use std::time::Duration;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let bytes = b"alfa";
+/// let duration = Duration::from_secs(10);
+/// assert_command_stdout_eq_x_timeout!(command, bytes, duration);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let bytes = b"zz";
+/// let duration = Duration::from_secs(10);
+/// assert_command_stdout_eq_x_timeout!(command, bytes, duration);
+/// # });
+/// // assertion failed: `assert_command_stdout_eq_x_timeout!(command, expr, duration)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_eq_x_timeout.html
+/// //   command label: `command`,
+/// //   command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,
+/// //      expr label: `bytes`,
+/// //      expr debug: `[122, 122]`,
+/// //  duration label: `duration`,
+/// //  duration debug: `10s`,
+/// //   command value: `[97, 108, 102, 97]`,
+/// //      expr value: `[122, 122]`,
+/// //      diff index: `0`,
+/// //    command diff: `alfa`,
+/// //       expr diff: `zz`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_command_stdout_eq_x_timeout!(command, expr, duration)`\n",
+/// #     assertables_url_line!("assert_command_stdout_eq_x_timeout"),
+/// #     "  command label: `command`,\n",
+/// #     "  command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+/// #     "     expr label: `bytes`,\n",
+/// #     "     expr debug: `[122, 122]`,\n",
+/// #     " duration label: `duration`,\n",
+/// #     " duration debug: `10s`,\n",
+/// #     "  command value: `[97, 108, 102, 97]`,\n",
+/// #     "     expr value: `[122, 122]`,\n",
+/// #     "     diff index: `0`,\n",
+/// #     "   command diff: `alfa`,\n",
+/// #     "      expr diff: `zz`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro spawns the command, then waits for it up to the duration.
+/// If the command does not finish in time, then this macro kills the
+/// command, and the assertion fails, with a message that says how long
+/// it waited before giving up. This helps a test suite fail rather than hang.
+///
+/// * The command stdin is null, and the command stdout and stderr are
+///   read on separate threads, so a command with large output does not block.
+///
+/// * The comparison is the same as for
+///   [`assert_command_stdout_eq_x`](macro@crate::assert_command_stdout_eq_x).
+///
+/// * A kill stops the command itself, not any child process that the command
+///   spawns in turn.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_timeout`](macro@crate::assert_command_stdout_eq_x_timeout)
+/// * [`assert_command_stdout_eq_x_timeout_as_result`](macro@crate::assert_command_stdout_eq_x_timeout_as_result)
+/// * [`debug_assert_command_stdout_eq_x_timeout`](macro@crate::debug_assert_command_stdout_eq_x_timeout)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_eq_x_timeout {
+    ($command:expr, $expr:expr, $duration:expr $(,)?) => {{
+        match $crate::assert_command_stdout_eq_x_timeout_as_result!($command, $expr, $duration) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($command:expr, $expr:expr, $duration:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_eq_x_timeout_as_result!($command, $expr, $duration) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdout_eq_x_timeout {
+    use std::panic;
+    use std::process::Command;
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let bytes = b"alfa";
+        let duration = Duration::from_secs(10);
+        let actual = assert_command_stdout_eq_x_timeout!(command, bytes, duration);
+        assert_eq!(actual, b"alfa".to_vec());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut command = Command::new("bin/printf-stdout");
+            command.args(["%s", "alfa"]);
+            let bytes = b"zz";
+            let duration = Duration::from_secs(10);
+            let _actual = assert_command_stdout_eq_x_timeout!(command, bytes, duration);
+        });
+        let message = concat!(
+            "assertion failed: `assert_command_stdout_eq_x_timeout!(command, expr, duration)`\n",
+            assertables_url_line!("assert_command_stdout_eq_x_timeout"),
+            "  command label: `command`,\n",
+            "  command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+            "     expr label: `bytes`,\n",
+            "     expr debug: `[122, 122]`,\n",
+            " duration label: `duration`,\n",
+            " duration debug: `10s`,\n",
+            "  command value: `[97, 108, 102, 97]`,\n",
+            "     expr value: `[122, 122]`,\n",
+            "     diff index: `0`,\n",
+            "   command diff: `alfa`,\n",
+            "      expr diff: `zz`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a command stdout is equal to an expression, as bytes, within a timeout.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout within duration) = (expr as bytes)
+///
+/// This macro provides the same statements as [`assert_command_stdout_eq_x_timeout`](macro.assert_command_stdout_eq_x_timeout.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_eq_x_timeout`](macro@crate::assert_command_stdout_eq_x_timeout)
+/// * [`assert_command_stdout_eq_x_timeout_as_result`](macro@crate::assert_command_stdout_eq_x_timeout_as_result)
+/// * [`debug_assert_command_stdout_eq_x_timeout`](macro@crate::debug_assert_command_stdout_eq_x_timeout)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_eq_x_timeout {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_command_stdout_eq_x_timeout!($($arg)*);
+        }
+    };
+}
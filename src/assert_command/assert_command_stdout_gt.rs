@@ -43,6 +43,8 @@
 #[macro_export]
 macro_rules! assert_command_stdout_gt_as_result {
     ($a_command:expr, $b_command:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
         match ($a_command.output(), $b_command.output()) {
             (Ok(a), Ok(b)) => {
                 let a = a.stdout;
@@ -54,7 +56,7 @@ macro_rules! assert_command_stdout_gt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_command_stdout_gt!(a_command, b_command)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_gt.html\n",
+                                $crate::assertables_url_line!("assert_command_stdout_gt"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -77,7 +79,7 @@ macro_rules! assert_command_stdout_gt_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_command_stdout_gt!(a_command, b_command)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_gt.html\n",
+                            $crate::assertables_url_line!("assert_command_stdout_gt"),
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
@@ -124,7 +126,7 @@ mod test_assert_command_stdout_gt_as_result {
         let actual = assert_command_stdout_gt_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stdout_gt!(a_command, b_command)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_gt.html\n",
+            assertables_url_line!("assert_command_stdout_gt"),
             " a label: `a`,\n",
             " a debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             " b label: `b`,\n",
@@ -144,7 +146,7 @@ mod test_assert_command_stdout_gt_as_result {
         let actual = assert_command_stdout_gt_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stdout_gt!(a_command, b_command)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_gt.html\n",
+            assertables_url_line!("assert_command_stdout_gt"),
             " a label: `a`,\n",
             " a debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             " b label: `b`,\n",
@@ -199,7 +201,7 @@ mod test_assert_command_stdout_gt_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_command_stdout_gt!(a_command, b_command)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_gt.html\n",
+/// #     assertables_url_line!("assert_command_stdout_gt"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
 /// #     " b label: `b`,\n",
@@ -222,13 +224,13 @@ macro_rules! assert_command_stdout_gt {
     ($a_command:expr, $b_command:expr $(,)?) => {{
         match $crate::assert_command_stdout_gt_as_result!($a_command, $b_command) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_command:expr, $b_command:expr, $($message:tt)+) => {{
         match $crate::assert_command_stdout_gt_as_result!($a_command, $b_command) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -259,7 +261,7 @@ mod test_assert_command_stdout_gt {
         });
         let message = concat!(
             "assertion failed: `assert_command_stdout_gt!(a_command, b_command)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_gt.html\n",
+            assertables_url_line!("assert_command_stdout_gt"),
             " a label: `a`,\n",
             " a debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             " b label: `b`,\n",
@@ -288,7 +290,7 @@ mod test_assert_command_stdout_gt {
         });
         let message = concat!(
             "assertion failed: `assert_command_stdout_gt!(a_command, b_command)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_gt.html\n",
+            assertables_url_line!("assert_command_stdout_gt"),
             " a label: `a`,\n",
             " a debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             " b label: `b`,\n",
@@ -338,7 +340,7 @@ mod test_assert_command_stdout_gt {
 #[macro_export]
 macro_rules! debug_assert_command_stdout_gt {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_command_stdout_gt!($($arg)*);
         }
     };
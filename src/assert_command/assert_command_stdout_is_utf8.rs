@@ -0,0 +1,281 @@
+//! Assert a command stdout is valid UTF-8.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout) is valid UTF-8
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! assert_command_stdout_is_utf8!(command);
+//! ```
+//!
+//! This uses [`::std::String`](https://doc.rust-lang.org/std/string/struct.String.html) method `from_utf8`.
+//!
+//! * The failure message shows the index of the first invalid byte,
+//!   which is the same as the length of the valid UTF-8 prefix.
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_is_utf8`](macro@crate::assert_command_stdout_is_utf8)
+//! * [`assert_command_stdout_is_utf8_as_result`](macro@crate::assert_command_stdout_is_utf8_as_result)
+//! * [`debug_assert_command_stdout_is_utf8`](macro@crate::debug_assert_command_stdout_is_utf8)
+
+/// Assert a command stdout is valid UTF-8.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout) is valid UTF-8
+///
+/// * If true, return Result `Ok(command ⇒ stdout ⇒ string)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_is_utf8`](macro@crate::assert_command_stdout_is_utf8)
+/// * [`assert_command_stdout_is_utf8_as_result`](macro@crate::assert_command_stdout_is_utf8_as_result)
+/// * [`debug_assert_command_stdout_is_utf8`](macro@crate::debug_assert_command_stdout_is_utf8)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_is_utf8_as_result {
+    ($command:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
+        match $command.output() {
+            Ok(output) => {
+                match String::from_utf8(output.stdout) {
+                    Ok(string) => Ok(string),
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdout_is_utf8!(command)`\n",
+                                    $crate::assertables_url_line!("assert_command_stdout_is_utf8"),
+                                    " command label: `{}`,\n",
+                                    " command debug: `{:?}`,\n",
+                                    "        stdout: `{:?}`,\n",
+                                    "    utf8 error: `{}`,\n",
+                                    " invalid index: `{}`"
+                                ),
+                                stringify!($command),
+                                $command,
+                                err.as_bytes(),
+                                err.utf8_error(),
+                                err.utf8_error().valid_up_to()
+                            )
+                        )
+                    }
+                }
+            },
+            Err(err) => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_command_stdout_is_utf8!(command)`\n",
+                            $crate::assertables_url_line!("assert_command_stdout_is_utf8"),
+                            " command label: `{}`,\n",
+                            " command debug: `{:?}`,\n",
+                            " output is err: `{:?}`"
+                        ),
+                        stringify!($command),
+                        $command,
+                        err
+                    )
+                )
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdout_is_utf8_as_result {
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let actual = assert_command_stdout_is_utf8_as_result!(command);
+        assert_eq!(actual.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s\\377", "alfa"]);
+        let actual = assert_command_stdout_is_utf8_as_result!(command);
+        let message = concat!(
+            "assertion failed: `assert_command_stdout_is_utf8!(command)`\n",
+            assertables_url_line!("assert_command_stdout_is_utf8"),
+            " command label: `command`,\n",
+            " command debug: `\"bin/printf-stdout\" \"%s\\\\377\" \"alfa\"`,\n",
+            "        stdout: `[97, 108, 102, 97, 255]`,\n",
+            "    utf8 error: `invalid utf-8 sequence of 1 bytes from index 4`,\n",
+            " invalid index: `4`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a command stdout is valid UTF-8.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout) is valid UTF-8
+///
+/// * If true, return `command ⇒ stdout ⇒ string`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// assert_command_stdout_is_utf8!(command);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s\\377", "alfa"]);
+/// assert_command_stdout_is_utf8!(command);
+/// # });
+/// // assertion failed: `assert_command_stdout_is_utf8!(command)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_is_utf8.html
+/// //  command label: `command`,
+/// //  command debug: `\"bin/printf-stdout\" \"%s\\\\377\" \"alfa\"`,
+/// //         stdout: `[97, 108, 102, 97, 255]`,
+/// //     utf8 error: `invalid utf-8 sequence of 1 bytes from index 4`,
+/// //  invalid index: `4`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_command_stdout_is_utf8!(command)`\n",
+/// #     assertables_url_line!("assert_command_stdout_is_utf8"),
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"bin/printf-stdout\" \"%s\\\\377\" \"alfa\"`,\n",
+/// #     "        stdout: `[97, 108, 102, 97, 255]`,\n",
+/// #     "    utf8 error: `invalid utf-8 sequence of 1 bytes from index 4`,\n",
+/// #     " invalid index: `4`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This uses [`::std::String`](https://doc.rust-lang.org/std/string/struct.String.html) method `from_utf8`.
+///
+/// * The failure message shows the index of the first invalid byte,
+///   which is the same as the length of the valid UTF-8 prefix.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_is_utf8`](macro@crate::assert_command_stdout_is_utf8)
+/// * [`assert_command_stdout_is_utf8_as_result`](macro@crate::assert_command_stdout_is_utf8_as_result)
+/// * [`debug_assert_command_stdout_is_utf8`](macro@crate::debug_assert_command_stdout_is_utf8)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_is_utf8 {
+    ($command:expr $(,)?) => {{
+        match $crate::assert_command_stdout_is_utf8_as_result!($command) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($command:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_is_utf8_as_result!($command) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdout_is_utf8 {
+    use std::panic;
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let actual = assert_command_stdout_is_utf8!(command);
+        assert_eq!(actual, "alfa");
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut command = Command::new("bin/printf-stdout");
+            command.args(["%s\\377", "alfa"]);
+            let _actual = assert_command_stdout_is_utf8!(command);
+        });
+        let message = concat!(
+            "assertion failed: `assert_command_stdout_is_utf8!(command)`\n",
+            assertables_url_line!("assert_command_stdout_is_utf8"),
+            " command label: `command`,\n",
+            " command debug: `\"bin/printf-stdout\" \"%s\\\\377\" \"alfa\"`,\n",
+            "        stdout: `[97, 108, 102, 97, 255]`,\n",
+            "    utf8 error: `invalid utf-8 sequence of 1 bytes from index 4`,\n",
+            " invalid index: `4`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a command stdout is valid UTF-8.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout) is valid UTF-8
+///
+/// This macro provides the same statements as [`assert_command_stdout_is_utf8`](macro.assert_command_stdout_is_utf8.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_is_utf8`](macro@crate::assert_command_stdout_is_utf8)
+/// * [`assert_command_stdout_is_utf8_as_result`](macro@crate::assert_command_stdout_is_utf8_as_result)
+/// * [`debug_assert_command_stdout_is_utf8`](macro@crate::debug_assert_command_stdout_is_utf8)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_is_utf8 {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_command_stdout_is_utf8!($($arg)*);
+        }
+    };
+}
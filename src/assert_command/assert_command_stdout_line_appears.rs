@@ -0,0 +1,419 @@
+//! Assert a command stdout has a line that is a matcher match within a timeout.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ spawn ⇒ stdout ⇒ lines) any line is a matcher match within timeout
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use regex::Regex;
+//! use std::process::Command;
+//! use std::time::Duration;
+//!
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s\\n%s\\n", "alfa", "bravo"]);
+//! let matcher = Regex::new(r"^br").expect("regex");
+//! let timeout = Duration::from_secs(5);
+//! assert_command_stdout_line_appears!(command, matcher, timeout);
+//! ```
+//!
+//! This macro is for long-running commands, such as servers and daemons,
+//! that print a line when they are ready. The macro spawns the command,
+//! reads its stdout line by line as the lines are streamed, and succeeds
+//! as soon as any line is a matcher match.
+//!
+//! * The matcher can be any type with a method `is_match(&str)`,
+//!   such as [`::regex::Regex`](https://docs.rs/regex/latest/regex/struct.Regex.html).
+//!
+//! * The timeout is a [`::std::time::Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html).
+//!
+//! * The command is killed after the matching line, or after the timeout,
+//!   or after its stdout ends, so the command never outlives the assertion.
+//!
+//! * The failure message shows all the lines seen so far.
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_line_appears`](macro@crate::assert_command_stdout_line_appears)
+//! * [`assert_command_stdout_line_appears_as_result`](macro@crate::assert_command_stdout_line_appears_as_result)
+//! * [`debug_assert_command_stdout_line_appears`](macro@crate::debug_assert_command_stdout_line_appears)
+
+/// Assert a command stdout has a line that is a matcher match within a timeout.
+///
+/// Pseudocode:<br>
+/// (command ⇒ spawn ⇒ stdout ⇒ lines) any line is a matcher match within timeout
+///
+/// * If true, return Result `Ok(line)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_line_appears`](macro@crate::assert_command_stdout_line_appears)
+/// * [`assert_command_stdout_line_appears_as_result`](macro@crate::assert_command_stdout_line_appears_as_result)
+/// * [`debug_assert_command_stdout_line_appears`](macro@crate::debug_assert_command_stdout_line_appears)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_line_appears_as_result {
+    ($command:expr, $matcher:expr, $timeout:expr $(,)?) => {{
+        match (&$matcher, &$timeout) {
+            (matcher, timeout) => {
+                match $command.stdout(::std::process::Stdio::piped()).spawn() {
+                    Ok(mut child) => {
+                        let (sender, receiver) = ::std::sync::mpsc::channel::<String>();
+                        if let Some(stdout) = child.stdout.take() {
+                            ::std::thread::spawn(move || {
+                                use ::std::io::BufRead;
+                                for line in ::std::io::BufReader::new(stdout).lines() {
+                                    match line {
+                                        Ok(line) => {
+                                            if sender.send(line).is_err() {
+                                                break;
+                                            }
+                                        }
+                                        Err(_) => break,
+                                    }
+                                }
+                            });
+                        }
+                        let timeout: ::std::time::Duration = *timeout;
+                        let deadline = ::std::time::Instant::now() + timeout;
+                        let mut lines: Vec<String> = Vec::new();
+                        let mut found: Option<String> = None;
+                        let mut reason = "timed out";
+                        loop {
+                            let now = ::std::time::Instant::now();
+                            if now >= deadline {
+                                break;
+                            }
+                            match receiver.recv_timeout(deadline - now) {
+                                Ok(line) => {
+                                    if matcher.is_match(&line) {
+                                        found = Some(line);
+                                        break;
+                                    }
+                                    lines.push(line);
+                                }
+                                Err(::std::sync::mpsc::RecvTimeoutError::Timeout) => break,
+                                Err(::std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                                    reason = "stdout ended";
+                                    break;
+                                }
+                            }
+                        }
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        match found {
+                            Some(line) => Ok(line),
+                            None => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_line_appears!(command, matcher, timeout)`\n",
+                                            $crate::assertables_url_line!("assert_command_stdout_line_appears"),
+                                            " command label: `{}`,\n",
+                                            " command debug: `{:?}`,\n",
+                                            " matcher label: `{}`,\n",
+                                            " matcher debug: `{:?}`,\n",
+                                            " timeout label: `{}`,\n",
+                                            " timeout debug: `{:?}`,\n",
+                                            "        reason: `{}`,\n",
+                                            "    lines seen: `{:?}`"
+                                        ),
+                                        stringify!($command),
+                                        $command,
+                                        stringify!($matcher),
+                                        matcher,
+                                        stringify!($timeout),
+                                        timeout,
+                                        reason,
+                                        lines
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdout_line_appears!(command, matcher, timeout)`\n",
+                                    $crate::assertables_url_line!("assert_command_stdout_line_appears"),
+                                    " command label: `{}`,\n",
+                                    " command debug: `{:?}`,\n",
+                                    " matcher label: `{}`,\n",
+                                    " matcher debug: `{:?}`,\n",
+                                    " timeout label: `{}`,\n",
+                                    " timeout debug: `{:?}`,\n",
+                                    "  spawn is err: `{:?}`"
+                                ),
+                                stringify!($command),
+                                $command,
+                                stringify!($matcher),
+                                matcher,
+                                stringify!($timeout),
+                                timeout,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdout_line_appears_as_result {
+    use regex::Regex;
+    use std::process::Command;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s\\n%s\\n", "alfa", "bravo"]);
+        let matcher = Regex::new(r"^br").expect("regex");
+        let timeout = Duration::from_secs(5);
+        let actual = assert_command_stdout_line_appears_as_result!(command, matcher, timeout);
+        assert_eq!(actual.unwrap(), "bravo");
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s\\n%s\\n", "alfa", "bravo"]);
+        let matcher = Regex::new(r"zz").expect("regex");
+        let timeout = Duration::from_secs(5);
+        let actual = assert_command_stdout_line_appears_as_result!(command, matcher, timeout);
+        let message = concat!(
+            "assertion failed: `assert_command_stdout_line_appears!(command, matcher, timeout)`\n",
+            assertables_url_line!("assert_command_stdout_line_appears"),
+            " command label: `command`,\n",
+            " command debug: `\"bin/printf-stdout\" \"%s\\\\n%s\\\\n\" \"alfa\" \"bravo\"`,\n",
+            " matcher label: `matcher`,\n",
+            " matcher debug: `Regex(\"zz\")`,\n",
+            " timeout label: `timeout`,\n",
+            " timeout debug: `5s`,\n",
+            "        reason: `stdout ended`,\n",
+            "    lines seen: `[\"alfa\", \"bravo\"]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_timed_out() {
+        let mut command = Command::new("sleep");
+        command.args(["5"]);
+        let matcher = Regex::new(r"zz").expect("regex");
+        let timeout = Duration::from_millis(100);
+        let start = Instant::now();
+        let actual = assert_command_stdout_line_appears_as_result!(command, matcher, timeout);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let message = actual.unwrap_err();
+        assert!(message.contains(
+            " timeout debug: `100ms`,\n        reason: `timed out`,\n    lines seen: `[]`"
+        ));
+    }
+
+    #[test]
+    fn failure_because_spawn_is_err() {
+        let mut command = Command::new("bin/no-such-program");
+        let matcher = Regex::new(r"zz").expect("regex");
+        let timeout = Duration::from_secs(1);
+        let actual = assert_command_stdout_line_appears_as_result!(command, matcher, timeout);
+        let message = actual.unwrap_err();
+        assert!(message.contains("  spawn is err: `"));
+    }
+}
+
+/// Assert a command stdout has a line that is a matcher match within a timeout.
+///
+/// Pseudocode:<br>
+/// (command ⇒ spawn ⇒ stdout ⇒ lines) any line is a matcher match within timeout
+///
+/// * If true, return `line`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use regex::Regex;
+/// use std::process::Command;
+/// use std::time::Duration;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s\\n%s\\n", "alfa", "bravo"]);
+/// let matcher = Regex::new(r"^br").expect("regex");
+/// let timeout = Duration::from_secs(5);
+/// assert_command_stdout_line_appears!(command, matcher, timeout);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s\\n%s\\n", "alfa", "bravo"]);
+/// let matcher = Regex::new(r"zz").expect("regex");
+/// let timeout = Duration::from_secs(5);
+/// assert_command_stdout_line_appears!(command, matcher, timeout);
+/// # });
+/// // assertion failed: `assert_command_stdout_line_appears!(command, matcher, timeout)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_line_appears.html
+/// //  command label: `command`,
+/// //  command debug: `\"bin/printf-stdout\" \"%s\\\\n%s\\\\n\" \"alfa\" \"bravo\"`,
+/// //  matcher label: `matcher`,
+/// //  matcher debug: `Regex(\"zz\")`,
+/// //  timeout label: `timeout`,
+/// //  timeout debug: `5s`,
+/// //         reason: `stdout ended`,
+/// //     lines seen: `[\"alfa\", \"bravo\"]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_command_stdout_line_appears!(command, matcher, timeout)`\n",
+/// #     assertables_url_line!("assert_command_stdout_line_appears"),
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"bin/printf-stdout\" \"%s\\\\n%s\\\\n\" \"alfa\" \"bravo\"`,\n",
+/// #     " matcher label: `matcher`,\n",
+/// #     " matcher debug: `Regex(\"zz\")`,\n",
+/// #     " timeout label: `timeout`,\n",
+/// #     " timeout debug: `5s`,\n",
+/// #     "        reason: `stdout ended`,\n",
+/// #     "    lines seen: `[\"alfa\", \"bravo\"]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is for long-running commands, such as servers and daemons,
+/// that print a line when they are ready. The macro spawns the command,
+/// reads its stdout line by line as the lines are streamed, and succeeds
+/// as soon as any line is a matcher match.
+///
+/// * The matcher can be any type with a method `is_match(&str)`,
+///   such as [`::regex::Regex`](https://docs.rs/regex/latest/regex/struct.Regex.html).
+///
+/// * The timeout is a [`::std::time::Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html).
+///
+/// * The command is killed after the matching line, or after the timeout,
+///   or after its stdout ends, so the command never outlives the assertion.
+///
+/// * The failure message shows all the lines seen so far.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_line_appears`](macro@crate::assert_command_stdout_line_appears)
+/// * [`assert_command_stdout_line_appears_as_result`](macro@crate::assert_command_stdout_line_appears_as_result)
+/// * [`debug_assert_command_stdout_line_appears`](macro@crate::debug_assert_command_stdout_line_appears)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_line_appears {
+    ($command:expr, $matcher:expr, $timeout:expr $(,)?) => {{
+        match $crate::assert_command_stdout_line_appears_as_result!($command, $matcher, $timeout) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($command:expr, $matcher:expr, $timeout:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_line_appears_as_result!($command, $matcher, $timeout) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdout_line_appears {
+    use regex::Regex;
+    use std::panic;
+    use std::process::Command;
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s\\n%s\\n", "alfa", "bravo"]);
+        let matcher = Regex::new(r"^br").expect("regex");
+        let timeout = Duration::from_secs(5);
+        let actual = assert_command_stdout_line_appears!(command, matcher, timeout);
+        assert_eq!(actual, "bravo");
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut command = Command::new("bin/printf-stdout");
+            command.args(["%s\\n%s\\n", "alfa", "bravo"]);
+            let matcher = Regex::new(r"zz").expect("regex");
+            let timeout = Duration::from_secs(5);
+            let _actual = assert_command_stdout_line_appears!(command, matcher, timeout);
+        });
+        let message = concat!(
+            "assertion failed: `assert_command_stdout_line_appears!(command, matcher, timeout)`\n",
+            assertables_url_line!("assert_command_stdout_line_appears"),
+            " command label: `command`,\n",
+            " command debug: `\"bin/printf-stdout\" \"%s\\\\n%s\\\\n\" \"alfa\" \"bravo\"`,\n",
+            " matcher label: `matcher`,\n",
+            " matcher debug: `Regex(\"zz\")`,\n",
+            " timeout label: `timeout`,\n",
+            " timeout debug: `5s`,\n",
+            "        reason: `stdout ended`,\n",
+            "    lines seen: `[\"alfa\", \"bravo\"]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a command stdout has a line that is a matcher match within a timeout.
+///
+/// Pseudocode:<br>
+/// (command ⇒ spawn ⇒ stdout ⇒ lines) any line is a matcher match within timeout
+///
+/// This macro provides the same statements as [`assert_command_stdout_line_appears`](macro.assert_command_stdout_line_appears.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_line_appears`](macro@crate::assert_command_stdout_line_appears)
+/// * [`assert_command_stdout_line_appears_as_result`](macro@crate::assert_command_stdout_line_appears_as_result)
+/// * [`debug_assert_command_stdout_line_appears`](macro@crate::debug_assert_command_stdout_line_appears)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_line_appears {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_command_stdout_line_appears!($($arg)*);
+        }
+    };
+}
@@ -42,6 +42,8 @@
 #[macro_export]
 macro_rules! assert_command_stdout_lt_x_as_result {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
         match (/*&$command,*/ &$b_expr) {
             b => {
                 match $a_command.output() {
@@ -54,7 +56,7 @@ macro_rules! assert_command_stdout_lt_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_command_stdout_lt_x!(command, expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_lt_x.html\n",
+                                        $crate::assertables_url_line!("assert_command_stdout_lt_x"),
                                         " command label: `{}`,\n",
                                         " command debug: `{:?}`,\n",
                                         "    expr label: `{}`,\n",
@@ -77,7 +79,7 @@ macro_rules! assert_command_stdout_lt_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_command_stdout_lt_x!(command, expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_lt_x.html\n",
+                                    $crate::assertables_url_line!("assert_command_stdout_lt_x"),
                                     "  command label: `{}`,\n",
                                     "  command debug: `{:?}`,\n",
                                     "     expr label: `{}`,\n",
@@ -119,7 +121,7 @@ mod test_assert_command_stdout_lt_x_as_result {
         let actual = assert_command_stdout_lt_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stdout_lt_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_lt_x.html\n",
+            assertables_url_line!("assert_command_stdout_lt_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
@@ -138,7 +140,7 @@ mod test_assert_command_stdout_lt_x_as_result {
         let actual = assert_command_stdout_lt_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stdout_lt_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_lt_x.html\n",
+            assertables_url_line!("assert_command_stdout_lt_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
@@ -191,7 +193,7 @@ mod test_assert_command_stdout_lt_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_command_stdout_lt_x!(command, expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_lt_x.html\n",
+/// #     assertables_url_line!("assert_command_stdout_lt_x"),
 /// #     " command label: `command`,\n",
 /// #     " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
 /// #     "    expr label: `bytes`,\n",
@@ -214,13 +216,13 @@ macro_rules! assert_command_stdout_lt_x {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_command_stdout_lt_x_as_result!($a_command, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_command:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_command_stdout_lt_x_as_result!($a_command, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -249,7 +251,7 @@ mod test_assert_command_stdout_lt_x {
         });
         let message = concat!(
             "assertion failed: `assert_command_stdout_lt_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_lt_x.html\n",
+            assertables_url_line!("assert_command_stdout_lt_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
@@ -277,7 +279,7 @@ mod test_assert_command_stdout_lt_x {
         });
         let message = concat!(
             "assertion failed: `assert_command_stdout_lt_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_lt_x.html\n",
+            assertables_url_line!("assert_command_stdout_lt_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
@@ -330,7 +332,7 @@ mod test_assert_command_stdout_lt_x {
 #[macro_export]
 macro_rules! debug_assert_command_stdout_lt_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_command_stdout_lt_x!($($arg)*);
         }
     };
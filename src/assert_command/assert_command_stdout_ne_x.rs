@@ -36,6 +36,8 @@
 #[macro_export]
 macro_rules! assert_command_stdout_ne_x_as_result {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
         match (/*&$command,*/ &$b_expr) {
             b => {
                 match $a_command.output() {
@@ -48,7 +50,7 @@ macro_rules! assert_command_stdout_ne_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_command_stdout_ne_x!(command, expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_ne_x.html\n",
+                                        $crate::assertables_url_line!("assert_command_stdout_ne_x"),
                                         " command label: `{}`,\n",
                                         " command debug: `{:?}`,\n",
                                         "    expr label: `{}`,\n",
@@ -71,7 +73,7 @@ macro_rules! assert_command_stdout_ne_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_command_stdout_ne_x!(command, expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_ne_x.html\n",
+                                    $crate::assertables_url_line!("assert_command_stdout_ne_x"),
                                     "  command label: `{}`,\n",
                                     "  command debug: `{:?}`,\n",
                                     "     expr label: `{}`,\n",
@@ -122,7 +124,7 @@ mod test_assert_command_stdout_ne_x_as_result {
         let actual = assert_command_stdout_ne_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stdout_ne_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_ne_x.html\n",
+            assertables_url_line!("assert_command_stdout_ne_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
@@ -175,7 +177,7 @@ mod test_assert_command_stdout_ne_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_command_stdout_ne_x!(command, expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_ne_x.html\n",
+/// #     assertables_url_line!("assert_command_stdout_ne_x"),
 /// #     " command label: `command`,\n",
 /// #     " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
 /// #     "    expr label: `bytes`,\n",
@@ -198,13 +200,13 @@ macro_rules! assert_command_stdout_ne_x {
     ($a_command:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_command_stdout_ne_x_as_result!($a_command, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_command:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_command_stdout_ne_x_as_result!($a_command, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -242,7 +244,7 @@ mod test_assert_command_stdout_ne_x {
         });
         let message = concat!(
             "assertion failed: `assert_command_stdout_ne_x!(command, expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_ne_x.html\n",
+            assertables_url_line!("assert_command_stdout_ne_x"),
             " command label: `a`,\n",
             " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             "    expr label: `b`,\n",
@@ -295,7 +297,7 @@ mod test_assert_command_stdout_ne_x {
 #[macro_export]
 macro_rules! debug_assert_command_stdout_ne_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_command_stdout_ne_x!($($arg)*);
         }
     };
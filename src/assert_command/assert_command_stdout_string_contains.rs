@@ -42,6 +42,8 @@
 #[macro_export]
 macro_rules! assert_command_stdout_string_contains_as_result {
     ($command:expr, $containee:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
         match (/*&$command,*/ &$containee) {
             containee => {
                 match $command.output() {
@@ -54,7 +56,7 @@ macro_rules! assert_command_stdout_string_contains_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_command_stdout_string_contains!(command, containee)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_string_contains.html\n",
+                                        $crate::assertables_url_line!("assert_command_stdout_string_contains"),
                                         "   command label: `{}`,\n",
                                         "   command debug: `{:?}`,\n",
                                         " containee label: `{}`,\n",
@@ -75,7 +77,7 @@ macro_rules! assert_command_stdout_string_contains_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_command_stdout_string_contains!(command, containee)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_string_contains.html\n",
+                                    $crate::assertables_url_line!("assert_command_stdout_string_contains"),
                                     "   command label: `{}`,\n",
                                     "   command debug: `{:?}`,\n",
                                     " containee label: `{}`,\n",
@@ -117,7 +119,7 @@ mod test_assert_command_stdout_string_contains_as_result {
         let actual = assert_command_stdout_string_contains_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_command_stdout_string_contains!(command, containee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_string_contains.html\n",
+            assertables_url_line!("assert_command_stdout_string_contains"),
             "   command label: `a`,\n",
             "   command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             " containee label: `b`,\n",
@@ -173,7 +175,7 @@ mod test_assert_command_stdout_string_contains_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_command_stdout_string_contains!(command, containee)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_string_contains.html\n",
+/// #     assertables_url_line!("assert_command_stdout_string_contains"),
 /// #     "   command label: `command`,\n",
 /// #     "   command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
 /// #     " containee label: `&containee`,\n",
@@ -195,13 +197,13 @@ macro_rules! assert_command_stdout_string_contains {
     ($command:expr, $containee:expr $(,)?) => {{
         match $crate::assert_command_stdout_string_contains_as_result!($command, $containee) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($command:expr, $containee:expr, $($message:tt)+) => {{
         match $crate::assert_command_stdout_string_contains_as_result!($command, $containee) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -230,7 +232,7 @@ mod test_assert_command_stdout_string_contains {
         });
         let message = concat!(
             "assertion failed: `assert_command_stdout_string_contains!(command, containee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_string_contains.html\n",
+            assertables_url_line!("assert_command_stdout_string_contains"),
             "   command label: `a`,\n",
             "   command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
             " containee label: `b`,\n",
@@ -282,7 +284,7 @@ mod test_assert_command_stdout_string_contains {
 #[macro_export]
 macro_rules! debug_assert_command_stdout_string_contains {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_command_stdout_string_contains!($($arg)*);
         }
     };
@@ -0,0 +1,333 @@
+//! Assert a command stdout string is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (command ⇒ stdout ⇒ string) = (expr into string)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! let mut command = Command::new("bin/printf-stdout");
+//! command.args(["%s", "alfa"]);
+//! let s = "alfa";
+//! assert_command_stdout_string_eq_x!(command, s);
+//! ```
+//!
+//! This macro converts the stdout bytes to a UTF-8 string, then compares
+//! the string with the expression, such as a `&str` or a `String`.
+//! To compare the stdout as raw bytes, use
+//! [`assert_command_stdout_eq_x`](macro@crate::assert_command_stdout_eq_x).
+//!
+//! # Module macros
+//!
+//! * [`assert_command_stdout_string_eq_x`](macro@crate::assert_command_stdout_string_eq_x)
+//! * [`assert_command_stdout_string_eq_x_as_result`](macro@crate::assert_command_stdout_string_eq_x_as_result)
+//! * [`debug_assert_command_stdout_string_eq_x`](macro@crate::debug_assert_command_stdout_string_eq_x)
+
+/// Assert a command stdout string is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string) = (expr into string)
+///
+/// * If true, return Result `Ok(string)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_string_eq_x`](macro@crate::assert_command_stdout_string_eq_x)
+/// * [`assert_command_stdout_string_eq_x_as_result`](macro@crate::assert_command_stdout_string_eq_x_as_result)
+/// * [`debug_assert_command_stdout_string_eq_x`](macro@crate::debug_assert_command_stdout_string_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_string_eq_x_as_result {
+    ($command:expr, $expr:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_command::CapturedOutput as _;
+        match (/*&$command,*/ &$expr) {
+            expr => {
+                match $command.output() {
+                    Ok(output) => {
+                        match String::from_utf8(output.stdout) {
+                            Ok(string) => {
+                                if string == *expr {
+                                    Ok(string)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_command_stdout_string_eq_x!(command, expr)`\n",
+                                                $crate::assertables_url_line!("assert_command_stdout_string_eq_x"),
+                                                " command label: `{}`,\n",
+                                                " command debug: `{:?}`,\n",
+                                                "    expr label: `{}`,\n",
+                                                "    expr debug: `{:?}`,\n",
+                                                " command value: `{:?}`,\n",
+                                                "    expr value: `{:?}`"
+                                            ),
+                                            stringify!($command),
+                                            $command,
+                                            stringify!($expr),
+                                            expr,
+                                            string,
+                                            expr
+                                        )
+                                    )
+                                }
+                            }
+                            Err(err) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_command_stdout_string_eq_x!(command, expr)`\n",
+                                            $crate::assertables_url_line!("assert_command_stdout_string_eq_x"),
+                                            " command label: `{}`,\n",
+                                            " command debug: `{:?}`,\n",
+                                            "    expr label: `{}`,\n",
+                                            "    expr debug: `{:?}`,\n",
+                                            "      utf8 err: `{:?}`"
+                                        ),
+                                        stringify!($command),
+                                        $command,
+                                        stringify!($expr),
+                                        expr,
+                                        err
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_command_stdout_string_eq_x!(command, expr)`\n",
+                                    $crate::assertables_url_line!("assert_command_stdout_string_eq_x"),
+                                    " command label: `{}`,\n",
+                                    " command debug: `{:?}`,\n",
+                                    "    expr label: `{}`,\n",
+                                    "    expr debug: `{:?}`,\n",
+                                    "    output err: `{:?}`"
+                                ),
+                                stringify!($command),
+                                $command,
+                                stringify!($expr),
+                                expr,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdout_string_eq_x_as_result {
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let s = "alfa";
+        let actual = assert_command_stdout_string_eq_x_as_result!(command, s);
+        assert_eq!(actual.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn failure() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let s = "zz";
+        let actual = assert_command_stdout_string_eq_x_as_result!(command, s);
+        let message = concat!(
+            "assertion failed: `assert_command_stdout_string_eq_x!(command, expr)`\n",
+            assertables_url_line!("assert_command_stdout_string_eq_x"),
+            " command label: `command`,\n",
+            " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+            "    expr label: `s`,\n",
+            "    expr debug: `\"zz\"`,\n",
+            " command value: `\"alfa\"`,\n",
+            "    expr value: `\"zz\"`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_string() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let actual = assert_command_stdout_string_eq_x_as_result!(command, String::from("alfa"));
+        assert_eq!(actual.unwrap(), "alfa");
+    }
+}
+
+/// Assert a command stdout string is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string) = (expr into string)
+///
+/// * If true, return `string`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let s = "alfa";
+/// assert_command_stdout_string_eq_x!(command, s);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut command = Command::new("bin/printf-stdout");
+/// command.args(["%s", "alfa"]);
+/// let s = "zz";
+/// assert_command_stdout_string_eq_x!(command, s);
+/// # });
+/// // assertion failed: `assert_command_stdout_string_eq_x!(command, expr)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_command_stdout_string_eq_x.html
+/// //  command label: `command`,
+/// //  command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,
+/// //     expr label: `s`,
+/// //     expr debug: `\"zz\"`,
+/// //  command value: `\"alfa\"`,
+/// //     expr value: `\"zz\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_command_stdout_string_eq_x!(command, expr)`\n",
+/// #     assertables_url_line!("assert_command_stdout_string_eq_x"),
+/// #     " command label: `command`,\n",
+/// #     " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+/// #     "    expr label: `s`,\n",
+/// #     "    expr debug: `\"zz\"`,\n",
+/// #     " command value: `\"alfa\"`,\n",
+/// #     "    expr value: `\"zz\"`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro converts the stdout bytes to a UTF-8 string, then compares
+/// the string with the expression, such as a `&str` or a `String`.
+/// To compare the stdout as raw bytes, use
+/// [`assert_command_stdout_eq_x`](macro@crate::assert_command_stdout_eq_x).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_string_eq_x`](macro@crate::assert_command_stdout_string_eq_x)
+/// * [`assert_command_stdout_string_eq_x_as_result`](macro@crate::assert_command_stdout_string_eq_x_as_result)
+/// * [`debug_assert_command_stdout_string_eq_x`](macro@crate::debug_assert_command_stdout_string_eq_x)
+///
+#[macro_export]
+macro_rules! assert_command_stdout_string_eq_x {
+    ($command:expr, $expr:expr $(,)?) => {{
+        match $crate::assert_command_stdout_string_eq_x_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($command:expr, $expr:expr, $($message:tt)+) => {{
+        match $crate::assert_command_stdout_string_eq_x_as_result!($command, $expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_command_stdout_string_eq_x {
+    use std::panic;
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut command = Command::new("bin/printf-stdout");
+        command.args(["%s", "alfa"]);
+        let s = "alfa";
+        let actual = assert_command_stdout_string_eq_x!(command, s);
+        assert_eq!(actual, "alfa");
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut command = Command::new("bin/printf-stdout");
+            command.args(["%s", "alfa"]);
+            let s = "zz";
+            let _actual = assert_command_stdout_string_eq_x!(command, s);
+        });
+        let message = concat!(
+            "assertion failed: `assert_command_stdout_string_eq_x!(command, expr)`\n",
+            assertables_url_line!("assert_command_stdout_string_eq_x"),
+            " command label: `command`,\n",
+            " command debug: `\"bin/printf-stdout\" \"%s\" \"alfa\"`,\n",
+            "    expr label: `s`,\n",
+            "    expr debug: `\"zz\"`,\n",
+            " command value: `\"alfa\"`,\n",
+            "    expr value: `\"zz\"`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a command stdout string is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (command ⇒ stdout ⇒ string) = (expr into string)
+///
+/// This macro provides the same statements as [`assert_command_stdout_string_eq_x`](macro.assert_command_stdout_string_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_command_stdout_string_eq_x`](macro@crate::assert_command_stdout_string_eq_x)
+/// * [`assert_command_stdout_string_eq_x_as_result`](macro@crate::assert_command_stdout_string_eq_x_as_result)
+/// * [`debug_assert_command_stdout_string_eq_x`](macro@crate::debug_assert_command_stdout_string_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_command_stdout_string_eq_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_command_stdout_string_eq_x!($($arg)*);
+        }
+    };
+}
@@ -19,7 +19,7 @@
 //!
 //! Compare command standard output to an expression:
 //!
-//! * [`assert_command_stdout_eq_x!(command, expr)`](macro@crate::assert_command_stdout_eq_x) ≈ command stdout = expr
+//! * [`assert_command_stdout_eq_x!(command, expr)`](macro@crate::assert_command_stdout_eq_x) ≈ command stdout = expr bytes
 //! * [`assert_command_stdout_ne_x!(command, expr)`](macro@crate::assert_command_stdout_ne_x) ≈ command stdout ≠ expr
 //! * [`assert_command_stdout_lt_x!(command, expr)`](macro@crate::assert_command_stdout_lt_x) ≈ command stdout < expr
 //! * [`assert_command_stdout_le_x!(command, expr)`](macro@crate::assert_command_stdout_le_x) ≈ command stdout ≤ expr
@@ -28,8 +28,23 @@
 //!
 //! Assert command standard output as a string:
 //!
+//! * [`assert_command_stdout_string_eq_x!(command, expr)`](macro@crate::assert_command_stdout_string_eq_x) ≈ command stdout string = expr
 //! * [`assert_command_stdout_string_contains!(command, containee)`](macro@crate::assert_command_stdout_string_contains) ≈ command stdout string contains containee
 //! * [`assert_command_stdout_string_is_match!(command, matcher)`](macro@crate::assert_command_stdout_string_is_match) ≈ command stdout string is a matcher match
+//! * [`assert_command_stdout_string_not_contains!(command, containee)`](macro@crate::assert_command_stdout_string_not_contains) ≈ command stdout string does not contain containee
+//! * [`assert_command_stdout_is_utf8!(command)`](macro@crate::assert_command_stdout_is_utf8) ≈ command stdout is valid UTF-8
+//!
+//! Compare command standard output to an expression, given standard input:
+//!
+//! * [`assert_command_stdin_stdout_eq_x!(command, stdin, expr)`](macro@crate::assert_command_stdin_stdout_eq_x) ≈ command with stdin ⇒ stdout = expr bytes
+//!
+//! Compare command standard output to an expression, within a timeout:
+//!
+//! * [`assert_command_stdout_eq_x_timeout!(command, expr, duration)`](macro@crate::assert_command_stdout_eq_x_timeout) ≈ command stdout within duration = expr bytes
+//!
+//! Assert command standard output lines as they are streamed:
+//!
+//! * [`assert_command_stdout_line_appears!(command, matcher, timeout)`](macro@crate::assert_command_stdout_line_appears) ≈ command stdout has a line that is a matcher match within timeout
 //!
 //! ## Macros for command standard error
 //!
@@ -55,6 +70,23 @@
 //!
 //! * [`assert_command_stderr_string_contains!(command, containee)`](macro@crate::assert_command_stderr_string_contains) ≈ command stderr string contains containee
 //! * [`assert_command_stderr_string_is_match!(command, matcher)`](macro@crate::assert_command_stderr_string_is_match) ≈ command stderr string is a matcher match
+//! * [`assert_command_stderr_string_not_contains!(command, containee)`](macro@crate::assert_command_stderr_string_not_contains) ≈ command stderr string does not contain containee
+//!
+//! ## Macros for command output
+//!
+//! Run a command once, and return its output, to check many times:
+//!
+//! * [`assert_command_output!(command)`](macro@crate::assert_command_output) ≈ command ⇒ output
+//!
+//! Compare command standard output followed by standard error to an expression:
+//!
+//! * [`assert_command_output_eq_x!(command, expr)`](macro@crate::assert_command_output_eq_x) ≈ command stdout + stderr = expr bytes
+//!
+//! ## Macros for command resource usage
+//!
+//! Assert command peak memory, on Unix:
+//!
+//! * [`assert_command_max_rss!(command, kilobytes)`](macro@crate::assert_command_max_rss) ≈ command max rss ≤ kilobytes
 //!
 //! # Example
 //!
@@ -127,8 +159,20 @@ pub mod assert_command_stdout_ne_x;
 // stdout string
 pub mod assert_command_stdout_contains;
 pub mod assert_command_stdout_is_match;
+pub mod assert_command_stdout_is_utf8;
 pub mod assert_command_stdout_string_contains;
+pub mod assert_command_stdout_string_eq_x;
 pub mod assert_command_stdout_string_is_match;
+pub mod assert_command_stdout_string_not_contains;
+
+// stdin
+pub mod assert_command_stdin_stdout_eq_x;
+
+// stdout timeout
+pub mod assert_command_stdout_eq_x_timeout;
+
+// stdout lines
+pub mod assert_command_stdout_line_appears;
 
 // stderr
 pub mod assert_command_stderr_eq;
@@ -151,3 +195,30 @@ pub mod assert_command_stderr_contains;
 pub mod assert_command_stderr_is_match;
 pub mod assert_command_stderr_string_contains;
 pub mod assert_command_stderr_string_is_match;
+pub mod assert_command_stderr_string_not_contains;
+
+// output
+pub mod assert_command_output;
+pub mod assert_command_output_eq_x;
+
+// resource usage
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub mod assert_command_max_rss;
+
+/// Get the output of a command that has already run.
+///
+/// The command macros call `.output()` on their command argument. A
+/// `Command` uses its inherent method, which runs the command. An `Output`,
+/// such as from [`assert_command_output`](macro@crate::assert_command_output),
+/// uses this trait, which clones the captured output, so the macros can
+/// check the same output many times without running the command again.
+#[doc(hidden)]
+pub trait CapturedOutput {
+    fn output(&self) -> ::std::io::Result<::std::process::Output>;
+}
+
+impl CapturedOutput for ::std::process::Output {
+    fn output(&self) -> ::std::io::Result<::std::process::Output> {
+        Ok(self.clone())
+    }
+}
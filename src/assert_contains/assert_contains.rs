@@ -24,6 +24,19 @@
 //! assert_contains!(a, &b);
 //! ```
 //!
+//! The container is borrowed, not moved, so you can use the container again
+//! after the macro, such as to call the macro twice on the same `String`.
+//! The container and the containee are each evaluated once. The containee
+//! can be any argument of `contains`, such as a closure for a string; if its
+//! type is not `Clone` and `Debug`, then the failure message shows a note.
+//!
+//! If the container and containee are both strings, then the failure message
+//! also shows the nearest match: the longest leading part of the containee
+//! that the container does contain, and its byte index. For example, if the
+//! containee is "world", and the container only contains "wor", then the
+//! message says `"wor"` at its byte index. If the container does not contain
+//! even the first char, then the message says "none".
+//!
 //! # Module macros
 //!
 //! * [`assert_contains`](macro@crate::assert_contains)
@@ -51,25 +64,36 @@
 #[macro_export]
 macro_rules! assert_contains_as_result {
     ($container:expr, $containee:expr $(,)?) => {{
-        match (&$container, &$containee) {
+        #[allow(unused_imports)]
+        use $crate::assert_contains::{ProbeNearestMatch as _, ProbeNoNearestMatch as _};
+        #[allow(unused_imports)]
+        use $crate::__private::{ProbeKeepClone as _, ProbeKeepNone as _};
+        match (&$container, $containee) {
             (container, containee) => {
-                if container.contains($containee) {
+                let containee_kept = (&$crate::__private::KeepProbe(&containee)).keep();
+                if container.contains(containee) {
                     Ok(())
                 } else {
+                    let probe = $crate::assert_contains::NearestMatchProbe(container, &containee_kept);
+                    let nearest_match = match (&probe).probe_nearest_match() {
+                        Some(nearest_match) => format!(",\n   nearest match: {}", nearest_match),
+                        None => String::new(),
+                    };
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_contains!(container, containee)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains.html\n",
+                                $crate::assertables_url_line!("assert_contains"),
                                 " container label: `{}`,\n",
                                 " container debug: `{:?}`,\n",
                                 " containee label: `{}`,\n",
-                                " containee debug: `{:?}`",
+                                " containee debug: `{:?}`{}",
                             ),
                             stringify!($container),
                             container,
                             stringify!($containee),
-                            containee,
+                            containee_kept,
+                            nearest_match,
                         )
                     )
                 }
@@ -98,11 +122,63 @@ mod test_assert_contains_as_result {
             let actual = assert_contains_as_result!(a, b);
             let message = concat!(
                 "assertion failed: `assert_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains.html\n",
+                assertables_url_line!("assert_contains"),
                 " container label: `a`,\n",
                 " container debug: `\"alfa\"`,\n",
                 " containee label: `b`,\n",
-                " containee debug: `\"zz\"`"
+                " containee debug: `\"zz\"`,\n",
+                "   nearest match: none"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn failure_with_nearest_match() {
+            let a: &str = "hello wor";
+            let b = "world";
+            let actual = assert_contains_as_result!(a, b);
+            let message = concat!(
+                "assertion failed: `assert_contains!(container, containee)`\n",
+                assertables_url_line!("assert_contains"),
+                " container label: `a`,\n",
+                " container debug: `\"hello wor\"`,\n",
+                " containee label: `b`,\n",
+                " containee debug: `\"world\"`,\n",
+                "   nearest match: `\"wor\"` at byte index 6"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn failure_with_nearest_match_at_first_index() {
+            let a: String = String::from("w wo wor wo");
+            let b: String = String::from("world");
+            let actual = assert_contains_as_result!(a, &b);
+            let message = concat!(
+                "assertion failed: `assert_contains!(container, containee)`\n",
+                assertables_url_line!("assert_contains"),
+                " container label: `a`,\n",
+                " container debug: `\"w wo wor wo\"`,\n",
+                " containee label: `&b`,\n",
+                " containee debug: `\"world\"`,\n",
+                "   nearest match: `\"wor\"` at byte index 5"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn failure_with_nearest_match_on_char_boundary() {
+            let a: &str = "café";
+            let b = "cafés";
+            let actual = assert_contains_as_result!(a, b);
+            let message = concat!(
+                "assertion failed: `assert_contains!(container, containee)`\n",
+                assertables_url_line!("assert_contains"),
+                " container label: `a`,\n",
+                " container debug: `\"café\"`,\n",
+                " containee label: `b`,\n",
+                " containee debug: `\"cafés\"`,\n",
+                "   nearest match: `\"café\"` at byte index 0"
             );
             assert_eq!(actual.unwrap_err(), message);
         }
@@ -125,7 +201,7 @@ mod test_assert_contains_as_result {
             let actual = assert_contains_as_result!(a, &b);
             let message = concat!(
                 "assertion failed: `assert_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains.html\n",
+                assertables_url_line!("assert_contains"),
                 " container label: `a`,\n",
                 " container debug: `1..3`,\n",
                 " containee label: `&b`,\n",
@@ -152,7 +228,7 @@ mod test_assert_contains_as_result {
             let actual = assert_contains_as_result!(a, &b);
             let message = concat!(
                 "assertion failed: `assert_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains.html\n",
+                assertables_url_line!("assert_contains"),
                 " container label: `a`,\n",
                 " container debug: `[1, 2, 3]`,\n",
                 " containee label: `&b`,\n",
@@ -161,6 +237,39 @@ mod test_assert_contains_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    mod evaluation {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        #[test]
+        fn evaluates_each_expression_once() {
+            static A: AtomicU32 = AtomicU32::new(0);
+            static B: AtomicU32 = AtomicU32::new(0);
+            fn a() -> Vec<i32> {
+                A.fetch_add(1, Ordering::SeqCst);
+                vec![1, 2, 3]
+            }
+            fn b() -> &'static i32 {
+                B.fetch_add(1, Ordering::SeqCst);
+                &2
+            }
+            let _ = assert_contains_as_result!(a(), b());
+            assert_eq!(A.load(Ordering::SeqCst), 1);
+            assert_eq!(B.load(Ordering::SeqCst), 1);
+        }
+
+        #[test]
+        fn closure_that_captures_non_copy_state() {
+            let vowels = String::from("aeiou");
+            let actual = assert_contains_as_result!("alfa", move |c: char| vowels.contains(c));
+            assert_eq!(actual.unwrap(), ());
+            let vowels = String::from("aeiou");
+            let actual = assert_contains_as_result!("xyz", move |c: char| vowels.contains(c));
+            assert!(actual.unwrap_err().ends_with(
+                " containee debug: `(type does not implement Clone and Debug)`"
+            ));
+        }
+    }
 }
 
 /// Assert a container is a match for an expression.
@@ -201,15 +310,17 @@ mod test_assert_contains_as_result {
 /// //  container label: `a`,
 /// //  container debug: `\"alfa\"`,
 /// //  containee label: `b`,
-/// //  containee debug: `\"zz\"`
+/// //  containee debug: `\"zz\"`,
+/// //    nearest match: none
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_contains!(container, containee)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains.html\n",
+/// #     assertables_url_line!("assert_contains"),
 /// #     " container label: `a`,\n",
 /// #     " container debug: `\"alfa\"`,\n",
 /// #     " containee label: `b`,\n",
-/// #     " containee debug: `\"zz\"`"
+/// #     " containee debug: `\"zz\"`,\n",
+/// #     "   nearest match: none"
 /// # );
 /// # assert_eq!(actual, message);
 /// # }
@@ -226,13 +337,13 @@ macro_rules! assert_contains {
     ($container:expr, $containee:expr $(,)?) => {{
         match $crate::assert_contains_as_result!($container, $containee) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($container:expr, $containee:expr, $($message:tt)+) => {{
         match $crate::assert_contains_as_result!($container, $containee) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -260,11 +371,12 @@ mod test_assert_contains {
             });
             let message = concat!(
                 "assertion failed: `assert_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains.html\n",
+                assertables_url_line!("assert_contains"),
                 " container label: `a`,\n",
                 " container debug: `\"alfa\"`,\n",
                 " containee label: `b`,\n",
-                " containee debug: `\"zz\"`"
+                " containee debug: `\"zz\"`,\n",
+                "   nearest match: none"
             );
             assert_eq!(
                 result
@@ -297,7 +409,7 @@ mod test_assert_contains {
             });
             let message = concat!(
                 "assertion failed: `assert_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains.html\n",
+                assertables_url_line!("assert_contains"),
                 " container label: `a`,\n",
                 " container debug: `1..3`,\n",
                 " containee label: `&b`,\n",
@@ -334,7 +446,7 @@ mod test_assert_contains {
             });
             let message = concat!(
                 "assertion failed: `assert_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains.html\n",
+                assertables_url_line!("assert_contains"),
                 " container label: `a`,\n",
                 " container debug: `[1, 2, 3]`,\n",
                 " containee label: `&b`,\n",
@@ -350,6 +462,26 @@ mod test_assert_contains {
             );
         }
     }
+
+    mod reuse {
+
+        #[test]
+        fn string_is_not_moved() {
+            let a = String::from("alfa");
+            assert_contains!(a, "");
+            assert_contains!(a, "lf");
+            assert_eq!(a, "alfa");
+        }
+
+        #[test]
+        fn vec_is_not_moved() {
+            let a = vec![String::from("alfa"), String::from("bravo")];
+            let b = String::from("alfa");
+            assert_contains!(a, &b);
+            assert_contains!(a, &b);
+            assert_eq!(a.len(), 2);
+        }
+    }
 }
 
 /// Assert a container is a match for an expression.
@@ -386,7 +518,7 @@ mod test_assert_contains {
 #[macro_export]
 macro_rules! debug_assert_contains {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_contains!($($arg)*);
         }
     };
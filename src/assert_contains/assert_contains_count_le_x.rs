@@ -0,0 +1,297 @@
+//! Assert a container contains a containee a count of times less than or equal to an expression.
+//!
+//! Pseudocode:<br>
+//! container.count(containee) ≤ n
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "ERROR: alfa, ERROR: bravo";
+//! let n = 2;
+//! assert_contains_count_le_x!(a, "ERROR", n);
+//! ```
+//!
+//! The count depends on the container:
+//!
+//! * For a string, the containee can be a `&str`, a `String`, or a `char`.
+//!   The count is the number of non-overlapping occurrences, searching from
+//!   left to right, the same as `str::matches`. For example, the count of
+//!   "aa" in "aaa" is 1, not 2.
+//!
+//! * For a slice, array, or vector, the containee is an element,
+//!   and the count is the number of elements that are equal to it.
+//!
+//! # Module macros
+//!
+//! * [`assert_contains_count_le_x`](macro@crate::assert_contains_count_le_x)
+//! * [`assert_contains_count_le_x_as_result`](macro@crate::assert_contains_count_le_x_as_result)
+//! * [`debug_assert_contains_count_le_x`](macro@crate::debug_assert_contains_count_le_x)
+
+/// Assert a container contains a containee a count of times less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// container.count(containee) ≤ n
+///
+/// * If true, return Result `Ok(count)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_contains_count_le_x`](macro@crate::assert_contains_count_le_x)
+/// * [`assert_contains_count_le_x_as_result`](macro@crate::assert_contains_count_le_x_as_result)
+/// * [`debug_assert_contains_count_le_x`](macro@crate::debug_assert_contains_count_le_x)
+///
+#[macro_export]
+macro_rules! assert_contains_count_le_x_as_result {
+    ($container:expr, $containee:expr, $n:expr $(,)?) => {{
+        match (&$container, &$containee, &$n) {
+            (container, containee, n) => {
+                let count: usize = {
+                    use $crate::assert_contains::ContainsCount;
+                    container.contains_count(containee)
+                };
+                if count <= *n {
+                    Ok(count)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_contains_count_le_x!(container, containee, n)`\n",
+                                $crate::assertables_url_line!("assert_contains_count_le_x"),
+                                " container label: `{}`,\n",
+                                " container debug: `{:?}`,\n",
+                                " containee label: `{}`,\n",
+                                " containee debug: `{:?}`,\n",
+                                "         n label: `{}`,\n",
+                                "         n debug: `{:?}`,\n",
+                                "           count: `{}`"
+                            ),
+                            stringify!($container),
+                            container,
+                            stringify!($containee),
+                            containee,
+                            stringify!($n),
+                            n,
+                            count
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_contains_count_le_x_as_result {
+    #[test]
+    fn success() {
+        let a = "ERROR: alfa, ERROR: bravo";
+        let n = 2;
+        let actual = assert_contains_count_le_x_as_result!(a, "ERROR", n);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure() {
+        let a = "ERROR: alfa, ERROR: bravo";
+        let n = 1;
+        let actual = assert_contains_count_le_x_as_result!(a, "ERROR", n);
+        let message = concat!(
+            "assertion failed: `assert_contains_count_le_x!(container, containee, n)`\n",
+            assertables_url_line!("assert_contains_count_le_x"),
+            " container label: `a`,\n",
+            " container debug: `\"ERROR: alfa, ERROR: bravo\"`,\n",
+            " containee label: `\"ERROR\"`,\n",
+            " containee debug: `\"ERROR\"`,\n",
+            "         n label: `n`,\n",
+            "         n debug: `1`,\n",
+            "           count: `2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_char() {
+        let a = String::from("a-b-c");
+        let actual = assert_contains_count_le_x_as_result!(a, '-', 2);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn success_with_vec() {
+        let a = vec![1, 2, 1, 3];
+        let actual = assert_contains_count_le_x_as_result!(a, 1, 2);
+        assert_eq!(actual.unwrap(), 2);
+    }
+}
+
+/// Assert a container contains a containee a count of times less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// container.count(containee) ≤ n
+///
+/// * If true, return `count`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "ERROR: alfa, ERROR: bravo";
+/// let n = 2;
+/// assert_contains_count_le_x!(a, "ERROR", n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "ERROR: alfa, ERROR: bravo";
+/// let n = 1;
+/// assert_contains_count_le_x!(a, "ERROR", n);
+/// # });
+/// // assertion failed: `assert_contains_count_le_x!(container, containee, n)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains_count_le_x.html
+/// //  container label: `a`,
+/// //  container debug: `\"ERROR: alfa, ERROR: bravo\"`,
+/// //  containee label: `\"ERROR\"`,
+/// //  containee debug: `\"ERROR\"`,
+/// //          n label: `n`,
+/// //          n debug: `1`,
+/// //            count: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_contains_count_le_x!(container, containee, n)`\n",
+/// #     assertables_url_line!("assert_contains_count_le_x"),
+/// #     " container label: `a`,\n",
+/// #     " container debug: `\"ERROR: alfa, ERROR: bravo\"`,\n",
+/// #     " containee label: `\"ERROR\"`,\n",
+/// #     " containee debug: `\"ERROR\"`,\n",
+/// #     "         n label: `n`,\n",
+/// #     "         n debug: `1`,\n",
+/// #     "           count: `2`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The count depends on the container:
+///
+/// * For a string, the containee can be a `&str`, a `String`, or a `char`.
+///   The count is the number of non-overlapping occurrences, searching from
+///   left to right, the same as `str::matches`. For example, the count of
+///   "aa" in "aaa" is 1, not 2.
+///
+/// * For a slice, array, or vector, the containee is an element,
+///   and the count is the number of elements that are equal to it.
+///
+/// # Module macros
+///
+/// * [`assert_contains_count_le_x`](macro@crate::assert_contains_count_le_x)
+/// * [`assert_contains_count_le_x_as_result`](macro@crate::assert_contains_count_le_x_as_result)
+/// * [`debug_assert_contains_count_le_x`](macro@crate::debug_assert_contains_count_le_x)
+///
+#[macro_export]
+macro_rules! assert_contains_count_le_x {
+    ($container:expr, $containee:expr, $n:expr $(,)?) => {{
+        match $crate::assert_contains_count_le_x_as_result!($container, $containee, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($container:expr, $containee:expr, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_contains_count_le_x_as_result!($container, $containee, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_contains_count_le_x {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = "ERROR: alfa, ERROR: bravo";
+        let n = 2;
+        let actual = assert_contains_count_le_x!(a, "ERROR", n);
+        assert_eq!(actual, 2);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = "ERROR: alfa, ERROR: bravo";
+            let n = 1;
+            let _actual = assert_contains_count_le_x!(a, "ERROR", n);
+        });
+        let message = concat!(
+            "assertion failed: `assert_contains_count_le_x!(container, containee, n)`\n",
+            assertables_url_line!("assert_contains_count_le_x"),
+            " container label: `a`,\n",
+            " container debug: `\"ERROR: alfa, ERROR: bravo\"`,\n",
+            " containee label: `\"ERROR\"`,\n",
+            " containee debug: `\"ERROR\"`,\n",
+            "         n label: `n`,\n",
+            "         n debug: `1`,\n",
+            "           count: `2`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a container contains a containee a count of times less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// container.count(containee) ≤ n
+///
+/// This macro provides the same statements as [`assert_contains_count_le_x`](macro.assert_contains_count_le_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_contains_count_le_x`](macro@crate::assert_contains_count_le_x)
+/// * [`assert_contains_count_le_x_as_result`](macro@crate::assert_contains_count_le_x_as_result)
+/// * [`debug_assert_contains_count_le_x`](macro@crate::debug_assert_contains_count_le_x)
+///
+#[macro_export]
+macro_rules! debug_assert_contains_count_le_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_contains_count_le_x!($($arg)*);
+        }
+    };
+}
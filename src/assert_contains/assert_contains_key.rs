@@ -0,0 +1,305 @@
+//! Assert a map contains a key.
+//!
+//! Pseudocode:<br>
+//! map.contains_key(key)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::collections::HashMap;
+//!
+//! let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+//! let b = "alfa";
+//! assert_contains_key!(a, &b);
+//! ```
+//!
+//! The map can be a `HashMap`, a `BTreeMap`, or anything with methods
+//! `contains_key`, `keys`, and `len`. The map is borrowed, not moved,
+//! so you can use the map again after the macro.
+//!
+//! The key is a reference, such as `&key`, or a `&str` for a map with
+//! `String` keys, the same as the argument of `contains_key`.
+//!
+//! The failure message shows the map's keys, sorted by their debug text,
+//! and truncated after the first ten.
+//!
+//! # Module macros
+//!
+//! * [`assert_contains_key`](macro@crate::assert_contains_key)
+//! * [`assert_contains_key_as_result`](macro@crate::assert_contains_key_as_result)
+//! * [`debug_assert_contains_key`](macro@crate::debug_assert_contains_key)
+
+/// Assert a map contains a key.
+///
+/// Pseudocode:<br>
+/// map.contains_key(key)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_contains_key`](macro@crate::assert_contains_key)
+/// * [`assert_contains_key_as_result`](macro@crate::assert_contains_key_as_result)
+/// * [`debug_assert_contains_key`](macro@crate::debug_assert_contains_key)
+///
+#[macro_export]
+macro_rules! assert_contains_key_as_result {
+    ($map:expr, $key:expr $(,)?) => {{
+        match (&$map, $key) {
+            (map, key) => {
+                if map.contains_key(key) {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_contains_key!(map, key)`\n",
+                                $crate::assertables_url_line!("assert_contains_key"),
+                                " map label: `{}`,\n",
+                                " key label: `{}`,\n",
+                                " key debug: `{:?}`,\n",
+                                "   map len: `{}`,\n",
+                                "  map keys: `{}`"
+                            ),
+                            stringify!($map),
+                            stringify!($key),
+                            key,
+                            map.len(),
+                            $crate::assert_contains::debug_list_truncated(map.keys())
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_contains_key_as_result {
+    use std::collections::BTreeMap;
+    use std::collections::HashMap;
+
+    #[test]
+    fn success() {
+        let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+        let b = "alfa";
+        let actual = assert_contains_key_as_result!(a, &b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+        let b = "zz";
+        let actual = assert_contains_key_as_result!(a, &b);
+        let message = concat!(
+            "assertion failed: `assert_contains_key!(map, key)`\n",
+            assertables_url_line!("assert_contains_key"),
+            " map label: `a`,\n",
+            " key label: `&b`,\n",
+            " key debug: `\"zz\"`,\n",
+            "   map len: `2`,\n",
+            "  map keys: `[\"alfa\", \"bravo\"]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_btreemap_and_string_keys() {
+        let a: BTreeMap<String, i32> = BTreeMap::from([(String::from("alfa"), 1)]);
+        let actual = assert_contains_key_as_result!(a, "alfa");
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_many_keys_is_truncated() {
+        let a: BTreeMap<i32, i32> = (10..22).map(|i| (i, i)).collect();
+        let b = 1;
+        let actual = assert_contains_key_as_result!(a, &b);
+        let message = concat!(
+            "assertion failed: `assert_contains_key!(map, key)`\n",
+            assertables_url_line!("assert_contains_key"),
+            " map label: `a`,\n",
+            " key label: `&b`,\n",
+            " key debug: `1`,\n",
+            "   map len: `12`,\n",
+            "  map keys: `[10, 11, 12, 13, 14, 15, 16, 17, 18, 19, … 2 more]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn borrows_the_map() {
+        let a: HashMap<String, i32> = HashMap::from([(String::from("alfa"), 1)]);
+        let _ = assert_contains_key_as_result!(a, "alfa");
+        let _ = assert_contains_key_as_result!(a, "alfa");
+        assert_eq!(a.len(), 1);
+    }
+}
+
+/// Assert a map contains a key.
+///
+/// Pseudocode:<br>
+/// map.contains_key(key)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::collections::HashMap;
+///
+/// # fn main() {
+/// let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+/// let b = "alfa";
+/// assert_contains_key!(a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+/// let b = "zz";
+/// assert_contains_key!(a, &b);
+/// # });
+/// // assertion failed: `assert_contains_key!(map, key)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains_key.html
+/// //  map label: `a`,
+/// //  key label: `&b`,
+/// //  key debug: `\"zz\"`,
+/// //    map len: `2`,
+/// //   map keys: `[\"alfa\", \"bravo\"]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_contains_key!(map, key)`\n",
+/// #     assertables_url_line!("assert_contains_key"),
+/// #     " map label: `a`,\n",
+/// #     " key label: `&b`,\n",
+/// #     " key debug: `\"zz\"`,\n",
+/// #     "   map len: `2`,\n",
+/// #     "  map keys: `[\"alfa\", \"bravo\"]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The map can be a `HashMap`, a `BTreeMap`, or anything with methods
+/// `contains_key`, `keys`, and `len`. The map is borrowed, not moved,
+/// so you can use the map again after the macro.
+///
+/// The key is a reference, such as `&key`, or a `&str` for a map with
+/// `String` keys, the same as the argument of `contains_key`.
+///
+/// The failure message shows the map's keys, sorted by their debug text,
+/// and truncated after the first ten.
+///
+/// # Module macros
+///
+/// * [`assert_contains_key`](macro@crate::assert_contains_key)
+/// * [`assert_contains_key_as_result`](macro@crate::assert_contains_key_as_result)
+/// * [`debug_assert_contains_key`](macro@crate::debug_assert_contains_key)
+///
+#[macro_export]
+macro_rules! assert_contains_key {
+    ($map:expr, $key:expr $(,)?) => {{
+        match $crate::assert_contains_key_as_result!($map, $key) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($map:expr, $key:expr, $($message:tt)+) => {{
+        match $crate::assert_contains_key_as_result!($map, $key) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_contains_key {
+    use std::collections::HashMap;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+        let b = "alfa";
+        let actual = assert_contains_key!(a, &b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+            let b = "zz";
+            let _actual = assert_contains_key!(a, &b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_contains_key!(map, key)`\n",
+            assertables_url_line!("assert_contains_key"),
+            " map label: `a`,\n",
+            " key label: `&b`,\n",
+            " key debug: `\"zz\"`,\n",
+            "   map len: `2`,\n",
+            "  map keys: `[\"alfa\", \"bravo\"]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a map contains a key.
+///
+/// Pseudocode:<br>
+/// map.contains_key(key)
+///
+/// This macro provides the same statements as [`assert_contains_key`](macro.assert_contains_key.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_contains_key`](macro@crate::assert_contains_key)
+/// * [`assert_contains_key_as_result`](macro@crate::assert_contains_key_as_result)
+/// * [`debug_assert_contains_key`](macro@crate::debug_assert_contains_key)
+///
+#[macro_export]
+macro_rules! debug_assert_contains_key {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_contains_key!($($arg)*);
+        }
+    };
+}
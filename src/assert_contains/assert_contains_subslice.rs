@@ -0,0 +1,360 @@
+//! Assert a slice contains a contiguous subslice.
+//!
+//! Pseudocode:<br>
+//! haystack.windows(needle.len()).any(|x| x == needle)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3, 4];
+//! let b = [2, 3];
+//! assert_contains_subslice!(a, b);
+//! ```
+//!
+//! The haystack and the needle can be anything that can be sliced with
+//! `[..]`, such as an array, a slice, or a `Vec`. The items must implement
+//! `PartialEq` and `Debug`. The arguments are borrowed, not moved.
+//!
+//! An empty needle is contained in any haystack.
+//!
+//! The failure message shows the closest partial match, which is the
+//! longest run of the needle's leading items found in the haystack,
+//! or "none" if the haystack does not contain the needle's first item.
+//!
+//! # Module macros
+//!
+//! * [`assert_contains_subslice`](macro@crate::assert_contains_subslice)
+//! * [`assert_contains_subslice_as_result`](macro@crate::assert_contains_subslice_as_result)
+//! * [`debug_assert_contains_subslice`](macro@crate::debug_assert_contains_subslice)
+
+/// Assert a slice contains a contiguous subslice.
+///
+/// Pseudocode:<br>
+/// haystack.windows(needle.len()).any(|x| x == needle)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_contains_subslice`](macro@crate::assert_contains_subslice)
+/// * [`assert_contains_subslice_as_result`](macro@crate::assert_contains_subslice_as_result)
+/// * [`debug_assert_contains_subslice`](macro@crate::debug_assert_contains_subslice)
+///
+#[macro_export]
+macro_rules! assert_contains_subslice_as_result {
+    ($haystack:expr, $needle:expr $(,)?) => {{
+        match (&$haystack, &$needle) {
+            (haystack, needle) => {
+                let haystack_slice = &haystack[..];
+                let needle_slice = &needle[..];
+                if $crate::assert_contains::subslice_position(haystack_slice, needle_slice).is_some() {
+                    Ok(())
+                } else {
+                    let closest_match = match $crate::assert_contains::subslice_closest_match(haystack_slice, needle_slice) {
+                        Some((index, count)) => format!("`haystack[{}..{}] = needle[..{}]`", index, index + count, count),
+                        None => String::from("none"),
+                    };
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_contains_subslice!(haystack, needle)`\n",
+                                $crate::assertables_url_line!("assert_contains_subslice"),
+                                " haystack label: `{}`,\n",
+                                " haystack debug: `{:?}`,\n",
+                                "   needle label: `{}`,\n",
+                                "   needle debug: `{:?}`,\n",
+                                "  closest match: {}"
+                            ),
+                            stringify!($haystack),
+                            haystack,
+                            stringify!($needle),
+                            needle,
+                            closest_match
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_contains_subslice_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 2, 3, 4];
+        let b = [2, 3];
+        let actual = assert_contains_subslice_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 2, 3, 4];
+        let b = [3, 5];
+        let actual = assert_contains_subslice_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_contains_subslice!(haystack, needle)`\n",
+            assertables_url_line!("assert_contains_subslice"),
+            " haystack label: `a`,\n",
+            " haystack debug: `[1, 2, 3, 4]`,\n",
+            "   needle label: `b`,\n",
+            "   needle debug: `[3, 5]`,\n",
+            "  closest match: `haystack[2..3] = needle[..1]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_needle_at_end() {
+        let a = [1, 2, 3, 4];
+        let b = [3, 4];
+        let actual = assert_contains_subslice_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_empty_needle() {
+        let a = vec![1, 2, 3, 4];
+        let b: Vec<i32> = vec![];
+        let actual = assert_contains_subslice_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+        let a: Vec<i32> = vec![];
+        let actual = assert_contains_subslice_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_strings() {
+        let a = vec!["alfa", "bravo", "charlie"];
+        let actual = assert_contains_subslice_as_result!(a, &["bravo", "charlie"]);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_because_not_contiguous() {
+        let a = vec![1, 2, 3, 4];
+        let b = vec![2, 4];
+        let actual = assert_contains_subslice_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_contains_subslice!(haystack, needle)`\n",
+            assertables_url_line!("assert_contains_subslice"),
+            " haystack label: `a`,\n",
+            " haystack debug: `[1, 2, 3, 4]`,\n",
+            "   needle label: `b`,\n",
+            "   needle debug: `[2, 4]`,\n",
+            "  closest match: `haystack[1..2] = needle[..1]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_needle_is_longer_than_haystack() {
+        let a = vec![1, 2];
+        let b = vec![1, 2, 3];
+        let actual = assert_contains_subslice_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_contains_subslice!(haystack, needle)`\n",
+            assertables_url_line!("assert_contains_subslice"),
+            " haystack label: `a`,\n",
+            " haystack debug: `[1, 2]`,\n",
+            "   needle label: `b`,\n",
+            "   needle debug: `[1, 2, 3]`,\n",
+            "  closest match: `haystack[0..2] = needle[..2]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_with_no_partial_match() {
+        let a = vec![1, 2, 3, 4];
+        let b = vec![8, 9];
+        let actual = assert_contains_subslice_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_contains_subslice!(haystack, needle)`\n",
+            assertables_url_line!("assert_contains_subslice"),
+            " haystack label: `a`,\n",
+            " haystack debug: `[1, 2, 3, 4]`,\n",
+            "   needle label: `b`,\n",
+            "   needle debug: `[8, 9]`,\n",
+            "  closest match: none"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn closest_match_is_the_longest() {
+        let a = vec![1, 2, 9, 1, 2, 3, 9];
+        let b = vec![1, 2, 3, 4];
+        let actual = assert_contains_subslice_as_result!(a, b);
+        assert!(actual
+            .unwrap_err()
+            .ends_with("  closest match: `haystack[3..6] = needle[..3]`"));
+    }
+}
+
+/// Assert a slice contains a contiguous subslice.
+///
+/// Pseudocode:<br>
+/// haystack.windows(needle.len()).any(|x| x == needle)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3, 4];
+/// let b = [2, 3];
+/// assert_contains_subslice!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3, 4];
+/// let b = [3, 5];
+/// assert_contains_subslice!(a, b);
+/// # });
+/// // assertion failed: `assert_contains_subslice!(haystack, needle)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains_subslice.html
+/// //  haystack label: `a`,
+/// //  haystack debug: `[1, 2, 3, 4]`,
+/// //    needle label: `b`,
+/// //    needle debug: `[3, 5]`,
+/// //   closest match: `haystack[2..3] = needle[..1]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_contains_subslice!(haystack, needle)`\n",
+/// #     assertables_url_line!("assert_contains_subslice"),
+/// #     " haystack label: `a`,\n",
+/// #     " haystack debug: `[1, 2, 3, 4]`,\n",
+/// #     "   needle label: `b`,\n",
+/// #     "   needle debug: `[3, 5]`,\n",
+/// #     "  closest match: `haystack[2..3] = needle[..1]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The haystack and the needle can be anything that can be sliced with
+/// `[..]`, such as an array, a slice, or a `Vec`. The items must implement
+/// `PartialEq` and `Debug`. The arguments are borrowed, not moved.
+///
+/// An empty needle is contained in any haystack.
+///
+/// The failure message shows the closest partial match, which is the
+/// longest run of the needle's leading items found in the haystack,
+/// or "none" if the haystack does not contain the needle's first item.
+///
+/// # Module macros
+///
+/// * [`assert_contains_subslice`](macro@crate::assert_contains_subslice)
+/// * [`assert_contains_subslice_as_result`](macro@crate::assert_contains_subslice_as_result)
+/// * [`debug_assert_contains_subslice`](macro@crate::debug_assert_contains_subslice)
+///
+#[macro_export]
+macro_rules! assert_contains_subslice {
+    ($haystack:expr, $needle:expr $(,)?) => {{
+        match $crate::assert_contains_subslice_as_result!($haystack, $needle) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($haystack:expr, $needle:expr, $($message:tt)+) => {{
+        match $crate::assert_contains_subslice_as_result!($haystack, $needle) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_contains_subslice {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3, 4];
+        let b = [2, 3];
+        let actual = assert_contains_subslice!(a, b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 2, 3, 4];
+            let b = [3, 5];
+            let _actual = assert_contains_subslice!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_contains_subslice!(haystack, needle)`\n",
+            assertables_url_line!("assert_contains_subslice"),
+            " haystack label: `a`,\n",
+            " haystack debug: `[1, 2, 3, 4]`,\n",
+            "   needle label: `b`,\n",
+            "   needle debug: `[3, 5]`,\n",
+            "  closest match: `haystack[2..3] = needle[..1]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a slice contains a contiguous subslice.
+///
+/// Pseudocode:<br>
+/// haystack.windows(needle.len()).any(|x| x == needle)
+///
+/// This macro provides the same statements as [`assert_contains_subslice`](macro.assert_contains_subslice.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_contains_subslice`](macro@crate::assert_contains_subslice)
+/// * [`assert_contains_subslice_as_result`](macro@crate::assert_contains_subslice_as_result)
+/// * [`debug_assert_contains_subslice`](macro@crate::debug_assert_contains_subslice)
+///
+#[macro_export]
+macro_rules! debug_assert_contains_subslice {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_contains_subslice!($($arg)*);
+        }
+    };
+}
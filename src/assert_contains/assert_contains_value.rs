@@ -0,0 +1,290 @@
+//! Assert a map contains a value.
+//!
+//! Pseudocode:<br>
+//! map.values().any(|x| x == value)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::collections::HashMap;
+//!
+//! let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+//! let b = 1;
+//! assert_contains_value!(a, &b);
+//! ```
+//!
+//! The map can be a `HashMap`, a `BTreeMap`, or anything with methods
+//! `values` and `len`. The map is borrowed, not moved, so you can use
+//! the map again after the macro.
+//!
+//! The value is a reference, such as `&value`. This macro checks each
+//! value in turn, so it takes time proportional to the map's length.
+//!
+//! The failure message shows the map's values, sorted by their debug text,
+//! and truncated after the first ten.
+//!
+//! # Module macros
+//!
+//! * [`assert_contains_value`](macro@crate::assert_contains_value)
+//! * [`assert_contains_value_as_result`](macro@crate::assert_contains_value_as_result)
+//! * [`debug_assert_contains_value`](macro@crate::debug_assert_contains_value)
+
+/// Assert a map contains a value.
+///
+/// Pseudocode:<br>
+/// map.values().any(|x| x == value)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_contains_value`](macro@crate::assert_contains_value)
+/// * [`assert_contains_value_as_result`](macro@crate::assert_contains_value_as_result)
+/// * [`debug_assert_contains_value`](macro@crate::debug_assert_contains_value)
+///
+#[macro_export]
+macro_rules! assert_contains_value_as_result {
+    ($map:expr, $value:expr $(,)?) => {{
+        match (&$map, $value) {
+            (map, value) => {
+                if map.values().any(|value_| value_ == value) {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_contains_value!(map, value)`\n",
+                                $crate::assertables_url_line!("assert_contains_value"),
+                                "   map label: `{}`,\n",
+                                " value label: `{}`,\n",
+                                " value debug: `{:?}`,\n",
+                                "     map len: `{}`,\n",
+                                "  map values: `{}`"
+                            ),
+                            stringify!($map),
+                            stringify!($value),
+                            value,
+                            map.len(),
+                            $crate::assert_contains::debug_list_truncated(map.values())
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_contains_value_as_result {
+    use std::collections::BTreeMap;
+    use std::collections::HashMap;
+
+    #[test]
+    fn success() {
+        let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+        let b = 1;
+        let actual = assert_contains_value_as_result!(a, &b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+        let b = 3;
+        let actual = assert_contains_value_as_result!(a, &b);
+        let message = concat!(
+            "assertion failed: `assert_contains_value!(map, value)`\n",
+            assertables_url_line!("assert_contains_value"),
+            "   map label: `a`,\n",
+            " value label: `&b`,\n",
+            " value debug: `3`,\n",
+            "     map len: `2`,\n",
+            "  map values: `[1, 2]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_btreemap() {
+        let a: BTreeMap<i32, String> = BTreeMap::from([(1, String::from("alfa"))]);
+        let b = String::from("alfa");
+        let actual = assert_contains_value_as_result!(a, &b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn borrows_the_map() {
+        let a: HashMap<i32, String> = HashMap::from([(1, String::from("alfa"))]);
+        let b = String::from("alfa");
+        let _ = assert_contains_value_as_result!(a, &b);
+        let _ = assert_contains_value_as_result!(a, &b);
+        assert_eq!(a.len(), 1);
+    }
+}
+
+/// Assert a map contains a value.
+///
+/// Pseudocode:<br>
+/// map.values().any(|x| x == value)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::collections::HashMap;
+///
+/// # fn main() {
+/// let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+/// let b = 1;
+/// assert_contains_value!(a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+/// let b = 3;
+/// assert_contains_value!(a, &b);
+/// # });
+/// // assertion failed: `assert_contains_value!(map, value)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_contains_value.html
+/// //    map label: `a`,
+/// //  value label: `&b`,
+/// //  value debug: `3`,
+/// //      map len: `2`,
+/// //   map values: `[1, 2]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_contains_value!(map, value)`\n",
+/// #     assertables_url_line!("assert_contains_value"),
+/// #     "   map label: `a`,\n",
+/// #     " value label: `&b`,\n",
+/// #     " value debug: `3`,\n",
+/// #     "     map len: `2`,\n",
+/// #     "  map values: `[1, 2]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The map can be a `HashMap`, a `BTreeMap`, or anything with methods
+/// `values` and `len`. The map is borrowed, not moved, so you can use
+/// the map again after the macro.
+///
+/// The value is a reference, such as `&value`. This macro checks each
+/// value in turn, so it takes time proportional to the map's length.
+///
+/// The failure message shows the map's values, sorted by their debug text,
+/// and truncated after the first ten.
+///
+/// # Module macros
+///
+/// * [`assert_contains_value`](macro@crate::assert_contains_value)
+/// * [`assert_contains_value_as_result`](macro@crate::assert_contains_value_as_result)
+/// * [`debug_assert_contains_value`](macro@crate::debug_assert_contains_value)
+///
+#[macro_export]
+macro_rules! assert_contains_value {
+    ($map:expr, $value:expr $(,)?) => {{
+        match $crate::assert_contains_value_as_result!($map, $value) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($map:expr, $value:expr, $($message:tt)+) => {{
+        match $crate::assert_contains_value_as_result!($map, $value) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_contains_value {
+    use std::collections::HashMap;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+        let b = 1;
+        let actual = assert_contains_value!(a, &b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a: HashMap<&str, i32> = HashMap::from([("alfa", 1), ("bravo", 2)]);
+            let b = 3;
+            let _actual = assert_contains_value!(a, &b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_contains_value!(map, value)`\n",
+            assertables_url_line!("assert_contains_value"),
+            "   map label: `a`,\n",
+            " value label: `&b`,\n",
+            " value debug: `3`,\n",
+            "     map len: `2`,\n",
+            "  map values: `[1, 2]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a map contains a value.
+///
+/// Pseudocode:<br>
+/// map.values().any(|x| x == value)
+///
+/// This macro provides the same statements as [`assert_contains_value`](macro.assert_contains_value.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_contains_value`](macro@crate::assert_contains_value)
+/// * [`assert_contains_value_as_result`](macro@crate::assert_contains_value_as_result)
+/// * [`debug_assert_contains_value`](macro@crate::debug_assert_contains_value)
+///
+#[macro_export]
+macro_rules! debug_assert_contains_value {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_contains_value!($($arg)*);
+        }
+    };
+}
@@ -24,6 +24,12 @@
 //! assert_not_contains!(a, &b);
 //! ```
 //!
+//! The container is borrowed, not moved, so you can use the container again
+//! after the macro. The container and the containee are each evaluated once.
+//! The containee can be any argument of `contains`, such as a closure for a
+//! string; if its type is not `Clone` and `Debug`, then the failure message
+//! shows a note.
+//!
 //! # Module macros
 //!
 //! * [`assert_not_contains`](macro@crate::assert_not_contains)
@@ -51,16 +57,19 @@
 #[macro_export]
 macro_rules! assert_not_contains_as_result {
     ($container:expr, $containee:expr $(,)?) => {{
-        match (&$container, &$containee) {
+        #[allow(unused_imports)]
+        use $crate::__private::{ProbeKeepClone as _, ProbeKeepNone as _};
+        match (&$container, $containee) {
             (container, containee) => {
-                if !(container.contains($containee)) {
+                let containee_kept = (&$crate::__private::KeepProbe(&containee)).keep();
+                if !(container.contains(containee)) {
                     Ok(())
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_not_contains!(container, containee)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_contains.html\n",
+                                $crate::assertables_url_line!("assert_not_contains"),
                                 " container label: `{}`,\n",
                                 " container debug: `{:?}`,\n",
                                 " containee label: `{}`,\n",
@@ -69,7 +78,7 @@ macro_rules! assert_not_contains_as_result {
                             stringify!($container),
                             container,
                             stringify!($containee),
-                            containee,
+                            containee_kept,
                         )
                     )
                 }
@@ -98,7 +107,7 @@ mod test_assert_not_contains_as_result {
             let actual = assert_not_contains_as_result!(a, b);
             let message = concat!(
                 "assertion failed: `assert_not_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_contains.html\n",
+                assertables_url_line!("assert_not_contains"),
                 " container label: `a`,\n",
                 " container debug: `\"alfa\"`,\n",
                 " containee label: `b`,\n",
@@ -125,7 +134,7 @@ mod test_assert_not_contains_as_result {
             let actual = assert_not_contains_as_result!(a, &b);
             let message = concat!(
                 "assertion failed: `assert_not_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_contains.html\n",
+                assertables_url_line!("assert_not_contains"),
                 " container label: `a`,\n",
                 " container debug: `1..3`,\n",
                 " containee label: `&b`,\n",
@@ -152,7 +161,7 @@ mod test_assert_not_contains_as_result {
             let actual = assert_not_contains_as_result!(a, &b);
             let message = concat!(
                 "assertion failed: `assert_not_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_contains.html\n",
+                assertables_url_line!("assert_not_contains"),
                 " container label: `a`,\n",
                 " container debug: `[1, 2, 3]`,\n",
                 " containee label: `&b`,\n",
@@ -161,6 +170,39 @@ mod test_assert_not_contains_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    mod evaluation {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        #[test]
+        fn evaluates_each_expression_once() {
+            static A: AtomicU32 = AtomicU32::new(0);
+            static B: AtomicU32 = AtomicU32::new(0);
+            fn a() -> Vec<i32> {
+                A.fetch_add(1, Ordering::SeqCst);
+                vec![1, 2, 3]
+            }
+            fn b() -> &'static i32 {
+                B.fetch_add(1, Ordering::SeqCst);
+                &2
+            }
+            let _ = assert_not_contains_as_result!(a(), b());
+            assert_eq!(A.load(Ordering::SeqCst), 1);
+            assert_eq!(B.load(Ordering::SeqCst), 1);
+        }
+
+        #[test]
+        fn closure_that_captures_non_copy_state() {
+            let vowels = String::from("aeiou");
+            let actual = assert_not_contains_as_result!("xyz", move |c: char| vowels.contains(c));
+            assert_eq!(actual.unwrap(), ());
+            let vowels = String::from("aeiou");
+            let actual = assert_not_contains_as_result!("alfa", move |c: char| vowels.contains(c));
+            assert!(actual.unwrap_err().ends_with(
+                " containee debug: `(type does not implement Clone and Debug)`"
+            ));
+        }
+    }
 }
 
 /// Assert an expression (such as a string) does not contain an expression (such as a substring).
@@ -210,7 +252,7 @@ mod test_assert_not_contains_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_not_contains!(container, containee)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_contains.html\n",
+/// #     assertables_url_line!("assert_not_contains"),
 /// #     " container label: `a`,\n",
 /// #     " container debug: `\"alfa\"`,\n",
 /// #     " containee label: `b`,\n",
@@ -231,13 +273,13 @@ macro_rules! assert_not_contains {
     ($container:expr, $containee:expr $(,)?) => {{
         match $crate::assert_not_contains_as_result!($container, $containee) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($container:expr, $containee:expr, $($message:tt)+) => {{
         match $crate::assert_not_contains_as_result!($container, $containee) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -265,7 +307,7 @@ mod test_assert_not_contains {
             });
             let message = concat!(
                 "assertion failed: `assert_not_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_contains.html\n",
+                assertables_url_line!("assert_not_contains"),
                 " container label: `a`,\n",
                 " container debug: `\"alfa\"`,\n",
                 " containee label: `b`,\n",
@@ -302,7 +344,7 @@ mod test_assert_not_contains {
             });
             let message = concat!(
                 "assertion failed: `assert_not_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_contains.html\n",
+                assertables_url_line!("assert_not_contains"),
                 " container label: `a`,\n",
                 " container debug: `1..3`,\n",
                 " containee label: `&b`,\n",
@@ -339,7 +381,7 @@ mod test_assert_not_contains {
             });
             let message = concat!(
                 "assertion failed: `assert_not_contains!(container, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_contains.html\n",
+                assertables_url_line!("assert_not_contains"),
                 " container label: `a`,\n",
                 " container debug: `[1, 2, 3]`,\n",
                 " containee label: `&b`,\n",
@@ -355,6 +397,26 @@ mod test_assert_not_contains {
             );
         }
     }
+
+    mod reuse {
+
+        #[test]
+        fn string_is_not_moved() {
+            let a = String::from("alfa");
+            assert_not_contains!(a, "zz");
+            assert_not_contains!(a, "zz");
+            assert_eq!(a, "alfa");
+        }
+
+        #[test]
+        fn vec_is_not_moved() {
+            let a = vec![String::from("alfa"), String::from("bravo")];
+            let b = String::from("charlie");
+            assert_not_contains!(a, &b);
+            assert_not_contains!(a, &b);
+            assert_eq!(a.len(), 2);
+        }
+    }
 }
 
 /// Assert an expression (such as a string) does not contain an expression (such as a substring).
@@ -391,7 +453,7 @@ mod test_assert_not_contains {
 #[macro_export]
 macro_rules! debug_assert_not_contains {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_not_contains!($($arg)*);
         }
     };
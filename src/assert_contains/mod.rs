@@ -7,6 +7,24 @@
 //!
 //! * [`assert_not_contains!(container, containee)`](macro@crate::assert_not_contains) ≈ !container.contains(containee)
 //!
+//! Check a map, such as a `HashMap` or `BTreeMap`, for a key or a value:
+//!
+//! * [`assert_contains_key!(map, key)`](macro@crate::assert_contains_key) ≈ map.contains_key(key)
+//! * [`assert_contains_value!(map, value)`](macro@crate::assert_contains_value) ≈ map.values().any(|x| x == value)
+//!
+//! Check a slice, such as an array or a `Vec`, for a contiguous subslice:
+//!
+//! * [`assert_contains_subslice!(haystack, needle)`](macro@crate::assert_contains_subslice) ≈ haystack.windows(needle.len()).any(|x| x == needle)
+//!
+//! Count the occurrences of a containee, such as a substring or an element:
+//!
+//! * [`assert_contains_count_eq_x!(container, containee, n)`](macro@crate::assert_contains_count_eq_x) ≈ container.count(containee) = n
+//! * [`assert_contains_count_ne_x!(container, containee, n)`](macro@crate::assert_contains_count_ne_x) ≈ container.count(containee) ≠ n
+//! * [`assert_contains_count_lt_x!(container, containee, n)`](macro@crate::assert_contains_count_lt_x) ≈ container.count(containee) < n
+//! * [`assert_contains_count_le_x!(container, containee, n)`](macro@crate::assert_contains_count_le_x) ≈ container.count(containee) ≤ n
+//! * [`assert_contains_count_gt_x!(container, containee, n)`](macro@crate::assert_contains_count_gt_x) ≈ container.count(containee) > n
+//! * [`assert_contains_count_ge_x!(container, containee, n)`](macro@crate::assert_contains_count_ge_x) ≈ container.count(containee) ≥ n
+//!
 //!
 //! # Example
 //!
@@ -29,5 +47,181 @@
 //! assert_contains!(a, &b);
 //! ```
 
+/// Count the occurrences of a containee in a container.
+///
+/// This trait is an implementation detail of the macros such as
+/// [`assert_contains_count_eq_x`](macro@crate::assert_contains_count_eq_x).
+///
+/// * For a string, count the non-overlapping occurrences of a substring or char.
+///
+/// * For a slice, count the elements that are equal to the containee.
+#[doc(hidden)]
+pub trait ContainsCount<Containee: ?Sized> {
+    fn contains_count(&self, containee: &Containee) -> usize;
+}
+
+impl ContainsCount<&str> for str {
+    fn contains_count(&self, containee: &&str) -> usize {
+        self.matches(*containee).count()
+    }
+}
+
+impl ContainsCount<String> for str {
+    fn contains_count(&self, containee: &String) -> usize {
+        self.matches(containee.as_str()).count()
+    }
+}
+
+impl ContainsCount<char> for str {
+    fn contains_count(&self, containee: &char) -> usize {
+        self.matches(*containee).count()
+    }
+}
+
+impl<T: PartialEq> ContainsCount<T> for [T] {
+    fn contains_count(&self, containee: &T) -> usize {
+        self.iter().filter(|x| *x == containee).count()
+    }
+}
+
+/// Return the nearest match of a needle in a haystack string, as the pair
+/// (longest leading part of the needle that is in the haystack, byte index).
+///
+/// The leading part ends on a char boundary. A tie goes to the first index.
+/// Return `None` if the haystack does not contain the needle's first char.
+#[doc(hidden)]
+pub fn str_nearest_match<'a>(haystack: &str, needle: &'a str) -> Option<(&'a str, usize)> {
+    needle
+        .char_indices()
+        .map(|(index, c)| index + c.len_utf8())
+        .rev()
+        .find_map(|end| {
+            haystack
+                .find(&needle[..end])
+                .map(|index| (&needle[..end], index))
+        })
+}
+
+/// Probe a container and a containee for a nearest match, if both are strings.
+///
+/// The macro [`assert_contains`](macro@crate::assert_contains) calls
+/// `(&NearestMatchProbe(a, b)).probe_nearest_match()`. Method lookup tries
+/// [`ProbeNearestMatch`] first, which applies only if both types implement
+/// [`KnownStr`](crate::assert_eq::KnownStr), then falls back to
+/// [`ProbeNoNearestMatch`], which returns `None`.
+#[doc(hidden)]
+pub struct NearestMatchProbe<'a, A: ?Sized, B: ?Sized>(pub &'a A, pub &'a B);
+
+#[doc(hidden)]
+pub trait ProbeNearestMatch {
+    fn probe_nearest_match(&self) -> Option<String>;
+}
+
+impl<A, B> ProbeNearestMatch for NearestMatchProbe<'_, A, B>
+where
+    A: crate::assert_eq::KnownStr + ?Sized,
+    B: crate::assert_eq::KnownStr + ?Sized,
+{
+    fn probe_nearest_match(&self) -> Option<String> {
+        match str_nearest_match(self.0.known_str(), self.1.known_str()) {
+            Some((part, index)) => Some(format!("`{:?}` at byte index {}", part, index)),
+            None => Some(String::from("none")),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeNoNearestMatch {
+    fn probe_nearest_match(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> ProbeNoNearestMatch for &NearestMatchProbe<'_, A, B> {}
+
+/// The most items to show in a failure message list, such as a map's keys.
+#[doc(hidden)]
+pub const DEBUG_LIST_MAX_ITEMS: usize = 10;
+
+/// Return the debug text of items as a list, sorted and truncated.
+///
+/// The items are sorted by their debug text, so the list is the same for
+/// a map with an unspecified order, such as a `HashMap`. After the first
+/// [`DEBUG_LIST_MAX_ITEMS`] items, the list shows how many more there are.
+#[doc(hidden)]
+pub fn debug_list_truncated<I>(items: I) -> String
+where
+    I: IntoIterator,
+    I::Item: ::std::fmt::Debug,
+{
+    let mut items: Vec<String> = items
+        .into_iter()
+        .map(|item| format!("{:?}", item))
+        .collect();
+    items.sort();
+    let more = items.len().saturating_sub(DEBUG_LIST_MAX_ITEMS);
+    items.truncate(DEBUG_LIST_MAX_ITEMS);
+    if more > 0 {
+        items.push(format!("… {} more", more));
+    }
+    format!("[{}]", items.join(", "))
+}
+
+/// Return the index of the first contiguous window of a haystack that equals
+/// a needle, or `None` if there is no such window.
+///
+/// An empty needle is found at index 0.
+#[doc(hidden)]
+pub fn subslice_position<T: PartialEq>(haystack: &[T], needle: &[T]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Return the closest partial match of a needle in a haystack, as the pair
+/// (haystack index, count of leading needle items that match there).
+///
+/// The closest match is the longest run of the needle's leading items,
+/// including a run that reaches the end of the haystack. A tie goes to the
+/// first index. Return `None` if no haystack item equals the needle's first item.
+#[doc(hidden)]
+pub fn subslice_closest_match<T: PartialEq>(
+    haystack: &[T],
+    needle: &[T],
+) -> Option<(usize, usize)> {
+    let mut closest: Option<(usize, usize)> = None;
+    let mut best = 0;
+    for index in 0..haystack.len() {
+        let count = haystack[index..]
+            .iter()
+            .zip(needle)
+            .take_while(|(h, n)| h == n)
+            .count();
+        if count > best {
+            best = count;
+            closest = Some((index, count));
+        }
+    }
+    closest
+}
+
 pub mod assert_contains;
 pub mod assert_not_contains;
+
+// Slice
+pub mod assert_contains_subslice;
+
+// Map
+pub mod assert_contains_key;
+pub mod assert_contains_value;
+
+// Count
+pub mod assert_contains_count_eq_x;
+pub mod assert_contains_count_ge_x;
+pub mod assert_contains_count_gt_x;
+pub mod assert_contains_count_le_x;
+pub mod assert_contains_count_lt_x;
+pub mod assert_contains_count_ne_x;
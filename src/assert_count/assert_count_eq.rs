@@ -42,8 +42,8 @@ macro_rules! assert_count_eq_as_result {
     ($a:expr, $b:expr $(,)?) => {{
         match (&$a, &$b) {
             (a, b) => {
-                let a_count = a.clone().count(); // TODO replace clone
-                let b_count = b.clone().count(); // TODO replace clone
+                let a_count = a.clone().count();
+                let b_count = b.clone().count();
                 if a_count == b_count {
                     Ok((a_count, b_count))
                 } else {
@@ -51,7 +51,7 @@ macro_rules! assert_count_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_count_eq!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq.html\n",
+                                $crate::assertables_url_line!("assert_count_eq"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.count(): `{:?}`,\n",
@@ -75,6 +75,7 @@ macro_rules! assert_count_eq_as_result {
 
 #[cfg(test)]
 mod test_assert_count_eq_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn eq() {
@@ -91,7 +92,7 @@ mod test_assert_count_eq_as_result {
         let actual = assert_count_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_count_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq.html\n",
+            assertables_url_line!("assert_count_eq"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -109,7 +110,7 @@ mod test_assert_count_eq_as_result {
         let actual = assert_count_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_count_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq.html\n",
+            assertables_url_line!("assert_count_eq"),
             " a label: `a`,\n",
             " a debug: `Chars(['x', 'x'])`,\n",
             " a.count(): `2`,\n",
@@ -119,6 +120,41 @@ mod test_assert_count_eq_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        static B: AtomicU32 = AtomicU32::new(0);
+        static A_ITEMS: AtomicU32 = AtomicU32::new(0);
+        static B_ITEMS: AtomicU32 = AtomicU32::new(0);
+        fn a() -> impl Iterator<Item = char> + Clone + std::fmt::Debug {
+            A.fetch_add(1, Ordering::SeqCst);
+            "xx".chars().inspect(|_| {
+                A_ITEMS.fetch_add(1, Ordering::SeqCst);
+            })
+        }
+        fn b() -> impl Iterator<Item = char> + Clone + std::fmt::Debug {
+            B.fetch_add(1, Ordering::SeqCst);
+            "yy".chars().inspect(|_| {
+                B_ITEMS.fetch_add(1, Ordering::SeqCst);
+            })
+        }
+        let actual = assert_count_eq_as_result!(a(), b());
+        assert_eq!(actual.unwrap(), (2, 2));
+        assert_eq!(A.load(Ordering::SeqCst), 1);
+        assert_eq!(B.load(Ordering::SeqCst), 1);
+        assert_eq!(A_ITEMS.load(Ordering::SeqCst), 2);
+        assert_eq!(B_ITEMS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn does_not_consume_the_iterators() {
+        let a = "xx".chars();
+        let b = "yy".chars();
+        let _ = assert_count_eq_as_result!(a, b);
+        assert_eq!(a.count(), 2);
+        assert_eq!(b.count(), 2);
+    }
 }
 
 /// Assert a count is equal to another.
@@ -159,7 +195,7 @@ mod test_assert_count_eq_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_count_eq!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq.html\n",
+/// #     assertables_url_line!("assert_count_eq"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Chars(['x'])`,\n",
 /// #     " a.count(): `1`,\n",
@@ -182,13 +218,13 @@ macro_rules! assert_count_eq {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_count_eq_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_count_eq_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -214,7 +250,7 @@ mod test_assert_count_eq {
         });
         let message = concat!(
             "assertion failed: `assert_count_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq.html\n",
+            assertables_url_line!("assert_count_eq"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -241,7 +277,7 @@ mod test_assert_count_eq {
         });
         let message = concat!(
             "assertion failed: `assert_count_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq.html\n",
+            assertables_url_line!("assert_count_eq"),
             " a label: `a`,\n",
             " a debug: `Chars(['x', 'x'])`,\n",
             " a.count(): `2`,\n",
@@ -294,7 +330,7 @@ mod test_assert_count_eq {
 #[macro_export]
 macro_rules! debug_assert_count_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_count_eq!($($arg)*);
         }
     };
@@ -41,16 +41,16 @@
 macro_rules! assert_count_eq_x_as_result {
     ($a:expr, $b:expr $(,)?) => {{
         match (&$a, &$b) {
-            (a, _b) => {
+            (a, b) => {
                 let a_count = a.clone().count();
-                if a_count == $b {
-                    Ok((a_count, $b))
+                if a_count == *b {
+                    Ok((a_count, *b))
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_count_eq_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq_x.html\n",
+                                $crate::assertables_url_line!("assert_count_eq_x"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.count(): `{:?}`,\n",
@@ -61,7 +61,7 @@ macro_rules! assert_count_eq_x_as_result {
                             a,
                             a_count,
                             stringify!($b),
-                            $b
+                            b
                         )
                     )
                 }
@@ -72,6 +72,7 @@ macro_rules! assert_count_eq_x_as_result {
 
 #[cfg(test)]
 mod test_assert_count_eq_x_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn eq() {
@@ -88,7 +89,7 @@ mod test_assert_count_eq_x_as_result {
         let actual = assert_count_eq_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_count_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq_x.html\n",
+            assertables_url_line!("assert_count_eq_x"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -105,7 +106,7 @@ mod test_assert_count_eq_x_as_result {
         let actual = assert_count_eq_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_count_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq_x.html\n",
+            assertables_url_line!("assert_count_eq_x"),
             " a label: `a`,\n",
             " a debug: `Chars(['x', 'x'])`,\n",
             " a.count(): `2`,\n",
@@ -114,6 +115,35 @@ mod test_assert_count_eq_x_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        static B: AtomicU32 = AtomicU32::new(0);
+        static ITEMS: AtomicU32 = AtomicU32::new(0);
+        fn a() -> impl Iterator<Item = char> + Clone + std::fmt::Debug {
+            A.fetch_add(1, Ordering::SeqCst);
+            "xx".chars().inspect(|_| {
+                ITEMS.fetch_add(1, Ordering::SeqCst);
+            })
+        }
+        fn b() -> usize {
+            B.fetch_add(1, Ordering::SeqCst);
+            2
+        }
+        let actual = assert_count_eq_x_as_result!(a(), b());
+        assert_eq!(actual.unwrap(), (2, 2));
+        assert_eq!(A.load(Ordering::SeqCst), 1);
+        assert_eq!(B.load(Ordering::SeqCst), 1);
+        assert_eq!(ITEMS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn does_not_consume_the_iterator() {
+        let a = "xx".chars();
+        let _ = assert_count_eq_x_as_result!(a, 2);
+        assert_eq!(a.count(), 2);
+    }
 }
 
 /// Assert a count is equal to an expression.
@@ -153,7 +183,7 @@ mod test_assert_count_eq_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_count_eq_x!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq_x.html\n",
+/// #     assertables_url_line!("assert_count_eq_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Chars(['x'])`,\n",
 /// #     " a.count(): `1`,\n",
@@ -175,13 +205,13 @@ macro_rules! assert_count_eq_x {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_count_eq_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_count_eq_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -207,7 +237,7 @@ mod test_assert_count_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_count_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq_x.html\n",
+            assertables_url_line!("assert_count_eq_x"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -233,7 +263,7 @@ mod test_assert_count_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_count_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_eq_x.html\n",
+            assertables_url_line!("assert_count_eq_x"),
             " a label: `a`,\n",
             " a debug: `Chars(['x', 'x'])`,\n",
             " a.count(): `2`,\n",
@@ -285,7 +315,7 @@ mod test_assert_count_eq_x {
 #[macro_export]
 macro_rules! debug_assert_count_eq_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_count_eq_x!($($arg)*);
         }
     };
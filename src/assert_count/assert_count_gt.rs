@@ -51,7 +51,7 @@ macro_rules! assert_count_gt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_count_gt!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_gt.html\n",
+                                $crate::assertables_url_line!("assert_count_gt"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.count(): `{:?}`,\n",
@@ -91,7 +91,7 @@ mod test_assert_count_gt_as_result {
         let actual = assert_count_gt_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_count_gt!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_gt.html\n",
+            assertables_url_line!("assert_count_gt"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -109,7 +109,7 @@ mod test_assert_count_gt_as_result {
         let actual = assert_count_gt_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_count_gt!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_gt.html\n",
+            assertables_url_line!("assert_count_gt"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -159,7 +159,7 @@ mod test_assert_count_gt_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_count_gt!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_gt.html\n",
+/// #     assertables_url_line!("assert_count_gt"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Chars(['x'])`,\n",
 /// #     " a.count(): `1`,\n",
@@ -182,13 +182,13 @@ macro_rules! assert_count_gt {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_count_gt_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_count_gt_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -214,7 +214,7 @@ mod test_assert_count_gt {
         });
         let message = concat!(
             "assertion failed: `assert_count_gt!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_gt.html\n",
+            assertables_url_line!("assert_count_gt"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -241,7 +241,7 @@ mod test_assert_count_gt {
         });
         let message = concat!(
             "assertion failed: `assert_count_gt!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_gt.html\n",
+            assertables_url_line!("assert_count_gt"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -294,7 +294,7 @@ mod test_assert_count_gt {
 #[macro_export]
 macro_rules! debug_assert_count_gt {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_count_gt!($($arg)*);
         }
     };
@@ -51,7 +51,7 @@ macro_rules! assert_count_ne_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_count_ne!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_ne.html\n",
+                                $crate::assertables_url_line!("assert_count_ne"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.count(): `{:?}`,\n",
@@ -99,7 +99,7 @@ mod test_assert_count_ne_as_result {
         let actual = assert_count_ne_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_count_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_ne.html\n",
+            assertables_url_line!("assert_count_ne"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -149,7 +149,7 @@ mod test_assert_count_ne_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_count_ne!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_ne.html\n",
+/// #     assertables_url_line!("assert_count_ne"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Chars(['x'])`,\n",
 /// #     " a.count(): `1`,\n",
@@ -172,13 +172,13 @@ macro_rules! assert_count_ne {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_count_ne_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_count_ne_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -212,7 +212,7 @@ mod test_assert_count_ne {
         });
         let message = concat!(
             "assertion failed: `assert_count_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_count_ne.html\n",
+            assertables_url_line!("assert_count_ne"),
             " a label: `a`,\n",
             " a debug: `Chars(['x'])`,\n",
             " a.count(): `1`,\n",
@@ -265,7 +265,7 @@ mod test_assert_count_ne {
 #[macro_export]
 macro_rules! debug_assert_count_ne {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_count_ne!($($arg)*);
         }
     };
@@ -30,6 +30,24 @@
 //! let b = "x".chars();
 //! assert_count_eq!(a, b);
 //! ```
+//!
+//! ## Count versus length
+//!
+//! The method `.count()` is inherently consuming: it runs an iterator to its
+//! end. Therefore these macros do not call `.count()` on your iterator; they
+//! evaluate each argument exactly once, borrow it, then count a clone of it.
+//! This means:
+//!
+//! * Each iterator must implement `Clone`.
+//!
+//! * Your iterator is not consumed, so you can use it again after the macro.
+//!
+//! * Any side effect in an iterator adapter, such as `.inspect(…)`, runs once
+//!   per item, because only the clone is iterated.
+//!
+//! If your collection has a `.len()` method, such as a string, array, or vector,
+//! then prefer the macros in the module [`assert_len`](module@crate::assert_len),
+//! because `.len()` does not iterate and does not consume.
 
 // Compare another
 pub mod assert_count_eq;
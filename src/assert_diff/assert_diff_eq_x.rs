@@ -45,18 +45,20 @@
 #[macro_export]
 macro_rules! assert_diff_eq_x_as_result {
     ($a:expr, $b:expr, $x:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_diff::{ProbeCheckedDiff as _, ProbeSubDiff as _};
         match (&$a, &$b, &$x) {
             (a, b, x) => {
-                match ::std::panic::catch_unwind(|| b - a) {
-                    Ok(delta) => {
+                match $crate::__private::catch_unwind(|| (&$crate::assert_diff::DiffProbe(b, a)).probe_diff()) {
+                    Ok(Some(delta)) => {
                         if delta == *x {
                             Ok((delta, *x))
                         } else {
                             Err(
-                                format!(
+                                $crate::__private::format!(
                                     concat!(
                                         "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_eq_x.html\n",
+                                        $crate::assertables_url_line!("assert_diff_eq_x"),
                                         " a label: `{}`,\n",
                                         " a debug: `{:?}`,\n",
                                         " b label: `{}`,\n",
@@ -78,19 +80,19 @@ macro_rules! assert_diff_eq_x_as_result {
                             )
                         }
                     },
-                    Err(_err) => {
+                    _ => {
                         Err(
-                            format!(
+                            $crate::__private::format!(
                                 concat!(
                                     "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_eq_x.html\n",
+                                    $crate::assertables_url_line!("assert_diff_eq_x"),
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`,\n",
                                     " b label: `{}`,\n",
                                     " b debug: `{:?}`,\n",
                                     " x label: `{}`,\n",
                                     " x debug: `{:?}`,\n",
-                                    "       Δ: panic", //TODO add the panic message
+                                    "       Δ: overflow",
                                 ),
                                 stringify!($a),
                                 a,
@@ -127,7 +129,7 @@ mod test_assert_diff_eq_x_as_result {
         let actual = assert_diff_eq_x_as_result!(a, b, x);
         let message = concat!(
             "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_eq_x.html\n",
+            assertables_url_line!("assert_diff_eq_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -148,7 +150,7 @@ mod test_assert_diff_eq_x_as_result {
         let actual = assert_diff_eq_x_as_result!(a, b, x);
         let message = concat!(
             "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_eq_x.html\n",
+            assertables_url_line!("assert_diff_eq_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -170,19 +172,81 @@ mod test_assert_diff_eq_x_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_eq_x.html\n",
+                assertables_url_line!("assert_diff_eq_x"),
                 " a label: `a`,\n",
                 " a debug: `{}`,\n",
                 " b label: `b`,\n",
                 " b debug: `{}`,\n",
                 " x label: `x`,\n",
                 " x debug: `{}`,\n",
-                "       Δ: panic"
+                "       Δ: overflow"
             ),
             a, b, x
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn unsigned() {
+        let a: u32 = 10;
+        let b: u32 = 13;
+        let actual = assert_diff_eq_x_as_result!(a, b, 3);
+        assert_eq!(actual.unwrap(), (3, 3));
+    }
+
+    #[test]
+    fn wrapping() {
+        use std::num::Wrapping;
+        let a = Wrapping(250u8);
+        let b = Wrapping(4u8);
+        let x = Wrapping(10u8);
+        let actual = assert_diff_eq_x_as_result!(a, b, x);
+        assert_eq!(actual.unwrap(), (Wrapping(10u8), Wrapping(10u8)));
+    }
+
+    #[test]
+    fn unsigned_underflow() {
+        let a: u32 = 13;
+        let b: u32 = 10;
+        let x: u32 = 3;
+        let actual = assert_diff_eq_x_as_result!(a, b, x);
+        let message = concat!(
+            "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
+            assertables_url_line!("assert_diff_eq_x"),
+            " a label: `a`,\n",
+            " a debug: `13`,\n",
+            " b label: `b`,\n",
+            " b debug: `10`,\n",
+            " x label: `x`,\n",
+            " x debug: `3`,\n",
+            "       Δ: overflow"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn unsigned_near_max() {
+        let a: u32 = 0;
+        let b: u32 = u32::MAX;
+        let actual = assert_diff_eq_x_as_result!(a, b, u32::MAX);
+        assert_eq!(actual.unwrap(), (u32::MAX, u32::MAX));
+        let a: u32 = u32::MAX;
+        let b: u32 = 0;
+        let actual = assert_diff_eq_x_as_result!(a, b, 0);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn duration() {
+        use std::time::Duration;
+        let a = Duration::from_secs(1);
+        let b = Duration::from_secs(3);
+        let actual = assert_diff_eq_x_as_result!(a, b, Duration::from_secs(2));
+        assert_eq!(
+            actual.unwrap(),
+            (Duration::from_secs(2), Duration::from_secs(2))
+        );
+    }
 }
 
 /// Assert a difference is equal to an expression.
@@ -227,7 +291,7 @@ mod test_assert_diff_eq_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_eq_x.html\n",
+/// #     assertables_url_line!("assert_diff_eq_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `10`,\n",
 /// #     " b label: `b`,\n",
@@ -252,13 +316,13 @@ macro_rules! assert_diff_eq_x {
     ($a:expr, $b:expr, $x:expr $(,)?) => {{
         match $crate::assert_diff_eq_x_as_result!($a, $b, $x) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $x:expr, $($message:tt)+) => {{
         match $crate::assert_diff_eq_x_as_result!($a, $b, $x) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -286,7 +350,7 @@ mod test_assert_diff_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_eq_x.html\n",
+            assertables_url_line!("assert_diff_eq_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -316,7 +380,7 @@ mod test_assert_diff_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_eq_x.html\n",
+            assertables_url_line!("assert_diff_eq_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -347,14 +411,14 @@ mod test_assert_diff_eq_x {
         let message = format!(
             concat!(
                 "assertion failed: `assert_diff_eq_x!(a, b, x)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_eq_x.html\n",
+                assertables_url_line!("assert_diff_eq_x"),
                 " a label: `a`,\n",
                 " a debug: `{}`,\n",
                 " b label: `b`,\n",
                 " b debug: `{}`,\n",
                 " x label: `x`,\n",
                 " x debug: `{}`,\n",
-                "       Δ: panic"
+                "       Δ: overflow"
             ),
             a, b, x
         );
@@ -367,6 +431,17 @@ mod test_assert_diff_eq_x {
             message
         );
     }
+
+    #[test]
+    fn debug_form_panics_only_with_debug_assertions() {
+        let a: i8 = 10;
+        let b: i8 = 13;
+        let x: i8 = 4;
+        let result = panic::catch_unwind(|| {
+            crate::debug_assert_diff_eq_x!(a, b, x);
+        });
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
 }
 
 /// Assert a difference is equal to an expression.
@@ -403,7 +478,7 @@ mod test_assert_diff_eq_x {
 #[macro_export]
 macro_rules! debug_assert_diff_eq_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_diff_eq_x!($($arg)*);
         }
     };
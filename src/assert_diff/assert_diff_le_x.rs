@@ -45,18 +45,20 @@
 #[macro_export]
 macro_rules! assert_diff_le_x_as_result {
     ($a:expr, $b:expr, $x:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_diff::{ProbeCheckedDiff as _, ProbeSubDiff as _};
         match (&$a, &$b, &$x) {
             (a, b, x) => {
-                match ::std::panic::catch_unwind(|| b - a) {
-                    Ok(delta) => {
+                match $crate::__private::catch_unwind(|| (&$crate::assert_diff::DiffProbe(b, a)).probe_diff()) {
+                    Ok(Some(delta)) => {
                         if delta <= *x {
                             Ok((delta, *x))
                         } else {
                             Err(
-                                format!(
+                                $crate::__private::format!(
                                     concat!(
                                         "assertion failed: `assert_diff_le_x!(a, b, x)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_le_x.html\n",
+                                        $crate::assertables_url_line!("assert_diff_le_x"),
                                         " a label: `{}`,\n",
                                         " a debug: `{:?}`,\n",
                                         " b label: `{}`,\n",
@@ -78,19 +80,19 @@ macro_rules! assert_diff_le_x_as_result {
                             )
                         }
                     },
-                    Err(_err) => {
+                    _ => {
                         Err(
-                            format!(
+                            $crate::__private::format!(
                                 concat!(
                                     "assertion failed: `assert_diff_le_x!(a, b, x)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_le_x.html\n",
+                                    $crate::assertables_url_line!("assert_diff_le_x"),
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`,\n",
                                     " b label: `{}`,\n",
                                     " b debug: `{:?}`,\n",
                                     " x label: `{}`,\n",
                                     " x debug: `{:?}`,\n",
-                                    "       Δ: panic", //TODO add the panic message
+                                    "       Δ: overflow",
                                 ),
                                 stringify!($a),
                                 a,
@@ -136,7 +138,7 @@ mod test_assert_diff_le_x_as_result {
         let actual = assert_diff_le_x_as_result!(a, b, x);
         let message = concat!(
             "assertion failed: `assert_diff_le_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_le_x.html\n",
+            assertables_url_line!("assert_diff_le_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -158,19 +160,27 @@ mod test_assert_diff_le_x_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_diff_le_x!(a, b, x)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_le_x.html\n",
+                assertables_url_line!("assert_diff_le_x"),
                 " a label: `a`,\n",
                 " a debug: `{}`,\n",
                 " b label: `b`,\n",
                 " b debug: `{}`,\n",
                 " x label: `x`,\n",
                 " x debug: `{}`,\n",
-                "       Δ: panic"
+                "       Δ: overflow"
             ),
             a, b, x
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn unsigned_underflow() {
+        let a: u32 = 13;
+        let b: u32 = 10;
+        let actual = assert_diff_le_x_as_result!(a, b, 0);
+        assert!(actual.unwrap_err().ends_with("       Δ: overflow"));
+    }
 }
 
 /// Assert a difference is less than or equal to an expression.
@@ -215,7 +225,7 @@ mod test_assert_diff_le_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_diff_le_x!(a, b, x)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_le_x.html\n",
+/// #     assertables_url_line!("assert_diff_le_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `10`,\n",
 /// #     " b label: `b`,\n",
@@ -240,13 +250,13 @@ macro_rules! assert_diff_le_x {
     ($a:expr, $b:expr, $x:expr $(,)?) => {{
         match $crate::assert_diff_le_x_as_result!($a, $b, $x) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $x:expr, $($message:tt)+) => {{
         match $crate::assert_diff_le_x_as_result!($a, $b, $x) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -283,7 +293,7 @@ mod test_assert_diff_le_x {
         });
         let message = concat!(
             "assertion failed: `assert_diff_le_x!(a, b, x)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_le_x.html\n",
+            assertables_url_line!("assert_diff_le_x"),
             " a label: `a`,\n",
             " a debug: `10`,\n",
             " b label: `b`,\n",
@@ -314,14 +324,14 @@ mod test_assert_diff_le_x {
         let message = format!(
             concat!(
                 "assertion failed: `assert_diff_le_x!(a, b, x)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_diff_le_x.html\n",
+                assertables_url_line!("assert_diff_le_x"),
                 " a label: `a`,\n",
                 " a debug: `{}`,\n",
                 " b label: `b`,\n",
                 " b debug: `{}`,\n",
                 " x label: `x`,\n",
                 " x debug: `{}`,\n",
-                "       Δ: panic"
+                "       Δ: overflow"
             ),
             a, b, x
         );
@@ -334,6 +344,17 @@ mod test_assert_diff_le_x {
             message
         );
     }
+
+    #[test]
+    fn debug_form_panics_only_with_debug_assertions() {
+        let a: i8 = 10;
+        let b: i8 = 13;
+        let x: i8 = 2;
+        let result = panic::catch_unwind(|| {
+            crate::debug_assert_diff_le_x!(a, b, x);
+        });
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
 }
 
 /// Assert a difference is less than or equal to an expression.
@@ -370,7 +391,7 @@ mod test_assert_diff_le_x {
 #[macro_export]
 macro_rules! debug_assert_diff_le_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_diff_le_x!($($arg)*);
         }
     };
@@ -19,6 +19,19 @@
 //! let x: i8 = 2;
 //! assert_diff_eq_x!(a, b, x);
 //! ```
+//!
+//! The difference Δ is `b - a` in the same type as the inputs, so for
+//! unsigned inputs the difference is unsigned too. When the difference
+//! does not fit in the type, such as `b < a` for unsigned inputs, or
+//! `i8::MIN - i8::MAX` for signed inputs, the assertion fails with the
+//! message `Δ: overflow`, rather than panicking on the arithmetic.
+//!
+//! The inputs can be any types where `&b - &a` works, such as primitive
+//! numbers, [`Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html),
+//! or [`Wrapping`](https://doc.rust-lang.org/std/num/struct.Wrapping.html).
+//! For primitive integers and `Duration`, the macros use checked subtraction,
+//! so overflow fails in any build. For other types, the macros catch a panic
+//! from the subtraction, and report it as overflow.
 
 pub mod assert_diff_eq_x;
 pub mod assert_diff_ge_x;
@@ -26,3 +39,93 @@ pub mod assert_diff_gt_x;
 pub mod assert_diff_le_x;
 pub mod assert_diff_lt_x;
 pub mod assert_diff_ne_x;
+
+/// Checked subtraction for the primitive types.
+///
+/// This returns `None` when the difference does not fit in the type,
+/// so the macros can report an overflow instead of panicking, including
+/// in release builds, where the subtraction would wrap.
+#[doc(hidden)]
+pub trait CheckedDiff: Sized {
+    fn checked_diff(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_diff_via_checked_sub {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedDiff for $t {
+                fn checked_diff(&self, rhs: &Self) -> Option<Self> {
+                    self.checked_sub(*rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_diff_via_checked_sub!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    ::core::time::Duration
+);
+
+impl CheckedDiff for f32 {
+    fn checked_diff(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+}
+
+impl CheckedDiff for f64 {
+    fn checked_diff(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+}
+
+/// Probe two items for their difference, `self.0 - self.1`.
+///
+/// The macros call `(&DiffProbe(b, a)).probe_diff()`. Method lookup tries
+/// [`ProbeCheckedDiff`] first, which applies only if both items are the same
+/// type that implements [`CheckedDiff`], then falls back to [`ProbeSubDiff`],
+/// which applies to any types that implement `Sub`, such as `Wrapping<u8>`.
+/// The fallback subtraction can panic, so the macros call the probe within
+/// `catch_unwind`, the same as the `assert_abs_diff` macros.
+#[doc(hidden)]
+pub struct DiffProbe<'a, B: ?Sized, A: ?Sized>(pub &'a B, pub &'a A);
+
+#[doc(hidden)]
+pub trait ProbeCheckedDiff {
+    type Output;
+    fn probe_diff(&self) -> Option<Self::Output>;
+}
+
+impl<T: CheckedDiff> ProbeCheckedDiff for DiffProbe<'_, T, T> {
+    type Output = T;
+    fn probe_diff(&self) -> Option<T> {
+        self.0.checked_diff(self.1)
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeSubDiff {
+    type Output;
+    fn probe_diff(&self) -> Option<Self::Output>;
+}
+
+impl<'a, B: ?Sized, A: ?Sized> ProbeSubDiff for &DiffProbe<'a, B, A>
+where
+    &'a B: ::core::ops::Sub<&'a A>,
+{
+    type Output = <&'a B as ::core::ops::Sub<&'a A>>::Output;
+    fn probe_diff(&self) -> Option<Self::Output> {
+        Some(self.0 - self.1)
+    }
+}
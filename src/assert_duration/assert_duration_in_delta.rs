@@ -0,0 +1,377 @@
+//! Assert a duration is within delta of another duration.
+//!
+//! Pseudocode:<br>
+//! | a - b | ≤ Δ
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::time::Duration;
+//!
+//! let a = Duration::from_millis(100);
+//! let b = Duration::from_millis(102);
+//! let delta = Duration::from_millis(2);
+//! assert_duration_in_delta!(a, b, delta);
+//! ```
+//!
+//! The absolute difference is computed by subtracting the shorter duration
+//! from the longer duration, so the macro works with `a < b` without the
+//! underflow panic of `Duration` subtraction.
+//!
+//! # Module macros
+//!
+//! * [`assert_duration_in_delta`](macro@crate::assert_duration_in_delta)
+//! * [`assert_duration_in_delta_as_result`](macro@crate::assert_duration_in_delta_as_result)
+//! * [`debug_assert_duration_in_delta`](macro@crate::debug_assert_duration_in_delta)
+
+/// Assert a duration is within delta of another duration.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ Δ
+///
+/// * If true, return Result `Ok((abs_diff, delta))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_duration_in_delta`](macro@crate::assert_duration_in_delta)
+/// * [`assert_duration_in_delta_as_result`](macro@crate::assert_duration_in_delta_as_result)
+/// * [`debug_assert_duration_in_delta`](macro@crate::debug_assert_duration_in_delta)
+///
+#[macro_export]
+macro_rules! assert_duration_in_delta_as_result {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match (&$a, &$b, &$delta) {
+            (a, b, delta) => {
+                let a: &::core::time::Duration = a;
+                let b: &::core::time::Duration = b;
+                let delta: &::core::time::Duration = delta;
+                let abs_diff = if a >= b { *a - *b } else { *b - *a };
+                if abs_diff <= *delta {
+                    Ok((abs_diff, *delta))
+                } else {
+                    Err($crate::__private::format!(
+                        concat!(
+                            "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+                            $crate::assertables_url_line!("assert_duration_in_delta"),
+                            "       a label: `{}`,\n",
+                            "       a debug: `{:?}`,\n",
+                            "       b label: `{}`,\n",
+                            "       b debug: `{:?}`,\n",
+                            "       Δ label: `{}`,\n",
+                            "       Δ debug: `{:?}`,\n",
+                            "     | a - b |: `{:?}`,\n",
+                            " | a - b | ≤ Δ: {}"
+                        ),
+                        stringify!($a),
+                        a,
+                        stringify!($b),
+                        b,
+                        stringify!($delta),
+                        delta,
+                        abs_diff,
+                        false
+                    ))
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_duration_in_delta_as_result {
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let a = Duration::from_millis(100);
+        let b = Duration::from_millis(102);
+        let delta = Duration::from_millis(2);
+        let actual = assert_duration_in_delta_as_result!(a, b, delta);
+        assert_eq!(
+            actual.unwrap(),
+            (Duration::from_millis(2), Duration::from_millis(2))
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = Duration::from_millis(100);
+        let b = Duration::from_millis(103);
+        let delta = Duration::from_millis(2);
+        let actual = assert_duration_in_delta_as_result!(a, b, delta);
+        let message = concat!(
+            "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+            assertables_url_line!("assert_duration_in_delta"),
+            "       a label: `a`,\n",
+            "       a debug: `100ms`,\n",
+            "       b label: `b`,\n",
+            "       b debug: `103ms`,\n",
+            "       Δ label: `delta`,\n",
+            "       Δ debug: `2ms`,\n",
+            "     | a - b |: `3ms`,\n",
+            " | a - b | ≤ Δ: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_a_less_than_b() {
+        let a = Duration::from_millis(98);
+        let b = Duration::from_millis(100);
+        let delta = Duration::from_millis(2);
+        let actual = assert_duration_in_delta_as_result!(a, b, delta);
+        assert_eq!(
+            actual.unwrap(),
+            (Duration::from_millis(2), Duration::from_millis(2))
+        );
+    }
+
+    #[test]
+    fn failure_with_a_less_than_b() {
+        let a = Duration::from_millis(90);
+        let b = Duration::from_millis(100);
+        let delta = Duration::from_millis(5);
+        let actual = assert_duration_in_delta_as_result!(a, b, delta);
+        let message = concat!(
+            "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+            assertables_url_line!("assert_duration_in_delta"),
+            "       a label: `a`,\n",
+            "       a debug: `90ms`,\n",
+            "       b label: `b`,\n",
+            "       b debug: `100ms`,\n",
+            "       Δ label: `delta`,\n",
+            "       Δ debug: `5ms`,\n",
+            "     | a - b |: `10ms`,\n",
+            " | a - b | ≤ Δ: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_nanos() {
+        let a = Duration::from_nanos(1_000);
+        let b = Duration::from_nanos(1_003);
+        let delta = Duration::from_nanos(3);
+        let actual = assert_duration_in_delta_as_result!(a, b, delta);
+        assert_eq!(
+            actual.unwrap(),
+            (Duration::from_nanos(3), Duration::from_nanos(3))
+        );
+    }
+
+    #[test]
+    fn failure_with_nanos() {
+        let a = Duration::from_nanos(1_004);
+        let b = Duration::from_nanos(1_000);
+        let delta = Duration::from_nanos(3);
+        let actual = assert_duration_in_delta_as_result!(a, b, delta);
+        let message = concat!(
+            "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+            assertables_url_line!("assert_duration_in_delta"),
+            "       a label: `a`,\n",
+            "       a debug: `1.004µs`,\n",
+            "       b label: `b`,\n",
+            "       b debug: `1µs`,\n",
+            "       Δ label: `delta`,\n",
+            "       Δ debug: `3ns`,\n",
+            "     | a - b |: `4ns`,\n",
+            " | a - b | ≤ Δ: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_zero_durations() {
+        let a = Duration::ZERO;
+        let b = Duration::ZERO;
+        let delta = Duration::ZERO;
+        let actual = assert_duration_in_delta_as_result!(a, b, delta);
+        assert_eq!(actual.unwrap(), (Duration::ZERO, Duration::ZERO));
+    }
+
+    #[test]
+    fn success_with_references() {
+        let a = Duration::from_millis(100);
+        let b = Duration::from_millis(101);
+        let delta = Duration::from_millis(1);
+        let actual = assert_duration_in_delta_as_result!(&a, &b, &delta);
+        assert_eq!(
+            actual.unwrap(),
+            (Duration::from_millis(1), Duration::from_millis(1))
+        );
+    }
+}
+
+/// Assert a duration is within delta of another duration.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ Δ
+///
+/// * If true, return `(abs_diff, delta)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::time::Duration;
+///
+/// # fn main() {
+/// let a = Duration::from_millis(100);
+/// let b = Duration::from_millis(102);
+/// let delta = Duration::from_millis(2);
+/// assert_duration_in_delta!(a, b, delta);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = Duration::from_millis(100);
+/// let b = Duration::from_millis(103);
+/// let delta = Duration::from_millis(2);
+/// assert_duration_in_delta!(a, b, delta);
+/// # });
+/// // assertion failed: `assert_duration_in_delta!(a, b, Δ)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_duration_in_delta.html
+/// //        a label: `a`,
+/// //        a debug: `100ms`,
+/// //        b label: `b`,
+/// //        b debug: `103ms`,
+/// //        Δ label: `delta`,
+/// //        Δ debug: `2ms`,
+/// //      | a - b |: `3ms`,
+/// //  | a - b | ≤ Δ: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+/// #     assertables_url_line!("assert_duration_in_delta"),
+/// #     "       a label: `a`,\n",
+/// #     "       a debug: `100ms`,\n",
+/// #     "       b label: `b`,\n",
+/// #     "       b debug: `103ms`,\n",
+/// #     "       Δ label: `delta`,\n",
+/// #     "       Δ debug: `2ms`,\n",
+/// #     "     | a - b |: `3ms`,\n",
+/// #     " | a - b | ≤ Δ: false"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The absolute difference is computed by subtracting the shorter duration
+/// from the longer duration, so the macro works with `a < b` without the
+/// underflow panic of `Duration` subtraction.
+///
+/// # Module macros
+///
+/// * [`assert_duration_in_delta`](macro@crate::assert_duration_in_delta)
+/// * [`assert_duration_in_delta_as_result`](macro@crate::assert_duration_in_delta_as_result)
+/// * [`debug_assert_duration_in_delta`](macro@crate::debug_assert_duration_in_delta)
+///
+#[macro_export]
+macro_rules! assert_duration_in_delta {
+    ($a:expr, $b:expr, $delta:expr $(,)?) => {{
+        match $crate::assert_duration_in_delta_as_result!($a, $b, $delta) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $delta:expr, $($message:tt)+) => {{
+        match $crate::assert_duration_in_delta_as_result!($a, $b, $delta) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_duration_in_delta {
+    use std::panic;
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let a = Duration::from_millis(100);
+        let b = Duration::from_millis(102);
+        let delta = Duration::from_millis(2);
+        let actual = assert_duration_in_delta!(a, b, delta);
+        assert_eq!(actual, (Duration::from_millis(2), Duration::from_millis(2)));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = Duration::from_millis(100);
+            let b = Duration::from_millis(103);
+            let delta = Duration::from_millis(2);
+            let _actual = assert_duration_in_delta!(a, b, delta);
+        });
+        let message = concat!(
+            "assertion failed: `assert_duration_in_delta!(a, b, Δ)`\n",
+            assertables_url_line!("assert_duration_in_delta"),
+            "       a label: `a`,\n",
+            "       a debug: `100ms`,\n",
+            "       b label: `b`,\n",
+            "       b debug: `103ms`,\n",
+            "       Δ label: `delta`,\n",
+            "       Δ debug: `2ms`,\n",
+            "     | a - b |: `3ms`,\n",
+            " | a - b | ≤ Δ: false"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a duration is within delta of another duration.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ Δ
+///
+/// This macro provides the same statements as [`assert_duration_in_delta`](macro.assert_duration_in_delta.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_duration_in_delta`](macro@crate::assert_duration_in_delta)
+/// * [`assert_duration_in_delta_as_result`](macro@crate::assert_duration_in_delta_as_result)
+/// * [`debug_assert_duration_in_delta`](macro@crate::debug_assert_duration_in_delta)
+///
+#[macro_export]
+macro_rules! debug_assert_duration_in_delta {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_duration_in_delta!($($arg)*);
+        }
+    };
+}
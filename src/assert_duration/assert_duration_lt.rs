@@ -0,0 +1,303 @@
+//! Assert a duration is less than another duration.
+//!
+//! Pseudocode:<br>
+//! a < b
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::time::Duration;
+//!
+//! let a = Duration::from_millis(100);
+//! let b = Duration::from_millis(101);
+//! assert_duration_lt!(a, b);
+//! ```
+//!
+//! Each argument must be a `Duration`, or a reference to a `Duration`.
+//! The failure message shows the absolute difference, which is computed
+//! without the underflow panic of `Duration` subtraction.
+//!
+//! # Module macros
+//!
+//! * [`assert_duration_lt`](macro@crate::assert_duration_lt)
+//! * [`assert_duration_lt_as_result`](macro@crate::assert_duration_lt_as_result)
+//! * [`debug_assert_duration_lt`](macro@crate::debug_assert_duration_lt)
+
+/// Assert a duration is less than another duration.
+///
+/// Pseudocode:<br>
+/// a < b
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_duration_lt`](macro@crate::assert_duration_lt)
+/// * [`assert_duration_lt_as_result`](macro@crate::assert_duration_lt_as_result)
+/// * [`debug_assert_duration_lt`](macro@crate::debug_assert_duration_lt)
+///
+#[macro_export]
+macro_rules! assert_duration_lt_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let a: &::core::time::Duration = a;
+                let b: &::core::time::Duration = b;
+                if a < b {
+                    Ok(())
+                } else {
+                    let abs_diff = if a >= b { *a - *b } else { *b - *a };
+                    Err($crate::__private::format!(
+                        concat!(
+                            "assertion failed: `assert_duration_lt!(a, b)`\n",
+                            $crate::assertables_url_line!("assert_duration_lt"),
+                            "   a label: `{}`,\n",
+                            "   a debug: `{:?}`,\n",
+                            "   b label: `{}`,\n",
+                            "   b debug: `{:?}`,\n",
+                            " | a - b |: `{:?}`"
+                        ),
+                        stringify!($a),
+                        a,
+                        stringify!($b),
+                        b,
+                        abs_diff
+                    ))
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_duration_lt_as_result {
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let a = Duration::from_millis(100);
+        let b = Duration::from_millis(101);
+        let actual = assert_duration_lt_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = Duration::from_millis(101);
+        let b = Duration::from_millis(100);
+        let actual = assert_duration_lt_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_duration_lt!(a, b)`\n",
+            assertables_url_line!("assert_duration_lt"),
+            "   a label: `a`,\n",
+            "   a debug: `101ms`,\n",
+            "   b label: `b`,\n",
+            "   b debug: `100ms`,\n",
+            " | a - b |: `1ms`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_with_millis_100_100() {
+        let a = Duration::from_millis(100);
+        let b = Duration::from_millis(100);
+        let actual = assert_duration_lt_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_duration_lt!(a, b)`\n",
+            assertables_url_line!("assert_duration_lt"),
+            "   a label: `a`,\n",
+            "   a debug: `100ms`,\n",
+            "   b label: `b`,\n",
+            "   b debug: `100ms`,\n",
+            " | a - b |: `0ns`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_nanos() {
+        let a = Duration::from_nanos(2000);
+        let b = Duration::from_nanos(2010);
+        let actual = assert_duration_lt_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_nanos() {
+        let a = Duration::from_nanos(2010);
+        let b = Duration::from_nanos(2000);
+        let actual = assert_duration_lt_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_duration_lt!(a, b)`\n",
+            assertables_url_line!("assert_duration_lt"),
+            "   a label: `a`,\n",
+            "   a debug: `2.01µs`,\n",
+            "   b label: `b`,\n",
+            "   b debug: `2µs`,\n",
+            " | a - b |: `10ns`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a duration is less than another duration.
+///
+/// Pseudocode:<br>
+/// a < b
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::time::Duration;
+///
+/// # fn main() {
+/// let a = Duration::from_millis(100);
+/// let b = Duration::from_millis(101);
+/// assert_duration_lt!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = Duration::from_millis(101);
+/// let b = Duration::from_millis(100);
+/// assert_duration_lt!(a, b);
+/// # });
+/// // assertion failed: `assert_duration_lt!(a, b)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_duration_lt.html
+/// //    a label: `a`,
+/// //    a debug: `101ms`,
+/// //    b label: `b`,
+/// //    b debug: `100ms`,
+/// //  | a - b |: `1ms`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_duration_lt!(a, b)`\n",
+/// #     assertables_url_line!("assert_duration_lt"),
+/// #     "   a label: `a`,\n",
+/// #     "   a debug: `101ms`,\n",
+/// #     "   b label: `b`,\n",
+/// #     "   b debug: `100ms`,\n",
+/// #     " | a - b |: `1ms`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// Each argument must be a `Duration`, or a reference to a `Duration`.
+/// The failure message shows the absolute difference, which is computed
+/// without the underflow panic of `Duration` subtraction.
+///
+/// # Module macros
+///
+/// * [`assert_duration_lt`](macro@crate::assert_duration_lt)
+/// * [`assert_duration_lt_as_result`](macro@crate::assert_duration_lt_as_result)
+/// * [`debug_assert_duration_lt`](macro@crate::debug_assert_duration_lt)
+///
+#[macro_export]
+macro_rules! assert_duration_lt {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_duration_lt_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_duration_lt_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_duration_lt {
+    use std::panic;
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let a = Duration::from_millis(100);
+        let b = Duration::from_millis(101);
+        let actual = assert_duration_lt!(a, b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = Duration::from_millis(101);
+            let b = Duration::from_millis(100);
+            let _actual = assert_duration_lt!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_duration_lt!(a, b)`\n",
+            assertables_url_line!("assert_duration_lt"),
+            "   a label: `a`,\n",
+            "   a debug: `101ms`,\n",
+            "   b label: `b`,\n",
+            "   b debug: `100ms`,\n",
+            " | a - b |: `1ms`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a duration is less than another duration.
+///
+/// Pseudocode:<br>
+/// a < b
+///
+/// This macro provides the same statements as [`assert_duration_lt`](macro.assert_duration_lt.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_duration_lt`](macro@crate::assert_duration_lt)
+/// * [`assert_duration_lt_as_result`](macro@crate::assert_duration_lt_as_result)
+/// * [`debug_assert_duration_lt`](macro@crate::debug_assert_duration_lt)
+///
+#[macro_export]
+macro_rules! debug_assert_duration_lt {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_duration_lt!($($arg)*);
+        }
+    };
+}
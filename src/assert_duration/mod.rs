@@ -0,0 +1,45 @@
+//! Assert for comparing durations.
+//!
+//! These macros help with timing, such as checking that a measured
+//! [`Duration`](core::time::Duration) is close to an expected duration.
+//! Each macro uses `Duration` arithmetic directly, and computes an absolute
+//! difference by subtracting the shorter duration from the longer duration,
+//! so the macros work in either order without the underflow panic of
+//! `Duration` subtraction.
+//!
+//! Compare a duration with another duration within a delta:
+//!
+//! * [`assert_duration_in_delta!(a, b, delta)`](macro@crate::assert_duration_in_delta) ≈ | a - b | ≤ Δ
+//!
+//! Compare a duration with another duration:
+//!
+//! * [`assert_duration_eq!(a, b)`](macro@crate::assert_duration_eq) ≈ a = b
+//! * [`assert_duration_ne!(a, b)`](macro@crate::assert_duration_ne) ≈ a ≠ b
+//! * [`assert_duration_lt!(a, b)`](macro@crate::assert_duration_lt) ≈ a < b
+//! * [`assert_duration_le!(a, b)`](macro@crate::assert_duration_le) ≈ a ≤ b
+//! * [`assert_duration_gt!(a, b)`](macro@crate::assert_duration_gt) ≈ a > b
+//! * [`assert_duration_ge!(a, b)`](macro@crate::assert_duration_ge) ≈ a ≥ b
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::time::Duration;
+//!
+//! let a = Duration::from_millis(98);
+//! let b = Duration::from_millis(100);
+//! let delta = Duration::from_millis(2);
+//! assert_duration_in_delta!(a, b, delta);
+//! assert_duration_lt!(a, b);
+//! ```
+
+// Compare another within a delta
+pub mod assert_duration_in_delta;
+
+// Compare another
+pub mod assert_duration_eq;
+pub mod assert_duration_ge;
+pub mod assert_duration_gt;
+pub mod assert_duration_le;
+pub mod assert_duration_lt;
+pub mod assert_duration_ne;
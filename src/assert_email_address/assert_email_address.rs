@@ -0,0 +1,351 @@
+//! Assert an expression (such as a string) is an email address.
+//!
+//! Pseudocode:<br>
+//! a is an email address
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "alfa@example.com";
+//! assert_email_address!(a);
+//! ```
+//!
+//! If the assertion fails, then the message shows the reason,
+//! such as a missing `@`, an empty local part, or an invalid domain.
+//! For the rules, see the module [`assert_email_address`](module@crate::assert_email_address).
+//!
+//! # Module macros
+//!
+//! * [`assert_email_address`](macro@crate::assert_email_address)
+//! * [`assert_email_address_as_result`](macro@crate::assert_email_address_as_result)
+//! * [`debug_assert_email_address`](macro@crate::debug_assert_email_address)
+
+/// Assert an expression (such as a string) is an email address.
+///
+/// Pseudocode:<br>
+/// a is an email address
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_email_address`](macro@crate::assert_email_address)
+/// * [`assert_email_address_as_result`](macro@crate::assert_email_address_as_result)
+/// * [`debug_assert_email_address`](macro@crate::debug_assert_email_address)
+///
+#[macro_export]
+macro_rules! assert_email_address_as_result {
+    ($a:expr $(,)?) => {{
+        match (&$a) {
+            a => {
+                match $crate::assert_email_address::email_address_check(a) {
+                    Ok(()) => Ok(()),
+                    Err(reason) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_email_address!(a)`\n",
+                                    $crate::assertables_url_line!("assert_email_address"),
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    "  reason: {}"
+                                ),
+                                stringify!($a),
+                                a,
+                                reason
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_email_address_as_result {
+    #[test]
+    fn success() {
+        let a = "alfa@example.com";
+        let actual = assert_email_address_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = "alfa";
+        let actual = assert_email_address_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_email_address!(a)`\n",
+            assertables_url_line!("assert_email_address"),
+            " a label: `a`,\n",
+            " a debug: `\"alfa\"`,\n",
+            "  reason: missing the @ separator"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_local_part_is_empty() {
+        let a = "@example.com";
+        let actual = assert_email_address_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_email_address!(a)`\n",
+            assertables_url_line!("assert_email_address"),
+            " a label: `a`,\n",
+            " a debug: `\"@example.com\"`,\n",
+            "  reason: local part is empty"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_local_part_is_too_long() {
+        let a = "x".repeat(65) + "@example.com";
+        let actual = assert_email_address_as_result!(a);
+        assert!(actual
+            .unwrap_err()
+            .ends_with("\n  reason: local part is 65 characters, which is more than 64"));
+    }
+
+    #[test]
+    fn failure_because_local_part_has_invalid_character() {
+        let a = "alfa bravo@example.com";
+        let actual = assert_email_address_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_email_address!(a)`\n",
+            assertables_url_line!("assert_email_address"),
+            " a label: `a`,\n",
+            " a debug: `\"alfa bravo@example.com\"`,\n",
+            "  reason: local part has an invalid character ' '"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_local_part_has_two_at() {
+        let a = "alfa@bravo@example.com";
+        let actual = assert_email_address_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_email_address!(a)`\n",
+            assertables_url_line!("assert_email_address"),
+            " a label: `a`,\n",
+            " a debug: `\"alfa@bravo@example.com\"`,\n",
+            "  reason: local part has an invalid character '@'"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_local_part_has_invalid_dot() {
+        let a = "alfa..bravo@example.com";
+        let actual = assert_email_address_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_email_address!(a)`\n",
+            assertables_url_line!("assert_email_address"),
+            " a label: `a`,\n",
+            " a debug: `\"alfa..bravo@example.com\"`,\n",
+            "  reason: local part has a dot that is first, last, or next to another dot"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_domain_is_empty() {
+        let a = "alfa@";
+        let actual = assert_email_address_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_email_address!(a)`\n",
+            assertables_url_line!("assert_email_address"),
+            " a label: `a`,\n",
+            " a debug: `\"alfa@\"`,\n",
+            "  reason: domain is empty"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_domain_has_empty_label() {
+        let a = "alfa@example..com";
+        let actual = assert_email_address_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_email_address!(a)`\n",
+            assertables_url_line!("assert_email_address"),
+            " a label: `a`,\n",
+            " a debug: `\"alfa@example..com\"`,\n",
+            "  reason: domain has an invalid label \"\""
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_domain_has_invalid_label() {
+        let a = "alfa@-example.com";
+        let actual = assert_email_address_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_email_address!(a)`\n",
+            assertables_url_line!("assert_email_address"),
+            " a label: `a`,\n",
+            " a debug: `\"alfa@-example.com\"`,\n",
+            "  reason: domain has an invalid label \"-example\""
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_string() {
+        let a = String::from("alfa.bravo+charlie@example.co.uk");
+        let actual = assert_email_address_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+}
+
+/// Assert an expression (such as a string) is an email address.
+///
+/// Pseudocode:<br>
+/// a is an email address
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa@example.com";
+/// assert_email_address!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa";
+/// assert_email_address!(a);
+/// # });
+/// // assertion failed: `assert_email_address!(a)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_email_address.html
+/// //  a label: `a`,
+/// //  a debug: `\"alfa\"`,
+/// //   reason: missing the @ separator
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_email_address!(a)`\n",
+/// #     assertables_url_line!("assert_email_address"),
+/// #     " a label: `a`,\n",
+/// #     " a debug: `\"alfa\"`,\n",
+/// #     "  reason: missing the @ separator"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// If the assertion fails, then the message shows the reason,
+/// such as a missing `@`, an empty local part, or an invalid domain.
+/// For the rules, see the module [`assert_email_address`](module@crate::assert_email_address).
+///
+/// # Module macros
+///
+/// * [`assert_email_address`](macro@crate::assert_email_address)
+/// * [`assert_email_address_as_result`](macro@crate::assert_email_address_as_result)
+/// * [`debug_assert_email_address`](macro@crate::debug_assert_email_address)
+///
+#[macro_export]
+macro_rules! assert_email_address {
+    ($a:expr $(,)?) => {{
+        match $crate::assert_email_address_as_result!($a) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $($message:tt)+) => {{
+        match $crate::assert_email_address_as_result!($a) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_email_address {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = "alfa@example.com";
+        let actual = assert_email_address!(a);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = "alfa";
+            let _actual = assert_email_address!(a);
+        });
+        let message = concat!(
+            "assertion failed: `assert_email_address!(a)`\n",
+            assertables_url_line!("assert_email_address"),
+            " a label: `a`,\n",
+            " a debug: `\"alfa\"`,\n",
+            "  reason: missing the @ separator"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an expression (such as a string) is an email address.
+///
+/// Pseudocode:<br>
+/// a is an email address
+///
+/// This macro provides the same statements as [`assert_email_address`](macro.assert_email_address.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_email_address`](macro@crate::assert_email_address)
+/// * [`assert_email_address_as_result`](macro@crate::assert_email_address_as_result)
+/// * [`debug_assert_email_address`](macro@crate::debug_assert_email_address)
+///
+#[macro_export]
+macro_rules! debug_assert_email_address {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_email_address!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,124 @@
+//! Assert for email addresses.
+//!
+//! These macros help with checking that a string is a plausible email address,
+//! and, if it is not, explaining why not.
+//!
+//! * [`assert_email_address!(a)`](macro@crate::assert_email_address) ≈ a is an email address
+//!
+//! The check is deliberately pragmatic, rather than a full parser for RFC 5322:
+//!
+//! * The address has a local part, then `@`, then a domain.
+//!
+//! * The local part is 1 to 64 characters, using letters, digits, dots, and
+//!   the characters `` !#$%&'*+-/=?^_`{|}~ ``. A dot cannot be first, last,
+//!   or next to another dot.
+//!
+//! * The domain is 1 to 255 characters, made of labels separated by dots.
+//!   Each label is 1 to 63 characters, using letters, digits, and hyphens.
+//!   A hyphen cannot be first or last in a label.
+//!
+//! * Any non-ASCII character counts as a letter, for internationalized addresses.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "alfa@example.com";
+//! assert_email_address!(a);
+//! ```
+
+#[allow(clippy::module_inception)]
+pub mod assert_email_address;
+
+/// Why a string is not an email address.
+///
+/// The `Display` text is stable, because the macro messages include it.
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailAddressReason {
+    MissingAt,
+    LocalPartEmpty,
+    LocalPartTooLong(usize),
+    LocalPartInvalidCharacter(char),
+    LocalPartInvalidDot,
+    DomainEmpty,
+    DomainTooLong(usize),
+    DomainInvalidLabel(String),
+}
+
+impl ::std::fmt::Display for EmailAddressReason {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Self::MissingAt => write!(f, "missing the @ separator"),
+            Self::LocalPartEmpty => write!(f, "local part is empty"),
+            Self::LocalPartTooLong(n) => {
+                write!(f, "local part is {} characters, which is more than 64", n)
+            }
+            Self::LocalPartInvalidCharacter(c) => {
+                write!(f, "local part has an invalid character {:?}", c)
+            }
+            Self::LocalPartInvalidDot => write!(
+                f,
+                "local part has a dot that is first, last, or next to another dot"
+            ),
+            Self::DomainEmpty => write!(f, "domain is empty"),
+            Self::DomainTooLong(n) => {
+                write!(f, "domain is {} characters, which is more than 255", n)
+            }
+            Self::DomainInvalidLabel(label) => {
+                write!(f, "domain has an invalid label {:?}", label)
+            }
+        }
+    }
+}
+
+/// Check a string is an email address, and if not, return the reason.
+///
+/// The separator is the last `@`, so any other `@` is in the local part,
+/// where it is an invalid character.
+#[doc(hidden)]
+pub fn email_address_check<S: AsRef<str> + ?Sized>(s: &S) -> Result<(), EmailAddressReason> {
+    let s = s.as_ref();
+    let (local, domain) = match s.rfind('@') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => return Err(EmailAddressReason::MissingAt),
+    };
+    // Local part
+    if local.is_empty() {
+        return Err(EmailAddressReason::LocalPartEmpty);
+    }
+    let local_len = local.chars().count();
+    if local_len > 64 {
+        return Err(EmailAddressReason::LocalPartTooLong(local_len));
+    }
+    if let Some(c) = local.chars().find(|c| {
+        !(c.is_ascii_alphanumeric() || !c.is_ascii() || ".!#$%&'*+-/=?^_`{|}~".contains(*c))
+    }) {
+        return Err(EmailAddressReason::LocalPartInvalidCharacter(c));
+    }
+    if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+        return Err(EmailAddressReason::LocalPartInvalidDot);
+    }
+    // Domain
+    if domain.is_empty() {
+        return Err(EmailAddressReason::DomainEmpty);
+    }
+    let domain_len = domain.chars().count();
+    if domain_len > 255 {
+        return Err(EmailAddressReason::DomainTooLong(domain_len));
+    }
+    for label in domain.split('.') {
+        if label.is_empty()
+            || label.chars().count() > 63
+            || label.starts_with('-')
+            || label.ends_with('-')
+            || !label
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || !c.is_ascii() || c == '-')
+        {
+            return Err(EmailAddressReason::DomainInvalidLabel(String::from(label)));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,345 @@
+//! Assert an iterator ends with the items of a suffix iterator.
+//!
+//! Pseudocode:<br>
+//! (iter into iter).last(suffix count) = (suffix into iter)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let iter = 1..5;
+//! let suffix = [3, 4];
+//! assert_ends_with_iter!(iter, suffix);
+//! ```
+//!
+//! This macro is useful for a streaming iterator, because it keeps only as
+//! many items of the iterator as the suffix has, while it reads to the end.
+//!
+//! * The arguments can be anything that implements `IntoIterator`, such as an
+//!   iterator, an array, or a vector. The arguments are moved, so to keep using
+//!   a collection, pass a reference, such as `&vec` with a suffix `[&3]`.
+//!
+//! * The iterator and the suffix must both be finite, because the macro
+//!   reads each one to its end. An unbounded iterator, such as `1..`,
+//!   never ends, so use [`assert_starts_with_iter`](macro@crate::assert_starts_with_iter)
+//!   or take a finite part first, such as `(1..).take(10)`.
+//!
+//! * The failure message shows the expected suffix and the actual last items,
+//!   which has fewer items when the iterator is shorter than the suffix.
+//!
+//! This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+//!
+//! # Module macros
+//!
+//! * [`assert_ends_with_iter`](macro@crate::assert_ends_with_iter)
+//! * [`assert_ends_with_iter_as_result`](macro@crate::assert_ends_with_iter_as_result)
+//! * [`debug_assert_ends_with_iter`](macro@crate::debug_assert_ends_with_iter)
+
+/// Assert an iterator ends with the items of a suffix iterator.
+///
+/// Pseudocode:<br>
+/// (iter into iter).last(suffix count) = (suffix into iter)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ends_with_iter`](macro@crate::assert_ends_with_iter)
+/// * [`assert_ends_with_iter_as_result`](macro@crate::assert_ends_with_iter_as_result)
+/// * [`debug_assert_ends_with_iter`](macro@crate::debug_assert_ends_with_iter)
+///
+#[macro_export]
+macro_rules! assert_ends_with_iter_as_result {
+    ($iter:expr, $suffix:expr $(,)?) => {{
+        match ($iter, $suffix) {
+            (iter, suffix) => {
+                let suffix: Vec<_> = ::std::iter::IntoIterator::into_iter(suffix).collect();
+                let mut tail = ::std::collections::VecDeque::with_capacity(suffix.len());
+                if !suffix.is_empty() {
+                    for item in iter {
+                        if tail.len() == suffix.len() {
+                            tail.pop_front();
+                        }
+                        tail.push_back(item);
+                    }
+                }
+                if tail.len() == suffix.len()
+                    && tail.iter().zip(suffix.iter()).all(|(actual, expected)| actual == expected)
+                {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_ends_with_iter!(iter, suffix)`\n",
+                                $crate::assertables_url_line!("assert_ends_with_iter"),
+                                "   iter label: `{}`,\n",
+                                " suffix label: `{}`,\n",
+                                "     expected: `{:?}`,\n",
+                                "       actual: `{:?}`"
+                            ),
+                            stringify!($iter),
+                            stringify!($suffix),
+                            suffix,
+                            tail
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_ends_with_iter_as_result {
+    #[test]
+    fn success() {
+        let iter = 1..5;
+        let suffix = [3, 4];
+        let actual = assert_ends_with_iter_as_result!(iter, suffix);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let iter = 1..5;
+        let suffix = [3, 5];
+        let actual = assert_ends_with_iter_as_result!(iter, suffix);
+        let message = concat!(
+            "assertion failed: `assert_ends_with_iter!(iter, suffix)`\n",
+            assertables_url_line!("assert_ends_with_iter"),
+            "   iter label: `iter`,\n",
+            " suffix label: `suffix`,\n",
+            "     expected: `[3, 5]`,\n",
+            "       actual: `[3, 4]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_vec() {
+        let iter = vec!["alfa", "bravo", "charlie"];
+        let suffix = vec!["bravo", "charlie"];
+        let actual = assert_ends_with_iter_as_result!(iter, suffix);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_vec_reference() {
+        let iter = vec![1, 2, 3];
+        let actual = assert_ends_with_iter_as_result!(&iter, [&3]);
+        assert_eq!(actual.unwrap(), ());
+        assert_eq!(iter.len(), 3);
+    }
+
+    #[test]
+    fn success_with_empty_suffix() {
+        let iter = vec![1, 2, 3];
+        let suffix: [i32; 0] = [];
+        let actual = assert_ends_with_iter_as_result!(iter, suffix);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_custom_iterator() {
+        struct Countdown(u8);
+        impl Iterator for Countdown {
+            type Item = u8;
+            fn next(&mut self) -> Option<u8> {
+                if self.0 == 0 {
+                    None
+                } else {
+                    self.0 -= 1;
+                    Some(self.0)
+                }
+            }
+        }
+        let actual = assert_ends_with_iter_as_result!(Countdown(5), [1, 0]);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_because_iter_is_shorter() {
+        let iter = vec!["alfa"];
+        let suffix = vec!["alfa", "bravo"];
+        let actual = assert_ends_with_iter_as_result!(iter, suffix);
+        let message = concat!(
+            "assertion failed: `assert_ends_with_iter!(iter, suffix)`\n",
+            assertables_url_line!("assert_ends_with_iter"),
+            "   iter label: `iter`,\n",
+            " suffix label: `suffix`,\n",
+            "     expected: `[\"alfa\", \"bravo\"]`,\n",
+            "       actual: `[\"alfa\"]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert an iterator ends with the items of a suffix iterator.
+///
+/// Pseudocode:<br>
+/// (iter into iter).last(suffix count) = (suffix into iter)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let iter = 1..5;
+/// let suffix = [3, 4];
+/// assert_ends_with_iter!(iter, suffix);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let iter = 1..5;
+/// let suffix = [3, 5];
+/// assert_ends_with_iter!(iter, suffix);
+/// # });
+/// // assertion failed: `assert_ends_with_iter!(iter, suffix)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_ends_with_iter.html
+/// //    iter label: `iter`,
+/// //  suffix label: `suffix`,
+/// //      expected: `[3, 5]`,
+/// //        actual: `[3, 4]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_ends_with_iter!(iter, suffix)`\n",
+/// #     assertables_url_line!("assert_ends_with_iter"),
+/// #     "   iter label: `iter`,\n",
+/// #     " suffix label: `suffix`,\n",
+/// #     "     expected: `[3, 5]`,\n",
+/// #     "       actual: `[3, 4]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for a streaming iterator, because it keeps only as
+/// many items of the iterator as the suffix has, while it reads to the end.
+///
+/// * The arguments can be anything that implements `IntoIterator`, such as an
+///   iterator, an array, or a vector. The arguments are moved, so to keep using
+///   a collection, pass a reference, such as `&vec` with a suffix `[&3]`.
+///
+/// * The iterator and the suffix must both be finite, because the macro
+///   reads each one to its end. An unbounded iterator, such as `1..`,
+///   never ends, so use [`assert_starts_with_iter`](macro@crate::assert_starts_with_iter)
+///   or take a finite part first, such as `(1..).take(10)`.
+///
+/// * The failure message shows the expected suffix and the actual last items,
+///   which has fewer items when the iterator is shorter than the suffix.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_ends_with_iter`](macro@crate::assert_ends_with_iter)
+/// * [`assert_ends_with_iter_as_result`](macro@crate::assert_ends_with_iter_as_result)
+/// * [`debug_assert_ends_with_iter`](macro@crate::debug_assert_ends_with_iter)
+///
+#[macro_export]
+macro_rules! assert_ends_with_iter {
+    ($iter:expr, $suffix:expr $(,)?) => {{
+        match $crate::assert_ends_with_iter_as_result!($iter, $suffix) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($iter:expr, $suffix:expr, $($message:tt)+) => {{
+        match $crate::assert_ends_with_iter_as_result!($iter, $suffix) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_ends_with_iter {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let iter = 1..5;
+        let suffix = [3, 4];
+        let actual = assert_ends_with_iter!(iter, suffix);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let iter = 1..5;
+            let suffix = [3, 5];
+            let _actual = assert_ends_with_iter!(iter, suffix);
+        });
+        let message = concat!(
+            "assertion failed: `assert_ends_with_iter!(iter, suffix)`\n",
+            assertables_url_line!("assert_ends_with_iter"),
+            "   iter label: `iter`,\n",
+            " suffix label: `suffix`,\n",
+            "     expected: `[3, 5]`,\n",
+            "       actual: `[3, 4]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an iterator ends with the items of a suffix iterator.
+///
+/// Pseudocode:<br>
+/// (iter into iter).last(suffix count) = (suffix into iter)
+///
+/// This macro provides the same statements as [`assert_ends_with_iter`](macro.assert_ends_with_iter.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ends_with_iter`](macro@crate::assert_ends_with_iter)
+/// * [`assert_ends_with_iter_as_result`](macro@crate::assert_ends_with_iter_as_result)
+/// * [`debug_assert_ends_with_iter`](macro@crate::debug_assert_ends_with_iter)
+///
+#[macro_export]
+macro_rules! debug_assert_ends_with_iter {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_ends_with_iter!($($arg)*);
+        }
+    };
+}
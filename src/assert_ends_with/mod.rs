@@ -7,6 +7,8 @@
 //!
 //! * [`assert_not_ends_with!(sequence, subsequence)`](macro@crate::assert_not_ends_with) ≈ !container.contains(containee)
 //!
+//! * [`assert_ends_with_iter!(iter, suffix)`](macro@crate::assert_ends_with_iter) ≈ (iter into iter).last(suffix count) = (suffix into iter)
+//!
 //!
 //! # Example
 //!
@@ -22,7 +24,13 @@
 //! let sequence = vec![1, 2, 3];
 //! let subsequence = [3];
 //! assert_ends_with!(sequence, subsequence);
+//!
+//! // Iterator ends with items?
+//! let iter = 1..5;
+//! let suffix = [3, 4];
+//! assert_ends_with_iter!(iter, suffix);
 //! ```
 
 pub mod assert_ends_with;
+pub mod assert_ends_with_iter;
 pub mod assert_not_ends_with;
@@ -41,10 +41,10 @@ macro_rules! assert_eq_as_result {
                 if a == b {
                     Ok(())
                 } else {
-                    Err(format!(
+                    Err($crate::__private::format!(
                         concat!(
                             "assertion failed: `assert_eq!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_eq.html\n",
+                            $crate::assertables_url_line!("assert_eq"),
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
@@ -79,7 +79,7 @@ mod test_assert_eq_as_result {
         let actual = assert_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_eq.html\n",
+            assertables_url_line!("assert_eq"),
             " a label: `a`,\n",
             " a debug: `1`,\n",
             " b label: `b`,\n",
@@ -0,0 +1,328 @@
+//! Assert an expression is equal to another, by using a comparator.
+//!
+//! Pseudocode:<br>
+//! compare(a, b) = true
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "Alfa";
+//! let b = "ALFA";
+//! assert_eq_by!(a, b, |x, y| x.eq_ignore_ascii_case(y));
+//! ```
+//!
+//! This macro is useful for comparing values in a custom way, such as
+//! comparing floats field by field, or comparing types without `PartialEq`.
+//!
+//! * The comparator is called once, with a reference to `a` and a reference to `b`,
+//!   and returns `true` if the values are equal in your domain.
+//!
+//! * The failure message shows each value, and the comparator result.
+//!
+//! # Module macros
+//!
+//! * [`assert_eq_by`](macro@crate::assert_eq_by)
+//! * [`assert_eq_by_as_result`](macro@crate::assert_eq_by_as_result)
+//! * [`debug_assert_eq_by`](macro@crate::debug_assert_eq_by)
+
+/// Assert an expression is equal to another, by using a comparator.
+///
+/// Pseudocode:<br>
+/// compare(a, b) = true
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_eq_by`](macro@crate::assert_eq_by)
+/// * [`assert_eq_by_as_result`](macro@crate::assert_eq_by_as_result)
+/// * [`debug_assert_eq_by`](macro@crate::debug_assert_eq_by)
+///
+#[macro_export]
+macro_rules! assert_eq_by_as_result {
+    ($a:expr, $b:expr, $compare:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let compare_result = $crate::assert_eq::compare_by(a, b, $compare);
+                if compare_result {
+                    Ok(())
+                } else {
+                    Err($crate::__private::format!(
+                        concat!(
+                            "assertion failed: `assert_eq_by!(a, b, compare)`\n",
+                            $crate::assertables_url_line!("assert_eq_by"),
+                            "       a label: `{}`,\n",
+                            "       a debug: `{:?}`,\n",
+                            "       b label: `{}`,\n",
+                            "       b debug: `{:?}`,\n",
+                            " compare label: `{}`,\n",
+                            " compare(a, b): {}"
+                        ),
+                        stringify!($a),
+                        a,
+                        stringify!($b),
+                        b,
+                        stringify!($compare),
+                        compare_result
+                    ))
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_eq_by_as_result {
+    #[test]
+    fn success() {
+        let a = "Alfa";
+        let b = "ALFA";
+        let actual = assert_eq_by_as_result!(a, b, |x, y| x.eq_ignore_ascii_case(y));
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = "Alfa";
+        let b = "Bravo";
+        let actual = assert_eq_by_as_result!(a, b, |x, y| x.eq_ignore_ascii_case(y));
+        let message = concat!(
+            "assertion failed: `assert_eq_by!(a, b, compare)`\n",
+            assertables_url_line!("assert_eq_by"),
+            "       a label: `a`,\n",
+            "       a debug: `\"Alfa\"`,\n",
+            "       b label: `b`,\n",
+            "       b debug: `\"Bravo\"`,\n",
+            " compare label: `|x, y| x.eq_ignore_ascii_case(y)`,\n",
+            " compare(a, b): false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[derive(Debug)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[test]
+    fn success_with_type_without_partial_eq() {
+        let a = Point { x: 1.0, y: 2.0 };
+        let b = Point {
+            x: 1.0 + 1e-12,
+            y: 2.0 - 1e-12,
+        };
+        let actual = assert_eq_by_as_result!(a, b, |p, q| (p.x - q.x).abs() < 1e-9
+            && (p.y - q.y).abs() < 1e-9);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_type_without_partial_eq() {
+        let a = Point { x: 1.0, y: 2.0 };
+        let b = Point { x: 1.0, y: 3.0 };
+        let actual = assert_eq_by_as_result!(a, b, |p, q| (p.x - q.x).abs() < 1e-9
+            && (p.y - q.y).abs() < 1e-9);
+        assert!(actual.unwrap_err().ends_with(" compare(a, b): false"));
+    }
+
+    #[test]
+    fn success_with_different_types() {
+        let a: i32 = 1;
+        let b: &str = "1";
+        let actual = assert_eq_by_as_result!(a, b, |x: &i32, y: &&str| x.to_string() == *y);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn evaluates_each_argument_once_and_calls_compare_once() {
+        let mut a_count = 0;
+        let mut b_count = 0;
+        let mut compare_count = 0;
+        let actual = assert_eq_by_as_result!(
+            {
+                a_count += 1;
+                1
+            },
+            {
+                b_count += 1;
+                1
+            },
+            |x: &i32, y: &i32| {
+                compare_count += 1;
+                x == y
+            }
+        );
+        assert_eq!(actual.unwrap(), ());
+        assert_eq!(a_count, 1);
+        assert_eq!(b_count, 1);
+        assert_eq!(compare_count, 1);
+    }
+}
+
+/// Assert an expression is equal to another, by using a comparator.
+///
+/// Pseudocode:<br>
+/// compare(a, b) = true
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "Alfa";
+/// let b = "ALFA";
+/// assert_eq_by!(a, b, |x, y| x.eq_ignore_ascii_case(y));
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "Alfa";
+/// let b = "Bravo";
+/// assert_eq_by!(a, b, |x, y| x.eq_ignore_ascii_case(y));
+/// # });
+/// // assertion failed: `assert_eq_by!(a, b, compare)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_eq_by.html
+/// //        a label: `a`,
+/// //        a debug: `\"Alfa\"`,
+/// //        b label: `b`,
+/// //        b debug: `\"Bravo\"`,
+/// //  compare label: `|x, y| x.eq_ignore_ascii_case(y)`,
+/// //  compare(a, b): false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_eq_by!(a, b, compare)`\n",
+/// #     assertables_url_line!("assert_eq_by"),
+/// #     "       a label: `a`,\n",
+/// #     "       a debug: `\"Alfa\"`,\n",
+/// #     "       b label: `b`,\n",
+/// #     "       b debug: `\"Bravo\"`,\n",
+/// #     " compare label: `|x, y| x.eq_ignore_ascii_case(y)`,\n",
+/// #     " compare(a, b): false"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for comparing values in a custom way, such as
+/// comparing floats field by field, or comparing types without `PartialEq`.
+///
+/// * The comparator is called once, with a reference to `a` and a reference to `b`,
+///   and returns `true` if the values are equal in your domain.
+///
+/// * The failure message shows each value, and the comparator result.
+///
+/// # Module macros
+///
+/// * [`assert_eq_by`](macro@crate::assert_eq_by)
+/// * [`assert_eq_by_as_result`](macro@crate::assert_eq_by_as_result)
+/// * [`debug_assert_eq_by`](macro@crate::debug_assert_eq_by)
+///
+#[macro_export]
+macro_rules! assert_eq_by {
+    ($a:expr, $b:expr, $compare:expr $(,)?) => {{
+        match $crate::assert_eq_by_as_result!($a, $b, $compare) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $compare:expr, $($message:tt)+) => {{
+        match $crate::assert_eq_by_as_result!($a, $b, $compare) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_eq_by {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = "Alfa";
+        let b = "ALFA";
+        let actual = assert_eq_by!(a, b, |x, y| x.eq_ignore_ascii_case(y));
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = "Alfa";
+            let b = "Bravo";
+            let _actual = assert_eq_by!(a, b, |x, y| x.eq_ignore_ascii_case(y));
+        });
+        let message = concat!(
+            "assertion failed: `assert_eq_by!(a, b, compare)`\n",
+            assertables_url_line!("assert_eq_by"),
+            "       a label: `a`,\n",
+            "       a debug: `\"Alfa\"`,\n",
+            "       b label: `b`,\n",
+            "       b debug: `\"Bravo\"`,\n",
+            " compare label: `|x, y| x.eq_ignore_ascii_case(y)`,\n",
+            " compare(a, b): false"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an expression is equal to another, by using a comparator.
+///
+/// Pseudocode:<br>
+/// compare(a, b) = true
+///
+/// This macro provides the same statements as [`assert_eq_by`](macro.assert_eq_by.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_eq_by`](macro@crate::assert_eq_by)
+/// * [`assert_eq_by_as_result`](macro@crate::assert_eq_by_as_result)
+/// * [`debug_assert_eq_by`](macro@crate::debug_assert_eq_by)
+///
+#[macro_export]
+macro_rules! debug_assert_eq_by {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_eq_by!($($arg)*);
+        }
+    };
+}
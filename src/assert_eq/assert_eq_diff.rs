@@ -0,0 +1,334 @@
+//! Assert an expression is equal to another, with a line diff of strings.
+//!
+//! Pseudocode:<br>
+//! a = b
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "alfa\nbravo\ncharlie";
+//! let b = "alfa\nbravo\ncharlie";
+//! assert_eq_diff!(a, b);
+//! ```
+//!
+//! This macro is useful for comparing long multi-line strings, such as
+//! generated source code, assembly, or reports, where a failure message
+//! that shows both full strings is hard to read.
+//!
+//! * If both values are strings, such as `&str`, `String`, or `Cow<str>`,
+//!   then the failure message shows a unified line diff: a line only in `a`
+//!   starts with `-`, a line only in `b` starts with `+`, and each line shows
+//!   its line number in `a` and in `b`. Unchanged lines far from a change are
+//!   elided as `...`. If the strings have very many differing lines, such as
+//!   thousands in each, then the diff shows only the first differing line,
+//!   to bound the memory that the message uses.
+//!
+//! * Otherwise, the failure message shows each value's debug text,
+//!   the same as [`assert_eq`](macro@crate::assert_eq_as_result).
+//!
+//! # Module macros
+//!
+//! * [`assert_eq_diff`](macro@crate::assert_eq_diff)
+//! * [`assert_eq_diff_as_result`](macro@crate::assert_eq_diff_as_result)
+//! * [`debug_assert_eq_diff`](macro@crate::debug_assert_eq_diff)
+
+/// Assert an expression is equal to another, with a line diff of strings.
+///
+/// Pseudocode:<br>
+/// a = b
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_eq_diff`](macro@crate::assert_eq_diff)
+/// * [`assert_eq_diff_as_result`](macro@crate::assert_eq_diff_as_result)
+/// * [`debug_assert_eq_diff`](macro@crate::debug_assert_eq_diff)
+///
+#[macro_export]
+macro_rules! assert_eq_diff_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_eq::{ProbeDiff as _, ProbeNoDiff as _};
+        match (&$a, &$b) {
+            (a, b) => {
+                if a == b {
+                    Ok(())
+                } else {
+                    match (&$crate::assert_eq::DiffProbe(a, b)).probe_diff() {
+                        Some(diff) => Err(
+                            $crate::__private::format!(
+                                concat!(
+                                    "assertion failed: `assert_eq_diff!(a, b)`\n",
+                                    $crate::assertables_url_line!("assert_eq_diff"),
+                                    " a label: `{}`,\n",
+                                    " b label: `{}`,\n",
+                                    "    diff:\n",
+                                    "{}"
+                                ),
+                                stringify!($a),
+                                stringify!($b),
+                                diff
+                            )
+                        ),
+                        None => Err(
+                            $crate::__private::format!(
+                                concat!(
+                                    "assertion failed: `assert_eq_diff!(a, b)`\n",
+                                    $crate::assertables_url_line!("assert_eq_diff"),
+                                    " a label: `{}`,\n",
+                                    " a debug: `{:?}`,\n",
+                                    " b label: `{}`,\n",
+                                    " b debug: `{:?}`"
+                                ),
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b
+                            )
+                        ),
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_eq_diff_as_result {
+    #[test]
+    fn success() {
+        let a = "alfa\nbravo\ncharlie";
+        let b = "alfa\nbravo\ncharlie";
+        let actual = assert_eq_diff_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = "alfa\nbravo\ncharlie";
+        let b = "alfa\nbravo\ndelta";
+        let actual = assert_eq_diff_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_eq_diff!(a, b)`\n",
+            assertables_url_line!("assert_eq_diff"),
+            " a label: `a`,\n",
+            " b label: `b`,\n",
+            "    diff:\n",
+            " 1 1 | alfa\n",
+            " 2 2 | bravo\n",
+            "-3   | charlie\n",
+            "+  3 | delta"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_with_string_and_str() {
+        let a = String::from("alfa\nbravo");
+        let b = "alfa\ncharlie";
+        let actual = assert_eq_diff_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_eq_diff!(a, b)`\n",
+            assertables_url_line!("assert_eq_diff"),
+            " a label: `a`,\n",
+            " b label: `b`,\n",
+            "    diff:\n",
+            " 1 1 | alfa\n",
+            "-2   | bravo\n",
+            "+  2 | charlie"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_with_non_string_falls_back_to_debug() {
+        let a: i32 = 1;
+        let b: i32 = 2;
+        let actual = assert_eq_diff_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_eq_diff!(a, b)`\n",
+            assertables_url_line!("assert_eq_diff"),
+            " a label: `a`,\n",
+            " a debug: `1`,\n",
+            " b label: `b`,\n",
+            " b debug: `2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert an expression is equal to another, with a line diff of strings.
+///
+/// Pseudocode:<br>
+/// a = b
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa\nbravo\ncharlie";
+/// let b = "alfa\nbravo\ncharlie";
+/// assert_eq_diff!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa\nbravo\ncharlie";
+/// let b = "alfa\nbravo\ndelta";
+/// assert_eq_diff!(a, b);
+/// # });
+/// // assertion failed: `assert_eq_diff!(a, b)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_eq_diff.html
+/// //  a label: `a`,
+/// //  b label: `b`,
+/// //     diff:
+/// //  1 1 | alfa
+/// //  2 2 | bravo
+/// // -3   | charlie
+/// // +  3 | delta
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_eq_diff!(a, b)`\n",
+/// #     assertables_url_line!("assert_eq_diff"),
+/// #     " a label: `a`,\n",
+/// #     " b label: `b`,\n",
+/// #     "    diff:\n",
+/// #     " 1 1 | alfa\n",
+/// #     " 2 2 | bravo\n",
+/// #     "-3   | charlie\n",
+/// #     "+  3 | delta"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for comparing long multi-line strings, such as
+/// generated source code, assembly, or reports, where a failure message
+/// that shows both full strings is hard to read.
+///
+/// * If both values are strings, such as `&str`, `String`, or `Cow<str>`,
+///   then the failure message shows a unified line diff: a line only in `a`
+///   starts with `-`, a line only in `b` starts with `+`, and each line shows
+///   its line number in `a` and in `b`. Unchanged lines far from a change are
+///   elided as `...`.
+///
+/// * Otherwise, the failure message shows each value's debug text,
+///   the same as [`assert_eq`](macro@crate::assert_eq_as_result).
+///
+/// # Module macros
+///
+/// * [`assert_eq_diff`](macro@crate::assert_eq_diff)
+/// * [`assert_eq_diff_as_result`](macro@crate::assert_eq_diff_as_result)
+/// * [`debug_assert_eq_diff`](macro@crate::debug_assert_eq_diff)
+///
+#[macro_export]
+macro_rules! assert_eq_diff {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_eq_diff_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_eq_diff_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_eq_diff {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = "alfa\nbravo\ncharlie";
+        let b = "alfa\nbravo\ncharlie";
+        let actual = assert_eq_diff!(a, b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = "alfa\nbravo\ncharlie";
+            let b = "alfa\nbravo\ndelta";
+            let _actual = assert_eq_diff!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_eq_diff!(a, b)`\n",
+            assertables_url_line!("assert_eq_diff"),
+            " a label: `a`,\n",
+            " b label: `b`,\n",
+            "    diff:\n",
+            " 1 1 | alfa\n",
+            " 2 2 | bravo\n",
+            "-3   | charlie\n",
+            "+  3 | delta"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an expression is equal to another, with a line diff of strings.
+///
+/// Pseudocode:<br>
+/// a = b
+///
+/// This macro provides the same statements as [`assert_eq_diff`](macro.assert_eq_diff.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_eq_diff`](macro@crate::assert_eq_diff)
+/// * [`assert_eq_diff_as_result`](macro@crate::assert_eq_diff_as_result)
+/// * [`debug_assert_eq_diff`](macro@crate::debug_assert_eq_diff)
+///
+#[macro_export]
+macro_rules! debug_assert_eq_diff {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_eq_diff!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,330 @@
+//! Assert an expression is equal to another, after normalizing each one.
+//!
+//! Pseudocode:<br>
+//! normalize(a) = normalize(b)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "Alfa";
+//! let b = "ALFA";
+//! assert_eq_normalized!(a, b, str::to_lowercase);
+//! ```
+//!
+//! This macro is useful for comparing values that are equivalent in your domain,
+//! yet differ in a way that does not matter, such as letter case, surrounding
+//! whitespace, item order, or key order.
+//!
+//! * The normalize function is called with a reference to `a`, then with a reference to `b`,
+//!   so the same function must accept both.
+//!
+//! * The normalized values are compared by using `==`.
+//!
+//! * The failure message shows each original value and each normalized value.
+//!
+//! # Module macros
+//!
+//! * [`assert_eq_normalized`](macro@crate::assert_eq_normalized)
+//! * [`assert_eq_normalized_as_result`](macro@crate::assert_eq_normalized_as_result)
+//! * [`debug_assert_eq_normalized`](macro@crate::debug_assert_eq_normalized)
+
+/// Assert an expression is equal to another, after normalizing each one.
+///
+/// Pseudocode:<br>
+/// normalize(a) = normalize(b)
+///
+/// * If true, return Result `Ok((normalize(a), normalize(b)))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_eq_normalized`](macro@crate::assert_eq_normalized)
+/// * [`assert_eq_normalized_as_result`](macro@crate::assert_eq_normalized_as_result)
+/// * [`debug_assert_eq_normalized`](macro@crate::debug_assert_eq_normalized)
+///
+#[macro_export]
+macro_rules! assert_eq_normalized_as_result {
+    ($a:expr, $b:expr, $normalize:expr $(,)?) => {{
+        match (&$a, &$b, &$normalize) {
+            (a, b, normalize) => {
+                let a_normalized = normalize(a);
+                let b_normalized = normalize(b);
+                if a_normalized == b_normalized {
+                    Ok((a_normalized, b_normalized))
+                } else {
+                    Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_eq_normalized!(a, b, normalize)`\n",
+                                $crate::assertables_url_line!("assert_eq_normalized"),
+                                "         a label: `{}`,\n",
+                                "         a debug: `{:?}`,\n",
+                                "         b label: `{}`,\n",
+                                "         b debug: `{:?}`,\n",
+                                " normalize label: `{}`,\n",
+                                "    a normalized: `{:?}`,\n",
+                                "    b normalized: `{:?}`"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($normalize),
+                            a_normalized,
+                            b_normalized
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_eq_normalized_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    #[test]
+    fn success() {
+        let a = "Alfa";
+        let b = "ALFA";
+        let actual = assert_eq_normalized_as_result!(a, b, str::to_lowercase);
+        assert_eq!(
+            actual.unwrap(),
+            (String::from("alfa"), String::from("alfa"))
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = "Alfa";
+        let b = "Bravo";
+        let actual = assert_eq_normalized_as_result!(a, b, str::to_lowercase);
+        let message = concat!(
+            "assertion failed: `assert_eq_normalized!(a, b, normalize)`\n",
+            assertables_url_line!("assert_eq_normalized"),
+            "         a label: `a`,\n",
+            "         a debug: `\"Alfa\"`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `\"Bravo\"`,\n",
+            " normalize label: `str::to_lowercase`,\n",
+            "    a normalized: `\"alfa\"`,\n",
+            "    b normalized: `\"bravo\"`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_function_returning_a_reference() {
+        let a = " alfa";
+        let b = "alfa ";
+        let actual = assert_eq_normalized_as_result!(a, b, str::trim);
+        assert_eq!(actual.unwrap(), ("alfa", "alfa"));
+    }
+
+    #[test]
+    fn success_with_sorting() {
+        let a = vec![3, 1, 2];
+        let b = vec![1, 2, 3];
+        let actual = assert_eq_normalized_as_result!(a, b, |x: &Vec<i32>| {
+            let mut x = x.clone();
+            x.sort();
+            x
+        });
+        assert_eq!(actual.unwrap(), (vec![1, 2, 3], vec![1, 2, 3]));
+        assert_eq!(a, vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        static B: AtomicU32 = AtomicU32::new(0);
+        static N: AtomicU32 = AtomicU32::new(0);
+        fn a() -> &'static str {
+            A.fetch_add(1, Ordering::SeqCst);
+            "alfa"
+        }
+        fn b() -> &'static str {
+            B.fetch_add(1, Ordering::SeqCst);
+            "bravo"
+        }
+        let _ = assert_eq_normalized_as_result!(a(), b(), |x: &str| {
+            N.fetch_add(1, Ordering::SeqCst);
+            x.to_uppercase()
+        });
+        assert_eq!(A.load(Ordering::SeqCst), 1);
+        assert_eq!(B.load(Ordering::SeqCst), 1);
+        assert_eq!(N.load(Ordering::SeqCst), 2);
+    }
+}
+
+/// Assert an expression is equal to another, after normalizing each one.
+///
+/// Pseudocode:<br>
+/// normalize(a) = normalize(b)
+///
+/// * If true, return `(normalize(a), normalize(b))`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "Alfa";
+/// let b = "ALFA";
+/// assert_eq_normalized!(a, b, str::to_lowercase);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "Alfa";
+/// let b = "Bravo";
+/// assert_eq_normalized!(a, b, str::to_lowercase);
+/// # });
+/// // assertion failed: `assert_eq_normalized!(a, b, normalize)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_eq_normalized.html
+/// //          a label: `a`,
+/// //          a debug: `\"Alfa\"`,
+/// //          b label: `b`,
+/// //          b debug: `\"Bravo\"`,
+/// //  normalize label: `str::to_lowercase`,
+/// //     a normalized: `\"alfa\"`,
+/// //     b normalized: `\"bravo\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_eq_normalized!(a, b, normalize)`\n",
+/// #     assertables_url_line!("assert_eq_normalized"),
+/// #     "         a label: `a`,\n",
+/// #     "         a debug: `\"Alfa\"`,\n",
+/// #     "         b label: `b`,\n",
+/// #     "         b debug: `\"Bravo\"`,\n",
+/// #     " normalize label: `str::to_lowercase`,\n",
+/// #     "    a normalized: `\"alfa\"`,\n",
+/// #     "    b normalized: `\"bravo\"`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for comparing values that are equivalent in your domain,
+/// yet differ in a way that does not matter, such as letter case, surrounding
+/// whitespace, item order, or key order.
+///
+/// * The normalize function is called with a reference to `a`, then with a reference to `b`,
+///   so the same function must accept both.
+///
+/// * The normalized values are compared by using `==`.
+///
+/// * The failure message shows each original value and each normalized value.
+///
+/// # Module macros
+///
+/// * [`assert_eq_normalized`](macro@crate::assert_eq_normalized)
+/// * [`assert_eq_normalized_as_result`](macro@crate::assert_eq_normalized_as_result)
+/// * [`debug_assert_eq_normalized`](macro@crate::debug_assert_eq_normalized)
+///
+#[macro_export]
+macro_rules! assert_eq_normalized {
+    ($a:expr, $b:expr, $normalize:expr $(,)?) => {{
+        match $crate::assert_eq_normalized_as_result!($a, $b, $normalize) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $normalize:expr, $($message:tt)+) => {{
+        match $crate::assert_eq_normalized_as_result!($a, $b, $normalize) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_eq_normalized {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = "Alfa";
+        let b = "ALFA";
+        let actual = assert_eq_normalized!(a, b, str::to_lowercase);
+        assert_eq!(actual, (String::from("alfa"), String::from("alfa")));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = "Alfa";
+            let b = "Bravo";
+            let _actual = assert_eq_normalized!(a, b, str::to_lowercase);
+        });
+        let message = concat!(
+            "assertion failed: `assert_eq_normalized!(a, b, normalize)`\n",
+            assertables_url_line!("assert_eq_normalized"),
+            "         a label: `a`,\n",
+            "         a debug: `\"Alfa\"`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `\"Bravo\"`,\n",
+            " normalize label: `str::to_lowercase`,\n",
+            "    a normalized: `\"alfa\"`,\n",
+            "    b normalized: `\"bravo\"`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an expression is equal to another, after normalizing each one.
+///
+/// Pseudocode:<br>
+/// normalize(a) = normalize(b)
+///
+/// This macro provides the same statements as [`assert_eq_normalized`](macro.assert_eq_normalized.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_eq_normalized`](macro@crate::assert_eq_normalized)
+/// * [`assert_eq_normalized_as_result`](macro@crate::assert_eq_normalized_as_result)
+/// * [`debug_assert_eq_normalized`](macro@crate::debug_assert_eq_normalized)
+///
+#[macro_export]
+macro_rules! debug_assert_eq_normalized {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_eq_normalized!($($arg)*);
+        }
+    };
+}
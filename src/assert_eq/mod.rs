@@ -0,0 +1,308 @@
+//! Assert for comparing equality.
+//!
+//! These macros help compare two expressions for equality.
+//!
+//! * [`assert_eq_as_result!(a, b)`](macro@crate::assert_eq_as_result) ≈ a = b
+//!
+//! * [`assert_eq_normalized!(a, b, normalize)`](macro@crate::assert_eq_normalized) ≈ normalize(a) = normalize(b)
+//!
+//! * [`assert_eq_diff!(a, b)`](macro@crate::assert_eq_diff) ≈ a = b, with a line diff of strings
+//!
+//! * [`assert_eq_by!(a, b, compare)`](macro@crate::assert_eq_by) ≈ compare(a, b) = true
+//!
+//! * [`assert_ne_by!(a, b, compare)`](macro@crate::assert_ne_by) ≈ compare(a, b) = false
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "Alfa";
+//! let b = "ALFA";
+//! assert_eq_normalized!(a, b, str::to_lowercase);
+//! ```
+//!
+//! # Rust standard macros
+//!
+//! * [`assert_eq`](https://doc.rust-lang.org/std/macro.assert_eq.html)
+//! * [`debug_assert_eq`](https://doc.rust-lang.org/std/macro.debug_assert_eq.html)
+
+#[allow(clippy::module_inception)]
+pub mod assert_eq;
+pub mod assert_eq_by;
+pub mod assert_eq_diff;
+pub mod assert_eq_normalized;
+pub mod assert_ne_by;
+
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+/// Return a unified line diff of two strings.
+///
+/// Each line shows a sign, then the line number in `a` and in `b`, then the
+/// text. The sign is `-` for a line only in `a`, `+` for a line only in `b`,
+/// and a space for a line in both. Runs of unchanged lines that are more
+/// than two lines away from a change are elided as `...`.
+///
+/// The diff uses a table that has a cell for each pair of differing lines.
+/// If the table would have more than `MAX_TABLE_CELLS` cells, then the diff
+/// shows only the first differing line, so a failure message for two large
+/// strings does not use a large amount of memory.
+#[doc(hidden)]
+pub fn lines_diff(a: &str, b: &str) -> String {
+    const CONTEXT: usize = 2;
+    const MAX_TABLE_CELLS: usize = 1 << 20;
+    let a_lines: Vec<&str> = a.split('\n').collect();
+    let b_lines: Vec<&str> = b.split('\n').collect();
+
+    // Skip the common prefix and suffix, to keep the table below small.
+    let prefix = a_lines
+        .iter()
+        .zip(b_lines.iter())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let suffix = a_lines[prefix..]
+        .iter()
+        .rev()
+        .zip(b_lines[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let a_mid = &a_lines[prefix..a_lines.len() - suffix];
+    let b_mid = &b_lines[prefix..b_lines.len() - suffix];
+
+    // Each edit is (sign, a index, b index).
+    let mut edits: Vec<(char, Option<usize>, Option<usize>)> =
+        (0..prefix).map(|i| (' ', Some(i), Some(i))).collect();
+    let too_large = (a_mid.len() + 1).saturating_mul(b_mid.len() + 1) > MAX_TABLE_CELLS;
+    if too_large {
+        // The table would be too large, so show only the first differing line.
+        if !a_mid.is_empty() {
+            edits.push(('-', Some(prefix), None));
+        }
+        if !b_mid.is_empty() {
+            edits.push(('+', None, Some(prefix)));
+        }
+    } else {
+        // lcs[i][j] is the length of the longest common subsequence of a_mid[i..] and b_mid[j..].
+        let mut lcs = vec![vec![0usize; b_mid.len() + 1]; a_mid.len() + 1];
+        for i in (0..a_mid.len()).rev() {
+            for j in (0..b_mid.len()).rev() {
+                lcs[i][j] = if a_mid[i] == b_mid[j] {
+                    lcs[i + 1][j + 1] + 1
+                } else {
+                    lcs[i + 1][j].max(lcs[i][j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < a_mid.len() || j < b_mid.len() {
+            if i < a_mid.len() && j < b_mid.len() && a_mid[i] == b_mid[j] {
+                edits.push((' ', Some(prefix + i), Some(prefix + j)));
+                i += 1;
+                j += 1;
+            } else if i < a_mid.len() && (j == b_mid.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+                edits.push(('-', Some(prefix + i), None));
+                i += 1;
+            } else {
+                edits.push(('+', None, Some(prefix + j)));
+                j += 1;
+            }
+        }
+        let a_suffix_start = a_lines.len() - suffix;
+        let b_suffix_start = b_lines.len() - suffix;
+        edits
+            .extend((0..suffix).map(|k| (' ', Some(a_suffix_start + k), Some(b_suffix_start + k))));
+    }
+
+    // Keep each change, and each unchanged line near a change.
+    let mut keep = vec![false; edits.len()];
+    for (index, edit) in edits.iter().enumerate() {
+        if edit.0 != ' ' {
+            let start = index.saturating_sub(CONTEXT);
+            let end = (index + CONTEXT + 1).min(edits.len());
+            keep[start..end].iter_mut().for_each(|k| *k = true);
+        }
+    }
+
+    let width = a_lines.len().max(b_lines.len()).to_string().len();
+    let number = |n: Option<usize>| n.map(|n| (n + 1).to_string()).unwrap_or_default();
+    let mut lines: Vec<String> = Vec::new();
+    let mut elided = false;
+    for (edit, keep) in edits.iter().zip(keep.iter()) {
+        if !keep {
+            if !elided {
+                lines.push(String::from("..."));
+                elided = true;
+            }
+            continue;
+        }
+        elided = false;
+        let (sign, a_index, b_index) = *edit;
+        let text = match (a_index, b_index) {
+            (Some(a_index), _) => a_lines[a_index],
+            (None, Some(b_index)) => b_lines[b_index],
+            (None, None) => "",
+        };
+        let line = format!(
+            "{}{:>width$} {:>width$} |",
+            sign,
+            number(a_index),
+            number(b_index),
+            width = width
+        );
+        lines.push(if text.is_empty() {
+            line
+        } else {
+            format!("{} {}", line, text)
+        });
+    }
+    if too_large {
+        lines.push(String::from(
+            "... (the diff is too large, so this shows only the first differing line)",
+        ));
+    }
+    lines.join("\n")
+}
+
+/// A type whose value can be viewed as a string, for a line diff.
+#[doc(hidden)]
+pub trait KnownStr {
+    fn known_str(&self) -> &str;
+}
+
+impl KnownStr for str {
+    fn known_str(&self) -> &str {
+        self
+    }
+}
+
+impl KnownStr for String {
+    fn known_str(&self) -> &str {
+        self
+    }
+}
+
+impl KnownStr for Cow<'_, str> {
+    fn known_str(&self) -> &str {
+        self
+    }
+}
+
+impl<T: KnownStr + ?Sized> KnownStr for &T {
+    fn known_str(&self) -> &str {
+        (**self).known_str()
+    }
+}
+
+/// Probe two items for a line diff, if both types are strings.
+///
+/// The macros call `(&DiffProbe(a, b)).probe_diff()`. Method lookup tries
+/// [`ProbeDiff`] first, which applies only if both types implement
+/// [`KnownStr`], then falls back to [`ProbeNoDiff`], which returns `None`.
+#[doc(hidden)]
+pub struct DiffProbe<'a, A: ?Sized, B: ?Sized>(pub &'a A, pub &'a B);
+
+#[doc(hidden)]
+pub trait ProbeDiff {
+    fn probe_diff(&self) -> Option<String>;
+}
+
+impl<A: KnownStr + ?Sized, B: KnownStr + ?Sized> ProbeDiff for DiffProbe<'_, A, B> {
+    fn probe_diff(&self) -> Option<String> {
+        Some(lines_diff(self.0.known_str(), self.1.known_str()))
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeNoDiff {
+    fn probe_diff(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<A: ?Sized, B: ?Sized> ProbeNoDiff for &DiffProbe<'_, A, B> {}
+
+/// Call a comparator with two items, and return its result.
+///
+/// The macros [`assert_eq_by`](macro@crate::assert_eq_by) and
+/// [`assert_ne_by`](macro@crate::assert_ne_by) call the comparator via this
+/// function, so a closure such as `|x, y| x.eq_ignore_ascii_case(y)` gets
+/// its parameter types inferred as `&A` and `&B`.
+#[doc(hidden)]
+pub fn compare_by<A, B, F>(a: &A, b: &B, compare: F) -> bool
+where
+    A: ?Sized,
+    B: ?Sized,
+    F: FnOnce(&A, &B) -> bool,
+{
+    compare(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lines_diff;
+
+    #[test]
+    fn lines_diff_x_change() {
+        let actual = lines_diff("alfa\nbravo\ncharlie", "alfa\nbravo\ndelta");
+        let expect = concat!(
+            " 1 1 | alfa\n",
+            " 2 2 | bravo\n",
+            "-3   | charlie\n",
+            "+  3 | delta"
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn lines_diff_x_insert_and_remove() {
+        let actual = lines_diff("alfa\nbravo\ncharlie", "alfa\ncharlie\ndelta");
+        let expect = concat!(
+            " 1 1 | alfa\n",
+            "-2   | bravo\n",
+            " 3 2 | charlie\n",
+            "+  3 | delta"
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn lines_diff_x_trailing_newline() {
+        let actual = lines_diff("alfa\n", "alfa");
+        let expect = concat!(" 1 1 | alfa\n", "-2   |");
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn lines_diff_x_elide_far_context() {
+        let a = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10";
+        let b = "1\n2\n3\n4\n5\nsix\n7\n8\n9\n10";
+        let actual = lines_diff(a, b);
+        let expect = concat!(
+            "...\n",
+            "  4  4 | 4\n",
+            "  5  5 | 5\n",
+            "- 6    | 6\n",
+            "+    6 | six\n",
+            "  7  7 | 7\n",
+            "  8  8 | 8\n",
+            "..."
+        );
+        assert_eq!(actual, expect);
+    }
+
+    #[test]
+    fn lines_diff_x_too_large() {
+        let a: String = (0..2000).map(|i| format!("a{}\n", i)).collect();
+        let b: String = (0..2000).map(|i| format!("b{}\n", i)).collect();
+        let actual = lines_diff(&a, &b);
+        let expect = concat!(
+            "-   1      | a0\n",
+            "+        1 | b0\n",
+            "... (the diff is too large, so this shows only the first differing line)"
+        );
+        assert_eq!(actual, expect);
+    }
+}
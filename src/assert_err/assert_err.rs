@@ -39,17 +39,17 @@
 #[macro_export]
 macro_rules! assert_err_as_result {
     ($a:expr $(,)?) => {
-        match ($a) {
+        match $a {
             Err(a1) => Ok(a1),
-            _ => Err(format!(
+            a => Err(format!(
                 concat!(
                     "assertion failed: `assert_err!(a)`\n",
-                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err.html\n",
+                    $crate::assertables_url_line!("assert_err"),
                     " a label: `{}`,\n",
-                    " a debug: `{:?}`",
+                    " a debug: `{:?}`"
                 ),
                 stringify!($a),
-                $a
+                a
             )),
         }
     };
@@ -57,6 +57,7 @@ macro_rules! assert_err_as_result {
 
 #[cfg(test)]
 mod test_assert_err_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn success() {
@@ -71,12 +72,29 @@ mod test_assert_err_as_result {
         let actual = assert_err_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_err!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err.html\n",
+            assertables_url_line!("assert_err"),
             " a label: `a`,\n",
             " a debug: `Ok(1)`",
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        fn err() -> Result<i8, i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            Err(1)
+        }
+        fn ok() -> Result<i8, i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            Ok(1)
+        }
+        let _ = assert_err_as_result!(err());
+        assert_eq!(A.load(Ordering::SeqCst), 1);
+        let _ = assert_err_as_result!(ok());
+        assert_eq!(A.load(Ordering::SeqCst), 2);
+    }
 }
 
 /// Assert expression is Err.
@@ -111,7 +129,7 @@ mod test_assert_err_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_err!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err.html\n",
+/// #     assertables_url_line!("assert_err"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Ok(1)`",
 /// # );
@@ -130,13 +148,13 @@ macro_rules! assert_err {
     ($a:expr $(,)?) => {{
         match $crate::assert_err_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_err_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -160,7 +178,7 @@ mod test_assert_err {
         });
         let message = concat!(
             "assertion failed: `assert_err!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err.html\n",
+            assertables_url_line!("assert_err"),
             " a label: `a`,\n",
             " a debug: `Ok(1)`",
         );
@@ -209,7 +227,7 @@ mod test_assert_err {
 #[macro_export]
 macro_rules! debug_assert_err {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_err!($($arg)*);
         }
     };
@@ -49,48 +49,47 @@ macro_rules! assert_err_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_err_eq!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_eq.html\n",
+                                $crate::assertables_url_line!("assert_err_eq"),
                                 " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
+                                " a debug: `Err({:?})`,\n",
                                 " a inner: `{:?}`,\n",
                                 " b label: `{}`,\n",
-                                " b debug: `{:?}`,\n",
+                                " b debug: `Err({:?})`,\n",
                                 " b inner: `{:?}`"
                             ),
                             stringify!($a),
-                            $a,
+                            a1,
                             a1,
                             stringify!($b),
-                            $b,
+                            b1,
                             b1
                         )
                     )
                 }
-            },
-            _ => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_err_eq!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_eq.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`",
-                        ),
-                        stringify!($a),
-                        $a,
-                        stringify!($b),
-                        $b,
-                    )
-                )
             }
+            (a, b) => Err(
+                format!(
+                    concat!(
+                        "assertion failed: `assert_err_eq!(a, b)`\n",
+                        $crate::assertables_url_line!("assert_err_eq"),
+                        " a label: `{}`,\n",
+                        " a debug: `{:?}`,\n",
+                        " b label: `{}`,\n",
+                        " b debug: `{:?}`"
+                    ),
+                    stringify!($a),
+                    a,
+                    stringify!($b),
+                    b
+                )
+            ),
         }
     };
 }
 
 #[cfg(test)]
 mod test_assert_err_eq_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn success() {
@@ -107,7 +106,7 @@ mod test_assert_err_eq_as_result {
         let actual = assert_err_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_err_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_eq.html\n",
+            assertables_url_line!("assert_err_eq"),
             " a label: `a`,\n",
             " a debug: `Err(1)`,\n",
             " a inner: `1`,\n",
@@ -125,7 +124,7 @@ mod test_assert_err_eq_as_result {
         let actual = assert_err_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_err_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_eq.html\n",
+            assertables_url_line!("assert_err_eq"),
             " a label: `a`,\n",
             " a debug: `Ok(1)`,\n",
             " b label: `b`,\n",
@@ -133,6 +132,28 @@ mod test_assert_err_eq_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        static B: AtomicU32 = AtomicU32::new(0);
+        fn a() -> Result<i8, i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            Err(1)
+        }
+        fn b(x: Result<i8, i8>) -> Result<i8, i8> {
+            B.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        // Success
+        let _ = assert_err_eq_as_result!(a(), b(Err(1)));
+        // Failure because inner values
+        let _ = assert_err_eq_as_result!(a(), b(Err(2)));
+        // Failure because not err
+        let _ = assert_err_eq_as_result!(a(), b(Ok(1)));
+        assert_eq!(A.load(Ordering::SeqCst), 3);
+        assert_eq!(B.load(Ordering::SeqCst), 3);
+    }
 }
 
 /// Assert two expressions are Err and their values are equal.
@@ -173,7 +194,7 @@ mod test_assert_err_eq_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_err_eq!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_eq.html\n",
+/// #     assertables_url_line!("assert_err_eq"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Err(1)`,\n",
 /// #     " a inner: `1`,\n",
@@ -196,13 +217,13 @@ macro_rules! assert_err_eq {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_err_eq_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_err_eq_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -228,7 +249,7 @@ mod test_assert_err_eq {
         });
         let message = concat!(
             "assertion failed: `assert_err_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_eq.html\n",
+            assertables_url_line!("assert_err_eq"),
             " a label: `a`,\n",
             " a debug: `Err(1)`,\n",
             " a inner: `1`,\n",
@@ -255,7 +276,7 @@ mod test_assert_err_eq {
         });
         let message = concat!(
             "assertion failed: `assert_err_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_eq.html\n",
+            assertables_url_line!("assert_err_eq"),
             " a label: `a`,\n",
             " a debug: `Ok(1)`,\n",
             " b label: `b`,\n",
@@ -306,7 +327,7 @@ mod test_assert_err_eq {
 #[macro_export]
 macro_rules! debug_assert_err_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_err_eq!($($arg)*);
         }
     };
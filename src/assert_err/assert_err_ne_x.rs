@@ -40,55 +40,54 @@
 #[macro_export]
 macro_rules! assert_err_ne_x_as_result {
     ($a:expr, $b:expr $(,)?) => {
-        match ($a) {
-            Err(a1) => {
-                if a1 != $b {
+        match ($a, &$b) {
+            (Err(a1), b) => {
+                if a1 != *b {
                     Ok(a1)
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_err_ne_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_ne_x.html\n",
+                                $crate::assertables_url_line!("assert_err_ne_x"),
                                 " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
+                                " a debug: `Err({:?})`,\n",
                                 " a inner: `{:?}`,\n",
                                 " b label: `{}`,\n",
-                                " b debug: `{:?}`",
+                                " b debug: `{:?}`"
                             ),
                             stringify!($a),
-                            $a,
+                            a1,
                             a1,
                             stringify!($b),
-                            $b
+                            b
                         )
                     )
                 }
-            },
-            _ => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_err_ne_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_ne_x.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`",
-                        ),
-                        stringify!($a),
-                        $a,
-                        stringify!($b),
-                        $b,
-                    )
-                )
             }
+            (a, b) => Err(
+                format!(
+                    concat!(
+                        "assertion failed: `assert_err_ne_x!(a, b)`\n",
+                        $crate::assertables_url_line!("assert_err_ne_x"),
+                        " a label: `{}`,\n",
+                        " a debug: `{:?}`,\n",
+                        " b label: `{}`,\n",
+                        " b debug: `{:?}`"
+                    ),
+                    stringify!($a),
+                    a,
+                    stringify!($b),
+                    b
+                )
+            ),
         }
     };
 }
 
 #[cfg(test)]
 mod test_assert_err_ne_x_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn success() {
@@ -105,7 +104,7 @@ mod test_assert_err_ne_x_as_result {
         let actual = assert_err_ne_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_err_ne_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_ne_x.html\n",
+            assertables_url_line!("assert_err_ne_x"),
             " a label: `a`,\n",
             " a debug: `Err(1)`,\n",
             " a inner: `1`,\n",
@@ -122,7 +121,7 @@ mod test_assert_err_ne_x_as_result {
         let actual = assert_err_ne_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_err_ne_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_ne_x.html\n",
+            assertables_url_line!("assert_err_ne_x"),
             " a label: `a`,\n",
             " a debug: `Ok(1)`,\n",
             " b label: `b`,\n",
@@ -130,6 +129,28 @@ mod test_assert_err_ne_x_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        static B: AtomicU32 = AtomicU32::new(0);
+        fn a(x: Result<i8, i8>) -> Result<i8, i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        fn b(x: i8) -> i8 {
+            B.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        // Success
+        let _ = assert_err_ne_x_as_result!(a(Err(1)), b(2));
+        // Failure because inner value
+        let _ = assert_err_ne_x_as_result!(a(Err(1)), b(1));
+        // Failure because not err
+        let _ = assert_err_ne_x_as_result!(a(Ok(1)), b(2));
+        assert_eq!(A.load(Ordering::SeqCst), 3);
+        assert_eq!(B.load(Ordering::SeqCst), 3);
+    }
 }
 
 /// Assert an expression is Err and its value is not equal to an expression.
@@ -169,7 +190,7 @@ mod test_assert_err_ne_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_err_ne_x!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_ne_x.html\n",
+/// #     assertables_url_line!("assert_err_ne_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Err(1)`,\n",
 /// #     " a inner: `1`,\n",
@@ -191,13 +212,13 @@ macro_rules! assert_err_ne_x {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_err_ne_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_err_ne_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -223,7 +244,7 @@ mod test_assert_err_ne_x {
         });
         let message = concat!(
             "assertion failed: `assert_err_ne_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_ne_x.html\n",
+            assertables_url_line!("assert_err_ne_x"),
             " a label: `a`,\n",
             " a debug: `Err(1)`,\n",
             " a inner: `1`,\n",
@@ -249,7 +270,7 @@ mod test_assert_err_ne_x {
         });
         let message = concat!(
             "assertion failed: `assert_err_ne_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_err_ne_x.html\n",
+            assertables_url_line!("assert_err_ne_x"),
             " a label: `a`,\n",
             " a debug: `Ok(1)`,\n",
             " b label: `b`,\n",
@@ -300,7 +321,7 @@ mod test_assert_err_ne_x {
 #[macro_export]
 macro_rules! debug_assert_err_ne_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_err_ne_x!($($arg)*);
         }
     };
@@ -16,6 +16,10 @@
 //! * [`assert_err_eq_x!(a, expr)`](macro@crate::assert_err_eq_x) ≈ (a ⇒ Err(a1) ⇒ a1) = expr
 //! * [`assert_err_ne_x!(a, expr)`](macro@crate::assert_err_ne_x) ≈ (a ⇒ Err(a1) ⇒ a1) ≠ expr
 //!
+//! Check Err(…) with a predicate:
+//!
+//! * [`assert_err_and!(a, predicate)`](macro@crate::assert_err_and) ≈ (a ⇒ Err(a1) ⇒ a1) ⇒ predicate(a1)
+//!
 //! # Example
 //!
 //! ```rust
@@ -35,3 +39,6 @@ pub mod assert_err_ne;
 // Compare expression
 pub mod assert_err_eq_x;
 pub mod assert_err_ne_x;
+
+// Check predicate
+pub mod assert_err_and;
@@ -0,0 +1,365 @@
+//! Assert an error source chain contains an error of a given type.
+//!
+//! Pseudocode:<br>
+//! error ⇒ source ⇒ source ⇒ … contains type
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::num::ParseIntError;
+//!
+//! #[derive(Debug)]
+//! struct ConfigError(ParseIntError);
+//!
+//! impl std::fmt::Display for ConfigError {
+//!     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//!         write!(f, "config error")
+//!     }
+//! }
+//!
+//! impl std::error::Error for ConfigError {
+//!     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+//!         Some(&self.0)
+//!     }
+//! }
+//!
+//! let a = ConfigError("x".parse::<i32>().unwrap_err());
+//! assert_error_source_contains!(a, ParseIntError);
+//! ```
+//!
+//! This macro is useful for testing layered error wrapping, such as an
+//! application error that wraps a library error that wraps an I/O error.
+//!
+//! * The chain starts with the error itself, then calls `source()`
+//!   repeatedly, and checks each error by calling `downcast_ref::<type>()`.
+//!
+//! * The error must implement `std::error::Error + 'static`. For a
+//!   `Box<dyn Error>`, pass the inner error, such as `*boxed`.
+//!
+//! * On success, the return value is a reference to the first error in
+//!   the chain that has the type, so you can do further testing.
+//!
+//! * The failure message shows the debug representation of every error
+//!   in the chain.
+//!
+//! This implementation uses [`::std::error::Error`](https://doc.rust-lang.org/std/error/trait.Error.html).
+//!
+//! # Module macros
+//!
+//! * [`assert_error_source_contains`](macro@crate::assert_error_source_contains)
+//! * [`assert_error_source_contains_as_result`](macro@crate::assert_error_source_contains_as_result)
+//! * [`debug_assert_error_source_contains`](macro@crate::debug_assert_error_source_contains)
+
+/// Assert an error source chain contains an error of a given type.
+///
+/// Pseudocode:<br>
+/// error ⇒ source ⇒ source ⇒ … contains type
+///
+/// * If true, return Result `Ok(&type)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_error_source_contains`](macro@crate::assert_error_source_contains)
+/// * [`assert_error_source_contains_as_result`](macro@crate::assert_error_source_contains_as_result)
+/// * [`debug_assert_error_source_contains`](macro@crate::debug_assert_error_source_contains)
+///
+#[macro_export]
+macro_rules! assert_error_source_contains_as_result {
+    ($error:expr, $type:ty $(,)?) => {{
+        match &$error {
+            error => {
+                let mut chain: Vec<&(dyn ::std::error::Error + 'static)> = Vec::new();
+                let mut current: Option<&(dyn ::std::error::Error + 'static)> = Some(error);
+                let mut found: Option<&$type> = None;
+                while let Some(e) = current {
+                    if let Some(x) = e.downcast_ref::<$type>() {
+                        found = Some(x);
+                        break;
+                    }
+                    chain.push(e);
+                    current = e.source();
+                }
+                match found {
+                    Some(x) => Ok(x),
+                    None => Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_error_source_contains!(error, type)`\n",
+                                $crate::assertables_url_line!("assert_error_source_contains"),
+                                " error label: `{}`,\n",
+                                " error debug: `{:?}`,\n",
+                                "  type label: `{}`,\n",
+                                " chain debug: `{:?}`"
+                            ),
+                            stringify!($error),
+                            error,
+                            stringify!($type),
+                            chain
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_error_source_contains_as_result {
+    use std::num::{ParseFloatError, ParseIntError};
+
+    #[derive(Debug)]
+    struct ConfigError(ParseIntError);
+
+    impl std::fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "config error")
+        }
+    }
+
+    impl std::error::Error for ConfigError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn success() {
+        let a = ConfigError("x".parse::<i32>().unwrap_err());
+        let actual = assert_error_source_contains_as_result!(a, ParseIntError);
+        assert_eq!(actual.unwrap(), &"x".parse::<i32>().unwrap_err());
+    }
+
+    #[test]
+    fn failure() {
+        let a = ConfigError("x".parse::<i32>().unwrap_err());
+        let actual = assert_error_source_contains_as_result!(a, ParseFloatError);
+        let message = concat!(
+            "assertion failed: `assert_error_source_contains!(error, type)`\n",
+            assertables_url_line!("assert_error_source_contains"),
+            " error label: `a`,\n",
+            " error debug: `ConfigError(ParseIntError { kind: InvalidDigit })`,\n",
+            "  type label: `ParseFloatError`,\n",
+            " chain debug: `[ConfigError(ParseIntError { kind: InvalidDigit }), ParseIntError { kind: InvalidDigit }]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_because_error_itself_has_type() {
+        let a = ConfigError("x".parse::<i32>().unwrap_err());
+        let actual = assert_error_source_contains_as_result!(a, ConfigError);
+        assert_eq!(actual.unwrap().to_string(), "config error");
+    }
+
+    #[test]
+    fn failure_because_chain_has_one_error() {
+        let a = "x".parse::<i32>().unwrap_err();
+        let actual = assert_error_source_contains_as_result!(a, ConfigError);
+        let message = concat!(
+            "assertion failed: `assert_error_source_contains!(error, type)`\n",
+            assertables_url_line!("assert_error_source_contains"),
+            " error label: `a`,\n",
+            " error debug: `ParseIntError { kind: InvalidDigit }`,\n",
+            "  type label: `ConfigError`,\n",
+            " chain debug: `[ParseIntError { kind: InvalidDigit }]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert an error source chain contains an error of a given type.
+///
+/// Pseudocode:<br>
+/// error ⇒ source ⇒ source ⇒ … contains type
+///
+/// * If true, return `&type`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::num::{ParseFloatError, ParseIntError};
+///
+/// #[derive(Debug)]
+/// struct ConfigError(ParseIntError);
+///
+/// impl std::fmt::Display for ConfigError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+///         write!(f, "config error")
+///     }
+/// }
+///
+/// impl std::error::Error for ConfigError {
+///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// # fn main() {
+/// let a = ConfigError("x".parse::<i32>().unwrap_err());
+/// assert_error_source_contains!(a, ParseIntError);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = ConfigError("x".parse::<i32>().unwrap_err());
+/// assert_error_source_contains!(a, ParseFloatError);
+/// # });
+/// // assertion failed: `assert_error_source_contains!(error, type)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_error_source_contains.html
+/// //  error label: `a`,
+/// //  error debug: `ConfigError(ParseIntError { kind: InvalidDigit })`,
+/// //   type label: `ParseFloatError`,
+/// //  chain debug: `[ConfigError(ParseIntError { kind: InvalidDigit }), ParseIntError { kind: InvalidDigit }]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_error_source_contains!(error, type)`\n",
+/// #     assertables_url_line!("assert_error_source_contains"),
+/// #     " error label: `a`,\n",
+/// #     " error debug: `ConfigError(ParseIntError { kind: InvalidDigit })`,\n",
+/// #     "  type label: `ParseFloatError`,\n",
+/// #     " chain debug: `[ConfigError(ParseIntError { kind: InvalidDigit }), ParseIntError { kind: InvalidDigit }]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for testing layered error wrapping, such as an
+/// application error that wraps a library error that wraps an I/O error.
+///
+/// * The chain starts with the error itself, then calls `source()`
+///   repeatedly, and checks each error by calling `downcast_ref::<type>()`.
+///
+/// * The error must implement `std::error::Error + 'static`. For a
+///   `Box<dyn Error>`, pass the inner error, such as `*boxed`.
+///
+/// * On success, the return value is a reference to the first error in
+///   the chain that has the type, so you can do further testing.
+///
+/// * The failure message shows the debug representation of every error
+///   in the chain.
+///
+/// This implementation uses [`::std::error::Error`](https://doc.rust-lang.org/std/error/trait.Error.html).
+///
+/// # Module macros
+///
+/// * [`assert_error_source_contains`](macro@crate::assert_error_source_contains)
+/// * [`assert_error_source_contains_as_result`](macro@crate::assert_error_source_contains_as_result)
+/// * [`debug_assert_error_source_contains`](macro@crate::debug_assert_error_source_contains)
+///
+#[macro_export]
+macro_rules! assert_error_source_contains {
+    ($error:expr, $type:ty $(,)?) => {{
+        match $crate::assert_error_source_contains_as_result!($error, $type) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($error:expr, $type:ty, $($message:tt)+) => {{
+        match $crate::assert_error_source_contains_as_result!($error, $type) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_error_source_contains {
+    use std::num::{ParseFloatError, ParseIntError};
+    use std::panic;
+
+    #[derive(Debug)]
+    struct ConfigError(ParseIntError);
+
+    impl std::fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "config error")
+        }
+    }
+
+    impl std::error::Error for ConfigError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn success() {
+        let a = ConfigError("x".parse::<i32>().unwrap_err());
+        let actual = assert_error_source_contains!(a, ParseIntError);
+        assert_eq!(actual, &"x".parse::<i32>().unwrap_err());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = ConfigError("x".parse::<i32>().unwrap_err());
+            let _actual = assert_error_source_contains!(a, ParseFloatError);
+        });
+        let message = concat!(
+            "assertion failed: `assert_error_source_contains!(error, type)`\n",
+            assertables_url_line!("assert_error_source_contains"),
+            " error label: `a`,\n",
+            " error debug: `ConfigError(ParseIntError { kind: InvalidDigit })`,\n",
+            "  type label: `ParseFloatError`,\n",
+            " chain debug: `[ConfigError(ParseIntError { kind: InvalidDigit }), ParseIntError { kind: InvalidDigit }]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an error source chain contains an error of a given type.
+///
+/// Pseudocode:<br>
+/// error ⇒ source ⇒ source ⇒ … contains type
+///
+/// This macro provides the same statements as [`assert_error_source_contains`](macro.assert_error_source_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_error_source_contains`](macro@crate::assert_error_source_contains)
+/// * [`assert_error_source_contains_as_result`](macro@crate::assert_error_source_contains_as_result)
+/// * [`debug_assert_error_source_contains`](macro@crate::debug_assert_error_source_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_error_source_contains {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_error_source_contains!($($arg)*);
+        }
+    };
+}
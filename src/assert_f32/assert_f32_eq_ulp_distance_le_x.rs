@@ -0,0 +1,366 @@
+//! Assert a f32 is equal to another, within a number of units in the last place (ULPs).
+//!
+//! Pseudocode:<br>
+//! ulp_distance(a, b) ≤ x
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a: f32 = 1.0;
+//! let b: f32 = 1.0000001;
+//! let x = 2;
+//! assert_f32_eq_ulp_distance_le_x!(a, b, x);
+//! ```
+//!
+//! The ULP distance is the number of representable floating point numbers
+//! between `a` and `b`, plus one, or zero when they are equal. This macro
+//! reinterprets the bits of each number as an ordered integer, so it
+//! works for numbers of any magnitude, unlike a comparison that uses
+//! a multiple of [`f32::EPSILON`](https://doc.rust-lang.org/std/primitive.f32.html#associated-constant.EPSILON).
+//!
+//! * Positive zero and negative zero have ULP distance zero.
+//!
+//! * Infinity is one ULP from the largest finite number of the same sign.
+//!
+//! * If either number is NaN, then the assertion fails, and the message
+//!   says the numbers are not comparable.
+//!
+//! # Module macros
+//!
+//! * [`assert_f32_eq_ulp_distance_le_x`](macro@crate::assert_f32_eq_ulp_distance_le_x)
+//! * [`assert_f32_eq_ulp_distance_le_x_as_result`](macro@crate::assert_f32_eq_ulp_distance_le_x_as_result)
+//! * [`debug_assert_f32_eq_ulp_distance_le_x`](macro@crate::debug_assert_f32_eq_ulp_distance_le_x)
+
+/// Assert a f32 is equal to another, within a number of units in the last place (ULPs).
+///
+/// Pseudocode:<br>
+/// ulp_distance(a, b) ≤ x
+///
+/// * If true, return Result `Ok(ulp_distance)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_f32_eq_ulp_distance_le_x`](macro@crate::assert_f32_eq_ulp_distance_le_x)
+/// * [`assert_f32_eq_ulp_distance_le_x_as_result`](macro@crate::assert_f32_eq_ulp_distance_le_x_as_result)
+/// * [`debug_assert_f32_eq_ulp_distance_le_x`](macro@crate::debug_assert_f32_eq_ulp_distance_le_x)
+///
+#[macro_export]
+macro_rules! assert_f32_eq_ulp_distance_le_x_as_result {
+    ($a:expr, $b:expr, $x:expr $(,)?) => {{
+        match (&$a, &$b, &$x) {
+            (a, b, x) => {
+                let (a, b, x): (&f32, &f32, &u32) = (a, b, x);
+                match $crate::assert_f32::ulp_distance(*a, *b) {
+                    Some(ulp_distance) if ulp_distance <= *x => Ok(ulp_distance),
+                    Some(ulp_distance) => Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_f32_eq_ulp_distance_le_x!(a, b, x)`\n",
+                                $crate::assertables_url_line!("assert_f32_eq_ulp_distance_le_x"),
+                                "          a label: `{}`,\n",
+                                "          a debug: `{:?}`,\n",
+                                "          b label: `{}`,\n",
+                                "          b debug: `{:?}`,\n",
+                                "          x label: `{}`,\n",
+                                "          x debug: `{:?}`,\n",
+                                "     ulp distance: `{:?}`,\n",
+                                " ulp distance ≤ x: false"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($x),
+                            x,
+                            ulp_distance
+                        )
+                    ),
+                    None => Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_f32_eq_ulp_distance_le_x!(a, b, x)`\n",
+                                $crate::assertables_url_line!("assert_f32_eq_ulp_distance_le_x"),
+                                "          a label: `{}`,\n",
+                                "          a debug: `{:?}`,\n",
+                                "          b label: `{}`,\n",
+                                "          b debug: `{:?}`,\n",
+                                "          x label: `{}`,\n",
+                                "          x debug: `{:?}`,\n",
+                                "           reason: a or b is NaN, so the numbers are not comparable"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($x),
+                            x
+                        )
+                    ),
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_f32_eq_ulp_distance_le_x_as_result {
+    #[test]
+    fn success() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.0000001;
+        let x = 2;
+        let actual = assert_f32_eq_ulp_distance_le_x_as_result!(a, b, x);
+        assert_eq!(actual.unwrap(), 1);
+    }
+
+    #[test]
+    fn failure() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.0000004;
+        let x = 2;
+        let actual = assert_f32_eq_ulp_distance_le_x_as_result!(a, b, x);
+        let message = concat!(
+            "assertion failed: `assert_f32_eq_ulp_distance_le_x!(a, b, x)`\n",
+            assertables_url_line!("assert_f32_eq_ulp_distance_le_x"),
+            "          a label: `a`,\n",
+            "          a debug: `1.0`,\n",
+            "          b label: `b`,\n",
+            "          b debug: `1.0000004`,\n",
+            "          x label: `x`,\n",
+            "          x debug: `2`,\n",
+            "     ulp distance: `3`,\n",
+            " ulp distance ≤ x: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_because_signed_zero() {
+        let actual = assert_f32_eq_ulp_distance_le_x_as_result!(0.0, -0.0, 0);
+        assert_eq!(actual.unwrap(), 0);
+    }
+
+    #[test]
+    fn success_across_zero() {
+        let a = f32::from_bits(1);
+        let b = -a;
+        let actual = assert_f32_eq_ulp_distance_le_x_as_result!(a, b, 2);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn success_with_large_magnitude() {
+        let a = f32::MAX;
+        let b = f32::from_bits(f32::MAX.to_bits() - 1);
+        let actual = assert_f32_eq_ulp_distance_le_x_as_result!(a, b, 1);
+        assert_eq!(actual.unwrap(), 1);
+    }
+
+    #[test]
+    fn success_with_infinity() {
+        let actual = assert_f32_eq_ulp_distance_le_x_as_result!(f32::INFINITY, f32::MAX, 1);
+        assert_eq!(actual.unwrap(), 1);
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let a = f32::NAN;
+        let b: f32 = 1.0;
+        let x = 2;
+        let actual = assert_f32_eq_ulp_distance_le_x_as_result!(a, b, x);
+        let message = concat!(
+            "assertion failed: `assert_f32_eq_ulp_distance_le_x!(a, b, x)`\n",
+            assertables_url_line!("assert_f32_eq_ulp_distance_le_x"),
+            "          a label: `a`,\n",
+            "          a debug: `NaN`,\n",
+            "          b label: `b`,\n",
+            "          b debug: `1.0`,\n",
+            "          x label: `x`,\n",
+            "          x debug: `2`,\n",
+            "           reason: a or b is NaN, so the numbers are not comparable"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a f32 is equal to another, within a number of units in the last place (ULPs).
+///
+/// Pseudocode:<br>
+/// ulp_distance(a, b) ≤ x
+///
+/// * If true, return `ulp_distance`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: f32 = 1.0;
+/// let b: f32 = 1.0000001;
+/// let x = 2;
+/// assert_f32_eq_ulp_distance_le_x!(a, b, x);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: f32 = 1.0;
+/// let b: f32 = 1.0000004;
+/// let x = 2;
+/// assert_f32_eq_ulp_distance_le_x!(a, b, x);
+/// # });
+/// // assertion failed: `assert_f32_eq_ulp_distance_le_x!(a, b, x)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_f32_eq_ulp_distance_le_x.html
+/// //           a label: `a`,
+/// //           a debug: `1.0`,
+/// //           b label: `b`,
+/// //           b debug: `1.0000004`,
+/// //           x label: `x`,
+/// //           x debug: `2`,
+/// //      ulp distance: `3`,
+/// //  ulp distance ≤ x: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_f32_eq_ulp_distance_le_x!(a, b, x)`\n",
+/// #     assertables_url_line!("assert_f32_eq_ulp_distance_le_x"),
+/// #     "          a label: `a`,\n",
+/// #     "          a debug: `1.0`,\n",
+/// #     "          b label: `b`,\n",
+/// #     "          b debug: `1.0000004`,\n",
+/// #     "          x label: `x`,\n",
+/// #     "          x debug: `2`,\n",
+/// #     "     ulp distance: `3`,\n",
+/// #     " ulp distance ≤ x: false"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The ULP distance is the number of representable floating point numbers
+/// between `a` and `b`, plus one, or zero when they are equal. This macro
+/// reinterprets the bits of each number as an ordered integer, so it
+/// works for numbers of any magnitude, unlike a comparison that uses
+/// a multiple of [`f32::EPSILON`](https://doc.rust-lang.org/std/primitive.f32.html#associated-constant.EPSILON).
+///
+/// * Positive zero and negative zero have ULP distance zero.
+///
+/// * Infinity is one ULP from the largest finite number of the same sign.
+///
+/// * If either number is NaN, then the assertion fails, and the message
+///   says the numbers are not comparable.
+///
+/// # Module macros
+///
+/// * [`assert_f32_eq_ulp_distance_le_x`](macro@crate::assert_f32_eq_ulp_distance_le_x)
+/// * [`assert_f32_eq_ulp_distance_le_x_as_result`](macro@crate::assert_f32_eq_ulp_distance_le_x_as_result)
+/// * [`debug_assert_f32_eq_ulp_distance_le_x`](macro@crate::debug_assert_f32_eq_ulp_distance_le_x)
+///
+#[macro_export]
+macro_rules! assert_f32_eq_ulp_distance_le_x {
+    ($a:expr, $b:expr, $x:expr $(,)?) => {{
+        match $crate::assert_f32_eq_ulp_distance_le_x_as_result!($a, $b, $x) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $x:expr, $($message:tt)+) => {{
+        match $crate::assert_f32_eq_ulp_distance_le_x_as_result!($a, $b, $x) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_f32_eq_ulp_distance_le_x {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.0000001;
+        let x = 2;
+        let actual = assert_f32_eq_ulp_distance_le_x!(a, b, x);
+        assert_eq!(actual, 1);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a: f32 = 1.0;
+            let b: f32 = 1.0000004;
+            let x = 2;
+            let _actual = assert_f32_eq_ulp_distance_le_x!(a, b, x);
+        });
+        let message = concat!(
+            "assertion failed: `assert_f32_eq_ulp_distance_le_x!(a, b, x)`\n",
+            assertables_url_line!("assert_f32_eq_ulp_distance_le_x"),
+            "          a label: `a`,\n",
+            "          a debug: `1.0`,\n",
+            "          b label: `b`,\n",
+            "          b debug: `1.0000004`,\n",
+            "          x label: `x`,\n",
+            "          x debug: `2`,\n",
+            "     ulp distance: `3`,\n",
+            " ulp distance ≤ x: false"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a f32 is equal to another, within a number of units in the last place (ULPs).
+///
+/// Pseudocode:<br>
+/// ulp_distance(a, b) ≤ x
+///
+/// This macro provides the same statements as [`assert_f32_eq_ulp_distance_le_x`](macro.assert_f32_eq_ulp_distance_le_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_f32_eq_ulp_distance_le_x`](macro@crate::assert_f32_eq_ulp_distance_le_x)
+/// * [`assert_f32_eq_ulp_distance_le_x_as_result`](macro@crate::assert_f32_eq_ulp_distance_le_x_as_result)
+/// * [`debug_assert_f32_eq_ulp_distance_le_x`](macro@crate::debug_assert_f32_eq_ulp_distance_le_x)
+///
+#[macro_export]
+macro_rules! debug_assert_f32_eq_ulp_distance_le_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_f32_eq_ulp_distance_le_x!($($arg)*);
+        }
+    };
+}
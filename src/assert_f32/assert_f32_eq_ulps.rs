@@ -0,0 +1,308 @@
+//! Assert a f32 is equal to another, within a multiple of f32::EPSILON.
+//!
+//! Pseudocode:<br>
+//! | a - b | ≤ n × f32::EPSILON
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a: f32 = 1.0;
+//! let b: f32 = 1.0000001;
+//! let n = 2;
+//! assert_f32_eq_ulps!(a, b, n);
+//! ```
+//!
+//! The multiplier `n` is the number of [`f32::EPSILON`](https://doc.rust-lang.org/std/primitive.f32.html#associated-constant.EPSILON)
+//! units of slack that the comparison allows. An epsilon unit is the
+//! distance between 1.0 and the next larger number, so this comparison
+//! is most useful for numbers that are near 1.0 in magnitude.
+//!
+//! If either number is NaN, then the assertion fails.
+//!
+//! # Module macros
+//!
+//! * [`assert_f32_eq_ulps`](macro@crate::assert_f32_eq_ulps)
+//! * [`assert_f32_eq_ulps_as_result`](macro@crate::assert_f32_eq_ulps_as_result)
+//! * [`debug_assert_f32_eq_ulps`](macro@crate::debug_assert_f32_eq_ulps)
+
+/// Assert a f32 is equal to another, within a multiple of f32::EPSILON.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ n × f32::EPSILON
+///
+/// * If true, return Result `Ok((abs_diff, n × epsilon))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_f32_eq_ulps`](macro@crate::assert_f32_eq_ulps)
+/// * [`assert_f32_eq_ulps_as_result`](macro@crate::assert_f32_eq_ulps_as_result)
+/// * [`debug_assert_f32_eq_ulps`](macro@crate::debug_assert_f32_eq_ulps)
+///
+#[macro_export]
+macro_rules! assert_f32_eq_ulps_as_result {
+    ($a:expr, $b:expr, $n:expr $(,)?) => {{
+        match (&$a, &$b, &$n) {
+            (a, b, n) => {
+                let (a, b): (&f32, &f32) = (a, b);
+                let tolerance = (*n as f32) * f32::EPSILON;
+                let abs_diff = $crate::assert_approx_eq_tol_as_result!(@impl a, b).unwrap_or(f32::NAN);
+                if abs_diff <= tolerance {
+                    Ok((abs_diff, tolerance))
+                } else {
+                    Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_f32_eq_ulps!(a, b, n)`\n",
+                                $crate::assertables_url_line!("assert_f32_eq_ulps"),
+                                "                a label: `{}`,\n",
+                                "                a debug: `{:?}`,\n",
+                                "                b label: `{}`,\n",
+                                "                b debug: `{:?}`,\n",
+                                "                n label: `{}`,\n",
+                                "                n debug: `{:?}`,\n",
+                                "              | a - b |: `{:?}`,\n",
+                                "            n × epsilon: `{:?}`,\n",
+                                " | a - b | ≤ n × epsilon: false"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            stringify!($n),
+                            n,
+                            abs_diff,
+                            tolerance
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_f32_eq_ulps_as_result {
+    #[test]
+    fn success() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.0000001;
+        let n = 2;
+        let actual = assert_f32_eq_ulps_as_result!(a, b, n);
+        assert_eq!(actual.unwrap(), (1.1920929e-7, 2.3841858e-7));
+    }
+
+    #[test]
+    fn failure() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.0000004;
+        let n = 2;
+        let actual = assert_f32_eq_ulps_as_result!(a, b, n);
+        let message = concat!(
+            "assertion failed: `assert_f32_eq_ulps!(a, b, n)`\n",
+            assertables_url_line!("assert_f32_eq_ulps"),
+            "                a label: `a`,\n",
+            "                a debug: `1.0`,\n",
+            "                b label: `b`,\n",
+            "                b debug: `1.0000004`,\n",
+            "                n label: `n`,\n",
+            "                n debug: `2`,\n",
+            "              | a - b |: `3.5762787e-7`,\n",
+            "            n × epsilon: `2.3841858e-7`,\n",
+            " | a - b | ≤ n × epsilon: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_larger_multiplier() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.0000004;
+        let actual = assert_f32_eq_ulps_as_result!(a, b, 4);
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let actual = assert_f32_eq_ulps_as_result!(f32::NAN, f32::NAN, 2);
+        assert!(actual.is_err());
+    }
+}
+
+/// Assert a f32 is equal to another, within a multiple of f32::EPSILON.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ n × f32::EPSILON
+///
+/// * If true, return `(abs_diff, n × epsilon)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: f32 = 1.0;
+/// let b: f32 = 1.0000001;
+/// let n = 2;
+/// assert_f32_eq_ulps!(a, b, n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: f32 = 1.0;
+/// let b: f32 = 1.0000004;
+/// let n = 2;
+/// assert_f32_eq_ulps!(a, b, n);
+/// # });
+/// // assertion failed: `assert_f32_eq_ulps!(a, b, n)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_f32_eq_ulps.html
+/// //                 a label: `a`,
+/// //                 a debug: `1.0`,
+/// //                 b label: `b`,
+/// //                 b debug: `1.0000004`,
+/// //                 n label: `n`,
+/// //                 n debug: `2`,
+/// //               | a - b |: `3.5762787e-7`,
+/// //             n × epsilon: `2.3841858e-7`,
+/// //  | a - b | ≤ n × epsilon: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_f32_eq_ulps!(a, b, n)`\n",
+/// #     assertables_url_line!("assert_f32_eq_ulps"),
+/// #     "                a label: `a`,\n",
+/// #     "                a debug: `1.0`,\n",
+/// #     "                b label: `b`,\n",
+/// #     "                b debug: `1.0000004`,\n",
+/// #     "                n label: `n`,\n",
+/// #     "                n debug: `2`,\n",
+/// #     "              | a - b |: `3.5762787e-7`,\n",
+/// #     "            n × epsilon: `2.3841858e-7`,\n",
+/// #     " | a - b | ≤ n × epsilon: false"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The multiplier `n` is the number of [`f32::EPSILON`](https://doc.rust-lang.org/std/primitive.f32.html#associated-constant.EPSILON)
+/// units of slack that the comparison allows. An epsilon unit is the
+/// distance between 1.0 and the next larger number, so this comparison
+/// is most useful for numbers that are near 1.0 in magnitude.
+///
+/// If either number is NaN, then the assertion fails.
+///
+/// # Module macros
+///
+/// * [`assert_f32_eq_ulps`](macro@crate::assert_f32_eq_ulps)
+/// * [`assert_f32_eq_ulps_as_result`](macro@crate::assert_f32_eq_ulps_as_result)
+/// * [`debug_assert_f32_eq_ulps`](macro@crate::debug_assert_f32_eq_ulps)
+///
+#[macro_export]
+macro_rules! assert_f32_eq_ulps {
+    ($a:expr, $b:expr, $n:expr $(,)?) => {{
+        match $crate::assert_f32_eq_ulps_as_result!($a, $b, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_f32_eq_ulps_as_result!($a, $b, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_f32_eq_ulps {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a: f32 = 1.0;
+        let b: f32 = 1.0000001;
+        let n = 2;
+        let actual = assert_f32_eq_ulps!(a, b, n);
+        assert_eq!(actual, (1.1920929e-7, 2.3841858e-7));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a: f32 = 1.0;
+            let b: f32 = 1.0000004;
+            let n = 2;
+            let _actual = assert_f32_eq_ulps!(a, b, n);
+        });
+        let message = concat!(
+            "assertion failed: `assert_f32_eq_ulps!(a, b, n)`\n",
+            assertables_url_line!("assert_f32_eq_ulps"),
+            "                a label: `a`,\n",
+            "                a debug: `1.0`,\n",
+            "                b label: `b`,\n",
+            "                b debug: `1.0000004`,\n",
+            "                n label: `n`,\n",
+            "                n debug: `2`,\n",
+            "              | a - b |: `3.5762787e-7`,\n",
+            "            n × epsilon: `2.3841858e-7`,\n",
+            " | a - b | ≤ n × epsilon: false"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a f32 is equal to another, within a multiple of f32::EPSILON.
+///
+/// Pseudocode:<br>
+/// | a - b | ≤ n × f32::EPSILON
+///
+/// This macro provides the same statements as [`assert_f32_eq_ulps`](macro.assert_f32_eq_ulps.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_f32_eq_ulps`](macro@crate::assert_f32_eq_ulps)
+/// * [`assert_f32_eq_ulps_as_result`](macro@crate::assert_f32_eq_ulps_as_result)
+/// * [`debug_assert_f32_eq_ulps`](macro@crate::debug_assert_f32_eq_ulps)
+///
+#[macro_export]
+macro_rules! debug_assert_f32_eq_ulps {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_f32_eq_ulps!($($arg)*);
+        }
+    };
+}
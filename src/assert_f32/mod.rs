@@ -0,0 +1,44 @@
+//! Assert for comparing f32 numbers.
+//!
+//! These macros compare two f32 numbers, where one number may be very close
+//! to another number but not quite equal, because of floating point rounding.
+//!
+//! * [`assert_f32_eq_ulps!(a, b, n)`](macro@crate::assert_f32_eq_ulps) ≈ | a - b | ≤ n × f32::EPSILON
+//!
+//! * [`assert_f32_eq_ulp_distance_le_x!(a, b, x)`](macro@crate::assert_f32_eq_ulp_distance_le_x) ≈ ulp_distance(a, b) ≤ x
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a: f32 = 1.0 / 3.0;
+//! let b: f32 = 1.0 - 2.0 / 3.0;
+//! assert_f32_eq_ulps!(a, b, 2);
+//! assert_f32_eq_ulp_distance_le_x!(a, b, 2);
+//! ```
+
+pub mod assert_f32_eq_ulp_distance_le_x;
+pub mod assert_f32_eq_ulps;
+
+/// Return the number of ULPs (units in the last place) between two f32 numbers,
+/// or `None` if either number is NaN.
+///
+/// This reinterprets the bits of each number as a sign-magnitude integer,
+/// then converts it to an ordered integer, so that adjacent floating point
+/// numbers have adjacent integers, and positive zero equals negative zero.
+#[doc(hidden)]
+pub fn ulp_distance(a: f32, b: f32) -> Option<u32> {
+    if a.is_nan() || b.is_nan() {
+        return None;
+    }
+    let ordered = |x: f32| {
+        let bits = x.to_bits() as i32 as i64;
+        if bits < 0 {
+            i64::from(i32::MIN) - bits
+        } else {
+            bits
+        }
+    };
+    Some((ordered(a) - ordered(b)).unsigned_abs() as u32)
+}
@@ -0,0 +1,44 @@
+//! Assert for comparing f64 numbers.
+//!
+//! These macros compare two f64 numbers, where one number may be very close
+//! to another number but not quite equal, because of floating point rounding.
+//!
+//! * [`assert_f64_eq_ulps!(a, b, n)`](macro@crate::assert_f64_eq_ulps) ≈ | a - b | ≤ n × f64::EPSILON
+//!
+//! * [`assert_f64_eq_ulp_distance_le_x!(a, b, x)`](macro@crate::assert_f64_eq_ulp_distance_le_x) ≈ ulp_distance(a, b) ≤ x
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a: f64 = 1.0 / 3.0;
+//! let b: f64 = 1.0 - 2.0 / 3.0;
+//! assert_f64_eq_ulps!(a, b, 2);
+//! assert_f64_eq_ulp_distance_le_x!(a, b, 2);
+//! ```
+
+pub mod assert_f64_eq_ulp_distance_le_x;
+pub mod assert_f64_eq_ulps;
+
+/// Return the number of ULPs (units in the last place) between two f64 numbers,
+/// or `None` if either number is NaN.
+///
+/// This reinterprets the bits of each number as a sign-magnitude integer,
+/// then converts it to an ordered integer, so that adjacent floating point
+/// numbers have adjacent integers, and positive zero equals negative zero.
+#[doc(hidden)]
+pub fn ulp_distance(a: f64, b: f64) -> Option<u64> {
+    if a.is_nan() || b.is_nan() {
+        return None;
+    }
+    let ordered = |x: f64| {
+        let bits = x.to_bits() as i64 as i128;
+        if bits < 0 {
+            i128::from(i64::MIN) - bits
+        } else {
+            bits
+        }
+    };
+    Some((ordered(a) - ordered(b)).unsigned_abs() as u64)
+}
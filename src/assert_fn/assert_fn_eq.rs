@@ -40,6 +40,46 @@
 #[macro_export]
 macro_rules! assert_fn_eq_as_result {
 
+    //// Arity N
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_function:path, args = ($($b_param:expr),+ $(,)?) $(,)?) => {{
+        match (
+            $crate::assert_fn_impl_call!($a_function, $($a_param),+),
+            $crate::assert_fn_impl_call!($b_function, $($b_param),+)
+        ) {
+            ((a, a_params), (b, b_params)) => {
+                if a == b {
+                    Ok((a, b))
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_fn_eq!(a_function, a_params, b_function, b_params)`\n",
+                                $crate::assertables_url_line!("assert_fn_eq"),
+                                " a_function label: `{}`,\n",
+                                "   a_params label: `{}`,\n",
+                                "   a_params debug: `{}`,\n",
+                                " b_function label: `{}`,\n",
+                                "   b_params label: `{}`,\n",
+                                "   b_params debug: `{}`,\n",
+                                "                a: `{:?}`,\n",
+                                "                b: `{:?}`"
+                            ),
+                            stringify!($a_function),
+                            stringify!(($($a_param),+)),
+                            a_params,
+                            stringify!($b_function),
+                            stringify!(($($b_param),+)),
+                            b_params,
+                            a,
+                            b
+                        )
+                    )
+                }
+            }
+        }
+    }};
+
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
@@ -54,7 +94,7 @@ macro_rules! assert_fn_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_eq!(a_function, a_param, b_function, b_param)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq.html\n",
+                                $crate::assertables_url_line!("assert_fn_eq"),
                                 " a_function label: `{}`,\n",
                                 "    a_param label: `{}`,\n",
                                 "    a_param debug: `{:?}`,\n",
@@ -91,7 +131,7 @@ macro_rules! assert_fn_eq_as_result {
                 format!(
                     concat!(
                         "assertion failed: `assert_fn_eq!(a_function, b_function)`\n",
-                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq.html\n",
+                        $crate::assertables_url_line!("assert_fn_eq"),
                         " a_function label: `{}`,\n",
                         " b_function label: `{}`,\n",
                         "                a: `{:?}`,\n",
@@ -136,7 +176,7 @@ mod test_assert_fn_eq_as_result {
             let actual = assert_fn_eq_as_result!(f, a, g, b);
             let message = concat!(
                 "assertion failed: `assert_fn_eq!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq.html\n",
+                assertables_url_line!("assert_fn_eq"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -171,7 +211,7 @@ mod test_assert_fn_eq_as_result {
             let actual = assert_fn_eq_as_result!(f, g);
             let message = concat!(
                 "assertion failed: `assert_fn_eq!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq.html\n",
+                assertables_url_line!("assert_fn_eq"),
                 " a_function label: `f`,\n",
                 " b_function label: `g`,\n",
                 "                a: `1`,\n",
@@ -180,6 +220,110 @@ mod test_assert_fn_eq_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    #[test]
+    fn eq_with_closures() {
+        let a = || 1;
+        let b = || 1;
+        let actual = assert_fn_eq_as_result!(a, b);
+        assert_eq!(actual.unwrap(), (1, 1));
+    }
+
+    #[test]
+    fn ne_with_closures() {
+        let a = || 1;
+        let b = || 2;
+        let actual = assert_fn_eq_as_result!(a, b);
+        assert!(actual
+            .unwrap_err()
+            .contains(" a_function label: `a`,\n b_function label: `b`,\n"));
+    }
+
+    mod arity_n {
+
+        #[test]
+        fn success() {
+            let (a1, a2): (i8, i8) = (1, 0);
+            let (b1, b2): (i8, i8) = (1, 0);
+            let actual = assert_fn_eq_as_result!(
+                i8::wrapping_add,
+                args = (a1, a2),
+                i8::wrapping_add,
+                args = (b1, b2)
+            );
+            assert_eq!(actual.unwrap(), (1, 1));
+        }
+
+        #[test]
+        fn failure() {
+            let (a1, a2): (i8, i8) = (1, 0);
+            let (b1, b2): (i8, i8) = (1, 1);
+            let actual = assert_fn_eq_as_result!(
+                i8::wrapping_add,
+                args = (a1, a2),
+                i8::wrapping_add,
+                args = (b1, b2)
+            );
+            let message = concat!(
+                "assertion failed: `assert_fn_eq!(a_function, a_params, b_function, b_params)`\n",
+                assertables_url_line!("assert_fn_eq"),
+                " a_function label: `i8::wrapping_add`,\n",
+                "   a_params label: `(a1, a2)`,\n",
+                "   a_params debug: `(1, 0)`,\n",
+                " b_function label: `i8::wrapping_add`,\n",
+                "   b_params label: `(b1, b2)`,\n",
+                "   b_params debug: `(1, 1)`,\n",
+                "                a: `1`,\n",
+                "                b: `2`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+    }
+
+    #[test]
+    fn three_parameters() {
+        fn f(a: i8, b: i8, c: i8) -> i8 {
+            a + b + c
+        }
+        let actual = assert_fn_eq_as_result!(f, args = (1, 2, 3), f, args = (3, 2, 1));
+        assert_eq!(actual.unwrap(), (6, 6));
+    }
+
+    #[test]
+    fn tuple_parameter_without_args_marker() {
+        fn f(pair: (i8, i8)) -> i8 {
+            pair.0 - pair.1
+        }
+        let actual = assert_fn_eq_as_result!(f, (3, 1), f, (4, 2));
+        assert_eq!(actual.unwrap(), (2, 2));
+    }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static P: AtomicU32 = AtomicU32::new(0);
+        fn p(i: i8) -> i8 {
+            P.fetch_add(1, Ordering::SeqCst);
+            i
+        }
+        // Success
+        assert!(assert_fn_eq_as_result!(
+            i8::wrapping_add,
+            args = (p(1), p(2)),
+            i8::wrapping_add,
+            args = (p(2), p(1))
+        )
+        .is_ok());
+        // Failure
+        assert!(assert_fn_eq_as_result!(
+            i8::wrapping_add,
+            args = (p(1), p(2)),
+            i8::wrapping_add,
+            args = (p(2), p(2))
+        )
+        .is_err());
+        assert_eq!(P.load(Ordering::SeqCst), 8);
+    }
 }
 
 /// Assert a function output is equal to another.
@@ -222,7 +366,7 @@ mod test_assert_fn_eq_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fn_eq!(a_function, a_param, b_function, b_param)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq.html\n",
+/// #     assertables_url_line!("assert_fn_eq"),
 /// #     " a_function label: `i8::abs`,\n",
 /// #     "    a_param label: `a`,\n",
 /// #     "    a_param debug: `-1`,\n",
@@ -245,19 +389,35 @@ mod test_assert_fn_eq_as_result {
 #[macro_export]
 macro_rules! assert_fn_eq {
 
+    //// Arity N
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_function:path, args = ($($b_param:expr),+ $(,)?) $(,)?) => {{
+        match $crate::assert_fn_eq_as_result!($a_function, args = ($($a_param),+), $b_function, args = ($($b_param),+)) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_function:path, args = ($($b_param:expr),+ $(,)?), $($message:tt)+) => {{
+        match $crate::assert_fn_eq_as_result!($a_function, args = ($($a_param),+), $b_function, args = ($($b_param),+)) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
         match $crate::assert_fn_eq_as_result!($a_function, $a_param, $b_function, $b_param) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr, $($message:tt)+) => {{
         match $crate::assert_fn_eq_as_result!($a_function, $a_param, $b_function, $b_param) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -266,14 +426,14 @@ macro_rules! assert_fn_eq {
     ($a_function:path, $b_function:path) => {{
         match $crate::assert_fn_eq_as_result!($a_function, $b_function) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $b_function:path, $($message:tt)+) => {{
         match $crate::assert_fn_eq_as_result!($a_function, $b_function) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -311,7 +471,7 @@ mod test_assert_fn_eq {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_eq!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq.html\n",
+                assertables_url_line!("assert_fn_eq"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -356,7 +516,7 @@ mod test_assert_fn_eq {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_eq!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq.html\n",
+                assertables_url_line!("assert_fn_eq"),
                 " a_function label: `f`,\n",
                 " b_function label: `g`,\n",
                 "                a: `1`,\n",
@@ -372,6 +532,32 @@ mod test_assert_fn_eq {
             );
         }
     }
+
+    mod arity_n {
+
+        #[test]
+        fn eq() {
+            let actual = assert_fn_eq!(
+                i8::wrapping_add,
+                args = (1, 2),
+                i8::wrapping_add,
+                args = (2, 1)
+            );
+            assert_eq!(actual, (3, 3));
+        }
+
+        #[test]
+        fn eq_with_message() {
+            let actual = assert_fn_eq!(
+                i8::wrapping_add,
+                args = (1, 2),
+                i8::wrapping_add,
+                args = (2, 1),
+                "message"
+            );
+            assert_eq!(actual, (3, 3));
+        }
+    }
 }
 
 /// Assert a function output is equal to another.
@@ -408,7 +594,7 @@ mod test_assert_fn_eq {
 #[macro_export]
 macro_rules! debug_assert_fn_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_fn_eq!($($arg)*);
         }
     };
@@ -40,6 +40,44 @@
 #[macro_export]
 macro_rules! assert_fn_eq_x_as_result {
 
+    //// Arity N
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_expr:expr $(,)?) => {{
+        match (
+            $crate::assert_fn_impl_call!($a_function, $($a_param),+),
+            &$b_expr
+        ) {
+            ((a, a_params), b_expr) => {
+                if a == *b_expr {
+                    Ok(a)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_fn_eq_x!(a_function, a_params, b_expr)`\n",
+                                $crate::assertables_url_line!("assert_fn_eq_x"),
+                                " a_function label: `{}`,\n",
+                                "   a_params label: `{}`,\n",
+                                "   a_params debug: `{}`,\n",
+                                "     b_expr label: `{}`,\n",
+                                "     b_expr debug: `{:?}`,\n",
+                                "                a: `{:?}`,\n",
+                                "                b: `{:?}`"
+                            ),
+                            stringify!($a_function),
+                            stringify!(($($a_param),+)),
+                            a_params,
+                            stringify!($b_expr),
+                            b_expr,
+                            a,
+                            b_expr
+                        )
+                    )
+                }
+            }
+        }
+    }};
+
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
@@ -53,7 +91,7 @@ macro_rules! assert_fn_eq_x_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_eq_x!(a_function, a_param, b_expr)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq_x.html\n",
+                                $crate::assertables_url_line!("assert_fn_eq_x"),
                                 " a_function label: `{}`,\n",
                                 "    a_param label: `{}`,\n",
                                 "    a_param debug: `{:?}`,\n",
@@ -89,7 +127,7 @@ macro_rules! assert_fn_eq_x_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_eq_x!(a_function, b_expr)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq_x.html\n",
+                                $crate::assertables_url_line!("assert_fn_eq_x"),
                                 " a_function label: `{}`,\n",
                                 "     b_expr label: `{}`,\n",
                                 "     b_expr debug: `{:?}`,\n",
@@ -134,7 +172,7 @@ mod test_assert_fn_eq_x_as_result {
             let actual = assert_fn_eq_x_as_result!(f, a, b);
             let message = concat!(
                 "assertion failed: `assert_fn_eq_x!(a_function, a_param, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq_x.html\n",
+                assertables_url_line!("assert_fn_eq_x"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -166,7 +204,7 @@ mod test_assert_fn_eq_x_as_result {
             let actual = assert_fn_eq_x_as_result!(f, b);
             let message = concat!(
                 "assertion failed: `assert_fn_eq_x!(a_function, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq_x.html\n",
+                assertables_url_line!("assert_fn_eq_x"),
                 " a_function label: `f`,\n",
                 "     b_expr label: `b`,\n",
                 "     b_expr debug: `2`,\n",
@@ -176,6 +214,52 @@ mod test_assert_fn_eq_x_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    #[test]
+    fn eq_with_closure() {
+        let a = || 1;
+        let actual = assert_fn_eq_x_as_result!(a, 1);
+        assert_eq!(actual.unwrap(), 1);
+    }
+
+    #[test]
+    fn tuple_parameter_without_args_marker() {
+        fn f(pair: (i8, i8)) -> i8 {
+            pair.0 - pair.1
+        }
+        let actual = assert_fn_eq_x_as_result!(f, (3, 1), 2);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    mod arity_n {
+
+        #[test]
+        fn success() {
+            let (a1, a2): (i8, i8) = (1, 0);
+            let b: i8 = 1;
+            let actual = assert_fn_eq_x_as_result!(i8::wrapping_add, args = (a1, a2), b);
+            assert_eq!(actual.unwrap(), 1);
+        }
+
+        #[test]
+        fn failure() {
+            let (a1, a2): (i8, i8) = (1, 0);
+            let b: i8 = 2;
+            let actual = assert_fn_eq_x_as_result!(i8::wrapping_add, args = (a1, a2), b);
+            let message = concat!(
+                "assertion failed: `assert_fn_eq_x!(a_function, a_params, b_expr)`\n",
+                assertables_url_line!("assert_fn_eq_x"),
+                " a_function label: `i8::wrapping_add`,\n",
+                "   a_params label: `(a1, a2)`,\n",
+                "   a_params debug: `(1, 0)`,\n",
+                "     b_expr label: `b`,\n",
+                "     b_expr debug: `2`,\n",
+                "                a: `1`,\n",
+                "                b: `2`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+    }
 }
 
 /// Assert a function output is equal to an expression.
@@ -217,7 +301,7 @@ mod test_assert_fn_eq_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fn_eq_x!(a_function, a_param, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq_x.html\n",
+/// #     assertables_url_line!("assert_fn_eq_x"),
 /// #     " a_function label: `i8::abs`,\n",
 /// #     "    a_param label: `a`,\n",
 /// #     "    a_param debug: `-1`,\n",
@@ -239,19 +323,35 @@ mod test_assert_fn_eq_x_as_result {
 #[macro_export]
 macro_rules! assert_fn_eq_x {
 
+    //// Arity N
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_expr:expr $(,)?) => {{
+        match $crate::assert_fn_eq_x_as_result!($a_function, args = ($($a_param),+), $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_expr:expr, $($message:tt)+) => {{
+        match $crate::assert_fn_eq_x_as_result!($a_function, args = ($($a_param),+), $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_fn_eq_x_as_result!($a_function, $a_param, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $a_param:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fn_eq_x_as_result!($a_function, $a_param, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -260,14 +360,14 @@ macro_rules! assert_fn_eq_x {
     ($a_function:path, $b_expr:expr $(,)?) => {{
         match $crate::assert_fn_eq_x_as_result!($a_function, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fn_eq_x_as_result!($a_function, $a_param, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -301,7 +401,7 @@ mod test_assert_fn_eq_x {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_eq_x!(a_function, a_param, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq_x.html\n",
+                assertables_url_line!("assert_fn_eq_x"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -343,7 +443,7 @@ mod test_assert_fn_eq_x {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_eq_x!(a_function, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_eq_x.html\n",
+                assertables_url_line!("assert_fn_eq_x"),
                 " a_function label: `f`,\n",
                 "     b_expr label: `b`,\n",
                 "     b_expr debug: `2`,\n",
@@ -396,7 +496,7 @@ mod test_assert_fn_eq_x {
 #[macro_export]
 macro_rules! debug_assert_fn_eq_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_fn_eq_x!($($arg)*);
         }
     };
@@ -43,6 +43,44 @@
 #[macro_export]
 macro_rules! assert_fn_le_x_as_result {
 
+    //// Arity N
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_expr:expr $(,)?) => {{
+        match (
+            $crate::assert_fn_impl_call!($a_function, $($a_param),+),
+            &$b_expr
+        ) {
+            ((a, a_params), b_expr) => {
+                if a <= *b_expr {
+                    Ok(a)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_fn_le_x!(a_function, a_params, b_expr)`\n",
+                                $crate::assertables_url_line!("assert_fn_le_x"),
+                                " a_function label: `{}`,\n",
+                                "   a_params label: `{}`,\n",
+                                "   a_params debug: `{}`,\n",
+                                "     b_expr label: `{}`,\n",
+                                "     b_expr debug: `{:?}`,\n",
+                                "                a: `{:?}`,\n",
+                                "                b: `{:?}`"
+                            ),
+                            stringify!($a_function),
+                            stringify!(($($a_param),+)),
+                            a_params,
+                            stringify!($b_expr),
+                            b_expr,
+                            a,
+                            b_expr
+                        )
+                    )
+                }
+            }
+        }
+    }};
+
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
@@ -56,7 +94,7 @@ macro_rules! assert_fn_le_x_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_le_x!(a_function, a_param, b_expr)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_le_x.html\n",
+                                $crate::assertables_url_line!("assert_fn_le_x"),
                                 " a_function label: `{}`,\n",
                                 "    a_param label: `{}`,\n",
                                 "    a_param debug: `{:?}`,\n",
@@ -92,7 +130,7 @@ macro_rules! assert_fn_le_x_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_le_x!(a_function, b_expr)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_le_x.html\n",
+                                $crate::assertables_url_line!("assert_fn_le_x"),
                                 " a_function label: `{}`,\n",
                                 "     b_expr label: `{}`,\n",
                                 "     b_expr debug: `{:?}`,\n",
@@ -145,7 +183,7 @@ mod test_assert_fn_le_x_as_result {
             let actual = assert_fn_le_x_as_result!(f, a, b);
             let message = concat!(
                 "assertion failed: `assert_fn_le_x!(a_function, a_param, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_le_x.html\n",
+                assertables_url_line!("assert_fn_le_x"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `2`,\n",
@@ -184,7 +222,7 @@ mod test_assert_fn_le_x_as_result {
             let actual = assert_fn_le_x_as_result!(f, b);
             let message = concat!(
                 "assertion failed: `assert_fn_le_x!(a_function, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_le_x.html\n",
+                assertables_url_line!("assert_fn_le_x"),
                 " a_function label: `f`,\n",
                 "     b_expr label: `b`,\n",
                 "     b_expr debug: `0`,\n",
@@ -194,6 +232,36 @@ mod test_assert_fn_le_x_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    mod arity_n {
+
+        #[test]
+        fn success() {
+            let (a1, a2): (i8, i8) = (1, 0);
+            let b: i8 = 1;
+            let actual = assert_fn_le_x_as_result!(i8::wrapping_add, args = (a1, a2), b);
+            assert_eq!(actual.unwrap(), 1);
+        }
+
+        #[test]
+        fn failure() {
+            let (a1, a2): (i8, i8) = (1, 1);
+            let b: i8 = 1;
+            let actual = assert_fn_le_x_as_result!(i8::wrapping_add, args = (a1, a2), b);
+            let message = concat!(
+                "assertion failed: `assert_fn_le_x!(a_function, a_params, b_expr)`\n",
+                assertables_url_line!("assert_fn_le_x"),
+                " a_function label: `i8::wrapping_add`,\n",
+                "   a_params label: `(a1, a2)`,\n",
+                "   a_params debug: `(1, 1)`,\n",
+                "     b_expr label: `b`,\n",
+                "     b_expr debug: `1`,\n",
+                "                a: `2`,\n",
+                "                b: `1`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+    }
 }
 
 /// Assert a function output is less than or equal to an expression.
@@ -235,7 +303,7 @@ mod test_assert_fn_le_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fn_le_x!(a_function, a_param, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_le_x.html\n",
+/// #     assertables_url_line!("assert_fn_le_x"),
 /// #     " a_function label: `i8::abs`,\n",
 /// #     "    a_param label: `a`,\n",
 /// #     "    a_param debug: `-2`,\n",
@@ -257,19 +325,35 @@ mod test_assert_fn_le_x_as_result {
 #[macro_export]
 macro_rules! assert_fn_le_x {
 
+    //// Arity N
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_expr:expr $(,)?) => {{
+        match $crate::assert_fn_le_x_as_result!($a_function, args = ($($a_param),+), $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_expr:expr, $($message:tt)+) => {{
+        match $crate::assert_fn_le_x_as_result!($a_function, args = ($($a_param),+), $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_fn_le_x_as_result!($a_function, $a_param, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $a_param:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fn_le_x_as_result!($a_function, $a_param, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -278,14 +362,14 @@ macro_rules! assert_fn_le_x {
     ($a_function:path, $b_expr:expr $(,)?) => {{
         match $crate::assert_fn_le_x_as_result!($a_function, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fn_le_x_as_result!($a_function, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -327,7 +411,7 @@ mod test_assert_fn_le_x {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_le_x!(a_function, a_param, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_le_x.html\n",
+                assertables_url_line!("assert_fn_le_x"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `2`,\n",
@@ -376,7 +460,7 @@ mod test_assert_fn_le_x {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_le_x!(a_function, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_le_x.html\n",
+                assertables_url_line!("assert_fn_le_x"),
                 " a_function label: `f`,\n",
                 "     b_expr label: `b`,\n",
                 "     b_expr debug: `0`,\n",
@@ -429,7 +513,7 @@ mod test_assert_fn_le_x {
 #[macro_export]
 macro_rules! debug_assert_fn_le_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_fn_le_x!($($arg)*);
         }
     };
@@ -40,6 +40,46 @@
 #[macro_export]
 macro_rules! assert_fn_lt_as_result {
 
+    //// Arity N
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_function:path, args = ($($b_param:expr),+ $(,)?) $(,)?) => {{
+        match (
+            $crate::assert_fn_impl_call!($a_function, $($a_param),+),
+            $crate::assert_fn_impl_call!($b_function, $($b_param),+)
+        ) {
+            ((a, a_params), (b, b_params)) => {
+                if a < b {
+                    Ok((a, b))
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_fn_lt!(a_function, a_params, b_function, b_params)`\n",
+                                $crate::assertables_url_line!("assert_fn_lt"),
+                                " a_function label: `{}`,\n",
+                                "   a_params label: `{}`,\n",
+                                "   a_params debug: `{}`,\n",
+                                " b_function label: `{}`,\n",
+                                "   b_params label: `{}`,\n",
+                                "   b_params debug: `{}`,\n",
+                                "                a: `{:?}`,\n",
+                                "                b: `{:?}`"
+                            ),
+                            stringify!($a_function),
+                            stringify!(($($a_param),+)),
+                            a_params,
+                            stringify!($b_function),
+                            stringify!(($($b_param),+)),
+                            b_params,
+                            a,
+                            b
+                        )
+                    )
+                }
+            }
+        }
+    }};
+
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
@@ -54,7 +94,7 @@ macro_rules! assert_fn_lt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_lt!(a_function, a_param, b_function, b_param)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                                $crate::assertables_url_line!("assert_fn_lt"),
                                 " a_function label: `{}`,\n",
                                 "    a_param label: `{}`,\n",
                                 "    a_param debug: `{:?}`,\n",
@@ -91,7 +131,7 @@ macro_rules! assert_fn_lt_as_result {
                 format!(
                     concat!(
                         "assertion failed: `assert_fn_lt!(a_function, b_function)`\n",
-                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                        $crate::assertables_url_line!("assert_fn_lt"),
                         " a_function label: `{}`,\n",
                         " b_function label: `{}`,\n",
                         "                a: `{:?}`,\n",
@@ -136,7 +176,7 @@ mod test_assert_fn_lt_as_result {
             let actual = assert_fn_lt_as_result!(f, a, g, b);
             let message = concat!(
                 "assertion failed: `assert_fn_lt!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                assertables_url_line!("assert_fn_lt"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -156,7 +196,7 @@ mod test_assert_fn_lt_as_result {
             let actual = assert_fn_lt_as_result!(f, a, g, b);
             let message = concat!(
                 "assertion failed: `assert_fn_lt!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                assertables_url_line!("assert_fn_lt"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `2`,\n",
@@ -191,7 +231,7 @@ mod test_assert_fn_lt_as_result {
             let actual = assert_fn_lt_as_result!(f, f);
             let message = concat!(
                 "assertion failed: `assert_fn_lt!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                assertables_url_line!("assert_fn_lt"),
                 " a_function label: `f`,\n",
                 " b_function label: `f`,\n",
                 "                a: `1`,\n",
@@ -205,7 +245,7 @@ mod test_assert_fn_lt_as_result {
             let actual = assert_fn_lt_as_result!(g, f);
             let message = concat!(
                 "assertion failed: `assert_fn_lt!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                assertables_url_line!("assert_fn_lt"),
                 " a_function label: `g`,\n",
                 " b_function label: `f`,\n",
                 "                a: `2`,\n",
@@ -214,6 +254,47 @@ mod test_assert_fn_lt_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    mod arity_n {
+
+        #[test]
+        fn success() {
+            let (a1, a2): (i8, i8) = (1, 0);
+            let (b1, b2): (i8, i8) = (1, 1);
+            let actual = assert_fn_lt_as_result!(
+                i8::wrapping_add,
+                args = (a1, a2),
+                i8::wrapping_add,
+                args = (b1, b2)
+            );
+            assert_eq!(actual.unwrap(), (1, 2));
+        }
+
+        #[test]
+        fn failure() {
+            let (a1, a2): (i8, i8) = (1, 1);
+            let (b1, b2): (i8, i8) = (1, 0);
+            let actual = assert_fn_lt_as_result!(
+                i8::wrapping_add,
+                args = (a1, a2),
+                i8::wrapping_add,
+                args = (b1, b2)
+            );
+            let message = concat!(
+                "assertion failed: `assert_fn_lt!(a_function, a_params, b_function, b_params)`\n",
+                assertables_url_line!("assert_fn_lt"),
+                " a_function label: `i8::wrapping_add`,\n",
+                "   a_params label: `(a1, a2)`,\n",
+                "   a_params debug: `(1, 1)`,\n",
+                " b_function label: `i8::wrapping_add`,\n",
+                "   b_params label: `(b1, b2)`,\n",
+                "   b_params debug: `(1, 0)`,\n",
+                "                a: `2`,\n",
+                "                b: `1`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+    }
 }
 
 /// Assert a function output is less than another.
@@ -256,7 +337,7 @@ mod test_assert_fn_lt_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fn_lt!(a_function, a_param, b_function, b_param)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+/// #     assertables_url_line!("assert_fn_lt"),
 /// #     " a_function label: `i8::abs`,\n",
 /// #     "    a_param label: `a`,\n",
 /// #     "    a_param debug: `-2`,\n",
@@ -279,19 +360,35 @@ mod test_assert_fn_lt_as_result {
 #[macro_export]
 macro_rules! assert_fn_lt {
 
+    //// Arity N
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_function:path, args = ($($b_param:expr),+ $(,)?) $(,)?) => {{
+        match $crate::assert_fn_lt_as_result!($a_function, args = ($($a_param),+), $b_function, args = ($($b_param),+)) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+
+    ($a_function:path, args = ($($a_param:expr),+ $(,)?), $b_function:path, args = ($($b_param:expr),+ $(,)?), $($message:tt)+) => {{
+        match $crate::assert_fn_lt_as_result!($a_function, args = ($($a_param),+), $b_function, args = ($($b_param),+)) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
         match $crate::assert_fn_lt_as_result!($a_function, $a_param, $b_function, $b_param) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr, $($message:tt)+) => {{
         match $crate::assert_fn_lt_as_result!($a_function, $a_param, $b_function, $b_param) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -300,14 +397,14 @@ macro_rules! assert_fn_lt {
     ($a_function:path, $b_function:path) => {{
         match $crate::assert_fn_lt_as_result!($a_function, $b_function) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $b_function:path, $($message:tt)+) => {{
         match $crate::assert_fn_lt_as_result!($a_function, $b_function) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -345,7 +442,7 @@ mod test_assert_fn_lt {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_lt!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                assertables_url_line!("assert_fn_lt"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -374,7 +471,7 @@ mod test_assert_fn_lt {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_lt!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                assertables_url_line!("assert_fn_lt"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `2`,\n",
@@ -419,7 +516,7 @@ mod test_assert_fn_lt {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_lt!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                assertables_url_line!("assert_fn_lt"),
                 " a_function label: `f`,\n",
                 " b_function label: `f`,\n",
                 "                a: `1`,\n",
@@ -442,7 +539,7 @@ mod test_assert_fn_lt {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_lt!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_lt.html\n",
+                assertables_url_line!("assert_fn_lt"),
                 " a_function label: `g`,\n",
                 " b_function label: `f`,\n",
                 "                a: `2`,\n",
@@ -494,7 +591,7 @@ mod test_assert_fn_lt {
 #[macro_export]
 macro_rules! debug_assert_fn_lt {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_fn_lt!($($arg)*);
         }
     };
@@ -0,0 +1,402 @@
+//! Assert a function panics.
+//!
+//! Pseudocode:<br>
+//! a_function(a) panics
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! fn f(i: i8) -> i8 {
+//!     if i < 0 { panic!("negative") }
+//!     i
+//! }
+//!
+//! let a: i8 = -1;
+//! assert_fn_panics!(f, a);
+//! ```
+//!
+//! The macro calls the function via a hidden wrapper around
+//! [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html),
+//! which keeps the expected panic out of the test output.
+//!
+//! If the function does not panic, then the message shows the return value,
+//! so the return type must implement `Debug`.
+//!
+//! # Module macros
+//!
+//! * [`assert_fn_panics`](macro@crate::assert_fn_panics)
+//! * [`assert_fn_panics_as_result`](macro@crate::assert_fn_panics_as_result)
+//! * [`debug_assert_fn_panics`](macro@crate::debug_assert_fn_panics)
+
+/// Assert a function panics.
+///
+/// Pseudocode:<br>
+/// a_function(a) panics
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_fn_panics`](macro@crate::assert_fn_panics)
+/// * [`assert_fn_panics_as_result`](macro@crate::assert_fn_panics_as_result)
+/// * [`debug_assert_fn_panics`](macro@crate::debug_assert_fn_panics)
+///
+#[macro_export]
+macro_rules! assert_fn_panics_as_result {
+
+    //// Arity 1
+
+    ($a_function:path, $a_param:expr $(,)?) => {{
+        match ($a_param) {
+            a_param => {
+                let a_param_debug = format!("{:?}", a_param);
+                match $crate::assert_fn::catch_unwind_silent(move || $a_function(a_param)) {
+                    Err(_) => Ok(()),
+                    Ok(a) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_fn_panics!(a_function, a_param)`\n",
+                                    $crate::assertables_url_line!("assert_fn_panics"),
+                                    " a_function label: `{}`,\n",
+                                    "    a_param label: `{}`,\n",
+                                    "    a_param debug: `{}`,\n",
+                                    "                a: `{:?}`"
+                                ),
+                                stringify!($a_function),
+                                stringify!($a_param),
+                                a_param_debug,
+                                a
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+
+    //// Arity 0
+
+    ($a_function:path) => {{
+        match $crate::assert_fn::catch_unwind_silent(|| $a_function()) {
+            Err(_) => Ok(()),
+            Ok(a) => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_fn_panics!(a_function)`\n",
+                            $crate::assertables_url_line!("assert_fn_panics"),
+                            " a_function label: `{}`,\n",
+                            "                a: `{:?}`"
+                        ),
+                        stringify!($a_function),
+                        a
+                    )
+                )
+            }
+        }
+    }};
+
+}
+
+#[cfg(test)]
+mod test_assert_fn_panics_as_result {
+
+    mod arity_1 {
+
+        fn f(i: i8) -> i8 {
+            if i < 0 {
+                panic!("negative");
+            }
+            i
+        }
+
+        #[test]
+        fn success() {
+            let a: i8 = -1;
+            let actual = assert_fn_panics_as_result!(f, a);
+            assert_eq!(actual.unwrap(), ());
+        }
+
+        #[test]
+        fn failure() {
+            let a: i8 = 1;
+            let actual = assert_fn_panics_as_result!(f, a);
+            let message = concat!(
+                "assertion failed: `assert_fn_panics!(a_function, a_param)`\n",
+                assertables_url_line!("assert_fn_panics"),
+                " a_function label: `f`,\n",
+                "    a_param label: `a`,\n",
+                "    a_param debug: `1`,\n",
+                "                a: `1`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn evaluates_each_expression_once() {
+            use std::sync::atomic::{AtomicU32, Ordering};
+            static A: AtomicU32 = AtomicU32::new(0);
+            fn a() -> i8 {
+                A.fetch_add(1, Ordering::SeqCst);
+                -1
+            }
+            let _ = assert_fn_panics_as_result!(f, a());
+            assert_eq!(A.load(Ordering::SeqCst), 1);
+        }
+    }
+
+    mod arity_0 {
+
+        fn f() -> i8 {
+            panic!("boom");
+        }
+
+        fn g() -> i8 {
+            1
+        }
+
+        #[test]
+        fn success() {
+            let actual = assert_fn_panics_as_result!(f);
+            assert_eq!(actual.unwrap(), ());
+        }
+
+        #[test]
+        fn failure() {
+            let actual = assert_fn_panics_as_result!(g);
+            let message = concat!(
+                "assertion failed: `assert_fn_panics!(a_function)`\n",
+                assertables_url_line!("assert_fn_panics"),
+                " a_function label: `g`,\n",
+                "                a: `1`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+    }
+}
+
+/// Assert a function panics.
+///
+/// Pseudocode:<br>
+/// a_function(a) panics
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// fn f(i: i8) -> i8 {
+///     if i < 0 { panic!("negative") }
+///     i
+/// }
+///
+/// # fn main() {
+/// let a: i8 = -1;
+/// assert_fn_panics!(f, a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: i8 = 1;
+/// assert_fn_panics!(f, a);
+/// # });
+/// // assertion failed: `assert_fn_panics!(a_function, a_param)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_panics.html
+/// //  a_function label: `f`,
+/// //     a_param label: `a`,
+/// //     a_param debug: `1`,
+/// //                 a: `1`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_fn_panics!(a_function, a_param)`\n",
+/// #     assertables_url_line!("assert_fn_panics"),
+/// #     " a_function label: `f`,\n",
+/// #     "    a_param label: `a`,\n",
+/// #     "    a_param debug: `1`,\n",
+/// #     "                a: `1`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The macro calls the function via a hidden wrapper around
+/// [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html),
+/// which keeps the expected panic out of the test output.
+///
+/// If the function does not panic, then the message shows the return value,
+/// so the return type must implement `Debug`.
+///
+/// # Module macros
+///
+/// * [`assert_fn_panics`](macro@crate::assert_fn_panics)
+/// * [`assert_fn_panics_as_result`](macro@crate::assert_fn_panics_as_result)
+/// * [`debug_assert_fn_panics`](macro@crate::debug_assert_fn_panics)
+///
+#[macro_export]
+macro_rules! assert_fn_panics {
+
+    //// Arity 1
+
+    ($a_function:path, $a_param:expr $(,)?) => {{
+        match $crate::assert_fn_panics_as_result!($a_function, $a_param) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+
+    ($a_function:path, $a_param:expr, $($message:tt)+) => {{
+        match $crate::assert_fn_panics_as_result!($a_function, $a_param) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+
+    //// Arity 0
+
+    ($a_function:path) => {{
+        match $crate::assert_fn_panics_as_result!($a_function) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+
+}
+
+#[cfg(test)]
+mod test_assert_fn_panics {
+    use std::panic;
+
+    mod arity_1 {
+        use super::*;
+
+        fn f(i: i8) -> i8 {
+            if i < 0 {
+                panic!("negative");
+            }
+            i
+        }
+
+        #[test]
+        fn success() {
+            let a: i8 = -1;
+            let actual = assert_fn_panics!(f, a);
+            assert_eq!(actual, ());
+        }
+
+        #[test]
+        fn failure() {
+            let result = panic::catch_unwind(|| {
+                let a: i8 = 1;
+                let _actual = assert_fn_panics!(f, a);
+            });
+            let message = concat!(
+                "assertion failed: `assert_fn_panics!(a_function, a_param)`\n",
+                assertables_url_line!("assert_fn_panics"),
+                " a_function label: `f`,\n",
+                "    a_param label: `a`,\n",
+                "    a_param debug: `1`,\n",
+                "                a: `1`"
+            );
+            assert_eq!(
+                result
+                    .unwrap_err()
+                    .downcast::<String>()
+                    .unwrap()
+                    .to_string(),
+                message
+            );
+        }
+    }
+
+    mod arity_0 {
+        use super::*;
+
+        fn f() -> i8 {
+            panic!("boom");
+        }
+
+        fn g() -> i8 {
+            1
+        }
+
+        #[test]
+        fn success() {
+            let actual = assert_fn_panics!(f);
+            assert_eq!(actual, ());
+        }
+
+        #[test]
+        fn failure() {
+            let result = panic::catch_unwind(|| {
+                let _actual = assert_fn_panics!(g);
+            });
+            let message = concat!(
+                "assertion failed: `assert_fn_panics!(a_function)`\n",
+                assertables_url_line!("assert_fn_panics"),
+                " a_function label: `g`,\n",
+                "                a: `1`"
+            );
+            assert_eq!(
+                result
+                    .unwrap_err()
+                    .downcast::<String>()
+                    .unwrap()
+                    .to_string(),
+                message
+            );
+        }
+    }
+}
+
+/// Assert a function panics.
+///
+/// Pseudocode:<br>
+/// a_function(a) panics
+///
+/// This macro provides the same statements as [`assert_fn_panics`](macro.assert_fn_panics.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_fn_panics`](macro@crate::assert_fn_panics)
+/// * [`assert_fn_panics_as_result`](macro@crate::assert_fn_panics_as_result)
+/// * [`debug_assert_fn_panics`](macro@crate::debug_assert_fn_panics)
+///
+#[macro_export]
+macro_rules! debug_assert_fn_panics {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_fn_panics!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,503 @@
+//! Assert a function panics with a message that contains an expression.
+//!
+//! Pseudocode:<br>
+//! a_function(a) panics with a message that contains x
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! fn f(i: i8) -> i8 {
+//!     if i < 0 { panic!("negative") }
+//!     i
+//! }
+//!
+//! let a: i8 = -1;
+//! let x = "neg";
+//! assert_fn_panics_message_contains_x!(f, a, x);
+//! ```
+//!
+//! The panic message is the panic payload, when the payload is a `&str`,
+//! such as from `panic!("…")`, or a `String`, such as from `panic!("{}", …)`.
+//! Any other payload has no message, so the assertion fails.
+//!
+//! The macro calls the function via a hidden wrapper around
+//! [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html),
+//! which keeps the expected panic out of the test output.
+//!
+//! If the function does not panic, then the message shows the return value,
+//! so the return type must implement `Debug`.
+//!
+//! # Module macros
+//!
+//! * [`assert_fn_panics_message_contains_x`](macro@crate::assert_fn_panics_message_contains_x)
+//! * [`assert_fn_panics_message_contains_x_as_result`](macro@crate::assert_fn_panics_message_contains_x_as_result)
+//! * [`debug_assert_fn_panics_message_contains_x`](macro@crate::debug_assert_fn_panics_message_contains_x)
+
+/// Assert a function panics with a message that contains an expression.
+///
+/// Pseudocode:<br>
+/// a_function(a) panics with a message that contains x
+///
+/// * If true, return Result `Ok(panic message)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_fn_panics_message_contains_x`](macro@crate::assert_fn_panics_message_contains_x)
+/// * [`assert_fn_panics_message_contains_x_as_result`](macro@crate::assert_fn_panics_message_contains_x_as_result)
+/// * [`debug_assert_fn_panics_message_contains_x`](macro@crate::debug_assert_fn_panics_message_contains_x)
+///
+#[macro_export]
+macro_rules! assert_fn_panics_message_contains_x_as_result {
+
+    //// Arity 1
+
+    ($a_function:path, $a_param:expr, $x:expr $(,)?) => {{
+        match ($a_param, &$x) {
+            (a_param, x) => {
+                let a_param_debug = format!("{:?}", a_param);
+                match $crate::assert_fn::catch_unwind_silent(move || $a_function(a_param)) {
+                    Err(payload) => {
+                        match $crate::assert_fn::panic_payload_message(&*payload) {
+                            Some(message) if message.contains(x) => Ok(message),
+                            message => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_fn_panics_message_contains_x!(a_function, a_param, x)`\n",
+                                            $crate::assertables_url_line!("assert_fn_panics_message_contains_x"),
+                                            " a_function label: `{}`,\n",
+                                            "    a_param label: `{}`,\n",
+                                            "    a_param debug: `{}`,\n",
+                                            "          x label: `{}`,\n",
+                                            "          x debug: `{:?}`,\n",
+                                            "    panic message: `{:?}`"
+                                        ),
+                                        stringify!($a_function),
+                                        stringify!($a_param),
+                                        a_param_debug,
+                                        stringify!($x),
+                                        x,
+                                        message
+                                    )
+                                )
+                            }
+                        }
+                    }
+                    Ok(a) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_fn_panics_message_contains_x!(a_function, a_param, x)`\n",
+                                    $crate::assertables_url_line!("assert_fn_panics_message_contains_x"),
+                                    " a_function label: `{}`,\n",
+                                    "    a_param label: `{}`,\n",
+                                    "    a_param debug: `{}`,\n",
+                                    "          x label: `{}`,\n",
+                                    "          x debug: `{:?}`,\n",
+                                    "                a: `{:?}`"
+                                ),
+                                stringify!($a_function),
+                                stringify!($a_param),
+                                a_param_debug,
+                                stringify!($x),
+                                x,
+                                a
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+
+    //// Arity 0
+
+    ($a_function:path, $x:expr $(,)?) => {{
+        match (&$x) {
+            x => {
+            match $crate::assert_fn::catch_unwind_silent(|| $a_function()) {
+                Err(payload) => {
+                    match $crate::assert_fn::panic_payload_message(&*payload) {
+                        Some(message) if message.contains(x) => Ok(message),
+                        message => {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_fn_panics_message_contains_x!(a_function, x)`\n",
+                                        $crate::assertables_url_line!("assert_fn_panics_message_contains_x"),
+                                        " a_function label: `{}`,\n",
+                                        "          x label: `{}`,\n",
+                                        "          x debug: `{:?}`,\n",
+                                        "    panic message: `{:?}`"
+                                    ),
+                                    stringify!($a_function),
+                                    stringify!($x),
+                                    x,
+                                    message
+                                )
+                            )
+                        }
+                    }
+                }
+                Ok(a) => {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_fn_panics_message_contains_x!(a_function, x)`\n",
+                                $crate::assertables_url_line!("assert_fn_panics_message_contains_x"),
+                                " a_function label: `{}`,\n",
+                                "          x label: `{}`,\n",
+                                "          x debug: `{:?}`,\n",
+                                "                a: `{:?}`"
+                            ),
+                            stringify!($a_function),
+                            stringify!($x),
+                            x,
+                            a
+                        )
+                    )
+                }
+            }
+            }
+        }
+    }};
+
+}
+
+#[cfg(test)]
+mod test_assert_fn_panics_message_contains_x_as_result {
+
+    mod arity_1 {
+
+        fn f(i: i8) -> i8 {
+            if i < 0 {
+                panic!("negative");
+            }
+            if i == 0 {
+                panic!("{} is zero", i);
+            }
+            if i > 100 {
+                std::panic::panic_any(i);
+            }
+            i
+        }
+
+        #[test]
+        fn success() {
+            let a: i8 = -1;
+            let x = "neg";
+            let actual = assert_fn_panics_message_contains_x_as_result!(f, a, x);
+            assert_eq!(actual.unwrap(), "negative");
+        }
+
+        #[test]
+        fn success_with_string_payload() {
+            let a: i8 = 0;
+            let x = "zero";
+            let actual = assert_fn_panics_message_contains_x_as_result!(f, a, x);
+            assert_eq!(actual.unwrap(), "0 is zero");
+        }
+
+        #[test]
+        fn failure_because_message_does_not_contain() {
+            let a: i8 = -1;
+            let x = "zz";
+            let actual = assert_fn_panics_message_contains_x_as_result!(f, a, x);
+            let message = concat!(
+                "assertion failed: `assert_fn_panics_message_contains_x!(a_function, a_param, x)`\n",
+                assertables_url_line!("assert_fn_panics_message_contains_x"),
+                " a_function label: `f`,\n",
+                "    a_param label: `a`,\n",
+                "    a_param debug: `-1`,\n",
+                "          x label: `x`,\n",
+                "          x debug: `\"zz\"`,\n",
+                "    panic message: `Some(\"negative\")`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn failure_because_payload_is_not_a_string() {
+            let a: i8 = 101;
+            let x = "zz";
+            let actual = assert_fn_panics_message_contains_x_as_result!(f, a, x);
+            let message = concat!(
+                "assertion failed: `assert_fn_panics_message_contains_x!(a_function, a_param, x)`\n",
+                assertables_url_line!("assert_fn_panics_message_contains_x"),
+                " a_function label: `f`,\n",
+                "    a_param label: `a`,\n",
+                "    a_param debug: `101`,\n",
+                "          x label: `x`,\n",
+                "          x debug: `\"zz\"`,\n",
+                "    panic message: `None`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn failure_because_no_panic() {
+            let a: i8 = 1;
+            let x = "neg";
+            let actual = assert_fn_panics_message_contains_x_as_result!(f, a, x);
+            let message = concat!(
+                "assertion failed: `assert_fn_panics_message_contains_x!(a_function, a_param, x)`\n",
+                assertables_url_line!("assert_fn_panics_message_contains_x"),
+                " a_function label: `f`,\n",
+                "    a_param label: `a`,\n",
+                "    a_param debug: `1`,\n",
+                "          x label: `x`,\n",
+                "          x debug: `\"neg\"`,\n",
+                "                a: `1`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+    }
+
+    mod arity_0 {
+
+        fn f() -> i8 {
+            panic!("boom");
+        }
+
+        fn g() -> i8 {
+            1
+        }
+
+        #[test]
+        fn success() {
+            let x = "oo";
+            let actual = assert_fn_panics_message_contains_x_as_result!(f, x);
+            assert_eq!(actual.unwrap(), "boom");
+        }
+
+        #[test]
+        fn failure() {
+            let x = "oo";
+            let actual = assert_fn_panics_message_contains_x_as_result!(g, x);
+            let message = concat!(
+                "assertion failed: `assert_fn_panics_message_contains_x!(a_function, x)`\n",
+                assertables_url_line!("assert_fn_panics_message_contains_x"),
+                " a_function label: `g`,\n",
+                "          x label: `x`,\n",
+                "          x debug: `\"oo\"`,\n",
+                "                a: `1`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+    }
+}
+
+/// Assert a function panics with a message that contains an expression.
+///
+/// Pseudocode:<br>
+/// a_function(a) panics with a message that contains x
+///
+/// * If true, return `panic message`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// fn f(i: i8) -> i8 {
+///     if i < 0 { panic!("negative") }
+///     i
+/// }
+///
+/// # fn main() {
+/// let a: i8 = -1;
+/// let x = "neg";
+/// assert_fn_panics_message_contains_x!(f, a, x);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: i8 = -1;
+/// let x = "zz";
+/// assert_fn_panics_message_contains_x!(f, a, x);
+/// # });
+/// // assertion failed: `assert_fn_panics_message_contains_x!(a_function, a_param, x)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_panics_message_contains_x.html
+/// //  a_function label: `f`,
+/// //     a_param label: `a`,
+/// //     a_param debug: `-1`,
+/// //           x label: `x`,
+/// //           x debug: `\"zz\"`,
+/// //     panic message: `Some(\"negative\")`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_fn_panics_message_contains_x!(a_function, a_param, x)`\n",
+/// #     assertables_url_line!("assert_fn_panics_message_contains_x"),
+/// #     " a_function label: `f`,\n",
+/// #     "    a_param label: `a`,\n",
+/// #     "    a_param debug: `-1`,\n",
+/// #     "          x label: `x`,\n",
+/// #     "          x debug: `\"zz\"`,\n",
+/// #     "    panic message: `Some(\"negative\")`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The panic message is the panic payload, when the payload is a `&str`,
+/// such as from `panic!("…")`, or a `String`, such as from `panic!("{}", …)`.
+/// Any other payload has no message, so the assertion fails.
+///
+/// The macro calls the function via a hidden wrapper around
+/// [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html),
+/// which keeps the expected panic out of the test output.
+///
+/// If the function does not panic, then the message shows the return value,
+/// so the return type must implement `Debug`.
+///
+/// # Module macros
+///
+/// * [`assert_fn_panics_message_contains_x`](macro@crate::assert_fn_panics_message_contains_x)
+/// * [`assert_fn_panics_message_contains_x_as_result`](macro@crate::assert_fn_panics_message_contains_x_as_result)
+/// * [`debug_assert_fn_panics_message_contains_x`](macro@crate::debug_assert_fn_panics_message_contains_x)
+///
+#[macro_export]
+macro_rules! assert_fn_panics_message_contains_x {
+
+    //// Arity 1
+
+    ($a_function:path, $a_param:expr, $x:expr $(,)?) => {{
+        match $crate::assert_fn_panics_message_contains_x_as_result!($a_function, $a_param, $x) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+
+    ($a_function:path, $a_param:expr, $x:expr, $($message:tt)+) => {{
+        match $crate::assert_fn_panics_message_contains_x_as_result!($a_function, $a_param, $x) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+
+    //// Arity 0
+
+    ($a_function:path, $x:expr $(,)?) => {{
+        match $crate::assert_fn_panics_message_contains_x_as_result!($a_function, $x) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+
+}
+
+#[cfg(test)]
+mod test_assert_fn_panics_message_contains_x {
+    use std::panic;
+
+    mod arity_1 {
+        use super::*;
+
+        fn f(i: i8) -> i8 {
+            if i < 0 {
+                panic!("negative");
+            }
+            i
+        }
+
+        #[test]
+        fn success() {
+            let a: i8 = -1;
+            let x = "neg";
+            let actual = assert_fn_panics_message_contains_x!(f, a, x);
+            assert_eq!(actual, "negative");
+        }
+
+        #[test]
+        fn failure() {
+            let result = panic::catch_unwind(|| {
+                let a: i8 = -1;
+                let x = "zz";
+                let _actual = assert_fn_panics_message_contains_x!(f, a, x);
+            });
+            let message = concat!(
+                "assertion failed: `assert_fn_panics_message_contains_x!(a_function, a_param, x)`\n",
+                assertables_url_line!("assert_fn_panics_message_contains_x"),
+                " a_function label: `f`,\n",
+                "    a_param label: `a`,\n",
+                "    a_param debug: `-1`,\n",
+                "          x label: `x`,\n",
+                "          x debug: `\"zz\"`,\n",
+                "    panic message: `Some(\"negative\")`"
+            );
+            assert_eq!(
+                result
+                    .unwrap_err()
+                    .downcast::<String>()
+                    .unwrap()
+                    .to_string(),
+                message
+            );
+        }
+    }
+
+    mod arity_0 {
+        use super::*;
+
+        fn f() -> i8 {
+            panic!("boom");
+        }
+
+        #[test]
+        fn success() {
+            let x = "oo";
+            let actual = assert_fn_panics_message_contains_x!(f, x);
+            assert_eq!(actual, "boom");
+        }
+    }
+}
+
+/// Assert a function panics with a message that contains an expression.
+///
+/// Pseudocode:<br>
+/// a_function(a) panics with a message that contains x
+///
+/// This macro provides the same statements as [`assert_fn_panics_message_contains_x`](macro.assert_fn_panics_message_contains_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_fn_panics_message_contains_x`](macro@crate::assert_fn_panics_message_contains_x)
+/// * [`assert_fn_panics_message_contains_x_as_result`](macro@crate::assert_fn_panics_message_contains_x_as_result)
+/// * [`debug_assert_fn_panics_message_contains_x`](macro@crate::debug_assert_fn_panics_message_contains_x)
+///
+#[macro_export]
+macro_rules! debug_assert_fn_panics_message_contains_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_fn_panics_message_contains_x!($($arg)*);
+        }
+    };
+}
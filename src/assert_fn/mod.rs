@@ -21,6 +21,29 @@
 //! * [`assert_fn_le_x!(function, expr)`](macro@crate::assert_fn_le_x) ≈ function() ≤ expr
 //! * [`assert_fn_lt_x!(function, expr)`](macro@crate::assert_fn_lt_x) ≈ function() < expr
 //!
+//! Check a function panics:
+//!
+//! * [`assert_fn_panics!(function)`](macro@crate::assert_fn_panics) ≈ function() panics
+//! * [`assert_fn_panics_message_contains_x!(function, x)`](macro@crate::assert_fn_panics_message_contains_x) ≈ function() panics with a message that contains x
+//!
+//! # Arity
+//!
+//! Each macro accepts functions that take one argument, such as
+//! `assert_fn_eq!(f, a, g, b)`, which calls `f(a)` and `g(b)`,
+//! or functions that take no arguments, such as `assert_fn_eq!(f, g)`,
+//! which calls `f()` and `g()`.
+//!
+//! For functions that take more than one argument, put each function's
+//! arguments after `args =`, such as
+//! `assert_fn_eq!(f, args = (a1, a2), g, args = (b1, b2))`, which calls
+//! `f(a1, a2)` and `g(b1, b2)`, and such as
+//! `assert_fn_eq_x!(f, args = (a1, a2), expr)`, which calls `f(a1, a2)`.
+//! Without `args =`, a parenthesized argument is one tuple argument, so
+//! `assert_fn_eq!(f, (1, 2), g, (1, 2))` calls `f((1, 2))` and `g((1, 2))`.
+//!
+//! Each function is a path, such as a function name or a closure that is
+//! bound to a name, such as `let f = || 1;`. An inline closure is not a path,
+//! so bind it to a name first.
 //!
 //! # Example
 //!
@@ -47,3 +70,92 @@ pub mod assert_fn_gt_x;
 pub mod assert_fn_le_x;
 pub mod assert_fn_lt_x;
 pub mod assert_fn_ne_x;
+
+// Compare panic
+pub mod assert_fn_panics;
+pub mod assert_fn_panics_message_contains_x;
+
+::std::thread_local! {
+    static PANIC_HOOK_SILENT: ::std::cell::Cell<bool> = const { ::std::cell::Cell::new(false) };
+    static PANIC_LOCATION: ::std::cell::RefCell<Option<String>> = const { ::std::cell::RefCell::new(None) };
+}
+
+static PANIC_HOOK_INSTALL: ::std::sync::Once = ::std::sync::Once::new();
+
+/// Call a function, catch any panic, and keep the panic out of the output.
+///
+/// The first call installs a panic hook that wraps the previous hook.
+/// The wrapper skips the previous hook only on a thread that is inside
+/// this function, so panics on other threads still print as usual.
+/// This avoids swapping the global hook per call, which would race
+/// when tests run in parallel.
+///
+/// Instead of printing, the wrapper saves the panic location, which
+/// [`panic_location`] returns after this function returns.
+#[doc(hidden)]
+pub fn catch_unwind_silent<F: FnOnce() -> R, R>(f: F) -> ::std::thread::Result<R> {
+    PANIC_HOOK_INSTALL.call_once(|| {
+        let previous = ::std::panic::take_hook();
+        ::std::panic::set_hook(Box::new(move |info| {
+            if PANIC_HOOK_SILENT.with(|silent| silent.get()) {
+                let location = info.location().map(|location| location.to_string());
+                PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+            } else {
+                previous(info)
+            }
+        }));
+    });
+    PANIC_LOCATION.with(|cell| cell.borrow_mut().take());
+    let was_silent = PANIC_HOOK_SILENT.with(|silent| silent.replace(true));
+    let result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(f));
+    PANIC_HOOK_SILENT.with(|silent| silent.set(was_silent));
+    result
+}
+
+/// Get the location of the most recent panic that [`catch_unwind_silent`]
+/// caught on this thread, such as `src/main.rs:1:2`, if the hook saw it.
+///
+/// The location is unavailable if other code replaced the panic hook
+/// after the first call of [`catch_unwind_silent`].
+#[doc(hidden)]
+pub fn panic_location() -> Option<String> {
+    PANIC_LOCATION.with(|cell| cell.borrow().clone())
+}
+
+/// Get the message of a panic payload, if the payload is a `&str` or `String`.
+///
+/// A panic via `panic!("…")` has a `&str` payload, and a panic via
+/// `panic!("{}", …)` with arguments has a `String` payload.
+#[doc(hidden)]
+pub fn panic_payload_message(payload: &(dyn ::std::any::Any + Send)) -> Option<String> {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        Some(String::from(*s))
+    } else {
+        payload.downcast_ref::<String>().cloned()
+    }
+}
+
+/// Call a function with parameters, evaluating each parameter once.
+///
+/// Return a tuple of the function output and the debug text of the
+/// parameters, such as `(1, 2)`. The debug text is made before the call,
+/// because the call moves the parameters.
+///
+/// Each recursion binds one parameter to a local named `param`; macro
+/// hygiene keeps each of these locals distinct.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assert_fn_impl_call {
+    (@bind $function:path; [$($bound:ident)*]; $param:expr $(, $rest:expr)*) => {
+        match $param {
+            param => $crate::assert_fn_impl_call!(@bind $function; [$($bound)* param]; $($rest),*),
+        }
+    };
+    (@bind $function:path; [$($bound:ident)*];) => {{
+        let params_debug = format!("{:?}", ($(&$bound,)*));
+        ($function($($bound),*), params_debug)
+    }};
+    ($function:path, $($param:expr),+ $(,)?) => {
+        $crate::assert_fn_impl_call!(@bind $function; []; $($param),+)
+    };
+}
@@ -45,21 +45,22 @@ macro_rules! assert_fn_err_eq_x_as_result {
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
-        match (&$a_function, &$a_param, &$b_expr) {
-            (_a_function, a_param, b_expr) => {
-                match ($a_function($a_param)) {
+        match ($a_param, &$b_expr) {
+            (a_param, b_expr) => {
+                let a_param_debug = format!("{:?}", a_param);
+                match ($a_function(a_param)) {
                     Err(a) => {
-                        if a == $b_expr {
+                        if a == *b_expr {
                             Ok(a)
                         } else {
                             Err(
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_eq_x!(a_function, a_param, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                                        $crate::assertables_url_line!("assert_fn_err_eq_x"),
                                         " a_function label: `{}`,\n",
                                         "    a_param label: `{}`,\n",
-                                        "    a_param debug: `{:?}`,\n",
+                                        "    a_param debug: `{}`,\n",
                                         "     b_expr label: `{}`,\n",
                                         "     b_expr debug: `{:?}`,\n",
                                         "                a: `{:?}`,\n",
@@ -67,11 +68,11 @@ macro_rules! assert_fn_err_eq_x_as_result {
                                     ),
                                     stringify!($a_function),
                                     stringify!($a_param),
-                                    a_param,
+                                    a_param_debug,
                                     stringify!($b_expr),
                                     b_expr,
                                     a,
-                                    $b_expr
+                                    *b_expr
                                 )
                             )
                         }
@@ -81,17 +82,17 @@ macro_rules! assert_fn_err_eq_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_eq_x!(a_function, a_param, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                                    $crate::assertables_url_line!("assert_fn_err_eq_x"),
                                     " a_function label: `{}`,\n",
                                     "    a_param label: `{}`,\n",
-                                    "    a_param debug: `{:?}`,\n",
+                                    "    a_param debug: `{}`,\n",
                                     "     b_expr label: `{}`,\n",
                                     "     b_expr debug: `{:?}`,\n",
                                     "                a: `{:?}`",
                                 ),
                                 stringify!($a_function),
                                 stringify!($a_param),
-                                a_param,
+                                a_param_debug,
                                 stringify!($b_expr),
                                 b_expr,
                                 a
@@ -110,14 +111,14 @@ macro_rules! assert_fn_err_eq_x_as_result {
             (_a_function, b_expr) => {
                 match ($a_function()) {
                     Err(a) => {
-                        if a == $b_expr {
+                        if a == *b_expr {
                             Ok(a)
                         } else {
                             Err(
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_eq_x!(a_function, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                                        $crate::assertables_url_line!("assert_fn_err_eq_x"),
                                         " a_function label: `{}`,\n",
                                         "     b_expr label: `{}`,\n",
                                         "     b_expr debug: `{:?}`,\n",
@@ -128,7 +129,7 @@ macro_rules! assert_fn_err_eq_x_as_result {
                                     stringify!($b_expr),
                                     b_expr,
                                     a,
-                                    $b_expr
+                                    *b_expr
                                 )
                             )
                         }
@@ -138,7 +139,7 @@ macro_rules! assert_fn_err_eq_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_eq_x!(a_function, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                                    $crate::assertables_url_line!("assert_fn_err_eq_x"),
                                     " a_function label: `{}`,\n",
                                     "     b_expr label: `{}`,\n",
                                     "     b_expr debug: `{:?}`,\n",
@@ -159,6 +160,7 @@ macro_rules! assert_fn_err_eq_x_as_result {
 
 #[cfg(test)]
 mod test_assert_fn_err_eq_x_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     mod arity_1 {
 
@@ -181,7 +183,7 @@ mod test_assert_fn_err_eq_x_as_result {
             let actual = assert_fn_err_eq_x_as_result!(f, a, b);
             let message = concat!(
                 "assertion failed: `assert_fn_err_eq_x!(a_function, a_param, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                assertables_url_line!("assert_fn_err_eq_x"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -212,7 +214,7 @@ mod test_assert_fn_err_eq_x_as_result {
             let actual = assert_fn_err_eq_x_as_result!(f, b);
             let message = concat!(
                 "assertion failed: `assert_fn_err_eq_x!(a_function, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                assertables_url_line!("assert_fn_err_eq_x"),
                 " a_function label: `f`,\n",
                 "     b_expr label: `b`,\n",
                 "     b_expr debug: `2`,\n",
@@ -222,6 +224,39 @@ mod test_assert_fn_err_eq_x_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static F: AtomicU32 = AtomicU32::new(0);
+        static P: AtomicU32 = AtomicU32::new(0);
+        static X: AtomicU32 = AtomicU32::new(0);
+        fn f(i: i8) -> Result<i8, i8> {
+            F.fetch_add(1, Ordering::SeqCst);
+            Err(i)
+        }
+        fn p(i: i8) -> i8 {
+            P.fetch_add(1, Ordering::SeqCst);
+            i
+        }
+        fn x(i: i8) -> i8 {
+            X.fetch_add(1, Ordering::SeqCst);
+            i
+        }
+        // Success
+        assert!(assert_fn_err_eq_x_as_result!(f, p(1), x(1)).is_ok());
+        // Failure
+        assert!(assert_fn_err_eq_x_as_result!(f, p(1), x(2)).is_err());
+        assert_eq!(F.load(Ordering::SeqCst), 2);
+        assert_eq!(P.load(Ordering::SeqCst), 2);
+        assert_eq!(X.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn eq_with_closure() {
+        let a = || -> Result<i8, i8> { Err(1) };
+        let actual = assert_fn_err_eq_x_as_result!(a, 1);
+        assert_eq!(actual.unwrap(), 1);
+    }
 }
 
 /// Assert a function error is equal to an expression.
@@ -269,7 +304,7 @@ mod test_assert_fn_err_eq_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fn_err_eq_x!(a_function, a_param, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_eq_x.html\n",
+/// #     assertables_url_line!("assert_fn_err_eq_x"),
 /// #     " a_function label: `f`,\n",
 /// #     "    a_param label: `a`,\n",
 /// #     "    a_param debug: `10`,\n",
@@ -296,14 +331,14 @@ macro_rules! assert_fn_err_eq_x {
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_fn_err_eq_x_as_result!($a_function, $a_param, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $a_param:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fn_err_eq_x_as_result!($a_function, $a_param, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -312,14 +347,14 @@ macro_rules! assert_fn_err_eq_x {
     ($a_function:path, $b_expr:expr $(,)?) => {{
         match $crate::assert_fn_err_eq_x_as_result!($a_function, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fn_err_eq_x_as_result!($a_function, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -353,7 +388,7 @@ mod test_assert_fn_err_eq_x {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_err_eq_x!(a_function, a_param, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                assertables_url_line!("assert_fn_err_eq_x"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -394,7 +429,7 @@ mod test_assert_fn_err_eq_x {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_err_eq_x!(a_function, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_eq_x.html\n",
+                assertables_url_line!("assert_fn_err_eq_x"),
                 " a_function label: `f`,\n",
                 "     b_expr label: `b`,\n",
                 "     b_expr debug: `2`,\n",
@@ -447,7 +482,7 @@ mod test_assert_fn_err_eq_x {
 #[macro_export]
 macro_rules! debug_assert_fn_err_eq_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_fn_err_eq_x!($($arg)*);
         }
     };
@@ -49,21 +49,22 @@ macro_rules! assert_fn_err_le_x_as_result {
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
-        match (&$a_function, &$a_param, &$b_expr) {
-            (_a_function, a_param, b_expr) => {
-                match ($a_function($a_param)) {
+        match ($a_param, &$b_expr) {
+            (a_param, b_expr) => {
+                let a_param_debug = format!("{:?}", a_param);
+                match ($a_function(a_param)) {
                     Err(a) => {
-                        if a <= $b_expr {
+                        if a <= *b_expr {
                             Ok(a)
                         } else {
                             Err(
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_le_x!(a_function, a_param, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_le_x.html\n",
+                                        $crate::assertables_url_line!("assert_fn_err_le_x"),
                                         " a_function label: `{}`,\n",
                                         "    a_param label: `{}`,\n",
-                                        "    a_param debug: `{:?}`,\n",
+                                        "    a_param debug: `{}`,\n",
                                         "     b_expr label: `{}`,\n",
                                         "     b_expr debug: `{:?}`,\n",
                                         "                a: `{:?}`,\n",
@@ -71,11 +72,11 @@ macro_rules! assert_fn_err_le_x_as_result {
                                     ),
                                     stringify!($a_function),
                                     stringify!($a_param),
-                                    a_param,
+                                    a_param_debug,
                                     stringify!($b_expr),
                                     b_expr,
                                     a,
-                                    $b_expr
+                                    *b_expr
                                 )
                             )
                         }
@@ -85,17 +86,17 @@ macro_rules! assert_fn_err_le_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_le_x!(a_function, a_param, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_le_x.html\n",
+                                    $crate::assertables_url_line!("assert_fn_err_le_x"),
                                     " a_function label: `{}`,\n",
                                     "    a_param label: `{}`,\n",
-                                    "    a_param debug: `{:?}`,\n",
+                                    "    a_param debug: `{}`,\n",
                                     "     b_expr label: `{}`,\n",
                                     "     b_expr debug: `{:?}`,\n",
                                     "                a: `{:?}`",
                                 ),
                                 stringify!($a_function),
                                 stringify!($a_param),
-                                a_param,
+                                a_param_debug,
                                 stringify!($b_expr),
                                 b_expr,
                                 a
@@ -114,14 +115,14 @@ macro_rules! assert_fn_err_le_x_as_result {
             (_a_function, b_expr) => {
                 match ($a_function()) {
                     Err(a) => {
-                        if a <= $b_expr {
+                        if a <= *b_expr {
                             Ok(a)
                         } else {
                             Err(
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_le_x!(a_function, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_le_x.html\n",
+                                        $crate::assertables_url_line!("assert_fn_err_le_x"),
                                         " a_function label: `{}`,\n",
                                         "     b_expr label: `{}`,\n",
                                         "     b_expr debug: `{:?}`,\n",
@@ -132,7 +133,7 @@ macro_rules! assert_fn_err_le_x_as_result {
                                     stringify!($b_expr),
                                     b_expr,
                                     a,
-                                    $b_expr
+                                    *b_expr
                                 )
                             )
                         }
@@ -142,7 +143,7 @@ macro_rules! assert_fn_err_le_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_le_x!(a_function, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_le_x.html\n",
+                                    $crate::assertables_url_line!("assert_fn_err_le_x"),
                                     " a_function label: `{}`,\n",
                                     "     b_expr label: `{}`,\n",
                                     "     b_expr debug: `{:?}`,\n",
@@ -164,6 +165,7 @@ macro_rules! assert_fn_err_le_x_as_result {
 
 #[cfg(test)]
 mod test_assert_fn_err_le_x_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     mod arity_1 {
 
@@ -194,7 +196,7 @@ mod test_assert_fn_err_le_x_as_result {
             let actual = assert_fn_err_le_x_as_result!(f, a, b);
             let message = concat!(
                 "assertion failed: `assert_fn_err_le_x!(a_function, a_param, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_le_x.html\n",
+                assertables_url_line!("assert_fn_err_le_x"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `2`,\n",
@@ -233,7 +235,7 @@ mod test_assert_fn_err_le_x_as_result {
             let actual = assert_fn_err_le_x_as_result!(f, b);
             let message = concat!(
                 "assertion failed: `assert_fn_err_le_x!(a_function, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_le_x.html\n",
+                assertables_url_line!("assert_fn_err_le_x"),
                 " a_function label: `f`,\n",
                 "     b_expr label: `b`,\n",
                 "     b_expr debug: `0`,\n",
@@ -243,6 +245,32 @@ mod test_assert_fn_err_le_x_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static F: AtomicU32 = AtomicU32::new(0);
+        static P: AtomicU32 = AtomicU32::new(0);
+        static X: AtomicU32 = AtomicU32::new(0);
+        fn f(i: i8) -> Result<i8, i8> {
+            F.fetch_add(1, Ordering::SeqCst);
+            Err(i)
+        }
+        fn p(i: i8) -> i8 {
+            P.fetch_add(1, Ordering::SeqCst);
+            i
+        }
+        fn x(i: i8) -> i8 {
+            X.fetch_add(1, Ordering::SeqCst);
+            i
+        }
+        // Success
+        assert!(assert_fn_err_le_x_as_result!(f, p(1), x(1)).is_ok());
+        // Failure
+        assert!(assert_fn_err_le_x_as_result!(f, p(2), x(1)).is_err());
+        assert_eq!(F.load(Ordering::SeqCst), 2);
+        assert_eq!(P.load(Ordering::SeqCst), 2);
+        assert_eq!(X.load(Ordering::SeqCst), 2);
+    }
 }
 
 /// Assert a function error is less than or equal to an expression.
@@ -290,7 +318,7 @@ mod test_assert_fn_err_le_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fn_err_le_x!(a_function, a_param, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_le_x.html\n",
+/// #     assertables_url_line!("assert_fn_err_le_x"),
 /// #     " a_function label: `f`,\n",
 /// #     "    a_param label: `a`,\n",
 /// #     "    a_param debug: `20`,\n",
@@ -317,14 +345,14 @@ macro_rules! assert_fn_err_le_x {
     ($a_function:path, $a_param:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_fn_err_le_x_as_result!($a_function, $a_param, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $a_param:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fn_err_le_x_as_result!($a_function, $a_param, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -333,14 +361,14 @@ macro_rules! assert_fn_err_le_x {
     ($a_function:path, $b_expr:expr $(,)?) => {{
         match $crate::assert_fn_err_le_x_as_result!($a_function, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fn_err_le_x_as_result!($a_function, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -381,7 +409,7 @@ mod test_assert_fn_err_le_x {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_err_le_x!(a_function, a_param, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_le_x.html\n",
+                assertables_url_line!("assert_fn_err_le_x"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `2`,\n",
@@ -430,7 +458,7 @@ mod test_assert_fn_err_le_x {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_err_le_x!(a_function, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_le_x.html\n",
+                assertables_url_line!("assert_fn_err_le_x"),
                 " a_function label: `f`,\n",
                 "     b_expr label: `b`,\n",
                 "     b_expr debug: `0`,\n",
@@ -483,7 +511,7 @@ mod test_assert_fn_err_le_x {
 #[macro_export]
 macro_rules! debug_assert_fn_err_le_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_fn_err_le_x!($($arg)*);
         }
     };
@@ -49,11 +49,13 @@ macro_rules! assert_fn_err_ne_as_result {
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
-        match (&$a_function, &$a_param, &$b_function, &$b_param) {
-            (_a_function, a_param, _b_function, b_param) => {
+        match ($a_param, $b_param) {
+            (a_param, b_param) => {
+                let a_param_debug = format!("{:?}", a_param);
+                let b_param_debug = format!("{:?}", b_param);
                 match (
-                    $a_function($a_param),
-                    $b_function($b_param)
+                    $a_function(a_param),
+                    $b_function(b_param)
                 ) {
                     (Err(a), Err(b)) => {
                         if a != b {
@@ -63,22 +65,22 @@ macro_rules! assert_fn_err_ne_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_err_ne!(a_function, a_param, b_function, b_param)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_ne.html\n",
+                                        $crate::assertables_url_line!("assert_fn_err_ne"),
                                         " a_function label: `{}`,\n",
                                         "    a_param label: `{}`,\n",
-                                        "    a_param debug: `{:?}`,\n",
+                                        "    a_param debug: `{}`,\n",
                                         " b_function label: `{}`,\n",
                                         "    b_param label: `{}`,\n",
-                                        "    b_param debug: `{:?}`,\n",
+                                        "    b_param debug: `{}`,\n",
                                         "                a: `{:?}`,\n",
                                         "                b: `{:?}`"
                                     ),
                                     stringify!($a_function),
                                     stringify!($a_param),
-                                    a_param,
+                                    a_param_debug,
                                     stringify!($b_function),
                                     stringify!($b_param),
-                                    b_param,
+                                    b_param_debug,
                                     a,
                                     b
                                 )
@@ -90,22 +92,22 @@ macro_rules! assert_fn_err_ne_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_err_ne!(a_function, a_param, b_function, b_param)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_ne.html\n",
+                                    $crate::assertables_url_line!("assert_fn_err_ne"),
                                     " a_function label: `{}`,\n",
                                     "    a_param label: `{}`,\n",
-                                    "    a_param debug: `{:?}`,\n",
+                                    "    a_param debug: `{}`,\n",
                                     " b_function label: `{}`,\n",
                                     "    b_param label: `{}`,\n",
-                                    "    b_param debug: `{:?}`,\n",
+                                    "    b_param debug: `{}`,\n",
                                     "                a: `{:?}`,\n",
                                     "                b: `{:?}`"
                                 ),
                                 stringify!($a_function),
                                 stringify!($a_param),
-                                a_param,
+                                a_param_debug,
                                 stringify!($b_function),
                                 stringify!($b_param),
-                                b_param,
+                                b_param_debug,
                                 a,
                                 b
                             )
@@ -131,7 +133,7 @@ macro_rules! assert_fn_err_ne_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_err_ne!(a_function, b_function)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_ne.html\n",
+                                $crate::assertables_url_line!("assert_fn_err_ne"),
                                 " a_function label: `{}`,\n",
                                 " b_function label: `{}`,\n",
                                 "                a: `{:?}`,\n",
@@ -150,7 +152,7 @@ macro_rules! assert_fn_err_ne_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_fn_err_ne!(a_function, b_function)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_ne.html\n",
+                            $crate::assertables_url_line!("assert_fn_err_ne"),
                             " a_function label: `{}`,\n",
                             " b_function label: `{}`,\n",
                             "                a: `{:?}`,\n",
@@ -170,6 +172,7 @@ macro_rules! assert_fn_err_ne_as_result {
 
 #[cfg(test)]
 mod test_assert_fn_err_ne_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     mod arity_1 {
 
@@ -196,7 +199,7 @@ mod test_assert_fn_err_ne_as_result {
             let actual = assert_fn_err_ne_as_result!(f, a, g, b);
             let message = concat!(
                 "assertion failed: `assert_fn_err_ne!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_ne.html\n",
+                assertables_url_line!("assert_fn_err_ne"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -231,7 +234,7 @@ mod test_assert_fn_err_ne_as_result {
             let actual = assert_fn_err_ne_as_result!(f, f);
             let message = concat!(
                 "assertion failed: `assert_fn_err_ne!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_ne.html\n",
+                assertables_url_line!("assert_fn_err_ne"),
                 " a_function label: `f`,\n",
                 " b_function label: `f`,\n",
                 "                a: `1`,\n",
@@ -240,6 +243,26 @@ mod test_assert_fn_err_ne_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static F: AtomicU32 = AtomicU32::new(0);
+        static P: AtomicU32 = AtomicU32::new(0);
+        fn f(i: i8) -> Result<i8, i8> {
+            F.fetch_add(1, Ordering::SeqCst);
+            Err(i)
+        }
+        fn p(i: i8) -> i8 {
+            P.fetch_add(1, Ordering::SeqCst);
+            i
+        }
+        // Success
+        assert!(assert_fn_err_ne_as_result!(f, p(1), f, p(2)).is_ok());
+        // Failure
+        assert!(assert_fn_err_ne_as_result!(f, p(1), f, p(1)).is_err());
+        assert_eq!(F.load(Ordering::SeqCst), 4);
+        assert_eq!(P.load(Ordering::SeqCst), 4);
+    }
 }
 
 /// Assert a function error is not equal to another.
@@ -288,7 +311,7 @@ mod test_assert_fn_err_ne_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fn_err_ne!(a_function, a_param, b_function, b_param)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_ne.html\n",
+/// #     assertables_url_line!("assert_fn_err_ne"),
 /// #     " a_function label: `f`,\n",
 /// #     "    a_param label: `a`,\n",
 /// #     "    a_param debug: `10`,\n",
@@ -316,14 +339,14 @@ macro_rules! assert_fn_err_ne {
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
         match $crate::assert_fn_err_ne_as_result!($a_function, $a_param, $b_function, $b_param) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr, $($message:tt)+) => {{
         match $crate::assert_fn_err_ne_as_result!($a_function, $a_param, $b_function, $b_param) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -332,14 +355,14 @@ macro_rules! assert_fn_err_ne {
     ($a_function:path, $b_function:path) => {{
         match $crate::assert_fn_err_ne_as_result!($a_function, $b_function) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $b_function:path, $($message:tt)+) => {{
         match $crate::assert_fn_err_ne_as_result!($a_function, $b_function) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -376,7 +399,7 @@ mod test_assert_fn_err_ne {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_err_ne!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_ne.html\n",
+                assertables_url_line!("assert_fn_err_ne"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -421,7 +444,7 @@ mod test_assert_fn_err_ne {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_err_ne!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_err_ne.html\n",
+                assertables_url_line!("assert_fn_err_ne"),
                 " a_function label: `f`,\n",
                 " b_function label: `f`,\n",
                 "                a: `1`,\n",
@@ -473,7 +496,7 @@ mod test_assert_fn_err_ne {
 #[macro_export]
 macro_rules! debug_assert_fn_err_ne {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_fn_err_ne!($($arg)*);
         }
     };
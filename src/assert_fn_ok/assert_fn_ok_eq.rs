@@ -49,11 +49,13 @@ macro_rules! assert_fn_ok_eq_as_result {
     //// Arity 1
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
-        match (&$a_function, &$a_param, &$b_function, &$b_param) {
-            (_a_function, a_param, _b_function, b_param) => {
+        match ($a_param, $b_param) {
+            (a_param, b_param) => {
+                let a_param_debug = format!("{:?}", a_param);
+                let b_param_debug = format!("{:?}", b_param);
                 match (
-                    $a_function($a_param),
-                    $b_function($b_param)
+                    $a_function(a_param),
+                    $b_function(b_param)
                 ) {
                     (Ok(a), Ok(b)) => {
                         if a == b {
@@ -63,22 +65,22 @@ macro_rules! assert_fn_ok_eq_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fn_ok_eq!(a_function, a_param, b_function, b_param)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_ok_eq.html\n",
+                                        $crate::assertables_url_line!("assert_fn_ok_eq"),
                                         " a_function label: `{}`,\n",
                                         "    a_param label: `{}`,\n",
-                                        "    a_param debug: `{:?}`,\n",
+                                        "    a_param debug: `{}`,\n",
                                         " b_function label: `{}`,\n",
                                         "    b_param label: `{}`,\n",
-                                        "    b_param debug: `{:?}`,\n",
+                                        "    b_param debug: `{}`,\n",
                                         "                a: `{:?}`,\n",
                                         "                b: `{:?}`"
                                     ),
                                     stringify!($a_function),
                                     stringify!($a_param),
-                                    a_param,
+                                    a_param_debug,
                                     stringify!($b_function),
                                     stringify!($b_param),
-                                    b_param,
+                                    b_param_debug,
                                     a,
                                     b
                                 )
@@ -90,22 +92,22 @@ macro_rules! assert_fn_ok_eq_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_fn_ok_eq!(a_function, a_param, b_function, b_param)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_ok_eq.html\n",
+                                    $crate::assertables_url_line!("assert_fn_ok_eq"),
                                     " a_function label: `{}`,\n",
                                     "    a_param label: `{}`,\n",
-                                    "    a_param debug: `{:?}`,\n",
+                                    "    a_param debug: `{}`,\n",
                                     " b_function label: `{}`,\n",
                                     "    b_param label: `{}`,\n",
-                                    "    b_param debug: `{:?}`,\n",
+                                    "    b_param debug: `{}`,\n",
                                     "                a: `{:?}`,\n",
                                     "                b: `{:?}`"
                                 ),
                                 stringify!($a_function),
                                 stringify!($a_param),
-                                a_param,
+                                a_param_debug,
                                 stringify!($b_function),
                                 stringify!($b_param),
-                                b_param,
+                                b_param_debug,
                                 a,
                                 b
                             )
@@ -131,7 +133,7 @@ macro_rules! assert_fn_ok_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_fn_ok_eq!(a_function, b_function)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_ok_eq.html\n",
+                                $crate::assertables_url_line!("assert_fn_ok_eq"),
                                 " a_function label: `{}`,\n",
                                 " b_function label: `{}`,\n",
                                 "                a: `{:?}`,\n",
@@ -150,7 +152,7 @@ macro_rules! assert_fn_ok_eq_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_fn_ok_eq!(a_function, b_function)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_ok_eq.html\n",
+                            $crate::assertables_url_line!("assert_fn_ok_eq"),
                             " a_function label: `{}`,\n",
                             " b_function label: `{}`,\n",
                             "                a: `{:?}`,\n",
@@ -170,6 +172,7 @@ macro_rules! assert_fn_ok_eq_as_result {
 
 #[cfg(test)]
 mod test_assert_fn_ok_eq_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     mod arity_1 {
 
@@ -196,7 +199,7 @@ mod test_assert_fn_ok_eq_as_result {
             let actual = assert_fn_ok_eq_as_result!(f, a, g, b);
             let message = concat!(
                 "assertion failed: `assert_fn_ok_eq!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_ok_eq.html\n",
+                assertables_url_line!("assert_fn_ok_eq"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -231,7 +234,7 @@ mod test_assert_fn_ok_eq_as_result {
             let actual = assert_fn_ok_eq_as_result!(f, g);
             let message = concat!(
                 "assertion failed: `assert_fn_ok_eq!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_ok_eq.html\n",
+                assertables_url_line!("assert_fn_ok_eq"),
                 " a_function label: `f`,\n",
                 " b_function label: `g`,\n",
                 "                a: `1`,\n",
@@ -240,6 +243,44 @@ mod test_assert_fn_ok_eq_as_result {
             assert_eq!(actual.unwrap_err(), message);
         }
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static F: AtomicU32 = AtomicU32::new(0);
+        static P: AtomicU32 = AtomicU32::new(0);
+        fn f(i: i8) -> Result<i8, i8> {
+            F.fetch_add(1, Ordering::SeqCst);
+            Ok(i)
+        }
+        fn p(i: i8) -> i8 {
+            P.fetch_add(1, Ordering::SeqCst);
+            i
+        }
+        // Success
+        assert!(assert_fn_ok_eq_as_result!(f, p(1), f, p(1)).is_ok());
+        // Failure
+        assert!(assert_fn_ok_eq_as_result!(f, p(1), f, p(2)).is_err());
+        assert_eq!(F.load(Ordering::SeqCst), 4);
+        assert_eq!(P.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn eq_with_closures() {
+        let a = || -> Result<i8, i8> { Ok(1) };
+        let b = || -> Result<i8, i8> { Ok(1) };
+        let actual = assert_fn_ok_eq_as_result!(a, b);
+        assert_eq!(actual.unwrap(), (1, 1));
+    }
+
+    #[test]
+    fn ne_with_closures() {
+        let a = || -> Result<i8, i8> { Ok(1) };
+        let b = || -> Result<i8, i8> { Ok(2) };
+        let actual = assert_fn_ok_eq_as_result!(a, b);
+        assert!(actual
+            .unwrap_err()
+            .contains(" a_function label: `a`,\n b_function label: `b`,\n"));
+    }
 }
 
 /// Assert a function Ok(…) is equal to another.
@@ -288,7 +329,7 @@ mod test_assert_fn_ok_eq_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fn_ok_eq!(a_function, a_param, b_function, b_param)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_ok_eq.html\n",
+/// #     assertables_url_line!("assert_fn_ok_eq"),
 /// #     " a_function label: `f`,\n",
 /// #     "    a_param label: `a`,\n",
 /// #     "    a_param debug: `1`,\n",
@@ -316,14 +357,14 @@ macro_rules! assert_fn_ok_eq {
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr $(,)?) => {{
         match $crate::assert_fn_ok_eq_as_result!($a_function, $a_param, $b_function, $b_param) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $a_param:expr, $b_function:path, $b_param:expr, $($message:tt)+) => {{
         match $crate::assert_fn_ok_eq_as_result!($a_function, $a_param, $b_function, $b_param) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 
@@ -332,14 +373,14 @@ macro_rules! assert_fn_ok_eq {
     ($a_function:path, $b_function:path) => {{
         match $crate::assert_fn_ok_eq_as_result!($a_function, $b_function) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
 
     ($a_function:path, $b_function:path, $($message:tt)+) => {{
         match $crate::assert_fn_ok_eq_as_result!($a_function, $b_function) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -376,7 +417,7 @@ mod test_assert_fn_ok_eq {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_ok_eq!(a_function, a_param, b_function, b_param)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_ok_eq.html\n",
+                assertables_url_line!("assert_fn_ok_eq"),
                 " a_function label: `f`,\n",
                 "    a_param label: `a`,\n",
                 "    a_param debug: `1`,\n",
@@ -421,7 +462,7 @@ mod test_assert_fn_ok_eq {
             });
             let message = concat!(
                 "assertion failed: `assert_fn_ok_eq!(a_function, b_function)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fn_ok_eq.html\n",
+                assertables_url_line!("assert_fn_ok_eq"),
                 " a_function label: `f`,\n",
                 " b_function label: `g`,\n",
                 "                a: `1`,\n",
@@ -473,7 +514,7 @@ mod test_assert_fn_ok_eq {
 #[macro_export]
 macro_rules! debug_assert_fn_ok_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_fn_ok_eq!($($arg)*);
         }
     };
@@ -27,6 +27,17 @@
 //! * [`assert_fn_ok_le_x!(function, expr)`](macro@crate::assert_fn_ok_le_x) ≈ function().unwrap_err() ≤ expr
 //! * [`assert_fn_ok_lt_x!(function, expr)`](macro@crate::assert_fn_ok_lt_x) ≈ function().unwrap_err() < expr
 //!
+//! # Arity
+//!
+//! Each macro accepts functions that take one argument, such as
+//! `assert_fn_ok_eq!(f, a, g, b)`, which calls `f(a)` and `g(b)`,
+//! or functions that take no arguments, such as `assert_fn_ok_eq!(f, g)`,
+//! which calls `f()` and `g()`.
+//!
+//! Each function is a path, such as a function name or a closure that is
+//! bound to a name, such as `let f = || 1;`. An inline closure is not a path,
+//! so bind it to a name first.
+//!
 //! # Example
 //!
 //! ```rust
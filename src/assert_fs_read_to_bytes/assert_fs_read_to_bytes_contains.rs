@@ -0,0 +1,329 @@
+//! Assert a ::std::fs::read(path) contains a byte subslice.
+//!
+//! Pseudocode:<br>
+//! std::fs::read(path) contains containee
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let path = "alfa.txt";
+//! let containee = b"lf";
+//! assert_fs_read_to_bytes_contains!(&path, &containee);
+//! ```
+//!
+//! The containee can be anything that implements `AsRef<[u8]>`,
+//! such as a byte string literal, a byte array, or a `Vec<u8>`.
+//! An empty containee is contained in any bytes.
+//!
+//! # Module macros
+//!
+//! * [`assert_fs_read_to_bytes_contains`](macro@crate::assert_fs_read_to_bytes_contains)
+//! * [`assert_fs_read_to_bytes_contains_as_result`](macro@crate::assert_fs_read_to_bytes_contains_as_result)
+//! * [`debug_assert_fs_read_to_bytes_contains`](macro@crate::debug_assert_fs_read_to_bytes_contains)
+
+/// Assert a ::std::fs::read(path) contains a byte subslice.
+///
+/// Pseudocode:<br>
+/// std::fs::read(path) contains containee
+///
+/// * If true, return Result `Ok(bytes)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_bytes_contains`](macro@crate::assert_fs_read_to_bytes_contains)
+/// * [`assert_fs_read_to_bytes_contains_as_result`](macro@crate::assert_fs_read_to_bytes_contains_as_result)
+/// * [`debug_assert_fs_read_to_bytes_contains`](macro@crate::debug_assert_fs_read_to_bytes_contains)
+///
+#[macro_export]
+macro_rules! assert_fs_read_to_bytes_contains_as_result {
+    ($path:expr, $containee:expr $(,)?) => {{
+        match (&$path, &$containee) {
+            (path, containee) => {
+                match ::std::fs::read(path) {
+                    Ok(bytes) => {
+                        let containee_bytes: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(containee);
+                        if containee_bytes.is_empty()
+                            || bytes
+                                .windows(containee_bytes.len())
+                                .any(|window| window == containee_bytes)
+                        {
+                            Ok(bytes)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_fs_read_to_bytes_contains!(path, containee)`\n",
+                                        $crate::assertables_url_line!("assert_fs_read_to_bytes_contains"),
+                                        "      path label: `{}`,\n",
+                                        "      path debug: `{:?}`,\n",
+                                        " containee label: `{}`,\n",
+                                        " containee debug: `{:?}`,\n",
+                                        "           bytes: `{:?}`"
+                                    ),
+                                    stringify!($path),
+                                    path,
+                                    stringify!($containee),
+                                    containee,
+                                    bytes
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "read error: `assert_fs_read_to_bytes_contains!(path, containee)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_bytes_contains"),
+                                    "      path label: `{}`,\n",
+                                    "      path debug: `{:?}`,\n",
+                                    " containee label: `{}`,\n",
+                                    " containee debug: `{:?}`,\n",
+                                    "      error kind: `{:?}`,\n",
+                                    "      error text: `{}`"
+                                ),
+                                stringify!($path),
+                                path,
+                                stringify!($containee),
+                                containee,
+                                err.kind(),
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_fs_read_to_bytes_contains_as_result {
+    use std::path::PathBuf;
+    use std::sync::LazyLock;
+
+    pub static DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("src")
+            .join("std")
+            .join("fs")
+    });
+
+    #[test]
+    fn success() {
+        let path = DIR.join("alfa.txt");
+        let containee = b"lf";
+        let actual = assert_fs_read_to_bytes_contains_as_result!(&path, &containee);
+        assert_eq!(actual.unwrap(), vec![97, 108, 102, 97, 10]);
+    }
+
+    #[test]
+    fn failure() {
+        let path = DIR.join("alfa.txt");
+        let containee = b"zz";
+        let actual = assert_fs_read_to_bytes_contains_as_result!(&path, &containee);
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_bytes_contains!(path, containee)`\n",
+                assertables_url_line!("assert_fs_read_to_bytes_contains"),
+                "      path label: `&path`,\n",
+                "      path debug: `{:?}`,\n",
+                " containee label: `&containee`,\n",
+                " containee debug: `[122, 122]`,\n",
+                "           bytes: `[97, 108, 102, 97, 10]`"
+            ),
+            DIR.join("alfa.txt")
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn binary() {
+        let path = DIR.join("binary.bin");
+        let containee = [0x00, 0x01];
+        let actual = assert_fs_read_to_bytes_contains_as_result!(&path, &containee);
+        assert_eq!(actual.unwrap(), vec![0xff, 0xfe, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn empty() {
+        let path = DIR.join("alfa.txt");
+        let containee: &[u8] = &[];
+        let actual = assert_fs_read_to_bytes_contains_as_result!(&path, &containee);
+        assert_eq!(actual.unwrap(), vec![97, 108, 102, 97, 10]);
+    }
+}
+
+/// Assert a ::std::fs::read(path) contains a byte subslice.
+///
+/// Pseudocode:<br>
+/// std::fs::read(path) contains containee
+///
+/// * If true, return `bytes`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let path = "alfa.txt";
+/// let containee = b"lf";
+/// assert_fs_read_to_bytes_contains!(&path, &containee);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let path = "alfa.txt";
+/// let containee = b"zz";
+/// assert_fs_read_to_bytes_contains!(&path, &containee);
+/// # });
+/// // assertion failed: `assert_fs_read_to_bytes_contains!(path, containee)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_bytes_contains.html
+/// //       path label: `&path`,
+/// //       path debug: `\"alfa.txt\"`,
+/// //  containee label: `&containee`,
+/// //  containee debug: `[122, 122]`,
+/// //            bytes: `[97, 108, 102, 97, 10]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_fs_read_to_bytes_contains!(path, containee)`\n",
+/// #     assertables_url_line!("assert_fs_read_to_bytes_contains"),
+/// #     "      path label: `&path`,\n",
+/// #     "      path debug: `\"alfa.txt\"`,\n",
+/// #     " containee label: `&containee`,\n",
+/// #     " containee debug: `[122, 122]`,\n",
+/// #     "           bytes: `[97, 108, 102, 97, 10]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The containee can be anything that implements `AsRef<[u8]>`,
+/// such as a byte string literal, a byte array, or a `Vec<u8>`.
+/// An empty containee is contained in any bytes.
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_bytes_contains`](macro@crate::assert_fs_read_to_bytes_contains)
+/// * [`assert_fs_read_to_bytes_contains_as_result`](macro@crate::assert_fs_read_to_bytes_contains_as_result)
+/// * [`debug_assert_fs_read_to_bytes_contains`](macro@crate::debug_assert_fs_read_to_bytes_contains)
+///
+#[macro_export]
+macro_rules! assert_fs_read_to_bytes_contains {
+    ($path:expr, $containee:expr $(,)?) => {{
+        match $crate::assert_fs_read_to_bytes_contains_as_result!($path, $containee) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($path:expr, $containee:expr, $($message:tt)+) => {{
+        match $crate::assert_fs_read_to_bytes_contains_as_result!($path, $containee) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_fs_read_to_bytes_contains {
+    use std::panic;
+    use std::path::PathBuf;
+    use std::sync::LazyLock;
+
+    pub static DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("src")
+            .join("std")
+            .join("fs")
+    });
+
+    #[test]
+    fn success() {
+        let path = DIR.join("alfa.txt");
+        let containee = b"lf";
+        let actual = assert_fs_read_to_bytes_contains!(&path, &containee);
+        assert_eq!(actual, vec![97, 108, 102, 97, 10]);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let path = DIR.join("alfa.txt");
+            let containee = b"zz";
+            let _actual = assert_fs_read_to_bytes_contains!(&path, &containee);
+        });
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_bytes_contains!(path, containee)`\n",
+                assertables_url_line!("assert_fs_read_to_bytes_contains"),
+                "      path label: `&path`,\n",
+                "      path debug: `{:?}`,\n",
+                " containee label: `&containee`,\n",
+                " containee debug: `[122, 122]`,\n",
+                "           bytes: `[97, 108, 102, 97, 10]`"
+            ),
+            DIR.join("alfa.txt")
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a ::std::fs::read(path) contains a byte subslice.
+///
+/// Pseudocode:<br>
+/// std::fs::read(path) contains containee
+///
+/// This macro provides the same statements as [`assert_fs_read_to_bytes_contains`](macro.assert_fs_read_to_bytes_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_bytes_contains`](macro@crate::assert_fs_read_to_bytes_contains)
+/// * [`assert_fs_read_to_bytes_contains_as_result`](macro@crate::assert_fs_read_to_bytes_contains_as_result)
+/// * [`debug_assert_fs_read_to_bytes_contains`](macro@crate::debug_assert_fs_read_to_bytes_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_fs_read_to_bytes_contains {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_fs_read_to_bytes_contains!($($arg)*);
+        }
+    };
+}
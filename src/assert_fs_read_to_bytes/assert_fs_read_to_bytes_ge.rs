@@ -0,0 +1,338 @@
+//! Assert a ::std::fs::read(path) value is greater than or equal to another.
+//!
+//! Pseudocode:<br>
+//! std::fs::read(a_path) ≥ std::fs::read(b_path)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "alfa.txt";
+//! let b = "alfa.txt";
+//! assert_fs_read_to_bytes_ge!(&a, &b);
+//! ```
+//!
+//! # Module macros
+//!
+//! * [`assert_fs_read_to_bytes_ge`](macro@crate::assert_fs_read_to_bytes_ge)
+//! * [`assert_fs_read_to_bytes_ge_as_result`](macro@crate::assert_fs_read_to_bytes_ge_as_result)
+//! * [`debug_assert_fs_read_to_bytes_ge`](macro@crate::debug_assert_fs_read_to_bytes_ge)
+
+/// Assert a ::std::fs::read(path) value is greater than or equal to another.
+///
+/// Pseudocode:<br>
+/// std::fs::read(a_path) ≥ std::fs::read(b_path)
+///
+/// * If true, return Result `Ok((a_bytes, b_bytes))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_bytes_ge`](macro@crate::assert_fs_read_to_bytes_ge)
+/// * [`assert_fs_read_to_bytes_ge_as_result`](macro@crate::assert_fs_read_to_bytes_ge_as_result)
+/// * [`debug_assert_fs_read_to_bytes_ge`](macro@crate::debug_assert_fs_read_to_bytes_ge)
+///
+#[macro_export]
+macro_rules! assert_fs_read_to_bytes_ge_as_result {
+    ($a_path:expr, $b_path:expr $(,)?) => {{
+        match (&$a_path, &$b_path) {
+            (a_path, b_path) => {
+                match (::std::fs::read(a_path), ::std::fs::read(b_path)) {
+                    (Ok(a_bytes), Ok(b_bytes)) => {
+                        if a_bytes >= b_bytes {
+                            Ok((a_bytes, b_bytes))
+                        } else {
+                            let b_bytes: &[u8] = &b_bytes;
+                            let (index, a_diff, b_diff) = $crate::bytes_diff::bytes_diff_region(&a_bytes, b_bytes, $crate::bytes_diff::hex);
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_fs_read_to_bytes_ge!(a_path, b_path)`\n",
+                                        $crate::assertables_url_line!("assert_fs_read_to_bytes_ge"),
+                                        " a_path label: `{}`,\n",
+                                        " a_path debug: `{:?}`,\n",
+                                        " b_path label: `{}`,\n",
+                                        " b_path debug: `{:?}`,\n",
+                                        "      a bytes: `{:?}`,\n",
+                                        "      b bytes: `{:?}`,\n",
+                                        "   diff index: `{}`,\n",
+                                        "       a diff: `{}`,\n",
+                                        "       b diff: `{}`"
+                                    ),
+                                    stringify!($a_path),
+                                    a_path,
+                                    stringify!($b_path),
+                                    b_path,
+                                    a_bytes,
+                                    b_bytes,
+                                    index,
+                                    a_diff,
+                                    b_diff
+                                )
+                            )
+                        }
+                    },
+                    (a_result, b_result) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "read error: `assert_fs_read_to_bytes_ge!(a_path, b_path)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_bytes_ge"),
+                                    " a_path label: `{}`,\n",
+                                    " a_path debug: `{:?}`,\n",
+                                    " b_path label: `{}`,\n",
+                                    " b_path debug: `{:?}`,\n",
+                                    " a error kind: `{:?}`,\n",
+                                    " a error text: `{:?}`,\n",
+                                    " b error kind: `{:?}`,\n",
+                                    " b error text: `{:?}`"
+                                ),
+                                stringify!($a_path),
+                                a_path,
+                                stringify!($b_path),
+                                b_path,
+                                $crate::assert_fs_read_to_string::read_error_kind(&a_result),
+                                $crate::assert_fs_read_to_string::read_error_text(&a_result),
+                                $crate::assert_fs_read_to_string::read_error_kind(&b_result),
+                                $crate::assert_fs_read_to_string::read_error_text(&b_result)
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_fs_read_to_bytes_ge_as_result {
+    use std::path::PathBuf;
+    use std::sync::LazyLock;
+
+    pub static DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("src")
+            .join("std")
+            .join("fs")
+    });
+
+    #[test]
+    fn success() {
+        let a = DIR.join("alfa.txt");
+        let b = DIR.join("alfa.txt");
+        let actual = assert_fs_read_to_bytes_ge_as_result!(&a, &b);
+        assert_eq!(
+            actual.unwrap(),
+            (vec![97, 108, 102, 97, 10], vec![97, 108, 102, 97, 10])
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = DIR.join("alfa.txt");
+        let b = DIR.join("bravo.txt");
+        let actual = assert_fs_read_to_bytes_ge_as_result!(&a, &b);
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_bytes_ge!(a_path, b_path)`\n",
+                assertables_url_line!("assert_fs_read_to_bytes_ge"),
+                " a_path label: `&a`,\n",
+                " a_path debug: `{:?}`,\n",
+                " b_path label: `&b`,\n",
+                " b_path debug: `{:?}`,\n",
+                "      a bytes: `[97, 108, 102, 97, 10]`,\n",
+                "      b bytes: `[98, 114, 97, 118, 111, 10]`,\n",
+                "   diff index: `0`,\n",
+                "       a diff: `61 6c 66 61 0a`,\n",
+                "       b diff: `62 72 61 76 6f 0a`"
+            ),
+            DIR.join("alfa.txt"),
+            DIR.join("bravo.txt")
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a ::std::fs::read(path) value is greater than or equal to another.
+///
+/// Pseudocode:<br>
+/// std::fs::read(a_path) ≥ std::fs::read(b_path)
+///
+/// * If true, return `(a_bytes, b_bytes)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa.txt";
+/// let b = "alfa.txt";
+/// assert_fs_read_to_bytes_ge!(&a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa.txt";
+/// let b = "bravo.txt";
+/// assert_fs_read_to_bytes_ge!(&a, &b);
+/// # });
+/// // assertion failed: `assert_fs_read_to_bytes_ge!(a_path, b_path)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_bytes_ge.html
+/// //  a_path label: `&a`,
+/// //  a_path debug: `\"alfa.txt\"`,
+/// //  b_path label: `&b`,
+/// //  b_path debug: `\"bravo.txt\"`,
+/// //       a bytes: `[97, 108, 102, 97, 10]`,
+/// //       b bytes: `[98, 114, 97, 118, 111, 10]`,
+/// //    diff index: `0`,
+/// //        a diff: `61 6c 66 61 0a`,
+/// //        b diff: `62 72 61 76 6f 0a`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_fs_read_to_bytes_ge!(a_path, b_path)`\n",
+/// #     assertables_url_line!("assert_fs_read_to_bytes_ge"),
+/// #     " a_path label: `&a`,\n",
+/// #     " a_path debug: `\"alfa.txt\"`,\n",
+/// #     " b_path label: `&b`,\n",
+/// #     " b_path debug: `\"bravo.txt\"`,\n",
+/// #     "      a bytes: `[97, 108, 102, 97, 10]`,\n",
+/// #     "      b bytes: `[98, 114, 97, 118, 111, 10]`,\n",
+/// #     "   diff index: `0`,\n",
+/// #     "       a diff: `61 6c 66 61 0a`,\n",
+/// #     "       b diff: `62 72 61 76 6f 0a`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_bytes_ge`](macro@crate::assert_fs_read_to_bytes_ge)
+/// * [`assert_fs_read_to_bytes_ge_as_result`](macro@crate::assert_fs_read_to_bytes_ge_as_result)
+/// * [`debug_assert_fs_read_to_bytes_ge`](macro@crate::debug_assert_fs_read_to_bytes_ge)
+///
+#[macro_export]
+macro_rules! assert_fs_read_to_bytes_ge {
+    ($a_path:expr, $b_path:expr $(,)?) => {{
+        match $crate::assert_fs_read_to_bytes_ge_as_result!($a_path, $b_path) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_path:expr, $b_path:expr, $($message:tt)+) => {{
+        match $crate::assert_fs_read_to_bytes_ge_as_result!($a_path, $b_path) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_fs_read_to_bytes_ge {
+    use std::panic;
+    use std::path::PathBuf;
+    use std::sync::LazyLock;
+
+    pub static DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("src")
+            .join("std")
+            .join("fs")
+    });
+
+    #[test]
+    fn success() {
+        let a = DIR.join("alfa.txt");
+        let b = DIR.join("alfa.txt");
+        let actual = assert_fs_read_to_bytes_ge!(&a, &b);
+        assert_eq!(
+            actual,
+            (vec![97, 108, 102, 97, 10], vec![97, 108, 102, 97, 10])
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = DIR.join("alfa.txt");
+            let b = DIR.join("bravo.txt");
+            let _actual = assert_fs_read_to_bytes_ge!(&a, &b);
+        });
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_bytes_ge!(a_path, b_path)`\n",
+                assertables_url_line!("assert_fs_read_to_bytes_ge"),
+                " a_path label: `&a`,\n",
+                " a_path debug: `{:?}`,\n",
+                " b_path label: `&b`,\n",
+                " b_path debug: `{:?}`,\n",
+                "      a bytes: `[97, 108, 102, 97, 10]`,\n",
+                "      b bytes: `[98, 114, 97, 118, 111, 10]`,\n",
+                "   diff index: `0`,\n",
+                "       a diff: `61 6c 66 61 0a`,\n",
+                "       b diff: `62 72 61 76 6f 0a`"
+            ),
+            DIR.join("alfa.txt"),
+            DIR.join("bravo.txt")
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a ::std::fs::read(path) value is greater than or equal to another.
+///
+/// Pseudocode:<br>
+/// std::fs::read(a_path) ≥ std::fs::read(b_path)
+///
+/// This macro provides the same statements as [`assert_fs_read_to_bytes_ge`](macro.assert_fs_read_to_bytes_ge.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_bytes_ge`](macro@crate::assert_fs_read_to_bytes_ge)
+/// * [`assert_fs_read_to_bytes_ge_as_result`](macro@crate::assert_fs_read_to_bytes_ge_as_result)
+/// * [`debug_assert_fs_read_to_bytes_ge`](macro@crate::debug_assert_fs_read_to_bytes_ge)
+///
+#[macro_export]
+macro_rules! debug_assert_fs_read_to_bytes_ge {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_fs_read_to_bytes_ge!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,332 @@
+//! Assert a ::std::fs::read(path) value is greater than an expression.
+//!
+//! Pseudocode:<br>
+//! std::fs::read(a_path) > b_expr
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let path = "bravo.txt";
+//! let value = b"alfa\n";
+//! assert_fs_read_to_bytes_gt_x!(&path, &value);
+//! ```
+//!
+//! The expression can be anything that implements `AsRef<[u8]>`,
+//! such as a byte string literal, a byte array, or a `Vec<u8>`.
+//!
+//! # Module macros
+//!
+//! * [`assert_fs_read_to_bytes_gt_x`](macro@crate::assert_fs_read_to_bytes_gt_x)
+//! * [`assert_fs_read_to_bytes_gt_x_as_result`](macro@crate::assert_fs_read_to_bytes_gt_x_as_result)
+//! * [`debug_assert_fs_read_to_bytes_gt_x`](macro@crate::debug_assert_fs_read_to_bytes_gt_x)
+
+/// Assert a ::std::fs::read(path) value is greater than an expression.
+///
+/// Pseudocode:<br>
+/// std::fs::read(a_path) > b_expr
+///
+/// * If true, return Result `Ok(a_bytes)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_bytes_gt_x`](macro@crate::assert_fs_read_to_bytes_gt_x)
+/// * [`assert_fs_read_to_bytes_gt_x_as_result`](macro@crate::assert_fs_read_to_bytes_gt_x_as_result)
+/// * [`debug_assert_fs_read_to_bytes_gt_x`](macro@crate::debug_assert_fs_read_to_bytes_gt_x)
+///
+#[macro_export]
+macro_rules! assert_fs_read_to_bytes_gt_x_as_result {
+    ($a_path:expr, $b_expr:expr $(,)?) => {{
+        match (&$a_path, &$b_expr) {
+            (a_path, b_expr) => {
+                match ::std::fs::read(a_path) {
+                    Ok(a_bytes) => {
+                        let b_bytes: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(b_expr);
+                        if a_bytes.as_slice() > b_bytes {
+                            Ok(a_bytes)
+                        } else {
+                            let (index, a_diff, b_diff) = $crate::bytes_diff::bytes_diff_region(&a_bytes, b_bytes, $crate::bytes_diff::hex);
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_fs_read_to_bytes_gt_x!(a_path, b_expr)`\n",
+                                        $crate::assertables_url_line!("assert_fs_read_to_bytes_gt_x"),
+                                        " a_path label: `{}`,\n",
+                                        " a_path debug: `{:?}`,\n",
+                                        " b_expr label: `{}`,\n",
+                                        " b_expr debug: `{:?}`,\n",
+                                        "      a bytes: `{:?}`,\n",
+                                        "      b bytes: `{:?}`,\n",
+                                        "   diff index: `{}`,\n",
+                                        "       a diff: `{}`,\n",
+                                        "       b diff: `{}`"
+                                    ),
+                                    stringify!($a_path),
+                                    a_path,
+                                    stringify!($b_expr),
+                                    b_expr,
+                                    a_bytes,
+                                    b_bytes,
+                                    index,
+                                    a_diff,
+                                    b_diff
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "read error: `assert_fs_read_to_bytes_gt_x!(a_path, b_expr)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_bytes_gt_x"),
+                                    " a_path label: `{}`,\n",
+                                    " a_path debug: `{:?}`,\n",
+                                    " b_expr label: `{}`,\n",
+                                    " b_expr debug: `{:?}`,\n",
+                                    "   error kind: `{:?}`,\n",
+                                    "   error text: `{}`"
+                                ),
+                                stringify!($a_path),
+                                a_path,
+                                stringify!($b_expr),
+                                b_expr,
+                                err.kind(),
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_fs_read_to_bytes_gt_x_as_result {
+    use std::path::PathBuf;
+    use std::sync::LazyLock;
+
+    pub static DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("src")
+            .join("std")
+            .join("fs")
+    });
+
+    #[test]
+    fn success() {
+        let path = DIR.join("bravo.txt");
+        let value = b"alfa\n";
+        let actual = assert_fs_read_to_bytes_gt_x_as_result!(&path, &value);
+        assert_eq!(actual.unwrap(), vec![98, 114, 97, 118, 111, 10]);
+    }
+
+    #[test]
+    fn failure() {
+        let path = DIR.join("alfa.txt");
+        let value = b"bravo\n";
+        let actual = assert_fs_read_to_bytes_gt_x_as_result!(&path, &value);
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_bytes_gt_x!(a_path, b_expr)`\n",
+                assertables_url_line!("assert_fs_read_to_bytes_gt_x"),
+                " a_path label: `&path`,\n",
+                " a_path debug: `{:?}`,\n",
+                " b_expr label: `&value`,\n",
+                " b_expr debug: `[98, 114, 97, 118, 111, 10]`,\n",
+                "      a bytes: `[97, 108, 102, 97, 10]`,\n",
+                "      b bytes: `[98, 114, 97, 118, 111, 10]`,\n",
+                "   diff index: `0`,\n",
+                "       a diff: `61 6c 66 61 0a`,\n",
+                "       b diff: `62 72 61 76 6f 0a`"
+            ),
+            DIR.join("alfa.txt")
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a ::std::fs::read(path) value is greater than an expression.
+///
+/// Pseudocode:<br>
+/// std::fs::read(a_path) > b_expr
+///
+/// * If true, return `a_bytes`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let path = "bravo.txt";
+/// let value = b"alfa\n";
+/// assert_fs_read_to_bytes_gt_x!(&path, &value);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let path = "alfa.txt";
+/// let value = b"bravo\n";
+/// assert_fs_read_to_bytes_gt_x!(&path, &value);
+/// # });
+/// // assertion failed: `assert_fs_read_to_bytes_gt_x!(a_path, b_expr)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_bytes_gt_x.html
+/// //  a_path label: `&path`,
+/// //  a_path debug: `\"alfa.txt\"`,
+/// //  b_expr label: `&value`,
+/// //  b_expr debug: `[98, 114, 97, 118, 111, 10]`,
+/// //       a bytes: `[97, 108, 102, 97, 10]`,
+/// //       b bytes: `[98, 114, 97, 118, 111, 10]`,
+/// //    diff index: `0`,
+/// //        a diff: `61 6c 66 61 0a`,
+/// //        b diff: `62 72 61 76 6f 0a`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_fs_read_to_bytes_gt_x!(a_path, b_expr)`\n",
+/// #     assertables_url_line!("assert_fs_read_to_bytes_gt_x"),
+/// #     " a_path label: `&path`,\n",
+/// #     " a_path debug: `\"alfa.txt\"`,\n",
+/// #     " b_expr label: `&value`,\n",
+/// #     " b_expr debug: `[98, 114, 97, 118, 111, 10]`,\n",
+/// #     "      a bytes: `[97, 108, 102, 97, 10]`,\n",
+/// #     "      b bytes: `[98, 114, 97, 118, 111, 10]`,\n",
+/// #     "   diff index: `0`,\n",
+/// #     "       a diff: `61 6c 66 61 0a`,\n",
+/// #     "       b diff: `62 72 61 76 6f 0a`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The expression can be anything that implements `AsRef<[u8]>`,
+/// such as a byte string literal, a byte array, or a `Vec<u8>`.
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_bytes_gt_x`](macro@crate::assert_fs_read_to_bytes_gt_x)
+/// * [`assert_fs_read_to_bytes_gt_x_as_result`](macro@crate::assert_fs_read_to_bytes_gt_x_as_result)
+/// * [`debug_assert_fs_read_to_bytes_gt_x`](macro@crate::debug_assert_fs_read_to_bytes_gt_x)
+///
+#[macro_export]
+macro_rules! assert_fs_read_to_bytes_gt_x {
+    ($a_path:expr, $b_expr:expr $(,)?) => {{
+        match $crate::assert_fs_read_to_bytes_gt_x_as_result!($a_path, $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_path:expr, $b_expr:expr, $($message:tt)+) => {{
+        match $crate::assert_fs_read_to_bytes_gt_x_as_result!($a_path, $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_fs_read_to_bytes_gt_x {
+    use std::panic;
+    use std::path::PathBuf;
+    use std::sync::LazyLock;
+
+    pub static DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("src")
+            .join("std")
+            .join("fs")
+    });
+
+    #[test]
+    fn success() {
+        let path = DIR.join("bravo.txt");
+        let value = b"alfa\n";
+        let actual = assert_fs_read_to_bytes_gt_x!(&path, &value);
+        assert_eq!(actual, vec![98, 114, 97, 118, 111, 10]);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let path = DIR.join("alfa.txt");
+            let value = b"bravo\n";
+            let _actual = assert_fs_read_to_bytes_gt_x!(&path, &value);
+        });
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_bytes_gt_x!(a_path, b_expr)`\n",
+                assertables_url_line!("assert_fs_read_to_bytes_gt_x"),
+                " a_path label: `&path`,\n",
+                " a_path debug: `{:?}`,\n",
+                " b_expr label: `&value`,\n",
+                " b_expr debug: `[98, 114, 97, 118, 111, 10]`,\n",
+                "      a bytes: `[97, 108, 102, 97, 10]`,\n",
+                "      b bytes: `[98, 114, 97, 118, 111, 10]`,\n",
+                "   diff index: `0`,\n",
+                "       a diff: `61 6c 66 61 0a`,\n",
+                "       b diff: `62 72 61 76 6f 0a`"
+            ),
+            DIR.join("alfa.txt")
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a ::std::fs::read(path) value is greater than an expression.
+///
+/// Pseudocode:<br>
+/// std::fs::read(a_path) > b_expr
+///
+/// This macro provides the same statements as [`assert_fs_read_to_bytes_gt_x`](macro.assert_fs_read_to_bytes_gt_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_bytes_gt_x`](macro@crate::assert_fs_read_to_bytes_gt_x)
+/// * [`assert_fs_read_to_bytes_gt_x_as_result`](macro@crate::assert_fs_read_to_bytes_gt_x_as_result)
+/// * [`debug_assert_fs_read_to_bytes_gt_x`](macro@crate::debug_assert_fs_read_to_bytes_gt_x)
+///
+#[macro_export]
+macro_rules! debug_assert_fs_read_to_bytes_gt_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_fs_read_to_bytes_gt_x!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,67 @@
+//! Assert for comparing file system path contents as bytes.
+//!
+//! These macros help with file system paths, such as disk files, `Path`,
+//! `PathBuf`, the trait `AsRef<Path>`, and anything that is readable via
+//! `::std::fs::read(…)`.
+//!
+//! These macros compare raw bytes, so binary files do not need to go through
+//! a lossy UTF-8 conversion. For text files, see
+//! [`assert_fs_read_to_string`](module@crate::assert_fs_read_to_string).
+//!
+//! Compare a path with another path:
+//!
+//! * [`assert_fs_read_to_bytes_eq!(path1, path2)`](macro@crate::assert_fs_read_to_bytes_eq) ≈ std::fs::read(path1) = std::fs::read(path2)
+//! * [`assert_fs_read_to_bytes_ne!(path1, path2)`](macro@crate::assert_fs_read_to_bytes_ne) ≈ std::fs::read(path1) ≠ std::fs::read(path2)
+//! * [`assert_fs_read_to_bytes_lt!(path1, path2)`](macro@crate::assert_fs_read_to_bytes_lt) ≈ std::fs::read(path1) < std::fs::read(path2)
+//! * [`assert_fs_read_to_bytes_le!(path1, path2)`](macro@crate::assert_fs_read_to_bytes_le) ≈ std::fs::read(path1) ≤ std::fs::read(path2)
+//! * [`assert_fs_read_to_bytes_gt!(path1, path2)`](macro@crate::assert_fs_read_to_bytes_gt) ≈ std::fs::read(path1) > std::fs::read(path2)
+//! * [`assert_fs_read_to_bytes_ge!(path1, path2)`](macro@crate::assert_fs_read_to_bytes_ge) ≈ std::fs::read(path1) ≥ std::fs::read(path2)
+//!
+//! Compare a path with an expression:
+//!
+//! * [`assert_fs_read_to_bytes_eq_x!(path, expr)`](macro@crate::assert_fs_read_to_bytes_eq_x) ≈ std::fs::read(path) = expr
+//! * [`assert_fs_read_to_bytes_ne_x!(path, expr)`](macro@crate::assert_fs_read_to_bytes_ne_x) ≈ std::fs::read(path) ≠ expr
+//! * [`assert_fs_read_to_bytes_lt_x!(path, expr)`](macro@crate::assert_fs_read_to_bytes_lt_x) ≈ std::fs::read(path) < expr
+//! * [`assert_fs_read_to_bytes_le_x!(path, expr)`](macro@crate::assert_fs_read_to_bytes_le_x) ≈ std::fs::read(path) ≤ expr
+//! * [`assert_fs_read_to_bytes_gt_x!(path, expr)`](macro@crate::assert_fs_read_to_bytes_gt_x) ≈ std::fs::read(path) > expr
+//! * [`assert_fs_read_to_bytes_ge_x!(path, expr)`](macro@crate::assert_fs_read_to_bytes_ge_x) ≈ std::fs::read(path) ≥ expr
+//!
+//! Compare a path with its contents:
+//!
+//! * [`assert_fs_read_to_bytes_contains!(path, containee)`](macro@crate::assert_fs_read_to_bytes_contains) ≈ std::fs::read(path) contains containee
+//!
+//! When the bytes differ, the message shows the offset of the first differing
+//! byte, and a hex dump of each side's bytes from that offset, up to 16 bytes.
+//!
+//! If a path cannot be read, then the message starts with "read error:" and
+//! shows the OS error kind and text, the same as
+//! [`assert_fs_read_to_string`](module@crate::assert_fs_read_to_string).
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "alfa.txt";
+//! let b = "alfa.txt";
+//! assert_fs_read_to_bytes_eq!(&a, &b);
+//! ```
+
+// Compare another
+pub mod assert_fs_read_to_bytes_eq;
+pub mod assert_fs_read_to_bytes_ge;
+pub mod assert_fs_read_to_bytes_gt;
+pub mod assert_fs_read_to_bytes_le;
+pub mod assert_fs_read_to_bytes_lt;
+pub mod assert_fs_read_to_bytes_ne;
+
+// Compare expression
+pub mod assert_fs_read_to_bytes_eq_x;
+pub mod assert_fs_read_to_bytes_ge_x;
+pub mod assert_fs_read_to_bytes_gt_x;
+pub mod assert_fs_read_to_bytes_le_x;
+pub mod assert_fs_read_to_bytes_lt_x;
+pub mod assert_fs_read_to_bytes_ne_x;
+
+// Specializations
+pub mod assert_fs_read_to_bytes_contains;
@@ -51,7 +51,7 @@ macro_rules! assert_fs_read_to_string_contains_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_contains!(path, containee)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_contains.html\n",
+                                        $crate::assertables_url_line!("assert_fs_read_to_string_contains"),
                                         "      path label: `{}`,\n",
                                         "      path debug: `{:?}`,\n",
                                         " containee label: `{}`,\n",
@@ -71,18 +71,20 @@ macro_rules! assert_fs_read_to_string_contains_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_fs_read_to_string_contains!(path, containee)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_contains.html\n",
+                                    "read error: `assert_fs_read_to_string_contains!(path, containee)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_string_contains"),
                                     "      path label: `{}`,\n",
                                     "      path debug: `{:?}`,\n",
                                     " containee label: `{}`,\n",
                                     " containee debug: `{:?}`,\n",
-                                    "        read err: `{:?}`"
+                                    "      error kind: `{:?}`,\n",
+                                    "      error text: `{}`"
                                 ),
                                 stringify!($path),
                                 path,
                                 stringify!($containee),
                                 containee,
+                                err.kind(),
                                 err
                             )
                         )
@@ -124,7 +126,7 @@ mod test_read_to_string_contains_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_contains!(path, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_contains.html\n",
+                assertables_url_line!("assert_fs_read_to_string_contains"),
                 "      path label: `&path`,\n",
                 "      path debug: `{:?}`,\n",
                 " containee label: `&containee`,\n",
@@ -175,7 +177,7 @@ mod test_read_to_string_contains_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fs_read_to_string_contains!(path, containee)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_contains.html\n",
+/// #     assertables_url_line!("assert_fs_read_to_string_contains"),
 /// #     "      path label: `&path`,\n",
 /// #     "      path debug: `\"alfa.txt\"`,\n",
 /// #     " containee label: `&containee`,\n",
@@ -197,13 +199,13 @@ macro_rules! assert_fs_read_to_string_contains {
     ($path:expr, $containee:expr $(,)?) => {{
         match $crate::assert_fs_read_to_string_contains_as_result!($path, $containee) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($path:expr, $containee:expr, $($message:tt)+) => {{
         match $crate::assert_fs_read_to_string_contains_as_result!($path, $containee) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -242,7 +244,7 @@ mod test_read_to_string_contains {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_contains!(path, containee)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_contains.html\n",
+                assertables_url_line!("assert_fs_read_to_string_contains"),
                 "      path label: `&path`,\n",
                 "      path debug: `{:?}`,\n",
                 " containee label: `&containee`,\n",
@@ -296,7 +298,7 @@ mod test_read_to_string_contains {
 #[macro_export]
 macro_rules! debug_assert_fs_read_to_string_contains {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::std::fs::read_to_string_contains!($($arg)*);
         }
     };
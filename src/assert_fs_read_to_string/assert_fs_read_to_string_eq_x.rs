@@ -52,7 +52,7 @@ macro_rules! assert_fs_read_to_string_eq_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_eq_x.html\n",
+                                        $crate::assertables_url_line!("assert_fs_read_to_string_eq_x"),
                                         " a_path label: `{}`,\n",
                                         " a_path debug: `{:?}`,\n",
                                         " b_expr label: `{}`,\n",
@@ -74,18 +74,20 @@ macro_rules! assert_fs_read_to_string_eq_x_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_eq_x.html\n",
+                                    "read error: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_string_eq_x"),
                                     " a_path label: `{}`,\n",
                                     " a_path debug: `{:?}`,\n",
                                     " b_expr label: `{}`,\n",
                                     " b_expr debug: `{:?}`,\n",
-                                    "          err: `{:?}`"
+                                    "   error kind: `{:?}`,\n",
+                                    "   error text: `{}`"
                                 ),
                                 stringify!($a_path),
                                 a_path,
                                 stringify!($b_expr),
                                 b_expr,
+                                err.kind(),
                                 err
                             )
                         )
@@ -127,7 +129,7 @@ mod test_assert_fs_read_to_string_eq_x_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_eq_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_eq_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -148,7 +150,7 @@ mod test_assert_fs_read_to_string_eq_x_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_eq_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_eq_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -160,6 +162,53 @@ mod test_assert_fs_read_to_string_eq_x_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn read_error_not_found() {
+        let path = DIR.join("missing.txt");
+        let value = String::from("alfa\n");
+        let actual = assert_fs_read_to_string_eq_x_as_result!(&path, &value);
+        let err = ::std::fs::read_to_string(&path).unwrap_err();
+        let message = format!(
+            concat!(
+                "read error: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n",
+                assertables_url_line!("assert_fs_read_to_string_eq_x"),
+                " a_path label: `&path`,\n",
+                " a_path debug: `{:?}`,\n",
+                " b_expr label: `&value`,\n",
+                " b_expr debug: `\"alfa\\n\"`,\n",
+                "   error kind: `NotFound`,\n",
+                "   error text: `{}`"
+            ),
+            path, err
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_error_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+        let path = ::std::env::temp_dir().join(format!(
+            "assertables-permission-denied-{}.txt",
+            ::std::process::id()
+        ));
+        ::std::fs::write(&path, "alfa\n").unwrap();
+        ::std::fs::set_permissions(&path, ::std::fs::Permissions::from_mode(0o000)).unwrap();
+        // A privileged user, such as root, can read the file anyway.
+        if ::std::fs::File::open(&path).is_ok() {
+            ::std::fs::remove_file(&path).unwrap();
+            return;
+        }
+        let value = String::from("alfa\n");
+        let actual = assert_fs_read_to_string_eq_x_as_result!(&path, &value);
+        ::std::fs::remove_file(&path).unwrap();
+        let message = actual.unwrap_err();
+        assert!(
+            message.starts_with("read error: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n")
+        );
+        assert!(message.contains("\n   error kind: `PermissionDenied`,\n"));
+    }
 }
 
 /// Assert a ::std::fs::read_to_string(path) value is equal to an expression.
@@ -201,7 +250,7 @@ mod test_assert_fs_read_to_string_eq_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_eq_x.html\n",
+/// #     assertables_url_line!("assert_fs_read_to_string_eq_x"),
 /// #     " a_path label: `&path`,\n",
 /// #     " a_path debug: `\"alfa.txt\"`,\n",
 /// #     " b_expr label: `&value`,\n",
@@ -224,13 +273,13 @@ macro_rules! assert_fs_read_to_string_eq_x {
     ($a_path:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_fs_read_to_string_eq_x_as_result!($a_path, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_path:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fs_read_to_string_eq_x_as_result!($a_path, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -269,7 +318,7 @@ mod test_assert_fs_read_to_string_eq_x {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_eq_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_eq_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -299,7 +348,7 @@ mod test_assert_fs_read_to_string_eq_x {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_eq_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_eq_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_eq_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -354,7 +403,7 @@ mod test_assert_fs_read_to_string_eq_x {
 #[macro_export]
 macro_rules! debug_assert_fs_read_to_string_eq_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::std::fs::read_to_string_eq_expr!($($arg)*);
         }
     };
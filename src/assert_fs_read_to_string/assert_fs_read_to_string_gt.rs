@@ -51,7 +51,7 @@ macro_rules! assert_fs_read_to_string_gt_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_gt!(a_path, b_path)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_gt.html\n",
+                                        $crate::assertables_url_line!("assert_fs_read_to_string_gt"),
                                         " a_path label: `{}`,\n",
                                         " a_path debug: `{:?}`,\n",
                                         " b_path label: `{}`,\n",
@@ -73,21 +73,25 @@ macro_rules! assert_fs_read_to_string_gt_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_fs_read_to_string_gt!(a_path, b_path)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_gt.html\n",
+                                    "read error: `assert_fs_read_to_string_gt!(a_path, b_path)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_string_gt"),
                                     " a_path label: `{}`,\n",
                                     " a_path debug: `{:?}`,\n",
                                     " b_path label: `{}`,\n",
                                     " b_path debug: `{:?}`,\n",
-                                    "     a result: `{:?}`,\n",
-                                    "     b result: `{:?}`"
+                                    " a error kind: `{:?}`,\n",
+                                    " a error text: `{:?}`,\n",
+                                    " b error kind: `{:?}`,\n",
+                                    " b error text: `{:?}`"
                                 ),
                                 stringify!($a_path),
                                 a_path,
                                 stringify!($b_path),
                                 b_path,
-                                a_result,
-                                b_result
+                                $crate::assert_fs_read_to_string::read_error_kind(&a_result),
+                                $crate::assert_fs_read_to_string::read_error_text(&a_result),
+                                $crate::assert_fs_read_to_string::read_error_kind(&b_result),
+                                $crate::assert_fs_read_to_string::read_error_text(&b_result)
                             )
                         )
                     }
@@ -131,7 +135,7 @@ mod test_assert_fs_read_to_string_gt_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_gt!(a_path, b_path)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_gt.html\n",
+                assertables_url_line!("assert_fs_read_to_string_gt"),
                 " a_path label: `&a`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_path label: `&b`,\n",
@@ -153,7 +157,7 @@ mod test_assert_fs_read_to_string_gt_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_gt!(a_path, b_path)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_gt.html\n",
+                assertables_url_line!("assert_fs_read_to_string_gt"),
                 " a_path label: `&a`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_path label: `&b`,\n",
@@ -207,7 +211,7 @@ mod test_assert_fs_read_to_string_gt_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fs_read_to_string_gt!(a_path, b_path)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_gt.html\n",
+/// #     assertables_url_line!("assert_fs_read_to_string_gt"),
 /// #     " a_path label: `&a`,\n",
 /// #     " a_path debug: `\"alfa.txt\"`,\n",
 /// #     " b_path label: `&b`,\n",
@@ -230,13 +234,13 @@ macro_rules! assert_fs_read_to_string_gt {
     ($a_path:expr, $b_path:expr $(,)?) => {{
         match $crate::assert_fs_read_to_string_gt_as_result!($a_path, $b_path) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_path:expr, $b_path:expr, $($message:tt)+) => {{
         match $crate::assert_fs_read_to_string_gt_as_result!($a_path, $b_path) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -275,7 +279,7 @@ mod test_assert_fs_read_to_string_gt {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_gt!(a_path, b_path)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_gt.html\n",
+                assertables_url_line!("assert_fs_read_to_string_gt"),
                 " a_path label: `&a`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_path label: `&b`,\n",
@@ -306,7 +310,7 @@ mod test_assert_fs_read_to_string_gt {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_gt!(a_path, b_path)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_gt.html\n",
+                assertables_url_line!("assert_fs_read_to_string_gt"),
                 " a_path label: `&a`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_path label: `&b`,\n",
@@ -362,7 +366,7 @@ mod test_assert_fs_read_to_string_gt {
 #[macro_export]
 macro_rules! debug_assert_fs_read_to_string_gt {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::std::fs::read_to_string_gt!($($arg)*);
         }
     };
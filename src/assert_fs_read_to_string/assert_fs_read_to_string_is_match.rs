@@ -52,7 +52,7 @@ macro_rules! assert_fs_read_to_string_is_match_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_is_match!(path, matcher)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_is_match.html\n",
+                                        $crate::assertables_url_line!("assert_fs_read_to_string_is_match"),
                                         "    path label: `{}`,\n",
                                         "    path debug: `{:?}`,\n",
                                         " matcher label: `{}`,\n",
@@ -72,18 +72,20 @@ macro_rules! assert_fs_read_to_string_is_match_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_fs_read_to_string_is_match!(path, matcher)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_is_match.html\n",
+                                    "read error: `assert_fs_read_to_string_is_match!(path, matcher)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_string_is_match"),
                                     "    path label: `{}`,\n",
                                     "    path debug: `{:?}`,\n",
                                     " matcher label: `{}`,\n",
                                     " matcher debug: `{:?}`,\n",
-                                    "           err: `{:?}`"
+                                    "    error kind: `{:?}`,\n",
+                                    "    error text: `{}`"
                                 ),
                                 stringify!($path),
                                 path,
                                 stringify!($matcher),
                                 matcher,
+                                err.kind(),
                                 err
                             )
                         )
@@ -124,7 +126,7 @@ mod test_assert_fs_read_to_string_is_match_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_is_match!(path, matcher)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_is_match.html\n",
+                assertables_url_line!("assert_fs_read_to_string_is_match"),
                 "    path label: `&path`,\n",
                 "    path debug: `{:?}`,\n",
                 " matcher label: `&matcher`,\n",
@@ -176,7 +178,7 @@ mod test_assert_fs_read_to_string_is_match_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fs_read_to_string_is_match!(path, matcher)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_is_match.html\n",
+/// #     assertables_url_line!("assert_fs_read_to_string_is_match"),
 /// #     "    path label: `&path`,\n",
 /// #     "    path debug: `\"alfa.txt\"`,\n",
 /// #     " matcher label: `&matcher`,\n",
@@ -198,13 +200,13 @@ macro_rules! assert_fs_read_to_string_is_match {
     ($path:expr, $matcher:expr $(,)?) => {{
         match $crate::assert_fs_read_to_string_is_match_as_result!($path, $matcher) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($path:expr, $matcher:expr, $($message:tt)+) => {{
         match $crate::assert_fs_read_to_string_is_match_as_result!($path, $matcher) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -242,7 +244,7 @@ mod test_assert_fs_read_to_string_is_match {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_is_match!(path, matcher)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_is_match.html\n",
+                assertables_url_line!("assert_fs_read_to_string_is_match"),
                 "    path label: `&path`,\n",
                 "    path debug: `{:?}`,\n",
                 " matcher label: `&matcher`,\n",
@@ -296,7 +298,7 @@ mod test_assert_fs_read_to_string_is_match {
 #[macro_export]
 macro_rules! debug_assert_fs_read_to_string_is_match {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::std::fs::read_to_string_is_match!($($arg)*);
         }
     };
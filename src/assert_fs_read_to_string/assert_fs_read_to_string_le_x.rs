@@ -52,7 +52,7 @@ macro_rules! assert_fs_read_to_string_le_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_le_x!(a_path, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_le_x.html\n",
+                                        $crate::assertables_url_line!("assert_fs_read_to_string_le_x"),
                                         " a_path label: `{}`,\n",
                                         " a_path debug: `{:?}`,\n",
                                         " b_expr label: `{}`,\n",
@@ -74,18 +74,20 @@ macro_rules! assert_fs_read_to_string_le_x_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_fs_read_to_string_le_x!(a_path, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_le_x.html\n",
+                                    "read error: `assert_fs_read_to_string_le_x!(a_path, b_expr)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_string_le_x"),
                                     " a_path label: `{}`,\n",
                                     " a_path debug: `{:?}`,\n",
                                     " b_expr label: `{}`,\n",
                                     " b_expr debug: `{:?}`,\n",
-                                    "          err: `{:?}`"
+                                    "   error kind: `{:?}`,\n",
+                                    "   error text: `{}`"
                                 ),
                                 stringify!($a_path),
                                 a_path,
                                 stringify!($b_expr),
                                 b_expr,
+                                err.kind(),
                                 err
                             )
                         )
@@ -135,7 +137,7 @@ mod test_assert_fs_read_to_string_le_x_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_le_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_le_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_le_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -188,7 +190,7 @@ mod test_assert_fs_read_to_string_le_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fs_read_to_string_le_x!(a_path, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_le_x.html\n",
+/// #     assertables_url_line!("assert_fs_read_to_string_le_x"),
 /// #     " a_path label: `&path`,\n",
 /// #     " a_path debug: `\"bravo.txt\"`,\n",
 /// #     " b_expr label: `&value`,\n",
@@ -211,13 +213,13 @@ macro_rules! assert_fs_read_to_string_le_x {
     ($a_path:expr,  $b_expr:expr $(,)?) => {{
         match $crate::assert_fs_read_to_string_le_x_as_result!($a_path, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_path:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fs_read_to_string_le_x_as_result!($a_path, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -264,7 +266,7 @@ mod test_assert_fs_read_to_string_le_x {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_le_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_le_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_le_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -319,7 +321,7 @@ mod test_assert_fs_read_to_string_le_x {
 #[macro_export]
 macro_rules! debug_assert_fs_read_to_string_le_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::std::fs::read_to_string_le_expr!($($arg)*);
         }
     };
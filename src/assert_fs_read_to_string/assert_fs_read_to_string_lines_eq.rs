@@ -0,0 +1,396 @@
+//! Assert a ::std::fs::read_to_string(path) is equal to another, line by line.
+//!
+//! Pseudocode:<br>
+//! std::fs::read_to_string(a_path).lines() = std::fs::read_to_string(b_path).lines()
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "alfa.txt";
+//! let b = "alfa.txt";
+//! assert_fs_read_to_string_lines_eq!(&a, &b);
+//! ```
+//!
+//! This macro splits each file into lines, then compares line by line.
+//! A line ending can be `\n` or `\r\n`. A trailing empty line is ignored,
+//! so a file that ends with a newline equals a file that does not.
+//!
+//! If the lines differ, the message shows the first differing line number,
+//! counting from 1, and each file's line there, or `None` if the file
+//! has no line there.
+//!
+//! # Module macros
+//!
+//! * [`assert_fs_read_to_string_lines_eq`](macro@crate::assert_fs_read_to_string_lines_eq)
+//! * [`assert_fs_read_to_string_lines_eq_as_result`](macro@crate::assert_fs_read_to_string_lines_eq_as_result)
+//! * [`debug_assert_fs_read_to_string_lines_eq`](macro@crate::debug_assert_fs_read_to_string_lines_eq)
+
+/// Assert a ::std::fs::read_to_string(path) is equal to another, line by line.
+///
+/// Pseudocode:<br>
+/// std::fs::read_to_string(a_path).lines() = std::fs::read_to_string(b_path).lines()
+///
+/// * If true, return Result `Ok((a_string, b_string))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_string_lines_eq`](macro@crate::assert_fs_read_to_string_lines_eq)
+/// * [`assert_fs_read_to_string_lines_eq_as_result`](macro@crate::assert_fs_read_to_string_lines_eq_as_result)
+/// * [`debug_assert_fs_read_to_string_lines_eq`](macro@crate::debug_assert_fs_read_to_string_lines_eq)
+///
+#[macro_export]
+macro_rules! assert_fs_read_to_string_lines_eq_as_result {
+    ($a_path:expr, $b_path:expr $(,)?) => {{
+        match (&$a_path, &$b_path) {
+            (a_path, b_path) => {
+                match (::std::fs::read_to_string(a_path), ::std::fs::read_to_string(b_path)) {
+                    (Ok(a_string), Ok(b_string)) => {
+                        match $crate::assert_fs_read_to_string::lines_first_difference(&a_string, &b_string) {
+                            None => Ok((a_string, b_string)),
+                            Some((line_number, a_line, b_line)) => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_fs_read_to_string_lines_eq!(a_path, b_path)`\n",
+                                            $crate::assertables_url_line!("assert_fs_read_to_string_lines_eq"),
+                                            " a_path label: `{}`,\n",
+                                            " a_path debug: `{:?}`,\n",
+                                            " b_path label: `{}`,\n",
+                                            " b_path debug: `{:?}`,\n",
+                                            "  line number: `{}`,\n",
+                                            "       a line: `{:?}`,\n",
+                                            "       b line: `{:?}`"
+                                        ),
+                                        stringify!($a_path),
+                                        a_path,
+                                        stringify!($b_path),
+                                        b_path,
+                                        line_number,
+                                        a_line,
+                                        b_line
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    (a_result, b_result) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "read error: `assert_fs_read_to_string_lines_eq!(a_path, b_path)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_string_lines_eq"),
+                                    " a_path label: `{}`,\n",
+                                    " a_path debug: `{:?}`,\n",
+                                    " b_path label: `{}`,\n",
+                                    " b_path debug: `{:?}`,\n",
+                                    " a error kind: `{:?}`,\n",
+                                    " a error text: `{:?}`,\n",
+                                    " b error kind: `{:?}`,\n",
+                                    " b error text: `{:?}`"
+                                ),
+                                stringify!($a_path),
+                                a_path,
+                                stringify!($b_path),
+                                b_path,
+                                $crate::assert_fs_read_to_string::read_error_kind(&a_result),
+                                $crate::assert_fs_read_to_string::read_error_text(&a_result),
+                                $crate::assert_fs_read_to_string::read_error_kind(&b_result),
+                                $crate::assert_fs_read_to_string::read_error_text(&b_result)
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_fs_read_to_string_lines_eq_as_result {
+    use std::path::PathBuf;
+    use std::sync::LazyLock;
+
+    pub static DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("src")
+            .join("std")
+            .join("fs")
+    });
+
+    #[test]
+    fn success() {
+        let a = DIR.join("alfa.txt");
+        let b = DIR.join("alfa.txt");
+        let actual = assert_fs_read_to_string_lines_eq_as_result!(&a, &b);
+        assert_eq!(
+            actual.unwrap(),
+            (String::from("alfa\n"), String::from("alfa\n"))
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = DIR.join("alfa.txt");
+        let b = DIR.join("bravo.txt");
+        let actual = assert_fs_read_to_string_lines_eq_as_result!(&a, &b);
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_string_lines_eq!(a_path, b_path)`\n",
+                assertables_url_line!("assert_fs_read_to_string_lines_eq"),
+                " a_path label: `&a`,\n",
+                " a_path debug: `{:?}`,\n",
+                " b_path label: `&b`,\n",
+                " b_path debug: `{:?}`,\n",
+                "  line number: `1`,\n",
+                "       a line: `Some(\"alfa\")`,\n",
+                "       b line: `Some(\"bravo\")`"
+            ),
+            DIR.join("alfa.txt"),
+            DIR.join("bravo.txt")
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn ignores_trailing_newline() {
+        let a = DIR.join("lines.txt");
+        let b = DIR.join("lines_without_trailing_newline.txt");
+        let actual = assert_fs_read_to_string_lines_eq_as_result!(&a, &b);
+        assert_eq!(
+            actual.unwrap(),
+            (
+                String::from("alfa\nbravo\ncharlie\n"),
+                String::from("alfa\nbravo\ncharlie")
+            )
+        );
+    }
+
+    #[test]
+    fn reports_first_differing_line() {
+        let a = DIR.join("lines.txt");
+        let b = DIR.join("lines_with_difference.txt");
+        let actual = assert_fs_read_to_string_lines_eq_as_result!(&a, &b);
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_string_lines_eq!(a_path, b_path)`\n",
+                assertables_url_line!("assert_fs_read_to_string_lines_eq"),
+                " a_path label: `&a`,\n",
+                " a_path debug: `{:?}`,\n",
+                " b_path label: `&b`,\n",
+                " b_path debug: `{:?}`,\n",
+                "  line number: `3`,\n",
+                "       a line: `Some(\"charlie\")`,\n",
+                "       b line: `Some(\"delta\")`"
+            ),
+            a, b
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn reports_missing_line() {
+        let a = DIR.join("lines.txt");
+        let b = DIR.join("alfa.txt");
+        let actual = assert_fs_read_to_string_lines_eq_as_result!(&a, &b);
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_string_lines_eq!(a_path, b_path)`\n",
+                assertables_url_line!("assert_fs_read_to_string_lines_eq"),
+                " a_path label: `&a`,\n",
+                " a_path debug: `{:?}`,\n",
+                " b_path label: `&b`,\n",
+                " b_path debug: `{:?}`,\n",
+                "  line number: `2`,\n",
+                "       a line: `Some(\"bravo\")`,\n",
+                "       b line: `None`"
+            ),
+            a, b
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a ::std::fs::read_to_string(path) is equal to another, line by line.
+///
+/// Pseudocode:<br>
+/// std::fs::read_to_string(a_path).lines() = std::fs::read_to_string(b_path).lines()
+///
+/// * If true, return `(a_string, b_string)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa.txt";
+/// let b = "alfa.txt";
+/// assert_fs_read_to_string_lines_eq!(&a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa.txt";
+/// let b = "bravo.txt";
+/// assert_fs_read_to_string_lines_eq!(&a, &b);
+/// # });
+/// // assertion failed: `assert_fs_read_to_string_lines_eq!(a_path, b_path)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_lines_eq.html
+/// //  a_path label: `&a`,
+/// //  a_path debug: `\"alfa.txt\"`,
+/// //  b_path label: `&b`,
+/// //  b_path debug: `\"bravo.txt\"`,
+/// //   line number: `1`,
+/// //        a line: `Some(\"alfa\")`,
+/// //        b line: `Some(\"bravo\")`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_fs_read_to_string_lines_eq!(a_path, b_path)`\n",
+/// #     assertables_url_line!("assert_fs_read_to_string_lines_eq"),
+/// #     " a_path label: `&a`,\n",
+/// #     " a_path debug: `\"alfa.txt\"`,\n",
+/// #     " b_path label: `&b`,\n",
+/// #     " b_path debug: `\"bravo.txt\"`,\n",
+/// #     "  line number: `1`,\n",
+/// #     "       a line: `Some(\"alfa\")`,\n",
+/// #     "       b line: `Some(\"bravo\")`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro splits each file into lines, then compares line by line.
+/// A line ending can be `\n` or `\r\n`. A trailing empty line is ignored,
+/// so a file that ends with a newline equals a file that does not.
+///
+/// If the lines differ, the message shows the first differing line number,
+/// counting from 1, and each file's line there, or `None` if the file
+/// has no line there.
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_string_lines_eq`](macro@crate::assert_fs_read_to_string_lines_eq)
+/// * [`assert_fs_read_to_string_lines_eq_as_result`](macro@crate::assert_fs_read_to_string_lines_eq_as_result)
+/// * [`debug_assert_fs_read_to_string_lines_eq`](macro@crate::debug_assert_fs_read_to_string_lines_eq)
+///
+#[macro_export]
+macro_rules! assert_fs_read_to_string_lines_eq {
+    ($a_path:expr, $b_path:expr $(,)?) => {{
+        match $crate::assert_fs_read_to_string_lines_eq_as_result!($a_path, $b_path) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_path:expr, $b_path:expr, $($message:tt)+) => {{
+        match $crate::assert_fs_read_to_string_lines_eq_as_result!($a_path, $b_path) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_fs_read_to_string_lines_eq {
+    use std::panic;
+    use std::path::PathBuf;
+    use std::sync::LazyLock;
+
+    pub static DIR: LazyLock<PathBuf> = LazyLock::new(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("src")
+            .join("std")
+            .join("fs")
+    });
+
+    #[test]
+    fn success() {
+        let a = DIR.join("alfa.txt");
+        let b = DIR.join("alfa.txt");
+        let actual = assert_fs_read_to_string_lines_eq!(&a, &b);
+        assert_eq!(actual, (String::from("alfa\n"), String::from("alfa\n")));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = DIR.join("alfa.txt");
+            let b = DIR.join("bravo.txt");
+            let _actual = assert_fs_read_to_string_lines_eq!(&a, &b);
+        });
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_fs_read_to_string_lines_eq!(a_path, b_path)`\n",
+                assertables_url_line!("assert_fs_read_to_string_lines_eq"),
+                " a_path label: `&a`,\n",
+                " a_path debug: `{:?}`,\n",
+                " b_path label: `&b`,\n",
+                " b_path debug: `{:?}`,\n",
+                "  line number: `1`,\n",
+                "       a line: `Some(\"alfa\")`,\n",
+                "       b line: `Some(\"bravo\")`"
+            ),
+            DIR.join("alfa.txt"),
+            DIR.join("bravo.txt")
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a ::std::fs::read_to_string(path) is equal to another, line by line.
+///
+/// Pseudocode:<br>
+/// std::fs::read_to_string(a_path).lines() = std::fs::read_to_string(b_path).lines()
+///
+/// This macro provides the same statements as [`assert_fs_read_to_string_lines_eq`](macro.assert_fs_read_to_string_lines_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_fs_read_to_string_lines_eq`](macro@crate::assert_fs_read_to_string_lines_eq)
+/// * [`assert_fs_read_to_string_lines_eq_as_result`](macro@crate::assert_fs_read_to_string_lines_eq_as_result)
+/// * [`debug_assert_fs_read_to_string_lines_eq`](macro@crate::debug_assert_fs_read_to_string_lines_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_fs_read_to_string_lines_eq {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_fs_read_to_string_lines_eq!($($arg)*);
+        }
+    };
+}
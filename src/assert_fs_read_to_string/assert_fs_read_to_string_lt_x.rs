@@ -52,7 +52,7 @@ macro_rules! assert_fs_read_to_string_lt_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_fs_read_to_string_lt_x!(a_path, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_lt_x.html\n",
+                                        $crate::assertables_url_line!("assert_fs_read_to_string_lt_x"),
                                         " a_path label: `{}`,\n",
                                         " a_path debug: `{:?}`,\n",
                                         " b_expr label: `{}`,\n",
@@ -74,18 +74,20 @@ macro_rules! assert_fs_read_to_string_lt_x_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_fs_read_to_string_lt_x!(a_path, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_lt_x.html\n",
+                                    "read error: `assert_fs_read_to_string_lt_x!(a_path, b_expr)`\n",
+                                    $crate::assertables_url_line!("assert_fs_read_to_string_lt_x"),
                                     " a_path label: `{}`,\n",
                                     " a_path debug: `{:?}`,\n",
                                     " b_expr label: `{}`,\n",
                                     " b_expr debug: `{:?}`,\n",
-                                    "          err: `{:?}`"
+                                    "   error kind: `{:?}`,\n",
+                                    "   error text: `{}`"
                                 ),
                                 stringify!($a_path),
                                 a_path,
                                 stringify!($b_expr),
                                 b_expr,
+                                err.kind(),
                                 err
                             )
                         )
@@ -127,7 +129,7 @@ mod test_assert_fs_read_to_string_lt_x_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_lt_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_lt_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_lt_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -148,7 +150,7 @@ mod test_assert_fs_read_to_string_lt_x_as_result {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_lt_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_lt_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_lt_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -201,7 +203,7 @@ mod test_assert_fs_read_to_string_lt_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_fs_read_to_string_lt_x!(a_path, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_lt_x.html\n",
+/// #     assertables_url_line!("assert_fs_read_to_string_lt_x"),
 /// #     " a_path label: `&path`,\n",
 /// #     " a_path debug: `\"bravo.txt\"`,\n",
 /// #     " b_expr label: `&value`,\n",
@@ -224,13 +226,13 @@ macro_rules! assert_fs_read_to_string_lt_x {
     ($a_path:expr,  $b_expr:expr $(,)?) => {{
         match $crate::assert_fs_read_to_string_lt_x_as_result!($a_path, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_path:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_fs_read_to_string_lt_x_as_result!($a_path, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -269,7 +271,7 @@ mod test_assert_fs_read_to_string_lt_x {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_lt_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_lt_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_lt_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -299,7 +301,7 @@ mod test_assert_fs_read_to_string_lt_x {
         let message = format!(
             concat!(
                 "assertion failed: `assert_fs_read_to_string_lt_x!(a_path, b_expr)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_fs_read_to_string_lt_x.html\n",
+                assertables_url_line!("assert_fs_read_to_string_lt_x"),
                 " a_path label: `&path`,\n",
                 " a_path debug: `{:?}`,\n",
                 " b_expr label: `&value`,\n",
@@ -354,7 +356,7 @@ mod test_assert_fs_read_to_string_lt_x {
 #[macro_export]
 macro_rules! debug_assert_fs_read_to_string_lt_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::std::fs::read_to_string_lt_expr!($($arg)*);
         }
     };
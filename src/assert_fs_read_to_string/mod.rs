@@ -22,11 +22,22 @@
 //! * [`assert_fs_read_to_string_gt_x!(path, expr)`](macro@crate::assert_fs_read_to_string_gt_x) ≈ std::fs::read_to_string(path) > expr
 //! * [`assert_fs_read_to_string_ge_x!(path, expr)`](macro@crate::assert_fs_read_to_string_ge_x) ≈ std::fs::read_to_string(path) ≥ expr
 //!
+//! Compare a path with another path, line by line:
+//!
+//! * [`assert_fs_read_to_string_lines_eq!(path1, path2)`](macro@crate::assert_fs_read_to_string_lines_eq) ≈ std::fs::read_to_string(path1).lines() = std::fs::read_to_string(path2).lines()
+//!
 //! Compare a path with its contents:
 //!
 //! * [`assert_fs_read_to_string_contains!(path, containee)`](macro@crate::assert_fs_read_to_string_contains) ≈ std::fs::read_to_string(path).contains(containee)
 //! * [`assert_fs_read_to_string_is_match!(path, matcher)`](macro@crate::assert_fs_read_to_string_is_match) ≈ matcher.is_match(::std::fs::read_to_string(path))
 //!
+//! If a path cannot be read, such as when the file is missing, then the
+//! message starts with "read error:" rather than "assertion failed:", and
+//! shows the OS error kind, such as `NotFound` or `PermissionDenied`, and
+//! the OS error text. This applies to both the panic macros and the
+//! `_as_result` macros, so a caller can tell a read error apart from
+//! a content mismatch.
+//!
 //! # Example
 //!
 //! ```rust
@@ -83,7 +94,47 @@ pub mod assert_fs_read_to_string_le_x;
 pub mod assert_fs_read_to_string_lt_x;
 pub mod assert_fs_read_to_string_ne_x;
 
+// Compare lines
+pub mod assert_fs_read_to_string_lines_eq;
+
 // Specializations
 pub mod assert_fs_read_to_string_contains;
 pub mod assert_fs_read_to_string_is_match;
 pub mod assert_fs_read_to_string_matches; // Deprecated.
+
+/// Return the error kind of a read result, or None if the read succeeded.
+#[doc(hidden)]
+pub fn read_error_kind<T>(result: &::std::io::Result<T>) -> Option<::std::io::ErrorKind> {
+    result.as_ref().err().map(|err| err.kind())
+}
+
+/// Return the error text of a read result, or None if the read succeeded.
+#[doc(hidden)]
+pub fn read_error_text<T>(result: &::std::io::Result<T>) -> Option<String> {
+    result.as_ref().err().map(|err| err.to_string())
+}
+
+/// Return the first line where two strings differ, or None if the lines match.
+///
+/// The result is the line number, counting from 1, and each string's line
+/// there, or None if the string has no line there. Lines are split the same
+/// way as `str::lines`, and a trailing empty line is ignored.
+#[doc(hidden)]
+pub fn lines_first_difference<'a>(
+    a: &'a str,
+    b: &'a str,
+) -> Option<(usize, Option<&'a str>, Option<&'a str>)> {
+    let lines = |s: &'a str| {
+        let mut lines: Vec<&'a str> = s.lines().collect();
+        if lines.last() == Some(&"") {
+            lines.pop();
+        }
+        lines
+    };
+    let a_lines = lines(a);
+    let b_lines = lines(b);
+    (0..a_lines.len().max(b_lines.len()))
+        .map(|i| (i, a_lines.get(i).copied(), b_lines.get(i).copied()))
+        .find(|(_, a_line, b_line)| a_line != b_line)
+        .map(|(i, a_line, b_line)| (i + 1, a_line, b_line))
+}
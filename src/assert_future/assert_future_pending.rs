@@ -0,0 +1,246 @@
+//! Assert a future is Pending when polled once.
+//!
+//! Pseudocode:<br>
+//! a ⇒ poll once ⇒ Pending
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = std::future::pending::<i8>();
+//! assert_future_pending!(a);
+//! ```
+//!
+//! The macro pins the future, then polls it exactly one time, with a waker
+//! that does nothing, so no async runtime is needed. The future is consumed.
+//!
+//! # Module macros
+//!
+//! * [`assert_future_pending`](macro@crate::assert_future_pending)
+//! * [`assert_future_pending_as_result`](macro@crate::assert_future_pending_as_result)
+//! * [`debug_assert_future_pending`](macro@crate::debug_assert_future_pending)
+
+/// Assert a future is Pending when polled once.
+///
+/// Pseudocode:<br>
+/// a ⇒ poll once ⇒ Pending
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_future_pending`](macro@crate::assert_future_pending)
+/// * [`assert_future_pending_as_result`](macro@crate::assert_future_pending_as_result)
+/// * [`debug_assert_future_pending`](macro@crate::debug_assert_future_pending)
+///
+#[macro_export]
+macro_rules! assert_future_pending_as_result {
+    ($a:expr $(,)?) => {{
+        match ($crate::assert_future::poll_once($a)) {
+            ::std::task::Poll::Pending => Ok(()),
+            poll => Err(
+                format!(
+                    concat!(
+                        "assertion failed: `assert_future_pending!(a)`\n",
+                        $crate::assertables_url_line!("assert_future_pending"),
+                        " a label: `{}`,\n",
+                        "  a poll: `{:?}`"
+                    ),
+                    stringify!($a),
+                    poll
+                )
+            ),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_future_pending_as_result {
+    #[test]
+    fn success() {
+        let a = std::future::pending::<i8>();
+        let actual = assert_future_pending_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = std::future::ready(1);
+        let actual = assert_future_pending_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_future_pending!(a)`\n",
+            assertables_url_line!("assert_future_pending"),
+            " a label: `a`,\n",
+            "  a poll: `Ready(1)`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_async_block_that_awaits_pending() {
+        let a = async {
+            std::future::pending::<()>().await;
+            1
+        };
+        let actual = assert_future_pending_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_async_block() {
+        let a = async { 1 };
+        let actual = assert_future_pending_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_future_pending!(a)`\n",
+            assertables_url_line!("assert_future_pending"),
+            " a label: `a`,\n",
+            "  a poll: `Ready(1)`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a future is Pending when polled once.
+///
+/// Pseudocode:<br>
+/// a ⇒ poll once ⇒ Pending
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = std::future::pending::<i8>();
+/// assert_future_pending!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = std::future::ready(1);
+/// assert_future_pending!(a);
+/// # });
+/// // assertion failed: `assert_future_pending!(a)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_future_pending.html
+/// //  a label: `a`,
+/// //   a poll: `Ready(1)`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_future_pending!(a)`\n",
+/// #     assertables_url_line!("assert_future_pending"),
+/// #     " a label: `a`,\n",
+/// #     "  a poll: `Ready(1)`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The macro pins the future, then polls it exactly one time, with a waker
+/// that does nothing, so no async runtime is needed. The future is consumed.
+///
+/// # Module macros
+///
+/// * [`assert_future_pending`](macro@crate::assert_future_pending)
+/// * [`assert_future_pending_as_result`](macro@crate::assert_future_pending_as_result)
+/// * [`debug_assert_future_pending`](macro@crate::debug_assert_future_pending)
+///
+#[macro_export]
+macro_rules! assert_future_pending {
+    ($a:expr $(,)?) => {{
+        match $crate::assert_future_pending_as_result!($a) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $($message:tt)+) => {{
+        match $crate::assert_future_pending_as_result!($a) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_future_pending {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = std::future::pending::<i8>();
+        let actual = assert_future_pending!(a);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = std::future::ready(1);
+            let _actual = assert_future_pending!(a);
+        });
+        let message = concat!(
+            "assertion failed: `assert_future_pending!(a)`\n",
+            assertables_url_line!("assert_future_pending"),
+            " a label: `a`,\n",
+            "  a poll: `Ready(1)`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a future is Pending when polled once.
+///
+/// Pseudocode:<br>
+/// a ⇒ poll once ⇒ Pending
+///
+/// This macro provides the same statements as [`assert_future_pending`](macro.assert_future_pending.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_future_pending`](macro@crate::assert_future_pending)
+/// * [`assert_future_pending_as_result`](macro@crate::assert_future_pending_as_result)
+/// * [`debug_assert_future_pending`](macro@crate::debug_assert_future_pending)
+///
+#[macro_export]
+macro_rules! debug_assert_future_pending {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_future_pending!($($arg)*);
+        }
+    };
+}
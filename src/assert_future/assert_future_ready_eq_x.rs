@@ -0,0 +1,303 @@
+//! Assert a future is Ready when polled once, and its value is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ poll once ⇒ Ready(a1) ⇒ a1) = b
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = std::future::ready(1);
+//! let b: i8 = 1;
+//! assert_future_ready_eq_x!(a, b);
+//! ```
+//!
+//! The macro pins the future, then polls it exactly one time, with a waker
+//! that does nothing, so no async runtime is needed. The future is consumed.
+//!
+//! # Module macros
+//!
+//! * [`assert_future_ready_eq_x`](macro@crate::assert_future_ready_eq_x)
+//! * [`assert_future_ready_eq_x_as_result`](macro@crate::assert_future_ready_eq_x_as_result)
+//! * [`debug_assert_future_ready_eq_x`](macro@crate::debug_assert_future_ready_eq_x)
+
+/// Assert a future is Ready when polled once, and its value is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ poll once ⇒ Ready(a1) ⇒ a1) = b
+///
+/// * If true, return Result `Ok(a1)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_future_ready_eq_x`](macro@crate::assert_future_ready_eq_x)
+/// * [`assert_future_ready_eq_x_as_result`](macro@crate::assert_future_ready_eq_x_as_result)
+/// * [`debug_assert_future_ready_eq_x`](macro@crate::debug_assert_future_ready_eq_x)
+///
+#[macro_export]
+macro_rules! assert_future_ready_eq_x_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match ($crate::assert_future::poll_once($a), &$b) {
+            (::std::task::Poll::Ready(a1), b) => {
+                if a1 == *b {
+                    Ok(a1)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_future_ready_eq_x!(a, b)`\n",
+                                $crate::assertables_url_line!("assert_future_ready_eq_x"),
+                                " a label: `{}`,\n",
+                                "  a poll: `Ready({:?})`,\n",
+                                " a inner: `{:?}`,\n",
+                                " b label: `{}`,\n",
+                                " b debug: `{:?}`"
+                            ),
+                            stringify!($a),
+                            a1,
+                            a1,
+                            stringify!($b),
+                            b
+                        )
+                    )
+                }
+            },
+            (poll, b) => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_future_ready_eq_x!(a, b)`\n",
+                            $crate::assertables_url_line!("assert_future_ready_eq_x"),
+                            " a label: `{}`,\n",
+                            "  a poll: `{:?}`,\n",
+                            " b label: `{}`,\n",
+                            " b debug: `{:?}`"
+                        ),
+                        stringify!($a),
+                        poll,
+                        stringify!($b),
+                        b
+                    )
+                )
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_future_ready_eq_x_as_result {
+    #[test]
+    fn success() {
+        let a = std::future::ready(1);
+        let b: i8 = 1;
+        let actual = assert_future_ready_eq_x_as_result!(a, b);
+        assert_eq!(actual.unwrap(), 1);
+    }
+
+    #[test]
+    fn failure() {
+        let a = std::future::ready(1);
+        let b: i8 = 2;
+        let actual = assert_future_ready_eq_x_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_future_ready_eq_x!(a, b)`\n",
+            assertables_url_line!("assert_future_ready_eq_x"),
+            " a label: `a`,\n",
+            "  a poll: `Ready(1)`,\n",
+            " a inner: `1`,\n",
+            " b label: `b`,\n",
+            " b debug: `2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_async_block() {
+        let a = async { String::from("alfa") };
+        let actual = assert_future_ready_eq_x_as_result!(a, "alfa");
+        assert_eq!(actual.unwrap(), "alfa");
+    }
+
+    #[test]
+    fn failure_because_pending() {
+        let a = std::future::pending::<i8>();
+        let b: i8 = 1;
+        let actual = assert_future_ready_eq_x_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_future_ready_eq_x!(a, b)`\n",
+            assertables_url_line!("assert_future_ready_eq_x"),
+            " a label: `a`,\n",
+            "  a poll: `Pending`,\n",
+            " b label: `b`,\n",
+            " b debug: `1`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a future is Ready when polled once, and its value is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ poll once ⇒ Ready(a1) ⇒ a1) = b
+///
+/// * If true, return `a1`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = std::future::ready(1);
+/// let b: i8 = 1;
+/// assert_future_ready_eq_x!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = std::future::ready(1);
+/// let b: i8 = 2;
+/// assert_future_ready_eq_x!(a, b);
+/// # });
+/// // assertion failed: `assert_future_ready_eq_x!(a, b)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_future_ready_eq_x.html
+/// //  a label: `a`,
+/// //   a poll: `Ready(1)`,
+/// //  a inner: `1`,
+/// //  b label: `b`,
+/// //  b debug: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_future_ready_eq_x!(a, b)`\n",
+/// #     assertables_url_line!("assert_future_ready_eq_x"),
+/// #     " a label: `a`,\n",
+/// #     "  a poll: `Ready(1)`,\n",
+/// #     " a inner: `1`,\n",
+/// #     " b label: `b`,\n",
+/// #     " b debug: `2`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The macro pins the future, then polls it exactly one time, with a waker
+/// that does nothing, so no async runtime is needed. The future is consumed.
+///
+/// # Module macros
+///
+/// * [`assert_future_ready_eq_x`](macro@crate::assert_future_ready_eq_x)
+/// * [`assert_future_ready_eq_x_as_result`](macro@crate::assert_future_ready_eq_x_as_result)
+/// * [`debug_assert_future_ready_eq_x`](macro@crate::debug_assert_future_ready_eq_x)
+///
+#[macro_export]
+macro_rules! assert_future_ready_eq_x {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_future_ready_eq_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_future_ready_eq_x_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_future_ready_eq_x {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = std::future::ready(1);
+        let b: i8 = 1;
+        let actual = assert_future_ready_eq_x!(a, b);
+        assert_eq!(actual, 1);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = std::future::ready(1);
+            let b: i8 = 2;
+            let _actual = assert_future_ready_eq_x!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_future_ready_eq_x!(a, b)`\n",
+            assertables_url_line!("assert_future_ready_eq_x"),
+            " a label: `a`,\n",
+            "  a poll: `Ready(1)`,\n",
+            " a inner: `1`,\n",
+            " b label: `b`,\n",
+            " b debug: `2`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+
+    #[test]
+    fn failure_because_pending() {
+        let result = panic::catch_unwind(|| {
+            let a = std::future::pending::<i8>();
+            let _actual = assert_future_ready_eq_x!(a, 1);
+        });
+        assert!(result.is_err());
+    }
+}
+
+/// Assert a future is Ready when polled once, and its value is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a ⇒ poll once ⇒ Ready(a1) ⇒ a1) = b
+///
+/// This macro provides the same statements as [`assert_future_ready_eq_x`](macro.assert_future_ready_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_future_ready_eq_x`](macro@crate::assert_future_ready_eq_x)
+/// * [`assert_future_ready_eq_x_as_result`](macro@crate::assert_future_ready_eq_x_as_result)
+/// * [`debug_assert_future_ready_eq_x`](macro@crate::debug_assert_future_ready_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_future_ready_eq_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_future_ready_eq_x!($($arg)*);
+        }
+    };
+}
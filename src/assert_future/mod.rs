@@ -0,0 +1,50 @@
+//! Assert for Future items.
+//!
+//! These macros help check a [`Future`](std::future::Future) by polling it
+//! one time, without needing an async runtime. Each macro pins the future,
+//! then polls it with a waker that does nothing, then checks the `Poll`.
+//!
+//! Assert a future is Pending:
+//!
+//! * [`assert_future_pending!(a)`](macro@crate::assert_future_pending)
+//!   ≈ a ⇒ poll once ⇒ Pending
+//!
+//! Compare a future's Ready(…) to an expression:
+//!
+//! * [`assert_future_ready_eq_x!(a, expr)`](macro@crate::assert_future_ready_eq_x)
+//!   ≈ (a ⇒ poll once ⇒ Ready(a1) ⇒ a1) = expr
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = async { 1 };
+//! assert_future_ready_eq_x!(a, 1);
+//!
+//! let a = std::future::pending::<i8>();
+//! assert_future_pending!(a);
+//! ```
+
+pub mod assert_future_pending;
+pub mod assert_future_ready_eq_x;
+
+/// Poll a future one time, with a waker that does nothing.
+///
+/// The future is pinned on the stack, so it need not be `Unpin`,
+/// and the future is consumed. This is sufficient for checking a
+/// future that is immediately ready or immediately pending.
+#[doc(hidden)]
+pub fn poll_once<F: ::std::future::Future>(future: F) -> ::std::task::Poll<F::Output> {
+    let mut future = ::std::pin::pin!(future);
+    let waker = ::std::task::Waker::from(::std::sync::Arc::new(NoopWake));
+    let mut context = ::std::task::Context::from_waker(&waker);
+    future.as_mut().poll(&mut context)
+}
+
+/// A waker that does nothing when woken.
+struct NoopWake;
+
+impl ::std::task::Wake for NoopWake {
+    fn wake(self: ::std::sync::Arc<Self>) {}
+}
@@ -13,6 +13,12 @@
 //! assert_ge!(a, b);
 //! ```
 //!
+//! The two expressions can have different types, when the type of `a`
+//! implements `PartialOrd` for the type of `b`, such as `Path` and `PathBuf`.
+//! Rust std has no such impl for some pairs, such as `i32` and `i64`,
+//! or `&str` and `String`, so convert one side first, such as with
+//! `i64::from(a)` or `b.as_str()`.
+//!
 //! # Module macros
 //!
 //! * [`assert_ge`](macro@crate::assert_ge)
@@ -45,10 +51,10 @@ macro_rules! assert_ge_as_result {
                 if a >= b {
                     Ok(())
                 } else {
-                    Err(format!(
+                    Err($crate::__private::format!(
                         concat!(
                             "assertion failed: `assert_ge!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ge.html\n",
+                            $crate::assertables_url_line!("assert_ge"),
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
@@ -91,7 +97,7 @@ mod test_assert_ge_as_result {
         let actual = assert_ge_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_ge!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ge.html\n",
+            assertables_url_line!("assert_ge"),
             " a label: `a`,\n",
             " a debug: `1`,\n",
             " b label: `b`,\n",
@@ -99,6 +105,101 @@ mod test_assert_ge_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    mod different_types {
+        use std::ffi::OsString;
+        use std::path::{Path, PathBuf};
+
+        #[derive(Debug)]
+        struct Meters(f64);
+
+        #[derive(Debug)]
+        struct Feet(f64);
+
+        impl PartialEq<Feet> for Meters {
+            fn eq(&self, other: &Feet) -> bool {
+                self.0 * 3.28 == other.0
+            }
+        }
+
+        impl PartialOrd<Feet> for Meters {
+            fn partial_cmp(&self, other: &Feet) -> Option<std::cmp::Ordering> {
+                (self.0 * 3.28).partial_cmp(&other.0)
+            }
+        }
+
+        #[test]
+        fn success_with_path_and_path_buf() {
+            let a: &Path = Path::new("bravo");
+            let b: PathBuf = PathBuf::from("alfa");
+            let actual = assert_ge_as_result!(a, &b);
+            assert_eq!(actual.unwrap(), ());
+        }
+
+        #[test]
+        fn success_with_os_string_and_str() {
+            let a: OsString = OsString::from("bravo");
+            let b: &str = "alfa";
+            let actual = assert_ge_as_result!(a, *b);
+            assert_eq!(actual.unwrap(), ());
+        }
+
+        #[test]
+        fn success_with_converted_str_and_string() {
+            let a: &str = "bravo";
+            let b: String = String::from("alfa");
+            let actual = assert_ge_as_result!(a, b.as_str());
+            assert_eq!(actual.unwrap(), ());
+        }
+
+        #[test]
+        fn success_with_widened_number() {
+            let a: i32 = 2;
+            let b: i64 = 1;
+            let actual = assert_ge_as_result!(i64::from(a), b);
+            assert_eq!(actual.unwrap(), ());
+        }
+
+        #[test]
+        fn success_with_custom_types() {
+            let a = Meters(1.0);
+            let b = Feet(3.0);
+            let actual = assert_ge_as_result!(a, b);
+            assert_eq!(actual.unwrap(), ());
+        }
+
+        #[test]
+        fn failure_with_custom_types() {
+            let a = Meters(1.0);
+            let b = Feet(4.0);
+            let actual = assert_ge_as_result!(a, b);
+            let message = concat!(
+                "assertion failed: `assert_ge!(a, b)`\n",
+                assertables_url_line!("assert_ge"),
+                " a label: `a`,\n",
+                " a debug: `Meters(1.0)`,\n",
+                " b label: `b`,\n",
+                " b debug: `Feet(4.0)`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn failure_with_path_and_path_buf() {
+            let a: &Path = Path::new("alfa");
+            let b: PathBuf = PathBuf::from("bravo");
+            let actual = assert_ge_as_result!(a, &b);
+            let message = concat!(
+                "assertion failed: `assert_ge!(a, b)`\n",
+                assertables_url_line!("assert_ge"),
+                " a label: `a`,\n",
+                " a debug: `\"alfa\"`,\n",
+                " b label: `&b`,\n",
+                " b debug: `\"bravo\"`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+    }
 }
 
 /// Assert an expression is greater than or equal to another.
@@ -137,7 +238,7 @@ mod test_assert_ge_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_ge!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ge.html\n",
+/// #     assertables_url_line!("assert_ge"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `1`,\n",
 /// #     " b label: `b`,\n",
@@ -158,13 +259,13 @@ macro_rules! assert_ge {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_ge_as_result!($a, $b) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_ge_as_result!($a, $b) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -198,7 +299,7 @@ mod test_assert_ge {
         });
         let message = concat!(
             "assertion failed: `assert_ge!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ge.html\n",
+            assertables_url_line!("assert_ge"),
             " a label: `a`,\n",
             " a debug: `1`,\n",
             " b label: `b`,\n",
@@ -249,7 +350,7 @@ mod test_assert_ge {
 #[macro_export]
 macro_rules! debug_assert_ge {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_ge!($($arg)*);
         }
     };
@@ -43,10 +43,10 @@ macro_rules! assert_in_as_result {
         if $container.contains(&$a) {
             Ok(())
         } else {
-            Err(format!(
+            Err($crate::__private::format!(
                 concat!(
                     "assertion failed: `assert_in!(a, container)`\n",
-                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in.html\n",
+                    $crate::assertables_url_line!("assert_in"),
                     "         a label: `{}`,\n",
                     "         a debug: `{:?}`,\n",
                     " container label: `{}`,\n",
@@ -79,7 +79,7 @@ mod test_assert_in_as_result {
         let actual = assert_in_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_in!(a, container)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in.html\n",
+            assertables_url_line!("assert_in"),
             "         a label: `a`,\n",
             "         a debug: `1`,\n",
             " container label: `b`,\n",
@@ -125,7 +125,7 @@ mod test_assert_in_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_in!(a, container)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in.html\n",
+/// #     assertables_url_line!("assert_in"),
 /// #     "         a label: `a`,\n",
 /// #     "         a debug: `1`,\n",
 /// #     " container label: `b`,\n",
@@ -146,13 +146,13 @@ macro_rules! assert_in {
     ($a:expr, $container:expr $(,)?) => {{
         match $crate::assert_in_as_result!($a, $container) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $container:expr, $($message:tt)+) => {{
         match $crate::assert_in_as_result!($a, $container) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -178,7 +178,7 @@ mod test_assert_in {
         });
         let message = concat!(
             "assertion failed: `assert_in!(a, container)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in.html\n",
+            assertables_url_line!("assert_in"),
             "         a label: `a`,\n",
             "         a debug: `1`,\n",
             " container label: `b`,\n",
@@ -229,7 +229,7 @@ mod test_assert_in {
 #[macro_export]
 macro_rules! debug_assert_in {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_in!($($arg)*);
         }
     };
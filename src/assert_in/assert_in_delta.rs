@@ -14,6 +14,10 @@
 //! assert_in_delta!(a, b, delta);
 //! ```
 //!
+//! The absolute difference is computed by subtracting the smaller number
+//! from the larger number, so unsigned numbers such as `u8` and `usize`
+//! work in either order, without underflow.
+//!
 //!
 //! ## Comparisons
 //!
@@ -92,10 +96,10 @@ macro_rules! assert_in_delta_as_result {
                     Ok((abs_diff, *delta))
                 } else {
                     Err(
-                        format!(
+                        $crate::__private::format!(
                             concat!(
                                 "assertion failed: `assert_in_delta!(a, b, Δ)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_delta.html\n",
+                                $crate::assertables_url_line!("assert_in_delta"),
                                 "       a label: `{}`,\n",
                                 "       a debug: `{:?}`,\n",
                                 "       b label: `{}`,\n",
@@ -141,7 +145,7 @@ mod test_assert_in_delta_as_result {
         let actual = assert_in_delta_as_result!(a, b, delta);
         let message = concat!(
             "assertion failed: `assert_in_delta!(a, b, Δ)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_delta.html\n",
+            assertables_url_line!("assert_in_delta"),
             "       a label: `a`,\n",
             "       a debug: `10`,\n",
             "       b label: `b`,\n",
@@ -153,6 +157,24 @@ mod test_assert_in_delta_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn unsigned_with_b_greater_than_a() {
+        let a: u8 = 10;
+        let b: u8 = 11;
+        let delta: u8 = 1;
+        let actual = assert_in_delta_as_result!(a, b, delta);
+        assert_eq!(actual.unwrap(), (1, 1));
+    }
+
+    #[test]
+    fn unsigned_with_b_greater_than_a_failure() {
+        let a: usize = 0;
+        let b: usize = usize::MAX;
+        let delta: usize = 1;
+        let actual = assert_in_delta_as_result!(a, b, delta);
+        assert!(actual.is_err());
+    }
 }
 
 /// Assert a number is within delta of another.
@@ -197,7 +219,7 @@ mod test_assert_in_delta_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_in_delta!(a, b, Δ)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_delta.html\n",
+/// #     assertables_url_line!("assert_in_delta"),
 /// #     "       a label: `a`,\n",
 /// #     "       a debug: `10`,\n",
 /// #     "       b label: `b`,\n",
@@ -240,13 +262,13 @@ macro_rules! assert_in_delta {
     ($a:expr, $b:expr, $delta:expr $(,)?) => {{
         match $crate::assert_in_delta_as_result!($a, $b, $delta) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $delta:expr, $($message:tt)+) => {{
         match $crate::assert_in_delta_as_result!($a, $b, $delta) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -274,7 +296,7 @@ mod test_assert_in_delta {
         });
         let message = concat!(
             "assertion failed: `assert_in_delta!(a, b, Δ)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_delta.html\n",
+            assertables_url_line!("assert_in_delta"),
             "       a label: `a`,\n",
             "       a debug: `10`,\n",
             "       b label: `b`,\n",
@@ -329,7 +351,7 @@ mod test_assert_in_delta {
 #[macro_export]
 macro_rules! debug_assert_in_delta {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_in_delta!($($arg)*);
         }
     };
@@ -14,6 +14,12 @@
 //! assert_in_epsilon!(a, b, epsilon);
 //! ```
 //!
+//! If min(a, b) is zero, then the relative tolerance would collapse to zero,
+//! so the macro uses ε itself as an absolute tolerance, i.e. | a - b | ≤ ε.
+//!
+//! If computing | a - b | or ε * min(a, b) overflows, then the assertion
+//! fails, and the message says which computation panicked.
+//!
 //!
 //! ## Comparisons
 //!
@@ -65,11 +71,13 @@
 /// Pseudocode:<br>
 /// | a - b | ≤ ε * min(a, b)
 ///
-/// * If true, return Result `Ok((lhs, rhs))`.
+/// * If true, return Result `Ok((abs_diff, tolerance))`.
 ///
 /// * When false, return [`Err`] with a message and the values of the
 ///   expressions with their debug representations.
 ///
+/// The tolerance is ε * min(a, b), or ε when min(a, b) is zero.
+///
 /// This macro is useful for runtime checks, such as checking parameters,
 /// or sanitizing inputs, or handling different results in different ways.
 ///
@@ -84,38 +92,126 @@ macro_rules! assert_in_epsilon_as_result {
     ($a:expr, $b:expr, $epsilon:expr $(,)?) => {{
         match (&$a, &$b, &$epsilon) {
             (a, b, epsilon) => {
-                let abs_diff = if (a >= b) { a - b } else { b - a };
-                let min = if (a < b) { a } else { b };
-                let rhs = *epsilon * min;
-                if abs_diff <= rhs {
-                    Ok((abs_diff, rhs))
-                } else {
-                    Err(
-                        format!(
-                            concat!(
-                                "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_epsilon.html\n",
-                                "                   a label: `{}`,\n",
-                                "                   a debug: `{:?}`,\n",
-                                "                   b label: `{}`,\n",
-                                "                   b debug: `{:?}`,\n",
-                                "                   ε label: `{}`,\n",
-                                "                   ε debug: `{:?}`,\n",
-                                "                 | a - b |: `{:?}`,\n",
-                                "             ε * min(a, b): `{:?}`,\n",
-                                " | a - b | ≤ ε * min(a, b): {}",
-                            ),
-                            stringify!($a),
-                            a,
-                            stringify!($b),
-                            b,
-                            stringify!($epsilon),
-                            epsilon,
-                            abs_diff,
-                            rhs,
-                            false
+                match $crate::__private::catch_unwind(|| if (a >= b) { a - b } else { b - a }) {
+                    Ok(abs_diff) => {
+                        let min = if (a < b) { a } else { b };
+                        let zero = min - min;
+                        if *min == zero {
+                            if abs_diff <= *epsilon {
+                                Ok((abs_diff, *epsilon))
+                            } else {
+                                Err(
+                                    $crate::__private::format!(
+                                        concat!(
+                                            "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
+                                            $crate::assertables_url_line!("assert_in_epsilon"),
+                                            "                   a label: `{}`,\n",
+                                            "                   a debug: `{:?}`,\n",
+                                            "                   b label: `{}`,\n",
+                                            "                   b debug: `{:?}`,\n",
+                                            "                   ε label: `{}`,\n",
+                                            "                   ε debug: `{:?}`,\n",
+                                            "                 | a - b |: `{:?}`,\n",
+                                            "                 min(a, b): `{:?}`,\n",
+                                            "             | a - b | ≤ ε: {}",
+                                        ),
+                                        stringify!($a),
+                                        a,
+                                        stringify!($b),
+                                        b,
+                                        stringify!($epsilon),
+                                        epsilon,
+                                        abs_diff,
+                                        min,
+                                        false
+                                    )
+                                )
+                            }
+                        } else {
+                            match $crate::__private::catch_unwind(|| *epsilon * min) {
+                                Ok(rhs) => {
+                                    if abs_diff <= rhs {
+                                        Ok((abs_diff, rhs))
+                                    } else {
+                                        Err(
+                                            $crate::__private::format!(
+                                                concat!(
+                                                    "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
+                                                    $crate::assertables_url_line!("assert_in_epsilon"),
+                                                    "                   a label: `{}`,\n",
+                                                    "                   a debug: `{:?}`,\n",
+                                                    "                   b label: `{}`,\n",
+                                                    "                   b debug: `{:?}`,\n",
+                                                    "                   ε label: `{}`,\n",
+                                                    "                   ε debug: `{:?}`,\n",
+                                                    "                 | a - b |: `{:?}`,\n",
+                                                    "             ε * min(a, b): `{:?}`,\n",
+                                                    " | a - b | ≤ ε * min(a, b): {}",
+                                                ),
+                                                stringify!($a),
+                                                a,
+                                                stringify!($b),
+                                                b,
+                                                stringify!($epsilon),
+                                                epsilon,
+                                                abs_diff,
+                                                rhs,
+                                                false
+                                            )
+                                        )
+                                    }
+                                },
+                                Err(_err) => {
+                                    Err(
+                                        $crate::__private::format!(
+                                            concat!(
+                                                "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
+                                                $crate::assertables_url_line!("assert_in_epsilon"),
+                                                "                   a label: `{}`,\n",
+                                                "                   a debug: `{:?}`,\n",
+                                                "                   b label: `{}`,\n",
+                                                "                   b debug: `{:?}`,\n",
+                                                "                   ε label: `{}`,\n",
+                                                "                   ε debug: `{:?}`,\n",
+                                                "                 | a - b |: `{:?}`,\n",
+                                                "             ε * min(a, b): panic",
+                                            ),
+                                            stringify!($a),
+                                            a,
+                                            stringify!($b),
+                                            b,
+                                            stringify!($epsilon),
+                                            epsilon,
+                                            abs_diff
+                                        )
+                                    )
+                                }
+                            }
+                        }
+                    },
+                    Err(_err) => {
+                        Err(
+                            $crate::__private::format!(
+                                concat!(
+                                    "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
+                                    $crate::assertables_url_line!("assert_in_epsilon"),
+                                    "                   a label: `{}`,\n",
+                                    "                   a debug: `{:?}`,\n",
+                                    "                   b label: `{}`,\n",
+                                    "                   b debug: `{:?}`,\n",
+                                    "                   ε label: `{}`,\n",
+                                    "                   ε debug: `{:?}`,\n",
+                                    "                 | a - b |: panic",
+                                ),
+                                stringify!($a),
+                                a,
+                                stringify!($b),
+                                b,
+                                stringify!($epsilon),
+                                epsilon
+                            )
                         )
-                    )
+                    }
                 }
             }
         }
@@ -142,7 +238,7 @@ mod test_assert_in_epsilon_as_result {
         let actual = assert_in_epsilon_as_result!(a, b, epsilon);
         let message = concat!(
             "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_epsilon.html\n",
+            assertables_url_line!("assert_in_epsilon"),
             "                   a label: `a`,\n",
             "                   a debug: `10`,\n",
             "                   b label: `b`,\n",
@@ -155,6 +251,104 @@ mod test_assert_in_epsilon_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn a_zero_within_absolute_epsilon() {
+        let a: i8 = 0;
+        let b: i8 = 1;
+        let epsilon: i8 = 1;
+        let actual = assert_in_epsilon_as_result!(a, b, epsilon);
+        assert_eq!(actual.unwrap(), (1, 1));
+    }
+
+    #[test]
+    fn a_zero_outside_absolute_epsilon() {
+        let a: i8 = 0;
+        let b: i8 = 2;
+        let epsilon: i8 = 1;
+        let actual = assert_in_epsilon_as_result!(a, b, epsilon);
+        let message = concat!(
+            "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
+            assertables_url_line!("assert_in_epsilon"),
+            "                   a label: `a`,\n",
+            "                   a debug: `0`,\n",
+            "                   b label: `b`,\n",
+            "                   b debug: `2`,\n",
+            "                   ε label: `epsilon`,\n",
+            "                   ε debug: `1`,\n",
+            "                 | a - b |: `2`,\n",
+            "                 min(a, b): `0`,\n",
+            "             | a - b | ≤ ε: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn b_zero() {
+        let a: f64 = 0.5;
+        let b: f64 = 0.0;
+        let actual = assert_in_epsilon_as_result!(a, b, 1.0);
+        assert_eq!(actual.unwrap(), (0.5, 1.0));
+        let actual = assert_in_epsilon_as_result!(a, b, 0.1);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn a_zero_and_b_zero() {
+        let a: f64 = 0.0;
+        let b: f64 = -0.0;
+        let actual = assert_in_epsilon_as_result!(a, b, 0.0);
+        assert_eq!(actual.unwrap(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn large_floats() {
+        let a: f64 = 1.0e300;
+        let b: f64 = 1.0001e300;
+        let actual = assert_in_epsilon_as_result!(a, b, 0.001);
+        assert!(actual.is_ok());
+    }
+
+    #[test]
+    fn overflow_of_tolerance() {
+        let a: i8 = 100;
+        let b: i8 = 101;
+        let epsilon: i8 = 2;
+        let actual = assert_in_epsilon_as_result!(a, b, epsilon);
+        let message = concat!(
+            "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
+            assertables_url_line!("assert_in_epsilon"),
+            "                   a label: `a`,\n",
+            "                   a debug: `100`,\n",
+            "                   b label: `b`,\n",
+            "                   b debug: `101`,\n",
+            "                   ε label: `epsilon`,\n",
+            "                   ε debug: `2`,\n",
+            "                 | a - b |: `1`,\n",
+            "             ε * min(a, b): panic"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn overflow_of_difference() {
+        let a: i8 = i8::MIN;
+        let b: i8 = i8::MAX;
+        let epsilon: i8 = 1;
+        let actual = assert_in_epsilon_as_result!(a, b, epsilon);
+        let message = concat!(
+            "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
+            assertables_url_line!("assert_in_epsilon"),
+            "                   a label: `a`,\n",
+            "                   a debug: `-128`,\n",
+            "                   b label: `b`,\n",
+            "                   b debug: `127`,\n",
+            "                   ε label: `epsilon`,\n",
+            "                   ε debug: `1`,\n",
+            "                 | a - b |: panic"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
 }
 
 /// Assert a number is within epsilon of another.
@@ -162,7 +356,7 @@ mod test_assert_in_epsilon_as_result {
 /// Pseudocode:<br>
 /// | a - b | ≤ ε * min(a, b)
 ///
-/// * If true, return `(lhs, rhs)`.
+/// * If true, return `(abs_diff, tolerance)`.
 ///
 /// * Otherwise, call [`panic!`] with a message and the values of the
 ///   expressions with their debug representations.
@@ -200,7 +394,7 @@ mod test_assert_in_epsilon_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_epsilon.html\n",
+/// #     assertables_url_line!("assert_in_epsilon"),
 /// #     "                   a label: `a`,\n",
 /// #     "                   a debug: `10`,\n",
 /// #     "                   b label: `b`,\n",
@@ -244,13 +438,13 @@ macro_rules! assert_in_epsilon {
     ($a:expr, $b:expr, $epsilon:expr $(,)?) => {{
         match $crate::assert_in_epsilon_as_result!($a, $b, $epsilon) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $epsilon:expr, $($message:tt)+) => {{
         match $crate::assert_in_epsilon_as_result!($a, $b, $epsilon) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -278,7 +472,7 @@ mod test_assert_in_epsilon {
         });
         let message = concat!(
             "assertion failed: `assert_in_epsilon!(a, b, ε)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_epsilon.html\n",
+            assertables_url_line!("assert_in_epsilon"),
             "                   a label: `a`,\n",
             "                   a debug: `10`,\n",
             "                   b label: `b`,\n",
@@ -334,7 +528,7 @@ mod test_assert_in_epsilon {
 #[macro_export]
 macro_rules! debug_assert_in_epsilon {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_in_epsilon!($($arg)*);
         }
     };
@@ -13,6 +13,12 @@
 //! assert_in_range!(a, b);
 //! ```
 //!
+//! The range can be any kind of range, meaning anything that implements
+//! [`RangeBounds`](https://doc.rust-lang.org/std/ops/trait.RangeBounds.html),
+//! such as `0..2`, `0..=2`, `0..`, `..2`, `..=2`, or `..`.
+//! The failure message shows the range bounds in mathematical interval
+//! notation, such as `[0, 2)` for `0..2`, and `(-∞, 2]` for `..=2`.
+//!
 //! # Module macros
 //!
 //! * [`assert_in_range`](macro@crate::assert_in_range)
@@ -40,27 +46,29 @@
 #[macro_export]
 macro_rules! assert_in_range_as_result {
     ($a:expr, $range:expr $(,)?) => {{
-        if $range.contains(&$a) {
-            Ok(())
-        } else {
-            Err(format!(
-                concat!(
-                    "assertion failed: `assert_in_range!(a, range)`\n",
-                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_range.html\n",
-                    "     a label: `{}`,\n",
-                    "     a debug: `{:?}`,\n",
-                    " range label: `{}`,\n",
-                    " range debug: `{:?}`,\n",
-                    " range start: `{:?}`,\n",
-                    "   range end: `{:?}`",
-                ),
-                stringify!($a),
-                $a,
-                stringify!($range),
-                $range,
-                $range.start,
-                $range.end,
-            ))
+        match (&$a, &$range) {
+            (a, range) => {
+                if $crate::assert_in::range_contains(range, a) {
+                    Ok(())
+                } else {
+                    Err($crate::__private::format!(
+                        concat!(
+                            "assertion failed: `assert_in_range!(a, range)`\n",
+                            $crate::assertables_url_line!("assert_in_range"),
+                            "      a label: `{}`,\n",
+                            "      a debug: `{:?}`,\n",
+                            "  range label: `{}`,\n",
+                            "  range debug: `{:?}`,\n",
+                            " range bounds: `{}`",
+                        ),
+                        stringify!($a),
+                        a,
+                        stringify!($range),
+                        range,
+                        $crate::assert_in::range_bounds_notation(range, a),
+                    ))
+                }
+            }
         }
     }};
 }
@@ -83,16 +91,58 @@ mod test_assert_in_range_as_result {
         let actual = assert_in_range_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_in_range!(a, range)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_range.html\n",
-            "     a label: `a`,\n",
-            "     a debug: `1`,\n",
-            " range label: `b`,\n",
-            " range debug: `2..4`,\n",
-            " range start: `2`,\n",
-            "   range end: `4`"
+            assertables_url_line!("assert_in_range"),
+            "      a label: `a`,\n",
+            "      a debug: `1`,\n",
+            "  range label: `b`,\n",
+            "  range debug: `2..4`,\n",
+            " range bounds: `[2, 4)`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn range_inclusive() {
+        assert!(assert_in_range_as_result!(2, 0..=2).is_ok());
+        let actual = assert_in_range_as_result!(3, 0..=2);
+        assert!(actual.unwrap_err().ends_with(" range bounds: `[0, 2]`"));
+    }
+
+    #[test]
+    fn range_from() {
+        assert!(assert_in_range_as_result!(5, 2..).is_ok());
+        let actual = assert_in_range_as_result!(1, 2..);
+        assert!(actual.unwrap_err().ends_with(" range bounds: `[2, +∞)`"));
+    }
+
+    #[test]
+    fn range_to() {
+        assert!(assert_in_range_as_result!(1, ..2).is_ok());
+        let actual = assert_in_range_as_result!(2, ..2);
+        assert!(actual.unwrap_err().ends_with(" range bounds: `(-∞, 2)`"));
+    }
+
+    #[test]
+    fn range_to_inclusive() {
+        assert!(assert_in_range_as_result!(2, ..=2).is_ok());
+        let actual = assert_in_range_as_result!(3, ..=2);
+        assert!(actual.unwrap_err().ends_with(" range bounds: `(-∞, 2]`"));
+    }
+
+    #[test]
+    fn range_full() {
+        assert!(assert_in_range_as_result!(i32::MIN, ..).is_ok());
+        assert!(assert_in_range_as_result!(f64::INFINITY, ..).is_ok());
+    }
+
+    #[test]
+    fn range_bounds_tuple() {
+        use std::ops::Bound;
+        let range = (Bound::Excluded(0), Bound::Included(2));
+        assert!(assert_in_range_as_result!(2, range).is_ok());
+        let actual = assert_in_range_as_result!(0, range);
+        assert!(actual.unwrap_err().ends_with(" range bounds: `(0, 2]`"));
+    }
 }
 
 /// Assert an item is in a range.
@@ -124,22 +174,20 @@ mod test_assert_in_range_as_result {
 /// # });
 /// // assertion failed: `assert_in_range!(a, range)`
 /// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_range.html
-/// //  a label: `a`,
-/// //  a debug: `1`,
-/// //  range label: `b`,
-/// //  range debug: `2..4`,
-/// //  range start: `2`,
-/// //    range end: `4`"
+/// //       a label: `a`,
+/// //       a debug: `1`,
+/// //   range label: `b`,
+/// //   range debug: `2..4`,
+/// //  range bounds: `[2, 4)`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_in_range!(a, range)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_range.html\n",
-/// #     "     a label: `a`,\n",
-/// #     "     a debug: `1`,\n",
-/// #     " range label: `b`,\n",
-/// #     " range debug: `2..4`,\n",
-/// #     " range start: `2`,\n",
-/// #     "   range end: `4`"
+/// #     assertables_url_line!("assert_in_range"),
+/// #     "      a label: `a`,\n",
+/// #     "      a debug: `1`,\n",
+/// #     "  range label: `b`,\n",
+/// #     "  range debug: `2..4`,\n",
+/// #     " range bounds: `[2, 4)`"
 /// # );
 /// # assert_eq!(actual, message);
 /// # }
@@ -156,13 +204,13 @@ macro_rules! assert_in_range {
     ($a:expr, $range:expr $(,)?) => {{
         match $crate::assert_in_range_as_result!($a, $range) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $range:expr, $($message:tt)+) => {{
         match $crate::assert_in_range_as_result!($a, $range) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -188,13 +236,12 @@ mod test_assert_in_range {
         });
         let message = concat!(
             "assertion failed: `assert_in_range!(a, range)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_in_range.html\n",
-            "     a label: `a`,\n",
-            "     a debug: `1`,\n",
-            " range label: `b`,\n",
-            " range debug: `2..4`,\n",
-            " range start: `2`,\n",
-            "   range end: `4`"
+            assertables_url_line!("assert_in_range"),
+            "      a label: `a`,\n",
+            "      a debug: `1`,\n",
+            "  range label: `b`,\n",
+            "  range debug: `2..4`,\n",
+            " range bounds: `[2, 4)`"
         );
         assert_eq!(
             result
@@ -241,7 +288,7 @@ mod test_assert_in_range {
 #[macro_export]
 macro_rules! debug_assert_in_range {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_in_range!($($arg)*);
         }
     };
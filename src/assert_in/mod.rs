@@ -23,3 +23,43 @@ pub mod assert_in;
 pub mod assert_in_delta;
 pub mod assert_in_epsilon;
 pub mod assert_in_range;
+
+use alloc::format;
+use alloc::string::String;
+
+/// Return true if a range contains an item, for any kind of range.
+///
+/// This function pins the range bound type to the item type, so that
+/// a range such as `..`, which has no bounds, can still be checked.
+#[doc(hidden)]
+pub fn range_contains<T, R>(range: &R, item: &T) -> bool
+where
+    T: PartialOrd + ?Sized,
+    R: ::core::ops::RangeBounds<T> + ?Sized,
+{
+    range.contains(item)
+}
+
+/// Return range bounds in mathematical interval notation, such as `[0, 2)`.
+///
+/// An unbounded start is `(-∞` and an unbounded end is `+∞)`.
+/// The item is unused, except to pin the range bound type, as above.
+#[doc(hidden)]
+pub fn range_bounds_notation<T, R>(range: &R, _item: &T) -> String
+where
+    T: ::core::fmt::Debug + ?Sized,
+    R: ::core::ops::RangeBounds<T> + ?Sized,
+{
+    use ::core::ops::Bound;
+    let start = match range.start_bound() {
+        Bound::Included(x) => format!("[{:?}", x),
+        Bound::Excluded(x) => format!("({:?}", x),
+        Bound::Unbounded => String::from("(-∞"),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(x) => format!("{:?}]", x),
+        Bound::Excluded(x) => format!("{:?})", x),
+        Bound::Unbounded => String::from("+∞)"),
+    };
+    format!("{}, {}", start, end)
+}
@@ -29,6 +29,26 @@
 //!
 //! * `assert_infix!(a || b)` ≈ a …∨ b ≈ a lazy OR b
 //!
+//! Compare an arithmetic result via infix value operator:
+//!
+//! * `assert_infix!(a % n == b)` ≈ a mod n = b
+//!
+//! * `assert_infix!(a + n == b)` ≈ a + n = b
+//!
+//! * `assert_infix!(a - n == b)` ≈ a - n = b
+//!
+//! * `assert_infix!(a * n == b)` ≈ a × n = b
+//!
+//! The arithmetic is on the left side, and any value operator can follow it,
+//! such as `assert_infix!(a * 2 <= b)`. The failure message shows each
+//! operand and the arithmetic result. The arithmetic uses references,
+//! such as `&a % &n`, which works for all primitive number types.
+//!
+//! Each operand is evaluated once, even though it appears in both the check
+//! and the failure message. The failure message shows each operand's label,
+//! meaning its source text, and its debug value. The lazy operators `&&`
+//! and `||` evaluate `b` only when needed; if `a` alone fails the check,
+//! then the message shows `b` as "(not evaluated)".
 //!
 //! # Example
 //!
@@ -38,6 +58,10 @@
 //! let a = 1;
 //! let b = 1;
 //! assert_infix!(a == b);
+//!
+//! let a = 12;
+//! let n = 3;
+//! assert_infix!(a % n == 0);
 //! ```
 //!
 //! # Infix operators
@@ -51,6 +75,13 @@
 //! * `>`	greater than
 //! * `>=`	greater than or equal to
 //!
+//! For arithmetic, on the left side of a value operator:
+//!
+//! * `%`  remainder
+//! * `+`  addition
+//! * `-`  subtraction
+//! * `*`  multiplication
+//!
 //! For booleans:
 //!
 //! * `^`	logical XOR
@@ -86,27 +117,120 @@
 ///
 #[macro_export]
 macro_rules! assert_infix_as_result {
-    ($a:tt $infix:tt $b:tt) => {{
-        if $a $infix $b {
-            Ok(())
-        } else {
-            Err(
-                format!(
-                    concat!(
-                        "assertion failed: `assert_infix!(a {} b)`\n",
-                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_infix.html\n",
-                        " a label: `{}`,\n",
-                        " a debug: `{:?}`,\n",
-                        " b label: `{}`,\n",
-                        " b debug: `{:?}`",
-                    ),
-                    stringify!($infix),
-                    stringify!($a),
-                    $a,
-                    stringify!($b),
-                    $b,
-                )
+    (@arith $a:tt $op:tt $n:tt $infix:tt $b:tt) => {{
+        match (&$a, &$n, &$b) {
+            (a, n, b) => {
+                let a_op_n = a $op n;
+                if a_op_n $infix *b {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_infix!(a {} n {} b)`\n",
+                                $crate::assertables_url_line!("assert_infix"),
+                                "     a label: `{}`,\n",
+                                "     a debug: `{:?}`,\n",
+                                "     n label: `{}`,\n",
+                                "     n debug: `{:?}`,\n",
+                                " a {} n debug: `{:?}`,\n",
+                                "     b label: `{}`,\n",
+                                "     b debug: `{:?}`"
+                            ),
+                            stringify!($op),
+                            stringify!($infix),
+                            stringify!($a),
+                            a,
+                            stringify!($n),
+                            n,
+                            stringify!($op),
+                            a_op_n,
+                            stringify!($b),
+                            b
+                        )
+                    )
+                }
+            }
+        }
+    }};
+    ($a:tt % $n:tt $infix:tt $b:tt) => {
+        $crate::assert_infix_as_result!(@arith $a % $n $infix $b)
+    };
+    ($a:tt + $n:tt $infix:tt $b:tt) => {
+        $crate::assert_infix_as_result!(@arith $a + $n $infix $b)
+    };
+    ($a:tt - $n:tt $infix:tt $b:tt) => {
+        $crate::assert_infix_as_result!(@arith $a - $n $infix $b)
+    };
+    ($a:tt * $n:tt $infix:tt $b:tt) => {
+        $crate::assert_infix_as_result!(@arith $a * $n $infix $b)
+    };
+    (@err $a:tt $infix:tt $b:tt, $a_debug:expr, $b_debug:expr) => {
+        Err(
+            format!(
+                concat!(
+                    "assertion failed: `assert_infix!(a {} b)`\n",
+                    $crate::assertables_url_line!("assert_infix"),
+                    " a label: `{}`,\n",
+                    " a debug: `{}`,\n",
+                    " b label: `{}`,\n",
+                    " b debug: `{}`",
+                ),
+                stringify!($infix),
+                stringify!($a),
+                $a_debug,
+                stringify!($b),
+                $b_debug,
             )
+        )
+    };
+    ($a:tt && $b:tt) => {{
+        match (&$a) {
+            a => {
+                if *a {
+                    match (&$b) {
+                        b => {
+                            if *b {
+                                Ok(())
+                            } else {
+                                $crate::assert_infix_as_result!(@err $a && $b, format!("{:?}", a), format!("{:?}", b))
+                            }
+                        }
+                    }
+                } else {
+                    $crate::assert_infix_as_result!(@err $a && $b, format!("{:?}", a), "(not evaluated)")
+                }
+            }
+        }
+    }};
+    ($a:tt || $b:tt) => {{
+        match (&$a) {
+            a => {
+                if *a {
+                    Ok(())
+                } else {
+                    match (&$b) {
+                        b => {
+                            if *b {
+                                Ok(())
+                            } else {
+                                $crate::assert_infix_as_result!(@err $a || $b, format!("{:?}", a), format!("{:?}", b))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }};
+    ($a:tt $infix:tt $b:tt) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                if *a $infix *b {
+                    Ok(())
+                } else {
+                    $crate::assert_infix_as_result!(@err $a $infix $b, format!("{:?}", a), format!("{:?}", b))
+                }
+            }
         }
     }};
 }
@@ -129,7 +253,7 @@ mod test_assert_infix_as_result {
         let actual = assert_infix_as_result!(a == b);
         let message = concat!(
             "assertion failed: `assert_infix!(a == b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_infix.html\n",
+            assertables_url_line!("assert_infix"),
             " a label: `a`,\n",
             " a debug: `1`,\n",
             " b label: `b`,\n",
@@ -137,6 +261,178 @@ mod test_assert_infix_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn failure_shows_both_values() {
+        let a: i32 = 2;
+        let b: i32 = 1;
+        let actual = assert_infix_as_result!(a < b);
+        let message = actual.unwrap_err();
+        assert!(message.starts_with("assertion failed: `assert_infix!(a < b)`\n"));
+        assert!(message.contains(" a label: `a`,\n a debug: `2`,\n"));
+        assert!(message.contains(" b label: `b`,\n b debug: `1`"));
+    }
+
+    #[test]
+    fn evaluates_each_operand_once() {
+        let mut count = 0;
+        let actual = assert_infix_as_result!(
+            ({
+                count += 1;
+                2
+            }) < ({
+                count += 1;
+                1
+            })
+        );
+        assert!(actual.is_err());
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn success_with_strings() {
+        let a = String::from("alfa");
+        let b = String::from("bravo");
+        let actual = assert_infix_as_result!(a < b);
+        assert_eq!(actual.unwrap(), ());
+        assert_eq!(a, "alfa");
+    }
+
+    #[test]
+    fn failure_with_lazy_and_does_not_evaluate_b() {
+        let v: Vec<i32> = vec![];
+        let actual = assert_infix_as_result!((!v.is_empty()) && (v[0] == 1));
+        let message = concat!(
+            "assertion failed: `assert_infix!(a && b)`\n",
+            assertables_url_line!("assert_infix"),
+            " a label: `(!v.is_empty())`,\n",
+            " a debug: `false`,\n",
+            " b label: `(v[0] == 1)`,\n",
+            " b debug: `(not evaluated)`",
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_with_lazy_and() {
+        let a = true;
+        let b = false;
+        let actual = assert_infix_as_result!(a && b);
+        let message = concat!(
+            "assertion failed: `assert_infix!(a && b)`\n",
+            assertables_url_line!("assert_infix"),
+            " a label: `a`,\n",
+            " a debug: `true`,\n",
+            " b label: `b`,\n",
+            " b debug: `false`",
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_lazy_or_does_not_evaluate_b() {
+        let v: Vec<i32> = vec![];
+        let actual = assert_infix_as_result!((v.is_empty()) || (v[0] == 1));
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_lazy_or() {
+        let a = false;
+        let b = false;
+        let actual = assert_infix_as_result!(a || b);
+        let message = concat!(
+            "assertion failed: `assert_infix!(a || b)`\n",
+            assertables_url_line!("assert_infix"),
+            " a label: `a`,\n",
+            " a debug: `false`,\n",
+            " b label: `b`,\n",
+            " b debug: `false`",
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_logical_xor() {
+        let a = true;
+        let b = false;
+        let actual = assert_infix_as_result!(a ^ b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_divisibility() {
+        let a: i32 = 12;
+        let n: i32 = 3;
+        let actual = assert_infix_as_result!(a % n == 0);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_divisibility() {
+        let a: i32 = 7;
+        let n: i32 = 3;
+        let actual = assert_infix_as_result!(a % n == 0);
+        let message = concat!(
+            "assertion failed: `assert_infix!(a % n == b)`\n",
+            assertables_url_line!("assert_infix"),
+            "     a label: `a`,\n",
+            "     a debug: `7`,\n",
+            "     n label: `n`,\n",
+            "     n debug: `3`,\n",
+            " a % n debug: `1`,\n",
+            "     b label: `0`,\n",
+            "     b debug: `0`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_offset() {
+        let a: i32 = 1;
+        let b: i32 = 3;
+        assert!(assert_infix_as_result!(a + 2 == b).is_ok());
+        assert!(assert_infix_as_result!(b - 2 == a).is_ok());
+        assert!(assert_infix_as_result!(a * 2 < b).is_ok());
+    }
+
+    #[test]
+    fn failure_with_offset() {
+        let a: i32 = 1;
+        let b: i32 = 4;
+        let actual = assert_infix_as_result!(a + 2 >= b);
+        let message = concat!(
+            "assertion failed: `assert_infix!(a + n >= b)`\n",
+            assertables_url_line!("assert_infix"),
+            "     a label: `a`,\n",
+            "     a debug: `1`,\n",
+            "     n label: `2`,\n",
+            "     n debug: `2`,\n",
+            " a + n debug: `3`,\n",
+            "     b label: `b`,\n",
+            "     b debug: `4`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn arithmetic_evaluates_each_operand_once() {
+        let mut count = 0;
+        let actual = assert_infix_as_result!(
+            ({
+                count += 1;
+                7
+            }) % ({
+                count += 1;
+                3
+            }) == ({
+                count += 1;
+                0
+            })
+        );
+        assert!(actual.is_err());
+        assert_eq!(count, 3);
+    }
 }
 
 /// Assert a infix operator, such as assert_infix!(a == b).
@@ -175,7 +471,7 @@ mod test_assert_infix_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_infix!(a == b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_infix.html\n",
+/// #     assertables_url_line!("assert_infix"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `1`,\n",
 /// #     " b label: `b`,\n",
@@ -196,6 +492,13 @@ mod test_assert_infix_as_result {
 /// * `>`	greater than
 /// * `>=`	greater than or equal to
 ///
+/// For arithmetic, on the left side of a value operator:
+///
+/// * `%`  remainder
+/// * `+`  addition
+/// * `-`  subtraction
+/// * `*`  multiplication
+///
 /// For booleans:
 ///
 /// * `^`	logical XOR
@@ -213,16 +516,52 @@ mod test_assert_infix_as_result {
 ///
 #[macro_export]
 macro_rules! assert_infix {
+    (@arith $a:tt $op:tt $n:tt $infix:tt $b:tt) => {{
+        match $crate::assert_infix_as_result!($a $op $n $infix $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    (@arith $a:tt $op:tt $n:tt $infix:tt $b:tt, $($message:tt)+) => {{
+        match $crate::assert_infix_as_result!($a $op $n $infix $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+    ($a:tt % $n:tt $infix:tt $b:tt) => {
+        $crate::assert_infix!(@arith $a % $n $infix $b)
+    };
+    ($a:tt % $n:tt $infix:tt $b:tt, $($message:tt)+) => {
+        $crate::assert_infix!(@arith $a % $n $infix $b, $($message)+)
+    };
+    ($a:tt + $n:tt $infix:tt $b:tt) => {
+        $crate::assert_infix!(@arith $a + $n $infix $b)
+    };
+    ($a:tt + $n:tt $infix:tt $b:tt, $($message:tt)+) => {
+        $crate::assert_infix!(@arith $a + $n $infix $b, $($message)+)
+    };
+    ($a:tt - $n:tt $infix:tt $b:tt) => {
+        $crate::assert_infix!(@arith $a - $n $infix $b)
+    };
+    ($a:tt - $n:tt $infix:tt $b:tt, $($message:tt)+) => {
+        $crate::assert_infix!(@arith $a - $n $infix $b, $($message)+)
+    };
+    ($a:tt * $n:tt $infix:tt $b:tt) => {
+        $crate::assert_infix!(@arith $a * $n $infix $b)
+    };
+    ($a:tt * $n:tt $infix:tt $b:tt, $($message:tt)+) => {
+        $crate::assert_infix!(@arith $a * $n $infix $b, $($message)+)
+    };
     ($a:tt $infix:tt $b:tt) => {{
         match $crate::assert_infix_as_result!($a $infix $b) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:tt $infix:tt $b:tt, $($message:tt)+) => {{
         match $crate::assert_infix_as_result!($a $infix $b) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -248,7 +587,7 @@ mod test_assert_infix {
         });
         let message = concat!(
             "assertion failed: `assert_infix!(a == b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_infix.html\n",
+            assertables_url_line!("assert_infix"),
             " a label: `a`,\n",
             " a debug: `1`,\n",
             " b label: `b`,\n",
@@ -263,6 +602,47 @@ mod test_assert_infix {
             message
         );
     }
+
+    #[test]
+    fn success_with_arithmetic() {
+        let a: i32 = 12;
+        let n: i32 = 3;
+        let actual = assert_infix!(a % n == 0);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure_with_message() {
+        let a: i32 = 1;
+        let b: i32 = 2;
+        let result = panic::catch_unwind(|| {
+            let _actual = assert_infix!(a == b, "custom message");
+        });
+        let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+        assert!(actual.starts_with("custom message\nassertion failed: `assert_infix!(a == b)`\n"));
+    }
+
+    #[test]
+    fn failure_with_message_format() {
+        let a: i32 = 1;
+        let b: i32 = 2;
+        let result = panic::catch_unwind(|| {
+            let _actual = assert_infix!(a == b, "custom {}", 1);
+        });
+        let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+        assert!(actual.starts_with("custom 1\nassertion failed: `assert_infix!(a == b)`\n"));
+    }
+
+    #[test]
+    fn failure_with_arithmetic_and_message() {
+        let a: i32 = 13;
+        let n: i32 = 3;
+        let result = panic::catch_unwind(|| {
+            let _actual = assert_infix!(a % n == 0, "custom {}", 1);
+        });
+        let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+        assert!(actual.starts_with("custom 1\nassertion failed: `assert_infix!(a % n == b)`\n"));
+    }
 }
 
 /// Assert a infix operator, such as assert_infix!(a == b).
@@ -299,7 +679,7 @@ mod test_assert_infix {
 #[macro_export]
 macro_rules! debug_assert_infix {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_infix!($($arg)*);
         }
     };
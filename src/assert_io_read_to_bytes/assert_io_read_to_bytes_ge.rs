@@ -0,0 +1,313 @@
+//! Assert a ::std::io::Read read_to_end() value is greater than or equal to another.
+//!
+//! Pseudocode:<br>
+//! (reader1.read_to_end(a_bytes) ⇒ a_bytes) ≥ (reader2.read_to_end(b_bytes) ⇒ b_bytes)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::io::Read;
+//!
+//! let mut a = "alfa".as_bytes();
+//! let mut b = "alfa".as_bytes();
+//! assert_io_read_to_bytes_ge!(a, b);
+//! ```
+//!
+//! This macro compares the raw bytes, so it works with any reader,
+//! including a reader of binary data that is not valid UTF-8.
+//!
+//! # Module macros
+//!
+//! * [`assert_io_read_to_bytes_ge`](macro@crate::assert_io_read_to_bytes_ge)
+//! * [`assert_io_read_to_bytes_ge_as_result`](macro@crate::assert_io_read_to_bytes_ge_as_result)
+//! * [`debug_assert_io_read_to_bytes_ge`](macro@crate::debug_assert_io_read_to_bytes_ge)
+
+/// Assert a ::std::io::Read read_to_end() value is greater than or equal to another.
+///
+/// Pseudocode:<br>
+/// (reader1.read_to_end(a_bytes) ⇒ a_bytes) ≥ (reader2.read_to_end(b_bytes) ⇒ b_bytes)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_io_read_to_bytes_ge`](macro@crate::assert_io_read_to_bytes_ge)
+/// * [`assert_io_read_to_bytes_ge_as_result`](macro@crate::assert_io_read_to_bytes_ge_as_result)
+/// * [`debug_assert_io_read_to_bytes_ge`](macro@crate::debug_assert_io_read_to_bytes_ge)
+///
+#[macro_export]
+macro_rules! assert_io_read_to_bytes_ge_as_result {
+    ($a_reader:expr, $b_reader:expr $(,)?) => {{
+        let mut a_bytes: Vec<u8> = Vec::new();
+        let mut b_bytes: Vec<u8> = Vec::new();
+        match (
+            $a_reader.read_to_end(&mut a_bytes),
+            $b_reader.read_to_end(&mut b_bytes)
+        ) {
+            (Ok(_a_size), Ok(_b_size)) => {
+                if a_bytes >= b_bytes {
+                    Ok(())
+                } else {
+                    let b_bytes: &[u8] = &b_bytes;
+                    let (index, a_diff, b_diff) = $crate::bytes_diff::bytes_diff_region(&a_bytes, b_bytes, $crate::bytes_diff::hex);
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_io_read_to_bytes_ge!(a_reader, b_reader)`\n",
+                                $crate::assertables_url_line!("assert_io_read_to_bytes_ge"),
+                                " a_reader label: `{}`,\n",
+                                " a_reader debug: `{:?}`,\n",
+                                " b_reader label: `{}`,\n",
+                                " b_reader debug: `{:?}`,\n",
+                                "              a: `{:?}`,\n",
+                                "              b: `{:?}`,\n",
+                                "     diff index: `{}`,\n",
+                                "         a diff: `{}`,\n",
+                                "         b diff: `{}`"
+                            ),
+                            stringify!($a_reader),
+                            $a_reader,
+                            stringify!($b_reader),
+                            $b_reader,
+                            a_bytes,
+                            b_bytes,
+                            index,
+                            a_diff,
+                            b_diff
+                        )
+                    )
+                }
+            },
+            (a, b) => {
+                Err(
+                    format!(
+                        concat!(
+                            "read error: `assert_io_read_to_bytes_ge!(a_reader, b_reader)`\n",
+                            $crate::assertables_url_line!("assert_io_read_to_bytes_ge"),
+                            " a_reader label: `{}`,\n",
+                            " a_reader debug: `{:?}`,\n",
+                            " b_reader label: `{}`,\n",
+                            " b_reader debug: `{:?}`,\n",
+                            "              a: `{:?}`,\n",
+                            "              b: `{:?}`"
+                        ),
+                        stringify!($a_reader),
+                        $a_reader,
+                        stringify!($b_reader),
+                        $b_reader,
+                        a,
+                        b
+                    )
+                )
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_io_read_to_bytes_ge_as_result {
+    #[allow(unused_imports)]
+    use std::io::Read;
+
+    #[test]
+    fn success() {
+        let mut a = "alfa".as_bytes();
+        let mut b = "alfa".as_bytes();
+        let actual = assert_io_read_to_bytes_ge_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let mut a = "alfa".as_bytes();
+        let mut b = "bravo".as_bytes();
+        let actual = assert_io_read_to_bytes_ge_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_io_read_to_bytes_ge!(a_reader, b_reader)`\n",
+            assertables_url_line!("assert_io_read_to_bytes_ge"),
+            " a_reader label: `a`,\n",
+            " a_reader debug: `[]`,\n",
+            " b_reader label: `b`,\n",
+            " b_reader debug: `[]`,\n",
+            "              a: `[97, 108, 102, 97]`,\n",
+            "              b: `[98, 114, 97, 118, 111]`,\n",
+            "     diff index: `0`,\n",
+            "         a diff: `61 6c 66 61`,\n",
+            "         b diff: `62 72 61 76 6f`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a ::std::io::Read read_to_end() value is greater than or equal to another.
+///
+/// Pseudocode:<br>
+/// (reader1.read_to_end(a_bytes) ⇒ a_bytes) ≥ (reader2.read_to_end(b_bytes) ⇒ b_bytes)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::io::Read;
+///
+/// # fn main() {
+/// let mut a = "alfa".as_bytes();
+/// let mut b = "alfa".as_bytes();
+/// assert_io_read_to_bytes_ge!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut a = "alfa".as_bytes();
+/// let mut b = "bravo".as_bytes();
+/// assert_io_read_to_bytes_ge!(a, b);
+/// # });
+/// // assertion failed: `assert_io_read_to_bytes_ge!(a_reader, b_reader)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_bytes_ge.html
+/// //  a_reader label: `a`,
+/// //  a_reader debug: `[]`,
+/// //  b_reader label: `b`,
+/// //  b_reader debug: `[]`,
+/// //               a: `[97, 108, 102, 97]`,
+/// //               b: `[98, 114, 97, 118, 111]`,
+/// //      diff index: `0`,
+/// //          a diff: `61 6c 66 61`,
+/// //          b diff: `62 72 61 76 6f`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_io_read_to_bytes_ge!(a_reader, b_reader)`\n",
+/// #     assertables_url_line!("assert_io_read_to_bytes_ge"),
+/// #     " a_reader label: `a`,\n",
+/// #     " a_reader debug: `[]`,\n",
+/// #     " b_reader label: `b`,\n",
+/// #     " b_reader debug: `[]`,\n",
+/// #     "              a: `[97, 108, 102, 97]`,\n",
+/// #     "              b: `[98, 114, 97, 118, 111]`,\n",
+/// #     "     diff index: `0`,\n",
+/// #     "         a diff: `61 6c 66 61`,\n",
+/// #     "         b diff: `62 72 61 76 6f`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro compares the raw bytes, so it works with any reader,
+/// including a reader of binary data that is not valid UTF-8.
+///
+/// # Module macros
+///
+/// * [`assert_io_read_to_bytes_ge`](macro@crate::assert_io_read_to_bytes_ge)
+/// * [`assert_io_read_to_bytes_ge_as_result`](macro@crate::assert_io_read_to_bytes_ge_as_result)
+/// * [`debug_assert_io_read_to_bytes_ge`](macro@crate::debug_assert_io_read_to_bytes_ge)
+///
+#[macro_export]
+macro_rules! assert_io_read_to_bytes_ge {
+    ($a_reader:expr, $b_reader:expr $(,)?) => {{
+        match $crate::assert_io_read_to_bytes_ge_as_result!($a_reader, $b_reader) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_reader:expr, $b_reader:expr, $($message:tt)+) => {{
+        match $crate::assert_io_read_to_bytes_ge_as_result!($a_reader, $b_reader) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_io_read_to_bytes_ge {
+    #[allow(unused_imports)]
+    use std::io::Read;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let mut a = "alfa".as_bytes();
+        let mut b = "alfa".as_bytes();
+        let actual = assert_io_read_to_bytes_ge!(a, b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut a = "alfa".as_bytes();
+            let mut b = "bravo".as_bytes();
+            let _actual = assert_io_read_to_bytes_ge!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_io_read_to_bytes_ge!(a_reader, b_reader)`\n",
+            assertables_url_line!("assert_io_read_to_bytes_ge"),
+            " a_reader label: `a`,\n",
+            " a_reader debug: `[]`,\n",
+            " b_reader label: `b`,\n",
+            " b_reader debug: `[]`,\n",
+            "              a: `[97, 108, 102, 97]`,\n",
+            "              b: `[98, 114, 97, 118, 111]`,\n",
+            "     diff index: `0`,\n",
+            "         a diff: `61 6c 66 61`,\n",
+            "         b diff: `62 72 61 76 6f`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a ::std::io::Read read_to_end() value is greater than or equal to another.
+///
+/// Pseudocode:<br>
+/// (reader1.read_to_end(a_bytes) ⇒ a_bytes) ≥ (reader2.read_to_end(b_bytes) ⇒ b_bytes)
+///
+/// This macro provides the same statements as [`assert_io_read_to_bytes_ge`](macro.assert_io_read_to_bytes_ge.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_io_read_to_bytes_ge`](macro@crate::assert_io_read_to_bytes_ge)
+/// * [`assert_io_read_to_bytes_ge_as_result`](macro@crate::assert_io_read_to_bytes_ge_as_result)
+/// * [`debug_assert_io_read_to_bytes_ge`](macro@crate::debug_assert_io_read_to_bytes_ge)
+///
+#[macro_export]
+macro_rules! debug_assert_io_read_to_bytes_ge {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_io_read_to_bytes_ge!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,317 @@
+//! Assert a ::std::io::Read read_to_end() value is less than an expression.
+//!
+//! Pseudocode:<br>
+//! (reader.read_to_end(a_bytes) ⇒ a_bytes) < (expr into bytes)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::io::Read;
+//!
+//! let mut reader = "alfa".as_bytes();
+//! let value = b"bravo";
+//! assert_io_read_to_bytes_lt_x!(reader, value);
+//! ```
+//!
+//! The expression can be anything that implements `AsRef<[u8]>`,
+//! such as a byte string literal, a byte array, or a `Vec<u8>`.
+//!
+//! This macro compares the raw bytes, so it works with any reader,
+//! including a reader of binary data that is not valid UTF-8.
+//!
+//! # Module macros
+//!
+//! * [`assert_io_read_to_bytes_lt_x`](macro@crate::assert_io_read_to_bytes_lt_x)
+//! * [`assert_io_read_to_bytes_lt_x_as_result`](macro@crate::assert_io_read_to_bytes_lt_x_as_result)
+//! * [`debug_assert_io_read_to_bytes_lt_x`](macro@crate::debug_assert_io_read_to_bytes_lt_x)
+
+/// Assert a ::std::io::Read read_to_end() value is less than an expression.
+///
+/// Pseudocode:<br>
+/// (reader.read_to_end(a_bytes) ⇒ a_bytes) < (expr into bytes)
+///
+/// * If true, return Result `Ok(a_bytes)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_io_read_to_bytes_lt_x`](macro@crate::assert_io_read_to_bytes_lt_x)
+/// * [`assert_io_read_to_bytes_lt_x_as_result`](macro@crate::assert_io_read_to_bytes_lt_x_as_result)
+/// * [`debug_assert_io_read_to_bytes_lt_x`](macro@crate::debug_assert_io_read_to_bytes_lt_x)
+///
+#[macro_export]
+macro_rules! assert_io_read_to_bytes_lt_x_as_result {
+    ($a_reader:expr, $b_expr:expr $(,)?) => {{
+        match (&$b_expr) {
+            b_expr => {
+                let mut a_bytes: Vec<u8> = Vec::new();
+                match $a_reader.read_to_end(&mut a_bytes) {
+                    Ok(_a_size) => {
+                        let b_bytes: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(b_expr);
+                        if a_bytes.as_slice() < b_bytes {
+                            Ok(a_bytes)
+                        } else {
+                            let (index, a_diff, b_diff) = $crate::bytes_diff::bytes_diff_region(&a_bytes, b_bytes, $crate::bytes_diff::hex);
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_io_read_to_bytes_lt_x!(a_reader, b_expr)`\n",
+                                        $crate::assertables_url_line!("assert_io_read_to_bytes_lt_x"),
+                                        " a_reader label: `{}`,\n",
+                                        " a_reader debug: `{:?}`,\n",
+                                        "   b_expr label: `{}`,\n",
+                                        "   b_expr debug: `{:?}`,\n",
+                                        "              a: `{:?}`,\n",
+                                        "              b: `{:?}`,\n",
+                                        "     diff index: `{}`,\n",
+                                        "         a diff: `{}`,\n",
+                                        "         b diff: `{}`"
+                                    ),
+                                    stringify!($a_reader),
+                                    $a_reader,
+                                    stringify!($b_expr),
+                                    b_expr,
+                                    a_bytes,
+                                    b_bytes,
+                                    index,
+                                    a_diff,
+                                    b_diff
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "read error: `assert_io_read_to_bytes_lt_x!(a_reader, b_expr)`\n",
+                                    $crate::assertables_url_line!("assert_io_read_to_bytes_lt_x"),
+                                    " a_reader label: `{}`,\n",
+                                    " a_reader debug: `{:?}`,\n",
+                                    "   b_expr label: `{}`,\n",
+                                    "   b_expr debug: `{:?}`,\n",
+                                    "            err: `{:?}`"
+                                ),
+                                stringify!($a_reader),
+                                $a_reader,
+                                stringify!($b_expr),
+                                b_expr,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_io_read_to_bytes_lt_x_as_result {
+    #[allow(unused_imports)]
+    use std::io::Read;
+
+    #[test]
+    fn success() {
+        let mut reader = "alfa".as_bytes();
+        let value = b"bravo";
+        let actual = assert_io_read_to_bytes_lt_x_as_result!(reader, value);
+        assert_eq!(actual.unwrap(), vec![97, 108, 102, 97]);
+    }
+
+    #[test]
+    fn failure() {
+        let mut reader = "bravo".as_bytes();
+        let value = b"alfa";
+        let actual = assert_io_read_to_bytes_lt_x_as_result!(reader, value);
+        let message = concat!(
+            "assertion failed: `assert_io_read_to_bytes_lt_x!(a_reader, b_expr)`\n",
+            assertables_url_line!("assert_io_read_to_bytes_lt_x"),
+            " a_reader label: `reader`,\n",
+            " a_reader debug: `[]`,\n",
+            "   b_expr label: `value`,\n",
+            "   b_expr debug: `[97, 108, 102, 97]`,\n",
+            "              a: `[98, 114, 97, 118, 111]`,\n",
+            "              b: `[97, 108, 102, 97]`,\n",
+            "     diff index: `0`,\n",
+            "         a diff: `62 72 61 76 6f`,\n",
+            "         b diff: `61 6c 66 61`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a ::std::io::Read read_to_end() value is less than an expression.
+///
+/// Pseudocode:<br>
+/// (reader.read_to_end(a_bytes) ⇒ a_bytes) < (expr into bytes)
+///
+/// * If true, return `a_bytes`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::io::Read;
+///
+/// # fn main() {
+/// let mut reader = "alfa".as_bytes();
+/// let value = b"bravo";
+/// assert_io_read_to_bytes_lt_x!(reader, value);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut reader = "bravo".as_bytes();
+/// let value = b"alfa";
+/// assert_io_read_to_bytes_lt_x!(reader, value);
+/// # });
+/// // assertion failed: `assert_io_read_to_bytes_lt_x!(a_reader, b_expr)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_bytes_lt_x.html
+/// //  a_reader label: `reader`,
+/// //  a_reader debug: `[]`,
+/// //    b_expr label: `value`,
+/// //    b_expr debug: `[97, 108, 102, 97]`,
+/// //               a: `[98, 114, 97, 118, 111]`,
+/// //               b: `[97, 108, 102, 97]`,
+/// //      diff index: `0`,
+/// //          a diff: `62 72 61 76 6f`,
+/// //          b diff: `61 6c 66 61`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_io_read_to_bytes_lt_x!(a_reader, b_expr)`\n",
+/// #     assertables_url_line!("assert_io_read_to_bytes_lt_x"),
+/// #     " a_reader label: `reader`,\n",
+/// #     " a_reader debug: `[]`,\n",
+/// #     "   b_expr label: `value`,\n",
+/// #     "   b_expr debug: `[97, 108, 102, 97]`,\n",
+/// #     "              a: `[98, 114, 97, 118, 111]`,\n",
+/// #     "              b: `[97, 108, 102, 97]`,\n",
+/// #     "     diff index: `0`,\n",
+/// #     "         a diff: `62 72 61 76 6f`,\n",
+/// #     "         b diff: `61 6c 66 61`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The expression can be anything that implements `AsRef<[u8]>`,
+/// such as a byte string literal, a byte array, or a `Vec<u8>`.
+///
+/// This macro compares the raw bytes, so it works with any reader,
+/// including a reader of binary data that is not valid UTF-8.
+///
+/// # Module macros
+///
+/// * [`assert_io_read_to_bytes_lt_x`](macro@crate::assert_io_read_to_bytes_lt_x)
+/// * [`assert_io_read_to_bytes_lt_x_as_result`](macro@crate::assert_io_read_to_bytes_lt_x_as_result)
+/// * [`debug_assert_io_read_to_bytes_lt_x`](macro@crate::debug_assert_io_read_to_bytes_lt_x)
+///
+#[macro_export]
+macro_rules! assert_io_read_to_bytes_lt_x {
+    ($a_reader:expr, $b_expr:expr $(,)?) => {{
+        match $crate::assert_io_read_to_bytes_lt_x_as_result!($a_reader, $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_reader:expr, $b_expr:expr, $($message:tt)+) => {{
+        match $crate::assert_io_read_to_bytes_lt_x_as_result!($a_reader, $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_io_read_to_bytes_lt_x {
+    #[allow(unused_imports)]
+    use std::io::Read;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let mut reader = "alfa".as_bytes();
+        let value = b"bravo";
+        let actual = assert_io_read_to_bytes_lt_x!(reader, value);
+        assert_eq!(actual, vec![97, 108, 102, 97]);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut reader = "bravo".as_bytes();
+            let value = b"alfa";
+            let _actual = assert_io_read_to_bytes_lt_x!(reader, value);
+        });
+        let message = concat!(
+            "assertion failed: `assert_io_read_to_bytes_lt_x!(a_reader, b_expr)`\n",
+            assertables_url_line!("assert_io_read_to_bytes_lt_x"),
+            " a_reader label: `reader`,\n",
+            " a_reader debug: `[]`,\n",
+            "   b_expr label: `value`,\n",
+            "   b_expr debug: `[97, 108, 102, 97]`,\n",
+            "              a: `[98, 114, 97, 118, 111]`,\n",
+            "              b: `[97, 108, 102, 97]`,\n",
+            "     diff index: `0`,\n",
+            "         a diff: `62 72 61 76 6f`,\n",
+            "         b diff: `61 6c 66 61`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a ::std::io::Read read_to_end() value is less than an expression.
+///
+/// Pseudocode:<br>
+/// (reader.read_to_end(a_bytes) ⇒ a_bytes) < (expr into bytes)
+///
+/// This macro provides the same statements as [`assert_io_read_to_bytes_lt_x`](macro.assert_io_read_to_bytes_lt_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_io_read_to_bytes_lt_x`](macro@crate::assert_io_read_to_bytes_lt_x)
+/// * [`assert_io_read_to_bytes_lt_x_as_result`](macro@crate::assert_io_read_to_bytes_lt_x_as_result)
+/// * [`debug_assert_io_read_to_bytes_lt_x`](macro@crate::debug_assert_io_read_to_bytes_lt_x)
+///
+#[macro_export]
+macro_rules! debug_assert_io_read_to_bytes_lt_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_io_read_to_bytes_lt_x!($($arg)*);
+        }
+    };
+}
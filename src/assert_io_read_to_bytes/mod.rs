@@ -0,0 +1,75 @@
+//! Assert for comparing input/output reader byte streams.
+//!
+//! These macros help with input/output readers, such as file handles, byte arrays,
+//! input streams, the trait `::std::io::Read`, and anything that implements a
+//! method `read_to_end() -> Vec<u8>`.
+//!
+//! These macros compare raw bytes, so they work with binary data that is not
+//! valid UTF-8. For text, see [`assert_io_read_to_string`](module@crate::assert_io_read_to_string).
+//!
+//! ## Macros
+//!
+//! Compare a reader with another reader:
+//!
+//! * [`assert_io_read_to_bytes_eq!(reader1, reader2)`](macro@crate::assert_io_read_to_bytes_eq) ≈ reader1.read_to_end() = reader2.read_to_end()
+//! * [`assert_io_read_to_bytes_ne!(reader1, reader2)`](macro@crate::assert_io_read_to_bytes_ne) ≈ reader1.read_to_end() ≠ reader2.read_to_end()
+//! * [`assert_io_read_to_bytes_lt!(reader1, reader2)`](macro@crate::assert_io_read_to_bytes_lt) ≈ reader1.read_to_end() < reader2.read_to_end()
+//! * [`assert_io_read_to_bytes_le!(reader1, reader2)`](macro@crate::assert_io_read_to_bytes_le) ≈ reader1.read_to_end() ≤ reader2.read_to_end()
+//! * [`assert_io_read_to_bytes_gt!(reader1, reader2)`](macro@crate::assert_io_read_to_bytes_gt) ≈ reader1.read_to_end() > reader2.read_to_end()
+//! * [`assert_io_read_to_bytes_ge!(reader1, reader2)`](macro@crate::assert_io_read_to_bytes_ge) ≈ reader1.read_to_end() ≥ reader2.read_to_end()
+//!
+//! Compare a reader with an expression:
+//!
+//! * [`assert_io_read_to_bytes_eq_x!(reader, expr)`](macro@crate::assert_io_read_to_bytes_eq_x) ≈ reader.read_to_end() = expr
+//! * [`assert_io_read_to_bytes_ne_x!(reader, expr)`](macro@crate::assert_io_read_to_bytes_ne_x) ≈ reader.read_to_end() ≠ expr
+//! * [`assert_io_read_to_bytes_lt_x!(reader, expr)`](macro@crate::assert_io_read_to_bytes_lt_x) ≈ reader.read_to_end() < expr
+//! * [`assert_io_read_to_bytes_le_x!(reader, expr)`](macro@crate::assert_io_read_to_bytes_le_x) ≈ reader.read_to_end() ≤ expr
+//! * [`assert_io_read_to_bytes_gt_x!(reader, expr)`](macro@crate::assert_io_read_to_bytes_gt_x) ≈ reader.read_to_end() > expr
+//! * [`assert_io_read_to_bytes_ge_x!(reader, expr)`](macro@crate::assert_io_read_to_bytes_ge_x) ≈ reader.read_to_end() ≥ expr
+//!
+//! When the bytes differ, the message shows the index of the first differing
+//! byte, and a hex dump of each side's bytes from that index, up to 16 bytes.
+//!
+//! If a reader fails, then the message starts with "read error:" rather than
+//! "assertion failed:", so a read error is distinct from a content mismatch.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::io::Read;
+//!
+//! let mut a: &[u8] = &[0xff, 0xfe];
+//! let mut b: &[u8] = &[0xff, 0xfe];
+//! assert_io_read_to_bytes_eq!(a, b);
+//! ```
+
+// Compare another
+pub mod assert_io_read_to_bytes_eq;
+pub mod assert_io_read_to_bytes_ge;
+pub mod assert_io_read_to_bytes_gt;
+pub mod assert_io_read_to_bytes_le;
+pub mod assert_io_read_to_bytes_lt;
+pub mod assert_io_read_to_bytes_ne;
+
+// Compare expression
+pub mod assert_io_read_to_bytes_eq_x;
+pub mod assert_io_read_to_bytes_ge_x;
+pub mod assert_io_read_to_bytes_gt_x;
+pub mod assert_io_read_to_bytes_le_x;
+pub mod assert_io_read_to_bytes_lt_x;
+pub mod assert_io_read_to_bytes_ne_x;
+
+#[cfg(test)]
+pub(crate) mod test_support {
+
+    /// A reader that always fails.
+    #[derive(Debug)]
+    pub struct ErrReader;
+
+    impl ::std::io::Read for ErrReader {
+        fn read(&mut self, _buf: &mut [u8]) -> ::std::io::Result<usize> {
+            Err(::std::io::Error::other("oops"))
+        }
+    }
+}
@@ -53,7 +53,7 @@ macro_rules! assert_io_read_to_string_contains_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_io_read_to_string_contains!(reader, &containee)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_contains.html\n",
+                                        $crate::assertables_url_line!("assert_io_read_to_string_contains"),
                                         "    reader label: `{}`,\n",
                                         "    reader debug: `{:?}`,\n",
                                         " containee label: `{}`,\n",
@@ -73,8 +73,8 @@ macro_rules! assert_io_read_to_string_contains_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_io_read_to_string_contains!(reader, &containee)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_contains.html\n",
+                                    "read error: `assert_io_read_to_string_contains!(reader, &containee)`\n",
+                                    $crate::assertables_url_line!("assert_io_read_to_string_contains"),
                                     "    reader label: `{}`,\n",
                                     "    reader debug: `{:?}`,\n",
                                     " containee label: `{}`,\n",
@@ -115,7 +115,7 @@ mod test_assert_io_read_to_string_contains_as_result {
         let actual = assert_io_read_to_string_contains_as_result!(reader, &containee);
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_contains!(reader, &containee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_contains.html\n",
+            assertables_url_line!("assert_io_read_to_string_contains"),
             "    reader label: `reader`,\n",
             "    reader debug: `[]`,\n",
             " containee label: `&containee`,\n",
@@ -164,7 +164,7 @@ mod test_assert_io_read_to_string_contains_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_io_read_to_string_contains!(reader, &containee)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_contains.html\n",
+/// #     assertables_url_line!("assert_io_read_to_string_contains"),
 /// #     "    reader label: `reader`,\n",
 /// #     "    reader debug: `[]`,\n",
 /// #     " containee label: `&containee`,\n",
@@ -186,13 +186,13 @@ macro_rules! assert_io_read_to_string_contains {
     ($reader:expr, $containee:expr $(,)?) => {{
         match $crate::assert_io_read_to_string_contains_as_result!($reader, $containee) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_io_read_to_string_contains_as_result!($reader, $containee) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -220,7 +220,7 @@ mod test_assert_io_read_to_string_contains {
         });
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_contains!(reader, &containee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_contains.html\n",
+            assertables_url_line!("assert_io_read_to_string_contains"),
             "    reader label: `reader`,\n",
             "    reader debug: `[]`,\n",
             " containee label: `&containee`,\n",
@@ -272,7 +272,7 @@ mod test_assert_io_read_to_string_contains {
 #[macro_export]
 macro_rules! debug_assert_io_read_to_string_contains {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_io_read_to_string_contains!($($arg)*);
         }
     };
@@ -55,7 +55,7 @@ macro_rules! assert_io_read_to_string_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_io_read_to_string_eq!(a_reader, b_reader)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq.html\n",
+                                $crate::assertables_url_line!("assert_io_read_to_string_eq"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -77,8 +77,8 @@ macro_rules! assert_io_read_to_string_eq_as_result {
                 Err(
                     format!(
                         concat!(
-                            "assertion failed: `assert_io_read_to_string_eq!(a_reader, b_reader)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq.html\n",
+                            "read error: `assert_io_read_to_string_eq!(a_reader, b_reader)`\n",
+                            $crate::assertables_url_line!("assert_io_read_to_string_eq"),
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
@@ -119,7 +119,7 @@ mod test_assert_io_read_to_string_eq_as_result {
         let actual = assert_io_read_to_string_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_eq!(a_reader, b_reader)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq.html\n",
+            assertables_url_line!("assert_io_read_to_string_eq"),
             " a label: `a`,\n",
             " a debug: `[]`,\n",
             " b label: `b`,\n",
@@ -137,7 +137,7 @@ mod test_assert_io_read_to_string_eq_as_result {
         let actual = assert_io_read_to_string_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_eq!(a_reader, b_reader)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq.html\n",
+            assertables_url_line!("assert_io_read_to_string_eq"),
             " a label: `a`,\n",
             " a debug: `[]`,\n",
             " b label: `b`,\n",
@@ -147,6 +147,24 @@ mod test_assert_io_read_to_string_eq_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn read_error() {
+        let mut a: &[u8] = &[0xff, 0xfe];
+        let mut b = "alfa".as_bytes();
+        let actual = assert_io_read_to_string_eq_as_result!(a, b);
+        let message = concat!(
+            "read error: `assert_io_read_to_string_eq!(a_reader, b_reader)`\n",
+            assertables_url_line!("assert_io_read_to_string_eq"),
+            " a label: `a`,\n",
+            " a debug: `[255, 254]`,\n",
+            " b label: `b`,\n",
+            " b debug: `[]`,\n",
+            "       a: `Err(Error { kind: InvalidData, message: \"stream did not contain valid UTF-8\" })`,\n",
+            "       b: `Ok(4)`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
 }
 
 /// Assert a ::std::io::Read read_to_string() value is equal to another.
@@ -188,7 +206,7 @@ mod test_assert_io_read_to_string_eq_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_io_read_to_string_eq!(a_reader, b_reader)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq.html\n",
+/// #     assertables_url_line!("assert_io_read_to_string_eq"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `[]`,\n",
 /// #     " b label: `b`,\n",
@@ -211,13 +229,13 @@ macro_rules! assert_io_read_to_string_eq {
     ($a_reader:expr, $b:expr $(,)?) => {{
         match $crate::assert_io_read_to_string_eq_as_result!($a_reader, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_reader:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_io_read_to_string_eq_as_result!($a_reader, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -245,7 +263,7 @@ mod test_assert_io_read_to_string_eq {
         });
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_eq!(a_reader, b_reader)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq.html\n",
+            assertables_url_line!("assert_io_read_to_string_eq"),
             " a label: `a`,\n",
             " a debug: `[]`,\n",
             " b label: `b`,\n",
@@ -272,7 +290,7 @@ mod test_assert_io_read_to_string_eq {
         });
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_eq!(a_reader, b_reader)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq.html\n",
+            assertables_url_line!("assert_io_read_to_string_eq"),
             " a label: `a`,\n",
             " a debug: `[]`,\n",
             " b label: `b`,\n",
@@ -325,7 +343,7 @@ mod test_assert_io_read_to_string_eq {
 #[macro_export]
 macro_rules! debug_assert_io_read_to_string_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_io_read_to_string_eq!($($arg)*);
         }
     };
@@ -54,7 +54,7 @@ macro_rules! assert_io_read_to_string_eq_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_io_read_to_string_eq_x!(a_reader, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq_x.html\n",
+                                        $crate::assertables_url_line!("assert_io_read_to_string_eq_x"),
                                         " a_reader label: `{}`,\n",
                                         " a_reader debug: `{:?}`,\n",
                                         "   b_expr label: `{}`,\n",
@@ -76,8 +76,8 @@ macro_rules! assert_io_read_to_string_eq_x_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_io_read_to_string_eq_x!(a_reader, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq_x.html\n",
+                                    "read error: `assert_io_read_to_string_eq_x!(a_reader, b_expr)`\n",
+                                    $crate::assertables_url_line!("assert_io_read_to_string_eq_x"),
                                     " a_reader label: `{}`,\n",
                                     " a_reader debug: `{:?}`,\n",
                                     "   b_expr label: `{}`,\n",
@@ -118,7 +118,7 @@ mod test_assert_io_read_to_string_eq_x_as_result {
         let actual = assert_io_read_to_string_eq_x_as_result!(reader, &value);
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_eq_x!(a_reader, b_expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq_x.html\n",
+            assertables_url_line!("assert_io_read_to_string_eq_x"),
             " a_reader label: `reader`,\n",
             " a_reader debug: `[]`,\n",
             "   b_expr label: `&value`,\n",
@@ -136,7 +136,7 @@ mod test_assert_io_read_to_string_eq_x_as_result {
         let actual = assert_io_read_to_string_eq_x_as_result!(reader, &value);
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_eq_x!(a_reader, b_expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq_x.html\n",
+            assertables_url_line!("assert_io_read_to_string_eq_x"),
             " a_reader label: `reader`,\n",
             " a_reader debug: `[]`,\n",
             "   b_expr label: `&value`,\n",
@@ -146,6 +146,23 @@ mod test_assert_io_read_to_string_eq_x_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn read_error() {
+        let mut reader: &[u8] = &[0xff, 0xfe];
+        let value = String::from("alfa");
+        let actual = assert_io_read_to_string_eq_x_as_result!(reader, &value);
+        let message = concat!(
+            "read error: `assert_io_read_to_string_eq_x!(a_reader, b_expr)`\n",
+            assertables_url_line!("assert_io_read_to_string_eq_x"),
+            " a_reader label: `reader`,\n",
+            " a_reader debug: `[255, 254]`,\n",
+            "   b_expr label: `&value`,\n",
+            "   b_expr debug: `\"alfa\"`,\n",
+            "            err: `Error { kind: InvalidData, message: \"stream did not contain valid UTF-8\" }`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
 }
 
 /// Assert a ::std::io::Read read_to_string() value is equal to an expression.
@@ -187,7 +204,7 @@ mod test_assert_io_read_to_string_eq_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_io_read_to_string_eq_x!(a_reader, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq_x.html\n",
+/// #     assertables_url_line!("assert_io_read_to_string_eq_x"),
 /// #     " a_reader label: `reader`,\n",
 /// #     " a_reader debug: `[]`,\n",
 /// #     "   b_expr label: `&value`,\n",
@@ -210,13 +227,13 @@ macro_rules! assert_io_read_to_string_eq_x {
     ($a_reader:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_io_read_to_string_eq_x_as_result!($a_reader, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_reader:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_io_read_to_string_eq_x_as_result!($a_reader, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -244,7 +261,7 @@ mod test_assert_io_read_to_string_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_eq_x!(a_reader, b_expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq_x.html\n",
+            assertables_url_line!("assert_io_read_to_string_eq_x"),
             " a_reader label: `reader`,\n",
             " a_reader debug: `[]`,\n",
             "   b_expr label: `&value`,\n",
@@ -271,7 +288,7 @@ mod test_assert_io_read_to_string_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_eq_x!(a_reader, b_expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_eq_x.html\n",
+            assertables_url_line!("assert_io_read_to_string_eq_x"),
             " a_reader label: `reader`,\n",
             " a_reader debug: `[]`,\n",
             "   b_expr label: `&value`,\n",
@@ -324,7 +341,7 @@ mod test_assert_io_read_to_string_eq_x {
 #[macro_export]
 macro_rules! debug_assert_io_read_to_string_eq_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_io_read_to_string_eq_x!($($arg)*);
         }
     };
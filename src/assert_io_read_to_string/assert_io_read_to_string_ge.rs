@@ -55,7 +55,7 @@ macro_rules! assert_io_read_to_string_ge_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_io_read_to_string_ge!(a_reader, b_reader)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_ge.html\n",
+                                $crate::assertables_url_line!("assert_io_read_to_string_ge"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -77,8 +77,8 @@ macro_rules! assert_io_read_to_string_ge_as_result {
                 Err(
                     format!(
                         concat!(
-                            "assertion failed: `assert_io_read_to_string_ge!(a_reader, b_reader)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_ge.html\n",
+                            "read error: `assert_io_read_to_string_ge!(a_reader, b_reader)`\n",
+                            $crate::assertables_url_line!("assert_io_read_to_string_ge"),
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             "  b label: `{}`,\n",
@@ -133,7 +133,7 @@ mod test_assert_io_read_to_string_ge_as_result {
         let actual = assert_io_read_to_string_ge_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_ge!(a_reader, b_reader)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_ge.html\n",
+            assertables_url_line!("assert_io_read_to_string_ge"),
             " a label: `a`,\n",
             " a debug: `[]`,\n",
             " b label: `b`,\n",
@@ -184,7 +184,7 @@ mod test_assert_io_read_to_string_ge_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_io_read_to_string_ge!(a_reader, b_reader)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_ge.html\n",
+/// #     assertables_url_line!("assert_io_read_to_string_ge"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `[]`,\n",
 /// #     " b label: `b`,\n",
@@ -207,13 +207,13 @@ macro_rules! assert_io_read_to_string_ge {
     ($a_reader:expr, $b:expr $(,)?) => {{
         match $crate::assert_io_read_to_string_ge_as_result!($a_reader, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_reader:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_io_read_to_string_ge_as_result!($a_reader, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -249,7 +249,7 @@ mod test_assert_io_read_to_string_ge {
         });
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_ge!(a_reader, b_reader)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_ge.html\n",
+            assertables_url_line!("assert_io_read_to_string_ge"),
             " a label: `a`,\n",
             " a debug: `[]`,\n",
             " b label: `b`,\n",
@@ -302,7 +302,7 @@ mod test_assert_io_read_to_string_ge {
 #[macro_export]
 macro_rules! debug_assert_io_read_to_string_ge {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_io_read_to_string_ge!($($arg)*);
         }
     };
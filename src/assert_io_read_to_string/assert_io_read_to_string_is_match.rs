@@ -54,7 +54,7 @@ macro_rules! assert_io_read_to_string_is_match_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_io_read_to_string_is_match!(a_reader, &matcher)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_is_match.html\n",
+                                        $crate::assertables_url_line!("assert_io_read_to_string_is_match"),
                                         "  reader label: `{}`,\n",
                                         "  reader debug: `{:?}`,\n",
                                         " matcher label: `{}`,\n",
@@ -76,8 +76,8 @@ macro_rules! assert_io_read_to_string_is_match_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_io_read_to_string_is_match!(a_reader, &matcher)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_is_match.html\n",
+                                    "read error: `assert_io_read_to_string_is_match!(a_reader, &matcher)`\n",
+                                    $crate::assertables_url_line!("assert_io_read_to_string_is_match"),
                                     "  reader label: `{}`,\n",
                                     "  reader debug: `{:?}`,\n",
                                     " matcher label: `{}`,\n",
@@ -118,7 +118,7 @@ mod test_assert_io_read_to_string_is_match_as_result {
         let actual = assert_io_read_to_string_is_match_as_result!(reader, &matcher);
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_is_match!(a_reader, &matcher)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_is_match.html\n",
+            assertables_url_line!("assert_io_read_to_string_is_match"),
             "  reader label: `reader`,\n",
             "  reader debug: `[]`,\n",
             " matcher label: `&matcher`,\n",
@@ -170,7 +170,7 @@ mod test_assert_io_read_to_string_is_match_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_io_read_to_string_is_match!(a_reader, &matcher)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_is_match.html\n",
+/// #     assertables_url_line!("assert_io_read_to_string_is_match"),
 /// #     "  reader label: `reader`,\n",
 /// #     "  reader debug: `[]`,\n",
 /// #     " matcher label: `&matcher`,\n",
@@ -193,13 +193,13 @@ macro_rules! assert_io_read_to_string_is_match {
     ($a_reader:expr, $b_matcher:expr $(,)?) => {{
         match $crate::assert_io_read_to_string_is_match_as_result!($a_reader, $b_matcher) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_reader:expr, $b_matcher:expr, $($message:tt)+) => {{
         match $crate::assert_io_read_to_string_is_match_as_result!($a_reader, $b_matcher) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -227,7 +227,7 @@ mod test_assert_io_read_to_string_is_match {
         });
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_is_match!(a_reader, &matcher)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_is_match.html\n",
+            assertables_url_line!("assert_io_read_to_string_is_match"),
             "  reader label: `reader`,\n",
             "  reader debug: `[]`,\n",
             " matcher label: `&matcher`,\n",
@@ -280,7 +280,7 @@ mod test_assert_io_read_to_string_is_match {
 #[macro_export]
 macro_rules! debug_assert_io_read_to_string_is_match {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_io_read_to_string_is_match!($($arg)*);
         }
     };
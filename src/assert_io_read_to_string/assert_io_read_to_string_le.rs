@@ -55,7 +55,7 @@ macro_rules! assert_io_read_to_string_le_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_io_read_to_string_le!(a_reader, b_reader)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le.html\n",
+                                $crate::assertables_url_line!("assert_io_read_to_string_le"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -77,8 +77,8 @@ macro_rules! assert_io_read_to_string_le_as_result {
                 Err(
                     format!(
                         concat!(
-                            "assertion failed: `assert_io_read_to_string_le!(a_reader, b_reader)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le.html\n",
+                            "read error: `assert_io_read_to_string_le!(a_reader, b_reader)`\n",
+                            $crate::assertables_url_line!("assert_io_read_to_string_le"),
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             "  b label: `{}`,\n",
@@ -122,7 +122,7 @@ mod test_assert_io_read_to_string_le_as_result {
         let actual = assert_io_read_to_string_le_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_le!(a_reader, b_reader)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le.html\n",
+            assertables_url_line!("assert_io_read_to_string_le"),
             " a label: `a`,\n",
             " a debug: `[]`,\n",
             " b label: `b`,\n",
@@ -173,7 +173,7 @@ mod test_assert_io_read_to_string_le_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_io_read_to_string_le!(a_reader, b_reader)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le.html\n",
+/// #     assertables_url_line!("assert_io_read_to_string_le"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `[]`,\n",
 /// #     " b label: `b`,\n",
@@ -196,13 +196,13 @@ macro_rules! assert_io_read_to_string_le {
     ($a_reader:expr, $b:expr $(,)?) => {{
         match $crate::assert_io_read_to_string_le_as_result!($a_reader, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_reader:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_io_read_to_string_le_as_result!($a_reader, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -230,7 +230,7 @@ mod test_assert_io_read_to_string_le {
         });
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_le!(a_reader, b_reader)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le.html\n",
+            assertables_url_line!("assert_io_read_to_string_le"),
             " a label: `a`,\n",
             " a debug: `[]`,\n",
             " b label: `b`,\n",
@@ -283,7 +283,7 @@ mod test_assert_io_read_to_string_le {
 #[macro_export]
 macro_rules! debug_assert_io_read_to_string_le {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_io_read_to_string_le!($($arg)*);
         }
     };
@@ -54,7 +54,7 @@ macro_rules! assert_io_read_to_string_le_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_io_read_to_string_le_x!(a_reader, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le_x.html\n",
+                                        $crate::assertables_url_line!("assert_io_read_to_string_le_x"),
                                         " a_reader label: `{}`,\n",
                                         " a_reader debug: `{:?}`,\n",
                                         "   b_expr label: `{}`,\n",
@@ -76,8 +76,8 @@ macro_rules! assert_io_read_to_string_le_x_as_result {
                         Err(
                             format!(
                                 concat!(
-                                    "assertion failed: `assert_io_read_to_string_le_x!(a_reader, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le_x.html\n",
+                                    "read error: `assert_io_read_to_string_le_x!(a_reader, b_expr)`\n",
+                                    $crate::assertables_url_line!("assert_io_read_to_string_le_x"),
                                     " a_reader label: `{}`,\n",
                                     " a_reader debug: `{:?}`,\n",
                                     "   b_expr label: `{}`,\n",
@@ -118,7 +118,7 @@ mod test_assert_io_read_to_string_le_x_as_result {
         let actual = assert_io_read_to_string_le_x_as_result!(reader, &value);
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_le_x!(a_reader, b_expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le_x.html\n",
+            assertables_url_line!("assert_io_read_to_string_le_x"),
             " a_reader label: `reader`,\n",
             " a_reader debug: `[]`,\n",
             "   b_expr label: `&value`,\n",
@@ -169,7 +169,7 @@ mod test_assert_io_read_to_string_le_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_io_read_to_string_le_x!(a_reader, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le_x.html\n",
+/// #     assertables_url_line!("assert_io_read_to_string_le_x"),
 /// #     " a_reader label: `reader`,\n",
 /// #     " a_reader debug: `[]`,\n",
 /// #     "   b_expr label: `&value`,\n",
@@ -192,13 +192,13 @@ macro_rules! assert_io_read_to_string_le_x {
     ($a_reader:expr,  $b_expr:expr $(,)?) => {{
         match $crate::assert_io_read_to_string_le_x_as_result!($a_reader, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_reader:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_io_read_to_string_le_x_as_result!($a_reader, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -226,7 +226,7 @@ mod test_assert_io_read_to_string_le_x {
         });
         let message = concat!(
             "assertion failed: `assert_io_read_to_string_le_x!(a_reader, b_expr)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_io_read_to_string_le_x.html\n",
+            assertables_url_line!("assert_io_read_to_string_le_x"),
             " a_reader label: `reader`,\n",
             " a_reader debug: `[]`,\n",
             "   b_expr label: `&value`,\n",
@@ -279,7 +279,7 @@ mod test_assert_io_read_to_string_le_x {
 #[macro_export]
 macro_rules! debug_assert_io_read_to_string_le_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_io_read_to_string_le_x!($($arg)*);
         }
     };
@@ -30,6 +30,11 @@
 //! * [`assert_io_read_to_string_is_match!(reader, &matcher)`](macro@crate::assert_io_read_to_string_is_match) ≈ matcher.is_match(reader.read_to_string())
 //!
 //!
+//! If a reader fails, such as when its bytes are not valid UTF-8, then the
+//! message starts with "read error:" rather than "assertion failed:", so a
+//! read error is distinct from a content mismatch, for both the panic and
+//! the `_as_result` macros.
+//!
 //! # Example
 //!
 //! ```rust
@@ -12,6 +12,11 @@
 //! assert_is_empty!(a);
 //! ```
 //!
+//! If the assertion fails, then the message shows the length of the item,
+//! when the item is a standard collection or string, such as a `String`,
+//! array, slice, `Vec`, `VecDeque`, `HashMap`, `BTreeMap`, and so on.
+//! The message truncates a long debug representation with an ellipsis.
+//!
 //! # Module macros
 //!
 //! * [`assert_is_empty`](macro@crate::assert_is_empty)
@@ -39,21 +44,28 @@
 #[macro_export]
 macro_rules! assert_is_empty_as_result {
     ($a:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_is_empty::{ProbeLen as _, ProbeNoLen as _};
         match (&$a) {
             a => {
                 if a.is_empty() {
                     Ok(())
                 } else {
+                    let len = match (&$crate::assert_is_empty::LenProbe(a)).probe_len() {
+                        Some(len) => format!(",\n   len: `{}`", len),
+                        None => String::new(),
+                    };
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_is_empty!(a)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_empty.html\n",
+                                $crate::assertables_url_line!("assert_is_empty"),
                                 " label: `{}`,\n",
-                                " debug: `{:?}`",
+                                " debug: `{}`{}",
                             ),
                             stringify!($a),
-                            a,
+                            $crate::__private::debug_truncated(a, $crate::assert_is_empty::DEBUG_MAX_CHARS),
+                            len,
                         )
                     )
                 }
@@ -78,12 +90,80 @@ mod test_assert_is_empty_as_result {
         let actual = assert_is_empty_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_is_empty!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_empty.html\n",
+            assertables_url_line!("assert_is_empty"),
+            " label: `a`,\n",
+            " debug: `\"alfa\"`,\n",
+            "   len: `4`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_with_vec() {
+        let a = vec![1, 2, 3];
+        let actual = assert_is_empty_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_is_empty!(a)`\n",
+            assertables_url_line!("assert_is_empty"),
             " label: `a`,\n",
-            " debug: `\"alfa\"`"
+            " debug: `[1, 2, 3]`,\n",
+            "   len: `3`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn failure_with_string() {
+        let a = String::from("alfa");
+        let actual = assert_is_empty_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_is_empty!(a)`\n",
+            assertables_url_line!("assert_is_empty"),
+            " label: `a`,\n",
+            " debug: `\"alfa\"`,\n",
+            "   len: `4`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_without_len() {
+        #[derive(Debug)]
+        struct Foo;
+        impl Foo {
+            fn is_empty(&self) -> bool {
+                false
+            }
+        }
+        let a = Foo;
+        let actual = assert_is_empty_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_is_empty!(a)`\n",
+            assertables_url_line!("assert_is_empty"),
+            " label: `a`,\n",
+            " debug: `Foo`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_with_long_debug() {
+        let a = vec![0; 1000];
+        let actual = assert_is_empty_as_result!(a);
+        let err = actual.unwrap_err();
+        let debug = format!("{:?}", a);
+        let message = format!(
+            concat!(
+                "assertion failed: `assert_is_empty!(a)`\n",
+                assertables_url_line!("assert_is_empty"),
+                " label: `a`,\n",
+                " debug: `{}…`,\n",
+                "   len: `1000`"
+            ),
+            &debug[..256]
+        );
+        assert_eq!(err, message);
+    }
 }
 
 /// Assert an expression (such as a string or array) is empty.
@@ -114,13 +194,15 @@ mod test_assert_is_empty_as_result {
 /// // assertion failed: `assert_is_empty!(a)`
 /// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_empty.html
 /// //  label: `a`,
-/// //  debug: `\"alfa\"`
+/// //  debug: `\"alfa\"`,
+/// //    len: `4`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_is_empty!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_empty.html\n",
+/// #     assertables_url_line!("assert_is_empty"),
 /// #     " label: `a`,\n",
-/// #     " debug: `\"alfa\"`"
+/// #     " debug: `\"alfa\"`,\n",
+/// #     "   len: `4`"
 /// # );
 /// # assert_eq!(actual, message);
 /// # }
@@ -137,13 +219,13 @@ macro_rules! assert_is_empty {
     ($a:expr $(,)?) => {{
         match $crate::assert_is_empty_as_result!($a) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_is_empty_as_result!($a) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -167,9 +249,10 @@ mod test_assert_is_empty {
         });
         let message = concat!(
             "assertion failed: `assert_is_empty!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_empty.html\n",
+            assertables_url_line!("assert_is_empty"),
             " label: `a`,\n",
-            " debug: `\"alfa\"`"
+            " debug: `\"alfa\"`,\n",
+            "   len: `4`"
         );
         assert_eq!(
             result
@@ -216,7 +299,7 @@ mod test_assert_is_empty {
 #[macro_export]
 macro_rules! debug_assert_is_empty {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_is_empty!($($arg)*);
         }
     };
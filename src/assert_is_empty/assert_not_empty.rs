@@ -48,7 +48,7 @@ macro_rules! assert_not_empty_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_not_empty!(a)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_empty.html\n",
+                                $crate::assertables_url_line!("assert_not_empty"),
                                 " label: `{}`,\n",
                                 " debug: `{:?}`"
                             ),
@@ -78,7 +78,7 @@ mod test_assert_not_empty_as_result {
         let actual = assert_not_empty_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_not_empty!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_empty.html\n",
+            assertables_url_line!("assert_not_empty"),
             " label: `a`,\n",
             " debug: `\"\"`",
         );
@@ -118,7 +118,7 @@ mod test_assert_not_empty_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_not_empty!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_empty.html\n",
+/// #     assertables_url_line!("assert_not_empty"),
 /// #     " label: `a`,\n",
 /// #     " debug: `\"\"`"
 /// # );
@@ -137,13 +137,13 @@ macro_rules! assert_not_empty {
     ($a:expr $(,)?) => {{
         match $crate::assert_not_empty_as_result!($a) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_not_empty_as_result!($a) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -167,7 +167,7 @@ mod test_assert_not_empty {
         });
         let message = concat!(
             "assertion failed: `assert_not_empty!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_empty.html\n",
+            assertables_url_line!("assert_not_empty"),
             " label: `a`,\n",
             " debug: `\"\"`",
         );
@@ -216,7 +216,7 @@ mod test_assert_not_empty {
 #[macro_export]
 macro_rules! debug_assert_not_empty {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_not_empty!($($arg)*);
         }
     };
@@ -17,3 +17,86 @@
 
 pub mod assert_is_empty;
 pub mod assert_not_empty;
+
+/// The maximum number of characters of a debug representation in a message.
+///
+/// Many callers pass large collections, so a full dump can bury the message.
+#[doc(hidden)]
+pub const DEBUG_MAX_CHARS: usize = 256;
+
+/// Get the length of a standard collection or string.
+///
+/// Rust has no standard trait for `len()`, so this trait provides one
+/// for the standard types that have an inherent `len()` method.
+#[doc(hidden)]
+pub trait KnownLen {
+    fn known_len(&self) -> usize;
+}
+
+macro_rules! impl_known_len {
+    ($($t:ty $(, $g:ident)*);+ $(;)?) => {
+        $(
+            impl<$($g),*> KnownLen for $t {
+                fn known_len(&self) -> usize {
+                    self.len()
+                }
+            }
+        )+
+    };
+}
+
+impl_known_len!(
+    str;
+    String;
+    ::std::ffi::OsStr;
+    ::std::ffi::OsString;
+    [T], T;
+    Vec<T>, T;
+    ::std::collections::VecDeque<T>, T;
+    ::std::collections::LinkedList<T>, T;
+    ::std::collections::BinaryHeap<T>, T;
+    ::std::collections::BTreeSet<T>, T;
+    ::std::collections::BTreeMap<K, V>, K, V;
+    ::std::collections::HashSet<T, S>, T, S;
+    ::std::collections::HashMap<K, V, S>, K, V, S;
+);
+
+impl<T, const N: usize> KnownLen for [T; N] {
+    fn known_len(&self) -> usize {
+        N
+    }
+}
+
+impl<T: KnownLen + ?Sized> KnownLen for &T {
+    fn known_len(&self) -> usize {
+        (**self).known_len()
+    }
+}
+
+/// Probe an item for its length, if its type has a known length.
+///
+/// The macros call `(&LenProbe(a)).probe_len()`. Method lookup tries
+/// [`ProbeLen`] first, which applies only if the type implements
+/// [`KnownLen`], then falls back to [`ProbeNoLen`], which returns `None`.
+#[doc(hidden)]
+pub struct LenProbe<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ProbeLen {
+    fn probe_len(&self) -> Option<usize>;
+}
+
+impl<T: KnownLen + ?Sized> ProbeLen for LenProbe<'_, T> {
+    fn probe_len(&self) -> Option<usize> {
+        Some(self.0.known_len())
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeNoLen {
+    fn probe_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<T: ?Sized> ProbeNoLen for &LenProbe<'_, T> {}
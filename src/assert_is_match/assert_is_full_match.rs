@@ -0,0 +1,350 @@
+//! Assert a matcher is a match for the entire matchee.
+//!
+//! Pseudocode:<br>
+//! (matcher anchored to start and end).is_match(matchee)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use regex::Regex;
+//!
+//! let a = Regex::new(r"al.a").expect("regex");
+//! let b = "alfa";
+//! assert_is_full_match!(a, b);
+//! ```
+//!
+//! This macro is for a matcher such as [`regex::Regex`](https://docs.rs/regex/),
+//! which has a method `as_str()` that returns its pattern, implements
+//! `FromStr`, and has a method `find(…)` that returns a match with `start()`
+//! and `end()`. The macro wraps the pattern as `\A(?:…)\z`, parses it as a
+//! new matcher, and checks that the new matcher is a match.
+//!
+//! In contrast, the macro [`assert_is_match`](macro@crate::assert_is_match)
+//! succeeds when the matcher finds a match anywhere in the matchee.
+//!
+//! Because the check is anchored, a regex with alternatives, such as `a|ab`,
+//! is a full match for `ab`, even though its first match is only `a`.
+//!
+//! If the assertion fails, then the message shows the span and text of
+//! the first match, if any, so you can see what partially matched.
+//!
+//! # Module macros
+//!
+//! * [`assert_is_full_match`](macro@crate::assert_is_full_match)
+//! * [`assert_is_full_match_as_result`](macro@crate::assert_is_full_match_as_result)
+//! * [`debug_assert_is_full_match`](macro@crate::debug_assert_is_full_match)
+
+/// Assert a matcher is a match for the entire matchee.
+///
+/// Pseudocode:<br>
+/// (matcher anchored to start and end).is_match(matchee)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_is_full_match`](macro@crate::assert_is_full_match)
+/// * [`assert_is_full_match_as_result`](macro@crate::assert_is_full_match_as_result)
+/// * [`debug_assert_is_full_match`](macro@crate::debug_assert_is_full_match)
+///
+#[macro_export]
+macro_rules! assert_is_full_match_as_result {
+    ($matcher:expr, $matchee:expr $(,)?) => {{
+        match (&$matcher, &$matchee) {
+            (matcher, matchee) => {
+                let anchored = $crate::assert_is_match::parse_like(
+                    matcher,
+                    &format!(r"\A(?:{})\z", matcher.as_str()),
+                );
+                let is_full_match = match anchored {
+                    Ok(anchored) => anchored.is_match(matchee),
+                    Err(_) => false,
+                };
+                if is_full_match {
+                    Ok(())
+                } else {
+                    let found = matcher.find(matchee);
+                    let span = found.as_ref().map(|m| m.start()..m.end());
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_is_full_match!(matcher, matchee)`\n",
+                                $crate::assertables_url_line!("assert_is_full_match"),
+                                " matcher label: `{}`,\n",
+                                " matcher debug: `{:?}`,\n",
+                                " matchee label: `{}`,\n",
+                                " matchee debug: `{:?}`,\n",
+                                "  matched span: `{:?}`,\n",
+                                "  matched text: `{:?}`"
+                            ),
+                            stringify!($matcher),
+                            matcher,
+                            stringify!($matchee),
+                            matchee,
+                            span,
+                            found.as_ref().map(|m| m.as_str())
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_is_full_match_as_result {
+    use regex::Regex;
+
+    #[test]
+    fn success() {
+        let a = Regex::new(r"al.a").expect("regex");
+        let b = "alfa";
+        let actual = assert_is_full_match_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = Regex::new(r"lf").expect("regex");
+        let b = "alfa";
+        let actual = assert_is_full_match_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_is_full_match!(matcher, matchee)`\n",
+            assertables_url_line!("assert_is_full_match"),
+            " matcher label: `a`,\n",
+            " matcher debug: `Regex(\"lf\")`,\n",
+            " matchee label: `b`,\n",
+            " matchee debug: `\"alfa\"`,\n",
+            "  matched span: `Some(1..3)`,\n",
+            "  matched text: `Some(\"lf\")`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_no_match() {
+        let a = Regex::new(r"zz").expect("regex");
+        let b = "alfa";
+        let actual = assert_is_full_match_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_is_full_match!(matcher, matchee)`\n",
+            assertables_url_line!("assert_is_full_match"),
+            " matcher label: `a`,\n",
+            " matcher debug: `Regex(\"zz\")`,\n",
+            " matchee label: `b`,\n",
+            " matchee debug: `\"alfa\"`,\n",
+            "  matched span: `None`,\n",
+            "  matched text: `None`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_alternation() {
+        let a = Regex::new(r"a|ab").expect("regex");
+        let b = "ab";
+        let actual = assert_is_full_match_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_alternation() {
+        let a = Regex::new(r"a|ab").expect("regex");
+        let b = "abc";
+        let actual = assert_is_full_match_as_result!(a, b);
+        assert!(actual
+            .unwrap_err()
+            .ends_with("  matched span: `Some(0..1)`,\n  matched text: `Some(\"a\")`"));
+    }
+
+    #[test]
+    fn success_with_string() {
+        let a = Regex::new(r"\w+").expect("regex");
+        let b = String::from("alfa");
+        let actual = assert_is_full_match_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_empty() {
+        let a = Regex::new(r"x*").expect("regex");
+        let b = "";
+        let actual = assert_is_full_match_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+}
+
+/// Assert a matcher is a match for the entire matchee.
+///
+/// Pseudocode:<br>
+/// (matcher anchored to start and end).is_match(matchee)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use regex::Regex;
+///
+/// # fn main() {
+/// let a = Regex::new(r"al.a").expect("regex");
+/// let b = "alfa";
+/// assert_is_full_match!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = Regex::new(r"lf").expect("regex");
+/// let b = "alfa";
+/// assert_is_full_match!(a, b);
+/// # });
+/// // assertion failed: `assert_is_full_match!(matcher, matchee)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_full_match.html
+/// //  matcher label: `a`,
+/// //  matcher debug: `Regex(\"lf\")`,
+/// //  matchee label: `b`,
+/// //  matchee debug: `\"alfa\"`,
+/// //   matched span: `Some(1..3)`,
+/// //   matched text: `Some(\"lf\")`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_is_full_match!(matcher, matchee)`\n",
+/// #     assertables_url_line!("assert_is_full_match"),
+/// #     " matcher label: `a`,\n",
+/// #     " matcher debug: `Regex(\"lf\")`,\n",
+/// #     " matchee label: `b`,\n",
+/// #     " matchee debug: `\"alfa\"`,\n",
+/// #     "  matched span: `Some(1..3)`,\n",
+/// #     "  matched text: `Some(\"lf\")`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is for a matcher such as [`regex::Regex`](https://docs.rs/regex/),
+/// which has a method `as_str()` that returns its pattern, implements
+/// `FromStr`, and has a method `find(…)` that returns a match with `start()`
+/// and `end()`. The macro wraps the pattern as `\A(?:…)\z`, parses it as a
+/// new matcher, and checks that the new matcher is a match.
+///
+/// In contrast, the macro [`assert_is_match`](macro@crate::assert_is_match)
+/// succeeds when the matcher finds a match anywhere in the matchee.
+///
+/// Because the check is anchored, a regex with alternatives, such as `a|ab`,
+/// is a full match for `ab`, even though its first match is only `a`.
+///
+/// If the assertion fails, then the message shows the span and text of
+/// the first match, if any, so you can see what partially matched.
+///
+/// # Module macros
+///
+/// * [`assert_is_full_match`](macro@crate::assert_is_full_match)
+/// * [`assert_is_full_match_as_result`](macro@crate::assert_is_full_match_as_result)
+/// * [`debug_assert_is_full_match`](macro@crate::debug_assert_is_full_match)
+///
+#[macro_export]
+macro_rules! assert_is_full_match {
+    ($matcher:expr, $matchee:expr $(,)?) => {{
+        match $crate::assert_is_full_match_as_result!($matcher, $matchee) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($matcher:expr, $matchee:expr, $($message:tt)+) => {{
+        match $crate::assert_is_full_match_as_result!($matcher, $matchee) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_is_full_match {
+    use regex::Regex;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = Regex::new(r"al.a").expect("regex");
+        let b = "alfa";
+        let actual = assert_is_full_match!(a, b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = Regex::new(r"lf").expect("regex");
+            let b = "alfa";
+            let _actual = assert_is_full_match!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_is_full_match!(matcher, matchee)`\n",
+            assertables_url_line!("assert_is_full_match"),
+            " matcher label: `a`,\n",
+            " matcher debug: `Regex(\"lf\")`,\n",
+            " matchee label: `b`,\n",
+            " matchee debug: `\"alfa\"`,\n",
+            "  matched span: `Some(1..3)`,\n",
+            "  matched text: `Some(\"lf\")`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a matcher is a match for the entire matchee.
+///
+/// Pseudocode:<br>
+/// (matcher anchored to start and end).is_match(matchee)
+///
+/// This macro provides the same statements as [`assert_is_full_match`](macro.assert_is_full_match.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_is_full_match`](macro@crate::assert_is_full_match)
+/// * [`assert_is_full_match_as_result`](macro@crate::assert_is_full_match_as_result)
+/// * [`debug_assert_is_full_match`](macro@crate::debug_assert_is_full_match)
+///
+#[macro_export]
+macro_rules! debug_assert_is_full_match {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_is_full_match!($($arg)*);
+        }
+    };
+}
@@ -50,7 +50,7 @@ macro_rules! assert_is_match_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_is_match!(matcher, matchee)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_match.html\n",
+                                $crate::assertables_url_line!("assert_is_match"),
                                 " matcher label: `{}`,\n",
                                 " matcher debug: `{:?}`,\n",
                                 " matchee label: `{}`,\n",
@@ -87,7 +87,7 @@ mod test_assert_is_match_as_result {
         let actual = assert_is_match_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_is_match!(matcher, matchee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_match.html\n",
+            assertables_url_line!("assert_is_match"),
             " matcher label: `a`,\n",
             " matcher debug: `Regex(\"lf\")`,\n",
             " matchee label: `b`,\n",
@@ -95,6 +95,41 @@ mod test_assert_is_match_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    // A matcher without the regex crate, to show that any type with
+    // a method `is_match` works.
+    #[derive(Debug)]
+    struct Prefix(&'static str);
+
+    impl Prefix {
+        fn is_match(&self, s: &str) -> bool {
+            s.starts_with(self.0)
+        }
+    }
+
+    #[test]
+    fn success_with_custom_matcher() {
+        let a = Prefix("al");
+        let b = "alfa";
+        let actual = assert_is_match_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_custom_matcher() {
+        let a = Prefix("al");
+        let b = "bravo";
+        let actual = assert_is_match_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_is_match!(matcher, matchee)`\n",
+            assertables_url_line!("assert_is_match"),
+            " matcher label: `a`,\n",
+            " matcher debug: `Prefix(\"al\")`,\n",
+            " matchee label: `b`,\n",
+            " matchee debug: `\"bravo\"`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
 }
 
 /// Assert a matcher is a match for an expression.
@@ -134,7 +169,7 @@ mod test_assert_is_match_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_is_match!(matcher, matchee)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_match.html\n",
+/// #     assertables_url_line!("assert_is_match"),
 /// #     " matcher label: `a`,\n",
 /// #     " matcher debug: `Regex(\"lf\")`,\n",
 /// #     " matchee label: `b`,\n",
@@ -155,13 +190,13 @@ macro_rules! assert_is_match {
     ($matcher:expr, $matchee:expr $(,)?) => {{
         match $crate::assert_is_match_as_result!($matcher, $matchee) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($matcher:expr, $matchee:expr, $($message:tt)+) => {{
         match $crate::assert_is_match_as_result!($matcher, $matchee) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -188,7 +223,7 @@ mod test_assert_is_match {
         });
         let message = concat!(
             "assertion failed: `assert_is_match!(matcher, matchee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_match.html\n",
+            assertables_url_line!("assert_is_match"),
             " matcher label: `a`,\n",
             " matcher debug: `Regex(\"lf\")`,\n",
             " matchee label: `b`,\n",
@@ -239,7 +274,7 @@ mod test_assert_is_match {
 #[macro_export]
 macro_rules! debug_assert_is_match {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_is_match!($($arg)*);
         }
     };
@@ -0,0 +1,293 @@
+//! Assert a matcher match count is less than or equal to an expression.
+//!
+//! Pseudocode:<br>
+//! matcher.find_iter(matchee).count() ≤ n
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use regex::Regex;
+//!
+//! let a = Regex::new(r"a").expect("regex");
+//! let b = "alfa";
+//! let n = 2;
+//! assert_is_match_count_le_x!(a, b, n);
+//! ```
+//!
+//! This macro is for a matcher such as [`regex::Regex`](https://docs.rs/regex/),
+//! which has a method `find_iter(…)` that returns successive non-overlapping
+//! matches, each with a method `as_str()`.
+//!
+//! If the assertion fails, then the message shows the count and the
+//! matched substrings.
+//!
+//! # Module macros
+//!
+//! * [`assert_is_match_count_le_x`](macro@crate::assert_is_match_count_le_x)
+//! * [`assert_is_match_count_le_x_as_result`](macro@crate::assert_is_match_count_le_x_as_result)
+//! * [`debug_assert_is_match_count_le_x`](macro@crate::debug_assert_is_match_count_le_x)
+
+/// Assert a matcher match count is less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// matcher.find_iter(matchee).count() ≤ n
+///
+/// * If true, return Result `Ok((count, n))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_is_match_count_le_x`](macro@crate::assert_is_match_count_le_x)
+/// * [`assert_is_match_count_le_x_as_result`](macro@crate::assert_is_match_count_le_x_as_result)
+/// * [`debug_assert_is_match_count_le_x`](macro@crate::debug_assert_is_match_count_le_x)
+///
+#[macro_export]
+macro_rules! assert_is_match_count_le_x_as_result {
+    ($matcher:expr, $matchee:expr, $n:expr $(,)?) => {{
+        match (&$matcher, &$matchee, &$n) {
+            (matcher, matchee, n) => {
+                let matches: Vec<&str> = matcher.find_iter(matchee).map(|m| m.as_str()).collect();
+                let count = matches.len();
+                if count <= *n {
+                    Ok((count, *n))
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_is_match_count_le_x!(matcher, matchee, n)`\n",
+                                $crate::assertables_url_line!("assert_is_match_count_le_x"),
+                                " matcher label: `{}`,\n",
+                                " matcher debug: `{:?}`,\n",
+                                " matchee label: `{}`,\n",
+                                " matchee debug: `{:?}`,\n",
+                                "       n label: `{}`,\n",
+                                "       n debug: `{:?}`,\n",
+                                "         count: `{:?}`,\n",
+                                "       matches: `{:?}`"
+                            ),
+                            stringify!($matcher),
+                            matcher,
+                            stringify!($matchee),
+                            matchee,
+                            stringify!($n),
+                            n,
+                            count,
+                            matches
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_is_match_count_le_x_as_result {
+    use regex::Regex;
+
+    #[test]
+    fn success() {
+        let a = Regex::new(r"a").expect("regex");
+        let b = "alfa";
+        let n = 2;
+        let actual = assert_is_match_count_le_x_as_result!(a, b, n);
+        assert_eq!(actual.unwrap(), (2, 2));
+    }
+
+    #[test]
+    fn failure() {
+        let a = Regex::new(r"a").expect("regex");
+        let b = "alfa";
+        let n = 1;
+        let actual = assert_is_match_count_le_x_as_result!(a, b, n);
+        let message = concat!(
+            "assertion failed: `assert_is_match_count_le_x!(matcher, matchee, n)`\n",
+            assertables_url_line!("assert_is_match_count_le_x"),
+            " matcher label: `a`,\n",
+            " matcher debug: `Regex(\"a\")`,\n",
+            " matchee label: `b`,\n",
+            " matchee debug: `\"alfa\"`,\n",
+            "       n label: `n`,\n",
+            "       n debug: `1`,\n",
+            "         count: `2`,\n",
+            "       matches: `[\"a\", \"a\"]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a matcher match count is less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// matcher.find_iter(matchee).count() ≤ n
+///
+/// * If true, return `(count, n)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use regex::Regex;
+///
+/// # fn main() {
+/// let a = Regex::new(r"a").expect("regex");
+/// let b = "alfa";
+/// let n = 2;
+/// assert_is_match_count_le_x!(a, b, n);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = Regex::new(r"a").expect("regex");
+/// let b = "alfa";
+/// let n = 1;
+/// assert_is_match_count_le_x!(a, b, n);
+/// # });
+/// // assertion failed: `assert_is_match_count_le_x!(matcher, matchee, n)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_is_match_count_le_x.html
+/// //  matcher label: `a`,
+/// //  matcher debug: `Regex(\"a\")`,
+/// //  matchee label: `b`,
+/// //  matchee debug: `\"alfa\"`,
+/// //        n label: `n`,
+/// //        n debug: `1`,
+/// //          count: `2`,
+/// //        matches: `[\"a\", \"a\"]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_is_match_count_le_x!(matcher, matchee, n)`\n",
+/// #     assertables_url_line!("assert_is_match_count_le_x"),
+/// #     " matcher label: `a`,\n",
+/// #     " matcher debug: `Regex(\"a\")`,\n",
+/// #     " matchee label: `b`,\n",
+/// #     " matchee debug: `\"alfa\"`,\n",
+/// #     "       n label: `n`,\n",
+/// #     "       n debug: `1`,\n",
+/// #     "         count: `2`,\n",
+/// #     "       matches: `[\"a\", \"a\"]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is for a matcher such as [`regex::Regex`](https://docs.rs/regex/),
+/// which has a method `find_iter(…)` that returns successive non-overlapping
+/// matches, each with a method `as_str()`.
+///
+/// If the assertion fails, then the message shows the count and the
+/// matched substrings.
+///
+/// # Module macros
+///
+/// * [`assert_is_match_count_le_x`](macro@crate::assert_is_match_count_le_x)
+/// * [`assert_is_match_count_le_x_as_result`](macro@crate::assert_is_match_count_le_x_as_result)
+/// * [`debug_assert_is_match_count_le_x`](macro@crate::debug_assert_is_match_count_le_x)
+///
+#[macro_export]
+macro_rules! assert_is_match_count_le_x {
+    ($matcher:expr, $matchee:expr, $n:expr $(,)?) => {{
+        match $crate::assert_is_match_count_le_x_as_result!($matcher, $matchee, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($matcher:expr, $matchee:expr, $n:expr, $($message:tt)+) => {{
+        match $crate::assert_is_match_count_le_x_as_result!($matcher, $matchee, $n) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_is_match_count_le_x {
+    use regex::Regex;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = Regex::new(r"a").expect("regex");
+        let b = "alfa";
+        let n = 2;
+        let actual = assert_is_match_count_le_x!(a, b, n);
+        assert_eq!(actual, (2, 2));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = Regex::new(r"a").expect("regex");
+            let b = "alfa";
+            let n = 1;
+            let _actual = assert_is_match_count_le_x!(a, b, n);
+        });
+        let message = concat!(
+            "assertion failed: `assert_is_match_count_le_x!(matcher, matchee, n)`\n",
+            assertables_url_line!("assert_is_match_count_le_x"),
+            " matcher label: `a`,\n",
+            " matcher debug: `Regex(\"a\")`,\n",
+            " matchee label: `b`,\n",
+            " matchee debug: `\"alfa\"`,\n",
+            "       n label: `n`,\n",
+            "       n debug: `1`,\n",
+            "         count: `2`,\n",
+            "       matches: `[\"a\", \"a\"]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a matcher match count is less than or equal to an expression.
+///
+/// Pseudocode:<br>
+/// matcher.find_iter(matchee).count() ≤ n
+///
+/// This macro provides the same statements as [`assert_is_match_count_le_x`](macro.assert_is_match_count_le_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_is_match_count_le_x`](macro@crate::assert_is_match_count_le_x)
+/// * [`assert_is_match_count_le_x_as_result`](macro@crate::assert_is_match_count_le_x_as_result)
+/// * [`debug_assert_is_match_count_le_x`](macro@crate::debug_assert_is_match_count_le_x)
+///
+#[macro_export]
+macro_rules! debug_assert_is_match_count_le_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_is_match_count_le_x!($($arg)*);
+        }
+    };
+}
@@ -50,7 +50,7 @@ macro_rules! assert_not_match_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_not_match!(matcher, matchee)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_match.html\n",
+                                $crate::assertables_url_line!("assert_not_match"),
                                 " matcher label: `{}`,\n",
                                 " matcher debug: `{:?}`,\n",
                                 " matchee label: `{}`,\n",
@@ -87,7 +87,7 @@ mod test_assert_not_match_as_result {
         let actual = assert_not_match_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_not_match!(matcher, matchee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_match.html\n",
+            assertables_url_line!("assert_not_match"),
             " matcher label: `a`,\n",
             " matcher debug: `Regex(\"lf\")`,\n",
             " matchee label: `b`,\n",
@@ -134,7 +134,7 @@ mod test_assert_not_match_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_not_match!(matcher, matchee)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_match.html\n",
+/// #     assertables_url_line!("assert_not_match"),
 /// #     " matcher label: `a`,\n",
 /// #     " matcher debug: `Regex(\"lf\")`,\n",
 /// #     " matchee label: `b`,\n",
@@ -155,13 +155,13 @@ macro_rules! assert_not_match {
     ($matcher:expr, $matchee:expr $(,)?) => {{
         match $crate::assert_not_match_as_result!($matcher, $matchee) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($matcher:expr, $matchee:expr, $($message:tt)+) => {{
         match $crate::assert_not_match_as_result!($matcher, $matchee) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -188,7 +188,7 @@ mod test_assert_not_match {
         });
         let message = concat!(
             "assertion failed: `assert_not_match!(matcher, matchee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_match.html\n",
+            assertables_url_line!("assert_not_match"),
             " matcher label: `a`,\n",
             " matcher debug: `Regex(\"lf\")`,\n",
             " matchee label: `b`,\n",
@@ -239,7 +239,7 @@ mod test_assert_not_match {
 #[macro_export]
 macro_rules! debug_assert_not_match {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_not_match!($($arg)*);
         }
     };
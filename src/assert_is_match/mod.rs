@@ -6,6 +6,14 @@
 //!
 //! * [`assert_not_match!(matcher, matchee)`](macro@crate::assert_not_match) ≈ !matcher.is_match(matchee)
 //!
+//! * [`assert_is_full_match!(matcher, matchee)`](macro@crate::assert_is_full_match) ≈ matcher.find(matchee) spans all of matchee
+//!
+//! Compare a match count with an expression:
+//!
+//! * [`assert_is_match_count_eq_x!(matcher, matchee, n)`](macro@crate::assert_is_match_count_eq_x) ≈ matcher.find_iter(matchee).count() = n
+//! * [`assert_is_match_count_ge_x!(matcher, matchee, n)`](macro@crate::assert_is_match_count_ge_x) ≈ matcher.find_iter(matchee).count() ≥ n
+//! * [`assert_is_match_count_le_x!(matcher, matchee, n)`](macro@crate::assert_is_match_count_le_x) ≈ matcher.find_iter(matchee).count() ≤ n
+//!
 //! # Example
 //!
 //! ```rust
@@ -16,6 +24,26 @@
 //! let b = "alfa";
 //! assert_is_match!(a, b);
 //! ```
+//!
+//! These macros do not depend on the `regex` crate, nor does Assertables.
+//! A matcher can be a `regex::Regex` from your own dependencies, or any other
+//! type with the method, so there is no dependency to turn off. The same is
+//! true for the command, program, and reader macros that end in `is_match`.
 
+pub mod assert_is_full_match;
 pub mod assert_is_match;
+pub mod assert_is_match_count_eq_x;
+pub mod assert_is_match_count_ge_x;
+pub mod assert_is_match_count_le_x;
 pub mod assert_not_match;
+
+/// Parse a pattern as a matcher of the same type as another matcher.
+///
+/// The macro [`assert_is_full_match`](macro@crate::assert_is_full_match)
+/// calls this with the matcher's pattern wrapped in `\A(?:…)\z`, so the new
+/// matcher is anchored to the entire matchee. This works for any matcher
+/// that implements `FromStr`, such as `regex::Regex`.
+#[doc(hidden)]
+pub fn parse_like<T: ::std::str::FromStr>(_like: &T, pattern: &str) -> Result<T, T::Err> {
+    pattern.parse()
+}
@@ -0,0 +1,274 @@
+//! Assert an iter contains an item.
+//!
+//! Pseudocode:<br>
+//! (collection into iter) contains item
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3];
+//! assert_iter_contains!(&a, 2);
+//! ```
+//!
+//! This macro iterates the collection by reference, so the collection is not moved,
+//! and stops at the first element that is equal to the item.
+//!
+//! * On success, the return value is the matched element, as yielded by the iterator.
+//!
+//! * The failure message shows how many elements were scanned.
+//!
+//! This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+//!
+//! # Module macros
+//!
+//! * [`assert_iter_contains`](macro@crate::assert_iter_contains)
+//! * [`assert_iter_contains_as_result`](macro@crate::assert_iter_contains_as_result)
+//! * [`debug_assert_iter_contains`](macro@crate::debug_assert_iter_contains)
+
+/// Assert an iter contains an item.
+///
+/// Pseudocode:<br>
+/// (collection into iter) contains item
+///
+/// * If true, return Result `Ok(matched_item)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_iter_contains`](macro@crate::assert_iter_contains)
+/// * [`assert_iter_contains_as_result`](macro@crate::assert_iter_contains_as_result)
+/// * [`debug_assert_iter_contains`](macro@crate::debug_assert_iter_contains)
+///
+#[macro_export]
+macro_rules! assert_iter_contains_as_result {
+    ($collection:expr, $item:expr $(,)?) => {{
+        match (&$collection, &$item) {
+            (collection, item) => {
+                let mut scanned: usize = 0;
+                let mut found = None;
+                for x in collection.into_iter() {
+                    scanned += 1;
+                    if x == item {
+                        found = Some(x);
+                        break;
+                    }
+                }
+                match found {
+                    Some(x) => Ok(x),
+                    None => Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_iter_contains!(collection, item)`\n",
+                                $crate::assertables_url_line!("assert_iter_contains"),
+                                " collection label: `{}`,\n",
+                                " collection debug: `{:?}`,\n",
+                                "       item label: `{}`,\n",
+                                "       item debug: `{:?}`,\n",
+                                "    scanned count: `{}`"
+                            ),
+                            stringify!($collection),
+                            collection,
+                            stringify!($item),
+                            item,
+                            scanned
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_contains_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let actual = assert_iter_contains_as_result!(&a, 2);
+        assert_eq!(actual.unwrap(), &2);
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 2, 3];
+        let actual = assert_iter_contains_as_result!(&a, 4);
+        let message = concat!(
+            "assertion failed: `assert_iter_contains!(collection, item)`\n",
+            assertables_url_line!("assert_iter_contains"),
+            " collection label: `&a`,\n",
+            " collection debug: `[1, 2, 3]`,\n",
+            "       item label: `4`,\n",
+            "       item debug: `4`,\n",
+            "    scanned count: `3`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_vec_of_strings() {
+        let a = vec![String::from("alfa"), String::from("bravo")];
+        let item = String::from("bravo");
+        let actual = assert_iter_contains_as_result!(&a, item);
+        assert_eq!(actual.unwrap(), "bravo");
+    }
+}
+
+/// Assert an iter contains an item.
+///
+/// Pseudocode:<br>
+/// (collection into iter) contains item
+///
+/// * If true, return `matched_item`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// assert_iter_contains!(&a, 2);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3];
+/// assert_iter_contains!(&a, 4);
+/// # });
+/// // assertion failed: `assert_iter_contains!(collection, item)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_contains.html
+/// //  collection label: `&a`,
+/// //  collection debug: `[1, 2, 3]`,
+/// //        item label: `4`,
+/// //        item debug: `4`,
+/// //     scanned count: `3`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_iter_contains!(collection, item)`\n",
+/// #     assertables_url_line!("assert_iter_contains"),
+/// #     " collection label: `&a`,\n",
+/// #     " collection debug: `[1, 2, 3]`,\n",
+/// #     "       item label: `4`,\n",
+/// #     "       item debug: `4`,\n",
+/// #     "    scanned count: `3`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro iterates the collection by reference, so the collection is not moved,
+/// and stops at the first element that is equal to the item.
+///
+/// * On success, the return value is the matched element, as yielded by the iterator.
+///
+/// * The failure message shows how many elements were scanned.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_iter_contains`](macro@crate::assert_iter_contains)
+/// * [`assert_iter_contains_as_result`](macro@crate::assert_iter_contains_as_result)
+/// * [`debug_assert_iter_contains`](macro@crate::debug_assert_iter_contains)
+///
+#[macro_export]
+macro_rules! assert_iter_contains {
+    ($collection:expr, $item:expr $(,)?) => {{
+        match $crate::assert_iter_contains_as_result!($collection, $item) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($collection:expr, $item:expr, $($message:tt)+) => {{
+        match $crate::assert_iter_contains_as_result!($collection, $item) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_contains {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let actual = assert_iter_contains!(&a, 2);
+        assert_eq!(actual, &2);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 2, 3];
+            let _actual = assert_iter_contains!(&a, 4);
+        });
+        let message = concat!(
+            "assertion failed: `assert_iter_contains!(collection, item)`\n",
+            assertables_url_line!("assert_iter_contains"),
+            " collection label: `&a`,\n",
+            " collection debug: `[1, 2, 3]`,\n",
+            "       item label: `4`,\n",
+            "       item debug: `4`,\n",
+            "    scanned count: `3`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an iter contains an item.
+///
+/// Pseudocode:<br>
+/// (collection into iter) contains item
+///
+/// This macro provides the same statements as [`assert_iter_contains`](macro.assert_iter_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_iter_contains`](macro@crate::assert_iter_contains)
+/// * [`assert_iter_contains_as_result`](macro@crate::assert_iter_contains_as_result)
+/// * [`debug_assert_iter_contains`](macro@crate::debug_assert_iter_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_iter_contains {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_iter_contains!($($arg)*);
+        }
+    };
+}
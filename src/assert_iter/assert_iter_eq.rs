@@ -55,7 +55,7 @@ macro_rules! assert_iter_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_iter_eq!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_eq.html\n",
+                                $crate::assertables_url_line!("assert_iter_eq"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -91,7 +91,7 @@ mod test_assert_iter_eq_as_result {
         let actual = assert_iter_eq_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_iter_eq!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_eq.html\n",
+            assertables_url_line!("assert_iter_eq"),
             " a label: `&a`,\n",
             " a debug: `[1, 2]`,\n",
             " b label: `&b`,\n",
@@ -137,7 +137,7 @@ mod test_assert_iter_eq_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_iter_eq!(a_collection, b_collection)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_eq.html\n",
+/// #     assertables_url_line!("assert_iter_eq"),
 /// #     " a label: `&a`,\n",
 /// #     " a debug: `[1, 2]`,\n",
 /// #     " b label: `&b`,\n",
@@ -160,13 +160,13 @@ macro_rules! assert_iter_eq {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match $crate::assert_iter_eq_as_result!($a_collection, $b_collection) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
         match $crate::assert_iter_eq_as_result!($a_collection, $b_collection) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -192,7 +192,7 @@ mod test_assert_iter_eq {
         });
         let message = concat!(
             "assertion failed: `assert_iter_eq!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_eq.html\n",
+            assertables_url_line!("assert_iter_eq"),
             " a label: `&a`,\n",
             " a debug: `[1, 2]`,\n",
             " b label: `&b`,\n",
@@ -243,7 +243,7 @@ mod test_assert_iter_eq {
 #[macro_export]
 macro_rules! debug_assert_iter_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_iter_eq!($($arg)*);
         }
     };
@@ -0,0 +1,354 @@
+//! Assert an iter is equal to another, and report all the differences.
+//!
+//! Pseudocode:<br>
+//! (collection1 into iter) = (collection2 into iter)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3];
+//! let b = [1, 2, 3];
+//! assert_iter_eq_report_all!(&a, &b);
+//! ```
+//!
+//! This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+//!
+//! This macro is like [`assert_iter_eq`](macro@crate::assert_iter_eq), except the failure message
+//! lists the differing indexes, so you can fix many small differences at once.
+//!
+//! * The message lists up to [`REPORT_ALL_LIMIT`](crate::assert_iter::REPORT_ALL_LIMIT)
+//!   differences. After that many, the macro stops comparing, the diff count
+//!   ends with `+`, and the diffs end with `… more`.
+//!
+//! * Each difference is a tuple `(index, a item, b item)`.
+//!
+//! * Each item is an `Option`, because when one iter is longer than the other,
+//!   then the shorter iter has no item at that index, which is shown as `None`.
+//!
+//! # Module macros
+//!
+//! * [`assert_iter_eq_report_all`](macro@crate::assert_iter_eq_report_all)
+//! * [`assert_iter_eq_report_all_as_result`](macro@crate::assert_iter_eq_report_all_as_result)
+//! * [`debug_assert_iter_eq_report_all`](macro@crate::debug_assert_iter_eq_report_all)
+
+/// Assert an iter is equal to another, and report all the differences.
+///
+/// Pseudocode:<br>
+/// (collection1 into iter) = (collection2 into iter)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_iter_eq_report_all`](macro@crate::assert_iter_eq_report_all)
+/// * [`assert_iter_eq_report_all_as_result`](macro@crate::assert_iter_eq_report_all_as_result)
+/// * [`debug_assert_iter_eq_report_all`](macro@crate::debug_assert_iter_eq_report_all)
+///
+#[macro_export]
+macro_rules! assert_iter_eq_report_all_as_result {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        match (&$a_collection, &$b_collection) {
+            (a_collection, b_collection) => {
+                let mut a = a_collection.into_iter();
+                let mut b = b_collection.into_iter();
+                let mut diffs = Vec::new();
+                let mut more = false;
+                let mut i: usize = 0;
+                loop {
+                    match (a.next(), b.next()) {
+                        (None, None) => break,
+                        (a_item, b_item) => {
+                            if a_item != b_item {
+                                if diffs.len() == $crate::assert_iter::REPORT_ALL_LIMIT {
+                                    more = true;
+                                    break;
+                                }
+                                diffs.push((i, a_item, b_item));
+                            }
+                        }
+                    }
+                    i += 1;
+                }
+                if diffs.is_empty() {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_iter_eq_report_all!(a_collection, b_collection)`\n",
+                                $crate::assertables_url_line!("assert_iter_eq_report_all"),
+                                "    a label: `{}`,\n",
+                                "    a debug: `{:?}`,\n",
+                                "    b label: `{}`,\n",
+                                "    b debug: `{:?}`,\n",
+                                " diff count: `{}{}`,\n",
+                                "      diffs: `{:?}{}`"
+                            ),
+                            stringify!($a_collection),
+                            a_collection,
+                            stringify!($b_collection),
+                            b_collection,
+                            diffs.len(),
+                            if more { "+" } else { "" },
+                            diffs,
+                            if more { " … more" } else { "" }
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_eq_report_all_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let b = [1, 2, 3];
+        let actual = assert_iter_eq_report_all_as_result!(&a, &b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 2, 3];
+        let b = [1, 4, 5, 6];
+        let actual = assert_iter_eq_report_all_as_result!(&a, &b);
+        let message = concat!(
+            "assertion failed: `assert_iter_eq_report_all!(a_collection, b_collection)`\n",
+            assertables_url_line!("assert_iter_eq_report_all"),
+            "    a label: `&a`,\n",
+            "    a debug: `[1, 2, 3]`,\n",
+            "    b label: `&b`,\n",
+            "    b debug: `[1, 4, 5, 6]`,\n",
+            " diff count: `3`,\n",
+            "      diffs: `[(1, Some(2), Some(4)), (2, Some(3), Some(5)), (3, None, Some(6))]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_empty() {
+        let a: [i8; 0] = [];
+        let b: [i8; 0] = [];
+        let actual = assert_iter_eq_report_all_as_result!(&a, &b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_a_longer_than_b() {
+        let a = [1, 2, 3];
+        let b = [1];
+        let actual = assert_iter_eq_report_all_as_result!(&a, &b);
+        let message = actual.unwrap_err();
+        assert!(message.contains(
+            " diff count: `2`,\n      diffs: `[(1, Some(2), None), (2, Some(3), None)]`"
+        ));
+    }
+
+    #[test]
+    fn failure_with_more_differences_than_the_limit() {
+        let a = [0; 100];
+        let b = [1; 100];
+        let actual = assert_iter_eq_report_all_as_result!(&a, &b);
+        let message = actual.unwrap_err();
+        assert!(message.contains(concat!(
+            " diff count: `10+`,\n",
+            "      diffs: `[(0, Some(0), Some(1)), (1, Some(0), Some(1)), (2, Some(0), Some(1)), ",
+            "(3, Some(0), Some(1)), (4, Some(0), Some(1)), (5, Some(0), Some(1)), ",
+            "(6, Some(0), Some(1)), (7, Some(0), Some(1)), (8, Some(0), Some(1)), ",
+            "(9, Some(0), Some(1))] … more`"
+        )));
+    }
+
+    #[test]
+    fn failure_stops_at_the_limit() {
+        #[derive(Debug)]
+        struct Endless;
+        impl<'a> IntoIterator for &'a Endless {
+            type Item = &'a u8;
+            type IntoIter = std::iter::Repeat<&'a u8>;
+            fn into_iter(self) -> Self::IntoIter {
+                std::iter::repeat(&0)
+            }
+        }
+        let a = Endless;
+        let b = [1u8; 20];
+        let actual = assert_iter_eq_report_all_as_result!(&a, &b);
+        assert!(actual.unwrap_err().ends_with("] … more`"));
+    }
+}
+
+/// Assert an iter is equal to another, and report all the differences.
+///
+/// Pseudocode:<br>
+/// (collection1 into iter) = (collection2 into iter)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// let b = [1, 2, 3];
+/// assert_iter_eq_report_all!(&a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3];
+/// let b = [1, 4, 5, 6];
+/// assert_iter_eq_report_all!(&a, &b);
+/// # });
+/// // assertion failed: `assert_iter_eq_report_all!(a_collection, b_collection)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_eq_report_all.html
+/// //     a label: `&a`,
+/// //     a debug: `[1, 2, 3]`,
+/// //     b label: `&b`,
+/// //     b debug: `[1, 4, 5, 6]`,
+/// //  diff count: `3`,
+/// //       diffs: `[(1, Some(2), Some(4)), (2, Some(3), Some(5)), (3, None, Some(6))]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_iter_eq_report_all!(a_collection, b_collection)`\n",
+/// #     assertables_url_line!("assert_iter_eq_report_all"),
+/// #     "    a label: `&a`,\n",
+/// #     "    a debug: `[1, 2, 3]`,\n",
+/// #     "    b label: `&b`,\n",
+/// #     "    b debug: `[1, 4, 5, 6]`,\n",
+/// #     " diff count: `3`,\n",
+/// #     "      diffs: `[(1, Some(2), Some(4)), (2, Some(3), Some(5)), (3, None, Some(6))]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// This macro is like [`assert_iter_eq`](macro@crate::assert_iter_eq), except the failure message
+/// lists the differing indexes, so you can fix many small differences at once.
+///
+/// * The message lists up to [`REPORT_ALL_LIMIT`](crate::assert_iter::REPORT_ALL_LIMIT)
+///   differences. After that many, the macro stops comparing, the diff count
+///   ends with `+`, and the diffs end with `… more`.
+///
+/// * Each difference is a tuple `(index, a item, b item)`.
+///
+/// * Each item is an `Option`, because when one iter is longer than the other,
+///   then the shorter iter has no item at that index, which is shown as `None`.
+///
+/// # Module macros
+///
+/// * [`assert_iter_eq_report_all`](macro@crate::assert_iter_eq_report_all)
+/// * [`assert_iter_eq_report_all_as_result`](macro@crate::assert_iter_eq_report_all_as_result)
+/// * [`debug_assert_iter_eq_report_all`](macro@crate::debug_assert_iter_eq_report_all)
+///
+#[macro_export]
+macro_rules! assert_iter_eq_report_all {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        match $crate::assert_iter_eq_report_all_as_result!($a_collection, $b_collection) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
+        match $crate::assert_iter_eq_report_all_as_result!($a_collection, $b_collection) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_eq_report_all {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let b = [1, 2, 3];
+        let actual = assert_iter_eq_report_all!(&a, &b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 2, 3];
+            let b = [1, 4, 5, 6];
+            let _actual = assert_iter_eq_report_all!(&a, &b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_iter_eq_report_all!(a_collection, b_collection)`\n",
+            assertables_url_line!("assert_iter_eq_report_all"),
+            "    a label: `&a`,\n",
+            "    a debug: `[1, 2, 3]`,\n",
+            "    b label: `&b`,\n",
+            "    b debug: `[1, 4, 5, 6]`,\n",
+            " diff count: `3`,\n",
+            "      diffs: `[(1, Some(2), Some(4)), (2, Some(3), Some(5)), (3, None, Some(6))]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an iter is equal to another, and report all the differences.
+///
+/// Pseudocode:<br>
+/// (collection1 into iter) = (collection2 into iter)
+///
+/// This macro provides the same statements as [`assert_iter_eq_report_all`](macro.assert_iter_eq_report_all.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_iter_eq_report_all`](macro@crate::assert_iter_eq_report_all)
+/// * [`assert_iter_eq_report_all_as_result`](macro@crate::assert_iter_eq_report_all_as_result)
+/// * [`debug_assert_iter_eq_report_all`](macro@crate::debug_assert_iter_eq_report_all)
+///
+#[macro_export]
+macro_rules! debug_assert_iter_eq_report_all {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_iter_eq_report_all!($($arg)*);
+        }
+    };
+}
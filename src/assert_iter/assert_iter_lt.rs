@@ -55,7 +55,7 @@ macro_rules! assert_iter_lt_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_iter_lt!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_lt.html\n",
+                                $crate::assertables_url_line!("assert_iter_lt"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -91,7 +91,7 @@ mod test_assert_iter_lt_as_result {
         let actual = assert_iter_lt_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_iter_lt!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_lt.html\n",
+            assertables_url_line!("assert_iter_lt"),
             " a label: `&a`,\n",
             " a debug: `[1, 2]`,\n",
             " b label: `&b`,\n",
@@ -107,7 +107,7 @@ mod test_assert_iter_lt_as_result {
         let actual = assert_iter_lt_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_iter_lt!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_lt.html\n",
+            assertables_url_line!("assert_iter_lt"),
             " a label: `&a`,\n",
             " a debug: `[3, 4]`,\n",
             " b label: `&b`,\n",
@@ -153,7 +153,7 @@ mod test_assert_iter_lt_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_iter_lt!(a_collection, b_collection)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_lt.html\n",
+/// #     assertables_url_line!("assert_iter_lt"),
 /// #     " a label: `&a`,\n",
 /// #     " a debug: `[3, 4]`,\n",
 /// #     " b label: `&b`,\n",
@@ -176,13 +176,13 @@ macro_rules! assert_iter_lt {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match $crate::assert_iter_lt_as_result!($a_collection, $b_collection) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
         match $crate::assert_iter_lt_as_result!($a_collection, $b_collection) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -208,7 +208,7 @@ mod test_assert_iter_lt {
         });
         let message = concat!(
             "assertion failed: `assert_iter_lt!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_lt.html\n",
+            assertables_url_line!("assert_iter_lt"),
             " a label: `&a`,\n",
             " a debug: `[1, 2]`,\n",
             " b label: `&b`,\n",
@@ -233,7 +233,7 @@ mod test_assert_iter_lt {
         });
         let message = concat!(
             "assertion failed: `assert_iter_lt!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_lt.html\n",
+            assertables_url_line!("assert_iter_lt"),
             " a label: `&a`,\n",
             " a debug: `[3, 4]`,\n",
             " b label: `&b`,\n",
@@ -284,7 +284,7 @@ mod test_assert_iter_lt {
 #[macro_export]
 macro_rules! debug_assert_iter_lt {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_iter_lt!($($arg)*);
         }
     };
@@ -0,0 +1,419 @@
+//! Assert an iter yields items with keys in non-decreasing order.
+//!
+//! Pseudocode:<br>
+//! (collection into iter) ∀ adjacent (a, b) ⇒ key(a) ≤ key(b)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! #[derive(Debug)]
+//! struct Item { id: i32, name: &'static str }
+//! let a = [Item { id: 1, name: "alfa" }, Item { id: 1, name: "bravo" }, Item { id: 2, name: "charlie" }];
+//! assert_iter_sorted_by_key!(a, |item| item.id);
+//! ```
+//!
+//! This implementation uses [`::std::cmp::PartialOrd`](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+//! to compare the keys of each pair of adjacent items, which must be `Less` or `Equal`.
+//!
+//! * The collection is iterated by reference, so the collection is not moved.
+//!
+//! * The key function is called once per item, with a reference to the item.
+//!
+//! * An empty collection, or a collection with one item, is sorted.
+//!
+//! * Keys that are not comparable, such as a float NaN, are out of order.
+//!
+//! * The failure message shows the first pair of adjacent items that are out of order, and their keys.
+//!
+//! # Module macros
+//!
+//! * [`assert_iter_sorted_by_key`](macro@crate::assert_iter_sorted_by_key)
+//! * [`assert_iter_sorted_by_key_as_result`](macro@crate::assert_iter_sorted_by_key_as_result)
+//! * [`debug_assert_iter_sorted_by_key`](macro@crate::debug_assert_iter_sorted_by_key)
+
+/// Assert an iter yields items with keys in non-decreasing order.
+///
+/// Pseudocode:<br>
+/// (collection into iter) ∀ adjacent (a, b) ⇒ key(a) ≤ key(b)
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_iter_sorted_by_key`](macro@crate::assert_iter_sorted_by_key)
+/// * [`assert_iter_sorted_by_key_as_result`](macro@crate::assert_iter_sorted_by_key_as_result)
+/// * [`debug_assert_iter_sorted_by_key`](macro@crate::debug_assert_iter_sorted_by_key)
+///
+#[macro_export]
+macro_rules! assert_iter_sorted_by_key_as_result {
+    ($collection:expr, $key:expr $(,)?) => {{
+        match (&$collection) {
+            collection => match $crate::assert_iter::unsorted_by_key(collection, $key, false) {
+                None => Ok(()),
+                Some(unsorted) => Err(format!(
+                    concat!(
+                        "assertion failed: `assert_iter_sorted_by_key!(collection, key)`\n",
+                        $crate::assertables_url_line!("assert_iter_sorted_by_key"),
+                        " collection label: `{}`,\n",
+                        " collection debug: `{:?}`,\n",
+                        "        key label: `{}`,\n",
+                        "       left index: `{}`,\n",
+                        "       left debug: `{:?}`,\n",
+                        "         left key: `{:?}`,\n",
+                        "      right index: `{}`,\n",
+                        "      right debug: `{:?}`,\n",
+                        "        right key: `{:?}`"
+                    ),
+                    stringify!($collection),
+                    collection,
+                    stringify!($key),
+                    unsorted.index,
+                    unsorted.left,
+                    unsorted.left_key,
+                    unsorted.index + 1,
+                    unsorted.right,
+                    unsorted.right_key
+                )),
+            },
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_sorted_by_key_as_result {
+    #[derive(Debug)]
+    struct Item {
+        id: i32,
+        name: &'static str,
+    }
+
+    #[test]
+    fn success() {
+        let a = [
+            Item {
+                id: 1,
+                name: "alfa",
+            },
+            Item {
+                id: 1,
+                name: "bravo",
+            },
+            Item {
+                id: 2,
+                name: "charlie",
+            },
+        ];
+        let actual = assert_iter_sorted_by_key_as_result!(a, |item| item.id);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = [
+            Item {
+                id: 2,
+                name: "alfa",
+            },
+            Item {
+                id: 1,
+                name: "bravo",
+            },
+        ];
+        let actual = assert_iter_sorted_by_key_as_result!(a, |item| item.id);
+        let message = concat!(
+            "assertion failed: `assert_iter_sorted_by_key!(collection, key)`\n",
+            assertables_url_line!("assert_iter_sorted_by_key"),
+            " collection label: `a`,\n",
+            " collection debug: `[Item { id: 2, name: \"alfa\" }, Item { id: 1, name: \"bravo\" }]`,\n",
+            "        key label: `|item| item.id`,\n",
+            "       left index: `0`,\n",
+            "       left debug: `Item { id: 2, name: \"alfa\" }`,\n",
+            "         left key: `2`,\n",
+            "      right index: `1`,\n",
+            "      right debug: `Item { id: 1, name: \"bravo\" }`,\n",
+            "        right key: `1`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_empty() {
+        let a: [Item; 0] = [];
+        let actual = assert_iter_sorted_by_key_as_result!(a, |item| item.id);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_key_by_reference() {
+        let a = vec![
+            Item {
+                id: 2,
+                name: "alfa",
+            },
+            Item {
+                id: 1,
+                name: "bravo",
+            },
+        ];
+        let actual = assert_iter_sorted_by_key_as_result!(a, |item| item.name);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_float_key_nan() {
+        let a = [1.0, f64::NAN];
+        let actual = assert_iter_sorted_by_key_as_result!(a, |x| *x);
+        assert!(actual.unwrap_err().ends_with(" right key: `NaN`"));
+    }
+
+    #[test]
+    fn calls_key_once_per_item() {
+        let a = [
+            Item {
+                id: 1,
+                name: "alfa",
+            },
+            Item {
+                id: 2,
+                name: "bravo",
+            },
+            Item {
+                id: 3,
+                name: "charlie",
+            },
+        ];
+        let mut count = 0;
+        let actual = assert_iter_sorted_by_key_as_result!(a, |item| {
+            count += 1;
+            item.id
+        });
+        assert_eq!(actual.unwrap(), ());
+        assert_eq!(count, 3);
+    }
+}
+
+/// Assert an iter yields items with keys in non-decreasing order.
+///
+/// Pseudocode:<br>
+/// (collection into iter) ∀ adjacent (a, b) ⇒ key(a) ≤ key(b)
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// #[derive(Debug)]
+/// struct Item { id: i32, name: &'static str }
+/// let a = [Item { id: 1, name: "alfa" }, Item { id: 1, name: "bravo" }, Item { id: 2, name: "charlie" }];
+/// assert_iter_sorted_by_key!(a, |item| item.id);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// #[derive(Debug)]
+/// struct Item { id: i32, name: &'static str }
+/// let a = [Item { id: 2, name: "alfa" }, Item { id: 1, name: "bravo" }];
+/// assert_iter_sorted_by_key!(a, |item| item.id);
+/// # });
+/// // assertion failed: `assert_iter_sorted_by_key!(collection, key)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_sorted_by_key.html
+/// //  collection label: `a`,
+/// //  collection debug: `[Item { id: 2, name: \"alfa\" }, Item { id: 1, name: \"bravo\" }]`,
+/// //         key label: `|item| item.id`,
+/// //        left index: `0`,
+/// //        left debug: `Item { id: 2, name: \"alfa\" }`,
+/// //          left key: `2`,
+/// //       right index: `1`,
+/// //       right debug: `Item { id: 1, name: \"bravo\" }`,
+/// //         right key: `1`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_iter_sorted_by_key!(collection, key)`\n",
+/// #     assertables_url_line!("assert_iter_sorted_by_key"),
+/// #     " collection label: `a`,\n",
+/// #     " collection debug: `[Item { id: 2, name: \"alfa\" }, Item { id: 1, name: \"bravo\" }]`,\n",
+/// #     "        key label: `|item| item.id`,\n",
+/// #     "       left index: `0`,\n",
+/// #     "       left debug: `Item { id: 2, name: \"alfa\" }`,\n",
+/// #     "         left key: `2`,\n",
+/// #     "      right index: `1`,\n",
+/// #     "      right debug: `Item { id: 1, name: \"bravo\" }`,\n",
+/// #     "        right key: `1`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This implementation uses [`::std::cmp::PartialOrd`](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+/// to compare the keys of each pair of adjacent items, which must be `Less` or `Equal`.
+///
+/// * The collection is iterated by reference, so the collection is not moved.
+///
+/// * The key function is called once per item, with a reference to the item.
+///
+/// * An empty collection, or a collection with one item, is sorted.
+///
+/// * Keys that are not comparable, such as a float NaN, are out of order.
+///
+/// * The failure message shows the first pair of adjacent items that are out of order, and their keys.
+///
+/// # Module macros
+///
+/// * [`assert_iter_sorted_by_key`](macro@crate::assert_iter_sorted_by_key)
+/// * [`assert_iter_sorted_by_key_as_result`](macro@crate::assert_iter_sorted_by_key_as_result)
+/// * [`debug_assert_iter_sorted_by_key`](macro@crate::debug_assert_iter_sorted_by_key)
+///
+#[macro_export]
+macro_rules! assert_iter_sorted_by_key {
+    ($collection:expr, $key:expr $(,)?) => {{
+        match $crate::assert_iter_sorted_by_key_as_result!($collection, $key) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($collection:expr, $key:expr, $($message:tt)+) => {{
+        match $crate::assert_iter_sorted_by_key_as_result!($collection, $key) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_sorted_by_key {
+    use std::panic;
+    #[derive(Debug)]
+    struct Item {
+        id: i32,
+        name: &'static str,
+    }
+
+    #[test]
+    fn success() {
+        let a = [
+            Item {
+                id: 1,
+                name: "alfa",
+            },
+            Item {
+                id: 1,
+                name: "bravo",
+            },
+            Item {
+                id: 2,
+                name: "charlie",
+            },
+        ];
+        let actual = assert_iter_sorted_by_key!(a, |item| item.id);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [
+                Item {
+                    id: 2,
+                    name: "alfa",
+                },
+                Item {
+                    id: 1,
+                    name: "bravo",
+                },
+            ];
+            let _actual = assert_iter_sorted_by_key!(a, |item| item.id);
+        });
+        let message = concat!(
+            "assertion failed: `assert_iter_sorted_by_key!(collection, key)`\n",
+            assertables_url_line!("assert_iter_sorted_by_key"),
+            " collection label: `a`,\n",
+            " collection debug: `[Item { id: 2, name: \"alfa\" }, Item { id: 1, name: \"bravo\" }]`,\n",
+            "        key label: `|item| item.id`,\n",
+            "       left index: `0`,\n",
+            "       left debug: `Item { id: 2, name: \"alfa\" }`,\n",
+            "         left key: `2`,\n",
+            "      right index: `1`,\n",
+            "      right debug: `Item { id: 1, name: \"bravo\" }`,\n",
+            "        right key: `1`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+
+    #[test]
+    fn failure_with_name_key() {
+        let result = panic::catch_unwind(|| {
+            let a = [
+                Item {
+                    id: 1,
+                    name: "bravo",
+                },
+                Item {
+                    id: 2,
+                    name: "alfa",
+                },
+            ];
+            let _actual = assert_iter_sorted_by_key!(a, |item| item.name);
+        });
+        assert!(result.is_err());
+    }
+}
+
+/// Assert an iter yields items with keys in non-decreasing order.
+///
+/// Pseudocode:<br>
+/// (collection into iter) ∀ adjacent (a, b) ⇒ key(a) ≤ key(b)
+///
+/// This macro provides the same statements as [`assert_iter_sorted_by_key`](macro.assert_iter_sorted_by_key.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_iter_sorted_by_key`](macro@crate::assert_iter_sorted_by_key)
+/// * [`assert_iter_sorted_by_key_as_result`](macro@crate::assert_iter_sorted_by_key_as_result)
+/// * [`debug_assert_iter_sorted_by_key`](macro@crate::debug_assert_iter_sorted_by_key)
+///
+#[macro_export]
+macro_rules! debug_assert_iter_sorted_by_key {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_iter_sorted_by_key!($($arg)*);
+        }
+    };
+}
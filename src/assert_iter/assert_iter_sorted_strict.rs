@@ -0,0 +1,296 @@
+//! Assert an iter yields items in strictly increasing order.
+//!
+//! Pseudocode:<br>
+//! (collection into iter) ∀ adjacent (a, b) ⇒ a < b
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3];
+//! assert_iter_sorted_strict!(a);
+//! ```
+//!
+//! This implementation uses [`::std::cmp::PartialOrd`](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+//! to compare each pair of adjacent items, which must be `Less`.
+//!
+//! * The collection is iterated by reference, so the collection is not moved.
+//!
+//! * An empty collection, or a collection with one item, is sorted.
+//!
+//! * Items that are not comparable, such as a float NaN, are out of order.
+//!
+//! * The failure message shows the first pair of adjacent items that are out of order.
+//!
+//! # Module macros
+//!
+//! * [`assert_iter_sorted_strict`](macro@crate::assert_iter_sorted_strict)
+//! * [`assert_iter_sorted_strict_as_result`](macro@crate::assert_iter_sorted_strict_as_result)
+//! * [`debug_assert_iter_sorted_strict`](macro@crate::debug_assert_iter_sorted_strict)
+
+/// Assert an iter yields items in strictly increasing order.
+///
+/// Pseudocode:<br>
+/// (collection into iter) ∀ adjacent (a, b) ⇒ a < b
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_iter_sorted_strict`](macro@crate::assert_iter_sorted_strict)
+/// * [`assert_iter_sorted_strict_as_result`](macro@crate::assert_iter_sorted_strict_as_result)
+/// * [`debug_assert_iter_sorted_strict`](macro@crate::debug_assert_iter_sorted_strict)
+///
+#[macro_export]
+macro_rules! assert_iter_sorted_strict_as_result {
+    ($collection:expr $(,)?) => {{
+        match (&$collection) {
+            collection => {
+                match $crate::assert_monotonic::monotonic_violation(
+                    collection,
+                    ::std::cmp::Ordering::Less,
+                    true,
+                ) {
+                    None => Ok(()),
+                    Some((i, left, right)) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_iter_sorted_strict!(collection)`\n",
+                                    $crate::assertables_url_line!("assert_iter_sorted_strict"),
+                                    " collection label: `{}`,\n",
+                                    " collection debug: `{:?}`,\n",
+                                    "       left index: `{}`,\n",
+                                    "       left debug: `{:?}`,\n",
+                                    "      right index: `{}`,\n",
+                                    "      right debug: `{:?}`"
+                                ),
+                                stringify!($collection),
+                                collection,
+                                i,
+                                left,
+                                i + 1,
+                                right
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_sorted_strict_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let actual = assert_iter_sorted_strict_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 2, 2, 3];
+        let actual = assert_iter_sorted_strict_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_iter_sorted_strict!(collection)`\n",
+            assertables_url_line!("assert_iter_sorted_strict"),
+            " collection label: `a`,\n",
+            " collection debug: `[1, 2, 2, 3]`,\n",
+            "       left index: `1`,\n",
+            "       left debug: `2`,\n",
+            "      right index: `2`,\n",
+            "      right debug: `2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_empty() {
+        let a: [i8; 0] = [];
+        let actual = assert_iter_sorted_strict_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_one() {
+        let a = [1];
+        let actual = assert_iter_sorted_strict_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_because_nan() {
+        let a = [1.0, f64::NAN, 2.0];
+        let actual = assert_iter_sorted_strict_as_result!(a);
+        assert!(actual
+            .unwrap_err()
+            .contains(" left debug: `1.0`,\n      right index: `1`,\n      right debug: `NaN`"));
+    }
+}
+
+/// Assert an iter yields items in strictly increasing order.
+///
+/// Pseudocode:<br>
+/// (collection into iter) ∀ adjacent (a, b) ⇒ a < b
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// assert_iter_sorted_strict!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 2, 3];
+/// assert_iter_sorted_strict!(a);
+/// # });
+/// // assertion failed: `assert_iter_sorted_strict!(collection)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_sorted_strict.html
+/// //  collection label: `a`,
+/// //  collection debug: `[1, 2, 2, 3]`,
+/// //        left index: `1`,
+/// //        left debug: `2`,
+/// //       right index: `2`,
+/// //       right debug: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_iter_sorted_strict!(collection)`\n",
+/// #     assertables_url_line!("assert_iter_sorted_strict"),
+/// #     " collection label: `a`,\n",
+/// #     " collection debug: `[1, 2, 2, 3]`,\n",
+/// #     "       left index: `1`,\n",
+/// #     "       left debug: `2`,\n",
+/// #     "      right index: `2`,\n",
+/// #     "      right debug: `2`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This implementation uses [`::std::cmp::PartialOrd`](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html)
+/// to compare each pair of adjacent items, which must be `Less`.
+///
+/// * The collection is iterated by reference, so the collection is not moved.
+///
+/// * An empty collection, or a collection with one item, is sorted.
+///
+/// * Items that are not comparable, such as a float NaN, are out of order.
+///
+/// * The failure message shows the first pair of adjacent items that are out of order.
+///
+/// # Module macros
+///
+/// * [`assert_iter_sorted_strict`](macro@crate::assert_iter_sorted_strict)
+/// * [`assert_iter_sorted_strict_as_result`](macro@crate::assert_iter_sorted_strict_as_result)
+/// * [`debug_assert_iter_sorted_strict`](macro@crate::debug_assert_iter_sorted_strict)
+///
+#[macro_export]
+macro_rules! assert_iter_sorted_strict {
+    ($collection:expr $(,)?) => {{
+        match $crate::assert_iter_sorted_strict_as_result!($collection) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($collection:expr, $($message:tt)+) => {{
+        match $crate::assert_iter_sorted_strict_as_result!($collection) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_sorted_strict {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let actual = assert_iter_sorted_strict!(a);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 2, 2, 3];
+            let _actual = assert_iter_sorted_strict!(a);
+        });
+        let message = concat!(
+            "assertion failed: `assert_iter_sorted_strict!(collection)`\n",
+            assertables_url_line!("assert_iter_sorted_strict"),
+            " collection label: `a`,\n",
+            " collection debug: `[1, 2, 2, 3]`,\n",
+            "       left index: `1`,\n",
+            "       left debug: `2`,\n",
+            "      right index: `2`,\n",
+            "      right debug: `2`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an iter yields items in strictly increasing order.
+///
+/// Pseudocode:<br>
+/// (collection into iter) ∀ adjacent (a, b) ⇒ a < b
+///
+/// This macro provides the same statements as [`assert_iter_sorted_strict`](macro.assert_iter_sorted_strict.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_iter_sorted_strict`](macro@crate::assert_iter_sorted_strict)
+/// * [`assert_iter_sorted_strict_as_result`](macro@crate::assert_iter_sorted_strict_as_result)
+/// * [`debug_assert_iter_sorted_strict`](macro@crate::debug_assert_iter_sorted_strict)
+///
+#[macro_export]
+macro_rules! debug_assert_iter_sorted_strict {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_iter_sorted_strict!($($arg)*);
+        }
+    };
+}
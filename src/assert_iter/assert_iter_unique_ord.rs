@@ -0,0 +1,294 @@
+//! Assert an iter yields unique items, meaning no duplicates, by using ordering.
+//!
+//! Pseudocode:<br>
+//! (collection into iter) ∀ items (a, b) ⇒ a ≠ b
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3];
+//! assert_iter_unique_ord!(a);
+//! ```
+//!
+//! This implementation uses [`::std::collections::BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html)
+//! to remember each item, so each item must implement `Ord + Debug`.
+//! This is useful for items that do not implement `Hash`. For items that
+//! implement `Hash`, you can use [`assert_iter_unique`](macro@crate::assert_iter_unique).
+//!
+//! * The collection is iterated by reference, so the collection is not moved.
+//!
+//! * The failure message shows the first duplicate item, and the index of
+//!   its first occurrence, and the index of its second occurrence.
+//!
+//! # Module macros
+//!
+//! * [`assert_iter_unique_ord`](macro@crate::assert_iter_unique_ord)
+//! * [`assert_iter_unique_ord_as_result`](macro@crate::assert_iter_unique_ord_as_result)
+//! * [`debug_assert_iter_unique_ord`](macro@crate::debug_assert_iter_unique_ord)
+
+/// Assert an iter yields unique items, meaning no duplicates, by using ordering.
+///
+/// Pseudocode:<br>
+/// (collection into iter) ∀ items (a, b) ⇒ a ≠ b
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_iter_unique_ord`](macro@crate::assert_iter_unique_ord)
+/// * [`assert_iter_unique_ord_as_result`](macro@crate::assert_iter_unique_ord_as_result)
+/// * [`debug_assert_iter_unique_ord`](macro@crate::debug_assert_iter_unique_ord)
+///
+#[macro_export]
+macro_rules! assert_iter_unique_ord_as_result {
+    ($collection:expr $(,)?) => {{
+        match (&$collection) {
+            collection => {
+                let mut seen: ::std::collections::BTreeMap<_, usize> = ::std::collections::BTreeMap::new();
+                let mut found = None;
+                for (i, x) in collection.into_iter().enumerate() {
+                    if let Some(&first) = seen.get(&x) {
+                        found = Some((first, i, x));
+                        break;
+                    }
+                    seen.insert(x, i);
+                }
+                match found {
+                    None => Ok(()),
+                    Some((first, second, x)) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_iter_unique_ord!(collection)`\n",
+                                    $crate::assertables_url_line!("assert_iter_unique_ord"),
+                                    " collection label: `{}`,\n",
+                                    " collection debug: `{:?}`,\n",
+                                    "  duplicate debug: `{:?}`,\n",
+                                    "      first index: `{}`,\n",
+                                    "     second index: `{}`"
+                                ),
+                                stringify!($collection),
+                                collection,
+                                x,
+                                first,
+                                second
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_unique_ord_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let actual = assert_iter_unique_ord_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 2, 3, 2];
+        let actual = assert_iter_unique_ord_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_iter_unique_ord!(collection)`\n",
+            assertables_url_line!("assert_iter_unique_ord"),
+            " collection label: `a`,\n",
+            " collection debug: `[1, 2, 3, 2]`,\n",
+            "  duplicate debug: `2`,\n",
+            "      first index: `1`,\n",
+            "     second index: `3`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_empty() {
+        let a: [i8; 0] = [];
+        let actual = assert_iter_unique_ord_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_vec_of_strings() {
+        let a = vec![String::from("alfa"), String::from("bravo")];
+        let actual = assert_iter_unique_ord_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+        assert_eq!(a.len(), 2);
+    }
+
+    #[test]
+    fn success_with_items_that_are_not_hash() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Point(i8, i8);
+        let a = [Point(1, 2), Point(2, 1)];
+        let actual = assert_iter_unique_ord_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+}
+
+/// Assert an iter yields unique items, meaning no duplicates, by using ordering.
+///
+/// Pseudocode:<br>
+/// (collection into iter) ∀ items (a, b) ⇒ a ≠ b
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// assert_iter_unique_ord!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3, 2];
+/// assert_iter_unique_ord!(a);
+/// # });
+/// // assertion failed: `assert_iter_unique_ord!(collection)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_iter_unique_ord.html
+/// //  collection label: `a`,
+/// //  collection debug: `[1, 2, 3, 2]`,
+/// //   duplicate debug: `2`,
+/// //       first index: `1`,
+/// //      second index: `3`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_iter_unique_ord!(collection)`\n",
+/// #     assertables_url_line!("assert_iter_unique_ord"),
+/// #     " collection label: `a`,\n",
+/// #     " collection debug: `[1, 2, 3, 2]`,\n",
+/// #     "  duplicate debug: `2`,\n",
+/// #     "      first index: `1`,\n",
+/// #     "     second index: `3`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This implementation uses [`::std::collections::BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html)
+/// to remember each item, so each item must implement `Ord + Debug`.
+/// This is useful for items that do not implement `Hash`. For items that
+/// implement `Hash`, you can use [`assert_iter_unique`](macro@crate::assert_iter_unique).
+///
+/// * The collection is iterated by reference, so the collection is not moved.
+///
+/// * The failure message shows the first duplicate item, and the index of
+///   its first occurrence, and the index of its second occurrence.
+///
+/// # Module macros
+///
+/// * [`assert_iter_unique_ord`](macro@crate::assert_iter_unique_ord)
+/// * [`assert_iter_unique_ord_as_result`](macro@crate::assert_iter_unique_ord_as_result)
+/// * [`debug_assert_iter_unique_ord`](macro@crate::debug_assert_iter_unique_ord)
+///
+#[macro_export]
+macro_rules! assert_iter_unique_ord {
+    ($collection:expr $(,)?) => {{
+        match $crate::assert_iter_unique_ord_as_result!($collection) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($collection:expr, $($message:tt)+) => {{
+        match $crate::assert_iter_unique_ord_as_result!($collection) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_iter_unique_ord {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let actual = assert_iter_unique_ord!(a);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 2, 3, 2];
+            let _actual = assert_iter_unique_ord!(a);
+        });
+        let message = concat!(
+            "assertion failed: `assert_iter_unique_ord!(collection)`\n",
+            assertables_url_line!("assert_iter_unique_ord"),
+            " collection label: `a`,\n",
+            " collection debug: `[1, 2, 3, 2]`,\n",
+            "  duplicate debug: `2`,\n",
+            "      first index: `1`,\n",
+            "     second index: `3`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an iter yields unique items, meaning no duplicates, by using ordering.
+///
+/// Pseudocode:<br>
+/// (collection into iter) ∀ items (a, b) ⇒ a ≠ b
+///
+/// This macro provides the same statements as [`assert_iter_unique_ord`](macro.assert_iter_unique_ord.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_iter_unique_ord`](macro@crate::assert_iter_unique_ord)
+/// * [`assert_iter_unique_ord_as_result`](macro@crate::assert_iter_unique_ord_as_result)
+/// * [`debug_assert_iter_unique_ord`](macro@crate::debug_assert_iter_unique_ord)
+///
+#[macro_export]
+macro_rules! debug_assert_iter_unique_ord {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_iter_unique_ord!($($arg)*);
+        }
+    };
+}
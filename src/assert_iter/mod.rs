@@ -10,6 +10,26 @@
 //! * [`assert_iter_gt!(collection1, collection2)`](macro@crate::assert_iter_gt) ≈ iter a > iter b
 //! * [`assert_iter_ge!(collection1, collection2)`](macro@crate::assert_iter_ge) ≈ iter a ≥ iter b
 //!
+//! Order:
+//!
+//! * [`assert_iter_sorted!(collection)`](macro@crate::assert_iter_sorted) ≈ iter items are non-decreasing
+//! * [`assert_iter_sorted_strict!(collection)`](macro@crate::assert_iter_sorted_strict) ≈ iter items are strictly increasing
+//! * [`assert_iter_sorted_by_key!(collection, key)`](macro@crate::assert_iter_sorted_by_key) ≈ iter item keys are non-decreasing
+//! * [`assert_iter_sorted_by_key_strict!(collection, key)`](macro@crate::assert_iter_sorted_by_key_strict) ≈ iter item keys are strictly increasing
+//!
+//! Uniqueness:
+//!
+//! * [`assert_iter_unique!(collection)`](macro@crate::assert_iter_unique) ≈ iter items are unique, by using `Hash`
+//! * [`assert_iter_unique_ord!(collection)`](macro@crate::assert_iter_unique_ord) ≈ iter items are unique, by using `Ord`
+//!
+//! Search:
+//!
+//! * [`assert_iter_contains!(collection, item)`](macro@crate::assert_iter_contains) ≈ iter contains item
+//!
+//! Report all differences:
+//!
+//! * [`assert_iter_eq_report_all!(collection1, collection2)`](macro@crate::assert_iter_eq_report_all) ≈ iter a = iter b, reporting every differing index
+//!
 //! # Example
 //!
 //! ```rust
@@ -27,3 +47,66 @@ pub mod assert_iter_gt;
 pub mod assert_iter_le;
 pub mod assert_iter_lt;
 pub mod assert_iter_ne;
+
+// Order
+pub mod assert_iter_sorted;
+pub mod assert_iter_sorted_by_key;
+pub mod assert_iter_sorted_by_key_strict;
+pub mod assert_iter_sorted_strict;
+
+// Uniqueness
+pub mod assert_iter_unique;
+pub mod assert_iter_unique_ord;
+
+// Search
+pub mod assert_iter_contains;
+
+// Reports
+pub mod assert_iter_eq_report_all;
+
+/// The most differences that [`assert_iter_eq_report_all`](macro@crate::assert_iter_eq_report_all)
+/// reports. After this many, the macro stops comparing, and the failure
+/// message ends with `… more`, so a long or endless iter gives a short message.
+pub const REPORT_ALL_LIMIT: usize = 10;
+
+/// The first pair of adjacent items whose keys are out of order.
+#[doc(hidden)]
+pub struct Unsorted<T, K> {
+    pub index: usize,
+    pub left: T,
+    pub right: T,
+    pub left_key: K,
+    pub right_key: K,
+}
+
+/// Find the first pair of adjacent items whose keys are out of order.
+///
+/// The key function is called once per item. The keys are in order if the
+/// left key is less than the right key, or if `strict` is false, equal.
+#[doc(hidden)]
+pub fn unsorted_by_key<I, F, K>(
+    collection: I,
+    mut key: F,
+    strict: bool,
+) -> Option<Unsorted<I::Item, K>>
+where
+    I: IntoIterator,
+    I::Item: Copy,
+    F: FnMut(I::Item) -> K,
+    K: PartialOrd,
+{
+    let pairs = collection.into_iter().map(|item| (item, key(item)));
+    crate::assert_monotonic::monotonic_violation_by(
+        pairs,
+        ::std::cmp::Ordering::Less,
+        strict,
+        |(_, left_key), (_, right_key)| left_key.partial_cmp(right_key),
+    )
+    .map(|(index, (left, left_key), (right, right_key))| Unsorted {
+        index,
+        left,
+        right,
+        left_key,
+        right_key,
+    })
+}
@@ -51,7 +51,7 @@ macro_rules! assert_len_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_len_eq!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq.html\n",
+                                $crate::assertables_url_line!("assert_len_eq"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.len(): `{:?}`,\n",
@@ -91,7 +91,7 @@ mod test_assert_len_eq_as_result {
         let actual = assert_len_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_len_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq.html\n",
+            assertables_url_line!("assert_len_eq"),
             " a label: `a`,\n",
             " a debug: `\"x\"`,\n",
             " a.len(): `1`,\n",
@@ -109,7 +109,7 @@ mod test_assert_len_eq_as_result {
         let actual = assert_len_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_len_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq.html\n",
+            assertables_url_line!("assert_len_eq"),
             " a label: `a`,\n",
             " a debug: `\"xx\"`,\n",
             " a.len(): `2`,\n",
@@ -119,6 +119,46 @@ mod test_assert_len_eq_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn collections() {
+        use std::collections::{BTreeMap, HashMap, VecDeque};
+        let a: HashMap<&str, i8> = HashMap::from([("a", 1), ("b", 2)]);
+        let b: BTreeMap<&str, i8> = BTreeMap::from([("c", 3), ("d", 4)]);
+        assert_eq!(assert_len_eq_as_result!(a, b).unwrap(), (2, 2));
+        let a: VecDeque<i8> = VecDeque::from([1, 2, 3, 4]);
+        let b = String::from("alfa");
+        assert_eq!(assert_len_eq_as_result!(a, b).unwrap(), (4, 4));
+        // The collections are borrowed, not moved, so they are usable here.
+        assert_eq!(a.len() + b.len(), 8);
+    }
+
+    #[test]
+    fn str_and_vec() {
+        let a: &str = "alfa";
+        let b: Vec<char> = vec!['a', 'l', 'f', 'a'];
+        assert_eq!(assert_len_eq_as_result!(a, b).unwrap(), (4, 4));
+        let b: Vec<u8> = vec![1, 2, 3];
+        assert!(assert_len_eq_as_result!(a, b).is_err());
+    }
+
+    #[test]
+    fn str_len_is_bytes_not_chars() {
+        let a: &str = "café";
+        let b: Vec<char> = a.chars().collect();
+        let actual = assert_len_eq_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_len_eq!(a, b)`\n",
+            assertables_url_line!("assert_len_eq"),
+            " a label: `a`,\n",
+            " a debug: `\"café\"`,\n",
+            " a.len(): `5`,\n",
+            " b label: `b`,\n",
+            " b debug: `['c', 'a', 'f', 'é']`\n",
+            " b.len(): `4`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
 }
 
 /// Assert a length is equal to another.
@@ -159,7 +199,7 @@ mod test_assert_len_eq_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_len_eq!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq.html\n",
+/// #     assertables_url_line!("assert_len_eq"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `\"x\"`,\n",
 /// #     " a.len(): `1`,\n",
@@ -182,13 +222,13 @@ macro_rules! assert_len_eq {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_len_eq_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_len_eq_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -214,7 +254,7 @@ mod test_assert_len_eq {
         });
         let message = concat!(
             "assertion failed: `assert_len_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq.html\n",
+            assertables_url_line!("assert_len_eq"),
             " a label: `a`,\n",
             " a debug: `\"x\"`,\n",
             " a.len(): `1`,\n",
@@ -241,7 +281,7 @@ mod test_assert_len_eq {
         });
         let message = concat!(
             "assertion failed: `assert_len_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq.html\n",
+            assertables_url_line!("assert_len_eq"),
             " a label: `a`,\n",
             " a debug: `\"xx\"`,\n",
             " a.len(): `2`,\n",
@@ -294,7 +334,7 @@ mod test_assert_len_eq {
 #[macro_export]
 macro_rules! debug_assert_len_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_len_eq!($($arg)*);
         }
     };
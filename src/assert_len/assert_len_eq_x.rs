@@ -43,14 +43,14 @@ macro_rules! assert_len_eq_x_as_result {
         match (&$a, &$b) {
             (a, b) => {
                 let a_len = a.len();
-                if a_len == $b {
-                    Ok((a_len, $b))
+                if a_len == *b {
+                    Ok((a_len, *b))
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_len_eq_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq_x.html\n",
+                                $crate::assertables_url_line!("assert_len_eq_x"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.len(): `{:?}`,\n",
@@ -72,6 +72,7 @@ macro_rules! assert_len_eq_x_as_result {
 
 #[cfg(test)]
 mod test_assert_len_eq_x_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn eq() {
@@ -88,7 +89,7 @@ mod test_assert_len_eq_x_as_result {
         let actual = assert_len_eq_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_len_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq_x.html\n",
+            assertables_url_line!("assert_len_eq_x"),
             " a label: `a`,\n",
             " a debug: `\"x\"`,\n",
             " a.len(): `1`,\n",
@@ -105,7 +106,7 @@ mod test_assert_len_eq_x_as_result {
         let actual = assert_len_eq_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_len_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq_x.html\n",
+            assertables_url_line!("assert_len_eq_x"),
             " a label: `a`,\n",
             " a debug: `\"xx\"`,\n",
             " a.len(): `2`,\n",
@@ -114,6 +115,41 @@ mod test_assert_len_eq_x_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        static B: AtomicU32 = AtomicU32::new(0);
+        fn a() -> Vec<i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            vec![1, 2]
+        }
+        fn b(x: usize) -> usize {
+            B.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        // Success
+        let _ = assert_len_eq_x_as_result!(a(), b(2));
+        // Failure
+        let _ = assert_len_eq_x_as_result!(a(), b(3));
+        assert_eq!(A.load(Ordering::SeqCst), 2);
+        assert_eq!(B.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn collections() {
+        use std::collections::{BTreeMap, HashMap, VecDeque};
+        let map: HashMap<&str, i8> = HashMap::from([("a", 1), ("b", 2)]);
+        assert_eq!(assert_len_eq_x_as_result!(map, 2).unwrap(), (2, 2));
+        let map: BTreeMap<&str, i8> = BTreeMap::from([("a", 1), ("b", 2)]);
+        assert_eq!(assert_len_eq_x_as_result!(map, 2).unwrap(), (2, 2));
+        let deque: VecDeque<i8> = VecDeque::from([1, 2, 3]);
+        assert_eq!(assert_len_eq_x_as_result!(deque, 3).unwrap(), (3, 3));
+        let string = String::from("alfa");
+        assert_eq!(assert_len_eq_x_as_result!(string, 4).unwrap(), (4, 4));
+        // The collections are borrowed, not moved, so they are usable here.
+        assert_eq!(map.len() + deque.len() + string.len(), 9);
+    }
 }
 
 /// Assert a length is equal to an expression.
@@ -153,7 +189,7 @@ mod test_assert_len_eq_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_len_eq_x!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq_x.html\n",
+/// #     assertables_url_line!("assert_len_eq_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `\"x\"`,\n",
 /// #     " a.len(): `1`,\n",
@@ -175,13 +211,13 @@ macro_rules! assert_len_eq_x {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_len_eq_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_len_eq_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -207,7 +243,7 @@ mod test_assert_len_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_len_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq_x.html\n",
+            assertables_url_line!("assert_len_eq_x"),
             " a label: `a`,\n",
             " a debug: `\"x\"`,\n",
             " a.len(): `1`,\n",
@@ -233,7 +269,7 @@ mod test_assert_len_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_len_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_eq_x.html\n",
+            assertables_url_line!("assert_len_eq_x"),
             " a label: `a`,\n",
             " a debug: `\"xx\"`,\n",
             " a.len(): `2`,\n",
@@ -285,7 +321,7 @@ mod test_assert_len_eq_x {
 #[macro_export]
 macro_rules! debug_assert_len_eq_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_len_eq_x!($($arg)*);
         }
     };
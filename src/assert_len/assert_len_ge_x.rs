@@ -43,14 +43,14 @@ macro_rules! assert_len_ge_x_as_result {
         match (&$a, &$b) {
             (a, b) => {
                 let a_len = a.len();
-                if a_len >= $b {
-                    Ok((a_len, $b))
+                if a_len >= *b {
+                    Ok((a_len, *b))
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_len_ge_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_ge_x.html\n",
+                                $crate::assertables_url_line!("assert_len_ge_x"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a.len(): `{:?}`,\n",
@@ -96,7 +96,7 @@ mod test_assert_len_ge_x_as_result {
         let actual = assert_len_ge_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_len_ge_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_ge_x.html\n",
+            assertables_url_line!("assert_len_ge_x"),
             " a label: `a`,\n",
             " a debug: `\"x\"`,\n",
             " a.len(): `1`,\n",
@@ -144,7 +144,7 @@ mod test_assert_len_ge_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_len_ge_x!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_ge_x.html\n",
+/// #     assertables_url_line!("assert_len_ge_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `\"x\"`,\n",
 /// #     " a.len(): `1`,\n",
@@ -166,13 +166,13 @@ macro_rules! assert_len_ge_x {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_len_ge_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_len_ge_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -206,7 +206,7 @@ mod test_assert_len_ge_x {
         });
         let message = concat!(
             "assertion failed: `assert_len_ge_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_len_ge_x.html\n",
+            assertables_url_line!("assert_len_ge_x"),
             " a label: `a`,\n",
             " a debug: `\"x\"`,\n",
             " a.len(): `1`,\n",
@@ -258,7 +258,7 @@ mod test_assert_len_ge_x {
 #[macro_export]
 macro_rules! debug_assert_len_ge_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_len_ge_x!($($arg)*);
         }
     };
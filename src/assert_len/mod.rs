@@ -30,6 +30,32 @@
 //! let b = "x";
 //! assert_len_eq!(a, b);
 //! ```
+//!
+//! ## Length versus count
+//!
+//! These macros borrow each argument and call `.len()`, which does not iterate
+//! and does not consume, so you can use the argument again after the macro.
+//!
+//! Many iterators do not have a `.len()` method. For those, use the macros
+//! in the module [`assert_count`](module@crate::assert_count), which count
+//! a clone of the iterator, so your iterator is not consumed.
+//!
+//! ## Mixed types
+//!
+//! The two arguments can be different types, such as a `&str` and a `Vec<T>`,
+//! because each macro only needs each argument to have a `.len()` method.
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "alfa";
+//! let b = vec![1, 2, 3, 4];
+//! assert_len_eq!(a, b);
+//! ```
+//!
+//! Beware that `str::len()` and `String::len()` return the number of bytes,
+//! not the number of chars, so `"café".len()` is 5. To compare chars, use
+//! [`assert_char_count`](module@crate::assert_char_count).
 
 // Compare another
 pub mod assert_len_eq;
@@ -9,6 +9,34 @@
 //! assert_matches!(a, 'a'..='z');
 //! ```
 //!
+//! To get values out of the match, add `=>` and an expression that uses the
+//! pattern bindings. On success, the macro returns the value of the expression.
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a: Result<(i8, i8), String> = Ok((1, 2));
+//! let (x, y) = assert_matches!(a, Ok((x, y)) => (x, y));
+//! assert_eq!(x, 1);
+//! assert_eq!(y, 2);
+//! ```
+//!
+//! The pattern can have a match guard, such as `n if n > 5`, the same as
+//! a Rust `match` arm. If the value does not match, then the message shows
+//! the value, if its type implements `Debug`, and the pattern with its guard.
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = 6;
+//! assert_matches!(a, n if n > 5);
+//! ```
+//!
+//! The expression is evaluated once. A pattern that binds by value moves the
+//! expression value when it matches, the same as a Rust `match`, so to keep
+//! using a variable, pass a reference, or bind with `ref`. A failed match
+//! does not move the value.
+//!
 //! Note: this implementation of `assert_matches` is relatively basic.
 //!
 //! * If you want more capabilities, consider the crate `assert_matches`.
@@ -23,7 +51,8 @@
 
 /// Assert expression matches a case.
 ///
-/// * If true, return Result `Ok(())`.
+/// * If true, return Result `Ok(())`, or for the form with `=> result`,
+///   return Result `Ok(result)`.
 ///
 /// * Otherwise, return Result `Err(message)`.
 ///
@@ -38,6 +67,48 @@
 ///
 #[macro_export]
 macro_rules! assert_matches_as_result {
+    ($expression:expr, $pattern:pat $(if $guard:expr)? => $result:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_matches::{ProbeDebug as _, ProbeNoDebug as _};
+        match $expression {
+            $pattern $(if $guard)? => Ok($result),
+            ref a => Err(
+                format!(
+                    concat!(
+                        "assertion failed: `assert_matches!(a, pattern => result)`\n",
+                        $crate::assertables_url_line!("assert_matches"),
+                        " a label: `{}`,\n",
+                        " a debug: `{}`,\n",
+                        " pattern: `{}`"
+                    ),
+                    stringify!($expression),
+                    (&$crate::assert_matches::DebugProbe(a)).probe_debug(),
+                    stringify!($pattern $(if $guard)?)
+                )
+            ),
+        }
+    }};
+    ($expression:expr, $pattern:pat if $guard:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_matches::{ProbeDebug as _, ProbeNoDebug as _};
+        match $expression {
+            $pattern if $guard => Ok(()),
+            ref a => Err(
+                format!(
+                    concat!(
+                        "assertion failed: `assert_matches!(a, pattern if guard)`\n",
+                        $crate::assertables_url_line!("assert_matches"),
+                        " a label: `{}`,\n",
+                        " a debug: `{}`,\n",
+                        " pattern: `{}`"
+                    ),
+                    stringify!($expression),
+                    (&$crate::assert_matches::DebugProbe(a)).probe_debug(),
+                    stringify!($pattern if $guard)
+                )
+            ),
+        }
+    }};
     ($($arg:tt)*) => {{
         if matches!($($arg)*) {
             Ok(())
@@ -46,7 +117,7 @@ macro_rules! assert_matches_as_result {
                 format!(
                     concat!(
                         "assertion failed: `assert_matches!(a)`\n",
-                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_matches.html\n",
+                        $crate::assertables_url_line!("assert_matches"),
                         " args: `{}`",
                     ),
                     stringify!($($arg)*)
@@ -75,7 +146,7 @@ mod test_assert_matches_as_result {
             let actual = assert_matches_as_result!(a, 'b'..='z');
             let message = concat!(
                 "assertion failed: `assert_matches!(a)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_matches.html\n",
+                assertables_url_line!("assert_matches"),
                 " args: `a, 'b'..='z'`",
             );
             assert_eq!(actual.unwrap_err(), message);
@@ -97,18 +168,183 @@ mod test_assert_matches_as_result {
             let a = Some(2);
             let actual = assert_matches_as_result!(a, Some(x) if x < 2);
             let message = concat!(
-                "assertion failed: `assert_matches!(a)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_matches.html\n",
-                " args: `a, Some(x) if x < 2`",
+                "assertion failed: `assert_matches!(a, pattern if guard)`\n",
+                assertables_url_line!("assert_matches"),
+                " a label: `a`,\n",
+                " a debug: `Some(2)`,\n",
+                " pattern: `Some(x) if x < 2`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+    }
+
+    mod use_guard {
+
+        #[test]
+        fn success() {
+            let a = 6;
+            let actual = assert_matches_as_result!(a, n if n > 5);
+            assert_eq!(actual.unwrap(), ());
+        }
+
+        #[test]
+        fn failure() {
+            let a = 5;
+            let actual = assert_matches_as_result!(a, n if n > 5);
+            let message = concat!(
+                "assertion failed: `assert_matches!(a, pattern if guard)`\n",
+                assertables_url_line!("assert_matches"),
+                " a label: `a`,\n",
+                " a debug: `5`,\n",
+                " pattern: `n if n > 5`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn failure_with_or_pattern() {
+            let a: Result<i8, i8> = Err(6);
+            let actual = assert_matches_as_result!(a, Ok(n) | Err(n) if n > 9);
+            let message = concat!(
+                "assertion failed: `assert_matches!(a, pattern if guard)`\n",
+                assertables_url_line!("assert_matches"),
+                " a label: `a`,\n",
+                " a debug: `Err(6)`,\n",
+                " pattern: `Ok(n) | Err(n) if n > 9`"
             );
             assert_eq!(actual.unwrap_err(), message);
         }
+
+        #[test]
+        fn guard_uses_binding_by_reference() {
+            let a = Some(String::from("alfa"));
+            let actual = assert_matches_as_result!(a, Some(s) if s.len() == 4);
+            assert_eq!(actual.unwrap(), ());
+        }
+
+        #[test]
+        fn evaluates_expression_and_guard_once() {
+            let mut calls = 0;
+            let mut guards = 0;
+            let mut a = || {
+                calls += 1;
+                Some(6)
+            };
+            let mut guard = |n: i32| {
+                guards += 1;
+                n > 5
+            };
+            let _ = assert_matches_as_result!(a(), Some(n) if guard(n));
+            assert_eq!(calls, 1);
+            assert_eq!(guards, 1);
+        }
+
+        #[test]
+        fn type_without_debug() {
+            #[allow(dead_code)]
+            enum Shape {
+                Circle(u8),
+                Square(u8),
+            }
+            let a = Shape::Square(2);
+            let actual = assert_matches_as_result!(a, Shape::Square(n) if n > 1);
+            assert_eq!(actual.unwrap(), ());
+            let a = Shape::Circle(2);
+            let actual = assert_matches_as_result!(a, Shape::Square(n) if n > 1);
+            let message = concat!(
+                "assertion failed: `assert_matches!(a, pattern if guard)`\n",
+                assertables_url_line!("assert_matches"),
+                " a label: `a`,\n",
+                " a debug: `(type does not implement Debug)`,\n",
+                " pattern: `Shape::Square(n) if n > 1`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn failure_does_not_move_value() {
+            let a: Option<String> = Some(String::from("alfa"));
+            let actual = assert_matches_as_result!(a, Some(ref s) if s.len() > 9);
+            assert!(actual.is_err());
+            // The value is not moved, so it is usable here.
+            assert_eq!(a, Some(String::from("alfa")));
+        }
+    }
+
+    mod use_captures {
+
+        #[test]
+        fn success() {
+            let a: Result<(i8, i8), String> = Ok((1, 2));
+            let actual = assert_matches_as_result!(a, Ok((x, y)) => (x, y));
+            assert_eq!(actual.unwrap(), (1, 2));
+        }
+
+        #[test]
+        fn success_with_guard() {
+            let a = Some(1);
+            let actual = assert_matches_as_result!(a, Some(x) if x < 2 => x * 10);
+            assert_eq!(actual.unwrap(), 10);
+        }
+
+        #[test]
+        fn failure() {
+            let a: Result<(i8, i8), String> = Err(String::from("alfa"));
+            let actual = assert_matches_as_result!(a, Ok((x, y)) => (x, y));
+            let message = concat!(
+                "assertion failed: `assert_matches!(a, pattern => result)`\n",
+                assertables_url_line!("assert_matches"),
+                " a label: `a`,\n",
+                " a debug: `Err(\"alfa\")`,\n",
+                " pattern: `Ok((x, y))`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn failure_with_guard() {
+            let a = Some(2);
+            let actual = assert_matches_as_result!(a, Some(x) if x < 2 => x * 10);
+            let message = concat!(
+                "assertion failed: `assert_matches!(a, pattern => result)`\n",
+                assertables_url_line!("assert_matches"),
+                " a label: `a`,\n",
+                " a debug: `Some(2)`,\n",
+                " pattern: `Some(x) if x < 2`"
+            );
+            assert_eq!(actual.unwrap_err(), message);
+        }
+
+        #[test]
+        fn type_without_debug() {
+            struct Point(u8, u8);
+            let a = Some(Point(1, 2));
+            let actual = assert_matches_as_result!(a, Some(Point(x, y)) => x + y);
+            assert_eq!(actual.unwrap(), 3);
+            let a: Option<Point> = None;
+            let actual = assert_matches_as_result!(a, Some(Point(x, y)) => x + y);
+            assert!(actual
+                .unwrap_err()
+                .contains(" a debug: `(type does not implement Debug)`,\n"));
+        }
+
+        #[test]
+        fn evaluates_expression_once() {
+            let mut calls = 0;
+            let mut a = || {
+                calls += 1;
+                Some(1)
+            };
+            let _ = assert_matches_as_result!(a(), Some(x) => x);
+            let _ = assert_matches_as_result!(a(), None => 0);
+            assert_eq!(calls, 2);
+        }
     }
 }
 
 /// Assert expression is Some.
 ///
-/// * If true, return `()`.
+/// * If true, return `()`, or for the form with `=> result`, return `result`.
 ///
 /// * Otherwise, call [`panic!`] with a message and the values of the
 ///   expressions with their debug representations.
@@ -134,10 +370,34 @@ mod test_assert_matches_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_matches!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_matches.html\n",
+/// #     assertables_url_line!("assert_matches"),
 /// #     " args: `a, 'b'..='z'`",
 /// # );
 /// # assert_eq!(actual, message);
+///
+/// // Return values from the pattern bindings
+/// let a = Some((1, 2));
+/// let (x, y) = assert_matches!(a, Some((x, y)) => (x, y));
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Option<(i8, i8)> = None;
+/// let (x, y) = assert_matches!(a, Some((x, y)) => (x, y));
+/// # });
+/// // assertion failed: `assert_matches!(a, pattern => result)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_matches.html
+/// //  a label: `a`,
+/// //  a debug: `None`,
+/// //  pattern: `Some((x, y))`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_matches!(a, pattern => result)`\n",
+/// #     assertables_url_line!("assert_matches"),
+/// #     " a label: `a`,\n",
+/// #     " a debug: `None`,\n",
+/// #     " pattern: `Some((x, y))`",
+/// # );
+/// # assert_eq!(actual, message);
 /// # }
 /// ```
 ///
@@ -149,28 +409,40 @@ mod test_assert_matches_as_result {
 ///
 #[macro_export]
 macro_rules! assert_matches {
+    ($expression:expr, $pattern:pat $(if $guard:expr)? => $result:expr $(,)?) => {{
+        match $crate::assert_matches_as_result!($expression, $pattern $(if $guard)? => $result) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($expression:expr, $pattern:pat $(if $guard:expr)? => $result:expr, $($message:tt)+) => {{
+        match $crate::assert_matches_as_result!($expression, $pattern $(if $guard)? => $result) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
     ($expression:expr, $pattern:pat if $guard:expr $(,)?) => {{
         match $crate::assert_matches_as_result!($expression, $pattern if $guard) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($expression:expr, $pattern:pat) => {{
         match $crate::assert_matches_as_result!($expression, $pattern) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($expression:expr, $pattern:pat if $guard:expr, $($message:tt)+) => {{
         match $crate::assert_matches_as_result!($expression, $pattern if $guard) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
     ($expression:expr, $pattern:pat, $($message:tt)+) => {{
-        match $crate::assert_matches_as_result!($expression, $pattern if $guard) {
+        match $crate::assert_matches_as_result!($expression, $pattern) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -197,7 +469,7 @@ mod test_assert_matches {
             });
             let message = concat!(
                 "assertion failed: `assert_matches!(a)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_matches.html\n",
+                assertables_url_line!("assert_matches"),
                 " args: `a, 'b'..='z'`",
             );
             assert_eq!(
@@ -229,9 +501,116 @@ mod test_assert_matches {
                 let _actual = assert_matches!(a, Some(x) if x < 2);
             });
             let message = concat!(
+                "assertion failed: `assert_matches!(a, pattern if guard)`\n",
+                assertables_url_line!("assert_matches"),
+                " a label: `a`,\n",
+                " a debug: `Some(2)`,\n",
+                " pattern: `Some(x) if x < 2`"
+            );
+            assert_eq!(
+                result
+                    .unwrap_err()
+                    .downcast::<String>()
+                    .unwrap()
+                    .to_string(),
+                message
+            );
+        }
+    }
+
+    mod use_guard {
+        use std::panic;
+
+        #[test]
+        fn success() {
+            let a = 6;
+            let actual = assert_matches!(a, n if n > 5);
+            assert_eq!(actual, ());
+        }
+
+        #[test]
+        fn failure() {
+            let a = 5;
+            let result = panic::catch_unwind(|| {
+                let _actual = assert_matches!(a, n if n > 5);
+            });
+            let message = concat!(
+                "assertion failed: `assert_matches!(a, pattern if guard)`\n",
+                assertables_url_line!("assert_matches"),
+                " a label: `a`,\n",
+                " a debug: `5`,\n",
+                " pattern: `n if n > 5`"
+            );
+            assert_eq!(
+                result
+                    .unwrap_err()
+                    .downcast::<String>()
+                    .unwrap()
+                    .to_string(),
+                message
+            );
+        }
+
+        #[test]
+        fn failure_with_custom_message() {
+            let a = 5;
+            let result = panic::catch_unwind(|| {
+                let _actual = assert_matches!(a, n if n > 5, "message {}", 1);
+            });
+            let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+            assert!(actual.starts_with("message 1\nassertion failed: `assert_matches!(a, pattern if guard)`\n"));
+        }
+    }
+
+    mod use_custom_message {
+        use std::panic;
+
+        #[test]
+        fn failure() {
+            let a = 'a';
+            let result = panic::catch_unwind(|| {
+                let _actual = assert_matches!(a, 'b'..='z', "message {}", 1);
+            });
+            let message = concat!(
+                "message 1\n",
                 "assertion failed: `assert_matches!(a)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_matches.html\n",
-                " args: `a, Some(x) if x < 2`",
+                assertables_url_line!("assert_matches"),
+                " args: `a, 'b'..='z'`",
+            );
+            assert_eq!(
+                result
+                    .unwrap_err()
+                    .downcast::<String>()
+                    .unwrap()
+                    .to_string(),
+                message
+            );
+        }
+    }
+
+    mod use_captures {
+        use std::panic;
+
+        #[test]
+        fn success() {
+            let a = Some((1, 2));
+            let (x, y) = assert_matches!(a, Some((x, y)) => (x, y));
+            assert_eq!(x, 1);
+            assert_eq!(y, 2);
+        }
+
+        #[test]
+        fn failure() {
+            let a: Option<(i8, i8)> = None;
+            let result = panic::catch_unwind(|| {
+                let _actual = assert_matches!(a, Some((x, y)) => (x, y));
+            });
+            let message = concat!(
+                "assertion failed: `assert_matches!(a, pattern => result)`\n",
+                assertables_url_line!("assert_matches"),
+                " a label: `a`,\n",
+                " a debug: `None`,\n",
+                " pattern: `Some((x, y))`"
             );
             assert_eq!(
                 result
@@ -276,7 +655,7 @@ mod test_assert_matches {
 #[macro_export]
 macro_rules! debug_assert_matches {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_matches!($($arg)*);
         }
     };
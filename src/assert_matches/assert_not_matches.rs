@@ -46,7 +46,7 @@ macro_rules! assert_not_matches_as_result {
                 format!(
                     concat!(
                         "assertion failed: `assert_not_matches!(a)`\n",
-                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_matches.html\n",
+                        $crate::assertables_url_line!("assert_not_matches"),
                         " args: `{}`",
                     ),
                     stringify!($($arg)*)
@@ -75,7 +75,7 @@ mod test_assert_not_matches_as_result {
             let actual = assert_not_matches_as_result!(a, 'a'..='z');
             let message = concat!(
                 "assertion failed: `assert_not_matches!(a)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_matches.html\n",
+                assertables_url_line!("assert_not_matches"),
                 " args: `a, 'a'..='z'`",
             );
             assert_eq!(actual.unwrap_err(), message);
@@ -98,7 +98,7 @@ mod test_assert_not_matches_as_result {
             let actual = assert_not_matches_as_result!(a, Some(x) if x < 2);
             let message = concat!(
                 "assertion failed: `assert_not_matches!(a)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_matches.html\n",
+                assertables_url_line!("assert_not_matches"),
                 " args: `a, Some(x) if x < 2`",
             );
             assert_eq!(actual.unwrap_err(), message);
@@ -134,7 +134,7 @@ mod test_assert_not_matches_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_not_matches!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_matches.html\n",
+/// #     assertables_url_line!("assert_not_matches"),
 /// #     " args: `a, 'a'..='z'`",
 /// # );
 /// # assert_eq!(actual, message);
@@ -152,25 +152,25 @@ macro_rules! assert_not_matches {
     ($expression:expr, $pattern:pat if $guard:expr $(,)?) => {{
         match $crate::assert_not_matches_as_result!($expression, $pattern if $guard) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($expression:expr, $pattern:pat) => {{
         match $crate::assert_not_matches_as_result!($expression, $pattern) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($expression:expr, $pattern:pat if $guard:expr, $($message:tt)+) => {{
         match $crate::assert_not_matches_as_result!($expression, $pattern if $guard) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
     ($expression:expr, $pattern:pat, $($message:tt)+) => {{
-        match $crate::assert_not_matches_as_result!($expression, $pattern if $guard) {
+        match $crate::assert_not_matches_as_result!($expression, $pattern) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -197,7 +197,7 @@ mod test_assert_not_matches {
             });
             let message = concat!(
                 "assertion failed: `assert_not_matches!(a)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_matches.html\n",
+                assertables_url_line!("assert_not_matches"),
                 " args: `a, 'a'..='z'`",
             );
             assert_eq!(
@@ -230,7 +230,7 @@ mod test_assert_not_matches {
             });
             let message = concat!(
                 "assertion failed: `assert_not_matches!(a)`\n",
-                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_not_matches.html\n",
+                assertables_url_line!("assert_not_matches"),
                 " args: `a, Some(x) if x < 2`",
             );
             assert_eq!(
@@ -243,6 +243,32 @@ mod test_assert_not_matches {
             );
         }
     }
+
+    mod use_custom_message {
+        use std::panic;
+
+        #[test]
+        fn failure() {
+            let a = 'a';
+            let result = panic::catch_unwind(|| {
+                let _actual = assert_not_matches!(a, 'a'..='z', "message {}", 1);
+            });
+            let message = concat!(
+                "message 1\n",
+                "assertion failed: `assert_not_matches!(a)`\n",
+                assertables_url_line!("assert_not_matches"),
+                " args: `a, 'a'..='z'`",
+            );
+            assert_eq!(
+                result
+                    .unwrap_err()
+                    .downcast::<String>()
+                    .unwrap()
+                    .to_string(),
+                message
+            );
+        }
+    }
 }
 
 /// Assert expression is Some.
@@ -276,7 +302,7 @@ mod test_assert_not_matches {
 #[macro_export]
 macro_rules! debug_assert_not_matches {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_not_matches!($($arg)*);
         }
     };
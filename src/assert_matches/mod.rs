@@ -4,6 +4,10 @@
 //!
 //! * [`assert_matches!(a, b)`](macro@crate::assert_matches) ≈ match(a) { b }
 //!
+//! * [`assert_matches!(a, b => c)`](macro@crate::assert_matches) ≈ match(a) { b => c }, returning c
+//!
+//! * [`assert_matches!(a, b if guard)`](macro@crate::assert_matches) ≈ match(a) { b if guard }
+//!
 //! * [`assert_not_matches!(a, b)`](macro@crate::assert_matches) ≈ match(a) { b }
 //!
 //! # Example
@@ -17,3 +21,32 @@
 
 pub mod assert_matches;
 pub mod assert_not_matches;
+
+/// Probe an item for its debug representation, if its type implements `Debug`.
+///
+/// The macros call `(&DebugProbe(&a)).probe_debug()`. Method lookup tries
+/// [`ProbeDebug`] first, which applies only if the type implements `Debug`,
+/// then falls back to [`ProbeNoDebug`], which returns a placeholder, so the
+/// macros work for any type, the same as a Rust `match`.
+#[doc(hidden)]
+pub struct DebugProbe<'a, T: ?Sized>(pub &'a T);
+
+#[doc(hidden)]
+pub trait ProbeDebug {
+    fn probe_debug(&self) -> String;
+}
+
+impl<T: ::std::fmt::Debug + ?Sized> ProbeDebug for DebugProbe<'_, T> {
+    fn probe_debug(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeNoDebug {
+    fn probe_debug(&self) -> String {
+        String::from("(type does not implement Debug)")
+    }
+}
+
+impl<T: ?Sized> ProbeNoDebug for &DebugProbe<'_, T> {}
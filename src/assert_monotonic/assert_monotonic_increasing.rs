@@ -0,0 +1,349 @@
+//! Assert a slice is weakly increasing, i.e. non-decreasing.
+//!
+//! Pseudocode:<br>
+//! ∀ i ⇒ slice\[i\] ≤ slice\[i + 1\]
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3, 5];
+//! assert_monotonic_increasing!(a);
+//! ```
+//!
+//! The slice can be a slice `&[T]`, or anything that can be indexed by `[..]`
+//! to get a slice, such as an array or a `Vec`. The items need `PartialOrd`
+//! and `Debug`.
+//!
+//! * An empty slice, or a slice with one item, is monotonic.
+//!
+//! * Equal adjacent items are allowed, because this is the weak variant.
+//!
+//! * Items that are not comparable, such as a float NaN, are a violation.
+//!
+//! * The failure message shows the first violating index and the adjacent pair.
+//!
+//! # Module macros
+//!
+//! * [`assert_monotonic_increasing`](macro@crate::assert_monotonic_increasing)
+//! * [`assert_monotonic_increasing_as_result`](macro@crate::assert_monotonic_increasing_as_result)
+//! * [`debug_assert_monotonic_increasing`](macro@crate::debug_assert_monotonic_increasing)
+
+/// Assert a slice is weakly increasing, i.e. non-decreasing.
+///
+/// Pseudocode:<br>
+/// ∀ i ⇒ slice\[i\] ≤ slice\[i + 1\]
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_monotonic_increasing`](macro@crate::assert_monotonic_increasing)
+/// * [`assert_monotonic_increasing_as_result`](macro@crate::assert_monotonic_increasing_as_result)
+/// * [`debug_assert_monotonic_increasing`](macro@crate::debug_assert_monotonic_increasing)
+///
+#[macro_export]
+macro_rules! assert_monotonic_increasing_as_result {
+    ($slice:expr $(,)?) => {{
+        match (&$slice) {
+            slice => {
+                let slice: &[_] = &slice[..];
+                match $crate::assert_monotonic::monotonic_violation(
+                    slice,
+                    ::core::cmp::Ordering::Less,
+                    false,
+                ) {
+                    None => Ok(()),
+                    Some((i, left, right)) => Err($crate::__private::format!(
+                        concat!(
+                            "assertion failed: `assert_monotonic_increasing!(slice)`\n",
+                            $crate::assertables_url_line!("assert_monotonic_increasing"),
+                            " slice label: `{}`,\n",
+                            " slice debug: `{:?}`,\n",
+                            "  left index: `{}`,\n",
+                            "  left debug: `{:?}`,\n",
+                            " right index: `{}`,\n",
+                            " right debug: `{:?}`"
+                        ),
+                        stringify!($slice),
+                        slice,
+                        i,
+                        left,
+                        i + 1,
+                        right
+                    )),
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_monotonic_increasing_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 2, 3, 5];
+        let actual = assert_monotonic_increasing_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 3, 2, 4];
+        let actual = assert_monotonic_increasing_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_monotonic_increasing!(slice)`\n",
+            assertables_url_line!("assert_monotonic_increasing"),
+            " slice label: `a`,\n",
+            " slice debug: `[1, 3, 2, 4]`,\n",
+            "  left index: `1`,\n",
+            "  left debug: `3`,\n",
+            " right index: `2`,\n",
+            " right debug: `2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_equal_adjacent_values() {
+        let a = [1, 2, 2, 3];
+        let actual = assert_monotonic_increasing_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_all_equal_values() {
+        let a = [7, 7, 7];
+        let actual = assert_monotonic_increasing_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_empty() {
+        let a: [i8; 0] = [];
+        let actual = assert_monotonic_increasing_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_one_item() {
+        let a = [1];
+        let actual = assert_monotonic_increasing_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_slice() {
+        let v = vec![1, 2, 3, 5];
+        let a: &[i32] = &v;
+        let actual = assert_monotonic_increasing_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_vec() {
+        let a: Vec<f64> = vec![0.5, 1.5, 2.5];
+        let actual = assert_monotonic_increasing_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_nan() {
+        let a = [1.0, f64::NAN, 2.0];
+        let actual = assert_monotonic_increasing_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_monotonic_increasing!(slice)`\n",
+            assertables_url_line!("assert_monotonic_increasing"),
+            " slice label: `a`,\n",
+            " slice debug: `[1.0, NaN, 2.0]`,\n",
+            "  left index: `0`,\n",
+            "  left debug: `1.0`,\n",
+            " right index: `1`,\n",
+            " right debug: `NaN`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_reports_first_violation() {
+        let a = [1, 0, 2, 1];
+        let actual = assert_monotonic_increasing_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_monotonic_increasing!(slice)`\n",
+            assertables_url_line!("assert_monotonic_increasing"),
+            " slice label: `a`,\n",
+            " slice debug: `[1, 0, 2, 1]`,\n",
+            "  left index: `0`,\n",
+            "  left debug: `1`,\n",
+            " right index: `1`,\n",
+            " right debug: `0`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a slice is weakly increasing, i.e. non-decreasing.
+///
+/// Pseudocode:<br>
+/// ∀ i ⇒ slice\[i\] ≤ slice\[i + 1\]
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3, 5];
+/// assert_monotonic_increasing!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 3, 2, 4];
+/// assert_monotonic_increasing!(a);
+/// # });
+/// // assertion failed: `assert_monotonic_increasing!(slice)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_monotonic_increasing.html
+/// //  slice label: `a`,
+/// //  slice debug: `[1, 3, 2, 4]`,
+/// //   left index: `1`,
+/// //   left debug: `3`,
+/// //  right index: `2`,
+/// //  right debug: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_monotonic_increasing!(slice)`\n",
+/// #     assertables_url_line!("assert_monotonic_increasing"),
+/// #     " slice label: `a`,\n",
+/// #     " slice debug: `[1, 3, 2, 4]`,\n",
+/// #     "  left index: `1`,\n",
+/// #     "  left debug: `3`,\n",
+/// #     " right index: `2`,\n",
+/// #     " right debug: `2`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The slice can be a slice `&[T]`, or anything that can be indexed by `[..]`
+/// to get a slice, such as an array or a `Vec`. The items need `PartialOrd`
+/// and `Debug`.
+///
+/// * An empty slice, or a slice with one item, is monotonic.
+///
+/// * Equal adjacent items are allowed, because this is the weak variant.
+///
+/// * Items that are not comparable, such as a float NaN, are a violation.
+///
+/// * The failure message shows the first violating index and the adjacent pair.
+///
+/// # Module macros
+///
+/// * [`assert_monotonic_increasing`](macro@crate::assert_monotonic_increasing)
+/// * [`assert_monotonic_increasing_as_result`](macro@crate::assert_monotonic_increasing_as_result)
+/// * [`debug_assert_monotonic_increasing`](macro@crate::debug_assert_monotonic_increasing)
+///
+#[macro_export]
+macro_rules! assert_monotonic_increasing {
+    ($slice:expr $(,)?) => {{
+        match $crate::assert_monotonic_increasing_as_result!($slice) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($slice:expr, $($message:tt)+) => {{
+        match $crate::assert_monotonic_increasing_as_result!($slice) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_monotonic_increasing {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3, 5];
+        let actual = assert_monotonic_increasing!(a);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 3, 2, 4];
+            let _actual = assert_monotonic_increasing!(a);
+        });
+        let message = concat!(
+            "assertion failed: `assert_monotonic_increasing!(slice)`\n",
+            assertables_url_line!("assert_monotonic_increasing"),
+            " slice label: `a`,\n",
+            " slice debug: `[1, 3, 2, 4]`,\n",
+            "  left index: `1`,\n",
+            "  left debug: `3`,\n",
+            " right index: `2`,\n",
+            " right debug: `2`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a slice is weakly increasing, i.e. non-decreasing.
+///
+/// Pseudocode:<br>
+/// ∀ i ⇒ slice\[i\] ≤ slice\[i + 1\]
+///
+/// This macro provides the same statements as [`assert_monotonic_increasing`](macro.assert_monotonic_increasing.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_monotonic_increasing`](macro@crate::assert_monotonic_increasing)
+/// * [`assert_monotonic_increasing_as_result`](macro@crate::assert_monotonic_increasing_as_result)
+/// * [`debug_assert_monotonic_increasing`](macro@crate::debug_assert_monotonic_increasing)
+///
+#[macro_export]
+macro_rules! debug_assert_monotonic_increasing {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_monotonic_increasing!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,83 @@
+//! Assert for checking a slice of numbers is monotonic.
+//!
+//! These macros help with sequences such as time series, where each item
+//! should increase, or decrease, compared to the item before it. The macros
+//! accept a slice `&[T]` directly, where `T` is `PartialOrd`. For any
+//! collection that is sorted by an order, see
+//! [`assert_iter_sorted`](macro@crate::assert_iter_sorted).
+//!
+//! Weak variants allow equal adjacent items, and strict variants do not:
+//!
+//! * [`assert_monotonic_increasing!(slice)`](macro@crate::assert_monotonic_increasing) ≈ ∀ i ⇒ slice\[i\] ≤ slice\[i + 1\]
+//! * [`assert_monotonic_increasing_strict!(slice)`](macro@crate::assert_monotonic_increasing_strict) ≈ ∀ i ⇒ slice\[i\] < slice\[i + 1\]
+//! * [`assert_monotonic_decreasing!(slice)`](macro@crate::assert_monotonic_decreasing) ≈ ∀ i ⇒ slice\[i\] ≥ slice\[i + 1\]
+//! * [`assert_monotonic_decreasing_strict!(slice)`](macro@crate::assert_monotonic_decreasing_strict) ≈ ∀ i ⇒ slice\[i\] > slice\[i + 1\]
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1.0, 2.0, 2.0, 3.5];
+//! assert_monotonic_increasing!(a);
+//! ```
+
+use core::cmp::Ordering;
+
+pub mod assert_monotonic_decreasing;
+pub mod assert_monotonic_decreasing_strict;
+pub mod assert_monotonic_increasing;
+pub mod assert_monotonic_increasing_strict;
+
+/// Find the first pair of adjacent items that breaks the monotonic order.
+///
+/// Return the index of the left item, the left item, and the right item.
+/// The order is `Less` for increasing, or `Greater` for decreasing.
+/// An `Equal` pair breaks only a strict order, and a pair that is not
+/// comparable, such as a float NaN, always breaks the order.
+///
+/// The monotonic macros, the sorted macros, and the iter sorted macros all
+/// call this function, or [`monotonic_violation_by`] for a custom comparison.
+#[doc(hidden)]
+pub fn monotonic_violation<I>(
+    items: I,
+    order: Ordering,
+    strict: bool,
+) -> Option<(usize, I::Item, I::Item)>
+where
+    I: IntoIterator,
+    I::Item: PartialOrd,
+{
+    monotonic_violation_by(items, order, strict, |left, right| {
+        left.partial_cmp(right)
+    })
+}
+
+/// Find the first pair of adjacent items that breaks the monotonic order,
+/// comparing each pair with a function, such as by a key.
+#[doc(hidden)]
+pub fn monotonic_violation_by<I, F>(
+    items: I,
+    order: Ordering,
+    strict: bool,
+    mut compare: F,
+) -> Option<(usize, I::Item, I::Item)>
+where
+    I: IntoIterator,
+    F: FnMut(&I::Item, &I::Item) -> Option<Ordering>,
+{
+    let mut iter = items.into_iter();
+    let mut left = iter.next()?;
+    for (index, right) in iter.enumerate() {
+        let in_order = match compare(&left, &right) {
+            Some(Ordering::Equal) => !strict,
+            Some(ordering) => ordering == order,
+            None => false,
+        };
+        if !in_order {
+            return Some((index, left, right));
+        }
+        left = right;
+    }
+    None
+}
@@ -42,10 +42,10 @@ macro_rules! assert_ne_as_result {
                 if a != b {
                     Ok(())
                 } else {
-                    Err(format!(
+                    Err($crate::__private::format!(
                         concat!(
                             "assertion failed: `assert_ne!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ne.html\n",
+                            $crate::assertables_url_line!("assert_ne"),
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
@@ -80,7 +80,7 @@ mod test_assert_ne_as_result {
         let actual = assert_ne_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ne.html\n",
+            assertables_url_line!("assert_ne"),
             " a label: `a`,\n",
             " a debug: `1`,\n",
             " b label: `b`,\n",
@@ -50,7 +50,7 @@ macro_rules! assert_none_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_none!(a)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_none.html\n",
+                                    $crate::assertables_url_line!("assert_none"),
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`",
                                 ),
@@ -67,6 +67,7 @@ macro_rules! assert_none_as_result {
 
 #[cfg(test)]
 mod test_assert_none_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn success() {
@@ -81,12 +82,25 @@ mod test_assert_none_as_result {
         let actual = assert_none_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_none!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_none.html\n",
+            assertables_url_line!("assert_none"),
             " a label: `a`,\n",
             " a debug: `Some(1)`",
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        fn a(x: Option<i8>) -> Option<i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        let _ = assert_none_as_result!(a(Some(1)));
+        assert_eq!(A.load(Ordering::SeqCst), 1);
+        let _ = assert_none_as_result!(a(None));
+        assert_eq!(A.load(Ordering::SeqCst), 2);
+    }
 }
 
 /// Assert expression is None.
@@ -121,7 +135,7 @@ mod test_assert_none_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_none!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_none.html\n",
+/// #     assertables_url_line!("assert_none"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Some(1)`",
 /// # );
@@ -140,13 +154,13 @@ macro_rules! assert_none {
     ($a:expr $(,)?) => {{
         match $crate::assert_none_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_none_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -170,7 +184,7 @@ mod test_assert_none {
         });
         let message = concat!(
             "assertion failed: `assert_none!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_none.html\n",
+            assertables_url_line!("assert_none"),
             " a label: `a`,\n",
             " a debug: `Some(1)`",
         );
@@ -219,7 +233,7 @@ mod test_assert_none {
 #[macro_export]
 macro_rules! debug_assert_none {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_none!($($arg)*);
         }
     };
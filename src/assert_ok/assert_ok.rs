@@ -39,17 +39,17 @@
 #[macro_export]
 macro_rules! assert_ok_as_result {
     ($a:expr $(,)?) => {
-        match ($a) {
+        match $a {
             Ok(a1) => Ok(a1),
-            _ => Err(format!(
+            a => Err(format!(
                 concat!(
                     "assertion failed: `assert_ok!(a)`\n",
-                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok.html\n",
+                    $crate::assertables_url_line!("assert_ok"),
                     " a label: `{}`,\n",
-                    " a debug: `{:?}`",
+                    " a debug: `{:?}`"
                 ),
                 stringify!($a),
-                $a
+                a
             )),
         }
     };
@@ -57,6 +57,7 @@ macro_rules! assert_ok_as_result {
 
 #[cfg(test)]
 mod test_assert_ok_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn success() {
@@ -71,12 +72,29 @@ mod test_assert_ok_as_result {
         let actual = assert_ok_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_ok!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok.html\n",
+            assertables_url_line!("assert_ok"),
             " a label: `a`,\n",
             " a debug: `Err(1)`",
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        fn ok() -> Result<i8, i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            Ok(1)
+        }
+        fn err() -> Result<i8, i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            Err(1)
+        }
+        let _ = assert_ok_as_result!(ok());
+        assert_eq!(A.load(Ordering::SeqCst), 1);
+        let _ = assert_ok_as_result!(err());
+        assert_eq!(A.load(Ordering::SeqCst), 2);
+    }
 }
 
 /// Assert expression is Ok.
@@ -111,7 +129,7 @@ mod test_assert_ok_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_ok!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok.html\n",
+/// #     assertables_url_line!("assert_ok"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Err(1)`",
 /// # );
@@ -130,13 +148,13 @@ macro_rules! assert_ok {
     ($a:expr $(,)?) => {{
         match $crate::assert_ok_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_ok_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -160,7 +178,7 @@ mod test_assert_ok {
         });
         let message = concat!(
             "assertion failed: `assert_ok!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok.html\n",
+            assertables_url_line!("assert_ok"),
             " a label: `a`,\n",
             " a debug: `Err(1)`",
         );
@@ -209,7 +227,7 @@ mod test_assert_ok {
 #[macro_export]
 macro_rules! debug_assert_ok {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_ok!($($arg)*);
         }
     };
@@ -0,0 +1,306 @@
+//! Assert expression is Ok, and its value satisfies a predicate.
+//!
+//! Pseudocode:<br>
+//! (a ⇒ Ok(a1) ⇒ a1) ⇒ predicate(a1) = true
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a: Result<i8, i8> = Ok(2);
+//! assert_ok_and!(a, |x| *x > 1);
+//! ```
+//!
+//! This macro fuses `let a1 = assert_ok!(a);` and `assert!(predicate(&a1));`.
+//! The predicate is called once, with a reference to the inner value,
+//! and on success the macro returns the inner value.
+//!
+//! The failure message distinguishes the two ways to fail: if `a` is `Err`,
+//! then the message ends with "a is Ok: false", and if the predicate
+//! returns false, then the message ends with "predicate(a inner): false".
+//!
+//! # Module macros
+//!
+//! * [`assert_ok_and`](macro@crate::assert_ok_and)
+//! * [`assert_ok_and_as_result`](macro@crate::assert_ok_and_as_result)
+//! * [`debug_assert_ok_and`](macro@crate::debug_assert_ok_and)
+
+/// Assert expression is Ok, and its value satisfies a predicate.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1) ⇒ predicate(a1) = true
+///
+/// * If true, return Result `Ok(a1)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ok_and`](macro@crate::assert_ok_and)
+/// * [`assert_ok_and_as_result`](macro@crate::assert_ok_and_as_result)
+/// * [`debug_assert_ok_and`](macro@crate::debug_assert_ok_and)
+///
+#[macro_export]
+macro_rules! assert_ok_and_as_result {
+    ($a:expr, $predicate:expr $(,)?) => {{
+        match ($a) {
+            Ok(a1) => {
+                if $crate::__private::call_predicate(&a1, $predicate) {
+                    Ok(a1)
+                } else {
+                    Err(format!(
+                        concat!(
+                            "assertion failed: `assert_ok_and!(a, predicate)`\n",
+                            $crate::assertables_url_line!("assert_ok_and"),
+                            "            a label: `{}`,\n",
+                            "            a debug: `Ok({:?})`,\n",
+                            "            a inner: `{:?}`,\n",
+                            "    predicate label: `{}`,\n",
+                            " predicate(a inner): false"
+                        ),
+                        stringify!($a),
+                        a1,
+                        a1,
+                        stringify!($predicate)
+                    ))
+                }
+            }
+            a => Err(format!(
+                concat!(
+                    "assertion failed: `assert_ok_and!(a, predicate)`\n",
+                    $crate::assertables_url_line!("assert_ok_and"),
+                    "            a label: `{}`,\n",
+                    "            a debug: `{:?}`,\n",
+                    "    predicate label: `{}`,\n",
+                    "            a is Ok: false"
+                ),
+                stringify!($a),
+                a,
+                stringify!($predicate)
+            )),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_ok_and_as_result {
+    #[test]
+    fn success() {
+        let a: Result<i8, i8> = Ok(2);
+        let actual = assert_ok_and_as_result!(a, |x| *x > 1);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure() {
+        let a: Result<i8, i8> = Ok(1);
+        let actual = assert_ok_and_as_result!(a, |x| *x > 1);
+        let message = concat!(
+            "assertion failed: `assert_ok_and!(a, predicate)`\n",
+            assertables_url_line!("assert_ok_and"),
+            "            a label: `a`,\n",
+            "            a debug: `Ok(1)`,\n",
+            "            a inner: `1`,\n",
+            "    predicate label: `|x| *x > 1`,\n",
+            " predicate(a inner): false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_err() {
+        let a: Result<i8, i8> = Err(2);
+        let actual = assert_ok_and_as_result!(a, |x| *x > 1);
+        let message = concat!(
+            "assertion failed: `assert_ok_and!(a, predicate)`\n",
+            assertables_url_line!("assert_ok_and"),
+            "            a label: `a`,\n",
+            "            a debug: `Err(2)`,\n",
+            "    predicate label: `|x| *x > 1`,\n",
+            "            a is Ok: false"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_value_without_copy() {
+        let a: Result<String, String> = Ok(String::from("alfa"));
+        let actual = assert_ok_and_as_result!(a, |s| s.starts_with('a'));
+        assert_eq!(actual.unwrap(), String::from("alfa"));
+    }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static A: AtomicU32 = AtomicU32::new(0);
+        static P: AtomicU32 = AtomicU32::new(0);
+        fn a() -> Result<i8, i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            Ok(2)
+        }
+        let actual = assert_ok_and_as_result!(a(), |x| {
+            P.fetch_add(1, Ordering::SeqCst);
+            *x > 1
+        });
+        assert_eq!(actual.unwrap(), 2);
+        assert_eq!(A.load(Ordering::SeqCst), 1);
+        assert_eq!(P.load(Ordering::SeqCst), 1);
+    }
+}
+
+/// Assert expression is Ok, and its value satisfies a predicate.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1) ⇒ predicate(a1) = true
+///
+/// * If true, return `a1`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Result<i8, i8> = Ok(2);
+/// assert_ok_and!(a, |x| *x > 1);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Result<i8, i8> = Ok(1);
+/// assert_ok_and!(a, |x| *x > 1);
+/// # });
+/// // assertion failed: `assert_ok_and!(a, predicate)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok_and.html
+/// //             a label: `a`,
+/// //             a debug: `Ok(1)`,
+/// //             a inner: `1`,
+/// //     predicate label: `|x| *x > 1`,
+/// //  predicate(a inner): false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_ok_and!(a, predicate)`\n",
+/// #     assertables_url_line!("assert_ok_and"),
+/// #     "            a label: `a`,\n",
+/// #     "            a debug: `Ok(1)`,\n",
+/// #     "            a inner: `1`,\n",
+/// #     "    predicate label: `|x| *x > 1`,\n",
+/// #     " predicate(a inner): false"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro fuses `let a1 = assert_ok!(a);` and `assert!(predicate(&a1));`.
+/// The predicate is called once, with a reference to the inner value,
+/// and on success the macro returns the inner value.
+///
+/// The failure message distinguishes the two ways to fail: if `a` is `Err`,
+/// then the message ends with "a is Ok: false", and if the predicate
+/// returns false, then the message ends with "predicate(a inner): false".
+///
+/// # Module macros
+///
+/// * [`assert_ok_and`](macro@crate::assert_ok_and)
+/// * [`assert_ok_and_as_result`](macro@crate::assert_ok_and_as_result)
+/// * [`debug_assert_ok_and`](macro@crate::debug_assert_ok_and)
+///
+#[macro_export]
+macro_rules! assert_ok_and {
+    ($a:expr, $predicate:expr $(,)?) => {{
+        match $crate::assert_ok_and_as_result!($a, $predicate) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $predicate:expr, $($message:tt)+) => {{
+        match $crate::assert_ok_and_as_result!($a, $predicate) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_ok_and {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a: Result<i8, i8> = Ok(2);
+        let actual = assert_ok_and!(a, |x| *x > 1);
+        assert_eq!(actual, 2);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a: Result<i8, i8> = Ok(1);
+            let _actual = assert_ok_and!(a, |x| *x > 1);
+        });
+        let message = concat!(
+            "assertion failed: `assert_ok_and!(a, predicate)`\n",
+            assertables_url_line!("assert_ok_and"),
+            "            a label: `a`,\n",
+            "            a debug: `Ok(1)`,\n",
+            "            a inner: `1`,\n",
+            "    predicate label: `|x| *x > 1`,\n",
+            " predicate(a inner): false"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert expression is Ok, and its value satisfies a predicate.
+///
+/// Pseudocode:<br>
+/// (a ⇒ Ok(a1) ⇒ a1) ⇒ predicate(a1) = true
+///
+/// This macro provides the same statements as [`assert_ok_and`](macro.assert_ok_and.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ok_and`](macro@crate::assert_ok_and)
+/// * [`assert_ok_and_as_result`](macro@crate::assert_ok_and_as_result)
+/// * [`debug_assert_ok_and`](macro@crate::debug_assert_ok_and)
+///
+#[macro_export]
+macro_rules! debug_assert_ok_and {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_ok_and!($($arg)*);
+        }
+    };
+}
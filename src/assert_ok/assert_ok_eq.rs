@@ -13,6 +13,10 @@
 //! assert_ok_eq!(a, b);
 //! ```
 //!
+//! The two results can have different Err types, because the macro compares
+//! only the Ok values, such as `Result<i32, String>` and `Result<i32, std::io::Error>`.
+//! The Ok values need `PartialEq`, and each result needs `Debug` for the message.
+//!
 //! # Module macros
 //!
 //! * [`assert_ok_eq`](macro@crate::assert_ok_eq)
@@ -49,48 +53,47 @@ macro_rules! assert_ok_eq_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_ok_eq!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok_eq.html\n",
+                                $crate::assertables_url_line!("assert_ok_eq"),
                                 " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
+                                " a debug: `Ok({:?})`,\n",
                                 " a inner: `{:?}`,\n",
                                 " b label: `{}`,\n",
-                                " b debug: `{:?}`,\n",
+                                " b debug: `Ok({:?})`,\n",
                                 " b inner: `{:?}`"
                             ),
                             stringify!($a),
-                            $a,
+                            a1,
                             a1,
                             stringify!($b),
-                            $b,
+                            b1,
                             b1
                         )
                     )
                 }
-            },
-            _ => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_ok_eq!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok_eq.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`",
-                        ),
-                        stringify!($a),
-                        $a,
-                        stringify!($b),
-                        $b
-                    )
-                )
             }
+            (a, b) => Err(
+                format!(
+                    concat!(
+                        "assertion failed: `assert_ok_eq!(a, b)`\n",
+                        $crate::assertables_url_line!("assert_ok_eq"),
+                        " a label: `{}`,\n",
+                        " a debug: `{:?}`,\n",
+                        " b label: `{}`,\n",
+                        " b debug: `{:?}`"
+                    ),
+                    stringify!($a),
+                    a,
+                    stringify!($b),
+                    b
+                )
+            ),
         }
     };
 }
 
 #[cfg(test)]
 mod test_assert_ok_eq_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn eq() {
@@ -107,7 +110,7 @@ mod test_assert_ok_eq_as_result {
         let actual = assert_ok_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_ok_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok_eq.html\n",
+            assertables_url_line!("assert_ok_eq"),
             " a label: `a`,\n",
             " a debug: `Ok(1)`,\n",
             " a inner: `1`,\n",
@@ -125,7 +128,7 @@ mod test_assert_ok_eq_as_result {
         let actual = assert_ok_eq_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_ok_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok_eq.html\n",
+            assertables_url_line!("assert_ok_eq"),
             " a label: `a`,\n",
             " a debug: `Err(1)`,\n",
             " b label: `b`,\n",
@@ -133,6 +136,74 @@ mod test_assert_ok_eq_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn eq_with_different_err_types() {
+        let a: Result<i32, String> = Ok(1);
+        let b: Result<i32, std::io::Error> = Ok(1);
+        let actual = assert_ok_eq_as_result!(a, b);
+        assert_eq!(actual.unwrap(), (1, 1));
+    }
+
+    #[test]
+    fn ne_with_different_err_types() {
+        let a: Result<i32, String> = Ok(1);
+        let b: Result<i32, std::io::Error> = Ok(2);
+        let actual = assert_ok_eq_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_ok_eq!(a, b)`\n",
+            assertables_url_line!("assert_ok_eq"),
+            " a label: `a`,\n",
+            " a debug: `Ok(1)`,\n",
+            " a inner: `1`,\n",
+            " b label: `b`,\n",
+            " b debug: `Ok(2)`,\n",
+            " b inner: `2`",
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn not_ok_with_different_err_types() {
+        let a: Result<i32, String> = Err(String::from("alfa"));
+        let b: Result<i32, std::io::Error> = Err(std::io::Error::other("bravo"));
+        let actual = assert_ok_eq_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_ok_eq!(a, b)`\n",
+            assertables_url_line!("assert_ok_eq"),
+            " a label: `a`,\n",
+            " a debug: `Err(\"alfa\")`,\n",
+            " b label: `b`,\n",
+            " b debug: `Err(Custom { kind: Other, error: \"bravo\" })`",
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        static B: AtomicU32 = AtomicU32::new(0);
+        fn ok_1() -> Result<i8, i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            Ok(1)
+        }
+        fn ok_2() -> Result<i8, i8> {
+            B.fetch_add(1, Ordering::SeqCst);
+            Ok(2)
+        }
+        fn err() -> Result<i8, i8> {
+            B.fetch_add(1, Ordering::SeqCst);
+            Err(1)
+        }
+        // Success
+        let _ = assert_ok_eq_as_result!(ok_1(), ok_1());
+        // Failure because inner values
+        let _ = assert_ok_eq_as_result!(ok_1(), ok_2());
+        // Failure because not ok
+        let _ = assert_ok_eq_as_result!(ok_1(), err());
+        assert_eq!(A.load(Ordering::SeqCst), 4);
+        assert_eq!(B.load(Ordering::SeqCst), 2);
+    }
 }
 
 /// Assert two expressions are Ok and their values are equal.
@@ -173,7 +244,7 @@ mod test_assert_ok_eq_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_ok_eq!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok_eq.html\n",
+/// #     assertables_url_line!("assert_ok_eq"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Ok(1)`,\n",
 /// #     " a inner: `1`,\n",
@@ -196,13 +267,13 @@ macro_rules! assert_ok_eq {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_ok_eq_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_ok_eq_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -228,7 +299,7 @@ mod test_assert_ok_eq {
         });
         let message = concat!(
             "assertion failed: `assert_ok_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok_eq.html\n",
+            assertables_url_line!("assert_ok_eq"),
             " a label: `a`,\n",
             " a debug: `Ok(1)`,\n",
             " a inner: `1`,\n",
@@ -255,7 +326,7 @@ mod test_assert_ok_eq {
         });
         let message = concat!(
             "assertion failed: `assert_ok_eq!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ok_eq.html\n",
+            assertables_url_line!("assert_ok_eq"),
             " a label: `a`,\n",
             " a debug: `Err(1)`,\n",
             " b label: `b`,\n",
@@ -306,7 +377,7 @@ mod test_assert_ok_eq {
 #[macro_export]
 macro_rules! debug_assert_ok_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_ok_eq!($($arg)*);
         }
     };
@@ -17,6 +17,10 @@
 //! * [`assert_ok_eq_x!(a, expr)`](macro@crate::assert_ok_eq_x) ≈ (a ⇒ Ok(a1) ⇒ a1) = expr
 //! * [`assert_ok_ne_x!(a, expr)`](macro@crate::assert_ok_ne_x) ≈ (a ⇒ Ok(a1) ⇒ a1) ≠ expr
 //!
+//! Check Ok(…) with a predicate:
+//!
+//! * [`assert_ok_and!(a, predicate)`](macro@crate::assert_ok_and) ≈ (a ⇒ Ok(a1) ⇒ a1) ⇒ predicate(a1)
+//!
 //! # Example
 //!
 //! ```rust
@@ -36,3 +40,6 @@ pub mod assert_ok_ne;
 // Compare expression
 pub mod assert_ok_eq_x;
 pub mod assert_ok_ne_x;
+
+// Check predicate
+pub mod assert_ok_and;
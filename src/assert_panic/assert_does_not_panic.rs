@@ -0,0 +1,287 @@
+//! Assert an expression does not panic.
+//!
+//! Pseudocode:<br>
+//! expr does not panic
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! assert_does_not_panic!(1 + 1);
+//! ```
+//!
+//! The expression can be any expression, such as a block `{ … }`.
+//! This macro is useful for regression tests that guard against a panic
+//! coming back, such as an index that was out of bounds.
+//!
+//! If the expression panics, then the failure message shows the panic
+//! message, and the panic location when it is available. The panic message
+//! is the panic payload as `Some(String)`, when the payload is a `&str` or a
+//! `String`, and `None` for any other payload.
+//!
+//! The macro evaluates the expression via a hidden wrapper around
+//! [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html).
+//! The wrapper silences the panic hook on the current thread while the
+//! expression runs, then restores it, so the captured panic stays out of
+//! the test output, while panics on other threads still print as usual.
+//!
+//! # Module macros
+//!
+//! * [`assert_does_not_panic`](macro@crate::assert_does_not_panic)
+//! * [`assert_does_not_panic_as_result`](macro@crate::assert_does_not_panic_as_result)
+//! * [`debug_assert_does_not_panic`](macro@crate::debug_assert_does_not_panic)
+
+/// Assert an expression does not panic.
+///
+/// Pseudocode:<br>
+/// expr does not panic
+///
+/// * If true, return Result `Ok(value)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_does_not_panic`](macro@crate::assert_does_not_panic)
+/// * [`assert_does_not_panic_as_result`](macro@crate::assert_does_not_panic_as_result)
+/// * [`debug_assert_does_not_panic`](macro@crate::debug_assert_does_not_panic)
+///
+#[macro_export]
+macro_rules! assert_does_not_panic_as_result {
+    ($expr:expr $(,)?) => {{
+        match $crate::assert_fn::catch_unwind_silent(|| $expr) {
+            Ok(a) => Ok(a),
+            Err(payload) => {
+                let location = match $crate::assert_fn::panic_location() {
+                    Some(location) => format!(",\n panic location: `{}`", location),
+                    None => String::new(),
+                };
+                Err(format!(
+                    concat!(
+                        "assertion failed: `assert_does_not_panic!(expr)`\n",
+                        $crate::assertables_url_line!("assert_does_not_panic"),
+                        "     expr label: `{}`,\n",
+                        "  panic message: `{:?}`{}"
+                    ),
+                    stringify!($expr),
+                    $crate::assert_fn::panic_payload_message(&*payload),
+                    location
+                ))
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_does_not_panic_as_result {
+    #[test]
+    fn success() {
+        let actual = assert_does_not_panic_as_result!(1 + 1);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure() {
+        let actual = assert_does_not_panic_as_result!(panic!("boom"));
+        let actual = actual.unwrap_err();
+        let prefix = concat!(
+            "assertion failed: `assert_does_not_panic!(expr)`\n",
+            assertables_url_line!("assert_does_not_panic"),
+            "     expr label: `panic!(\"boom\")`,\n",
+            "  panic message: `Some(\"boom\")`,\n",
+            " panic location: `",
+        );
+        assert!(actual.starts_with(prefix));
+        assert!(actual.contains(concat!(" panic location: `", file!(), ":")));
+    }
+
+    #[test]
+    fn success_with_block() {
+        let mut count = 0;
+        let actual = assert_does_not_panic_as_result!({
+            count += 1;
+            count
+        });
+        assert_eq!(actual.unwrap(), 1);
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn failure_with_non_str_payload() {
+        let actual = assert_does_not_panic_as_result!(std::panic::panic_any(42_i32));
+        let prefix = concat!(
+            "assertion failed: `assert_does_not_panic!(expr)`\n",
+            assertables_url_line!("assert_does_not_panic"),
+            "     expr label: `std::panic::panic_any(42_i32)`,\n",
+            "  panic message: `None`,\n",
+            " panic location: `"
+        );
+        let actual = actual.unwrap_err();
+        assert!(actual.starts_with(prefix));
+        assert!(actual.contains(concat!(" panic location: `", file!(), ":")));
+    }
+
+    #[test]
+    fn failure_with_block_that_panics_partway() {
+        let actual = assert_does_not_panic_as_result!({
+            let v: Vec<i32> = Vec::new();
+            v[0]
+        });
+        let actual = actual.unwrap_err();
+        assert!(actual.contains("  panic message: `Some(\"index out of bounds"));
+        assert!(actual.contains(concat!(" panic location: `", file!(), ":")));
+    }
+}
+
+/// Assert an expression does not panic.
+///
+/// Pseudocode:<br>
+/// expr does not panic
+///
+/// * If true, return `value`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// assert_does_not_panic!(1 + 1);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// assert_does_not_panic!(panic!("boom"));
+/// # });
+/// // assertion failed: `assert_does_not_panic!(expr)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_does_not_panic.html
+/// //      expr label: `panic!(\"boom\")`,
+/// //   panic message: `Some(\"boom\")`,
+/// //  panic location: `src/main.rs:11:1`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let prefix = concat!(
+/// #     "assertion failed: `assert_does_not_panic!(expr)`\n",
+/// #     assertables_url_line!("assert_does_not_panic"),
+/// #     "     expr label: `panic!(\"boom\")`,\n",
+/// #     "  panic message: `Some(\"boom\")`,\n",
+/// #     " panic location: `",
+/// # );
+/// # assert!(actual.starts_with(prefix));
+/// # }
+/// ```
+///
+/// The expression can be any expression, such as a block `{ … }`.
+/// This macro is useful for regression tests that guard against a panic
+/// coming back, such as an index that was out of bounds.
+///
+/// If the expression panics, then the failure message shows the panic
+/// message, and the panic location when it is available. The panic message
+/// is the panic payload as `Some(String)`, when the payload is a `&str` or a
+/// `String`, and `None` for any other payload.
+///
+/// The macro evaluates the expression via a hidden wrapper around
+/// [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html).
+/// The wrapper silences the panic hook on the current thread while the
+/// expression runs, then restores it, so the captured panic stays out of
+/// the test output, while panics on other threads still print as usual.
+///
+/// # Module macros
+///
+/// * [`assert_does_not_panic`](macro@crate::assert_does_not_panic)
+/// * [`assert_does_not_panic_as_result`](macro@crate::assert_does_not_panic_as_result)
+/// * [`debug_assert_does_not_panic`](macro@crate::debug_assert_does_not_panic)
+///
+#[macro_export]
+macro_rules! assert_does_not_panic {
+    ($expr:expr $(,)?) => {{
+        match $crate::assert_does_not_panic_as_result!($expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($expr:expr, $($message:tt)+) => {{
+        match $crate::assert_does_not_panic_as_result!($expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_does_not_panic {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let actual = assert_does_not_panic!(1 + 1);
+        assert_eq!(actual, 2);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let _actual = assert_does_not_panic!(panic!("boom"));
+        });
+        let actual = result
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap()
+            .to_string();
+        let prefix = concat!(
+            "assertion failed: `assert_does_not_panic!(expr)`\n",
+            assertables_url_line!("assert_does_not_panic"),
+            "     expr label: `panic!(\"boom\")`,\n",
+            "  panic message: `Some(\"boom\")`,\n",
+            " panic location: `",
+        );
+        assert!(actual.starts_with(prefix));
+        assert!(actual.contains(concat!(" panic location: `", file!(), ":")));
+    }
+}
+
+/// Assert an expression does not panic.
+///
+/// Pseudocode:<br>
+/// expr does not panic
+///
+/// This macro provides the same statements as [`assert_does_not_panic`](macro.assert_does_not_panic.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_does_not_panic`](macro@crate::assert_does_not_panic)
+/// * [`assert_does_not_panic_as_result`](macro@crate::assert_does_not_panic_as_result)
+/// * [`debug_assert_does_not_panic`](macro@crate::debug_assert_does_not_panic)
+///
+#[macro_export]
+macro_rules! debug_assert_does_not_panic {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_does_not_panic!($($arg)*);
+        }
+    };
+}
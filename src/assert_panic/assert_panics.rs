@@ -0,0 +1,280 @@
+//! Assert an expression panics.
+//!
+//! Pseudocode:<br>
+//! expr panics
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! assert_panics!(panic!("boom"));
+//! ```
+//!
+//! The expression can be any expression, such as a block `{ … }`.
+//!
+//! The message is the panic payload as `Some(String)`, when the payload is
+//! a `&str`, such as from `panic!("…")`, or a `String`, such as from
+//! `panic!("{}", …)`. Any other payload, such as from
+//! [`std::panic::panic_any`], has no message, so the message is `None`.
+//!
+//! The macro evaluates the expression via a hidden wrapper around
+//! [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html).
+//! The wrapper silences the panic hook on the current thread while the
+//! expression runs, then restores it, so the expected panic stays out of
+//! the test output, while panics on other threads still print as usual.
+//!
+//! If the expression does not panic, then the failure message shows the
+//! value of the expression, so the value must implement `Debug`.
+//!
+//! # Module macros
+//!
+//! * [`assert_panics`](macro@crate::assert_panics)
+//! * [`assert_panics_as_result`](macro@crate::assert_panics_as_result)
+//! * [`debug_assert_panics`](macro@crate::debug_assert_panics)
+
+/// Assert an expression panics.
+///
+/// Pseudocode:<br>
+/// expr panics
+///
+/// * If true, return Result `Ok(message)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_panics`](macro@crate::assert_panics)
+/// * [`assert_panics_as_result`](macro@crate::assert_panics_as_result)
+/// * [`debug_assert_panics`](macro@crate::debug_assert_panics)
+///
+#[macro_export]
+macro_rules! assert_panics_as_result {
+    ($expr:expr $(,)?) => {{
+        match $crate::assert_fn::catch_unwind_silent(|| $expr) {
+            Err(payload) => Ok($crate::assert_fn::panic_payload_message(&*payload)),
+            Ok(a) => Err(format!(
+                concat!(
+                    "assertion failed: `assert_panics!(expr)`\n",
+                    $crate::assertables_url_line!("assert_panics"),
+                    " expr label: `{}`,\n",
+                    " expr debug: `{:?}`"
+                ),
+                stringify!($expr),
+                a
+            )),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_panics_as_result {
+    #[test]
+    fn success() {
+        let actual = assert_panics_as_result!(panic!("boom"));
+        assert_eq!(actual.unwrap(), Some(String::from("boom")));
+    }
+
+    #[test]
+    fn failure() {
+        let actual = assert_panics_as_result!(1 + 1);
+        let message = concat!(
+            "assertion failed: `assert_panics!(expr)`\n",
+            assertables_url_line!("assert_panics"),
+            " expr label: `1 + 1`,\n",
+            " expr debug: `2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_string_payload() {
+        let i = 1;
+        let actual = assert_panics_as_result!(panic!("boom {}", i));
+        assert_eq!(actual.unwrap(), Some(String::from("boom 1")));
+    }
+
+    #[test]
+    fn success_with_non_str_payload() {
+        let actual = assert_panics_as_result!(std::panic::panic_any(42_i32));
+        assert_eq!(actual.unwrap(), None);
+    }
+
+    #[test]
+    fn success_with_block_that_panics_partway() {
+        let mut count = 0;
+        let actual = assert_panics_as_result!({
+            count += 1;
+            let v: Vec<i32> = Vec::new();
+            v[0]
+        });
+        assert!(actual.unwrap().unwrap().contains("index out of bounds"));
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn failure_with_block() {
+        let actual = assert_panics_as_result!({
+            let a = 1;
+            a + 1
+        });
+        let message = concat!(
+            "assertion failed: `assert_panics!(expr)`\n",
+            assertables_url_line!("assert_panics"),
+            " expr label: `{ let a = 1; a + 1 }`,\n",
+            " expr debug: `2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert an expression panics.
+///
+/// Pseudocode:<br>
+/// expr panics
+///
+/// * If true, return `message`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// assert_panics!(panic!("boom"));
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// assert_panics!(1 + 1);
+/// # });
+/// // assertion failed: `assert_panics!(expr)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_panics.html
+/// //  expr label: `1 + 1`,
+/// //  expr debug: `2`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_panics!(expr)`\n",
+/// #     assertables_url_line!("assert_panics"),
+/// #     " expr label: `1 + 1`,\n",
+/// #     " expr debug: `2`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The expression can be any expression, such as a block `{ … }`.
+///
+/// The message is the panic payload as `Some(String)`, when the payload is
+/// a `&str`, such as from `panic!("…")`, or a `String`, such as from
+/// `panic!("{}", …)`. Any other payload, such as from
+/// [`std::panic::panic_any`], has no message, so the message is `None`.
+///
+/// The macro evaluates the expression via a hidden wrapper around
+/// [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html).
+/// The wrapper silences the panic hook on the current thread while the
+/// expression runs, then restores it, so the expected panic stays out of
+/// the test output, while panics on other threads still print as usual.
+///
+/// If the expression does not panic, then the failure message shows the
+/// value of the expression, so the value must implement `Debug`.
+///
+/// # Module macros
+///
+/// * [`assert_panics`](macro@crate::assert_panics)
+/// * [`assert_panics_as_result`](macro@crate::assert_panics_as_result)
+/// * [`debug_assert_panics`](macro@crate::debug_assert_panics)
+///
+#[macro_export]
+macro_rules! assert_panics {
+    ($expr:expr $(,)?) => {{
+        match $crate::assert_panics_as_result!($expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($expr:expr, $($message:tt)+) => {{
+        match $crate::assert_panics_as_result!($expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_panics {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let actual = assert_panics!(panic!("boom"));
+        assert_eq!(actual, Some(String::from("boom")));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let _actual = assert_panics!(1 + 1);
+        });
+        let message = concat!(
+            "assertion failed: `assert_panics!(expr)`\n",
+            assertables_url_line!("assert_panics"),
+            " expr label: `1 + 1`,\n",
+            " expr debug: `2`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an expression panics.
+///
+/// Pseudocode:<br>
+/// expr panics
+///
+/// This macro provides the same statements as [`assert_panics`](macro.assert_panics.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_panics`](macro@crate::assert_panics)
+/// * [`assert_panics_as_result`](macro@crate::assert_panics_as_result)
+/// * [`debug_assert_panics`](macro@crate::debug_assert_panics)
+///
+#[macro_export]
+macro_rules! debug_assert_panics {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_panics!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,328 @@
+//! Assert an expression panics with a message that contains a substring.
+//!
+//! Pseudocode:<br>
+//! expr panics with a message that contains substr
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let substr = "boom";
+//! assert_panics_message_contains!(panic!("boom and bust"), substr);
+//! ```
+//!
+//! The panic message is the panic payload, when the payload is a `&str`,
+//! such as from `panic!("…")`, or a `String`, such as from `panic!("{}", …)`.
+//! Any other payload has no message, so the assertion fails.
+//!
+//! The macro evaluates the expression via a hidden wrapper around
+//! [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html).
+//! The wrapper silences the panic hook on the current thread while the
+//! expression runs, then restores it, so the expected panic stays out of
+//! the test output, while panics on other threads still print as usual.
+//!
+//! If the expression does not panic, then the failure message shows the
+//! value of the expression, so the value must implement `Debug`.
+//!
+//! # Module macros
+//!
+//! * [`assert_panics_message_contains`](macro@crate::assert_panics_message_contains)
+//! * [`assert_panics_message_contains_as_result`](macro@crate::assert_panics_message_contains_as_result)
+//! * [`debug_assert_panics_message_contains`](macro@crate::debug_assert_panics_message_contains)
+
+/// Assert an expression panics with a message that contains a substring.
+///
+/// Pseudocode:<br>
+/// expr panics with a message that contains substr
+///
+/// * If true, return Result `Ok(message)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_panics_message_contains`](macro@crate::assert_panics_message_contains)
+/// * [`assert_panics_message_contains_as_result`](macro@crate::assert_panics_message_contains_as_result)
+/// * [`debug_assert_panics_message_contains`](macro@crate::debug_assert_panics_message_contains)
+///
+#[macro_export]
+macro_rules! assert_panics_message_contains_as_result {
+    ($expr:expr, $substr:expr $(,)?) => {{
+        match (&$substr) {
+            substr => {
+                match $crate::assert_fn::catch_unwind_silent(|| $expr) {
+                    Err(payload) => {
+                        match $crate::assert_fn::panic_payload_message(&*payload) {
+                            Some(message) if message.contains(substr) => Ok(message),
+                            message => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_panics_message_contains!(expr, substr)`\n",
+                                            $crate::assertables_url_line!("assert_panics_message_contains"),
+                                            "    expr label: `{}`,\n",
+                                            "  substr label: `{}`,\n",
+                                            "  substr debug: `{:?}`,\n",
+                                            " panic message: `{:?}`"
+                                        ),
+                                        stringify!($expr),
+                                        stringify!($substr),
+                                        substr,
+                                        message
+                                    )
+                                )
+                            }
+                        }
+                    }
+                    Ok(a) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_panics_message_contains!(expr, substr)`\n",
+                                    $crate::assertables_url_line!("assert_panics_message_contains"),
+                                    "    expr label: `{}`,\n",
+                                    "  substr label: `{}`,\n",
+                                    "  substr debug: `{:?}`,\n",
+                                    "    expr debug: `{:?}`"
+                                ),
+                                stringify!($expr),
+                                stringify!($substr),
+                                substr,
+                                a
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_panics_message_contains_as_result {
+    #[test]
+    fn success() {
+        let substr = "boom";
+        let actual = assert_panics_message_contains_as_result!(panic!("boom and bust"), substr);
+        assert_eq!(actual.unwrap(), String::from("boom and bust"));
+    }
+
+    #[test]
+    fn failure() {
+        let substr = "boom";
+        let actual = assert_panics_message_contains_as_result!(panic!("bust"), substr);
+        let message = concat!(
+            "assertion failed: `assert_panics_message_contains!(expr, substr)`\n",
+            assertables_url_line!("assert_panics_message_contains"),
+            "    expr label: `panic!(\"bust\")`,\n",
+            "  substr label: `substr`,\n",
+            "  substr debug: `\"boom\"`,\n",
+            " panic message: `Some(\"bust\")`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_string_payload() {
+        let i = 1;
+        let substr = "boom";
+        let actual = assert_panics_message_contains_as_result!(panic!("{} boom", i), substr);
+        assert_eq!(actual.unwrap(), String::from("1 boom"));
+    }
+
+    #[test]
+    fn failure_with_non_str_payload() {
+        let substr = "boom";
+        let actual =
+            assert_panics_message_contains_as_result!(std::panic::panic_any(42_i32), substr);
+        let message = concat!(
+            "assertion failed: `assert_panics_message_contains!(expr, substr)`\n",
+            assertables_url_line!("assert_panics_message_contains"),
+            "    expr label: `std::panic::panic_any(42_i32)`,\n",
+            "  substr label: `substr`,\n",
+            "  substr debug: `\"boom\"`,\n",
+            " panic message: `None`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_with_no_panic() {
+        let substr = "boom";
+        let actual = assert_panics_message_contains_as_result!(
+            {
+                let a = 1;
+                a + 1
+            },
+            substr
+        );
+        let message = concat!(
+            "assertion failed: `assert_panics_message_contains!(expr, substr)`\n",
+            assertables_url_line!("assert_panics_message_contains"),
+            "    expr label: `{ let a = 1; a + 1 }`,\n",
+            "  substr label: `substr`,\n",
+            "  substr debug: `\"boom\"`,\n",
+            "    expr debug: `2`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert an expression panics with a message that contains a substring.
+///
+/// Pseudocode:<br>
+/// expr panics with a message that contains substr
+///
+/// * If true, return `message`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let substr = "boom";
+/// assert_panics_message_contains!(panic!("boom and bust"), substr);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let substr = "boom";
+/// assert_panics_message_contains!(panic!("bust"), substr);
+/// # });
+/// // assertion failed: `assert_panics_message_contains!(expr, substr)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_panics_message_contains.html
+/// //     expr label: `panic!(\"bust\")`,
+/// //   substr label: `substr`,
+/// //   substr debug: `\"boom\"`,
+/// //  panic message: `Some(\"bust\")`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_panics_message_contains!(expr, substr)`\n",
+/// #     assertables_url_line!("assert_panics_message_contains"),
+/// #     "    expr label: `panic!(\"bust\")`,\n",
+/// #     "  substr label: `substr`,\n",
+/// #     "  substr debug: `\"boom\"`,\n",
+/// #     " panic message: `Some(\"bust\")`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The panic message is the panic payload, when the payload is a `&str`,
+/// such as from `panic!("…")`, or a `String`, such as from `panic!("{}", …)`.
+/// Any other payload has no message, so the assertion fails.
+///
+/// The macro evaluates the expression via a hidden wrapper around
+/// [`std::panic::catch_unwind`](https://doc.rust-lang.org/std/panic/fn.catch_unwind.html).
+/// The wrapper silences the panic hook on the current thread while the
+/// expression runs, then restores it, so the expected panic stays out of
+/// the test output, while panics on other threads still print as usual.
+///
+/// If the expression does not panic, then the failure message shows the
+/// value of the expression, so the value must implement `Debug`.
+///
+/// # Module macros
+///
+/// * [`assert_panics_message_contains`](macro@crate::assert_panics_message_contains)
+/// * [`assert_panics_message_contains_as_result`](macro@crate::assert_panics_message_contains_as_result)
+/// * [`debug_assert_panics_message_contains`](macro@crate::debug_assert_panics_message_contains)
+///
+#[macro_export]
+macro_rules! assert_panics_message_contains {
+    ($expr:expr, $substr:expr $(,)?) => {{
+        match $crate::assert_panics_message_contains_as_result!($expr, $substr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($expr:expr, $substr:expr, $($message:tt)+) => {{
+        match $crate::assert_panics_message_contains_as_result!($expr, $substr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_panics_message_contains {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let substr = "boom";
+        let actual = assert_panics_message_contains!(panic!("boom and bust"), substr);
+        assert_eq!(actual, String::from("boom and bust"));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let substr = "boom";
+            let _actual = assert_panics_message_contains!(panic!("bust"), substr);
+        });
+        let message = concat!(
+            "assertion failed: `assert_panics_message_contains!(expr, substr)`\n",
+            assertables_url_line!("assert_panics_message_contains"),
+            "    expr label: `panic!(\"bust\")`,\n",
+            "  substr label: `substr`,\n",
+            "  substr debug: `\"boom\"`,\n",
+            " panic message: `Some(\"bust\")`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an expression panics with a message that contains a substring.
+///
+/// Pseudocode:<br>
+/// expr panics with a message that contains substr
+///
+/// This macro provides the same statements as [`assert_panics_message_contains`](macro.assert_panics_message_contains.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_panics_message_contains`](macro@crate::assert_panics_message_contains)
+/// * [`assert_panics_message_contains_as_result`](macro@crate::assert_panics_message_contains_as_result)
+/// * [`debug_assert_panics_message_contains`](macro@crate::debug_assert_panics_message_contains)
+///
+#[macro_export]
+macro_rules! debug_assert_panics_message_contains {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_panics_message_contains!($($arg)*);
+        }
+    };
+}
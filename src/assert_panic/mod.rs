@@ -0,0 +1,34 @@
+//! Assert for panics.
+//!
+//! These macros help check whether any expression panics, such as a block
+//! `{ … }`, rather than only a function call, as with
+//! [`assert_fn_panics`](macro@crate::assert_fn_panics).
+//!
+//! Check an expression panics:
+//!
+//! * [`assert_panics!(expr)`](macro@crate::assert_panics) ≈ expr panics
+//! * [`assert_panics_message_contains!(expr, substr)`](macro@crate::assert_panics_message_contains) ≈ expr panics with a message that contains substr
+//!
+//! Check an expression does not panic:
+//!
+//! * [`assert_does_not_panic!(expr)`](macro@crate::assert_does_not_panic) ≈ expr does not panic
+//!
+//! Each macro keeps the caught panic out of the test output, by using the
+//! same hidden wrapper around `catch_unwind` as the `assert_fn` macros.
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let v: Vec<i32> = Vec::new();
+//! assert_panics!(v[0]);
+//! assert_panics_message_contains!({ v[0] }, "index out of bounds");
+//!
+//! let v: Vec<i32> = vec![1];
+//! assert_does_not_panic!(v[0]);
+//! ```
+
+pub mod assert_does_not_panic;
+pub mod assert_panics;
+pub mod assert_panics_message_contains;
@@ -14,6 +14,20 @@
 //! assert_pending!(a);
 //! ```
 //!
+//! The item can be any type with a method `is_pending()`, such as a
+//! custom type that tracks a background job:
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! #[derive(Debug)]
+//! struct Job { done: bool }
+//! impl Job { fn is_pending(&self) -> bool { !self.done } }
+//!
+//! let a = Job { done: false };
+//! assert_pending!(a);
+//! ```
+//!
 //! # Module macros
 //!
 //! * [`assert_pending`](macro@crate::assert_pending)
@@ -25,6 +39,8 @@
 /// Pseudocode:<br>
 /// a is Pending
 ///
+/// The item can be a `Poll`, or any type with a method `is_pending()`.
+///
 /// * If true, return Result `Ok(())`.
 ///
 /// * Otherwise, return Result `Err(message)`.
@@ -43,24 +59,21 @@ macro_rules! assert_pending_as_result {
     ($a:expr $(,)?) => {{
         match (&$a) {
             a => {
-                match (a) {
-                    Pending => {
-                        Ok(())
-                    },
-                    _ => {
-                        Err(
-                            format!(
-                                concat!(
-                                    "assertion failed: `assert_pending!(a)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_pending.html\n",
-                                    " a label: `{}`,\n",
-                                    " a debug: `{:?}`",
-                                ),
-                                stringify!($a),
-                                a
-                            )
+                if a.is_pending() {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_pending!(a)`\n",
+                                $crate::assertables_url_line!("assert_pending"),
+                                " a label: `{}`,\n",
+                                " a debug: `{:?}`",
+                            ),
+                            stringify!($a),
+                            a
                         )
-                    }
+                    )
                 }
             }
         }
@@ -85,12 +98,54 @@ mod test_assert_pending_as_result {
         let actual = assert_pending_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_pending!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_pending.html\n",
+            assertables_url_line!("assert_pending"),
             " a label: `a`,\n",
             " a debug: `Ready(1)`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[derive(Debug)]
+    struct Job {
+        done: bool,
+    }
+
+    impl Job {
+        fn is_pending(&self) -> bool {
+            !self.done
+        }
+    }
+
+    #[test]
+    fn custom_type_success() {
+        let a = Job { done: false };
+        let actual = assert_pending_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn custom_type_failure() {
+        let a = Job { done: true };
+        let actual = assert_pending_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_pending!(a)`\n",
+            assertables_url_line!("assert_pending"),
+            " a label: `a`,\n",
+            " a debug: `Job { done: true }`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+#[cfg(test)]
+mod test_assert_pending_as_result_without_poll_import {
+
+    #[test]
+    fn failure() {
+        let a: std::task::Poll<i8> = std::task::Poll::Ready(1);
+        let actual = assert_pending_as_result!(a);
+        assert!(actual.is_err());
+    }
 }
 
 /// Assert an expression is Pending.
@@ -126,7 +181,7 @@ mod test_assert_pending_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_pending!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_pending.html\n",
+/// #     assertables_url_line!("assert_pending"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Ready(1)`",
 /// # );
@@ -145,13 +200,13 @@ macro_rules! assert_pending {
     ($a:expr $(,)?) => {{
         match $crate::assert_pending_as_result!($a) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_pending_as_result!($a) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -177,7 +232,7 @@ mod test_assert_pending {
         });
         let message = concat!(
             "assertion failed: `assert_pending!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_pending.html\n",
+            assertables_url_line!("assert_pending"),
             " a label: `a`,\n",
             " a debug: `Ready(1)`"
         );
@@ -226,7 +281,7 @@ mod test_assert_pending {
 #[macro_export]
 macro_rules! debug_assert_pending {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_pending!($($arg)*);
         }
     };
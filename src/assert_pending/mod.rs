@@ -2,6 +2,9 @@
 //!
 //! These macros help compare Pending items, such as `::std::Poll::Pending` or similar.
 //!
+//! The macro [`assert_pending`](macro@crate::assert_pending) accepts any
+//! type with a method `is_pending()`, such as a custom pollable type.
+//!
 //! Assert expression is Pending:
 //!
 //! * [`assert_pending!(a)`](macro@crate::assert_pending)
@@ -58,7 +58,7 @@ macro_rules! assert_program_args_stderr_eq_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stderr_eq!(a_program, a_args, b_program, b_args)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_eq.html\n",
+                                        $crate::assertables_url_line!("assert_program_args_stderr_eq"),
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -89,7 +89,7 @@ macro_rules! assert_program_args_stderr_eq_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stderr_eq!(a_program, a_args, b_program, b_args)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_eq.html\n",
+                                    $crate::assertables_url_line!("assert_program_args_stderr_eq"),
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -147,7 +147,7 @@ mod test_assert_program_args_stderr_eq_as_result {
             assert_program_args_stderr_eq_as_result!(&a_program, &a_args, &b_program, &b_args);
         let message = concat!(
             "assertion failed: `assert_program_args_stderr_eq!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_eq.html\n",
+            assertables_url_line!("assert_program_args_stderr_eq"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stderr\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -172,7 +172,7 @@ mod test_assert_program_args_stderr_eq_as_result {
             assert_program_args_stderr_eq_as_result!(&a_program, &a_args, &b_program, &b_args);
         let message = concat!(
             "assertion failed: `assert_program_args_stderr_eq!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_eq.html\n",
+            assertables_url_line!("assert_program_args_stderr_eq"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stderr\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -234,7 +234,7 @@ mod test_assert_program_args_stderr_eq_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_program_args_stderr_eq!(a_program, a_args, b_program, b_args)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_eq.html\n",
+/// #     assertables_url_line!("assert_program_args_stderr_eq"),
 /// #     " a_program label: `&a_program`,\n",
 /// #     " a_program debug: `\"bin/printf-stderr\"`,\n",
 /// #     "    a_args label: `&a_args`,\n",
@@ -261,13 +261,13 @@ macro_rules! assert_program_args_stderr_eq {
     ($a_program:expr, $a_args:expr, $b_program:expr, $b_args:expr $(,)?) => {{
         match $crate::assert_program_args_stderr_eq_as_result!($a_program, $a_args, $b_program, $b_args) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_program:expr, $a_args:expr, $b_program:expr, $($message:tt)+) => {{
         match $crate::assert_program_args_stderr_eq_as_result!($a_program, $a_args, $b_program, $b_args) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -300,7 +300,7 @@ mod test_assert_program_args_stderr_eq {
         });
         let message = concat!(
             "assertion failed: `assert_program_args_stderr_eq!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_eq.html\n",
+            assertables_url_line!("assert_program_args_stderr_eq"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stderr\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -333,7 +333,7 @@ mod test_assert_program_args_stderr_eq {
         });
         let message = concat!(
             "assertion failed: `assert_program_args_stderr_eq!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_eq.html\n",
+            assertables_url_line!("assert_program_args_stderr_eq"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stderr\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -390,7 +390,7 @@ mod test_assert_program_args_stderr_eq {
 #[macro_export]
 macro_rules! debug_assert_program_args_stderr_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_program_args_stderr_eq!($($arg)*);
         }
     };
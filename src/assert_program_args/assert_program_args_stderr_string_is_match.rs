@@ -54,7 +54,7 @@ macro_rules! assert_program_args_stderr_string_is_match_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stderr_string_is_match!(a_program, b_matcher)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_string_is_match.html\n",
+                                        $crate::assertables_url_line!("assert_program_args_stderr_string_is_match"),
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -81,7 +81,7 @@ macro_rules! assert_program_args_stderr_string_is_match_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stderr_string_is_match!(a_program, b_matcher)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_string_is_match.html\n",
+                                    $crate::assertables_url_line!("assert_program_args_stderr_string_is_match"),
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -127,7 +127,7 @@ mod test_assert_program_args_stderr_string_is_match_as_result {
         let actual = assert_program_args_stderr_string_is_match_as_result!(&a_program, &a_args, b);
         let message = concat!(
             "assertion failed: `assert_program_args_stderr_string_is_match!(a_program, b_matcher)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_string_is_match.html\n",
+            assertables_url_line!("assert_program_args_stderr_string_is_match"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stderr\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -184,7 +184,7 @@ mod test_assert_program_args_stderr_string_is_match_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_program_args_stderr_string_is_match!(a_program, b_matcher)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_string_is_match.html\n",
+/// #     assertables_url_line!("assert_program_args_stderr_string_is_match"),
 /// #     " a_program label: `&program`,\n",
 /// #     " a_program debug: `\"bin/printf-stderr\"`,\n",
 /// #     "    a_args label: `&args`,\n",
@@ -209,13 +209,13 @@ macro_rules! assert_program_args_stderr_string_is_match {
     ($a_program:expr, $a_args:expr, $matcher:expr $(,)?) => {{
         match $crate::assert_program_args_stderr_string_is_match_as_result!($a_program, $a_args, $matcher) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_program:expr, $a_args:expr, $matcher:expr, $($message:tt)+) => {{
         match $crate::assert_program_args_stderr_string_is_match_as_result!($a_program, $a_args, $matcher) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -244,7 +244,7 @@ mod test_assert_program_args_stderr_string_is_match {
         });
         let message = concat!(
             "assertion failed: `assert_program_args_stderr_string_is_match!(a_program, b_matcher)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stderr_string_is_match.html\n",
+            assertables_url_line!("assert_program_args_stderr_string_is_match"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stderr\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -299,7 +299,7 @@ mod test_assert_program_args_stderr_string_is_match {
 #[macro_export]
 macro_rules! debug_assert_program_args_stderr_string_is_match {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_program_args_stderr_string_is_match!($($arg)*);
         }
     };
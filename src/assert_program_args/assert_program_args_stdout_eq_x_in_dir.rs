@@ -0,0 +1,346 @@
+//! Assert a command (built with program and args, run in a directory) stdout is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! (a_program + a_args in a_dir ⇒ command ⇒ stdout) = (b_expr)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let program = "cat";
+//! let args = ["printf-stdout"];
+//! let dir = "bin";
+//! let bytes = b"#!/bin/sh\nprintf \"$@\"\n".to_vec();
+//! assert_program_args_stdout_eq_x_in_dir!(program, args, dir, bytes);
+//! ```
+//!
+//! This macro is like
+//! [`assert_program_args_stdout_eq_x`](macro@crate::assert_program_args_stdout_eq_x),
+//! and also sets the command working directory, by calling
+//! [`Command::current_dir`](https://doc.rust-lang.org/std/process/struct.Command.html#method.current_dir),
+//! which helps for testing a program that resolves relative paths.
+//! The failure message shows the working directory, so a failure is reproducible.
+//!
+//! A relative program path, such as `bin/example`, may resolve relative
+//! to the working directory or not, depending on the platform, so prefer
+//! a program name on the `PATH`, or an absolute program path.
+//!
+//! # Module macros
+//!
+//! * [`assert_program_args_stdout_eq_x_in_dir`](macro@crate::assert_program_args_stdout_eq_x_in_dir)
+//! * [`assert_program_args_stdout_eq_x_in_dir_as_result`](macro@crate::assert_program_args_stdout_eq_x_in_dir_as_result)
+//! * [`debug_assert_program_args_stdout_eq_x_in_dir`](macro@crate::debug_assert_program_args_stdout_eq_x_in_dir)
+
+/// Assert a command (built with program and args, run in a directory) stdout is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a_program + a_args in a_dir ⇒ command ⇒ stdout) = (b_expr)
+///
+/// * If true, return Result `Ok(stdout)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_program_args_stdout_eq_x_in_dir`](macro@crate::assert_program_args_stdout_eq_x_in_dir)
+/// * [`assert_program_args_stdout_eq_x_in_dir_as_result`](macro@crate::assert_program_args_stdout_eq_x_in_dir_as_result)
+/// * [`debug_assert_program_args_stdout_eq_x_in_dir`](macro@crate::debug_assert_program_args_stdout_eq_x_in_dir)
+///
+#[macro_export]
+macro_rules! assert_program_args_stdout_eq_x_in_dir_as_result {
+    ($a_program:expr, $a_args:expr, $a_dir:expr, $b_expr:expr $(,)?) => {{
+        match ($a_program, $a_args, &$a_dir, &$b_expr) {
+            (a_program, a_args, a_dir, b_expr) => {
+                match assert_program_args_in_dir_impl_prep!(a_program, a_args, a_dir) {
+                    Ok(a_output) => {
+                        let a = a_output.stdout;
+                        if a.eq(b_expr) {
+                            Ok(a)
+                        } else {
+                            Err(
+                                format!(
+                                    concat!(
+                                        "assertion failed: `assert_program_args_stdout_eq_x_in_dir!(a_program, a_args, a_dir, b_expr)`\n",
+                                        $crate::assertables_url_line!("assert_program_args_stdout_eq_x_in_dir"),
+                                        " a_program label: `{}`,\n",
+                                        " a_program debug: `{:?}`,\n",
+                                        "    a_args label: `{}`,\n",
+                                        "    a_args debug: `{:?}`,\n",
+                                        "     a_dir label: `{}`,\n",
+                                        "     a_dir debug: `{:?}`,\n",
+                                        "    b_expr label: `{}`,\n",
+                                        "    b_expr debug: `{:?}`,\n",
+                                        "               a: `{:?}`,\n",
+                                        "               b: `{:?}`"
+                                    ),
+                                    stringify!($a_program),
+                                    a_program,
+                                    stringify!($a_args),
+                                    a_args,
+                                    stringify!($a_dir),
+                                    a_dir,
+                                    stringify!($b_expr),
+                                    b_expr,
+                                    a,
+                                    b_expr
+                                )
+                            )
+                        }
+                    },
+                    Err(err) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_program_args_stdout_eq_x_in_dir!(a_program, a_args, a_dir, b_expr)`\n",
+                                    $crate::assertables_url_line!("assert_program_args_stdout_eq_x_in_dir"),
+                                    " a_program label: `{}`,\n",
+                                    " a_program debug: `{:?}`,\n",
+                                    "    a_args label: `{}`,\n",
+                                    "    a_args debug: `{:?}`,\n",
+                                    "     a_dir label: `{}`,\n",
+                                    "     a_dir debug: `{:?}`,\n",
+                                    "    b_expr label: `{}`,\n",
+                                    "    b_expr debug: `{:?}`,\n",
+                                    "             err: `{:?}`"
+                                ),
+                                stringify!($a_program),
+                                a_program,
+                                stringify!($a_args),
+                                a_args,
+                                stringify!($a_dir),
+                                a_dir,
+                                stringify!($b_expr),
+                                b_expr,
+                                err
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_program_args_stdout_eq_x_in_dir_as_result {
+    #[test]
+    fn success() {
+        let program = "cat";
+        let args = ["printf-stdout"];
+        let dir = "bin";
+        let bytes = b"#!/bin/sh\nprintf \"$@\"\n".to_vec();
+        let actual = assert_program_args_stdout_eq_x_in_dir_as_result!(program, args, dir, bytes);
+        assert_eq!(actual.unwrap(), b"#!/bin/sh\nprintf \"$@\"\n".to_vec());
+    }
+
+    #[test]
+    fn failure() {
+        let program = "cat";
+        let args = ["printf-stdout"];
+        let dir = "bin";
+        let bytes = b"zz".to_vec();
+        let actual = assert_program_args_stdout_eq_x_in_dir_as_result!(program, args, dir, bytes);
+        let message = concat!(
+            "assertion failed: `assert_program_args_stdout_eq_x_in_dir!(a_program, a_args, a_dir, b_expr)`\n",
+            assertables_url_line!("assert_program_args_stdout_eq_x_in_dir"),
+            " a_program label: `program`,\n",
+            " a_program debug: `\"cat\"`,\n",
+            "    a_args label: `args`,\n",
+            "    a_args debug: `[\"printf-stdout\"]`,\n",
+            "     a_dir label: `dir`,\n",
+            "     a_dir debug: `\"bin\"`,\n",
+            "    b_expr label: `bytes`,\n",
+            "    b_expr debug: `[122, 122]`,\n",
+            "               a: `[35, 33, 47, 98, 105, 110, 47, 115, 104, 10, 112, 114, 105, 110, 116, 102, 32, 34, 36, 64, 34, 10]`,\n",
+            "               b: `[122, 122]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a command (built with program and args, run in a directory) stdout is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a_program + a_args in a_dir ⇒ command ⇒ stdout) = (b_expr)
+///
+/// * If true, return `stdout`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let program = "cat";
+/// let args = ["printf-stdout"];
+/// let dir = "bin";
+/// let bytes = b"#!/bin/sh\nprintf \"$@\"\n".to_vec();
+/// assert_program_args_stdout_eq_x_in_dir!(program, args, dir, bytes);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let program = "cat";
+/// let args = ["printf-stdout"];
+/// let dir = "bin";
+/// let bytes = b"zz".to_vec();
+/// assert_program_args_stdout_eq_x_in_dir!(program, args, dir, bytes);
+/// # });
+/// // assertion failed: `assert_program_args_stdout_eq_x_in_dir!(a_program, a_args, a_dir, b_expr)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_eq_x_in_dir.html
+/// //  a_program label: `program`,
+/// //  a_program debug: `\"cat\"`,
+/// //     a_args label: `args`,
+/// //     a_args debug: `[\"printf-stdout\"]`,
+/// //      a_dir label: `dir`,
+/// //      a_dir debug: `\"bin\"`,
+/// //     b_expr label: `bytes`,
+/// //     b_expr debug: `[122, 122]`,
+/// //                a: `[35, 33, 47, 98, 105, 110, 47, 115, 104, 10, 112, 114, 105, 110, 116, 102, 32, 34, 36, 64, 34, 10]`,
+/// //                b: `[122, 122]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_program_args_stdout_eq_x_in_dir!(a_program, a_args, a_dir, b_expr)`\n",
+/// #     assertables_url_line!("assert_program_args_stdout_eq_x_in_dir"),
+/// #     " a_program label: `program`,\n",
+/// #     " a_program debug: `\"cat\"`,\n",
+/// #     "    a_args label: `args`,\n",
+/// #     "    a_args debug: `[\"printf-stdout\"]`,\n",
+/// #     "     a_dir label: `dir`,\n",
+/// #     "     a_dir debug: `\"bin\"`,\n",
+/// #     "    b_expr label: `bytes`,\n",
+/// #     "    b_expr debug: `[122, 122]`,\n",
+/// #     "               a: `[35, 33, 47, 98, 105, 110, 47, 115, 104, 10, 112, 114, 105, 110, 116, 102, 32, 34, 36, 64, 34, 10]`,\n",
+/// #     "               b: `[122, 122]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is like
+/// [`assert_program_args_stdout_eq_x`](macro@crate::assert_program_args_stdout_eq_x),
+/// and also sets the command working directory, by calling
+/// [`Command::current_dir`](https://doc.rust-lang.org/std/process/struct.Command.html#method.current_dir),
+/// which helps for testing a program that resolves relative paths.
+/// The failure message shows the working directory, so a failure is reproducible.
+///
+/// A relative program path, such as `bin/example`, may resolve relative
+/// to the working directory or not, depending on the platform, so prefer
+/// a program name on the `PATH`, or an absolute program path.
+///
+/// # Module macros
+///
+/// * [`assert_program_args_stdout_eq_x_in_dir`](macro@crate::assert_program_args_stdout_eq_x_in_dir)
+/// * [`assert_program_args_stdout_eq_x_in_dir_as_result`](macro@crate::assert_program_args_stdout_eq_x_in_dir_as_result)
+/// * [`debug_assert_program_args_stdout_eq_x_in_dir`](macro@crate::debug_assert_program_args_stdout_eq_x_in_dir)
+///
+#[macro_export]
+macro_rules! assert_program_args_stdout_eq_x_in_dir {
+    ($a_program:expr, $a_args:expr, $a_dir:expr, $b_expr:expr $(,)?) => {{
+        match $crate::assert_program_args_stdout_eq_x_in_dir_as_result!($a_program, $a_args, $a_dir, $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_program:expr, $a_args:expr, $a_dir:expr, $b_expr:expr, $($message:tt)+) => {{
+        match $crate::assert_program_args_stdout_eq_x_in_dir_as_result!($a_program, $a_args, $a_dir, $b_expr) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_program_args_stdout_eq_x_in_dir {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let program = "cat";
+        let args = ["printf-stdout"];
+        let dir = "bin";
+        let bytes = b"#!/bin/sh\nprintf \"$@\"\n".to_vec();
+        let actual = assert_program_args_stdout_eq_x_in_dir!(program, args, dir, bytes);
+        assert_eq!(actual, b"#!/bin/sh\nprintf \"$@\"\n".to_vec());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let program = "cat";
+            let args = ["printf-stdout"];
+            let dir = "bin";
+            let bytes = b"zz".to_vec();
+            let _actual = assert_program_args_stdout_eq_x_in_dir!(program, args, dir, bytes);
+        });
+        let message = concat!(
+            "assertion failed: `assert_program_args_stdout_eq_x_in_dir!(a_program, a_args, a_dir, b_expr)`\n",
+            assertables_url_line!("assert_program_args_stdout_eq_x_in_dir"),
+            " a_program label: `program`,\n",
+            " a_program debug: `\"cat\"`,\n",
+            "    a_args label: `args`,\n",
+            "    a_args debug: `[\"printf-stdout\"]`,\n",
+            "     a_dir label: `dir`,\n",
+            "     a_dir debug: `\"bin\"`,\n",
+            "    b_expr label: `bytes`,\n",
+            "    b_expr debug: `[122, 122]`,\n",
+            "               a: `[35, 33, 47, 98, 105, 110, 47, 115, 104, 10, 112, 114, 105, 110, 116, 102, 32, 34, 36, 64, 34, 10]`,\n",
+            "               b: `[122, 122]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a command (built with program and args, run in a directory) stdout is equal to an expression.
+///
+/// Pseudocode:<br>
+/// (a_program + a_args in a_dir ⇒ command ⇒ stdout) = (b_expr)
+///
+/// This macro provides the same statements as [`assert_program_args_stdout_eq_x_in_dir`](macro.assert_program_args_stdout_eq_x_in_dir.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_program_args_stdout_eq_x_in_dir`](macro@crate::assert_program_args_stdout_eq_x_in_dir)
+/// * [`assert_program_args_stdout_eq_x_in_dir_as_result`](macro@crate::assert_program_args_stdout_eq_x_in_dir_as_result)
+/// * [`debug_assert_program_args_stdout_eq_x_in_dir`](macro@crate::debug_assert_program_args_stdout_eq_x_in_dir)
+///
+#[macro_export]
+macro_rules! debug_assert_program_args_stdout_eq_x_in_dir {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_program_args_stdout_eq_x_in_dir!($($arg)*);
+        }
+    };
+}
@@ -53,7 +53,7 @@ macro_rules! assert_program_args_stdout_gt_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stdout_gt_x!(a_program, a_args, b_expr)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_gt_x.html\n",
+                                        $crate::assertables_url_line!("assert_program_args_stdout_gt_x"),
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -80,7 +80,7 @@ macro_rules! assert_program_args_stdout_gt_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stdout_gt_x!(a_program, a_args, b_expr)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_gt_x.html\n",
+                                    $crate::assertables_url_line!("assert_program_args_stdout_gt_x"),
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -125,7 +125,7 @@ mod test_assert_program_args_stdout_gt_x_as_result {
         let actual = assert_program_args_stdout_gt_x_as_result!(&a_program, &a_args, b);
         let message = concat!(
           "assertion failed: `assert_program_args_stdout_gt_x!(a_program, a_args, b_expr)`\n",
-          "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_gt_x.html\n",
+          assertables_url_line!("assert_program_args_stdout_gt_x"),
           " a_program label: `&a_program`,\n",
           " a_program debug: `\"bin/printf-stdout\"`,\n",
           "    a_args label: `&a_args`,\n",
@@ -146,7 +146,7 @@ mod test_assert_program_args_stdout_gt_x_as_result {
         let actual = assert_program_args_stdout_gt_x_as_result!(&a_program, &a_args, b);
         let message = concat!(
           "assertion failed: `assert_program_args_stdout_gt_x!(a_program, a_args, b_expr)`\n",
-          "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_gt_x.html\n",
+          assertables_url_line!("assert_program_args_stdout_gt_x"),
           " a_program label: `&a_program`,\n",
           " a_program debug: `\"bin/printf-stdout\"`,\n",
           "    a_args label: `&a_args`,\n",
@@ -202,7 +202,7 @@ mod test_assert_program_args_stdout_gt_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_program_args_stdout_gt_x!(a_program, a_args, b_expr)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_gt_x.html\n",
+/// #     assertables_url_line!("assert_program_args_stdout_gt_x"),
 /// #     " a_program label: `&program`,\n",
 /// #     " a_program debug: `\"bin/printf-stdout\"`,\n",
 /// #     "    a_args label: `&args`,\n",
@@ -227,13 +227,13 @@ macro_rules! assert_program_args_stdout_gt_x {
     ($a_program:expr, $a_args:expr, $b_expr:expr $(,)?) => {{
         match $crate::assert_program_args_stdout_gt_x_as_result!($a_program, $a_args, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_program:expr, $a_args:expr, $b_expr:expr, $($message:tt)+) => {{
         match $crate::assert_program_args_stdout_gt_x_as_result!($a_program, $a_args, $b_expr) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -261,7 +261,7 @@ mod test_assert_program_args_stdout_gt_x {
         });
         let message = concat!(
           "assertion failed: `assert_program_args_stdout_gt_x!(a_program, a_args, b_expr)`\n",
-          "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_gt_x.html\n",
+          assertables_url_line!("assert_program_args_stdout_gt_x"),
           " a_program label: `&a_program`,\n",
           " a_program debug: `\"bin/printf-stdout\"`,\n",
           "    a_args label: `&a_args`,\n",
@@ -291,7 +291,7 @@ mod test_assert_program_args_stdout_gt_x {
         });
         let message = concat!(
           "assertion failed: `assert_program_args_stdout_gt_x!(a_program, a_args, b_expr)`\n",
-          "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_gt_x.html\n",
+          assertables_url_line!("assert_program_args_stdout_gt_x"),
           " a_program label: `&a_program`,\n",
           " a_program debug: `\"bin/printf-stdout\"`,\n",
           "    a_args label: `&a_args`,\n",
@@ -346,7 +346,7 @@ mod test_assert_program_args_stdout_gt_x {
 #[macro_export]
 macro_rules! debug_assert_program_args_stdout_gt_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_program_args_stdout_gt_x!($($arg)*);
         }
     };
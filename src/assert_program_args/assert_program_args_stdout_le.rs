@@ -59,7 +59,7 @@ macro_rules! assert_program_args_stdout_le_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stdout_le!(a_program, a_args, b_program, b_args)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_le.html\n",
+                                        $crate::assertables_url_line!("assert_program_args_stdout_le"),
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -90,7 +90,7 @@ macro_rules! assert_program_args_stdout_le_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stdout_le!(a_program, a_args, b_program, b_args)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_le.html\n",
+                                    $crate::assertables_url_line!("assert_program_args_stdout_le"),
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -162,7 +162,7 @@ mod test_assert_program_args_stdout_le_as_result {
             assert_program_args_stdout_le_as_result!(&a_program, &a_args, &b_program, &b_args);
         let message = concat!(
             "assertion failed: `assert_program_args_stdout_le!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_le.html\n",
+            assertables_url_line!("assert_program_args_stdout_le"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stdout\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -224,7 +224,7 @@ mod test_assert_program_args_stdout_le_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_program_args_stdout_le!(a_program, a_args, b_program, b_args)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_le.html\n",
+/// #     assertables_url_line!("assert_program_args_stdout_le"),
 /// #     " a_program label: `&a_program`,\n",
 /// #     " a_program debug: `\"bin/printf-stdout\"`,\n",
 /// #     "    a_args label: `&a_args`,\n",
@@ -251,13 +251,13 @@ macro_rules! assert_program_args_stdout_le {
     ($a_program:expr, $a_args:expr, $b_program:expr, $b_args:expr $(,)?) => {{
         match $crate::assert_program_args_stdout_le_as_result!($a_program, $a_args, $b_program, $b_args) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_program:expr, $a_args:expr, $b_program:expr, $($message:tt)+) => {{
         match $crate::assert_program_args_stdout_le_as_result!($a_program, $a_args, $b_program, $b_args) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -300,7 +300,7 @@ mod test_assert_program_args_stdout_le {
         });
         let message = concat!(
             "assertion failed: `assert_program_args_stdout_le!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_le.html\n",
+            assertables_url_line!("assert_program_args_stdout_le"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stdout\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -357,7 +357,7 @@ mod test_assert_program_args_stdout_le {
 #[macro_export]
 macro_rules! debug_assert_program_args_stdout_le {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_program_args_stdout_le!($($arg)*);
         }
     };
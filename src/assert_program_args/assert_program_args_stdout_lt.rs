@@ -59,7 +59,7 @@ macro_rules! assert_program_args_stdout_lt_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stdout_lt!(a_program, a_args, b_program, b_args)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_lt.html\n",
+                                        $crate::assertables_url_line!("assert_program_args_stdout_lt"),
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -90,7 +90,7 @@ macro_rules! assert_program_args_stdout_lt_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stdout_lt!(a_program, a_args, b_program, b_args)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_lt.html\n",
+                                    $crate::assertables_url_line!("assert_program_args_stdout_lt"),
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -148,7 +148,7 @@ mod test_assert_program_args_stdout_lt_as_result {
             assert_program_args_stdout_lt_as_result!(&a_program, &a_args, &b_program, &b_args);
         let message = concat!(
             "assertion failed: `assert_program_args_stdout_lt!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_lt.html\n",
+            assertables_url_line!("assert_program_args_stdout_lt"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stdout\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -173,7 +173,7 @@ mod test_assert_program_args_stdout_lt_as_result {
             assert_program_args_stdout_lt_as_result!(&a_program, &a_args, &b_program, &b_args);
         let message = concat!(
             "assertion failed: `assert_program_args_stdout_lt!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_lt.html\n",
+            assertables_url_line!("assert_program_args_stdout_lt"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stdout\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -235,7 +235,7 @@ mod test_assert_program_args_stdout_lt_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_program_args_stdout_lt!(a_program, a_args, b_program, b_args)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_lt.html\n",
+/// #     assertables_url_line!("assert_program_args_stdout_lt"),
 /// #     " a_program label: `&a_program`,\n",
 /// #     " a_program debug: `\"bin/printf-stdout\"`,\n",
 /// #     "    a_args label: `&a_args`,\n",
@@ -262,13 +262,13 @@ macro_rules! assert_program_args_stdout_lt {
     ($a_program:expr, $a_args:expr, $b_program:expr, $b_args:expr $(,)?) => {{
         match $crate::assert_program_args_stdout_lt_as_result!($a_program, $a_args, $b_program, $b_args) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_program:expr, $a_args:expr, $b_program:expr, $($message:tt)+) => {{
         match $crate::assert_program_args_stdout_lt_as_result!($a_program, $a_args, $b_program, $b_args) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -298,7 +298,7 @@ mod test_assert_program_args_stdout_lt {
         });
         let message = concat!(
             "assertion failed: `assert_program_args_stdout_lt!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_lt.html\n",
+            assertables_url_line!("assert_program_args_stdout_lt"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stdout\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -331,7 +331,7 @@ mod test_assert_program_args_stdout_lt {
         });
         let message = concat!(
             "assertion failed: `assert_program_args_stdout_lt!(a_program, a_args, b_program, b_args)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_lt.html\n",
+            assertables_url_line!("assert_program_args_stdout_lt"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stdout\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -388,7 +388,7 @@ mod test_assert_program_args_stdout_lt {
 #[macro_export]
 macro_rules! debug_assert_program_args_stdout_lt {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_program_args_stdout_lt!($($arg)*);
         }
     };
@@ -58,7 +58,7 @@ macro_rules! assert_program_args_stdout_string_contains_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_program_args_stdout_string_contains!(a_program, a_args, containee)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_string_contains.html\n",
+                                        $crate::assertables_url_line!("assert_program_args_stdout_string_contains"),
                                         " a_program label: `{}`,\n",
                                         " a_program debug: `{:?}`,\n",
                                         "    a_args label: `{}`,\n",
@@ -85,7 +85,7 @@ macro_rules! assert_program_args_stdout_string_contains_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_program_args_stdout_string_contains!(a_program, a_args, containee)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_string_contains.html\n",
+                                    $crate::assertables_url_line!("assert_program_args_stdout_string_contains"),
                                     " a_program label: `{}`,\n",
                                     " a_program debug: `{:?}`,\n",
                                     "    a_args label: `{}`,\n",
@@ -130,7 +130,7 @@ mod test_assert_program_args_stdout_string_contains_as_result {
         let actual = assert_program_args_stdout_string_contains_as_result!(&a_program, &a_args, b);
         let message = concat!(
             "assertion failed: `assert_program_args_stdout_string_contains!(a_program, a_args, containee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_string_contains.html\n",
+            assertables_url_line!("assert_program_args_stdout_string_contains"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stdout\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -191,7 +191,7 @@ mod test_assert_program_args_stdout_string_contains_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_program_args_stdout_string_contains!(a_program, a_args, containee)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_string_contains.html\n",
+/// #     assertables_url_line!("assert_program_args_stdout_string_contains"),
 /// #     " a_program label: `&program`,\n",
 /// #     " a_program debug: `\"bin/printf-stdout\"`,\n",
 /// #     "    a_args label: `&args`,\n",
@@ -216,13 +216,13 @@ macro_rules! assert_program_args_stdout_string_contains {
     ($a_program:expr, $a_args:expr, $containee:expr $(,)?) => {{
         match $crate::assert_program_args_stdout_string_contains_as_result!($a_program, $a_args, $containee) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_program:expr, $a_args:expr, $containee:expr, $($message:tt)+) => {{
         match $crate::assert_program_args_stdout_string_contains_as_result!($a_program, $a_args, $containee) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -250,7 +250,7 @@ mod test_assert_program_args_stdout_string_contains {
         });
         let message = concat!(
             "assertion failed: `assert_program_args_stdout_string_contains!(a_program, a_args, containee)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_program_args_stdout_string_contains.html\n",
+            assertables_url_line!("assert_program_args_stdout_string_contains"),
             " a_program label: `&a_program`,\n",
             " a_program debug: `\"bin/printf-stdout\"`,\n",
             "    a_args label: `&a_args`,\n",
@@ -302,7 +302,7 @@ mod test_assert_program_args_stdout_string_contains {
 #[macro_export]
 macro_rules! debug_assert_program_args_stdout_string_contains {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_program_args_stdout_string_contains!($($arg)*);
         }
     };
@@ -26,6 +26,10 @@
 //! * [`assert_program_args_stdout_gt_x!(program, args, expr)`](macro@crate::assert_program_args_stdout_gt_x) ≈ command using program and args to stdout > expr
 //! * [`assert_program_args_stdout_ge_x!(program, args, expr)`](macro@crate::assert_program_args_stdout_ge_x) ≈ command using program and args to stdout ≥ expr
 //!
+//! Compare program and arguments standard output to an expression, in a working directory:
+//!
+//! * [`assert_program_args_stdout_eq_x_in_dir!(program, args, dir, expr)`](macro@crate::assert_program_args_stdout_eq_x_in_dir) ≈ command using program and args in dir to stdout = expr
+//!
 //! Assert program and arguments standard output as a string:
 //!
 //! * [`assert_program_args_stdout_string_contains!(program, args, containee)`](macro@crate::assert_program_args_stdout_string_contains) ≈ command using program and args to stdout string contains containee
@@ -126,6 +130,17 @@ macro_rules! assert_program_args_impl_prep {
     }};
 }
 
+/// Assert program args implementation preparation, in a working directory.
+#[macro_export]
+macro_rules! assert_program_args_in_dir_impl_prep {
+    ($program:expr, $args:expr, $dir:expr $(,)?) => {{
+        let mut command = ::std::process::Command::new($program);
+        command.args($args.into_iter());
+        command.current_dir($dir);
+        command.output()
+    }};
+}
+
 // stdout
 pub mod assert_program_args_stdout_eq;
 pub mod assert_program_args_stdout_ge;
@@ -142,6 +157,9 @@ pub mod assert_program_args_stdout_le_x;
 pub mod assert_program_args_stdout_lt_x;
 pub mod assert_program_args_stdout_ne_x;
 
+// stdout expr in dir
+pub mod assert_program_args_stdout_eq_x_in_dir;
+
 // stdout string
 pub mod assert_program_args_stdout_contains;
 pub mod assert_program_args_stdout_is_match;
@@ -0,0 +1,333 @@
+//! Assert a collection of ranges has no overlapping ranges.
+//!
+//! Pseudocode:<br>
+//! ranges into iter ∀ (a, b) ⇒ a ∩ b = ∅
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [0..2, 5..7, 2..5];
+//! assert_ranges_disjoint!(a);
+//! ```
+//!
+//! This implementation sorts the ranges by start, then checks each range
+//! against the preceding range that reaches the farthest end.
+//!
+//! * Each range is a half-open [`::std::ops::Range`](https://doc.rust-lang.org/std/ops/struct.Range.html),
+//!   so ranges that merely touch, such as `0..2` and `2..4`, do not overlap.
+//!
+//! * An empty range, such as `1..1`, contains no items, so it never overlaps.
+//!
+//! * The collection is iterated by reference, so the collection is not moved.
+//!
+//! * The failure message shows the first overlapping pair, with each index
+//!   in the original collection.
+//!
+//! # Module macros
+//!
+//! * [`assert_ranges_disjoint`](macro@crate::assert_ranges_disjoint)
+//! * [`assert_ranges_disjoint_as_result`](macro@crate::assert_ranges_disjoint_as_result)
+//! * [`debug_assert_ranges_disjoint`](macro@crate::debug_assert_ranges_disjoint)
+
+/// Assert a collection of ranges has no overlapping ranges.
+///
+/// Pseudocode:<br>
+/// ranges into iter ∀ (a, b) ⇒ a ∩ b = ∅
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_ranges_disjoint`](macro@crate::assert_ranges_disjoint)
+/// * [`assert_ranges_disjoint_as_result`](macro@crate::assert_ranges_disjoint_as_result)
+/// * [`debug_assert_ranges_disjoint`](macro@crate::debug_assert_ranges_disjoint)
+///
+#[macro_export]
+macro_rules! assert_ranges_disjoint_as_result {
+    ($ranges:expr $(,)?) => {{
+        match (&$ranges) {
+            ranges => {
+                let mut sorted: Vec<(usize, &::std::ops::Range<_>)> = ranges
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(_, range)| range.start < range.end)
+                    .collect();
+                sorted.sort_by(|(_, a), (_, b)| {
+                    a.start
+                        .partial_cmp(&b.start)
+                        .unwrap_or(::std::cmp::Ordering::Equal)
+                });
+                let mut found = None;
+                let mut iter = sorted.into_iter();
+                if let Some(mut widest) = iter.next() {
+                    for item in iter {
+                        if item.1.start < widest.1.end {
+                            found = Some((widest, item));
+                            break;
+                        }
+                        if item.1.end > widest.1.end {
+                            widest = item;
+                        }
+                    }
+                }
+                match found {
+                    None => Ok(()),
+                    Some(((left_index, left), (right_index, right))) => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_ranges_disjoint!(ranges)`\n",
+                                    $crate::assertables_url_line!("assert_ranges_disjoint"),
+                                    " ranges label: `{}`,\n",
+                                    " ranges debug: `{:?}`,\n",
+                                    "   left index: `{}`,\n",
+                                    "   left debug: `{:?}`,\n",
+                                    "  right index: `{}`,\n",
+                                    "  right debug: `{:?}`"
+                                ),
+                                stringify!($ranges),
+                                ranges,
+                                left_index,
+                                left,
+                                right_index,
+                                right
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_ranges_disjoint_as_result {
+    #[test]
+    fn success() {
+        let a = [0..2, 5..7, 2..5];
+        let actual = assert_ranges_disjoint_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = [0..2, 5..7, 1..3];
+        let actual = assert_ranges_disjoint_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_ranges_disjoint!(ranges)`\n",
+            assertables_url_line!("assert_ranges_disjoint"),
+            " ranges label: `a`,\n",
+            " ranges debug: `[0..2, 5..7, 1..3]`,\n",
+            "   left index: `0`,\n",
+            "   left debug: `0..2`,\n",
+            "  right index: `2`,\n",
+            "  right debug: `1..3`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_empty_collection() {
+        let a: Vec<std::ops::Range<i8>> = vec![];
+        let actual = assert_ranges_disjoint_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn success_with_empty_range_inside_another() {
+        let a = [0..4, 2..2];
+        let actual = assert_ranges_disjoint_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_with_range_nested_inside_a_wider_range() {
+        let a = [0..10, 1..2, 3..4];
+        let actual = assert_ranges_disjoint_as_result!(a);
+        let message = actual.unwrap_err();
+        assert!(message.contains("   left index: `0`,\n   left debug: `0..10`,\n  right index: `1`,\n  right debug: `1..2`"));
+    }
+
+    #[test]
+    fn failure_with_overlap_hidden_behind_a_shorter_range() {
+        let a = vec![0..10, 2..3, 8..12];
+        let actual = assert_ranges_disjoint_as_result!(a);
+        let message = actual.unwrap_err();
+        assert!(message.contains("  right index: `1`"));
+    }
+
+    #[test]
+    fn success_with_floats() {
+        let a = [0.0..0.5, 0.5..1.0];
+        let actual = assert_ranges_disjoint_as_result!(a);
+        assert_eq!(actual.unwrap(), ());
+    }
+}
+
+/// Assert a collection of ranges has no overlapping ranges.
+///
+/// Pseudocode:<br>
+/// ranges into iter ∀ (a, b) ⇒ a ∩ b = ∅
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [0..2, 5..7, 2..5];
+/// assert_ranges_disjoint!(a);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [0..2, 5..7, 1..3];
+/// assert_ranges_disjoint!(a);
+/// # });
+/// // assertion failed: `assert_ranges_disjoint!(ranges)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_ranges_disjoint.html
+/// //  ranges label: `a`,
+/// //  ranges debug: `[0..2, 5..7, 1..3]`,
+/// //    left index: `0`,
+/// //    left debug: `0..2`,
+/// //   right index: `2`,
+/// //   right debug: `1..3`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_ranges_disjoint!(ranges)`\n",
+/// #     assertables_url_line!("assert_ranges_disjoint"),
+/// #     " ranges label: `a`,\n",
+/// #     " ranges debug: `[0..2, 5..7, 1..3]`,\n",
+/// #     "   left index: `0`,\n",
+/// #     "   left debug: `0..2`,\n",
+/// #     "  right index: `2`,\n",
+/// #     "  right debug: `1..3`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This implementation sorts the ranges by start, then checks each range
+/// against the preceding range that reaches the farthest end.
+///
+/// * Each range is a half-open [`::std::ops::Range`](https://doc.rust-lang.org/std/ops/struct.Range.html),
+///   so ranges that merely touch, such as `0..2` and `2..4`, do not overlap.
+///
+/// * An empty range, such as `1..1`, contains no items, so it never overlaps.
+///
+/// * The collection is iterated by reference, so the collection is not moved.
+///
+/// * The failure message shows the first overlapping pair, with each index
+///   in the original collection.
+///
+/// # Module macros
+///
+/// * [`assert_ranges_disjoint`](macro@crate::assert_ranges_disjoint)
+/// * [`assert_ranges_disjoint_as_result`](macro@crate::assert_ranges_disjoint_as_result)
+/// * [`debug_assert_ranges_disjoint`](macro@crate::debug_assert_ranges_disjoint)
+///
+#[macro_export]
+macro_rules! assert_ranges_disjoint {
+    ($ranges:expr $(,)?) => {{
+        match $crate::assert_ranges_disjoint_as_result!($ranges) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($ranges:expr, $($message:tt)+) => {{
+        match $crate::assert_ranges_disjoint_as_result!($ranges) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_ranges_disjoint {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [0..2, 5..7, 2..5];
+        let actual = assert_ranges_disjoint!(a);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [0..2, 5..7, 1..3];
+            let _actual = assert_ranges_disjoint!(a);
+        });
+        let message = concat!(
+            "assertion failed: `assert_ranges_disjoint!(ranges)`\n",
+            assertables_url_line!("assert_ranges_disjoint"),
+            " ranges label: `a`,\n",
+            " ranges debug: `[0..2, 5..7, 1..3]`,\n",
+            "   left index: `0`,\n",
+            "   left debug: `0..2`,\n",
+            "  right index: `2`,\n",
+            "  right debug: `1..3`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a collection of ranges has no overlapping ranges.
+///
+/// Pseudocode:<br>
+/// ranges into iter ∀ (a, b) ⇒ a ∩ b = ∅
+///
+/// This macro provides the same statements as [`assert_ranges_disjoint`](macro.assert_ranges_disjoint.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_ranges_disjoint`](macro@crate::assert_ranges_disjoint)
+/// * [`assert_ranges_disjoint_as_result`](macro@crate::assert_ranges_disjoint_as_result)
+/// * [`debug_assert_ranges_disjoint`](macro@crate::debug_assert_ranges_disjoint)
+///
+#[macro_export]
+macro_rules! debug_assert_ranges_disjoint {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_ranges_disjoint!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,19 @@
+//! Assert for collections of ranges.
+//!
+//! These macros help with any collection of ranges that can be iterated by
+//! reference, such as an array or a vector of `start..end` ranges. This is
+//! useful for checking intervals, such as calendar events, memory allocations,
+//! and parser spans.
+//!
+//! * [`assert_ranges_disjoint!(ranges)`](macro@crate::assert_ranges_disjoint) ≈ no two ranges overlap
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [0..2, 2..4, 6..8];
+//! assert_ranges_disjoint!(a);
+//! ```
+
+pub mod assert_ranges_disjoint;
@@ -14,6 +14,22 @@
 //! assert_ready!(a);
 //! ```
 //!
+//! The item can be any type with a method `is_ready()`, such as a custom
+//! type that tracks a background job. For a `Poll`, the macro returns the
+//! inner value of `Ready`; for any other type, the macro returns the item.
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! #[derive(Debug)]
+//! struct Job { done: bool }
+//! impl Job { fn is_ready(&self) -> bool { self.done } }
+//!
+//! let a = Job { done: true };
+//! let job = assert_ready!(a);
+//! assert!(job.done);
+//! ```
+//!
 //! # Module macros
 //!
 //! * [`assert_ready`](macro@crate::assert_ready)
@@ -27,6 +43,9 @@
 ///
 /// * If true, return Result `Ok(a1)`.
 ///
+/// * If a is not a `Poll`, yet has a method `is_ready()` that is true,
+///   return Result `Ok(a)`.
+///
 /// * Otherwise, return Result `Err(message)`.
 ///
 /// This macro is useful for runtime checks, such as checking parameters,
@@ -40,21 +59,28 @@
 ///
 #[macro_export]
 macro_rules! assert_ready_as_result {
-    ($a:expr $(,)?) => {
+    ($a:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_ready::{ProbeReadyAny as _, ProbeReadyPoll as _};
         match ($a) {
-            Ready(a1) => Ok(a1),
-            _ => Err(format!(
-                concat!(
-                    "assertion failed: `assert_ready!(a)`\n",
-                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready.html\n",
-                    " a label: `{}`,\n",
-                    " a debug: `{:?}`",
-                ),
-                stringify!($a),
-                $a,
-            )),
+            a => {
+                let ready_fn = (&$crate::assert_ready::ReadyProbe::of(&a)).ready_fn();
+                match if a.is_ready() { ready_fn(a) } else { Err(a) } {
+                    Ok(value) => Ok(value),
+                    Err(a) => Err(format!(
+                        concat!(
+                            "assertion failed: `assert_ready!(a)`\n",
+                            $crate::assertables_url_line!("assert_ready"),
+                            " a label: `{}`,\n",
+                            " a debug: `{:?}`",
+                        ),
+                        stringify!($a),
+                        a,
+                    )),
+                }
+            }
         }
-    };
+    }};
 }
 
 #[cfg(test)]
@@ -75,12 +101,50 @@ mod test_assert_ready_as_result {
         let actual = assert_ready_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_ready!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready.html\n",
+            assertables_url_line!("assert_ready"),
             " a label: `a`,\n",
             " a debug: `Pending`",
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn success_with_reference() {
+        let a: Poll<i8> = Ready(1);
+        let actual: &i8 = assert_ready_as_result!(&a).unwrap();
+        assert_eq!(actual, &1);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Job {
+        done: bool,
+    }
+
+    impl Job {
+        fn is_ready(&self) -> bool {
+            self.done
+        }
+    }
+
+    #[test]
+    fn custom_type_success() {
+        let a = Job { done: true };
+        let actual = assert_ready_as_result!(a);
+        assert_eq!(actual.unwrap(), Job { done: true });
+    }
+
+    #[test]
+    fn custom_type_failure() {
+        let a = Job { done: false };
+        let actual = assert_ready_as_result!(a);
+        let message = concat!(
+            "assertion failed: `assert_ready!(a)`\n",
+            assertables_url_line!("assert_ready"),
+            " a label: `a`,\n",
+            " a debug: `Job { done: false }`",
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
 }
 
 /// Assert an expression is Ready.
@@ -116,7 +180,7 @@ mod test_assert_ready_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_ready!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready.html\n",
+/// #     assertables_url_line!("assert_ready"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Pending`",
 /// # );
@@ -135,13 +199,13 @@ macro_rules! assert_ready {
     ($a:expr $(,)?) => {{
         match $crate::assert_ready_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_ready_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -167,7 +231,7 @@ mod test_assert_ready {
         });
         let message = concat!(
             "assertion failed: `assert_ready!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready.html\n",
+            assertables_url_line!("assert_ready"),
             " a label: `a`,\n",
             " a debug: `Pending`",
         );
@@ -216,7 +280,7 @@ mod test_assert_ready {
 #[macro_export]
 macro_rules! debug_assert_ready {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_ready!($($arg)*);
         }
     };
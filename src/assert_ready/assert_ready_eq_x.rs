@@ -53,7 +53,7 @@ macro_rules! assert_ready_eq_x_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_ready_eq_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_eq_x.html\n",
+                                $crate::assertables_url_line!("assert_ready_eq_x"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a inner: `{:?}`,\n",
@@ -74,7 +74,7 @@ macro_rules! assert_ready_eq_x_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_ready_eq_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_eq_x.html\n",
+                            $crate::assertables_url_line!("assert_ready_eq_x"),
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
@@ -111,7 +111,7 @@ mod test_assert_ready_eq_x_as_result {
         let actual = assert_ready_eq_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_ready_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_eq_x.html\n",
+            assertables_url_line!("assert_ready_eq_x"),
             " a label: `a`,\n",
             " a debug: `Ready(1)`,\n",
             " a inner: `1`,\n",
@@ -128,7 +128,7 @@ mod test_assert_ready_eq_x_as_result {
         let actual = assert_ready_eq_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_ready_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_eq_x.html\n",
+            assertables_url_line!("assert_ready_eq_x"),
             " a label: `a`,\n",
             " a debug: `Pending`,\n",
             " b label: `b`,\n",
@@ -176,7 +176,7 @@ mod test_assert_ready_eq_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_ready_eq_x!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_eq_x.html\n",
+/// #     assertables_url_line!("assert_ready_eq_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Ready(1)`,\n",
 /// #     " a inner: `1`,\n",
@@ -198,13 +198,13 @@ macro_rules! assert_ready_eq_x {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_ready_eq_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_ready_eq_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -232,7 +232,7 @@ mod test_assert_ready_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_ready_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_eq_x.html\n",
+            assertables_url_line!("assert_ready_eq_x"),
             " a label: `a`,\n",
             " a debug: `Ready(1)`,\n",
             " a inner: `1`,\n",
@@ -258,7 +258,7 @@ mod test_assert_ready_eq_x {
         });
         let message = concat!(
             "assertion failed: `assert_ready_eq_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_eq_x.html\n",
+            assertables_url_line!("assert_ready_eq_x"),
             " a label: `a`,\n",
             " a debug: `Pending`,\n",
             " b label: `b`,\n",
@@ -309,7 +309,7 @@ mod test_assert_ready_eq_x {
 #[macro_export]
 macro_rules! debug_assert_ready_eq_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_ready_eq_x!($($arg)*);
         }
     };
@@ -53,7 +53,7 @@ macro_rules! assert_ready_ne_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_ready_ne!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_ne.html\n",
+                                $crate::assertables_url_line!("assert_ready_ne"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " a inner: `{:?}`,\n",
@@ -76,7 +76,7 @@ macro_rules! assert_ready_ne_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_ready_ne!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_ne.html\n",
+                            $crate::assertables_url_line!("assert_ready_ne"),
                             " a label: `{}`,\n",
                             " a debug: `{:?}`,\n",
                             " b label: `{}`,\n",
@@ -113,7 +113,7 @@ mod test_assert_ready_ne_as_result {
         let actual = assert_ready_ne_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_ready_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_ne.html\n",
+            assertables_url_line!("assert_ready_ne"),
             " a label: `a`,\n",
             " a debug: `Ready(1)`,\n",
             " a inner: `1`,\n",
@@ -131,7 +131,7 @@ mod test_assert_ready_ne_as_result {
         let actual = assert_ready_ne_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_ready_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_ne.html\n",
+            assertables_url_line!("assert_ready_ne"),
             " a label: `a`,\n",
             " a debug: `Pending`,\n",
             " b label: `b`,\n",
@@ -180,7 +180,7 @@ mod test_assert_ready_ne_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_ready_ne!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_ne.html\n",
+/// #     assertables_url_line!("assert_ready_ne"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Ready(1)`,\n",
 /// #     " a inner: `1`,\n",
@@ -203,13 +203,13 @@ macro_rules! assert_ready_ne {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_ready_ne_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_ready_ne_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -237,7 +237,7 @@ mod test_assert_ready_ne {
         });
         let message = concat!(
             "assertion failed: `assert_ready_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_ne.html\n",
+            assertables_url_line!("assert_ready_ne"),
             " a label: `a`,\n",
             " a debug: `Ready(1)`,\n",
             " a inner: `1`,\n",
@@ -264,7 +264,7 @@ mod test_assert_ready_ne {
         });
         let message = concat!(
             "assertion failed: `assert_ready_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_ready_ne.html\n",
+            assertables_url_line!("assert_ready_ne"),
             " a label: `a`,\n",
             " a debug: `Pending`,\n",
             " b label: `b`,\n",
@@ -315,7 +315,7 @@ mod test_assert_ready_ne {
 #[macro_export]
 macro_rules! debug_assert_ready_ne {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_ready_ne!($($arg)*);
         }
     };
@@ -2,6 +2,9 @@
 //!
 //! These macros help compare Ready(…) items, such as `::std::Ready::Ready` or similar.
 //!
+//! The macro [`assert_ready`](macro@crate::assert_ready) accepts any
+//! type with a method `is_ready()`, such as a custom pollable type.
+//!
 //! Assert expression is Ready:
 //!
 //! * [`assert_ready!(a)`](macro@crate::assert_ready)
@@ -39,3 +42,72 @@ pub mod assert_ready_ne;
 // Compare expression
 pub mod assert_ready_eq_x;
 pub mod assert_ready_ne_x;
+
+/// Probe a ready item for its value.
+///
+/// The macro [`assert_ready`](macro@crate::assert_ready) checks the item with
+/// its method `is_ready()`, then calls `(&ReadyProbe::of(&a)).ready_fn()` to
+/// get a function that converts the item to its value. Method lookup tries
+/// [`ProbeReadyPoll`] first, which applies only to a `Poll` or a `&Poll`, and
+/// returns the inner value of `Ready`, or a reference to it. Otherwise lookup
+/// falls back to [`ProbeReadyAny`], which returns the item itself, such as a
+/// custom type.
+///
+/// The function returns `Err(item)` when the item has no value, so the macro
+/// can report the item rather than panic.
+#[doc(hidden)]
+pub struct ReadyProbe<T>(::std::marker::PhantomData<T>);
+
+impl<T> ReadyProbe<T> {
+    pub fn of(_item: &T) -> Self {
+        ReadyProbe(::std::marker::PhantomData)
+    }
+}
+
+/// Convert an item to its ready value, or give the item back.
+#[doc(hidden)]
+pub type ReadyFn<I, O> = fn(I) -> Result<O, I>;
+
+#[doc(hidden)]
+pub trait ProbeReadyPoll {
+    type Item;
+    type Output;
+    fn ready_fn(&self) -> ReadyFn<Self::Item, Self::Output>;
+}
+
+impl<T> ProbeReadyPoll for ReadyProbe<::std::task::Poll<T>> {
+    type Item = ::std::task::Poll<T>;
+    type Output = T;
+    fn ready_fn(&self) -> ReadyFn<::std::task::Poll<T>, T> {
+        |item| match item {
+            ::std::task::Poll::Ready(t) => Ok(t),
+            pending => Err(pending),
+        }
+    }
+}
+
+impl<'a, T> ProbeReadyPoll for ReadyProbe<&'a ::std::task::Poll<T>> {
+    type Item = &'a ::std::task::Poll<T>;
+    type Output = &'a T;
+    fn ready_fn(&self) -> ReadyFn<&'a ::std::task::Poll<T>, &'a T> {
+        |item| match item {
+            ::std::task::Poll::Ready(t) => Ok(t),
+            pending => Err(pending),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait ProbeReadyAny {
+    type Item;
+    type Output;
+    fn ready_fn(&self) -> ReadyFn<Self::Item, Self::Output>;
+}
+
+impl<T> ProbeReadyAny for &ReadyProbe<T> {
+    type Item = T;
+    type Output = T;
+    fn ready_fn(&self) -> ReadyFn<T, T> {
+        Ok
+    }
+}
@@ -13,6 +13,10 @@
 //! assert_set_eq!(&a, &b);
 //! ```
 //!
+//! The failure message shows the items that differ, which are the items only
+//! in `a` and the items only in `b`. The debug of each whole collection is
+//! truncated to 64 characters, so the message stays short for large sets.
+//!
 //! This implementation uses [`::std::collections::BTreeSet`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html) to count items and sort them.
 //!
 //! # Module macros
@@ -46,29 +50,31 @@ macro_rules! assert_set_eq_as_result {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match (&$a_collection, &$b_collection) {
             (a_collection, b_collection) => {
-                let a: ::std::collections::BTreeSet<_> = assert_set_impl_prep!(a_collection);
-                let b: ::std::collections::BTreeSet<_> = assert_set_impl_prep!(b_collection);
+                let a: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(a_collection);
+                let b: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(b_collection);
                 if a == b {
                     Ok((a, b))
                 } else {
+                    let only_in_a: $crate::__private::BTreeSet<_> = a.difference(&b).collect();
+                    let only_in_b: $crate::__private::BTreeSet<_> = b.difference(&a).collect();
                     Err(
-                        format!(
+                        $crate::__private::format!(
                             concat!(
                                 "assertion failed: `assert_set_eq!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_eq.html\n",
-                                " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
-                                " b label: `{}`,\n",
-                                " b debug: `{:?}`,\n",
-                                "       a: `{:?}`,\n",
-                                "       b: `{:?}`"
+                                $crate::assertables_url_line!("assert_set_eq"),
+                                "   a label: `{}`,\n",
+                                "   a debug: `{}`,\n",
+                                "   b label: `{}`,\n",
+                                "   b debug: `{}`,\n",
+                                " only in a: `{:?}`,\n",
+                                " only in b: `{:?}`"
                             ),
                             stringify!($a_collection),
-                            a_collection,
+                            $crate::__private::debug_truncated(a_collection, 64),
                             stringify!($b_collection),
-                            b_collection,
-                            a,
-                            b
+                            $crate::__private::debug_truncated(b_collection, 64),
+                            only_in_a,
+                            only_in_b
                         )
                     )
                 }
@@ -99,16 +105,30 @@ mod test_assert_set_eq_as_result {
         let actual = assert_set_eq_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_set_eq!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_eq.html\n",
-            " a label: `&a`,\n",
-            " a debug: `[1, 2]`,\n",
-            " b label: `&b`,\n",
-            " b debug: `[3, 4]`,\n",
-            "       a: `{1, 2}`,\n",
-            "       b: `{3, 4}`"
+            assertables_url_line!("assert_set_eq"),
+            "   a label: `&a`,\n",
+            "   a debug: `[1, 2]`,\n",
+            "   b label: `&b`,\n",
+            "   b debug: `[3, 4]`,\n",
+            " only in a: `{1, 2}`,\n",
+            " only in b: `{3, 4}`"
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn failure_with_large_sets_shows_only_differences() {
+        let a: Vec<i32> = (0..1000).collect();
+        let b: Vec<i32> = (0..1000).map(|x| if x == 7 { 1007 } else { x }).collect();
+        let actual = assert_set_eq_as_result!(&a, &b);
+        let message = actual.unwrap_err();
+        assert!(message.ends_with(" only in a: `{7}`,\n only in b: `{1007}`"));
+        assert!(message.contains(
+            "   a debug: `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 1…`,\n"
+        ));
+        assert!(!message.contains("999"));
+        assert!(message.len() < 500);
+    }
 }
 
 /// Assert a set is equal to another.
@@ -140,22 +160,22 @@ mod test_assert_set_eq_as_result {
 /// # });
 /// // assertion failed: `assert_set_eq!(a_collection, b_collection)`
 /// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_eq.html
-/// //  a label: `&a`,
-/// //  a debug: `[1, 2]`,
-/// //  b label: `&b`,
-/// //  b debug: `[3, 4]`,
-/// //        a: `{1, 2}`,
-/// //        b: `{3, 4}`
+/// //    a label: `&a`,
+/// //    a debug: `[1, 2]`,
+/// //    b label: `&b`,
+/// //    b debug: `[3, 4]`,
+/// //  only in a: `{1, 2}`,
+/// //  only in b: `{3, 4}`
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_set_eq!(a_collection, b_collection)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_eq.html\n",
-/// #     " a label: `&a`,\n",
-/// #     " a debug: `[1, 2]`,\n",
-/// #     " b label: `&b`,\n",
-/// #     " b debug: `[3, 4]`,\n",
-/// #     "       a: `{1, 2}`,\n",
-/// #     "       b: `{3, 4}`"
+/// #     assertables_url_line!("assert_set_eq"),
+/// #     "   a label: `&a`,\n",
+/// #     "   a debug: `[1, 2]`,\n",
+/// #     "   b label: `&b`,\n",
+/// #     "   b debug: `[3, 4]`,\n",
+/// #     " only in a: `{1, 2}`,\n",
+/// #     " only in b: `{3, 4}`"
 /// # );
 /// # assert_eq!(actual, message);
 /// # }
@@ -174,13 +194,13 @@ macro_rules! assert_set_eq {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match $crate::assert_set_eq_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
         match $crate::assert_set_eq_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -207,13 +227,13 @@ mod test_assert_set_eq {
         });
         let message = concat!(
             "assertion failed: `assert_set_eq!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_eq.html\n",
-            " a label: `&a`,\n",
-            " a debug: `[1, 2]`,\n",
-            " b label: `&b`,\n",
-            " b debug: `[3, 4]`,\n",
-            "       a: `{1, 2}`,\n",
-            "       b: `{3, 4}`"
+            assertables_url_line!("assert_set_eq"),
+            "   a label: `&a`,\n",
+            "   a debug: `[1, 2]`,\n",
+            "   b label: `&b`,\n",
+            "   b debug: `[3, 4]`,\n",
+            " only in a: `{1, 2}`,\n",
+            " only in b: `{3, 4}`"
         );
         assert_eq!(
             result
@@ -260,7 +280,7 @@ mod test_assert_set_eq {
 #[macro_export]
 macro_rules! debug_assert_set_eq {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_set_eq!($($arg)*);
         }
     };
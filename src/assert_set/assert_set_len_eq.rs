@@ -0,0 +1,279 @@
+//! Assert a set length is equal to another.
+//!
+//! Pseudocode:<br>
+//! (a_collection ⇒ a_set).len() = (b_collection ⇒ b_set).len()
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 1, 2];
+//! let b = [3, 4, 4];
+//! assert_set_len_eq!(&a, &b);
+//! ```
+//!
+//! The set length is the number of distinct items, so duplicate items count once.
+//! The failure message shows each set, so you can see which duplicates were collapsed.
+//!
+//! This implementation uses [`::std::collections::BTreeSet`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html) to count items and sort them.
+//!
+//! # Module macros
+//!
+//! * [`assert_set_len_eq`](macro@crate::assert_set_len_eq)
+//! * [`assert_set_len_eq_as_result`](macro@crate::assert_set_len_eq_as_result)
+//! * [`debug_assert_set_len_eq`](macro@crate::debug_assert_set_len_eq)
+
+/// Assert a set length is equal to another.
+///
+/// Pseudocode:<br>
+/// (a_collection ⇒ a_set).len() = (b_collection ⇒ b_set).len()
+///
+/// * If true, return Result `Ok((a_set_len, b_set_len))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_set_len_eq`](macro@crate::assert_set_len_eq)
+/// * [`assert_set_len_eq_as_result`](macro@crate::assert_set_len_eq_as_result)
+/// * [`debug_assert_set_len_eq`](macro@crate::debug_assert_set_len_eq)
+///
+#[macro_export]
+macro_rules! assert_set_len_eq_as_result {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        match (&$a_collection, &$b_collection) {
+            (a_collection, b_collection) => {
+                let a: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(a_collection);
+                let b: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(b_collection);
+                let a_len = a.len();
+                let b_len = b.len();
+                if a_len == b_len {
+                    Ok((a_len, b_len))
+                } else {
+                    Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_set_len_eq!(a_collection, b_collection)`\n",
+                                $crate::assertables_url_line!("assert_set_len_eq"),
+                                "   a label: `{}`,\n",
+                                "   a debug: `{:?}`,\n",
+                                "     a set: `{:?}`,\n",
+                                " a set len: `{:?}`,\n",
+                                "   b label: `{}`,\n",
+                                "   b debug: `{:?}`,\n",
+                                "     b set: `{:?}`,\n",
+                                " b set len: `{:?}`"
+                            ),
+                            stringify!($a_collection),
+                            a_collection,
+                            a,
+                            a_len,
+                            stringify!($b_collection),
+                            b_collection,
+                            b,
+                            b_len
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_set_len_eq_as_result {
+    #[test]
+    fn success() {
+        let a = [1, 1, 2];
+        let b = [3, 4, 4];
+        let actual = assert_set_len_eq_as_result!(&a, &b);
+        assert_eq!(actual.unwrap(), (2, 2));
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 1, 2];
+        let b = [3, 4, 5, 5];
+        let actual = assert_set_len_eq_as_result!(&a, &b);
+        let message = concat!(
+            "assertion failed: `assert_set_len_eq!(a_collection, b_collection)`\n",
+            assertables_url_line!("assert_set_len_eq"),
+            "   a label: `&a`,\n",
+            "   a debug: `[1, 1, 2]`,\n",
+            "     a set: `{1, 2}`,\n",
+            " a set len: `2`,\n",
+            "   b label: `&b`,\n",
+            "   b debug: `[3, 4, 5, 5]`,\n",
+            "     b set: `{3, 4, 5}`,\n",
+            " b set len: `3`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a set length is equal to another.
+///
+/// Pseudocode:<br>
+/// (a_collection ⇒ a_set).len() = (b_collection ⇒ b_set).len()
+///
+/// * If true, return `(a_set_len, b_set_len)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 1, 2];
+/// let b = [3, 4, 4];
+/// assert_set_len_eq!(&a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 1, 2];
+/// let b = [3, 4, 5, 5];
+/// assert_set_len_eq!(&a, &b);
+/// # });
+/// // assertion failed: `assert_set_len_eq!(a_collection, b_collection)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_len_eq.html
+/// //    a label: `&a`,
+/// //    a debug: `[1, 1, 2]`,
+/// //      a set: `{1, 2}`,
+/// //  a set len: `2`,
+/// //    b label: `&b`,
+/// //    b debug: `[3, 4, 5, 5]`,
+/// //      b set: `{3, 4, 5}`,
+/// //  b set len: `3`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_set_len_eq!(a_collection, b_collection)`\n",
+/// #     assertables_url_line!("assert_set_len_eq"),
+/// #     "   a label: `&a`,\n",
+/// #     "   a debug: `[1, 1, 2]`,\n",
+/// #     "     a set: `{1, 2}`,\n",
+/// #     " a set len: `2`,\n",
+/// #     "   b label: `&b`,\n",
+/// #     "   b debug: `[3, 4, 5, 5]`,\n",
+/// #     "     b set: `{3, 4, 5}`,\n",
+/// #     " b set len: `3`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The set length is the number of distinct items, so duplicate items count once.
+/// The failure message shows each set, so you can see which duplicates were collapsed.
+///
+/// This implementation uses [`::std::collections::BTreeSet`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html) to count items and sort them.
+///
+/// # Module macros
+///
+/// * [`assert_set_len_eq`](macro@crate::assert_set_len_eq)
+/// * [`assert_set_len_eq_as_result`](macro@crate::assert_set_len_eq_as_result)
+/// * [`debug_assert_set_len_eq`](macro@crate::debug_assert_set_len_eq)
+///
+#[macro_export]
+macro_rules! assert_set_len_eq {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        match $crate::assert_set_len_eq_as_result!($a_collection, $b_collection) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
+        match $crate::assert_set_len_eq_as_result!($a_collection, $b_collection) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_set_len_eq {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 1, 2];
+        let b = [3, 4, 4];
+        let actual = assert_set_len_eq!(&a, &b);
+        assert_eq!(actual, (2, 2));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 1, 2];
+            let b = [3, 4, 5, 5];
+            let _actual = assert_set_len_eq!(&a, &b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_set_len_eq!(a_collection, b_collection)`\n",
+            assertables_url_line!("assert_set_len_eq"),
+            "   a label: `&a`,\n",
+            "   a debug: `[1, 1, 2]`,\n",
+            "     a set: `{1, 2}`,\n",
+            " a set len: `2`,\n",
+            "   b label: `&b`,\n",
+            "   b debug: `[3, 4, 5, 5]`,\n",
+            "     b set: `{3, 4, 5}`,\n",
+            " b set len: `3`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a set length is equal to another.
+///
+/// Pseudocode:<br>
+/// (a_collection ⇒ a_set).len() = (b_collection ⇒ b_set).len()
+///
+/// This macro provides the same statements as [`assert_set_len_eq`](macro.assert_set_len_eq.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_set_len_eq`](macro@crate::assert_set_len_eq)
+/// * [`assert_set_len_eq_as_result`](macro@crate::assert_set_len_eq_as_result)
+/// * [`debug_assert_set_len_eq`](macro@crate::debug_assert_set_len_eq)
+///
+#[macro_export]
+macro_rules! debug_assert_set_len_eq {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_set_len_eq!($($arg)*);
+        }
+    };
+}
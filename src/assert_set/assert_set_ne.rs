@@ -45,16 +45,16 @@ macro_rules! assert_set_ne_as_result {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match (&$a_collection, &$b_collection) {
             (a_collection, b_collection) => {
-                let a: ::std::collections::BTreeSet<_> = assert_set_impl_prep!(a_collection);
-                let b: ::std::collections::BTreeSet<_> = assert_set_impl_prep!(b_collection);
+                let a: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(a_collection);
+                let b: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(b_collection);
                 if a != b {
                     Ok((a, b))
                 } else {
                     Err(
-                        format!(
+                        $crate::__private::format!(
                             concat!(
                                 "assertion failed: `assert_set_ne!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_ne.html\n",
+                                $crate::assertables_url_line!("assert_set_ne"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -98,7 +98,7 @@ mod test_assert_set_ne_as_result {
         let actual = assert_set_ne_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_set_ne!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_ne.html\n",
+            assertables_url_line!("assert_set_ne"),
             " a label: `&a`,\n",
             " a debug: `[1, 2]`,\n",
             " b label: `&b`,\n",
@@ -148,7 +148,7 @@ mod test_assert_set_ne_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_set_ne!(a_collection, b_collection)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_ne.html\n",
+/// #     assertables_url_line!("assert_set_ne"),
 /// #     " a label: `&a`,\n",
 /// #     " a debug: `[1, 2]`,\n",
 /// #     " b label: `&b`,\n",
@@ -173,13 +173,13 @@ macro_rules! assert_set_ne {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match $crate::assert_set_ne_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
         match $crate::assert_set_ne_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -206,7 +206,7 @@ mod test_assert_set_ne {
         });
         let message = concat!(
             "assertion failed: `assert_set_ne!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_ne.html\n",
+            assertables_url_line!("assert_set_ne"),
             " a label: `&a`,\n",
             " a debug: `[1, 2]`,\n",
             " b label: `&b`,\n",
@@ -259,7 +259,7 @@ mod test_assert_set_ne {
 #[macro_export]
 macro_rules! debug_assert_set_ne {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_set_ne!($($arg)*);
         }
     };
@@ -44,16 +44,16 @@ macro_rules! assert_set_subset_as_result {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match (&$a_collection, &$b_collection) {
             (a_collection, b_collection) => {
-                let a: ::std::collections::BTreeSet<_> = assert_set_impl_prep!(a_collection);
-                let b: ::std::collections::BTreeSet<_> = assert_set_impl_prep!(b_collection);
+                let a: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(a_collection);
+                let b: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(b_collection);
                 if a.is_subset(&b) {
                     Ok((a, b))
                 } else {
                     Err(
-                        format!(
+                        $crate::__private::format!(
                             concat!(
                                 "assertion failed: `assert_set_subset!(a_collection, b_collection)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_subset.html\n",
+                                $crate::assertables_url_line!("assert_set_subset"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`,\n",
                                 " b label: `{}`,\n",
@@ -97,7 +97,7 @@ mod test_assert_set_subset_as_result {
         let actual = assert_set_subset_as_result!(&a, &b);
         let message = concat!(
             "assertion failed: `assert_set_subset!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_subset.html\n",
+            assertables_url_line!("assert_set_subset"),
             " a label: `&a`,\n",
             " a debug: `[1, 2, 3]`,\n",
             " b label: `&b`,\n",
@@ -147,7 +147,7 @@ mod test_assert_set_subset_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_set_subset!(a_collection, b_collection)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_subset.html\n",
+/// #     assertables_url_line!("assert_set_subset"),
 /// #     " a label: `&a`,\n",
 /// #     " a debug: `[1, 2, 3]`,\n",
 /// #     " b label: `&b`,\n",
@@ -172,13 +172,13 @@ macro_rules! assert_set_subset {
     ($a_collection:expr, $b_collection:expr $(,)?) => {{
         match $crate::assert_set_subset_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
         match $crate::assert_set_subset_as_result!($a_collection, $b_collection) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -208,7 +208,7 @@ mod test_assert_set_subset {
         });
         let message = concat!(
             "assertion failed: `assert_set_subset!(a_collection, b_collection)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_subset.html\n",
+            assertables_url_line!("assert_set_subset"),
             " a label: `&a`,\n",
             " a debug: `[1, 2, 3]`,\n",
             " b label: `&b`,\n",
@@ -261,7 +261,7 @@ mod test_assert_set_subset {
 #[macro_export]
 macro_rules! debug_assert_set_subset {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_set_subset!($($arg)*);
         }
     };
@@ -0,0 +1,307 @@
+//! Assert a set is a proper superset of another.
+//!
+//! Pseudocode:<br>
+//! (a_collection ⇒ a_set) ⊃ (b_collection ⇒ b_set)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3];
+//! let b = [1, 2];
+//! assert_set_superset_proper!(&a, &b);
+//! ```
+//!
+//! A proper superset is a superset that is not equal, so this macro fails when
+//! the sets are equal, unlike [`assert_set_superset`](macro@crate::assert_set_superset).
+//! The failure message says whether the sets are not a superset, or are equal.
+//!
+//! This implementation uses [`::std::collections::BTreeSet`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html) to count items and sort them.
+//!
+//! # Module macros
+//!
+//! * [`assert_set_superset_proper`](macro@crate::assert_set_superset_proper)
+//! * [`assert_set_superset_proper_as_result`](macro@crate::assert_set_superset_proper_as_result)
+//! * [`debug_assert_set_superset_proper`](macro@crate::debug_assert_set_superset_proper)
+
+/// Assert a set is a proper superset of another.
+///
+/// Pseudocode:<br>
+/// (a_collection ⇒ a_set) ⊃ (b_collection ⇒ b_set)
+///
+/// * If true, return Result `Ok((a_set, b_set))`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_set_superset_proper`](macro@crate::assert_set_superset_proper)
+/// * [`assert_set_superset_proper_as_result`](macro@crate::assert_set_superset_proper_as_result)
+/// * [`debug_assert_set_superset_proper`](macro@crate::debug_assert_set_superset_proper)
+///
+#[macro_export]
+macro_rules! assert_set_superset_proper_as_result {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        match (&$a_collection, &$b_collection) {
+            (a_collection, b_collection) => {
+                let a: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(a_collection);
+                let b: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(b_collection);
+                let reason = if !a.is_superset(&b) {
+                    Some("not a superset")
+                } else if a == b {
+                    Some("equal sets, so not a proper superset")
+                } else {
+                    None
+                };
+                match reason {
+                    None => Ok((a, b)),
+                    Some(reason) => Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_set_superset_proper!(a_collection, b_collection)`\n",
+                                $crate::assertables_url_line!("assert_set_superset_proper"),
+                                " a label: `{}`,\n",
+                                " a debug: `{:?}`,\n",
+                                " b label: `{}`,\n",
+                                " b debug: `{:?}`,\n",
+                                "       a: `{:?}`,\n",
+                                "       b: `{:?}`,\n",
+                                "  reason: `{}`"
+                            ),
+                            stringify!($a_collection),
+                            a_collection,
+                            stringify!($b_collection),
+                            b_collection,
+                            a,
+                            b,
+                            reason
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_set_superset_proper_as_result {
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let b = [1, 2];
+        let actual = assert_set_superset_proper_as_result!(&a, &b);
+        assert_eq!(
+            actual.unwrap(),
+            (BTreeSet::from([&1, &2, &3]), BTreeSet::from([&1, &2]))
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 2, 3];
+        let b = [1, 2, 4];
+        let actual = assert_set_superset_proper_as_result!(&a, &b);
+        let message = concat!(
+            "assertion failed: `assert_set_superset_proper!(a_collection, b_collection)`\n",
+            assertables_url_line!("assert_set_superset_proper"),
+            " a label: `&a`,\n",
+            " a debug: `[1, 2, 3]`,\n",
+            " b label: `&b`,\n",
+            " b debug: `[1, 2, 4]`,\n",
+            "       a: `{1, 2, 3}`,\n",
+            "       b: `{1, 2, 4}`,\n",
+            "  reason: `not a superset`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_equal() {
+        let a = [1, 2];
+        let b = [2, 1];
+        let actual = assert_set_superset_proper_as_result!(&a, &b);
+        let message = concat!(
+            "assertion failed: `assert_set_superset_proper!(a_collection, b_collection)`\n",
+            assertables_url_line!("assert_set_superset_proper"),
+            " a label: `&a`,\n",
+            " a debug: `[1, 2]`,\n",
+            " b label: `&b`,\n",
+            " b debug: `[2, 1]`,\n",
+            "       a: `{1, 2}`,\n",
+            "       b: `{1, 2}`,\n",
+            "  reason: `equal sets, so not a proper superset`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a set is a proper superset of another.
+///
+/// Pseudocode:<br>
+/// (a_collection ⇒ a_set) ⊃ (b_collection ⇒ b_set)
+///
+/// * If true, return `(a_set, b_set)`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// let b = [1, 2];
+/// assert_set_superset_proper!(&a, &b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3];
+/// let b = [1, 2, 4];
+/// assert_set_superset_proper!(&a, &b);
+/// # });
+/// // assertion failed: `assert_set_superset_proper!(a_collection, b_collection)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_superset_proper.html
+/// //  a label: `&a`,
+/// //  a debug: `[1, 2, 3]`,
+/// //  b label: `&b`,
+/// //  b debug: `[1, 2, 4]`,
+/// //        a: `{1, 2, 3}`,
+/// //        b: `{1, 2, 4}`,
+/// //   reason: `not a superset`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_set_superset_proper!(a_collection, b_collection)`\n",
+/// #     assertables_url_line!("assert_set_superset_proper"),
+/// #     " a label: `&a`,\n",
+/// #     " a debug: `[1, 2, 3]`,\n",
+/// #     " b label: `&b`,\n",
+/// #     " b debug: `[1, 2, 4]`,\n",
+/// #     "       a: `{1, 2, 3}`,\n",
+/// #     "       b: `{1, 2, 4}`,\n",
+/// #     "  reason: `not a superset`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// A proper superset is a superset that is not equal, so this macro fails when
+/// the sets are equal, unlike [`assert_set_superset`](macro@crate::assert_set_superset).
+/// The failure message says whether the sets are not a superset, or are equal.
+///
+/// This implementation uses [`::std::collections::BTreeSet`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html) to count items and sort them.
+///
+/// # Module macros
+///
+/// * [`assert_set_superset_proper`](macro@crate::assert_set_superset_proper)
+/// * [`assert_set_superset_proper_as_result`](macro@crate::assert_set_superset_proper_as_result)
+/// * [`debug_assert_set_superset_proper`](macro@crate::debug_assert_set_superset_proper)
+///
+#[macro_export]
+macro_rules! assert_set_superset_proper {
+    ($a_collection:expr, $b_collection:expr $(,)?) => {{
+        match $crate::assert_set_superset_proper_as_result!($a_collection, $b_collection) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_collection:expr, $b_collection:expr, $($message:tt)+) => {{
+        match $crate::assert_set_superset_proper_as_result!($a_collection, $b_collection) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_set_superset_proper {
+    use std::collections::BTreeSet;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let b = [1, 2];
+        let actual = assert_set_superset_proper!(&a, &b);
+        assert_eq!(
+            actual,
+            (BTreeSet::from([&1, &2, &3]), BTreeSet::from([&1, &2]))
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 2, 3];
+            let b = [1, 2, 4];
+            let _actual = assert_set_superset_proper!(&a, &b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_set_superset_proper!(a_collection, b_collection)`\n",
+            assertables_url_line!("assert_set_superset_proper"),
+            " a label: `&a`,\n",
+            " a debug: `[1, 2, 3]`,\n",
+            " b label: `&b`,\n",
+            " b debug: `[1, 2, 4]`,\n",
+            "       a: `{1, 2, 3}`,\n",
+            "       b: `{1, 2, 4}`,\n",
+            "  reason: `not a superset`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a set is a proper superset of another.
+///
+/// Pseudocode:<br>
+/// (a_collection ⇒ a_set) ⊃ (b_collection ⇒ b_set)
+///
+/// This macro provides the same statements as [`assert_set_superset_proper`](macro.assert_set_superset_proper.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_set_superset_proper`](macro@crate::assert_set_superset_proper)
+/// * [`assert_set_superset_proper_as_result`](macro@crate::assert_set_superset_proper_as_result)
+/// * [`debug_assert_set_superset_proper`](macro@crate::debug_assert_set_superset_proper)
+///
+#[macro_export]
+macro_rules! debug_assert_set_superset_proper {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_set_superset_proper!($($arg)*);
+        }
+    };
+}
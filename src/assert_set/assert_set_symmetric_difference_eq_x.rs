@@ -0,0 +1,310 @@
+//! Assert the symmetric difference of two sets is equal to an expression.
+//!
+//! Pseudocode:<br>
+//! ((a_collection ⇒ a_set) △ (b_collection ⇒ b_set)) = (x_collection ⇒ x_set)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3];
+//! let b = [2, 3, 4];
+//! let x = [1, 4];
+//! assert_set_symmetric_difference_eq_x!(&a, &b, &x);
+//! ```
+//!
+//! The symmetric difference is the set of elements that are in exactly one
+//! of the two sets. This macro is useful for large sets that differ by only
+//! a few elements, because the failure message shows the elements that are
+//! only in a, and the elements that are only in b, rather than only the full sets.
+//!
+//! This implementation uses [`::std::collections::BTreeSet`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html) to count items and sort them.
+//!
+//! # Module macros
+//!
+//! * [`assert_set_symmetric_difference_eq_x`](macro@crate::assert_set_symmetric_difference_eq_x)
+//! * [`assert_set_symmetric_difference_eq_x_as_result`](macro@crate::assert_set_symmetric_difference_eq_x_as_result)
+//! * [`debug_assert_set_symmetric_difference_eq_x`](macro@crate::debug_assert_set_symmetric_difference_eq_x)
+
+/// Assert the symmetric difference of two sets is equal to an expression.
+///
+/// Pseudocode:<br>
+/// ((a_collection ⇒ a_set) △ (b_collection ⇒ b_set)) = (x_collection ⇒ x_set)
+///
+/// * If true, return Result `Ok(symmetric_difference_set)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_set_symmetric_difference_eq_x`](macro@crate::assert_set_symmetric_difference_eq_x)
+/// * [`assert_set_symmetric_difference_eq_x_as_result`](macro@crate::assert_set_symmetric_difference_eq_x_as_result)
+/// * [`debug_assert_set_symmetric_difference_eq_x`](macro@crate::debug_assert_set_symmetric_difference_eq_x)
+///
+#[macro_export]
+macro_rules! assert_set_symmetric_difference_eq_x_as_result {
+    ($a_collection:expr, $b_collection:expr, $x_collection:expr $(,)?) => {{
+        match (&$a_collection, &$b_collection, &$x_collection) {
+            (a_collection, b_collection, x_collection) => {
+                let a: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(a_collection);
+                let b: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(b_collection);
+                let x: $crate::__private::BTreeSet<_> = assert_set_impl_prep!(x_collection);
+                let symmetric_difference: $crate::__private::BTreeSet<_> = a.symmetric_difference(&b).cloned().collect();
+                if symmetric_difference == x {
+                    Ok(symmetric_difference)
+                } else {
+                    let only_in_a: $crate::__private::BTreeSet<_> = a.difference(&b).collect();
+                    let only_in_b: $crate::__private::BTreeSet<_> = b.difference(&a).collect();
+                    Err(
+                        $crate::__private::format!(
+                            concat!(
+                                "assertion failed: `assert_set_symmetric_difference_eq_x!(a_collection, b_collection, x_collection)`\n",
+                                $crate::assertables_url_line!("assert_set_symmetric_difference_eq_x"),
+                                "   a label: `{}`,\n",
+                                "   a debug: `{:?}`,\n",
+                                "   b label: `{}`,\n",
+                                "   b debug: `{:?}`,\n",
+                                "   x label: `{}`,\n",
+                                "   x debug: `{:?}`,\n",
+                                " only in a: `{:?}`,\n",
+                                " only in b: `{:?}`,\n",
+                                "         x: `{:?}`"
+                            ),
+                            stringify!($a_collection),
+                            a_collection,
+                            stringify!($b_collection),
+                            b_collection,
+                            stringify!($x_collection),
+                            x_collection,
+                            only_in_a,
+                            only_in_b,
+                            x
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_set_symmetric_difference_eq_x_as_result {
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let b = [2, 3, 4];
+        let x = [1, 4];
+        let actual = assert_set_symmetric_difference_eq_x_as_result!(&a, &b, &x);
+        assert_eq!(actual.unwrap(), BTreeSet::from([&1, &4]));
+    }
+
+    #[test]
+    fn failure() {
+        let a = [1, 2, 3];
+        let b = [2, 3, 4];
+        let x = [1];
+        let actual = assert_set_symmetric_difference_eq_x_as_result!(&a, &b, &x);
+        let message = concat!(
+            "assertion failed: `assert_set_symmetric_difference_eq_x!(a_collection, b_collection, x_collection)`\n",
+            assertables_url_line!("assert_set_symmetric_difference_eq_x"),
+            "   a label: `&a`,\n",
+            "   a debug: `[1, 2, 3]`,\n",
+            "   b label: `&b`,\n",
+            "   b debug: `[2, 3, 4]`,\n",
+            "   x label: `&x`,\n",
+            "   x debug: `[1]`,\n",
+            " only in a: `{1}`,\n",
+            " only in b: `{4}`,\n",
+            "         x: `{1}`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_because_sets_are_equal() {
+        let a = [1, 2, 2];
+        let b = [2, 1];
+        let x: [i32; 0] = [];
+        let actual = assert_set_symmetric_difference_eq_x_as_result!(&a, &b, &x);
+        assert_eq!(actual.unwrap(), BTreeSet::new());
+    }
+}
+
+/// Assert the symmetric difference of two sets is equal to an expression.
+///
+/// Pseudocode:<br>
+/// ((a_collection ⇒ a_set) △ (b_collection ⇒ b_set)) = (x_collection ⇒ x_set)
+///
+/// * If true, return `symmetric_difference_set`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = [1, 2, 3];
+/// let b = [2, 3, 4];
+/// let x = [1, 4];
+/// assert_set_symmetric_difference_eq_x!(&a, &b, &x);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = [1, 2, 3];
+/// let b = [2, 3, 4];
+/// let x = [1];
+/// assert_set_symmetric_difference_eq_x!(&a, &b, &x);
+/// # });
+/// // assertion failed: `assert_set_symmetric_difference_eq_x!(a_collection, b_collection, x_collection)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_set_symmetric_difference_eq_x.html
+/// //    a label: `&a`,
+/// //    a debug: `[1, 2, 3]`,
+/// //    b label: `&b`,
+/// //    b debug: `[2, 3, 4]`,
+/// //    x label: `&x`,
+/// //    x debug: `[1]`,
+/// //  only in a: `{1}`,
+/// //  only in b: `{4}`,
+/// //          x: `{1}`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_set_symmetric_difference_eq_x!(a_collection, b_collection, x_collection)`\n",
+/// #     assertables_url_line!("assert_set_symmetric_difference_eq_x"),
+/// #     "   a label: `&a`,\n",
+/// #     "   a debug: `[1, 2, 3]`,\n",
+/// #     "   b label: `&b`,\n",
+/// #     "   b debug: `[2, 3, 4]`,\n",
+/// #     "   x label: `&x`,\n",
+/// #     "   x debug: `[1]`,\n",
+/// #     " only in a: `{1}`,\n",
+/// #     " only in b: `{4}`,\n",
+/// #     "         x: `{1}`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The symmetric difference is the set of elements that are in exactly one
+/// of the two sets. This macro is useful for large sets that differ by only
+/// a few elements, because the failure message shows the elements that are
+/// only in a, and the elements that are only in b, rather than only the full sets.
+///
+/// This implementation uses [`::std::collections::BTreeSet`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html) to count items and sort them.
+///
+/// # Module macros
+///
+/// * [`assert_set_symmetric_difference_eq_x`](macro@crate::assert_set_symmetric_difference_eq_x)
+/// * [`assert_set_symmetric_difference_eq_x_as_result`](macro@crate::assert_set_symmetric_difference_eq_x_as_result)
+/// * [`debug_assert_set_symmetric_difference_eq_x`](macro@crate::debug_assert_set_symmetric_difference_eq_x)
+///
+#[macro_export]
+macro_rules! assert_set_symmetric_difference_eq_x {
+    ($a_collection:expr, $b_collection:expr, $x_collection:expr $(,)?) => {{
+        match $crate::assert_set_symmetric_difference_eq_x_as_result!($a_collection, $b_collection, $x_collection) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_collection:expr, $b_collection:expr, $x_collection:expr, $($message:tt)+) => {{
+        match $crate::assert_set_symmetric_difference_eq_x_as_result!($a_collection, $b_collection, $x_collection) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_set_symmetric_difference_eq_x {
+    use std::collections::BTreeSet;
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = [1, 2, 3];
+        let b = [2, 3, 4];
+        let x = [1, 4];
+        let actual = assert_set_symmetric_difference_eq_x!(&a, &b, &x);
+        assert_eq!(actual, BTreeSet::from([&1, &4]));
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = [1, 2, 3];
+            let b = [2, 3, 4];
+            let x = [1];
+            let _actual = assert_set_symmetric_difference_eq_x!(&a, &b, &x);
+        });
+        let message = concat!(
+            "assertion failed: `assert_set_symmetric_difference_eq_x!(a_collection, b_collection, x_collection)`\n",
+            assertables_url_line!("assert_set_symmetric_difference_eq_x"),
+            "   a label: `&a`,\n",
+            "   a debug: `[1, 2, 3]`,\n",
+            "   b label: `&b`,\n",
+            "   b debug: `[2, 3, 4]`,\n",
+            "   x label: `&x`,\n",
+            "   x debug: `[1]`,\n",
+            " only in a: `{1}`,\n",
+            " only in b: `{4}`,\n",
+            "         x: `{1}`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert the symmetric difference of two sets is equal to an expression.
+///
+/// Pseudocode:<br>
+/// ((a_collection ⇒ a_set) △ (b_collection ⇒ b_set)) = (x_collection ⇒ x_set)
+///
+/// This macro provides the same statements as [`assert_set_symmetric_difference_eq_x`](macro.assert_set_symmetric_difference_eq_x.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_set_symmetric_difference_eq_x`](macro@crate::assert_set_symmetric_difference_eq_x)
+/// * [`assert_set_symmetric_difference_eq_x_as_result`](macro@crate::assert_set_symmetric_difference_eq_x_as_result)
+/// * [`debug_assert_set_symmetric_difference_eq_x`](macro@crate::debug_assert_set_symmetric_difference_eq_x)
+///
+#[macro_export]
+macro_rules! debug_assert_set_symmetric_difference_eq_x {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_set_symmetric_difference_eq_x!($($arg)*);
+        }
+    };
+}
@@ -17,12 +17,36 @@
 //!
 //! * [`assert_set_superset!(collection1, collection2)`](macro@crate::assert_set_superset) ≈ set a ⊇ set b
 //!
+//! * [`assert_set_subset_proper!(collection1, collection2)`](macro@crate::assert_set_subset_proper) ≈ set a ⊂ set b
+//!
+//! * [`assert_set_superset_proper!(collection1, collection2)`](macro@crate::assert_set_superset_proper) ≈ set a ⊃ set b
+//!
 //! For joint & disjoint:
 //!
 //! * [`assert_set_joint!(collection1, collection2)`](macro@crate::assert_set_joint) ≈ set a ∩ set b ≠ ∅
 //!
 //! * [`assert_set_disjoint!(collection1, collection2)`](macro@crate::assert_set_disjoint) ≈ set a ∩ set b = ∅
 //!
+//! For set length, meaning the count of distinct items:
+//!
+//! * [`assert_set_len_eq!(collection1, collection2)`](macro@crate::assert_set_len_eq) ≈ (set a).len() = (set b).len()
+//! * [`assert_set_len_ne!(collection1, collection2)`](macro@crate::assert_set_len_ne) ≈ (set a).len() ≠ (set b).len()
+//! * [`assert_set_len_lt!(collection1, collection2)`](macro@crate::assert_set_len_lt) ≈ (set a).len() < (set b).len()
+//! * [`assert_set_len_le!(collection1, collection2)`](macro@crate::assert_set_len_le) ≈ (set a).len() ≤ (set b).len()
+//! * [`assert_set_len_gt!(collection1, collection2)`](macro@crate::assert_set_len_gt) ≈ (set a).len() > (set b).len()
+//! * [`assert_set_len_ge!(collection1, collection2)`](macro@crate::assert_set_len_ge) ≈ (set a).len() ≥ (set b).len()
+//!
+//! * [`assert_set_len_eq_x!(collection, expr)`](macro@crate::assert_set_len_eq_x) ≈ (set a).len() = expr
+//! * [`assert_set_len_ne_x!(collection, expr)`](macro@crate::assert_set_len_ne_x) ≈ (set a).len() ≠ expr
+//! * [`assert_set_len_lt_x!(collection, expr)`](macro@crate::assert_set_len_lt_x) ≈ (set a).len() < expr
+//! * [`assert_set_len_le_x!(collection, expr)`](macro@crate::assert_set_len_le_x) ≈ (set a).len() ≤ expr
+//! * [`assert_set_len_gt_x!(collection, expr)`](macro@crate::assert_set_len_gt_x) ≈ (set a).len() > expr
+//! * [`assert_set_len_ge_x!(collection, expr)`](macro@crate::assert_set_len_ge_x) ≈ (set a).len() ≥ expr
+//!
+//! For symmetric difference:
+//!
+//! * [`assert_set_symmetric_difference_eq_x!(collection1, collection2, collection3)`](macro@crate::assert_set_symmetric_difference_eq_x) ≈ set a △ set b = set x
+//!
 //!
 //! # Example
 //!
@@ -113,4 +137,25 @@ pub mod assert_set_joint;
 
 // Containers
 pub mod assert_set_subset;
+pub mod assert_set_subset_proper;
 pub mod assert_set_superset;
+pub mod assert_set_superset_proper;
+
+// Set length compare another
+pub mod assert_set_len_eq;
+pub mod assert_set_len_ge;
+pub mod assert_set_len_gt;
+pub mod assert_set_len_le;
+pub mod assert_set_len_lt;
+pub mod assert_set_len_ne;
+
+// Set length compare expression
+pub mod assert_set_len_eq_x;
+pub mod assert_set_len_ge_x;
+pub mod assert_set_len_gt_x;
+pub mod assert_set_len_le_x;
+pub mod assert_set_len_lt_x;
+pub mod assert_set_len_ne_x;
+
+// Differences
+pub mod assert_set_symmetric_difference_eq_x;
@@ -39,17 +39,17 @@
 #[macro_export]
 macro_rules! assert_some_as_result {
     ($a:expr $(,)?) => {
-        match ($a) {
+        match $a {
             Some(a1) => Ok(a1),
-            _ => Err(format!(
+            a => Err(format!(
                 concat!(
                     "assertion failed: `assert_some!(a)`\n",
-                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some.html\n",
+                    $crate::assertables_url_line!("assert_some"),
                     " option label: `{}`,\n",
                     " option debug: `{:?}`",
                 ),
                 stringify!($a),
-                $a
+                a
             )),
         }
     };
@@ -57,6 +57,7 @@ macro_rules! assert_some_as_result {
 
 #[cfg(test)]
 mod test_assert_some_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn success() {
@@ -71,12 +72,25 @@ mod test_assert_some_as_result {
         let actual = assert_some_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_some!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some.html\n",
+            assertables_url_line!("assert_some"),
             " option label: `a`,\n",
             " option debug: `None`",
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        fn a(x: Option<i8>) -> Option<i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        let _ = assert_some_as_result!(a(Some(1)));
+        assert_eq!(A.load(Ordering::SeqCst), 1);
+        let _ = assert_some_as_result!(a(None));
+        assert_eq!(A.load(Ordering::SeqCst), 2);
+    }
 }
 
 /// Assert expression is Some.
@@ -111,7 +125,7 @@ mod test_assert_some_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_some!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some.html\n",
+/// #     assertables_url_line!("assert_some"),
 /// #     " option label: `a`,\n",
 /// #     " option debug: `None`",
 /// # );
@@ -130,13 +144,13 @@ macro_rules! assert_some {
     ($a:expr $(,)?) => {{
         match $crate::assert_some_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_some_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -160,7 +174,7 @@ mod test_assert_some {
         });
         let message = concat!(
             "assertion failed: `assert_some!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some.html\n",
+            assertables_url_line!("assert_some"),
             " option label: `a`,\n",
             " option debug: `None`",
         );
@@ -209,7 +223,7 @@ mod test_assert_some {
 #[macro_export]
 macro_rules! debug_assert_some {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_some!($($arg)*);
         }
     };
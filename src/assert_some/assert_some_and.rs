@@ -0,0 +1,322 @@
+//! Assert expression is Some, and its inner value matches a predicate.
+//!
+//! Pseudocode:<br>
+//! a is Some(a1) ⇒ predicate(a1)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a: Option<i8> = Option::Some(1);
+//! assert_some_and!(a, |x: &i8| *x > 0);
+//! ```
+//!
+//! This macro evaluates the expression `a` exactly once.
+//!
+//! * The predicate is called with a reference to the inner value.
+//!
+//! * The failure message distinguishes a `None` from a predicate that returns false.
+//!
+//! # Module macros
+//!
+//! * [`assert_some_and`](macro@crate::assert_some_and)
+//! * [`assert_some_and_as_result`](macro@crate::assert_some_and_as_result)
+//! * [`debug_assert_some_and`](macro@crate::debug_assert_some_and)
+
+/// Assert expression is Some, and its inner value matches a predicate.
+///
+/// Pseudocode:<br>
+/// a is Some(a1) ⇒ predicate(a1)
+///
+/// * If true, return Result `Ok(a1)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_some_and`](macro@crate::assert_some_and)
+/// * [`assert_some_and_as_result`](macro@crate::assert_some_and_as_result)
+/// * [`debug_assert_some_and`](macro@crate::debug_assert_some_and)
+///
+#[macro_export]
+macro_rules! assert_some_and_as_result {
+    ($a:expr, $predicate:expr $(,)?) => {{
+        match ($a) {
+            Some(a1) => {
+                if $crate::__private::call_predicate(&a1, $predicate) {
+                    Ok(a1)
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_some_and!(a, predicate)`\n",
+                                $crate::assertables_url_line!("assert_some_and"),
+                                " option label: `{}`,\n",
+                                " option debug: `{:?}`,\n",
+                                "    predicate: `{}`,\n",
+                                "       reason: `predicate is false`"
+                            ),
+                            stringify!($a),
+                            Some(&a1),
+                            stringify!($predicate)
+                        )
+                    )
+                }
+            },
+            None => {
+                Err(
+                    format!(
+                        concat!(
+                            "assertion failed: `assert_some_and!(a, predicate)`\n",
+                            $crate::assertables_url_line!("assert_some_and"),
+                            " option label: `{}`,\n",
+                            " option debug: `None`,\n",
+                            "    predicate: `{}`,\n",
+                            "       reason: `option is None`"
+                        ),
+                        stringify!($a),
+                        stringify!($predicate)
+                    )
+                )
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_some_and_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    #[test]
+    fn success() {
+        let a: Option<i8> = Option::Some(1);
+        let actual = assert_some_and_as_result!(a, |x: &i8| *x > 0);
+        assert_eq!(actual.unwrap(), 1);
+    }
+
+    #[test]
+    fn failure() {
+        let a: Option<i8> = Option::Some(-1);
+        let actual = assert_some_and_as_result!(a, |x: &i8| *x > 0);
+        let message = concat!(
+            "assertion failed: `assert_some_and!(a, predicate)`\n",
+            assertables_url_line!("assert_some_and"),
+            " option label: `a`,\n",
+            " option debug: `Some(-1)`,\n",
+            "    predicate: `|x: &i8| *x > 0`,\n",
+            "       reason: `predicate is false`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_none() {
+        let a: Option<i8> = Option::None;
+        let actual = assert_some_and_as_result!(a, |x: &i8| *x > 0);
+        let message = concat!(
+            "assertion failed: `assert_some_and!(a, predicate)`\n",
+            assertables_url_line!("assert_some_and"),
+            " option label: `a`,\n",
+            " option debug: `None`,\n",
+            "    predicate: `|x: &i8| *x > 0`,\n",
+            "       reason: `option is None`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_returns_owned_inner_value() {
+        let a: Option<String> = Option::Some(String::from("alfa"));
+        let actual = assert_some_and_as_result!(a, |x: &String| x.starts_with('a'));
+        assert_eq!(actual.unwrap(), String::from("alfa"));
+    }
+
+    #[test]
+    fn evaluates_expression_once() {
+        static COUNT: AtomicU32 = AtomicU32::new(0);
+        fn f(x: Option<i8>) -> Option<i8> {
+            COUNT.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        let _ = assert_some_and_as_result!(f(Some(1)), |x: &i8| *x > 0);
+        assert_eq!(COUNT.load(Ordering::SeqCst), 1);
+        let _ = assert_some_and_as_result!(f(Some(-1)), |x: &i8| *x > 0);
+        assert_eq!(COUNT.load(Ordering::SeqCst), 2);
+        let _ = assert_some_and_as_result!(f(None), |x: &i8| *x > 0);
+        assert_eq!(COUNT.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn success_with_closure_without_type_annotation() {
+        let a: Option<i8> = Option::Some(2);
+        let actual = assert_some_and_as_result!(a, |x| *x > 1);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn evaluates_option_before_predicate() {
+        static ORDER: AtomicU32 = AtomicU32::new(0);
+        let actual = assert_some_and_as_result!(
+            {
+                ORDER.store(1, Ordering::SeqCst);
+                Some(2)
+            },
+            {
+                assert_eq!(ORDER.load(Ordering::SeqCst), 1);
+                |x: &i8| *x > 1
+            }
+        );
+        assert_eq!(actual.unwrap(), 2);
+    }
+}
+
+/// Assert expression is Some, and its inner value matches a predicate.
+///
+/// Pseudocode:<br>
+/// a is Some(a1) ⇒ predicate(a1)
+///
+/// * If true, return `a1`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a: Option<i8> = Option::Some(1);
+/// assert_some_and!(a, |x: &i8| *x > 0);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a: Option<i8> = Option::Some(-1);
+/// assert_some_and!(a, |x: &i8| *x > 0);
+/// # });
+/// // assertion failed: `assert_some_and!(a, predicate)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_and.html
+/// //  option label: `a`,
+/// //  option debug: `Some(-1)`,
+/// //     predicate: `|x: &i8| *x > 0`,
+/// //        reason: `predicate is false`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_some_and!(a, predicate)`\n",
+/// #     assertables_url_line!("assert_some_and"),
+/// #     " option label: `a`,\n",
+/// #     " option debug: `Some(-1)`,\n",
+/// #     "    predicate: `|x: &i8| *x > 0`,\n",
+/// #     "       reason: `predicate is false`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro evaluates the expression `a` exactly once.
+///
+/// * The predicate is called with a reference to the inner value.
+///
+/// * The failure message distinguishes a `None` from a predicate that returns false.
+///
+/// # Module macros
+///
+/// * [`assert_some_and`](macro@crate::assert_some_and)
+/// * [`assert_some_and_as_result`](macro@crate::assert_some_and_as_result)
+/// * [`debug_assert_some_and`](macro@crate::debug_assert_some_and)
+///
+#[macro_export]
+macro_rules! assert_some_and {
+    ($a:expr, $predicate:expr $(,)?) => {{
+        match $crate::assert_some_and_as_result!($a, $predicate) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $predicate:expr, $($message:tt)+) => {{
+        match $crate::assert_some_and_as_result!($a, $predicate) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_some_and {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a: Option<i8> = Option::Some(1);
+        let actual = assert_some_and!(a, |x: &i8| *x > 0);
+        assert_eq!(actual, 1);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a: Option<i8> = Option::Some(-1);
+            let _actual = assert_some_and!(a, |x: &i8| *x > 0);
+        });
+        let message = concat!(
+            "assertion failed: `assert_some_and!(a, predicate)`\n",
+            assertables_url_line!("assert_some_and"),
+            " option label: `a`,\n",
+            " option debug: `Some(-1)`,\n",
+            "    predicate: `|x: &i8| *x > 0`,\n",
+            "       reason: `predicate is false`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert expression is Some, and its inner value matches a predicate.
+///
+/// Pseudocode:<br>
+/// a is Some(a1) ⇒ predicate(a1)
+///
+/// This macro provides the same statements as [`assert_some_and`](macro.assert_some_and.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_some_and`](macro@crate::assert_some_and)
+/// * [`assert_some_and_as_result`](macro@crate::assert_some_and_as_result)
+/// * [`debug_assert_some_and`](macro@crate::debug_assert_some_and)
+///
+#[macro_export]
+macro_rules! debug_assert_some_and {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_some_and!($($arg)*);
+        }
+    };
+}
@@ -52,48 +52,47 @@ macro_rules! assert_some_ne_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_some_ne!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne.html\n",
+                                $crate::assertables_url_line!("assert_some_ne"),
                                 " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
+                                " a debug: `Some({:?})`,\n",
                                 " a inner: `{:?}`,\n",
                                 " b label: `{}`,\n",
-                                " b debug: `{:?}`,\n",
+                                " b debug: `Some({:?})`,\n",
                                 " b inner: `{:?}`"
                             ),
                             stringify!($a),
-                            $a,
+                            a1,
                             a1,
                             stringify!($b),
-                            $b,
+                            b1,
                             b1
                         )
                     )
                 }
-            },
-            _ => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_some_ne!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`",
-                        ),
-                        stringify!($a),
-                        $a,
-                        stringify!($b),
-                        $b,
-                    )
-                )
             }
+            (a, b) => Err(
+                format!(
+                    concat!(
+                        "assertion failed: `assert_some_ne!(a, b)`\n",
+                        $crate::assertables_url_line!("assert_some_ne"),
+                        " a label: `{}`,\n",
+                        " a debug: `{:?}`,\n",
+                        " b label: `{}`,\n",
+                        " b debug: `{:?}`"
+                    ),
+                    stringify!($a),
+                    a,
+                    stringify!($b),
+                    b
+                )
+            ),
         }
     };
 }
 
 #[cfg(test)]
 mod test_assert_some_ne_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn ne() {
@@ -110,7 +109,7 @@ mod test_assert_some_ne_as_result {
         let actual = assert_some_ne_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_some_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne.html\n",
+            assertables_url_line!("assert_some_ne"),
             " a label: `a`,\n",
             " a debug: `Some(1)`,\n",
             " a inner: `1`,\n",
@@ -128,7 +127,7 @@ mod test_assert_some_ne_as_result {
         let actual = assert_some_ne_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_some_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne.html\n",
+            assertables_url_line!("assert_some_ne"),
             " a label: `a`,\n",
             " a debug: `None`,\n",
             " b label: `b`,\n",
@@ -136,6 +135,28 @@ mod test_assert_some_ne_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        static B: AtomicU32 = AtomicU32::new(0);
+        fn a() -> Option<i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            Some(1)
+        }
+        fn b(x: Option<i8>) -> Option<i8> {
+            B.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        // Success
+        let _ = assert_some_ne_as_result!(a(), b(Some(2)));
+        // Failure because inner values
+        let _ = assert_some_ne_as_result!(a(), b(Some(1)));
+        // Failure because not some
+        let _ = assert_some_ne_as_result!(a(), b(None));
+        assert_eq!(A.load(Ordering::SeqCst), 3);
+        assert_eq!(B.load(Ordering::SeqCst), 3);
+    }
 }
 
 /// Assert two expressions are Some and their values are not equal.
@@ -176,7 +197,7 @@ mod test_assert_some_ne_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_some_ne!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne.html\n",
+/// #     assertables_url_line!("assert_some_ne"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Some(1)`,\n",
 /// #     " a inner: `1`,\n",
@@ -199,13 +220,13 @@ macro_rules! assert_some_ne {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_some_ne_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_some_ne_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -231,7 +252,7 @@ mod test_assert_some_ne {
         });
         let message = concat!(
             "assertion failed: `assert_some_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne.html\n",
+            assertables_url_line!("assert_some_ne"),
             " a label: `a`,\n",
             " a debug: `Some(1)`,\n",
             " a inner: `1`,\n",
@@ -258,7 +279,7 @@ mod test_assert_some_ne {
         });
         let message = concat!(
             "assertion failed: `assert_some_ne!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne.html\n",
+            assertables_url_line!("assert_some_ne"),
             " a label: `a`,\n",
             " a debug: `None`,\n",
             " b label: `b`,\n",
@@ -309,7 +330,7 @@ mod test_assert_some_ne {
 #[macro_export]
 macro_rules! debug_assert_some_ne {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_some_ne!($($arg)*);
         }
     };
@@ -43,55 +43,54 @@
 #[macro_export]
 macro_rules! assert_some_ne_x_as_result {
     ($a:expr, $b:expr $(,)?) => {
-        match ($a) {
-            Some(a1) => {
-                if a1 != $b {
+        match ($a, &$b) {
+            (Some(a1), b) => {
+                if a1 != *b {
                     Ok(a1)
                 } else {
                     Err(
                         format!(
                             concat!(
                                 "assertion failed: `assert_some_ne_x!(a, b)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne_x.html\n",
+                                $crate::assertables_url_line!("assert_some_ne_x"),
                                 " a label: `{}`,\n",
-                                " a debug: `{:?}`,\n",
+                                " a debug: `Some({:?})`,\n",
                                 " a inner: `{:?}`,\n",
                                 " b label: `{}`,\n",
                                 " b debug: `{:?}`"
                             ),
                             stringify!($a),
-                            $a,
+                            a1,
                             a1,
                             stringify!($b),
-                            $b
+                            b
                         )
                     )
                 }
-            },
-            _ => {
-                Err(
-                    format!(
-                        concat!(
-                            "assertion failed: `assert_some_ne_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne_x.html\n",
-                            " a label: `{}`,\n",
-                            " a debug: `{:?}`,\n",
-                            " b label: `{}`,\n",
-                            " b debug: `{:?}`",
-                        ),
-                        stringify!($a),
-                        $a,
-                        stringify!($b),
-                        $b,
-                    )
-                )
             }
+            (a, b) => Err(
+                format!(
+                    concat!(
+                        "assertion failed: `assert_some_ne_x!(a, b)`\n",
+                        $crate::assertables_url_line!("assert_some_ne_x"),
+                        " a label: `{}`,\n",
+                        " a debug: `{:?}`,\n",
+                        " b label: `{}`,\n",
+                        " b debug: `{:?}`"
+                    ),
+                    stringify!($a),
+                    a,
+                    stringify!($b),
+                    b
+                )
+            ),
         }
     };
 }
 
 #[cfg(test)]
 mod test_assert_some_ne_x_as_result {
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn ne() {
@@ -108,7 +107,7 @@ mod test_assert_some_ne_x_as_result {
         let actual = assert_some_ne_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_some_ne_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne_x.html\n",
+            assertables_url_line!("assert_some_ne_x"),
             " a label: `a`,\n",
             " a debug: `Some(1)`,\n",
             " a inner: `1`,\n",
@@ -125,7 +124,7 @@ mod test_assert_some_ne_x_as_result {
         let actual = assert_some_ne_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_some_ne_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne_x.html\n",
+            assertables_url_line!("assert_some_ne_x"),
             " a label: `a`,\n",
             " a debug: `None`,\n",
             " b label: `b`,\n",
@@ -133,6 +132,28 @@ mod test_assert_some_ne_x_as_result {
         );
         assert_eq!(actual.unwrap_err(), message);
     }
+
+    #[test]
+    fn evaluates_each_expression_once() {
+        static A: AtomicU32 = AtomicU32::new(0);
+        static B: AtomicU32 = AtomicU32::new(0);
+        fn a(x: Option<i8>) -> Option<i8> {
+            A.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        fn b(x: i8) -> i8 {
+            B.fetch_add(1, Ordering::SeqCst);
+            x
+        }
+        // Success
+        let _ = assert_some_ne_x_as_result!(a(Some(1)), b(2));
+        // Failure because inner value
+        let _ = assert_some_ne_x_as_result!(a(Some(1)), b(1));
+        // Failure because not some
+        let _ = assert_some_ne_x_as_result!(a(None), b(2));
+        assert_eq!(A.load(Ordering::SeqCst), 3);
+        assert_eq!(B.load(Ordering::SeqCst), 3);
+    }
 }
 
 /// Assert an expression is Some and its value is not equal to an expression.
@@ -172,7 +193,7 @@ mod test_assert_some_ne_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_some_ne_x!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne_x.html\n",
+/// #     assertables_url_line!("assert_some_ne_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `Some(1)`,\n",
 /// #     " a inner: `1`,\n",
@@ -194,13 +215,13 @@ macro_rules! assert_some_ne_x {
     ($a:expr, $b:expr $(,)?) => {{
         match $crate::assert_some_ne_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_some_ne_x_as_result!($a, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -226,7 +247,7 @@ mod test_assert_some_ne_x {
         });
         let message = concat!(
             "assertion failed: `assert_some_ne_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne_x.html\n",
+            assertables_url_line!("assert_some_ne_x"),
             " a label: `a`,\n",
             " a debug: `Some(1)`,\n",
             " a inner: `1`,\n",
@@ -252,7 +273,7 @@ mod test_assert_some_ne_x {
         });
         let message = concat!(
             "assertion failed: `assert_some_ne_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_some_ne_x.html\n",
+            assertables_url_line!("assert_some_ne_x"),
             " a label: `a`,\n",
             " a debug: `None`,\n",
             " b label: `b`,\n",
@@ -303,7 +324,7 @@ mod test_assert_some_ne_x {
 #[macro_export]
 macro_rules! debug_assert_some_ne_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_some_ne_x!($($arg)*);
         }
     };
@@ -7,6 +7,10 @@
 //! * [`assert_some!(a)`](macro@crate::assert_some)
 //!   ≈ a is Some
 //!
+//! Assert expression is Some, and its inner value matches a predicate:
+//!
+//! * [`assert_some_and!(a, predicate)`](macro@crate::assert_some_and) ≈ a is Some(a1) ⇒ predicate(a1)
+//!
 //! Compare Some(…) to another Some(…):
 //!
 //! * [`assert_some_eq!(a, b)`](macro@crate::assert_some_eq) ≈ (a ⇒ Some(a1) ⇒ a1) = (b ⇒ Some(b1) ⇒ b1)
@@ -28,6 +32,7 @@
 
 // Verify Some(_)
 pub mod assert_some;
+pub mod assert_some_and;
 
 // Compare another
 pub mod assert_some_eq;
@@ -0,0 +1,21 @@
+//! Assert for sorted collections.
+//!
+//! These macros help with any collection that can be iterated by reference,
+//! such as an array or a vector, where the items implement `Ord`.
+//!
+//! * [`assert_sorted!(collection)`](macro@crate::assert_sorted) ≈ collection is sorted ascending
+//!
+//! * [`assert_sorted_descending!(collection)`](macro@crate::assert_sorted_descending) ≈ collection is sorted descending
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = [1, 2, 3];
+//! assert_sorted!(a);
+//! ```
+
+#[allow(clippy::module_inception)]
+pub mod assert_sorted;
+pub mod assert_sorted_descending;
@@ -55,7 +55,7 @@ macro_rules! assert_starts_with_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_starts_with!(sequence, subsequence)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_starts_with.html\n",
+                                $crate::assertables_url_line!("assert_starts_with"),
                                 "     sequence label: `{}`,\n",
                                 "     sequence debug: `{:?}`,\n",
                                 "  subsequence label: `{}`,\n",
@@ -91,7 +91,7 @@ mod test_assert_starts_with_as_result {
         let actual = assert_starts_with_as_result!(sequence, subsequence);
         let message = concat!(
             "assertion failed: `assert_starts_with!(sequence, subsequence)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_starts_with.html\n",
+            assertables_url_line!("assert_starts_with"),
             "     sequence label: `sequence`,\n",
             "     sequence debug: `\"alfa\"`,\n",
             "  subsequence label: `subsequence`,\n",
@@ -143,7 +143,7 @@ mod test_assert_starts_with_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_starts_with!(sequence, subsequence)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_starts_with.html\n",
+/// #     assertables_url_line!("assert_starts_with"),
 /// #     "     sequence label: `sequence`,\n",
 /// #     "     sequence debug: `\"alfa\"`,\n",
 /// #     "  subsequence label: `subsequence`,\n",
@@ -164,13 +164,13 @@ macro_rules! assert_starts_with {
     ($sequence:expr, $subsequence:expr $(,)?) => {{
         match $crate::assert_starts_with_as_result!($sequence, $subsequence) {
             Ok(()) => (),
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($sequence:expr, $subsequence:expr, $($message:tt)+) => {{
         match $crate::assert_starts_with_as_result!($sequence, $subsequence) {
             Ok(()) => (),
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -196,7 +196,7 @@ mod test_assert_starts_with {
         });
         let message = concat!(
             "assertion failed: `assert_starts_with!(sequence, subsequence)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_starts_with.html\n",
+            assertables_url_line!("assert_starts_with"),
             "     sequence label: `sequence`,\n",
             "     sequence debug: `\"alfa\"`,\n",
             "  subsequence label: `subsequence`,\n",
@@ -247,7 +247,7 @@ mod test_assert_starts_with {
 #[macro_export]
 macro_rules! debug_assert_starts_with {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_starts_with!($($arg)*);
         }
     };
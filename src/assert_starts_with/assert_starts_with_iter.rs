@@ -0,0 +1,313 @@
+//! Assert an iterator starts with the items of a prefix iterator.
+//!
+//! Pseudocode:<br>
+//! (iter into iter).take(prefix count) = (prefix into iter)
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let iter = 1..;
+//! let prefix = [1, 2, 3];
+//! assert_starts_with_iter!(iter, prefix);
+//! ```
+//!
+//! This macro is useful for a streaming iterator, because it consumes only as
+//! many items of the iterator as the prefix has, and compares each pair of items.
+//!
+//! * The arguments can be anything that implements `IntoIterator`, such as an
+//!   iterator, an array, or a vector. The arguments are moved, so to keep using
+//!   an iterator, pass `iter.by_ref()` or `&mut iter`.
+//!
+//! * On success, the return value is the rest of the iterator, which
+//!   you can use for further testing.
+//!
+//! * The failure message shows the index, the expected item, and the actual
+//!   item, which is `None` when the iterator ends before the prefix ends.
+//!
+//! This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+//!
+//! # Module macros
+//!
+//! * [`assert_starts_with_iter`](macro@crate::assert_starts_with_iter)
+//! * [`assert_starts_with_iter_as_result`](macro@crate::assert_starts_with_iter_as_result)
+//! * [`debug_assert_starts_with_iter`](macro@crate::debug_assert_starts_with_iter)
+
+/// Assert an iterator starts with the items of a prefix iterator.
+///
+/// Pseudocode:<br>
+/// (iter into iter).take(prefix count) = (prefix into iter)
+///
+/// * If true, return Result `Ok(rest)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_starts_with_iter`](macro@crate::assert_starts_with_iter)
+/// * [`assert_starts_with_iter_as_result`](macro@crate::assert_starts_with_iter_as_result)
+/// * [`debug_assert_starts_with_iter`](macro@crate::debug_assert_starts_with_iter)
+///
+#[macro_export]
+macro_rules! assert_starts_with_iter_as_result {
+    ($iter:expr, $prefix:expr $(,)?) => {{
+        match ($iter, $prefix) {
+            (iter, prefix) => {
+                #[allow(unused_mut)]
+                let mut iter = ::std::iter::IntoIterator::into_iter(iter);
+                let mut mismatch = None;
+                for (index, expected) in ::std::iter::IntoIterator::into_iter(prefix).enumerate() {
+                    match iter.next() {
+                        Some(actual) if actual == expected => {}
+                        actual => {
+                            mismatch = Some((index, expected, actual));
+                            break;
+                        }
+                    }
+                }
+                match mismatch {
+                    None => Ok(iter),
+                    Some((index, expected, actual)) => Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_starts_with_iter!(iter, prefix)`\n",
+                                $crate::assertables_url_line!("assert_starts_with_iter"),
+                                "   iter label: `{}`,\n",
+                                " prefix label: `{}`,\n",
+                                "        index: `{}`,\n",
+                                "     expected: `{:?}`,\n",
+                                "       actual: `{:?}`"
+                            ),
+                            stringify!($iter),
+                            stringify!($prefix),
+                            index,
+                            expected,
+                            actual
+                        )
+                    ),
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_starts_with_iter_as_result {
+    #[test]
+    fn success() {
+        let iter = 1..;
+        let prefix = [1, 2, 3];
+        let actual = assert_starts_with_iter_as_result!(iter, prefix);
+        assert_eq!(actual.unwrap(), 4..);
+    }
+
+    #[test]
+    fn failure() {
+        let iter = 1..;
+        let prefix = [1, 3];
+        let actual = assert_starts_with_iter_as_result!(iter, prefix);
+        let message = concat!(
+            "assertion failed: `assert_starts_with_iter!(iter, prefix)`\n",
+            assertables_url_line!("assert_starts_with_iter"),
+            "   iter label: `iter`,\n",
+            " prefix label: `prefix`,\n",
+            "        index: `1`,\n",
+            "     expected: `3`,\n",
+            "       actual: `Some(2)`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_returns_the_rest() {
+        let mut iter = [1, 2, 3, 4].into_iter();
+        let actual = assert_starts_with_iter_as_result!(iter.by_ref(), [1, 2]);
+        assert!(actual.is_ok());
+        assert_eq!(iter.next(), Some(3));
+    }
+
+    #[test]
+    fn failure_because_iter_ends() {
+        let iter = vec!["alfa"];
+        let prefix = vec!["alfa", "bravo"];
+        let actual = assert_starts_with_iter_as_result!(iter, prefix);
+        let message = concat!(
+            "assertion failed: `assert_starts_with_iter!(iter, prefix)`\n",
+            assertables_url_line!("assert_starts_with_iter"),
+            "   iter label: `iter`,\n",
+            " prefix label: `prefix`,\n",
+            "        index: `1`,\n",
+            "     expected: `\"bravo\"`,\n",
+            "       actual: `None`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert an iterator starts with the items of a prefix iterator.
+///
+/// Pseudocode:<br>
+/// (iter into iter).take(prefix count) = (prefix into iter)
+///
+/// * If true, return `rest`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let iter = 1..;
+/// let prefix = [1, 2, 3];
+/// assert_starts_with_iter!(iter, prefix);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let iter = 1..;
+/// let prefix = [1, 3];
+/// assert_starts_with_iter!(iter, prefix);
+/// # });
+/// // assertion failed: `assert_starts_with_iter!(iter, prefix)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_starts_with_iter.html
+/// //    iter label: `iter`,
+/// //  prefix label: `prefix`,
+/// //         index: `1`,
+/// //      expected: `3`,
+/// //        actual: `Some(2)`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_starts_with_iter!(iter, prefix)`\n",
+/// #     assertables_url_line!("assert_starts_with_iter"),
+/// #     "   iter label: `iter`,\n",
+/// #     " prefix label: `prefix`,\n",
+/// #     "        index: `1`,\n",
+/// #     "     expected: `3`,\n",
+/// #     "       actual: `Some(2)`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for a streaming iterator, because it consumes only as
+/// many items of the iterator as the prefix has, and compares each pair of items.
+///
+/// * The arguments can be anything that implements `IntoIterator`, such as an
+///   iterator, an array, or a vector. The arguments are moved, so to keep using
+///   an iterator, pass `iter.by_ref()` or `&mut iter`.
+///
+/// * On success, the return value is the rest of the iterator, which
+///   you can use for further testing.
+///
+/// * The failure message shows the index, the expected item, and the actual
+///   item, which is `None` when the iterator ends before the prefix ends.
+///
+/// This implementation uses [`::std::iter::Iterator`](https://doc.rust-lang.org/std/iter/trait.Iterator.html).
+///
+/// # Module macros
+///
+/// * [`assert_starts_with_iter`](macro@crate::assert_starts_with_iter)
+/// * [`assert_starts_with_iter_as_result`](macro@crate::assert_starts_with_iter_as_result)
+/// * [`debug_assert_starts_with_iter`](macro@crate::debug_assert_starts_with_iter)
+///
+#[macro_export]
+macro_rules! assert_starts_with_iter {
+    ($iter:expr, $prefix:expr $(,)?) => {{
+        match $crate::assert_starts_with_iter_as_result!($iter, $prefix) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($iter:expr, $prefix:expr, $($message:tt)+) => {{
+        match $crate::assert_starts_with_iter_as_result!($iter, $prefix) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_starts_with_iter {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let iter = 1..;
+        let prefix = [1, 2, 3];
+        let actual = assert_starts_with_iter!(iter, prefix);
+        assert_eq!(actual, 4..);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let iter = 1..;
+            let prefix = [1, 3];
+            let _actual = assert_starts_with_iter!(iter, prefix);
+        });
+        let message = concat!(
+            "assertion failed: `assert_starts_with_iter!(iter, prefix)`\n",
+            assertables_url_line!("assert_starts_with_iter"),
+            "   iter label: `iter`,\n",
+            " prefix label: `prefix`,\n",
+            "        index: `1`,\n",
+            "     expected: `3`,\n",
+            "       actual: `Some(2)`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert an iterator starts with the items of a prefix iterator.
+///
+/// Pseudocode:<br>
+/// (iter into iter).take(prefix count) = (prefix into iter)
+///
+/// This macro provides the same statements as [`assert_starts_with_iter`](macro.assert_starts_with_iter.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_starts_with_iter`](macro@crate::assert_starts_with_iter)
+/// * [`assert_starts_with_iter_as_result`](macro@crate::assert_starts_with_iter_as_result)
+/// * [`debug_assert_starts_with_iter`](macro@crate::debug_assert_starts_with_iter)
+///
+#[macro_export]
+macro_rules! debug_assert_starts_with_iter {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_starts_with_iter!($($arg)*);
+        }
+    };
+}
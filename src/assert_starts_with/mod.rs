@@ -7,6 +7,8 @@
 //!
 //! * [`assert_not_starts_with!(sequence, subsequence)`](macro@crate::assert_not_starts_with) ≈ !container.contains(containee)
 //!
+//! * [`assert_starts_with_iter!(iter, prefix)`](macro@crate::assert_starts_with_iter) ≈ (iter into iter).take(prefix count) = (prefix into iter)
+//!
 //!
 //! # Example
 //!
@@ -22,7 +24,13 @@
 //! let sequence = vec![1, 2, 3];
 //! let subsequence = [1];
 //! assert_starts_with!(sequence, subsequence);
+//!
+//! // Iterator starts with items?
+//! let iter = 1..;
+//! let prefix = [1, 2, 3];
+//! assert_starts_with_iter!(iter, prefix);
 //! ```
 
 pub mod assert_not_starts_with;
 pub mod assert_starts_with;
+pub mod assert_starts_with_iter;
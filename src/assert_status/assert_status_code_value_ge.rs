@@ -41,6 +41,8 @@
 #[macro_export]
 macro_rules! assert_status_code_value_ge_as_result {
     ($a_process:expr, $b_process:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_status::CapturedStatus as _;
         match ($a_process.status(), $b_process.status()) {
             (Ok(a1), Ok(b1)) => {
                 match (a1.code(), b1.code()) {
@@ -52,7 +54,7 @@ macro_rules! assert_status_code_value_ge_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_status_code_value_ge!(a, b)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_ge.html\n",
+                                        $crate::assertables_url_line!("assert_status_code_value_ge"),
                                         " a label: `{}`,\n",
                                         " a debug: `{:?}`,\n",
                                         " a value: `{:?}`,\n",
@@ -75,7 +77,7 @@ macro_rules! assert_status_code_value_ge_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_status_code_value_ge!(a, b)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_ge.html\n",
+                                    $crate::assertables_url_line!("assert_status_code_value_ge"),
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`,\n",
                                     "  a code: `{:?}`,\n",
@@ -99,7 +101,7 @@ macro_rules! assert_status_code_value_ge_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_status_code_value_ge!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_ge.html\n",
+                            $crate::assertables_url_line!("assert_status_code_value_ge"),
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             " a status: `{:?}`,\n",
@@ -153,7 +155,7 @@ mod test_assert_status_code_value_ge_as_result {
         let actual = assert_status_code_value_ge_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_status_code_value_ge!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_ge.html\n",
+            assertables_url_line!("assert_status_code_value_ge"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
             " a value: `1`,\n",
@@ -204,7 +206,7 @@ mod test_assert_status_code_value_ge_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_status_code_value_ge!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_ge.html\n",
+/// #     assertables_url_line!("assert_status_code_value_ge"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
 /// #     " a value: `1`,\n",
@@ -227,13 +229,13 @@ macro_rules! assert_status_code_value_ge {
     ($a_process:expr, $b_process:expr $(,)?) => {{
         match $crate::assert_status_code_value_ge_as_result!($a_process, $b_process) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_process:expr, $b_process:expr, $($message:tt)+) => {{
         match $crate::assert_status_code_value_ge_as_result!($a_process, $b_process) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -274,7 +276,7 @@ mod test_assert_status_code_value_ge {
         });
         let message = concat!(
             "assertion failed: `assert_status_code_value_ge!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_ge.html\n",
+            assertables_url_line!("assert_status_code_value_ge"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
             " a value: `1`,\n",
@@ -291,6 +293,18 @@ mod test_assert_status_code_value_ge {
             message
         );
     }
+
+    #[test]
+    fn debug_form_panics_only_with_debug_assertions() {
+        let result = panic::catch_unwind(|| {
+            let mut a = Command::new("bin/exit-with-arg");
+            a.arg("1");
+            let mut b = Command::new("bin/exit-with-arg");
+            b.arg("2");
+            crate::debug_assert_status_code_value_ge!(a, b);
+        });
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
 }
 
 /// Assert a status code value is greater than or equal to another.
@@ -327,7 +341,7 @@ mod test_assert_status_code_value_ge {
 #[macro_export]
 macro_rules! debug_assert_status_code_value_ge {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_status_code_value_ge!($($arg)*);
         }
     };
@@ -41,6 +41,8 @@
 #[macro_export]
 macro_rules! assert_status_code_value_gt_as_result {
     ($a_process:expr, $b_process:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_status::CapturedStatus as _;
         match ($a_process.status(), $b_process.status()) {
             (Ok(a1), Ok(b1)) => {
                 match (a1.code(), b1.code()) {
@@ -52,7 +54,7 @@ macro_rules! assert_status_code_value_gt_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_status_code_value_gt!(a, b)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_gt.html\n",
+                                        $crate::assertables_url_line!("assert_status_code_value_gt"),
                                         " a label: `{}`,\n",
                                         " a debug: `{:?}`,\n",
                                         " a value: `{:?}`,\n",
@@ -75,7 +77,7 @@ macro_rules! assert_status_code_value_gt_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_status_code_value_gt!(a, b)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_gt.html\n",
+                                    $crate::assertables_url_line!("assert_status_code_value_gt"),
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`,\n",
                                     "  a code: `{:?}`,\n",
@@ -99,7 +101,7 @@ macro_rules! assert_status_code_value_gt_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_status_code_value_gt!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_gt.html\n",
+                            $crate::assertables_url_line!("assert_status_code_value_gt"),
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             " a status: `{:?}`,\n",
@@ -143,7 +145,7 @@ mod test_assert_status_code_value_gt_as_result {
         let actual = assert_status_code_value_gt_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_status_code_value_gt!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_gt.html\n",
+            assertables_url_line!("assert_status_code_value_gt"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
             " a value: `1`,\n",
@@ -163,7 +165,7 @@ mod test_assert_status_code_value_gt_as_result {
         let actual = assert_status_code_value_gt_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_status_code_value_gt!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_gt.html\n",
+            assertables_url_line!("assert_status_code_value_gt"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
             " a value: `1`,\n",
@@ -214,7 +216,7 @@ mod test_assert_status_code_value_gt_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_status_code_value_gt!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_gt.html\n",
+/// #     assertables_url_line!("assert_status_code_value_gt"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
 /// #     " a value: `1`,\n",
@@ -237,13 +239,13 @@ macro_rules! assert_status_code_value_gt {
     ($a_process:expr, $b_process:expr $(,)?) => {{
         match $crate::assert_status_code_value_gt_as_result!($a_process, $b_process) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_process:expr, $b_process:expr, $($message:tt)+) => {{
         match $crate::assert_status_code_value_gt_as_result!($a_process, $b_process) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -274,7 +276,7 @@ mod test_assert_status_code_value_gt {
         });
         let message = concat!(
             "assertion failed: `assert_status_code_value_gt!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_gt.html\n",
+            assertables_url_line!("assert_status_code_value_gt"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
             " a value: `1`,\n",
@@ -303,7 +305,7 @@ mod test_assert_status_code_value_gt {
         });
         let message = concat!(
             "assertion failed: `assert_status_code_value_gt!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_gt.html\n",
+            assertables_url_line!("assert_status_code_value_gt"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
             " a value: `1`,\n",
@@ -320,6 +322,18 @@ mod test_assert_status_code_value_gt {
             message
         );
     }
+
+    #[test]
+    fn debug_form_panics_only_with_debug_assertions() {
+        let result = panic::catch_unwind(|| {
+            let mut a = Command::new("bin/exit-with-arg");
+            a.arg("1");
+            let mut b = Command::new("bin/exit-with-arg");
+            b.arg("1");
+            crate::debug_assert_status_code_value_gt!(a, b);
+        });
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
 }
 
 /// Assert a status code value is greater than another.
@@ -356,7 +370,7 @@ mod test_assert_status_code_value_gt {
 #[macro_export]
 macro_rules! debug_assert_status_code_value_gt {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_status_code_value_gt!($($arg)*);
         }
     };
@@ -0,0 +1,368 @@
+//! Assert a status code value is in a range.
+//!
+//! Pseudocode:<br>
+//! a ⇒ status ⇒ code ⇒ value is in range
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::process::Command;
+//!
+//! let mut a = Command::new("bin/exit-with-arg");
+//! a.arg("2");
+//! let range = 1..=3;
+//! assert_status_code_value_in_range!(a, range);
+//! ```
+//!
+//! The range can be any kind of range, meaning anything that implements
+//! [`RangeBounds`](https://doc.rust-lang.org/std/ops/trait.RangeBounds.html),
+//! such as `0..2`, `0..=2`, `2..`, or `..=2`, the same as
+//! [`assert_in_range`](macro@crate::assert_in_range).
+//!
+//! If the process has no exit code, such as when it is terminated
+//! by a signal on Unix, then the assertion fails.
+//!
+//! # Module macros
+//!
+//! * [`assert_status_code_value_in_range`](macro@crate::assert_status_code_value_in_range)
+//! * [`assert_status_code_value_in_range_as_result`](macro@crate::assert_status_code_value_in_range_as_result)
+//! * [`debug_assert_status_code_value_in_range`](macro@crate::debug_assert_status_code_value_in_range)
+
+/// Assert a status code value is in a range.
+///
+/// Pseudocode:<br>
+/// a ⇒ status ⇒ code ⇒ value is in range
+///
+/// * If true, return Result `Ok(a ⇒ status ⇒ code ⇒ value)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_status_code_value_in_range`](macro@crate::assert_status_code_value_in_range)
+/// * [`assert_status_code_value_in_range_as_result`](macro@crate::assert_status_code_value_in_range_as_result)
+/// * [`debug_assert_status_code_value_in_range`](macro@crate::debug_assert_status_code_value_in_range)
+///
+#[macro_export]
+macro_rules! assert_status_code_value_in_range_as_result {
+    ($a_process:expr, $range:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_status::CapturedStatus as _;
+        match (&$range) {
+            range => {
+                match ($a_process.status()) {
+                    Ok(a1) => {
+                        match (a1.code()) {
+                            Some(a2) => {
+                                if $crate::assert_in::range_contains(range, &a2) {
+                                    Ok(a2)
+                                } else {
+                                    Err(
+                                        format!(
+                                            concat!(
+                                                "assertion failed: `assert_status_code_value_in_range!(a, range)`\n",
+                                                $crate::assertables_url_line!("assert_status_code_value_in_range"),
+                                                "      a label: `{}`,\n",
+                                                "      a debug: `{:?}`,\n",
+                                                "      a value: `{:?}`,\n",
+                                                "  range label: `{}`,\n",
+                                                "  range debug: `{:?}`,\n",
+                                                " range bounds: `{}`"
+                                            ),
+                                            stringify!($a_process),
+                                            $a_process,
+                                            a2,
+                                            stringify!($range),
+                                            range,
+                                            $crate::assert_in::range_bounds_notation(range, &a2)
+                                        )
+                                    )
+                                }
+                            },
+                            None => {
+                                Err(
+                                    format!(
+                                        concat!(
+                                            "assertion failed: `assert_status_code_value_in_range!(a, range)`\n",
+                                            $crate::assertables_url_line!("assert_status_code_value_in_range"),
+                                            "      a label: `{}`,\n",
+                                            "      a debug: `{:?}`,\n",
+                                            "       a code: `None`,\n",
+                                            "  range label: `{}`,\n",
+                                            "  range debug: `{:?}`,\n",
+                                            "       reason: the process has no exit code, such as when terminated by a signal"
+                                        ),
+                                        stringify!($a_process),
+                                        $a_process,
+                                        stringify!($range),
+                                        range
+                                    )
+                                )
+                            }
+                        }
+                    },
+                    a_status => {
+                        Err(
+                            format!(
+                                concat!(
+                                    "assertion failed: `assert_status_code_value_in_range!(a, range)`\n",
+                                    $crate::assertables_url_line!("assert_status_code_value_in_range"),
+                                    "      a label: `{}`,\n",
+                                    "      a debug: `{:?}`,\n",
+                                    "     a status: `{:?}`,\n",
+                                    "  range label: `{}`,\n",
+                                    "  range debug: `{:?}`"
+                                ),
+                                stringify!($a_process),
+                                $a_process,
+                                a_status,
+                                stringify!($range),
+                                range
+                            )
+                        )
+                    }
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_status_code_value_in_range_as_result {
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut a = Command::new("bin/exit-with-arg");
+        a.arg("2");
+        let range = 1..=3;
+        let actual = assert_status_code_value_in_range_as_result!(a, range);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure() {
+        let mut a = Command::new("bin/exit-with-arg");
+        a.arg("4");
+        let range = 1..=3;
+        let actual = assert_status_code_value_in_range_as_result!(a, range);
+        let message = concat!(
+            "assertion failed: `assert_status_code_value_in_range!(a, range)`\n",
+            assertables_url_line!("assert_status_code_value_in_range"),
+            "      a label: `a`,\n",
+            "      a debug: `\"bin/exit-with-arg\" \"4\"`,\n",
+            "      a value: `4`,\n",
+            "  range label: `range`,\n",
+            "  range debug: `1..=3`,\n",
+            " range bounds: `[1, 3]`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_range_from() {
+        let mut a = Command::new("bin/exit-with-arg");
+        a.arg("200");
+        let actual = assert_status_code_value_in_range_as_result!(a, 1..);
+        assert_eq!(actual.unwrap(), 200);
+    }
+
+    #[test]
+    fn failure_because_no_code() {
+        let mut a = Command::new("sh");
+        a.args(["-c", "kill -9 $$"]);
+        let actual = assert_status_code_value_in_range_as_result!(a, 0..);
+        let message = concat!(
+            "assertion failed: `assert_status_code_value_in_range!(a, range)`\n",
+            assertables_url_line!("assert_status_code_value_in_range"),
+            "      a label: `a`,\n",
+            "      a debug: `\"sh\" \"-c\" \"kill -9 $$\"`,\n",
+            "       a code: `None`,\n",
+            "  range label: `0..`,\n",
+            "  range debug: `0..`,\n",
+            "       reason: the process has no exit code, such as when terminated by a signal"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a status code value is in a range.
+///
+/// Pseudocode:<br>
+/// a ⇒ status ⇒ code ⇒ value is in range
+///
+/// * If true, return `a ⇒ status ⇒ code ⇒ value`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::process::Command;
+///
+/// # fn main() {
+/// let mut a = Command::new("bin/exit-with-arg");
+/// a.arg("2");
+/// let range = 1..=3;
+/// assert_status_code_value_in_range!(a, range);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let mut a = Command::new("bin/exit-with-arg");
+/// a.arg("4");
+/// let range = 1..=3;
+/// assert_status_code_value_in_range!(a, range);
+/// # });
+/// // assertion failed: `assert_status_code_value_in_range!(a, range)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_in_range.html
+/// //       a label: `a`,
+/// //       a debug: `\"bin/exit-with-arg\" \"4\"`,
+/// //       a value: `4`,
+/// //   range label: `range`,
+/// //   range debug: `1..=3`,
+/// //  range bounds: `[1, 3]`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_status_code_value_in_range!(a, range)`\n",
+/// #     assertables_url_line!("assert_status_code_value_in_range"),
+/// #     "      a label: `a`,\n",
+/// #     "      a debug: `\"bin/exit-with-arg\" \"4\"`,\n",
+/// #     "      a value: `4`,\n",
+/// #     "  range label: `range`,\n",
+/// #     "  range debug: `1..=3`,\n",
+/// #     " range bounds: `[1, 3]`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// The range can be any kind of range, meaning anything that implements
+/// [`RangeBounds`](https://doc.rust-lang.org/std/ops/trait.RangeBounds.html),
+/// such as `0..2`, `0..=2`, `2..`, or `..=2`, the same as
+/// [`assert_in_range`](macro@crate::assert_in_range).
+///
+/// If the process has no exit code, such as when it is terminated
+/// by a signal on Unix, then the assertion fails.
+///
+/// # Module macros
+///
+/// * [`assert_status_code_value_in_range`](macro@crate::assert_status_code_value_in_range)
+/// * [`assert_status_code_value_in_range_as_result`](macro@crate::assert_status_code_value_in_range_as_result)
+/// * [`debug_assert_status_code_value_in_range`](macro@crate::debug_assert_status_code_value_in_range)
+///
+#[macro_export]
+macro_rules! assert_status_code_value_in_range {
+    ($a_process:expr, $range:expr $(,)?) => {{
+        match $crate::assert_status_code_value_in_range_as_result!($a_process, $range) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a_process:expr, $range:expr, $($message:tt)+) => {{
+        match $crate::assert_status_code_value_in_range_as_result!($a_process, $range) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_status_code_value_in_range {
+    use std::panic;
+    use std::process::Command;
+
+    #[test]
+    fn success() {
+        let mut a = Command::new("bin/exit-with-arg");
+        a.arg("2");
+        let range = 1..=3;
+        let actual = assert_status_code_value_in_range!(a, range);
+        assert_eq!(actual, 2);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let mut a = Command::new("bin/exit-with-arg");
+            a.arg("4");
+            let range = 1..=3;
+            let _actual = assert_status_code_value_in_range!(a, range);
+        });
+        let message = concat!(
+            "assertion failed: `assert_status_code_value_in_range!(a, range)`\n",
+            assertables_url_line!("assert_status_code_value_in_range"),
+            "      a label: `a`,\n",
+            "      a debug: `\"bin/exit-with-arg\" \"4\"`,\n",
+            "      a value: `4`,\n",
+            "  range label: `range`,\n",
+            "  range debug: `1..=3`,\n",
+            " range bounds: `[1, 3]`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+
+    #[test]
+    fn debug_form_panics_only_with_debug_assertions() {
+        let result = panic::catch_unwind(|| {
+            let mut a = Command::new("bin/exit-with-arg");
+            a.arg("4");
+            let range = 1..=3;
+            crate::debug_assert_status_code_value_in_range!(a, range);
+        });
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
+}
+
+/// Assert a status code value is in a range.
+///
+/// Pseudocode:<br>
+/// a ⇒ status ⇒ code ⇒ value is in range
+///
+/// This macro provides the same statements as [`assert_status_code_value_in_range`](macro.assert_status_code_value_in_range.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_status_code_value_in_range`](macro@crate::assert_status_code_value_in_range)
+/// * [`assert_status_code_value_in_range_as_result`](macro@crate::assert_status_code_value_in_range_as_result)
+/// * [`debug_assert_status_code_value_in_range`](macro@crate::debug_assert_status_code_value_in_range)
+///
+#[macro_export]
+macro_rules! debug_assert_status_code_value_in_range {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_status_code_value_in_range!($($arg)*);
+        }
+    };
+}
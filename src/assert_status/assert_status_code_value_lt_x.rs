@@ -41,6 +41,8 @@
 #[macro_export]
 macro_rules! assert_status_code_value_lt_x_as_result {
     ($a_process:expr, $b:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_status::CapturedStatus as _;
         match ($a_process.status()) {
             Ok(a1) => {
                 match (a1.code()) {
@@ -52,7 +54,7 @@ macro_rules! assert_status_code_value_lt_x_as_result {
                                 format!(
                                     concat!(
                                         "assertion failed: `assert_status_code_value_lt_x!(a, b)`\n",
-                                        "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_lt_x.html\n",
+                                        $crate::assertables_url_line!("assert_status_code_value_lt_x"),
                                         " a label: `{}`,\n",
                                         " a debug: `{:?}`,\n",
                                         " a value: `{:?}`,\n",
@@ -73,7 +75,7 @@ macro_rules! assert_status_code_value_lt_x_as_result {
                             format!(
                                 concat!(
                                     "assertion failed: `assert_status_code_value_lt_x!(a, b)`\n",
-                                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_lt_x.html\n",
+                                    $crate::assertables_url_line!("assert_status_code_value_lt_x"),
                                     " a label: `{}`,\n",
                                     " a debug: `{:?}`,\n",
                                     "  a code: `{:?}`,\n",
@@ -95,7 +97,7 @@ macro_rules! assert_status_code_value_lt_x_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_status_code_value_lt_x!(a, b)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_lt_x.html\n",
+                            $crate::assertables_url_line!("assert_status_code_value_lt_x"),
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             " a status: `{:?}`,\n",
@@ -135,7 +137,7 @@ mod test_assert_status_code_value_lt_x_as_result {
         let actual = assert_status_code_value_lt_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_status_code_value_lt_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_lt_x.html\n",
+            assertables_url_line!("assert_status_code_value_lt_x"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
             " a value: `1`,\n",
@@ -153,7 +155,7 @@ mod test_assert_status_code_value_lt_x_as_result {
         let actual = assert_status_code_value_lt_x_as_result!(a, b);
         let message = concat!(
             "assertion failed: `assert_status_code_value_lt_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_lt_x.html\n",
+            assertables_url_line!("assert_status_code_value_lt_x"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"2\"`,\n",
             " a value: `2`,\n",
@@ -202,7 +204,7 @@ mod test_assert_status_code_value_lt_x_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_status_code_value_lt_x!(a, b)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_lt_x.html\n",
+/// #     assertables_url_line!("assert_status_code_value_lt_x"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `\"bin/exit-with-arg\" \"2\"`,\n",
 /// #     " a value: `2`,\n",
@@ -224,13 +226,13 @@ macro_rules! assert_status_code_value_lt_x {
     ($a_process:expr, $b:expr $(,)?) => {{
         match $crate::assert_status_code_value_lt_x_as_result!($a_process, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a_process:expr, $b:expr, $($message:tt)+) => {{
         match $crate::assert_status_code_value_lt_x_as_result!($a_process, $b) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -259,7 +261,7 @@ mod test_assert_status_code_value_lt_x {
         });
         let message = concat!(
             "assertion failed: `assert_status_code_value_lt_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_lt_x.html\n",
+            assertables_url_line!("assert_status_code_value_lt_x"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`,\n",
             " a value: `1`,\n",
@@ -286,7 +288,7 @@ mod test_assert_status_code_value_lt_x {
         });
         let message = concat!(
             "assertion failed: `assert_status_code_value_lt_x!(a, b)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_code_value_lt_x.html\n",
+            assertables_url_line!("assert_status_code_value_lt_x"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"2\"`,\n",
             " a value: `2`,\n",
@@ -302,6 +304,17 @@ mod test_assert_status_code_value_lt_x {
             message
         );
     }
+
+    #[test]
+    fn debug_form_panics_only_with_debug_assertions() {
+        let result = panic::catch_unwind(|| {
+            let mut a = Command::new("bin/exit-with-arg");
+            a.arg("1");
+            let b = 1;
+            crate::debug_assert_status_code_value_lt_x!(a, b);
+        });
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
 }
 
 /// Assert a status code value is less than an expression.
@@ -338,7 +351,7 @@ mod test_assert_status_code_value_lt_x {
 #[macro_export]
 macro_rules! debug_assert_status_code_value_lt_x {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_status_code_value_lt_x!($($arg)*);
         }
     };
@@ -40,6 +40,8 @@
 #[macro_export]
 macro_rules! assert_status_success_as_result {
     ($a:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_status::CapturedStatus as _;
         match ($a.status()) {
             Ok(a1) => {
                 if a1.success()  {
@@ -49,7 +51,7 @@ macro_rules! assert_status_success_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_status_success!(a)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success.html\n",
+                                $crate::assertables_url_line!("assert_status_success"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`",
                             ),
@@ -64,7 +66,7 @@ macro_rules! assert_status_success_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_status_success!(a)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success.html\n",
+                            $crate::assertables_url_line!("assert_status_success"),
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             " a status: `{:?}`",
@@ -98,7 +100,7 @@ mod test_assert_status_success_as_result {
         let actual = assert_status_success_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_status_success!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success.html\n",
+            assertables_url_line!("assert_status_success"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`",
         );
@@ -139,7 +141,7 @@ mod test_assert_status_success_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_status_success!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success.html\n",
+/// #     assertables_url_line!("assert_status_success"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `\"bin/exit-with-arg\" \"1\"`"
 /// # );
@@ -158,13 +160,13 @@ macro_rules! assert_status_success {
     ($a:expr $(,)?) => {{
         match $crate::assert_status_success_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_status_success_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -191,7 +193,7 @@ mod test_assert_status_success {
         });
         let message = concat!(
             "assertion failed: `assert_status_success!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success.html\n",
+            assertables_url_line!("assert_status_success"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"1\"`",
         );
@@ -204,6 +206,16 @@ mod test_assert_status_success {
             message
         );
     }
+
+    #[test]
+    fn debug_form_panics_only_with_debug_assertions() {
+        let result = panic::catch_unwind(|| {
+            let mut a = Command::new("bin/exit-with-arg");
+            a.arg("1");
+            crate::debug_assert_status_success!(a);
+        });
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
 }
 
 /// Assert a status is a success.
@@ -240,7 +252,7 @@ mod test_assert_status_success {
 #[macro_export]
 macro_rules! debug_assert_status_success {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_status_success!($($arg)*);
         }
     };
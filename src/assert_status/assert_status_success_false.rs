@@ -40,6 +40,8 @@
 #[macro_export]
 macro_rules! assert_status_success_false_as_result {
     ($a:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::assert_status::CapturedStatus as _;
         match ($a.status()) {
             Ok(a1) => {
                 if !a1.success()  {
@@ -49,7 +51,7 @@ macro_rules! assert_status_success_false_as_result {
                         format!(
                             concat!(
                                 "assertion failed: `assert_status_success_false!(a)`\n",
-                                "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success_false.html\n",
+                                $crate::assertables_url_line!("assert_status_success_false"),
                                 " a label: `{}`,\n",
                                 " a debug: `{:?}`",
                             ),
@@ -64,7 +66,7 @@ macro_rules! assert_status_success_false_as_result {
                     format!(
                         concat!(
                             "assertion failed: `assert_status_success_false!(a)`\n",
-                            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success_false.html\n",
+                            $crate::assertables_url_line!("assert_status_success_false"),
                             "  a label: `{}`,\n",
                             "  a debug: `{:?}`,\n",
                             " a status: `{:?}`",
@@ -98,7 +100,7 @@ mod test_assert_status_success_false_as_result {
         let actual = assert_status_success_false_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_status_success_false!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success_false.html\n",
+            assertables_url_line!("assert_status_success_false"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"0\"`",
         );
@@ -139,7 +141,7 @@ mod test_assert_status_success_false_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_status_success_false!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success_false.html\n",
+/// #     assertables_url_line!("assert_status_success_false"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `\"bin/exit-with-arg\" \"0\"`"
 /// # );
@@ -158,13 +160,13 @@ macro_rules! assert_status_success_false {
     ($a:expr $(,)?) => {{
         match $crate::assert_status_success_false_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_status_success_false_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -191,7 +193,7 @@ mod test_assert_status_success_false {
         });
         let message = concat!(
             "assertion failed: `assert_status_success_false!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_status_success_false.html\n",
+            assertables_url_line!("assert_status_success_false"),
             " a label: `a`,\n",
             " a debug: `\"bin/exit-with-arg\" \"0\"`",
         );
@@ -204,6 +206,16 @@ mod test_assert_status_success_false {
             message
         );
     }
+
+    #[test]
+    fn debug_form_panics_only_with_debug_assertions() {
+        let result = panic::catch_unwind(|| {
+            let mut a = Command::new("bin/exit-with-arg");
+            a.arg("0");
+            crate::debug_assert_status_success_false!(a);
+        });
+        assert_eq!(result.is_err(), cfg!(debug_assertions));
+    }
 }
 
 /// Assert a status is a failure.
@@ -240,7 +252,7 @@ mod test_assert_status_success_false {
 #[macro_export]
 macro_rules! debug_assert_status_success_false {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_status_success_false!($($arg)*);
         }
     };
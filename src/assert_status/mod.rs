@@ -6,6 +6,10 @@
 //! * `success() => bool`
 //! * `code() => Result(T, E)`
 //!
+//! These macros also accept a captured [`std::process::Output`], such as from
+//! [`assert_command_output`](macro@crate::assert_command_output), and check its
+//! status without running the command again.
+//!
 //! Try success/failure:
 //!
 //! * [`assert_status_success!(a)`](macro@crate::assert_status_success) ≈ a.status().success() = true``
@@ -29,6 +33,10 @@
 //! * [`assert_status_code_value_gt_x!(a, expr)`](macro@crate::assert_status_code_value_gt_x) ≈ a.len() > expr
 //! * [`assert_status_code_value_ge_x!(a, expr)`](macro@crate::assert_status_code_value_ge_x) ≈ a.len() ≥ expr
 //!
+//! Compare a status code with a range:
+//!
+//! * [`assert_status_code_value_in_range!(a, range)`](macro@crate::assert_status_code_value_in_range) ≈ a.status().code().unwrap() is in range
+//!
 //! # Example
 //!
 //! ```rust
@@ -59,3 +67,23 @@ pub mod assert_status_code_value_gt_x;
 pub mod assert_status_code_value_le_x;
 pub mod assert_status_code_value_lt_x;
 pub mod assert_status_code_value_ne_x;
+
+// Compare range
+pub mod assert_status_code_value_in_range;
+
+/// Get the status of a command that has already run.
+///
+/// The status macros call `.status()` on their argument. A `Command` uses
+/// its inherent method, which runs the command. An `Output`, such as from
+/// [`assert_command_output`](macro@crate::assert_command_output), uses
+/// this trait, which returns the captured status, without running again.
+#[doc(hidden)]
+pub trait CapturedStatus {
+    fn status(&self) -> ::std::io::Result<::std::process::ExitStatus>;
+}
+
+impl CapturedStatus for ::std::process::Output {
+    fn status(&self) -> ::std::io::Result<::std::process::ExitStatus> {
+        Ok(self.status)
+    }
+}
@@ -0,0 +1,371 @@
+//! Assert a string is equal to another, ignoring whitespace.
+//!
+//! Pseudocode:<br>
+//! a.split_whitespace() = b.split_whitespace()
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "mov  eax, 1\n\tret";
+//! let b = "mov eax, 1 ret";
+//! assert_str_eq_ignore_whitespace!(a, b);
+//! ```
+//!
+//! This macro is useful for comparing text where the layout does not matter,
+//! such as assembly listings, generated code, or pretty-printed output.
+//!
+//! * Each string is split into tokens on any run of whitespace, such as
+//!   spaces, tabs, and newlines, then the token sequences are compared.
+//!
+//! * On success, the return is the token vector, as `Vec<String>`.
+//!
+//! * On failure, the message shows the index of the first differing token,
+//!   and each string's token there with its line number counting from 1,
+//!   or `None` if the string has no token there.
+//!
+//! # Module macros
+//!
+//! * [`assert_str_eq_ignore_whitespace`](macro@crate::assert_str_eq_ignore_whitespace)
+//! * [`assert_str_eq_ignore_whitespace_as_result`](macro@crate::assert_str_eq_ignore_whitespace_as_result)
+//! * [`debug_assert_str_eq_ignore_whitespace`](macro@crate::debug_assert_str_eq_ignore_whitespace)
+
+/// Split a string into whitespace-separated tokens, each with its line number.
+///
+/// The line number counts from 1. This function is an implementation detail
+/// of the macro [`assert_str_eq_ignore_whitespace`](macro@crate::assert_str_eq_ignore_whitespace).
+#[doc(hidden)]
+pub fn tokens(s: &str) -> Vec<(usize, &str)> {
+    s.lines()
+        .enumerate()
+        .flat_map(|(index, line)| line.split_whitespace().map(move |token| (index + 1, token)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test_tokens {
+    use super::tokens;
+
+    #[test]
+    fn tokens_with_lines() {
+        assert_eq!(
+            tokens("mov  eax, 1\n\tret\r\n"),
+            vec![(1, "mov"), (1, "eax,"), (1, "1"), (2, "ret")]
+        );
+    }
+
+    #[test]
+    fn tokens_of_whitespace() {
+        assert_eq!(tokens(" \t\n "), vec![]);
+    }
+}
+
+/// Assert a string is equal to another, ignoring whitespace.
+///
+/// Pseudocode:<br>
+/// a.split_whitespace() = b.split_whitespace()
+///
+/// * If true, return Result `Ok(tokens)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_str_eq_ignore_whitespace`](macro@crate::assert_str_eq_ignore_whitespace)
+/// * [`assert_str_eq_ignore_whitespace_as_result`](macro@crate::assert_str_eq_ignore_whitespace_as_result)
+/// * [`debug_assert_str_eq_ignore_whitespace`](macro@crate::debug_assert_str_eq_ignore_whitespace)
+///
+#[macro_export]
+macro_rules! assert_str_eq_ignore_whitespace_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let a_str: &str = ::std::convert::AsRef::<str>::as_ref(a);
+                let b_str: &str = ::std::convert::AsRef::<str>::as_ref(b);
+                let a_tokens = $crate::assert_str::assert_str_eq_ignore_whitespace::tokens(a_str);
+                let b_tokens = $crate::assert_str::assert_str_eq_ignore_whitespace::tokens(b_str);
+                if a_tokens.iter().map(|(_, token)| token).eq(b_tokens.iter().map(|(_, token)| token)) {
+                    Ok(a_tokens.into_iter().map(|(_, token)| String::from(token)).collect::<Vec<String>>())
+                } else {
+                    let index = a_tokens
+                        .iter()
+                        .zip(b_tokens.iter())
+                        .position(|((_, a_token), (_, b_token))| a_token != b_token)
+                        .unwrap_or_else(|| ::std::cmp::min(a_tokens.len(), b_tokens.len()));
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_str_eq_ignore_whitespace!(a, b)`\n",
+                                $crate::assertables_url_line!("assert_str_eq_ignore_whitespace"),
+                                "          a label: `{}`,\n",
+                                "          a debug: `{:?}`,\n",
+                                "          b label: `{}`,\n",
+                                "          b debug: `{:?}`,\n",
+                                " diff token index: `{}`,\n",
+                                "          a token: `{:?}`,\n",
+                                "           a line: `{:?}`,\n",
+                                "          b token: `{:?}`,\n",
+                                "           b line: `{:?}`"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            index,
+                            a_tokens.get(index).map(|(_, token)| token),
+                            a_tokens.get(index).map(|(line, _)| line),
+                            b_tokens.get(index).map(|(_, token)| token),
+                            b_tokens.get(index).map(|(line, _)| line)
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_str_eq_ignore_whitespace_as_result {
+    #[test]
+    fn success() {
+        let a = "mov  eax, 1\n\tret";
+        let b = "mov eax, 1 ret";
+        let actual = assert_str_eq_ignore_whitespace_as_result!(a, b);
+        assert_eq!(
+            actual.unwrap(),
+            vec![
+                String::from("mov"),
+                String::from("eax,"),
+                String::from("1"),
+                String::from("ret")
+            ]
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let a = "mov eax, 1\nret";
+        let b = "mov eax, 2\nret";
+        let actual = assert_str_eq_ignore_whitespace_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_str_eq_ignore_whitespace!(a, b)`\n",
+            assertables_url_line!("assert_str_eq_ignore_whitespace"),
+            "          a label: `a`,\n",
+            "          a debug: `\"mov eax, 1\\nret\"`,\n",
+            "          b label: `b`,\n",
+            "          b debug: `\"mov eax, 2\\nret\"`,\n",
+            " diff token index: `2`,\n",
+            "          a token: `Some(\"1\")`,\n",
+            "           a line: `Some(1)`,\n",
+            "          b token: `Some(\"2\")`,\n",
+            "           b line: `Some(1)`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_with_missing_token() {
+        let a = String::from("mov eax, 1\nret");
+        let b = String::from("mov eax, 1");
+        let actual = assert_str_eq_ignore_whitespace_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_str_eq_ignore_whitespace!(a, b)`\n",
+            assertables_url_line!("assert_str_eq_ignore_whitespace"),
+            "          a label: `a`,\n",
+            "          a debug: `\"mov eax, 1\\nret\"`,\n",
+            "          b label: `b`,\n",
+            "          b debug: `\"mov eax, 1\"`,\n",
+            " diff token index: `3`,\n",
+            "          a token: `Some(\"ret\")`,\n",
+            "           a line: `Some(2)`,\n",
+            "          b token: `None`,\n",
+            "           b line: `None`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a string is equal to another, ignoring whitespace.
+///
+/// Pseudocode:<br>
+/// a.split_whitespace() = b.split_whitespace()
+///
+/// * If true, return `tokens`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "mov  eax, 1\n\tret";
+/// let b = "mov eax, 1 ret";
+/// assert_str_eq_ignore_whitespace!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "mov eax, 1\nret";
+/// let b = "mov eax, 2\nret";
+/// assert_str_eq_ignore_whitespace!(a, b);
+/// # });
+/// // assertion failed: `assert_str_eq_ignore_whitespace!(a, b)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_str_eq_ignore_whitespace.html
+/// //           a label: `a`,
+/// //           a debug: `\"mov eax, 1\\nret\"`,
+/// //           b label: `b`,
+/// //           b debug: `\"mov eax, 2\\nret\"`,
+/// //  diff token index: `2`,
+/// //           a token: `Some(\"1\")`,
+/// //            a line: `Some(1)`,
+/// //           b token: `Some(\"2\")`,
+/// //            b line: `Some(1)`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_str_eq_ignore_whitespace!(a, b)`\n",
+/// #     assertables_url_line!("assert_str_eq_ignore_whitespace"),
+/// #     "          a label: `a`,\n",
+/// #     "          a debug: `\"mov eax, 1\\nret\"`,\n",
+/// #     "          b label: `b`,\n",
+/// #     "          b debug: `\"mov eax, 2\\nret\"`,\n",
+/// #     " diff token index: `2`,\n",
+/// #     "          a token: `Some(\"1\")`,\n",
+/// #     "           a line: `Some(1)`,\n",
+/// #     "          b token: `Some(\"2\")`,\n",
+/// #     "           b line: `Some(1)`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for comparing text where the layout does not matter,
+/// such as assembly listings, generated code, or pretty-printed output.
+///
+/// * Each string is split into tokens on any run of whitespace, such as
+///   spaces, tabs, and newlines, then the token sequences are compared.
+///
+/// * On success, the return is the token vector, as `Vec<String>`.
+///
+/// * On failure, the message shows the index of the first differing token,
+///   and each string's token there with its line number counting from 1,
+///   or `None` if the string has no token there.
+///
+/// # Module macros
+///
+/// * [`assert_str_eq_ignore_whitespace`](macro@crate::assert_str_eq_ignore_whitespace)
+/// * [`assert_str_eq_ignore_whitespace_as_result`](macro@crate::assert_str_eq_ignore_whitespace_as_result)
+/// * [`debug_assert_str_eq_ignore_whitespace`](macro@crate::debug_assert_str_eq_ignore_whitespace)
+///
+#[macro_export]
+macro_rules! assert_str_eq_ignore_whitespace {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_str_eq_ignore_whitespace_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_str_eq_ignore_whitespace_as_result!($a, $b) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_str_eq_ignore_whitespace {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = "mov  eax, 1\n\tret";
+        let b = "mov eax, 1 ret";
+        let actual = assert_str_eq_ignore_whitespace!(a, b);
+        assert_eq!(
+            actual,
+            vec![
+                String::from("mov"),
+                String::from("eax,"),
+                String::from("1"),
+                String::from("ret")
+            ]
+        );
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = "mov eax, 1\nret";
+            let b = "mov eax, 2\nret";
+            let _actual = assert_str_eq_ignore_whitespace!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_str_eq_ignore_whitespace!(a, b)`\n",
+            assertables_url_line!("assert_str_eq_ignore_whitespace"),
+            "          a label: `a`,\n",
+            "          a debug: `\"mov eax, 1\\nret\"`,\n",
+            "          b label: `b`,\n",
+            "          b debug: `\"mov eax, 2\\nret\"`,\n",
+            " diff token index: `2`,\n",
+            "          a token: `Some(\"1\")`,\n",
+            "           a line: `Some(1)`,\n",
+            "          b token: `Some(\"2\")`,\n",
+            "           b line: `Some(1)`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a string is equal to another, ignoring whitespace.
+///
+/// Pseudocode:<br>
+/// a.split_whitespace() = b.split_whitespace()
+///
+/// This macro provides the same statements as [`assert_str_eq_ignore_whitespace`](macro.assert_str_eq_ignore_whitespace.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_str_eq_ignore_whitespace`](macro@crate::assert_str_eq_ignore_whitespace)
+/// * [`assert_str_eq_ignore_whitespace_as_result`](macro@crate::assert_str_eq_ignore_whitespace_as_result)
+/// * [`debug_assert_str_eq_ignore_whitespace`](macro@crate::debug_assert_str_eq_ignore_whitespace)
+///
+#[macro_export]
+macro_rules! debug_assert_str_eq_ignore_whitespace {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_str_eq_ignore_whitespace!($($arg)*);
+        }
+    };
+}
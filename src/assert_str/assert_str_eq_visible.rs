@@ -0,0 +1,418 @@
+//! Assert a string is equal to another, and show invisible characters on failure.
+//!
+//! Pseudocode:<br>
+//! a = b
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "alfa\n";
+//! let b = "alfa\n";
+//! assert_str_eq_visible!(a, b);
+//! ```
+//!
+//! This macro is useful for strings that look identical yet differ in
+//! characters that are hard to see, such as trailing spaces, tabs versus
+//! spaces, CRLF versus LF, non-breaking spaces, and zero-width spaces.
+//!
+//! The failure message shows each string with its invisible characters
+//! made visible, and shows the char index of the first difference:
+//!
+//! * Space `' '` is shown as `␣`.
+//!
+//! * Tab `'\t'` is shown as `⇥`.
+//!
+//! * Line feed `'\n'` is shown as `⏎`.
+//!
+//! * Carriage return `'\r'` is shown as `␍`.
+//!
+//! * Any other whitespace, control, or zero-width character is shown as its
+//!   code point, such as `<U+00A0>` for a non-breaking space. The symbols
+//!   above are also shown as their code points, so they are unambiguous.
+//!
+//! # Module macros
+//!
+//! * [`assert_str_eq_visible`](macro@crate::assert_str_eq_visible)
+//! * [`assert_str_eq_visible_as_result`](macro@crate::assert_str_eq_visible_as_result)
+//! * [`debug_assert_str_eq_visible`](macro@crate::debug_assert_str_eq_visible)
+
+/// Render a string with its invisible characters made visible.
+///
+/// This function is an implementation detail of the macro
+/// [`assert_str_eq_visible`](macro@crate::assert_str_eq_visible).
+#[doc(hidden)]
+pub fn visible(s: &str) -> String {
+    let mut visible = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            ' ' => visible.push('␣'),
+            '\t' => visible.push('⇥'),
+            '\n' => visible.push('⏎'),
+            '\r' => visible.push('␍'),
+            '␣' | '⇥' | '⏎' | '␍' => visible.push_str(&format!("<U+{:04X}>", c as u32)),
+            c if c.is_whitespace() || c.is_control() || is_invisible_format(c) => {
+                visible.push_str(&format!("<U+{:04X}>", c as u32))
+            }
+            c => visible.push(c),
+        }
+    }
+    visible
+}
+
+/// Is the character a format character that renders with zero width,
+/// such as a zero-width space, a joiner, a direction mark, or a BOM?
+fn is_invisible_format(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{034F}'
+            | '\u{061C}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+#[cfg(test)]
+mod test_visible {
+    use super::visible;
+
+    #[test]
+    fn visible_text() {
+        assert_eq!(visible("alfa"), "alfa");
+    }
+
+    #[test]
+    fn visible_whitespace() {
+        assert_eq!(visible(" \t\r\n"), "␣⇥␍⏎");
+    }
+
+    #[test]
+    fn visible_code_points() {
+        assert_eq!(
+            visible("\u{a0}\u{200b}\u{feff}\u{7}"),
+            "<U+00A0><U+200B><U+FEFF><U+0007>"
+        );
+    }
+
+    #[test]
+    fn visible_symbols_are_unambiguous() {
+        assert_eq!(visible("␣⏎"), "<U+2423><U+23CE>");
+    }
+}
+
+/// Assert a string is equal to another, and show invisible characters on failure.
+///
+/// Pseudocode:<br>
+/// a = b
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_str_eq_visible`](macro@crate::assert_str_eq_visible)
+/// * [`assert_str_eq_visible_as_result`](macro@crate::assert_str_eq_visible_as_result)
+/// * [`debug_assert_str_eq_visible`](macro@crate::debug_assert_str_eq_visible)
+///
+#[macro_export]
+macro_rules! assert_str_eq_visible_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let a_str: &str = ::std::convert::AsRef::<str>::as_ref(a);
+                let b_str: &str = ::std::convert::AsRef::<str>::as_ref(b);
+                if a_str == b_str {
+                    Ok(())
+                } else {
+                    let index = a_str
+                        .chars()
+                        .zip(b_str.chars())
+                        .position(|(a_char, b_char)| a_char != b_char)
+                        .unwrap_or_else(|| ::std::cmp::min(a_str.chars().count(), b_str.chars().count()));
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_str_eq_visible!(a, b)`\n",
+                                $crate::assertables_url_line!("assert_str_eq_visible"),
+                                "         a label: `{}`,\n",
+                                "         a debug: `{:?}`,\n",
+                                "         b label: `{}`,\n",
+                                "         b debug: `{:?}`,\n",
+                                "       a visible: `{}`,\n",
+                                "       b visible: `{}`,\n",
+                                " diff char index: `{}`"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b,
+                            $crate::assert_str::assert_str_eq_visible::visible(a_str),
+                            $crate::assert_str::assert_str_eq_visible::visible(b_str),
+                            index
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_str_eq_visible_as_result {
+    #[test]
+    fn success() {
+        let a = "alfa\n";
+        let b = "alfa\n";
+        let actual = assert_str_eq_visible_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = "alfa \r\n";
+        let b = "alfa\n";
+        let actual = assert_str_eq_visible_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_str_eq_visible!(a, b)`\n",
+            assertables_url_line!("assert_str_eq_visible"),
+            "         a label: `a`,\n",
+            "         a debug: `\"alfa \\r\\n\"`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `\"alfa\\n\"`,\n",
+            "       a visible: `alfa␣␍⏎`,\n",
+            "       b visible: `alfa⏎`,\n",
+            " diff char index: `4`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_string() {
+        let a = String::from("alfa");
+        let b = "alfa";
+        let actual = assert_str_eq_visible_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure_because_non_breaking_space() {
+        let a = "alfa\u{a0}bravo";
+        let b = "alfa bravo";
+        let actual = assert_str_eq_visible_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_str_eq_visible!(a, b)`\n",
+            assertables_url_line!("assert_str_eq_visible"),
+            "         a label: `a`,\n",
+            "         a debug: `\"alfa\\u{a0}bravo\"`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `\"alfa bravo\"`,\n",
+            "       a visible: `alfa<U+00A0>bravo`,\n",
+            "       b visible: `alfa␣bravo`,\n",
+            " diff char index: `4`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn failure_because_zero_width_space_at_end() {
+        let a = "alfa";
+        let b = "alfa\u{200b}";
+        let actual = assert_str_eq_visible_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_str_eq_visible!(a, b)`\n",
+            assertables_url_line!("assert_str_eq_visible"),
+            "         a label: `a`,\n",
+            "         a debug: `\"alfa\"`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `\"alfa\\u{200b}\"`,\n",
+            "       a visible: `alfa`,\n",
+            "       b visible: `alfa<U+200B>`,\n",
+            " diff char index: `4`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+}
+
+/// Assert a string is equal to another, and show invisible characters on failure.
+///
+/// Pseudocode:<br>
+/// a = b
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "alfa\n";
+/// let b = "alfa\n";
+/// assert_str_eq_visible!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "alfa \r\n";
+/// let b = "alfa\n";
+/// assert_str_eq_visible!(a, b);
+/// # });
+/// // assertion failed: `assert_str_eq_visible!(a, b)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_str_eq_visible.html
+/// //          a label: `a`,
+/// //          a debug: `\"alfa \\r\\n\"`,
+/// //          b label: `b`,
+/// //          b debug: `\"alfa\\n\"`,
+/// //        a visible: `alfa␣␍⏎`,
+/// //        b visible: `alfa⏎`,
+/// //  diff char index: `4`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_str_eq_visible!(a, b)`\n",
+/// #     assertables_url_line!("assert_str_eq_visible"),
+/// #     "         a label: `a`,\n",
+/// #     "         a debug: `\"alfa \\r\\n\"`,\n",
+/// #     "         b label: `b`,\n",
+/// #     "         b debug: `\"alfa\\n\"`,\n",
+/// #     "       a visible: `alfa␣␍⏎`,\n",
+/// #     "       b visible: `alfa⏎`,\n",
+/// #     " diff char index: `4`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for strings that look identical yet differ in
+/// characters that are hard to see, such as trailing spaces, tabs versus
+/// spaces, CRLF versus LF, non-breaking spaces, and zero-width spaces.
+///
+/// The failure message shows each string with its invisible characters
+/// made visible, and shows the char index of the first difference:
+///
+/// * Space `' '` is shown as `␣`.
+///
+/// * Tab `'\t'` is shown as `⇥`.
+///
+/// * Line feed `'\n'` is shown as `⏎`.
+///
+/// * Carriage return `'\r'` is shown as `␍`.
+///
+/// * Any other whitespace, control, or zero-width character is shown as its
+///   code point, such as `<U+00A0>` for a non-breaking space. The symbols
+///   above are also shown as their code points, so they are unambiguous.
+///
+/// # Module macros
+///
+/// * [`assert_str_eq_visible`](macro@crate::assert_str_eq_visible)
+/// * [`assert_str_eq_visible_as_result`](macro@crate::assert_str_eq_visible_as_result)
+/// * [`debug_assert_str_eq_visible`](macro@crate::debug_assert_str_eq_visible)
+///
+#[macro_export]
+macro_rules! assert_str_eq_visible {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_str_eq_visible_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_str_eq_visible_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_str_eq_visible {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = "alfa\n";
+        let b = "alfa\n";
+        let actual = assert_str_eq_visible!(a, b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = "alfa \r\n";
+            let b = "alfa\n";
+            let _actual = assert_str_eq_visible!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_str_eq_visible!(a, b)`\n",
+            assertables_url_line!("assert_str_eq_visible"),
+            "         a label: `a`,\n",
+            "         a debug: `\"alfa \\r\\n\"`,\n",
+            "         b label: `b`,\n",
+            "         b debug: `\"alfa\\n\"`,\n",
+            "       a visible: `alfa␣␍⏎`,\n",
+            "       b visible: `alfa⏎`,\n",
+            " diff char index: `4`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a string is equal to another, and show invisible characters on failure.
+///
+/// Pseudocode:<br>
+/// a = b
+///
+/// This macro provides the same statements as [`assert_str_eq_visible`](macro.assert_str_eq_visible.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_str_eq_visible`](macro@crate::assert_str_eq_visible)
+/// * [`assert_str_eq_visible_as_result`](macro@crate::assert_str_eq_visible_as_result)
+/// * [`debug_assert_str_eq_visible`](macro@crate::debug_assert_str_eq_visible)
+///
+#[macro_export]
+macro_rules! debug_assert_str_eq_visible {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_str_eq_visible!($($arg)*);
+        }
+    };
+}
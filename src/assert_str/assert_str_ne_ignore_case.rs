@@ -0,0 +1,284 @@
+//! Assert a string is not equal to another, ignoring ASCII case.
+//!
+//! Pseudocode:<br>
+//! a.to_ascii_lowercase() ≠ b.to_ascii_lowercase()
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "Alfa";
+//! let b = "Bravo";
+//! assert_str_ne_ignore_case!(a, b);
+//! ```
+//!
+//! This macro is useful for user-facing messages, where letter case
+//! should not matter, such as "Not found" versus "NOT FOUND".
+//!
+//! * The comparison uses `str::eq_ignore_ascii_case`, so only the ASCII
+//!   letters `A-Z` and `a-z` are case-insensitive. Any other character,
+//!   such as "É" or the Turkish "İ", must match exactly.
+//!
+//! * For a Unicode-aware comparison, use
+//!   [`assert_eq_normalized`](macro@crate::assert_eq_normalized) with a
+//!   normalize function such as `str::to_lowercase`. Be aware that Unicode
+//!   case mapping depends on language, such as the Turkish dotted and
+//!   dotless "i", and the standard library does not handle that.
+//!
+//! * The failure message shows both original strings, so the casing
+//!   difference is visible.
+//!
+//! # Module macros
+//!
+//! * [`assert_str_ne_ignore_case`](macro@crate::assert_str_ne_ignore_case)
+//! * [`assert_str_ne_ignore_case_as_result`](macro@crate::assert_str_ne_ignore_case_as_result)
+//! * [`debug_assert_str_ne_ignore_case`](macro@crate::debug_assert_str_ne_ignore_case)
+
+/// Assert a string is not equal to another, ignoring ASCII case.
+///
+/// Pseudocode:<br>
+/// a.to_ascii_lowercase() ≠ b.to_ascii_lowercase()
+///
+/// * If true, return Result `Ok(())`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_str_ne_ignore_case`](macro@crate::assert_str_ne_ignore_case)
+/// * [`assert_str_ne_ignore_case_as_result`](macro@crate::assert_str_ne_ignore_case_as_result)
+/// * [`debug_assert_str_ne_ignore_case`](macro@crate::debug_assert_str_ne_ignore_case)
+///
+#[macro_export]
+macro_rules! assert_str_ne_ignore_case_as_result {
+    ($a:expr, $b:expr $(,)?) => {{
+        match (&$a, &$b) {
+            (a, b) => {
+                let a_str: &str = ::std::convert::AsRef::<str>::as_ref(a);
+                let b_str: &str = ::std::convert::AsRef::<str>::as_ref(b);
+                if !a_str.eq_ignore_ascii_case(b_str) {
+                    Ok(())
+                } else {
+                    Err(
+                        format!(
+                            concat!(
+                                "assertion failed: `assert_str_ne_ignore_case!(a, b)`\n",
+                                $crate::assertables_url_line!("assert_str_ne_ignore_case"),
+                                " a label: `{}`,\n",
+                                " a debug: `{:?}`,\n",
+                                " b label: `{}`,\n",
+                                " b debug: `{:?}`"
+                            ),
+                            stringify!($a),
+                            a,
+                            stringify!($b),
+                            b
+                        )
+                    )
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_str_ne_ignore_case_as_result {
+    #[test]
+    fn success() {
+        let a = "Alfa";
+        let b = "Bravo";
+        let actual = assert_str_ne_ignore_case_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+
+    #[test]
+    fn failure() {
+        let a = "Alfa";
+        let b = "ALFA";
+        let actual = assert_str_ne_ignore_case_as_result!(a, b);
+        let message = concat!(
+            "assertion failed: `assert_str_ne_ignore_case!(a, b)`\n",
+            assertables_url_line!("assert_str_ne_ignore_case"),
+            " a label: `a`,\n",
+            " a debug: `\"Alfa\"`,\n",
+            " b label: `b`,\n",
+            " b debug: `\"ALFA\"`"
+        );
+        assert_eq!(actual.unwrap_err(), message);
+    }
+
+    #[test]
+    fn success_with_non_ascii_case() {
+        // Only ASCII letters are case-folded, so "É" and "é" differ.
+        let a = "Éclair";
+        let b = "éclair";
+        let actual = assert_str_ne_ignore_case_as_result!(a, b);
+        assert_eq!(actual.unwrap(), ());
+    }
+}
+
+/// Assert a string is not equal to another, ignoring ASCII case.
+///
+/// Pseudocode:<br>
+/// a.to_ascii_lowercase() ≠ b.to_ascii_lowercase()
+///
+/// * If true, return `()`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+///
+/// # fn main() {
+/// let a = "Alfa";
+/// let b = "Bravo";
+/// assert_str_ne_ignore_case!(a, b);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let a = "Alfa";
+/// let b = "ALFA";
+/// assert_str_ne_ignore_case!(a, b);
+/// # });
+/// // assertion failed: `assert_str_ne_ignore_case!(a, b)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_str_ne_ignore_case.html
+/// //  a label: `a`,
+/// //  a debug: `\"Alfa\"`,
+/// //  b label: `b`,
+/// //  b debug: `\"ALFA\"`
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let message = concat!(
+/// #     "assertion failed: `assert_str_ne_ignore_case!(a, b)`\n",
+/// #     assertables_url_line!("assert_str_ne_ignore_case"),
+/// #     " a label: `a`,\n",
+/// #     " a debug: `\"Alfa\"`,\n",
+/// #     " b label: `b`,\n",
+/// #     " b debug: `\"ALFA\"`"
+/// # );
+/// # assert_eq!(actual, message);
+/// # }
+/// ```
+///
+/// This macro is useful for user-facing messages, where letter case
+/// should not matter, such as "Not found" versus "NOT FOUND".
+///
+/// * The comparison uses `str::eq_ignore_ascii_case`, so only the ASCII
+///   letters `A-Z` and `a-z` are case-insensitive. Any other character,
+///   such as "É" or the Turkish "İ", must match exactly.
+///
+/// * For a Unicode-aware comparison, use
+///   [`assert_eq_normalized`](macro@crate::assert_eq_normalized) with a
+///   normalize function such as `str::to_lowercase`. Be aware that Unicode
+///   case mapping depends on language, such as the Turkish dotted and
+///   dotless "i", and the standard library does not handle that.
+///
+/// * The failure message shows both original strings, so the casing
+///   difference is visible.
+///
+/// # Module macros
+///
+/// * [`assert_str_ne_ignore_case`](macro@crate::assert_str_ne_ignore_case)
+/// * [`assert_str_ne_ignore_case_as_result`](macro@crate::assert_str_ne_ignore_case_as_result)
+/// * [`debug_assert_str_ne_ignore_case`](macro@crate::debug_assert_str_ne_ignore_case)
+///
+#[macro_export]
+macro_rules! assert_str_ne_ignore_case {
+    ($a:expr, $b:expr $(,)?) => {{
+        match $crate::assert_str_ne_ignore_case_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($a:expr, $b:expr, $($message:tt)+) => {{
+        match $crate::assert_str_ne_ignore_case_as_result!($a, $b) {
+            Ok(()) => (),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_str_ne_ignore_case {
+    use std::panic;
+
+    #[test]
+    fn success() {
+        let a = "Alfa";
+        let b = "Bravo";
+        let actual = assert_str_ne_ignore_case!(a, b);
+        assert_eq!(actual, ());
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let a = "Alfa";
+            let b = "ALFA";
+            let _actual = assert_str_ne_ignore_case!(a, b);
+        });
+        let message = concat!(
+            "assertion failed: `assert_str_ne_ignore_case!(a, b)`\n",
+            assertables_url_line!("assert_str_ne_ignore_case"),
+            " a label: `a`,\n",
+            " a debug: `\"Alfa\"`,\n",
+            " b label: `b`,\n",
+            " b debug: `\"ALFA\"`"
+        );
+        assert_eq!(
+            result
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap()
+                .to_string(),
+            message
+        );
+    }
+}
+
+/// Assert a string is not equal to another, ignoring ASCII case.
+///
+/// Pseudocode:<br>
+/// a.to_ascii_lowercase() ≠ b.to_ascii_lowercase()
+///
+/// This macro provides the same statements as [`assert_str_ne_ignore_case`](macro.assert_str_ne_ignore_case.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_str_ne_ignore_case`](macro@crate::assert_str_ne_ignore_case)
+/// * [`assert_str_ne_ignore_case_as_result`](macro@crate::assert_str_ne_ignore_case_as_result)
+/// * [`debug_assert_str_ne_ignore_case`](macro@crate::debug_assert_str_ne_ignore_case)
+///
+#[macro_export]
+macro_rules! debug_assert_str_ne_ignore_case {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_str_ne_ignore_case!($($arg)*);
+        }
+    };
+}
@@ -0,0 +1,24 @@
+//! Assert for comparing strings.
+//!
+//! These macros help compare strings, such as text output, file contents,
+//! and user-facing messages, in ways that go beyond plain equality.
+//!
+//! * [`assert_str_eq_visible!(a, b)`](macro@crate::assert_str_eq_visible) ≈ a = b, and show invisible characters on failure
+//! * [`assert_str_eq_ignore_whitespace!(a, b)`](macro@crate::assert_str_eq_ignore_whitespace) ≈ a.split_whitespace() = b.split_whitespace()
+//! * [`assert_str_eq_ignore_case!(a, b)`](macro@crate::assert_str_eq_ignore_case) ≈ a = b, ignoring ASCII case
+//! * [`assert_str_ne_ignore_case!(a, b)`](macro@crate::assert_str_ne_ignore_case) ≈ a ≠ b, ignoring ASCII case
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//!
+//! let a = "alfa\r\n";
+//! let b = "alfa\r\n";
+//! assert_str_eq_visible!(a, b);
+//! ```
+
+pub mod assert_str_eq_ignore_case;
+pub mod assert_str_eq_ignore_whitespace;
+pub mod assert_str_eq_visible;
+pub mod assert_str_ne_ignore_case;
@@ -48,7 +48,7 @@ macro_rules! assert_success_false_as_result {
             Err(format!(
                 concat!(
                     "assertion failed: `assert_success_false!(a)`\n",
-                    "https://docs.rs/assertables/9.5.0/assertables/macro.assert_success_false.html\n",
+                    $crate::assertables_url_line!("assert_success_false"),
                     " a label: `{}`,\n",
                     " a debug: `{:?}`",
                 ),
@@ -89,7 +89,7 @@ mod test_assert_success_false_as_result {
         let actual = assert_success_false_as_result!(a);
         let message = concat!(
             "assertion failed: `assert_success_false!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_success_false.html\n",
+            assertables_url_line!("assert_success_false"),
             " a label: `a`,\n",
             " a debug: `A`",
         );
@@ -135,7 +135,7 @@ mod test_assert_success_false_as_result {
 /// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
 /// # let message = concat!(
 /// #     "assertion failed: `assert_success_false!(a)`\n",
-/// #     "https://docs.rs/assertables/9.5.0/assertables/macro.assert_success_false.html\n",
+/// #     assertables_url_line!("assert_success_false"),
 /// #     " a label: `a`,\n",
 /// #     " a debug: `A`",
 /// # );
@@ -154,13 +154,13 @@ macro_rules! assert_success_false {
     ($a:expr $(,)?) => {{
         match $crate::assert_success_false_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}", err),
+            Err(err) => panic!("{}", $crate::__private::style(err)),
         }
     }};
     ($a:expr, $($message:tt)+) => {{
         match $crate::assert_success_false_as_result!($a) {
             Ok(x) => x,
-            Err(err) => panic!("{}\n{}", format_args!($($message)+), err),
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
         }
     }};
 }
@@ -198,7 +198,7 @@ mod test_assert_success_false {
         });
         let message = concat!(
             "assertion failed: `assert_success_false!(a)`\n",
-            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_success_false.html\n",
+            assertables_url_line!("assert_success_false"),
             " a label: `a`,\n",
             " a debug: `A`",
         );
@@ -247,7 +247,7 @@ mod test_assert_success_false {
 #[macro_export]
 macro_rules! debug_assert_success_false {
     ($($arg:tt)*) => {
-        if $crate::cfg!(debug_assertions) {
+        if cfg!(debug_assertions) {
             $crate::assert_success_false!($($arg)*);
         }
     };
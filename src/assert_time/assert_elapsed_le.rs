@@ -0,0 +1,310 @@
+//! Assert a closure runs within a duration, by measuring the elapsed time.
+//!
+//! Pseudocode:<br>
+//! (start ⇒ closure() ⇒ elapsed) ≤ duration
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::time::Duration;
+//!
+//! let duration = Duration::from_secs(10);
+//! assert_elapsed_le!(duration, || 1 + 1);
+//! ```
+//!
+//! This macro calls the closure once, with no arguments, and measures the
+//! elapsed time by using `Instant::now()`. If the elapsed time is within
+//! the duration, then the macro returns the closure's value.
+//!
+//! The elapsed time is wall-clock time, so it includes any time when the
+//! thread is waiting, such as for the operating system scheduler or for
+//! other tests that run in parallel. This makes the elapsed time inherently
+//! noisy, so this macro is best for generous budgets, such as checking that
+//! a block that should take milliseconds does not take seconds.
+//!
+//! # Module macros
+//!
+//! * [`assert_elapsed_le`](macro@crate::assert_elapsed_le)
+//! * [`assert_elapsed_le_as_result`](macro@crate::assert_elapsed_le_as_result)
+//! * [`debug_assert_elapsed_le`](macro@crate::debug_assert_elapsed_le)
+
+/// Assert a closure runs within a duration, by measuring the elapsed time.
+///
+/// Pseudocode:<br>
+/// (start ⇒ closure() ⇒ elapsed) ≤ duration
+///
+/// * If true, return Result `Ok(value)`.
+///
+/// * Otherwise, return Result `Err(message)`.
+///
+/// This macro is useful for runtime checks, such as checking parameters,
+/// or sanitizing inputs, or handling different results in different ways.
+///
+/// # Module macros
+///
+/// * [`assert_elapsed_le`](macro@crate::assert_elapsed_le)
+/// * [`assert_elapsed_le_as_result`](macro@crate::assert_elapsed_le_as_result)
+/// * [`debug_assert_elapsed_le`](macro@crate::debug_assert_elapsed_le)
+///
+#[macro_export]
+macro_rules! assert_elapsed_le_as_result {
+    ($duration:expr, $closure:expr $(,)?) => {{
+        match (&$duration) {
+            duration => {
+                let duration: &::std::time::Duration = duration;
+                let start = ::std::time::Instant::now();
+                let value = ($closure)();
+                let elapsed = start.elapsed();
+                if elapsed <= *duration {
+                    Ok(value)
+                } else {
+                    Err(format!(
+                        concat!(
+                            "assertion failed: `assert_elapsed_le!(duration, closure)`\n",
+                            $crate::assertables_url_line!("assert_elapsed_le"),
+                            "     duration label: `{}`,\n",
+                            "     duration debug: `{:?}`,\n",
+                            "      closure label: `{}`,\n",
+                            "            elapsed: `{:?}`,\n",
+                            " elapsed ≤ duration: {}"
+                        ),
+                        stringify!($duration),
+                        duration,
+                        stringify!($closure),
+                        elapsed,
+                        false
+                    ))
+                }
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_elapsed_le_as_result {
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let duration = Duration::from_secs(10);
+        let actual = assert_elapsed_le_as_result!(duration, || 1 + 1);
+        assert_eq!(actual.unwrap(), 2);
+    }
+
+    #[test]
+    fn failure() {
+        let duration = Duration::from_millis(1);
+        let actual =
+            assert_elapsed_le_as_result!(duration, || thread::sleep(Duration::from_millis(20)));
+        let actual = actual.unwrap_err();
+        let prefix = concat!(
+            "assertion failed: `assert_elapsed_le!(duration, closure)`\n",
+            assertables_url_line!("assert_elapsed_le"),
+            "     duration label: `duration`,\n",
+            "     duration debug: `1ms`,\n",
+            "      closure label: `|| thread::sleep(Duration::from_millis(20))`,\n",
+            "            elapsed: `",
+        );
+        assert!(actual.starts_with(prefix));
+        assert!(actual.ends_with("`,\n elapsed ≤ duration: false"));
+    }
+
+    #[test]
+    fn success_with_closure_that_borrows() {
+        let duration = Duration::from_secs(10);
+        let mut count = 0;
+        let actual = assert_elapsed_le_as_result!(duration, || {
+            count += 1;
+            count
+        });
+        assert_eq!(actual.unwrap(), 1);
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn success_with_closure_that_moves() {
+        let duration = Duration::from_secs(10);
+        let v = vec![1, 2, 3];
+        let actual = assert_elapsed_le_as_result!(duration, move || v.into_iter().sum::<i32>());
+        assert_eq!(actual.unwrap(), 6);
+    }
+}
+
+/// Assert a closure runs within a duration, by measuring the elapsed time.
+///
+/// Pseudocode:<br>
+/// (start ⇒ closure() ⇒ elapsed) ≤ duration
+///
+/// * If true, return `value`.
+///
+/// * Otherwise, call [`panic!`] with a message and the values of the
+///   expressions with their debug representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use assertables::*;
+/// # use std::panic;
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// # fn main() {
+/// let duration = Duration::from_secs(10);
+/// assert_elapsed_le!(duration, || 1 + 1);
+///
+/// # let result = panic::catch_unwind(|| {
+/// // This will panic
+/// let duration = Duration::from_millis(1);
+/// assert_elapsed_le!(duration, || thread::sleep(Duration::from_millis(20)));
+/// # });
+/// // assertion failed: `assert_elapsed_le!(duration, closure)`
+/// // https://docs.rs/assertables/9.5.0/assertables/macro.assert_elapsed_le.html
+/// //      duration label: `duration`,
+/// //      duration debug: `1ms`,
+/// //       closure label: `|| thread::sleep(Duration::from_millis(20))`,
+/// //             elapsed: `20.1ms`,
+/// //  elapsed ≤ duration: false
+/// # let actual = result.unwrap_err().downcast::<String>().unwrap().to_string();
+/// # let prefix = concat!(
+/// #     "assertion failed: `assert_elapsed_le!(duration, closure)`\n",
+/// #     assertables_url_line!("assert_elapsed_le"),
+/// #     "     duration label: `duration`,\n",
+/// #     "     duration debug: `1ms`,\n",
+/// #     "      closure label: `|| thread::sleep(Duration::from_millis(20))`,\n",
+/// # );
+/// # assert!(actual.starts_with(prefix));
+/// # assert!(actual.ends_with(" elapsed ≤ duration: false"));
+/// # }
+/// ```
+///
+/// This macro calls the closure once, with no arguments, and measures the
+/// elapsed time by using `Instant::now()`. If the elapsed time is within
+/// the duration, then the macro returns the closure's value.
+///
+/// The elapsed time is wall-clock time, so it includes any time when the
+/// thread is waiting, such as for the operating system scheduler or for
+/// other tests that run in parallel. This makes the elapsed time inherently
+/// noisy, so this macro is best for generous budgets, such as checking that
+/// a block that should take milliseconds does not take seconds.
+///
+/// # Module macros
+///
+/// * [`assert_elapsed_le`](macro@crate::assert_elapsed_le)
+/// * [`assert_elapsed_le_as_result`](macro@crate::assert_elapsed_le_as_result)
+/// * [`debug_assert_elapsed_le`](macro@crate::debug_assert_elapsed_le)
+///
+#[macro_export]
+macro_rules! assert_elapsed_le {
+    ($duration:expr, $closure:expr $(,)?) => {{
+        match $crate::assert_elapsed_le_as_result!($duration, $closure) {
+            Ok(x) => x,
+            Err(err) => panic!("{}", $crate::__private::style(err)),
+        }
+    }};
+    ($duration:expr, $closure:expr, $($message:tt)+) => {{
+        match $crate::assert_elapsed_le_as_result!($duration, $closure) {
+            Ok(x) => x,
+            Err(err) => panic!("{}\n{}", format_args!($($message)+), $crate::__private::style(err)),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod test_assert_elapsed_le {
+    use std::panic;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn success() {
+        let duration = Duration::from_secs(10);
+        let actual = assert_elapsed_le!(duration, || 1 + 1);
+        assert_eq!(actual, 2);
+    }
+
+    #[test]
+    fn failure() {
+        let result = panic::catch_unwind(|| {
+            let duration = Duration::from_millis(1);
+            let _actual = assert_elapsed_le!(duration, || thread::sleep(Duration::from_millis(20)));
+        });
+        let actual = result
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap()
+            .to_string();
+        let prefix = concat!(
+            "assertion failed: `assert_elapsed_le!(duration, closure)`\n",
+            assertables_url_line!("assert_elapsed_le"),
+            "     duration label: `duration`,\n",
+            "     duration debug: `1ms`,\n",
+            "      closure label: `|| thread::sleep(Duration::from_millis(20))`,\n",
+            "            elapsed: `",
+        );
+        assert!(actual.starts_with(prefix));
+        assert!(actual.ends_with("`,\n elapsed ≤ duration: false"));
+    }
+
+    #[test]
+    fn failure_with_custom_message() {
+        let result = panic::catch_unwind(|| {
+            let duration = Duration::from_millis(1);
+            let _actual = assert_elapsed_le!(
+                duration,
+                || thread::sleep(Duration::from_millis(20)),
+                "budget for step {}",
+                1
+            );
+        });
+        let actual = result
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap()
+            .to_string();
+        assert!(actual.starts_with(
+            "budget for step 1\nassertion failed: `assert_elapsed_le!(duration, closure)`\n"
+        ));
+    }
+}
+
+/// Assert a closure runs within a duration, by measuring the elapsed time.
+///
+/// Pseudocode:<br>
+/// (start ⇒ closure() ⇒ elapsed) ≤ duration
+///
+/// This macro provides the same statements as [`assert_elapsed_le`](macro.assert_elapsed_le.html),
+/// except this macro's statements are only enabled in non-optimized
+/// builds by default. An optimized build will not execute this macro's
+/// statements unless `-C debug-assertions` is passed to the compiler.
+///
+/// This macro is useful for checks that are too expensive to be present
+/// in a release build but may be helpful during development.
+///
+/// The result of expanding this macro is always type checked.
+///
+/// An unchecked assertion allows a program in an inconsistent state to
+/// keep running, which might have unexpected consequences but does not
+/// introduce unsafety as long as this only happens in safe code. The
+/// performance cost of assertions, however, is not measurable in general.
+/// Replacing `assert*!` with `debug_assert*!` is thus only encouraged
+/// after thorough profiling, and more importantly, only in safe code!
+///
+/// This macro is intended to work in a similar way to
+/// [`::std::debug_assert`](https://doc.rust-lang.org/std/macro.debug_assert.html).
+///
+/// # Module macros
+///
+/// * [`assert_elapsed_le`](macro@crate::assert_elapsed_le)
+/// * [`assert_elapsed_le_as_result`](macro@crate::assert_elapsed_le_as_result)
+/// * [`debug_assert_elapsed_le`](macro@crate::debug_assert_elapsed_le)
+///
+#[macro_export]
+macro_rules! debug_assert_elapsed_le {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_elapsed_le!($($arg)*);
+        }
+    };
+}
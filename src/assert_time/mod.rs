@@ -0,0 +1,26 @@
+//! Assert for timing.
+//!
+//! These macros help with performance-sensitive code, such as checking that
+//! a block of code completes within a time budget. Each macro measures the
+//! elapsed wall-clock time by using [`Instant::now()`](std::time::Instant::now).
+//!
+//! Wall-clock time is inherently noisy, because it includes any time when the
+//! thread is waiting, such as for the operating system scheduler or for other
+//! tests that run in parallel. These macros are best for generous budgets.
+//!
+//! Compare the elapsed time of a closure with a duration:
+//!
+//! * [`assert_elapsed_le!(duration, closure)`](macro@crate::assert_elapsed_le) ≈ (start ⇒ closure() ⇒ elapsed) ≤ duration
+//!
+//! # Example
+//!
+//! ```rust
+//! use assertables::*;
+//! use std::time::Duration;
+//!
+//! let duration = Duration::from_secs(10);
+//! let value = assert_elapsed_le!(duration, || (1..=10).sum::<i32>());
+//! assert_eq!(value, 55);
+//! ```
+
+pub mod assert_elapsed_le;
@@ -0,0 +1,78 @@
+//! Show where two byte sequences differ, for failure messages.
+//!
+//! The byte macros, such as for command output, readers, and files, call
+//! [`bytes_diff_region`] with a format for the bytes, such as
+//! [`escape_ascii`] for text output, or [`hex`] for binary content.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Return the index of the first differing byte of two byte slices,
+/// and each slice's bytes from that index, up to 16 bytes, formatted.
+///
+/// If one slice is a prefix of the other, the index is the shorter length.
+pub fn bytes_diff_region(
+    a: &[u8],
+    b: &[u8],
+    format: fn(&[u8]) -> String,
+) -> (usize, String, String) {
+    let index = a
+        .iter()
+        .zip(b.iter())
+        .position(|(x, y)| x != y)
+        .unwrap_or(a.len().min(b.len()));
+    let region = |bytes: &[u8]| {
+        let end = bytes.len().min(index + 16);
+        format(&bytes[index..end])
+    };
+    (index, region(a), region(b))
+}
+
+/// Format bytes escaped as ASCII, such as `al\xff`.
+pub fn escape_ascii(bytes: &[u8]) -> String {
+    bytes.escape_ascii().to_string()
+}
+
+/// Format bytes as a hex dump, such as `61 6c ff`.
+pub fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| alloc::format!("{:02x}", byte))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bytes_diff_region, escape_ascii, hex};
+
+    #[test]
+    fn bytes_diff_region_x_differ() {
+        let actual = bytes_diff_region(b"alfa", b"alxa", hex);
+        assert_eq!(actual, (2, String::from("66 61"), String::from("78 61")));
+    }
+
+    #[test]
+    fn bytes_diff_region_x_prefix() {
+        let actual = bytes_diff_region(b"al", b"alfa", hex);
+        assert_eq!(actual, (2, String::from(""), String::from("66 61")));
+    }
+
+    #[test]
+    fn bytes_diff_region_x_truncate() {
+        let a = [0u8; 20];
+        let b = [1u8; 20];
+        let (index, a_diff, _b_diff) = bytes_diff_region(&a, &b, hex);
+        assert_eq!(index, 0);
+        assert_eq!(a_diff, ["00"; 16].join(" "));
+    }
+
+    #[test]
+    fn bytes_diff_region_x_escape_ascii() {
+        let actual = bytes_diff_region(b"alfa\n", b"al\xffa\n", escape_ascii);
+        assert_eq!(
+            actual,
+            (2, String::from("fa\\n"), String::from("\\xffa\\n"))
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Colors for failure messages, with the feature `colors`.
+//!
+//! Each panic form passes its message through `style`, which adds ANSI
+//! colors when stderr is a terminal, and the environment variable `NO_COLOR`
+//! is absent or empty. Otherwise the message stays exactly as is.
+//!
+//! * The first line, such as "assertion failed: …", is bold red.
+//! * The documentation URL is dim.
+//! * Each label, such as " a debug", is cyan, and its value is bold.
+//! * Each diff line that starts with `-` is red, and with `+` is green.
+
+#[cfg(not(test))]
+use std::io::IsTerminal;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+
+/// Style a message with colors, if colors are enabled.
+pub(crate) fn style(message: &str) -> String {
+    style_if(message, enabled())
+}
+
+/// Style a message with colors, if `colors` is true.
+///
+/// This separates the decision from the painting, so tests can pin it.
+fn style_if(message: &str, colors: bool) -> String {
+    if colors {
+        paint(message)
+    } else {
+        String::from(message)
+    }
+}
+
+/// Return true if stderr is a terminal and `NO_COLOR` is absent or empty.
+///
+/// The crate's own tests compare exact messages, so they never use colors,
+/// even when stderr is a terminal.
+#[cfg(not(test))]
+fn enabled() -> bool {
+    match std::env::var_os("NO_COLOR") {
+        Some(value) if !value.is_empty() => false,
+        _ => std::io::stderr().is_terminal(),
+    }
+}
+
+#[cfg(test)]
+fn enabled() -> bool {
+    false
+}
+
+/// Paint a message with colors, line by line.
+fn paint(message: &str) -> String {
+    message
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| paint_line(index, line))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn paint_line(index: usize, line: &str) -> String {
+    if index == 0 {
+        return format!("{}{}{}{}", BOLD, RED, line, RESET);
+    }
+    if line.starts_with("https://") {
+        return format!("{}{}{}", DIM, line, RESET);
+    }
+    if line.starts_with('-') {
+        return format!("{}{}{}", RED, line, RESET);
+    }
+    if line.starts_with('+') {
+        return format!("{}{}{}", GREEN, line, RESET);
+    }
+    match line.split_once(": ") {
+        Some((label, value)) if !label.trim().is_empty() && !label.contains('`') => {
+            format!("{}{}{}: {}{}{}", CYAN, label, RESET, BOLD, value, RESET)
+        }
+        _ => String::from(line),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{paint, style, style_if};
+
+    #[test]
+    fn paint_x_labels_and_values() {
+        let message = concat!(
+            "assertion failed: `assert_lt!(a, b)`\n",
+            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_lt.html\n",
+            " a label: `a`,\n",
+            " a debug: `2`"
+        );
+        let expect = concat!(
+            "\x1b[1m\x1b[31massertion failed: `assert_lt!(a, b)`\x1b[0m\n",
+            "\x1b[2mhttps://docs.rs/assertables/9.5.0/assertables/macro.assert_lt.html\x1b[0m\n",
+            "\x1b[36m a label\x1b[0m: \x1b[1m`a`,\x1b[0m\n",
+            "\x1b[36m a debug\x1b[0m: \x1b[1m`2`\x1b[0m"
+        );
+        assert_eq!(paint(message), expect);
+    }
+
+    #[test]
+    fn style_if_x_colors_on() {
+        let message = "assertion failed: `assert!(condition)`\n condition debug: `false`";
+        let expect = concat!(
+            "\x1b[1m\x1b[31massertion failed: `assert!(condition)`\x1b[0m\n",
+            "\x1b[36m condition debug\x1b[0m: \x1b[1m`false`\x1b[0m"
+        );
+        assert_eq!(style_if(message, true), expect);
+    }
+
+    #[test]
+    fn style_if_x_colors_off() {
+        let message = "assertion failed: `assert!(condition)`\n condition debug: `false`";
+        assert_eq!(style_if(message, false), message);
+    }
+
+    #[test]
+    fn style_x_tests_have_no_colors() {
+        let message = "assertion failed: `assert!(condition)`\n condition debug: `false`";
+        assert_eq!(style(message), message);
+    }
+
+    #[test]
+    fn panic_message_has_colors_off_in_tests() {
+        let result = std::panic::catch_unwind(|| {
+            crate::assert_lt!(2, 1);
+        });
+        let actual = result.unwrap_err().downcast::<String>().unwrap();
+        assert!(actual.starts_with("assertion failed: `assert_lt!(a, b)`\n"));
+        assert!(!actual.contains('\x1b'));
+    }
+
+    #[test]
+    fn paint_x_diff_lines() {
+        let message = concat!(
+            "assertion failed\n",
+            "    diff:\n",
+            "-2    | charlie\n",
+            "+   3 | delta"
+        );
+        let expect = concat!(
+            "\x1b[1m\x1b[31massertion failed\x1b[0m\n",
+            "    diff:\n",
+            "\x1b[31m-2    | charlie\x1b[0m\n",
+            "\x1b[32m+   3 | delta\x1b[0m"
+        );
+        assert_eq!(paint(message), expect);
+    }
+
+    #[test]
+    fn paint_x_keeps_text_without_colors_when_removed() {
+        let message = "assertion failed: `assert!(condition)`\n condition debug: `false`,\n";
+        let painted = paint(message);
+        let mut plain = String::new();
+        let mut rest = painted.as_str();
+        while let Some(start) = rest.find('\x1b') {
+            plain.push_str(&rest[..start]);
+            let end = rest[start..].find('m').unwrap();
+            rest = &rest[start + end + 1..];
+        }
+        plain.push_str(rest);
+        assert_eq!(plain, message);
+    }
+}
@@ -87,20 +87,24 @@
 //! ```rust
 //! # use assertables::*;
 //! # use regex::Regex;
+//! # #[cfg(feature = "std")] {
 //! let s = "hello";
 //! assert_starts_with!(s, "h");
 //! assert_contains!(s, "e");
 //! assert_is_match!(Regex::new(r"h.*o").unwrap(), s);
+//! # }
 //! ```
 //!
 //! Examples with arrays:
 //!
 //! ```rust
 //! # use assertables::*;
+//! # #[cfg(feature = "std")] {
 //! let a = [1, 2, 3];
 //! assert_not_empty!(a);
 //! assert_len_eq_x!(a, 3);
-//! assert_all!(a.into_iter(), |i: i32| i < 4);
+//! assert_all!(a, |i: i32| i < 4);
+//! # }
 //! ```
 //!
 //! ## Highlights
@@ -109,41 +113,59 @@
 //!
 //! * [`assert_eq!(a, b)`](module@crate::assert_eq) ≈ a = b ≈ equal to
 //! * [`assert_ne!(a, b)`](module@crate::assert_ne) ≈ a ≠ b ≈ not equal to
+//! * [`assert_eq_normalized!(a, b, f)`](module@crate::assert_eq::assert_eq_normalized) ≈ f(a) = f(b) ≈ equal after normalizing
+//! * [`assert_eq_diff!(a, b)`](module@crate::assert_eq::assert_eq_diff) ≈ a = b ≈ equal, with a line diff of strings
 //! * [`assert_lt!(a, b)`](module@crate::assert_lt) ≈ a < b ≈ less than
 //! * [`assert_le!(a, b)`](module@crate::assert_le) ≈ a ≤ b ≈ less than or equal to
 //! * [`assert_gt!(a, b)`](module@crate::assert_gt) ≈ a > b ≈ greater than
 //! * [`assert_ge!(a, b)`](module@crate::assert_ge) ≈ a ≥ b ≈ greater than or equal to
+//! * [`assert_between!(x, lo, hi)`](module@crate::assert_between) ≈ lo ≤ x ≤ hi ≈ between bounds
 //!
 //! Nearness:
 //!
 //! * [`assert_approx_eq!(a, b)`](module@crate::assert_approx::assert_approx_eq) ≈ |a-b| ≤ 1e-6
+//! * [`assert_f32_eq_ulps!(a, b, n)`](module@crate::assert_f32::assert_f32_eq_ulps) ≈ |a-b| ≤ n × f32::EPSILON
 //! * [`assert_diff_eq_x!(a, b, x)`](module@crate::assert_diff::assert_diff_eq_x) ≈ (b-a) = x
 //! * [`assert_abs_diff_eq_x!(a, b, x)`](module@crate::assert_abs_diff::assert_abs_diff_eq_x) ≈ |b-a| = x
+//! * [`assert_abs_diff_le_pct!(a, b, pct)`](module@crate::assert_abs_diff::assert_abs_diff_le_pct) ≈ |a-b| ≤ pct% max(|a|,|b|)
 //! * [`assert_in_delta!(a, b, delta)`](module@crate::assert_in::assert_in_delta) ≈ |a-b| ≤ Δ
 //! * [`assert_in_epsilon!(a, b, epsilon)`](module@crate::assert_in::assert_in_epsilon) ≈ |a-b| ≤ ε min(a,b)
 //! * [`assert_in_range!(a, range)`](module@crate::assert_in::assert_in_range) ≈ range.contains(a)
+//! * [`assert_duration_in_delta!(a, b, delta)`](module@crate::assert_duration::assert_duration_in_delta) ≈ |a-b| ≤ Δ for durations
+//! * [`assert_elapsed_le!(duration, closure)`](module@crate::assert_time::assert_elapsed_le) ≈ closure elapsed time ≤ duration
 //!
 //! Groups:
 //!
 //! * [`assert_all!(group, predicate)`](module@crate::assert_all) ≈ group.all(predicate)
 //! * [`assert_any!(group, predicate)`](module@crate::assert_any) ≈ group.any(predicate)
+//! * [`assert_all_entries!(map, predicate)`](module@crate::assert_all::assert_all_entries) ≈ map.iter().all(predicate)
 //! * [`assert_is_empty!(group)`](module@crate::assert_is_empty::assert_is_empty) ≈ a.is_empty()
 //! * [`assert_len_eq!(a, b)`](module@crate::assert_len::assert_len_eq) ≈ a.len() = b.len()
 //! * [`assert_count_eq!(a, b)`](module@crate::assert_count::assert_count_eq) ≈ a.count() = b.count()
+//! * [`assert_char_count_eq_x!(a, x)`](module@crate::assert_char_count::assert_char_count_eq_x) ≈ a.chars().count() = x
+//! * [`assert_sorted!(group)`](module@crate::assert_sorted::assert_sorted) ≈ group is sorted ascending
 //!
 //! Matching:
 //!
 //! * [`assert_starts_with!(sequence, x)`](module@crate::assert_starts_with) ≈ sequence.starts_with(x)
 //! * [`assert_ends_with!(sequence, x)`](module@crate::assert_ends_with) ≈ sequence.ends_with(x)
 //! * [`assert_contains!(container, x)`](module@crate::assert_contains) ≈ container.contains(x)
+//! * [`assert_contains_key!(map, key)`](module@crate::assert_contains::assert_contains_key) ≈ map.contains_key(key)
+//! * [`assert_contains_subslice!(haystack, needle)`](module@crate::assert_contains::assert_contains_subslice) ≈ haystack contains contiguous needle
 //! * [`assert_is_match!(matcher, x)`](module@crate::assert_is_match) ≈ matcher.is_match(x)
 //! * [`assert_matches!(expr, pattern)`](module@crate::assert_matches) ≈ matches!(expr, pattern)
+//! * [`assert_email_address!(a)`](module@crate::assert_email_address) ≈ a is an email address, or why not
+//! * [`assert_str_eq_visible!(a, b)`](module@crate::assert_str::assert_str_eq_visible) ≈ a = b, showing invisible characters
+//! * [`assert_str_eq_ignore_whitespace!(a, b)`](module@crate::assert_str::assert_str_eq_ignore_whitespace) ≈ a = b, ignoring whitespace
+//! * [`assert_str_eq_ignore_case!(a, b)`](module@crate::assert_str::assert_str_eq_ignore_case) ≈ a = b, ignoring ASCII case
 //!
 //! Results:
 //!
 //! * [`assert_ok!(a)`](module@crate::assert_ok) ≈ a is Ok
 //! * [`assert_err!(a)`](module@crate::assert_err) ≈ a is Err
 //! * [`assert_ok_eq_x!(a, x)`](module@crate::assert_ok::assert_ok_eq_x) ≈ a is Ok ⇒ unwrap = x
+//! * [`assert_ok_and!(a, predicate)`](module@crate::assert_ok::assert_ok_and) ≈ a is Ok ⇒ unwrap ⇒ predicate
+//! * [`assert_error_source_contains!(error, type)`](module@crate::assert_error_source_contains) ≈ error source chain contains type
 //!
 //! Options:
 //!
@@ -156,17 +178,28 @@
 //! * [`assert_ready!(a)`](module@crate::assert_ready) ≈ a is Ready
 //! * [`assert_pending!(a)`](module@crate::assert_pending) ≈ a is Pending
 //! * [`assert_ready_eq_x!(a, x)`](module@crate::assert_ready::assert_ready_eq_x) ≈ a is Ready ⇒ unwrap = x
+//! * [`assert_future_ready_eq_x!(a, x)`](module@crate::assert_future::assert_future_ready_eq_x) ≈ a ⇒ poll once ⇒ Ready ⇒ unwrap = x
+//!
+//! Panics:
+//!
+//! * [`assert_panics!(expr)`](module@crate::assert_panic::assert_panics) ≈ expr panics
+//! * [`assert_panics_message_contains!(expr, substr)`](module@crate::assert_panic::assert_panics_message_contains) ≈ expr panics ⇒ message contains substr
+//! * [`assert_does_not_panic!(expr)`](module@crate::assert_panic::assert_does_not_panic) ≈ expr does not panic
 //!
 //! Collections:
 //!
 //! * [`assert_iter_eq!(a, b)`](module@crate::assert_iter) ≈ a into iter = b into iter
 //! * [`assert_set_eq!(a, b)`](module@crate::assert_set) ≈ a into set = b into set
 //! * [`assert_bag_eq!(a, b)`](module@crate::assert_bag) ≈ a into bag = = b into bag
+//! * [`assert_ranges_disjoint!(ranges)`](module@crate::assert_ranges) ≈ ranges do not overlap
+//! * [`assert_monotonic_increasing!(slice)`](module@crate::assert_monotonic) ≈ slice items increase
 //!
 //! Readers:
 //!
 //! * [`assert_fs_read_to_string_eq_x!(path, x)`](module@crate::assert_fs_read_to_string) ≈ path ⇒ file ⇒ string = x
+//! * [`assert_fs_read_to_bytes_eq_x!(path, x)`](module@crate::assert_fs_read_to_bytes) ≈ path ⇒ file ⇒ bytes = x
 //! * [`assert_io_read_to_string_eq_x!(reader, x)`](module@crate::assert_io_read_to_string) ≈ reader ⇒ bytes ⇒ string = x
+//! * [`assert_io_read_to_bytes_eq_x!(reader, x)`](module@crate::assert_io_read_to_bytes) ≈ reader ⇒ bytes = x
 //!
 //! Commands:
 //!
@@ -215,6 +248,64 @@
 //! * [`let string = assert_fs_read_to_string_ne!("alfa.txt", "")`](module@crate::assert_fs_read_to_string::assert_fs_read_to_string_ne)
 //! * [`let stdout = assert_command_stdout_gt!("ls", vec![b' '])`](module@crate::assert_command::assert_command_stdout_gt)
 //!
+//! ## No std
+//!
+//! The crate has a default feature `std`. To use the crate without `std`,
+//! such as for embedded work, turn off default features:
+//!
+//! ```toml
+//! assertables = { version = "9", default-features = false }
+//! ```
+//!
+//! Without `std`, the crate uses `alloc` for its messages, and provides
+//! the macros for values, comparisons, nearness, and sets and bags:
+//!
+//! * [assert](module@crate::assert),
+//!   [assert_eq](module@crate::assert_eq),
+//!   [assert_ne](module@crate::assert_ne),
+//!   [assert_lt](module@crate::assert_lt),
+//!   [assert_le](module@crate::assert_le),
+//!   [assert_gt](module@crate::assert_gt),
+//!   [assert_ge](module@crate::assert_ge),
+//!   [assert_between](module@crate::assert_between),
+//!   [assert_monotonic](module@crate::assert_monotonic)
+//! * [assert_abs_diff](module@crate::assert_abs_diff),
+//!   [assert_approx](module@crate::assert_approx),
+//!   [assert_diff](module@crate::assert_diff),
+//!   [assert_f32](module@crate::assert_f32),
+//!   [assert_f64](module@crate::assert_f64),
+//!   [assert_in](module@crate::assert_in),
+//!   [assert_duration](module@crate::assert_duration)
+//! * [assert_set](module@crate::assert_set),
+//!   [assert_bag](module@crate::assert_bag)
+//!
+//! Without `std`, the overflow checks that would catch a panic, such as in
+//! [`assert_abs_diff_eq_x`](macro@crate::assert_abs_diff_eq_x), let the panic
+//! propagate. All other macro groups need `std`.
+//!
+//! ## Colors
+//!
+//! The crate has an optional feature `colors`, which adds ANSI colors to
+//! the panic messages, so the labels and values are easier to scan:
+//!
+//! ```toml
+//! assertables = { version = "9", features = ["colors"] }
+//! ```
+//!
+//! Colors are only used when stderr is a terminal, and the environment
+//! variable [`NO_COLOR`](https://no-color.org/) is absent or empty.
+//! The `_as_result` forms always return messages without colors.
+//!
+//! ## Messages without URL
+//!
+//! Each failure message has a line with a URL to the macro's documentation.
+//! To omit the line, such as for messages that go into your own error types,
+//! turn on the feature `no_url_in_messages`:
+//!
+//! ```toml
+//! assertables = { version = "9", features = ["no_url_in_messages"] }
+//! ```
+//!
 //! ## Tracking
 //!
 //! * Package: assertables-rust-crate
@@ -224,6 +315,214 @@
 //! * License: MIT or Apache-2.0 or GPL-2.0 or GPL-3.0 or contact us for more
 //! * Contact: Joel Parker Henderson (joel@joelparkerhenderson.com)
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+/// Items for macro expansions, which work with or without `std`.
+///
+/// The macros that are available in `no_std` call these items via
+/// `$crate::__private`, so a caller needs neither `std` nor `alloc`.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::collections::{BTreeMap, BTreeSet};
+    pub use alloc::format;
+    pub use alloc::string::String;
+    pub use alloc::vec::Vec;
+
+    #[cfg(any(feature = "std", test))]
+    pub use ::std::panic::catch_unwind;
+
+    /// Without `std` there is no unwinding to catch, so call the closure.
+    #[cfg(not(any(feature = "std", test)))]
+    pub fn catch_unwind<F: FnOnce() -> R, R>(
+        f: F,
+    ) -> Result<R, alloc::boxed::Box<dyn ::core::any::Any + Send + 'static>> {
+        Ok(f())
+    }
+
+    /// Call a predicate with a reference to a value.
+    ///
+    /// The macros `assert_ok_and` and `assert_err_and` pass their closure
+    /// through this function, so the closure's parameter type is inferred
+    /// from the value, such as `|x| *x > 1` rather than `|x: &i8| *x > 1`.
+    pub fn call_predicate<T: ?Sized, F: FnOnce(&T) -> bool>(value: &T, predicate: F) -> bool {
+        predicate(value)
+    }
+
+    /// Format a debug representation, truncated to `max` chars, with `…`
+    /// at the end when truncated.
+    ///
+    /// A message about a large collection uses this, so a full dump does not
+    /// bury the message, such as for each whole collection in a message that
+    /// shows the differences between two collections.
+    pub fn debug_truncated<T: ::core::fmt::Debug + ?Sized>(value: &T, max: usize) -> String {
+        let debug = format!("{:?}", value);
+        match debug.char_indices().nth(max) {
+            Some((index, _)) => format!("{}…", &debug[..index]),
+            None => debug,
+        }
+    }
+
+    /// Keep a copy of a value, so a failure message can show the value after
+    /// the macro moves the original, such as into a predicate.
+    ///
+    /// A macro calls `(&KeepProbe(&value)).keep()`. Method lookup tries
+    /// [`ProbeKeepClone`] first, which applies when the value is `Clone` and
+    /// `Debug`, and returns a clone. Otherwise lookup falls back to
+    /// [`ProbeKeepNone`], which returns [`NotKept`]. Either way the result is
+    /// `Debug`, and the macro formats it only on failure.
+    pub struct KeepProbe<'a, T>(pub &'a T);
+
+    pub trait ProbeKeepClone {
+        type Kept;
+        fn keep(&self) -> Self::Kept;
+    }
+
+    impl<T: Clone + ::core::fmt::Debug> ProbeKeepClone for KeepProbe<'_, T> {
+        type Kept = T;
+        fn keep(&self) -> T {
+            self.0.clone()
+        }
+    }
+
+    pub trait ProbeKeepNone {
+        type Kept;
+        fn keep(&self) -> Self::Kept;
+    }
+
+    impl<T> ProbeKeepNone for &KeepProbe<'_, T> {
+        type Kept = NotKept;
+        fn keep(&self) -> NotKept {
+            NotKept
+        }
+    }
+
+    /// A value that is not `Clone` and `Debug`, which formats as a note.
+    pub struct NotKept;
+
+    impl ::core::fmt::Debug for NotKept {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            f.write_str("(type does not implement Clone and Debug)")
+        }
+    }
+
+    /// Style a failure message for a panic, such as with colors.
+    ///
+    /// Without the feature `colors`, this returns the message as is.
+    #[cfg(not(feature = "colors"))]
+    pub fn style<M: ::core::fmt::Display>(message: M) -> M {
+        message
+    }
+
+    #[cfg(feature = "colors")]
+    pub fn style<M: ::core::fmt::Display>(message: M) -> String {
+        crate::colors::style(&message.to_string())
+    }
+}
+
+#[cfg(feature = "colors")]
+mod colors;
+
+#[doc(hidden)]
+pub mod bytes_diff;
+
+/// The version of this crate, as a literal, for the documentation URL in
+/// each failure message, such as within `concat!`.
+///
+/// A macro can't use `env!("CARGO_PKG_VERSION")` for this, because `env!`
+/// would expand in the caller's crate, and give the caller's version.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assertables_version {
+    () => {
+        "9.5.0"
+    };
+}
+
+/// The documentation URL line in each failure message, as a literal,
+/// such as within `concat!`.
+///
+/// With the feature `no_url_in_messages`, the line is empty, so the
+/// messages omit the URL.
+#[cfg(not(feature = "no_url_in_messages"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assertables_url_line {
+    ($name:literal) => {
+        concat!(
+            "https://docs.rs/assertables/",
+            $crate::assertables_version!(),
+            "/assertables/macro.",
+            $name,
+            ".html\n"
+        )
+    };
+}
+
+#[cfg(feature = "no_url_in_messages")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assertables_url_line {
+    ($name:literal) => {
+        ""
+    };
+}
+
+#[cfg(test)]
+mod test_assertables_version {
+
+    #[test]
+    fn version_is_cargo_pkg_version() {
+        assert_eq!(assertables_version!(), env!("CARGO_PKG_VERSION"));
+    }
+
+    #[cfg(not(feature = "no_url_in_messages"))]
+    #[test]
+    fn message_url_has_cargo_pkg_version() {
+        let actual = crate::assert_lt_as_result!(2, 1).unwrap_err();
+        let url = format!(
+            "https://docs.rs/assertables/{}/assertables/macro.assert_lt.html",
+            env!("CARGO_PKG_VERSION")
+        );
+        assert!(actual.contains(&url));
+    }
+}
+
+#[cfg(all(test, not(feature = "no_url_in_messages")))]
+mod test_assertables_url_line {
+
+    #[test]
+    fn url_line() {
+        assert_eq!(
+            assertables_url_line!("assert_lt"),
+            "https://docs.rs/assertables/9.5.0/assertables/macro.assert_lt.html\n"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "no_url_in_messages"))]
+mod test_assertables_url_line {
+
+    #[test]
+    fn url_line() {
+        assert_eq!(assertables_url_line!("assert_lt"), "");
+    }
+
+    #[test]
+    fn message_has_no_url() {
+        let actual = crate::assert_lt_as_result!(2, 1).unwrap_err();
+        let message = concat!(
+            "assertion failed: `assert_lt!(a, b)`\n",
+            " a label: `2`,\n",
+            " a debug: `2`,\n",
+            " b label: `1`,\n",
+            " b debug: `1`"
+        );
+        assert_eq!(actual, message);
+    }
+}
+
 // Assert truth
 pub mod assert; // (in addition to what's provided by Rust `std`)
 
@@ -235,62 +534,129 @@ pub mod assert_le;
 pub mod assert_lt;
 pub mod assert_ne; // (in addition to what's provided by Rust `std`)
 
+// Assert between bounds
+pub mod assert_between;
+
+// Assert monotonic
+pub mod assert_monotonic;
+
 // Assert difference
 pub mod assert_abs_diff;
 pub mod assert_approx;
 pub mod assert_diff;
+pub mod assert_f32;
+pub mod assert_f64;
 pub mod assert_in;
 
+// Assert duration
+pub mod assert_duration;
+
 // Assert all/any
+#[cfg(feature = "std")]
 pub mod assert_all;
+#[cfg(feature = "std")]
 pub mod assert_any;
 
 // Infix
+#[cfg(feature = "std")]
 pub mod assert_infix;
 
 // Matching
+#[cfg(feature = "std")]
+pub mod assert_char_count;
+#[cfg(feature = "std")]
 pub mod assert_contains;
+#[cfg(feature = "std")]
 pub mod assert_count;
+#[cfg(feature = "std")]
+pub mod assert_email_address;
+#[cfg(feature = "std")]
 pub mod assert_ends_with;
+#[cfg(feature = "std")]
 pub mod assert_is_empty;
+#[cfg(feature = "std")]
 pub mod assert_is_match;
+#[cfg(feature = "std")]
 pub mod assert_len;
+#[cfg(feature = "std")]
 pub mod assert_matches;
+#[cfg(feature = "std")]
 pub mod assert_starts_with;
+#[cfg(feature = "std")]
+pub mod assert_str;
 
 // For Result Ok & Err
+#[cfg(feature = "std")]
 pub mod assert_err;
+#[cfg(feature = "std")]
+pub mod assert_error_source_contains;
+#[cfg(feature = "std")]
 pub mod assert_ok;
+#[cfg(feature = "std")]
 pub mod assert_result; // Deprecated
 
 // For Option Some & None
+#[cfg(feature = "std")]
 pub mod assert_none;
+#[cfg(feature = "std")]
 pub mod assert_option;
+#[cfg(feature = "std")]
 pub mod assert_some; // Deprecated
 
+// For panics
+#[cfg(feature = "std")]
+pub mod assert_panic;
+
+// For timing
+#[cfg(feature = "std")]
+pub mod assert_time;
+
 // For Poll Ready & Pending
+#[cfg(feature = "std")]
+pub mod assert_future;
+#[cfg(feature = "std")]
 pub mod assert_pending;
+#[cfg(feature = "std")]
 pub mod assert_poll;
+#[cfg(feature = "std")]
 pub mod assert_ready; // Deprecated
 
 // For collections
 pub mod assert_bag;
+#[cfg(feature = "std")]
 pub mod assert_iter;
+#[cfg(feature = "std")]
+pub mod assert_ranges;
 pub mod assert_set;
+#[cfg(feature = "std")]
+pub mod assert_sorted;
 
 // For functions
+#[cfg(feature = "std")]
 pub mod assert_fn;
+#[cfg(feature = "std")]
 pub mod assert_fn_err;
+#[cfg(feature = "std")]
 pub mod assert_fn_ok;
 
 // For reading
+#[cfg(feature = "std")]
+pub mod assert_fs_read_to_bytes;
+#[cfg(feature = "std")]
 pub mod assert_fs_read_to_string;
+#[cfg(feature = "std")]
+pub mod assert_io_read_to_bytes;
+#[cfg(feature = "std")]
 pub mod assert_io_read_to_string;
 
 // For externals
+#[cfg(feature = "std")]
 pub mod assert_command;
+#[cfg(feature = "std")]
 pub mod assert_program_args;
+#[cfg(feature = "std")]
 pub mod assert_status;
 
 // Misc
+#[cfg(feature = "std")]
 pub mod assert_success;
@@ -82,4 +82,37 @@ fn validate_words() {
 	let result = validate_words("this text contains alfa");
 	assert_err!(result);
 
+}
+
+/// Validate a reading change, such as a sensor going from 20 to 23.
+/// Note that real-world code would use more-complex sensor validation.
+#[test]
+fn validate_reading_change() {
+
+	fn validate_reading_change(previous: i32, current: i32) -> Result<(i32, i32), String> {
+		assert_diff_ge_x_as_result!(previous, current, 0)?;
+		assert_abs_diff_le_x_as_result!(previous, current, 5)
+	}
+
+	// Success returns the absolute difference and the limit
+	match validate_reading_change(20, 23) {
+		Ok((abs_diff, limit)) => {
+			assert_eq!(abs_diff, 3);
+			assert_eq!(limit, 5);
+		}
+		Err(err) => panic!("{}", err),
+	}
+
+	// Failure because the reading went down
+	match validate_reading_change(20, 19) {
+		Ok(_) => panic!("expected Err"),
+		Err(err) => assert!(err.starts_with("assertion failed: `assert_diff_ge_x!(a, b, x)`\n")),
+	}
+
+	// Failure because the reading jumped too far
+	match validate_reading_change(20, 29) {
+		Ok(_) => panic!("expected Err"),
+		Err(err) => assert!(err.starts_with("assertion failed: `assert_abs_diff_le_x!(a, b, x)`\n")),
+	}
+
 }
\ No newline at end of file
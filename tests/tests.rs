@@ -1 +1,3 @@
+#![cfg(feature = "std")]
+
 mod examples;
\ No newline at end of file